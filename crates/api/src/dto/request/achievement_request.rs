@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for creating an achievement.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateAchievementRequest {
+    /// Stable identifier used by clients
+    #[schema(example = "tyt_matematik_net_30")]
+    #[validate(length(min = 1, max = 100))]
+    pub code: String,
+    #[schema(example = "TYT Matematik 30+")]
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+    #[schema(example = "Score a net of 30 or more on a TYT Matematik test")]
+    #[validate(length(min = 1, max = 1000))]
+    pub description: String,
+    /// solving_streak, tests_solved, test_books_completed or net_score
+    #[schema(example = "net_score")]
+    pub rule: String,
+    /// Value to reach: days, tests, test books or net
+    #[schema(example = 30.0)]
+    #[validate(range(exclusive_min = 0.0))]
+    pub threshold: f64,
+    /// Limit a net score achievement to tests of this exam type
+    pub exam_type_id: Option<Uuid>,
+    /// Limit a net score achievement to tests of this lesson
+    pub lesson_id: Option<Uuid>,
+}
+
+impl CreateAchievementRequest {
+    pub fn into_app_request(self) -> application::dto::CreateAchievementRequest {
+        application::dto::CreateAchievementRequest {
+            code: self.code,
+            name: self.name,
+            description: self.description,
+            rule: self.rule,
+            threshold: self.threshold,
+            exam_type_id: self.exam_type_id,
+            lesson_id: self.lesson_id,
+        }
+    }
+}
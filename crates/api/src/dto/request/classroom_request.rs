@@ -0,0 +1,55 @@
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for creating a classroom.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateClassroomRequest {
+    #[schema(example = "12-A Sayısal")]
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+}
+
+/// Request body for joining a classroom.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct JoinClassroomRequest {
+    /// Code shared by the teacher; case-insensitive
+    #[schema(example = "K7MXQ2PA")]
+    #[validate(length(min = 1, max = 20))]
+    pub invite_code: String,
+}
+
+/// Request body for assigning a practice test to a classroom.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateAssignmentRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    /// Deadline, in the future
+    #[schema(example = "2025-03-01T21:00:00Z")]
+    pub due_at: DateTime<Utc>,
+}
+
+impl CreateClassroomRequest {
+    pub fn into_app_request(self) -> application::dto::CreateClassroomRequest {
+        application::dto::CreateClassroomRequest { name: self.name }
+    }
+}
+
+impl JoinClassroomRequest {
+    pub fn into_app_request(self) -> application::dto::JoinClassroomRequest {
+        application::dto::JoinClassroomRequest {
+            invite_code: self.invite_code,
+        }
+    }
+}
+
+impl CreateAssignmentRequest {
+    pub fn into_app_request(self) -> application::dto::CreateAssignmentRequest {
+        application::dto::CreateAssignmentRequest {
+            practice_test_id: self.practice_test_id,
+            due_at: self.due_at,
+        }
+    }
+}
@@ -0,0 +1,20 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// Request body for inviting a student to link with a guardian.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct InviteStudentRequest {
+    /// Username or email of the student
+    #[schema(example = "ayse")]
+    #[validate(length(min = 1, max = 255))]
+    pub student: String,
+}
+
+impl InviteStudentRequest {
+    pub fn into_app_request(self) -> application::dto::InviteStudentRequest {
+        application::dto::InviteStudentRequest {
+            student: self.student,
+        }
+    }
+}
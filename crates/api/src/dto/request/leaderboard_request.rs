@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Request body for changing whether the user appears on public leaderboards.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct UpdateLeaderboardPreferenceRequest {
+    /// Hide the user from other users' leaderboards; their own rank stays visible to them
+    #[schema(example = true)]
+    pub leaderboard_opt_out: bool,
+}
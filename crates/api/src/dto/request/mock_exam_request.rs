@@ -0,0 +1,117 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for creating a mock exam.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateMockExamRequest {
+    #[schema(example = "TYT Deneme 1")]
+    #[validate(length(min = 1, max = 200))]
+    pub name: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub exam_type_id: Uuid,
+    /// Time allowed for the whole mock exam
+    #[schema(example = 165)]
+    #[validate(range(min = 1))]
+    pub time_limit_minutes: i32,
+    /// Practice tests forming the sections, in solving order
+    #[validate(length(min = 1, message = "At least one section is required"))]
+    pub practice_test_ids: Vec<Uuid>,
+}
+
+/// Request body for updating a mock exam.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct UpdateMockExamRequest {
+    #[schema(example = "TYT Deneme 1")]
+    #[validate(length(min = 1, max = 200))]
+    pub name: Option<String>,
+    #[schema(example = 165)]
+    #[validate(range(min = 1))]
+    pub time_limit_minutes: Option<i32>,
+    /// Replaces the sections, in solving order
+    #[validate(length(min = 1, message = "At least one section is required"))]
+    pub practice_test_ids: Option<Vec<Uuid>>,
+}
+
+/// Answers of one section of a mock exam attempt.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct MockExamSectionAnswersRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    #[schema(example = "ABCD_BCDABCDABCDABCDABCDABCDABCDABCDABCD")]
+    #[validate(length(min = 1))]
+    pub answers: String,
+}
+
+/// Request body for autosaving the answers of a mock exam attempt.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SaveMockExamAnswersRequest {
+    /// Sections to autosave; sections left out keep their saved answers
+    #[validate(nested)]
+    pub sections: Vec<MockExamSectionAnswersRequest>,
+}
+
+/// Request body for submitting a mock exam attempt.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SubmitMockExamAttemptRequest {
+    /// Final answers; sections left out are graded with their autosaved answers
+    #[serde(default)]
+    #[validate(nested)]
+    pub sections: Vec<MockExamSectionAnswersRequest>,
+}
+
+// Conversion implementations
+impl CreateMockExamRequest {
+    pub fn into_app_request(self) -> application::dto::CreateMockExamRequest {
+        application::dto::CreateMockExamRequest {
+            name: self.name,
+            exam_type_id: self.exam_type_id,
+            time_limit_minutes: self.time_limit_minutes,
+            practice_test_ids: self.practice_test_ids,
+        }
+    }
+}
+
+impl UpdateMockExamRequest {
+    pub fn into_app_request(self) -> application::dto::UpdateMockExamRequest {
+        application::dto::UpdateMockExamRequest {
+            name: self.name,
+            time_limit_minutes: self.time_limit_minutes,
+            practice_test_ids: self.practice_test_ids,
+        }
+    }
+}
+
+impl MockExamSectionAnswersRequest {
+    fn into_app_dto(self) -> application::dto::MockExamSectionAnswersDto {
+        application::dto::MockExamSectionAnswersDto {
+            practice_test_id: self.practice_test_id,
+            answers: self.answers,
+        }
+    }
+}
+
+impl SaveMockExamAnswersRequest {
+    pub fn into_app_request(self) -> application::dto::SaveMockExamAnswersRequest {
+        application::dto::SaveMockExamAnswersRequest {
+            sections: self
+                .sections
+                .into_iter()
+                .map(|s| s.into_app_dto())
+                .collect(),
+        }
+    }
+}
+
+impl SubmitMockExamAttemptRequest {
+    pub fn into_app_request(self) -> application::dto::SubmitMockExamAttemptRequest {
+        application::dto::SubmitMockExamAttemptRequest {
+            sections: self
+                .sections
+                .into_iter()
+                .map(|s| s.into_app_dto())
+                .collect(),
+        }
+    }
+}
@@ -1,8 +1,25 @@
+mod achievement_request;
 mod auth_request;
+mod classroom_request;
+mod guardian_request;
+mod leaderboard_request;
+mod mock_exam_request;
+mod result_import_request;
+mod score_request;
+mod study_plan_request;
 mod test_request;
+mod topic_request;
 mod user_request;
 
+pub use achievement_request::*;
 pub use auth_request::*;
+pub use classroom_request::*;
+pub use guardian_request::*;
+pub use leaderboard_request::*;
+pub use mock_exam_request::*;
+pub use result_import_request::*;
+pub use score_request::*;
+pub use study_plan_request::*;
 pub use test_request::*;
+pub use topic_request::*;
 pub use user_request::*;
-
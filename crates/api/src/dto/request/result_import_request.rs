@@ -0,0 +1,59 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use validator::{Validate, ValidationError};
+
+/// Request body for importing optical answer form (OMR) results of a practice test.
+///
+/// `content` holds the exported file. CSV rows are `student_number<delimiter>answers`;
+/// fixed-width rows are read from the given 1-based column positions.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct ImportOmrResultsRequest {
+    /// File layout: `csv` or `fixed_width`
+    #[schema(example = "csv")]
+    pub format: String,
+    /// Contents of the exported answer form file
+    #[schema(example = "student_number,answers\n2024001,ABCD_BCDA\n2024002,ABCDABCD_")]
+    #[validate(length(min = 1, message = "Content cannot be empty"))]
+    pub content: String,
+    /// CSV column delimiter; defaults to ','
+    #[schema(example = ";")]
+    #[validate(custom(function = "validate_delimiter"))]
+    pub delimiter: Option<String>,
+    /// Whether the first CSV line is a header
+    #[serde(default)]
+    pub has_header: bool,
+    /// 1-based column where the student number starts (fixed width)
+    #[schema(example = 1)]
+    pub student_number_start: Option<usize>,
+    /// Length of the student number (fixed width)
+    #[schema(example = 10)]
+    pub student_number_length: Option<usize>,
+    /// 1-based column where the answers start (fixed width)
+    #[schema(example = 11)]
+    pub answers_start: Option<usize>,
+    /// Import rows of students who already have a result for the test
+    #[serde(default)]
+    pub allow_existing: bool,
+}
+
+fn validate_delimiter(delimiter: &str) -> Result<(), ValidationError> {
+    if delimiter.chars().count() != 1 {
+        return Err(ValidationError::new("delimiter_must_be_single_character"));
+    }
+    Ok(())
+}
+
+impl ImportOmrResultsRequest {
+    pub fn into_app_request(self) -> application::dto::ImportOmrResultsRequest {
+        application::dto::ImportOmrResultsRequest {
+            format: self.format,
+            content: self.content,
+            delimiter: self.delimiter.and_then(|d| d.chars().next()),
+            has_header: self.has_header,
+            student_number_start: self.student_number_start,
+            student_number_length: self.student_number_length,
+            answers_start: self.answers_start,
+            allow_existing: self.allow_existing,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Coefficient of a single lesson in a score table.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct LessonCoefficientRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub lesson_id: Uuid,
+    #[schema(example = 3.3)]
+    pub coefficient: f64,
+}
+
+/// Request body for creating or replacing a yearly score table.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SaveScoreTableRequest {
+    #[schema(example = 100.0)]
+    #[validate(range(min = 0.0))]
+    pub base_score: f64,
+    /// Multiplier for the diploma score (OBP); defaults to 0.12
+    #[schema(example = 0.12)]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub diploma_coefficient: Option<f64>,
+    #[validate(length(min = 1, message = "At least one lesson coefficient is required"))]
+    pub coefficients: Vec<LessonCoefficientRequest>,
+}
+
+/// Net entered manually for a subject.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SubjectNetRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub subject_id: Uuid,
+    #[schema(example = 32.5)]
+    pub net: f64,
+    /// Number of questions the net was scored out of; the net must lie between
+    /// all of them answered wrong and all of them answered correctly
+    #[schema(example = 40)]
+    #[validate(range(min = 1))]
+    pub question_count: i32,
+}
+
+/// Request body for estimating a standard score.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct EstimateScoreRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub exam_type_id: Uuid,
+    /// Coefficient year; the most recent table is used when omitted
+    #[schema(example = 2024)]
+    pub year: Option<i32>,
+    /// IDs of the caller's own test results to include
+    #[serde(default)]
+    pub result_ids: Vec<Uuid>,
+    /// Manually entered nets per subject
+    #[serde(default)]
+    #[validate(nested)]
+    pub subject_nets: Vec<SubjectNetRequest>,
+    /// High school diploma grade (0-100), needed for the placement score
+    #[schema(example = 85.0)]
+    #[validate(range(min = 0.0, max = 100.0))]
+    pub diploma_grade: Option<f64>,
+}
+
+// Conversion implementations
+impl SaveScoreTableRequest {
+    pub fn into_app_request(self) -> application::dto::SaveScoreTableRequest {
+        application::dto::SaveScoreTableRequest {
+            base_score: self.base_score,
+            diploma_coefficient: self.diploma_coefficient,
+            coefficients: self
+                .coefficients
+                .into_iter()
+                .map(|c| application::dto::LessonCoefficientDto {
+                    lesson_id: c.lesson_id,
+                    coefficient: c.coefficient,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl EstimateScoreRequest {
+    pub fn into_app_request(self) -> application::dto::EstimateScoreRequest {
+        application::dto::EstimateScoreRequest {
+            exam_type_id: self.exam_type_id,
+            year: self.year,
+            result_ids: self.result_ids,
+            subject_nets: self
+                .subject_nets
+                .into_iter()
+                .map(|s| application::dto::SubjectNetDto {
+                    subject_id: s.subject_id,
+                    net: s.net,
+                    question_count: s.question_count,
+                })
+                .collect(),
+            diploma_grade: self.diploma_grade,
+        }
+    }
+}
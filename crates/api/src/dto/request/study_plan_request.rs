@@ -0,0 +1,49 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for setting the exam the user prepares for.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SetStudyPlanRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub exam_type_id: Uuid,
+    /// Date of the exam, today or later
+    #[schema(example = "2025-06-21")]
+    pub target_date: NaiveDate,
+}
+
+/// Request body for creating a daily study goal.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateStudyGoalRequest {
+    /// What the goal counts: tests or questions
+    #[schema(example = "questions")]
+    pub metric: String,
+    /// Amount to reach every day
+    #[schema(example = 40)]
+    #[validate(range(min = 1, max = 1000))]
+    pub daily_target: i32,
+    /// Only count results of this lesson; omit for all lessons
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub lesson_id: Option<Uuid>,
+}
+
+impl SetStudyPlanRequest {
+    pub fn into_app_request(self) -> application::dto::SetStudyPlanRequest {
+        application::dto::SetStudyPlanRequest {
+            exam_type_id: self.exam_type_id,
+            target_date: self.target_date,
+        }
+    }
+}
+
+impl CreateStudyGoalRequest {
+    pub fn into_app_request(self) -> application::dto::CreateStudyGoalRequest {
+        application::dto::CreateStudyGoalRequest {
+            metric: self.metric,
+            daily_target: self.daily_target,
+            lesson_id: self.lesson_id,
+        }
+    }
+}
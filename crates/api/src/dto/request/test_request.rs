@@ -27,6 +27,9 @@ pub struct CreateExamTypeRequest {
     pub name: String,
     #[schema(example = "Temel Yeterlilik Testi")]
     pub description: Option<String>,
+    /// Show answer keys to students before they submit a test
+    #[schema(example = false)]
+    pub reveal_answer_keys: Option<bool>,
 }
 
 /// Request body for updating an exam type.
@@ -37,6 +40,47 @@ pub struct UpdateExamTypeRequest {
     pub name: Option<String>,
     #[schema(example = "Temel Yeterlilik Testi")]
     pub description: Option<String>,
+    /// Show answer keys to students before they submit a test
+    #[schema(example = false)]
+    pub reveal_answer_keys: Option<bool>,
+}
+
+/// Request body for changing the scoring policy of an exam type.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct UpdateScoringPolicyRequest {
+    /// Fraction of a correct answer cancelled by each wrong answer
+    #[schema(example = 0.25)]
+    #[validate(range(min = 0.0, max = 1.0))]
+    pub penalty_ratio: f64,
+    /// Points awarded per net question
+    #[schema(example = 1.0)]
+    #[validate(range(exclusive_min = 0.0))]
+    pub question_weight: f64,
+    /// One of "none", "nearest" or "floor"
+    #[schema(example = "none")]
+    pub rounding_mode: String,
+    #[schema(example = 2)]
+    #[validate(range(min = 0, max = 4))]
+    pub decimal_places: i32,
+}
+
+/// Request body for setting the retake policy of an exam type or test book.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct UpdateRetakePolicyRequest {
+    /// Minutes to wait after an attempt before the next one
+    #[schema(example = 1440)]
+    #[validate(range(min = 0))]
+    pub cooldown_minutes: i32,
+    /// Maximum number of attempts, unlimited if omitted
+    #[schema(example = 3)]
+    #[validate(range(min = 1))]
+    pub max_attempts: Option<i32>,
+    /// Which attempt counts: "first" or "best"
+    #[schema(example = "first")]
+    pub attempt_scoring: String,
+    /// Ignore the cooldown and attempt limit entirely
+    #[schema(example = false)]
+    pub unlimited_practice: bool,
 }
 
 /// Request body for creating a subject.
@@ -109,12 +153,105 @@ pub struct CreatePracticeTestRequest {
     #[validate(range(min = 1))]
     pub question_count: i32,
     #[schema(example = "ABCDABCDABCDABCDABCDABCDABCDABCDABCDABCD")]
-    #[validate(length(min = 1))]
-    pub answer_key: String,
+    pub answer_key: AnswerKeyRequest,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
     pub test_book_id: Uuid,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
     pub subject_id: Uuid,
+    /// Minutes allowed for an exam session; omit for an untimed test
+    #[schema(example = 40)]
+    #[validate(range(min = 1))]
+    pub time_limit_minutes: Option<i32>,
+    /// Curriculum topic the test covers
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub topic_id: Option<Uuid>,
+}
+
+/// Grading of a single question in a detailed answer key.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnswerKeyEntryRequest {
+    /// Any of the listed options is correct (e.g. both B and D)
+    Accepted {
+        #[schema(example = json!(["B", "D"]))]
+        options: Vec<char>,
+    },
+    /// Cancelled (iptal) question; everyone gets credit
+    Cancelled,
+    /// Any marked option is correct; empty answers stay empty
+    AnyAnswer,
+}
+
+/// Answer key as plain letters ("ABCD...", one per question) or one entry per question.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+#[serde(untagged)]
+pub enum AnswerKeyRequest {
+    Plain(String),
+    Entries(Vec<AnswerKeyEntryRequest>),
+}
+
+impl AnswerKeyRequest {
+    pub fn into_app_request(self) -> application::dto::AnswerKeyInput {
+        match self {
+            AnswerKeyRequest::Plain(raw) => application::dto::AnswerKeyInput::Plain(raw),
+            AnswerKeyRequest::Entries(entries) => application::dto::AnswerKeyInput::Entries(
+                entries
+                    .into_iter()
+                    .map(AnswerKeyEntryRequest::into_app_request)
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl AnswerKeyEntryRequest {
+    pub fn into_app_request(self) -> application::dto::AnswerKeyEntryDto {
+        match self {
+            AnswerKeyEntryRequest::Accepted { options } => {
+                application::dto::AnswerKeyEntryDto::Accepted { options }
+            }
+            AnswerKeyEntryRequest::Cancelled => application::dto::AnswerKeyEntryDto::Cancelled,
+            AnswerKeyEntryRequest::AnyAnswer => application::dto::AnswerKeyEntryDto::AnyAnswer,
+        }
+    }
+}
+
+/// A question of a practice test, see [`ReplaceQuestionsRequest`].
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct QuestionRequest {
+    /// Grading of the question; omit to keep the current answer key entry at this position
+    pub answer: Option<AnswerKeyEntryRequest>,
+    /// Curriculum topic (kazanım) the question measures
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub topic_id: Option<Uuid>,
+    /// `easy`, `medium` or `hard`
+    #[schema(example = "medium")]
+    pub difficulty: Option<String>,
+}
+
+/// Request body for replacing the questions of a practice test.
+///
+/// Questions are given in position order; the practice test's answer key and
+/// question count are derived from them.
+#[derive(Debug, Clone, Deserialize, ToSchema)]
+pub struct ReplaceQuestionsRequest {
+    pub questions: Vec<QuestionRequest>,
+}
+
+impl ReplaceQuestionsRequest {
+    pub fn into_app_request(self) -> application::dto::ReplaceQuestionsRequest {
+        application::dto::ReplaceQuestionsRequest {
+            questions: self
+                .questions
+                .into_iter()
+                .map(|q| application::dto::QuestionInput {
+                    answer: q.answer.map(AnswerKeyEntryRequest::into_app_request),
+                    topic_id: q.topic_id,
+                    difficulty: q.difficulty,
+                })
+                .collect(),
+        }
+    }
 }
 
 /// Request body for updating a practice test.
@@ -130,12 +267,21 @@ pub struct UpdatePracticeTestRequest {
     #[validate(range(min = 1))]
     pub question_count: Option<i32>,
     #[schema(example = "ABCDABCDABCDABCDABCDABCDABCDABCDABCDABCD")]
-    #[validate(length(min = 1))]
-    pub answer_key: Option<String>,
+    pub answer_key: Option<AnswerKeyRequest>,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
     pub test_book_id: Option<Uuid>,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
     pub subject_id: Option<Uuid>,
+    /// Minutes allowed for an exam session; 0 removes the time limit
+    #[schema(example = 40)]
+    #[validate(range(min = 0))]
+    pub time_limit_minutes: Option<i32>,
+    /// Curriculum topic the test covers
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub topic_id: Option<Uuid>,
+    /// Removes the curriculum topic; cannot be combined with `topic_id`
+    #[serde(default)]
+    pub clear_topic: bool,
 }
 
 /// Request body for solving a test.
@@ -146,6 +292,24 @@ pub struct SolveTestRequest {
     pub user_answers: String,
 }
 
+/// Request body for autosaving the answers of an exam session.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SaveExamSessionAnswersRequest {
+    /// One character per question, '_' for empty
+    #[schema(example = "AB__CD__________________________________")]
+    #[validate(length(min = 1))]
+    pub answers: String,
+}
+
+/// Request body for submitting an exam session.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct SubmitExamSessionRequest {
+    /// Final answers; the last autosaved answers are used if omitted
+    #[schema(example = "ABCD_BCDABCDABCDABCDABCDABCDABCDABCDABCD")]
+    #[validate(length(min = 1))]
+    pub answers: Option<String>,
+}
+
 // Conversion implementations
 impl CreateLessonRequest {
     pub fn into_app_request(self) -> application::dto::CreateLessonRequest {
@@ -164,6 +328,7 @@ impl CreateExamTypeRequest {
         application::dto::CreateExamTypeRequest {
             name: self.name,
             description: self.description,
+            reveal_answer_keys: self.reveal_answer_keys,
         }
     }
 }
@@ -173,6 +338,29 @@ impl UpdateExamTypeRequest {
         application::dto::UpdateExamTypeRequest {
             name: self.name,
             description: self.description,
+            reveal_answer_keys: self.reveal_answer_keys,
+        }
+    }
+}
+
+impl UpdateScoringPolicyRequest {
+    pub fn into_app_request(self) -> application::dto::UpdateScoringPolicyRequest {
+        application::dto::UpdateScoringPolicyRequest {
+            penalty_ratio: self.penalty_ratio,
+            question_weight: self.question_weight,
+            rounding_mode: self.rounding_mode,
+            decimal_places: self.decimal_places,
+        }
+    }
+}
+
+impl UpdateRetakePolicyRequest {
+    pub fn into_app_request(self) -> application::dto::UpdateRetakePolicyRequest {
+        application::dto::UpdateRetakePolicyRequest {
+            cooldown_minutes: self.cooldown_minutes,
+            max_attempts: self.max_attempts,
+            attempt_scoring: self.attempt_scoring,
+            unlimited_practice: self.unlimited_practice,
         }
     }
 }
@@ -227,9 +415,11 @@ impl CreatePracticeTestRequest {
             name: self.name,
             test_number: self.test_number,
             question_count: self.question_count,
-            answer_key: self.answer_key,
+            answer_key: self.answer_key.into_app_request(),
             test_book_id: self.test_book_id,
             subject_id: self.subject_id,
+            time_limit_minutes: self.time_limit_minutes,
+            topic_id: self.topic_id,
         }
     }
 }
@@ -240,9 +430,12 @@ impl UpdatePracticeTestRequest {
             name: self.name,
             test_number: self.test_number,
             question_count: self.question_count,
-            answer_key: self.answer_key,
+            answer_key: self.answer_key.map(AnswerKeyRequest::into_app_request),
             test_book_id: self.test_book_id,
             subject_id: self.subject_id,
+            time_limit_minutes: self.time_limit_minutes,
+            topic_id: self.topic_id,
+            clear_topic: self.clear_topic,
         }
     }
 }
@@ -255,3 +448,18 @@ impl SolveTestRequest {
     }
 }
 
+impl SaveExamSessionAnswersRequest {
+    pub fn into_app_request(self) -> application::dto::SaveExamSessionAnswersRequest {
+        application::dto::SaveExamSessionAnswersRequest {
+            answers: self.answers,
+        }
+    }
+}
+
+impl SubmitExamSessionRequest {
+    pub fn into_app_request(self) -> application::dto::SubmitExamSessionRequest {
+        application::dto::SubmitExamSessionRequest {
+            answers: self.answers,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+use validator::Validate;
+
+/// Request body for creating a curriculum topic.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct CreateTopicRequest {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub subject_id: Uuid,
+    /// Parent topic; omit for a root topic (unit) of the subject
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub parent_id: Option<Uuid>,
+    #[schema(example = "Üslü Sayılar")]
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+}
+
+/// Request body for renaming a curriculum topic.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct UpdateTopicRequest {
+    #[schema(example = "Üslü Sayılar")]
+    #[validate(length(min = 1, max = 255))]
+    pub name: String,
+}
+
+/// Request body for moving a topic within its subject's tree.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct MoveTopicRequest {
+    /// New parent topic; omit to move to the root level of the subject
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub parent_id: Option<Uuid>,
+    /// 1-based position among the new siblings; omit to place it last
+    #[schema(example = 1)]
+    #[validate(range(min = 1))]
+    pub position: Option<i32>,
+}
+
+/// Request body for reordering the children of a topic.
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+pub struct ReorderTopicsRequest {
+    /// Parent whose children are reordered; omit for the root level of the subject
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub parent_id: Option<Uuid>,
+    /// Every child topic, in the new order
+    #[validate(length(min = 1))]
+    pub topic_ids: Vec<Uuid>,
+}
+
+impl CreateTopicRequest {
+    pub fn into_app_request(self) -> application::dto::CreateTopicRequest {
+        application::dto::CreateTopicRequest {
+            subject_id: self.subject_id,
+            parent_id: self.parent_id,
+            name: self.name,
+        }
+    }
+}
+
+impl UpdateTopicRequest {
+    pub fn into_app_request(self) -> application::dto::UpdateTopicRequest {
+        application::dto::UpdateTopicRequest { name: self.name }
+    }
+}
+
+impl MoveTopicRequest {
+    pub fn into_app_request(self) -> application::dto::MoveTopicRequest {
+        application::dto::MoveTopicRequest {
+            parent_id: self.parent_id,
+            position: self.position,
+        }
+    }
+}
+
+impl ReorderTopicsRequest {
+    pub fn into_app_request(self) -> application::dto::ReorderTopicsRequest {
+        application::dto::ReorderTopicsRequest {
+            parent_id: self.parent_id,
+            topic_ids: self.topic_ids,
+        }
+    }
+}
@@ -12,12 +12,25 @@ pub struct AssignRoleRequest {
     pub role_id: Option<Uuid>,
 }
 
+/// Request to grant a permission to a role.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+pub struct GrantPermissionRequest {
+    /// Permission ID to grant
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    #[validate(required)]
+    pub permission_id: Option<Uuid>,
+}
+
 /// Request to create a new role.
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct CreateRoleRequest {
     /// Role name
     #[schema(example = "moderator")]
-    #[validate(length(min = 1, max = 50, message = "Role name must be between 1 and 50 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Role name must be between 1 and 50 characters"
+    ))]
     pub name: String,
     /// Optional description
     #[schema(example = "Moderator role with limited permissions")]
@@ -29,7 +42,11 @@ pub struct CreateRoleRequest {
 pub struct UpdateRoleRequest {
     /// Role name
     #[schema(example = "moderator")]
-    #[validate(length(min = 1, max = 50, message = "Role name must be between 1 and 50 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Role name must be between 1 and 50 characters"
+    ))]
     pub name: Option<String>,
     /// Optional description
     #[schema(example = "Moderator role with limited permissions")]
@@ -41,7 +58,11 @@ pub struct UpdateRoleRequest {
 pub struct UpdateUserRequest {
     /// Username
     #[schema(example = "john_doe")]
-    #[validate(length(min = 3, max = 50, message = "Username must be between 3 and 50 characters"))]
+    #[validate(length(
+        min = 3,
+        max = 50,
+        message = "Username must be between 3 and 50 characters"
+    ))]
     pub username: Option<String>,
     /// Email address
     #[schema(example = "john@example.com")]
@@ -50,5 +71,8 @@ pub struct UpdateUserRequest {
     /// Whether the account is active
     #[schema(example = true)]
     pub is_active: Option<bool>,
+    /// Student number printed on optical answer forms; an empty string removes it
+    #[schema(example = "20240117")]
+    #[validate(length(max = 50, message = "Student number must be at most 50 characters"))]
+    pub student_number: Option<String>,
 }
-
@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// An achievement (badge) students can earn.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AchievementResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "streak_7")]
+    pub code: String,
+    #[schema(example = "Week Streak")]
+    pub name: String,
+    #[schema(example = "Solve at least one test on 7 consecutive days")]
+    pub description: String,
+    /// solving_streak, tests_solved, test_books_completed or net_score
+    #[schema(example = "solving_streak")]
+    pub rule: String,
+    /// Value to reach, in the rule's unit
+    #[schema(example = 7.0)]
+    pub threshold: f64,
+    /// Exam type a net score achievement is limited to
+    pub exam_type_id: Option<Uuid>,
+    /// Lesson a net score achievement is limited to
+    pub lesson_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// An achievement with the current user's progress towards it.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AchievementProgressResponse {
+    pub achievement: AchievementResponse,
+    #[schema(example = false)]
+    pub earned: bool,
+    pub awarded_at: Option<DateTime<Utc>>,
+    /// Current value in the rule's unit; the running streak for streak achievements
+    #[schema(example = 4.0)]
+    pub current: f64,
+    #[schema(example = 7.0)]
+    pub target: f64,
+}
+
+// Conversion implementations
+
+impl From<application::dto::AchievementResponse> for AchievementResponse {
+    fn from(dto: application::dto::AchievementResponse) -> Self {
+        Self {
+            id: dto.id,
+            code: dto.code,
+            name: dto.name,
+            description: dto.description,
+            rule: dto.rule,
+            threshold: dto.threshold,
+            exam_type_id: dto.exam_type_id,
+            lesson_id: dto.lesson_id,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+impl From<application::dto::AchievementProgressResponse> for AchievementProgressResponse {
+    fn from(dto: application::dto::AchievementProgressResponse) -> Self {
+        Self {
+            achievement: dto.achievement.into(),
+            earned: dto.earned,
+            awarded_at: dto.awarded_at,
+            current: dto.current,
+            target: dto.target,
+        }
+    }
+}
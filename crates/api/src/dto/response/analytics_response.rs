@@ -0,0 +1,179 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Performance of a student in one lesson, subject or topic.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PerformanceStatResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "Üslü Sayılar")]
+    pub name: String,
+    #[schema(example = 4)]
+    pub attempt_count: i32,
+    #[schema(example = 22)]
+    pub correct_count: i32,
+    #[schema(example = 12)]
+    pub wrong_count: i32,
+    #[schema(example = 6)]
+    pub empty_count: i32,
+    /// Share of questions answered correctly, between 0 and 1
+    #[schema(example = 0.55)]
+    pub accuracy: f64,
+    /// Average net per attempt, null for topics
+    #[schema(example = 2.5)]
+    pub average_net: Option<f64>,
+    #[schema(example = "declining")]
+    pub trend: String,
+    /// Recent accuracy minus older accuracy, null with fewer than two attempts
+    #[schema(example = -0.1)]
+    pub trend_delta: Option<f64>,
+}
+
+/// How often a student missed one question of a practice test.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuestionErrorRateResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    #[schema(example = "Deneme 1")]
+    pub practice_test_name: String,
+    #[schema(example = 7)]
+    pub question_number: i32,
+    #[schema(example = 2)]
+    pub attempt_count: i32,
+    #[schema(example = 1)]
+    pub wrong_count: i32,
+    #[schema(example = 1)]
+    pub empty_count: i32,
+    /// Share of attempts answered wrong or left empty, between 0 and 1
+    #[schema(example = 1.0)]
+    pub error_rate: f64,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub topic_id: Option<Uuid>,
+}
+
+/// A student's weak areas, each list ordered weakest first.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct WeaknessReportResponse {
+    /// Number of test results analyzed
+    #[schema(example = 12)]
+    pub total_attempts: i32,
+    pub lessons: Vec<PerformanceStatResponse>,
+    pub subjects: Vec<PerformanceStatResponse>,
+    pub topics: Vec<PerformanceStatResponse>,
+    /// Questions with the highest error rates
+    pub missed_questions: Vec<QuestionErrorRateResponse>,
+}
+
+/// Aggregated results of one time bucket.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProgressPointResponse {
+    /// Start of the day, week (Monday) or month
+    pub bucket_start: DateTime<Utc>,
+    #[schema(example = 24.75)]
+    pub average_net: f64,
+    /// Number of tests solved in the bucket
+    #[schema(example = 3)]
+    pub test_count: i64,
+    #[schema(example = 31.5)]
+    pub best_net: f64,
+}
+
+/// Net score timeline of one lesson or exam type.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProgressSeriesResponse {
+    /// ID of the lesson or exam type
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "Matematik")]
+    pub name: String,
+    /// Buckets with at least one solved test, oldest first
+    pub points: Vec<ProgressPointResponse>,
+}
+
+/// A student's net scores over time.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ProgressTimelineResponse {
+    #[schema(example = "week")]
+    pub granularity: String,
+    #[schema(example = "lesson")]
+    pub group_by: String,
+    pub series: Vec<ProgressSeriesResponse>,
+}
+
+// Conversion implementations
+impl From<application::dto::PerformanceStatResponse> for PerformanceStatResponse {
+    fn from(dto: application::dto::PerformanceStatResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            attempt_count: dto.attempt_count,
+            correct_count: dto.correct_count,
+            wrong_count: dto.wrong_count,
+            empty_count: dto.empty_count,
+            accuracy: dto.accuracy,
+            average_net: dto.average_net,
+            trend: dto.trend,
+            trend_delta: dto.trend_delta,
+        }
+    }
+}
+
+impl From<application::dto::QuestionErrorRateResponse> for QuestionErrorRateResponse {
+    fn from(dto: application::dto::QuestionErrorRateResponse) -> Self {
+        Self {
+            practice_test_id: dto.practice_test_id,
+            practice_test_name: dto.practice_test_name,
+            question_number: dto.question_number,
+            attempt_count: dto.attempt_count,
+            wrong_count: dto.wrong_count,
+            empty_count: dto.empty_count,
+            error_rate: dto.error_rate,
+            topic_id: dto.topic_id,
+        }
+    }
+}
+
+impl From<application::dto::WeaknessReportResponse> for WeaknessReportResponse {
+    fn from(dto: application::dto::WeaknessReportResponse) -> Self {
+        Self {
+            total_attempts: dto.total_attempts,
+            lessons: dto.lessons.into_iter().map(|s| s.into()).collect(),
+            subjects: dto.subjects.into_iter().map(|s| s.into()).collect(),
+            topics: dto.topics.into_iter().map(|s| s.into()).collect(),
+            missed_questions: dto.missed_questions.into_iter().map(|q| q.into()).collect(),
+        }
+    }
+}
+
+impl From<application::dto::ProgressPointResponse> for ProgressPointResponse {
+    fn from(dto: application::dto::ProgressPointResponse) -> Self {
+        Self {
+            bucket_start: dto.bucket_start,
+            average_net: dto.average_net,
+            test_count: dto.test_count,
+            best_net: dto.best_net,
+        }
+    }
+}
+
+impl From<application::dto::ProgressSeriesResponse> for ProgressSeriesResponse {
+    fn from(dto: application::dto::ProgressSeriesResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            points: dto.points.into_iter().map(|p| p.into()).collect(),
+        }
+    }
+}
+
+impl From<application::dto::ProgressTimelineResponse> for ProgressTimelineResponse {
+    fn from(dto: application::dto::ProgressTimelineResponse) -> Self {
+        Self {
+            granularity: dto.granularity,
+            group_by: dto.group_by,
+            series: dto.series.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
@@ -103,6 +103,12 @@ pub struct UserResponse {
     /// Whether the account is active
     #[schema(example = true)]
     pub is_active: bool,
+    /// Student number printed on optical answer forms
+    #[schema(example = "20240117")]
+    pub student_number: Option<String>,
+    /// Whether the user is hidden from public leaderboards
+    #[schema(example = false)]
+    pub leaderboard_opt_out: bool,
     /// User's roles
     #[schema(example = json!(["user"]))]
     pub roles: Vec<String>,
@@ -187,10 +193,11 @@ impl From<application::dto::UserResponse> for UserResponse {
             username: resp.username,
             email: resp.email,
             is_active: resp.is_active,
+            student_number: resp.student_number,
+            leaderboard_opt_out: resp.leaderboard_opt_out,
             roles: resp.roles,
             created_at: resp.created_at,
             updated_at: resp.updated_at,
         }
     }
 }
-
@@ -0,0 +1,197 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A teacher's classroom.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ClassroomResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub teacher_id: Uuid,
+    #[schema(example = "12-A Sayısal")]
+    pub name: String,
+    /// Only shown to the classroom's teacher
+    #[schema(example = "K7MXQ2PA")]
+    pub invite_code: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A student enrolled in a classroom.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ClassroomStudentResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub student_id: Uuid,
+    #[schema(example = "ayse")]
+    pub username: String,
+    pub enrolled_at: DateTime<Utc>,
+}
+
+/// A classroom with its students.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ClassroomDetailResponse {
+    pub classroom: ClassroomResponse,
+    pub students: Vec<ClassroomStudentResponse>,
+}
+
+/// A practice test assigned to a classroom.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssignmentResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub classroom_id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub practice_test_id: Uuid,
+    #[schema(example = "Test 1")]
+    pub practice_test_name: String,
+    pub due_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    #[schema(example = 30)]
+    pub student_count: i64,
+    /// Students who solved the test, on time or late
+    #[schema(example = 24)]
+    pub submitted_count: i64,
+}
+
+/// A student's result for an assignment.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssignmentSubmissionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub student_id: Uuid,
+    #[schema(example = "ayse")]
+    pub username: String,
+    /// One of pending, completed, late or missing
+    #[schema(example = "completed")]
+    pub status: String,
+    /// First result solved after the assignment was given; null until submitted
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub test_result_id: Option<Uuid>,
+    #[schema(example = 34.25)]
+    pub net_score: Option<f64>,
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+/// Every student of a classroom with their result for an assignment.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AssignmentSubmissionsResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub assignment_id: Uuid,
+    pub due_at: DateTime<Utc>,
+    pub submissions: Vec<AssignmentSubmissionResponse>,
+}
+
+/// An assignment as seen by the student it was given to.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StudentAssignmentResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub classroom_id: Uuid,
+    #[schema(example = "12-A Sayısal")]
+    pub classroom_name: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub practice_test_id: Uuid,
+    #[schema(example = "Test 1")]
+    pub practice_test_name: String,
+    pub due_at: DateTime<Utc>,
+    /// One of pending, completed, late or missing
+    #[schema(example = "pending")]
+    pub status: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub test_result_id: Option<Uuid>,
+    #[schema(example = 34.25)]
+    pub net_score: Option<f64>,
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+// Conversion implementations
+
+impl From<application::dto::ClassroomResponse> for ClassroomResponse {
+    fn from(dto: application::dto::ClassroomResponse) -> Self {
+        Self {
+            id: dto.id,
+            teacher_id: dto.teacher_id,
+            name: dto.name,
+            invite_code: dto.invite_code,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+impl From<application::dto::ClassroomStudentResponse> for ClassroomStudentResponse {
+    fn from(dto: application::dto::ClassroomStudentResponse) -> Self {
+        Self {
+            student_id: dto.student_id,
+            username: dto.username,
+            enrolled_at: dto.enrolled_at,
+        }
+    }
+}
+
+impl From<application::dto::ClassroomDetailResponse> for ClassroomDetailResponse {
+    fn from(dto: application::dto::ClassroomDetailResponse) -> Self {
+        Self {
+            classroom: dto.classroom.into(),
+            students: dto.students.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+impl From<application::dto::AssignmentResponse> for AssignmentResponse {
+    fn from(dto: application::dto::AssignmentResponse) -> Self {
+        Self {
+            id: dto.id,
+            classroom_id: dto.classroom_id,
+            practice_test_id: dto.practice_test_id,
+            practice_test_name: dto.practice_test_name,
+            due_at: dto.due_at,
+            created_at: dto.created_at,
+            student_count: dto.student_count,
+            submitted_count: dto.submitted_count,
+        }
+    }
+}
+
+impl From<application::dto::AssignmentSubmissionResponse> for AssignmentSubmissionResponse {
+    fn from(dto: application::dto::AssignmentSubmissionResponse) -> Self {
+        Self {
+            student_id: dto.student_id,
+            username: dto.username,
+            status: dto.status,
+            test_result_id: dto.test_result_id,
+            net_score: dto.net_score,
+            solved_at: dto.solved_at,
+        }
+    }
+}
+
+impl From<application::dto::AssignmentSubmissionsResponse> for AssignmentSubmissionsResponse {
+    fn from(dto: application::dto::AssignmentSubmissionsResponse) -> Self {
+        Self {
+            assignment_id: dto.assignment_id,
+            due_at: dto.due_at,
+            submissions: dto.submissions.into_iter().map(|s| s.into()).collect(),
+        }
+    }
+}
+
+impl From<application::dto::StudentAssignmentResponse> for StudentAssignmentResponse {
+    fn from(dto: application::dto::StudentAssignmentResponse) -> Self {
+        Self {
+            id: dto.id,
+            classroom_id: dto.classroom_id,
+            classroom_name: dto.classroom_name,
+            practice_test_id: dto.practice_test_id,
+            practice_test_name: dto.practice_test_name,
+            due_at: dto.due_at,
+            status: dto.status,
+            test_result_id: dto.test_result_id,
+            net_score: dto.net_score,
+            solved_at: dto.solved_at,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A link between a guardian and a student.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GuardianLinkResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub guardian_id: Uuid,
+    #[schema(example = "veli")]
+    pub guardian_username: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub student_id: Uuid,
+    #[schema(example = "ayse")]
+    pub student_username: String,
+    /// pending until the student accepts, then accepted
+    #[schema(example = "accepted")]
+    pub status: String,
+    pub invited_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+// Conversion implementations
+
+impl From<application::dto::GuardianLinkResponse> for GuardianLinkResponse {
+    fn from(dto: application::dto::GuardianLinkResponse) -> Self {
+        Self {
+            id: dto.id,
+            guardian_id: dto.guardian_id,
+            guardian_username: dto.guardian_username,
+            student_id: dto.student_id,
+            student_username: dto.student_username,
+            status: dto.status,
+            invited_at: dto.invited_at,
+            accepted_at: dto.accepted_at,
+        }
+    }
+}
@@ -29,6 +29,8 @@ pub struct ReadinessResponse {
 pub struct HealthChecks {
     /// Database health
     pub database: HealthCheckResult,
+    /// Redis health (cache and rate limit store)
+    pub redis: HealthCheckResult,
 }
 
 /// Individual health check result.
@@ -104,4 +106,3 @@ impl HealthCheckResult {
         }
     }
 }
-
@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use super::PaginationInfo;
+
+/// A user's position on a leaderboard.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LeaderboardEntryResponse {
+    /// 1-based rank; users with equal scores share a rank
+    #[schema(example = 3)]
+    pub rank: i64,
+    #[schema(example = "john_doe")]
+    pub username: String,
+    #[schema(example = 32.5)]
+    pub score: f64,
+    /// Number of practice tests counted in the score
+    #[schema(example = 4)]
+    pub test_count: i64,
+    /// Whether the entry belongs to the requesting user
+    #[schema(example = false)]
+    pub is_me: bool,
+}
+
+/// One page of a leaderboard with the requesting user's own rank.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LeaderboardResponse {
+    pub items: Vec<LeaderboardEntryResponse>,
+    pub pagination: PaginationInfo,
+    /// The requesting user's entry, null if they have no score on this leaderboard
+    pub my_rank: Option<LeaderboardEntryResponse>,
+    /// Start of the ranked week, for weekly leaderboards
+    pub week_start: Option<DateTime<Utc>>,
+    /// End (exclusive) of the ranked week, for weekly leaderboards
+    pub week_end: Option<DateTime<Utc>>,
+}
+
+/// The user's leaderboard visibility preference.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LeaderboardPreferenceResponse {
+    #[schema(example = true)]
+    pub leaderboard_opt_out: bool,
+}
+
+impl LeaderboardResponse {
+    /// Builds the response for the requested page of a leaderboard.
+    pub fn from_app(dto: application::dto::LeaderboardResponse, page: u32, per_page: u32) -> Self {
+        Self {
+            items: dto.entries.into_iter().map(|e| e.into()).collect(),
+            pagination: PaginationInfo {
+                page,
+                per_page,
+                total_items: dto.total_entries,
+                total_pages: (dto.total_entries as f64 / per_page as f64).ceil() as u32,
+            },
+            my_rank: dto.my_entry.map(|e| e.into()),
+            week_start: dto.week_start,
+            week_end: dto.week_end,
+        }
+    }
+}
+
+// Conversion implementations
+impl From<application::dto::LeaderboardEntryResponse> for LeaderboardEntryResponse {
+    fn from(dto: application::dto::LeaderboardEntryResponse) -> Self {
+        Self {
+            rank: dto.rank,
+            username: dto.username,
+            score: dto.score,
+            test_count: dto.test_count,
+            is_me: dto.is_me,
+        }
+    }
+}
+
+impl From<application::dto::LeaderboardPreferenceResponse> for LeaderboardPreferenceResponse {
+    fn from(dto: application::dto::LeaderboardPreferenceResponse) -> Self {
+        Self {
+            leaderboard_opt_out: dto.leaderboard_opt_out,
+        }
+    }
+}
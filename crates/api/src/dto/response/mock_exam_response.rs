@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+use super::TestResultResponse;
+
+/// One practice test of a mock exam.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MockExamSectionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    #[schema(example = 1)]
+    pub position: i32,
+    #[schema(example = "Türkçe Deneme 1")]
+    pub name: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub subject_id: Uuid,
+    #[schema(example = 40)]
+    pub question_count: i32,
+}
+
+/// Response for a mock exam.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MockExamResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "TYT Deneme 1")]
+    pub name: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub exam_type_id: Uuid,
+    #[schema(example = 165)]
+    pub time_limit_minutes: i32,
+    /// Total number of questions across all sections
+    #[schema(example = 120)]
+    pub question_count: i32,
+    pub sections: Vec<MockExamSectionResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// One section of a mock exam attempt.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MockExamAttemptSectionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    #[schema(example = 1)]
+    pub position: i32,
+    #[schema(example = "AB__")]
+    pub answers: String,
+    /// Graded result of the section, set once the attempt is submitted
+    pub result: Option<TestResultResponse>,
+}
+
+/// Response for a mock exam attempt.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct MockExamAttemptResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub mock_exam_id: Uuid,
+    #[schema(example = "in_progress")]
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Seconds left while in progress
+    #[schema(example = 9900)]
+    pub remaining_seconds: Option<i64>,
+    pub last_saved_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    #[schema(example = 85)]
+    pub total_correct: Option<i32>,
+    #[schema(example = 20)]
+    pub total_wrong: Option<i32>,
+    #[schema(example = 15)]
+    pub total_empty: Option<i32>,
+    /// Sum of the section nets
+    #[schema(example = 80.0)]
+    pub total_net: Option<f64>,
+    pub sections: Vec<MockExamAttemptSectionResponse>,
+}
+
+// Conversion implementations
+impl From<application::dto::MockExamResponse> for MockExamResponse {
+    fn from(dto: application::dto::MockExamResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            exam_type_id: dto.exam_type_id,
+            time_limit_minutes: dto.time_limit_minutes,
+            question_count: dto.question_count,
+            sections: dto
+                .sections
+                .into_iter()
+                .map(|s| MockExamSectionResponse {
+                    practice_test_id: s.practice_test_id,
+                    position: s.position,
+                    name: s.name,
+                    subject_id: s.subject_id,
+                    question_count: s.question_count,
+                })
+                .collect(),
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+impl From<application::dto::MockExamAttemptResponse> for MockExamAttemptResponse {
+    fn from(dto: application::dto::MockExamAttemptResponse) -> Self {
+        Self {
+            id: dto.id,
+            mock_exam_id: dto.mock_exam_id,
+            status: dto.status,
+            started_at: dto.started_at,
+            expires_at: dto.expires_at,
+            remaining_seconds: dto.remaining_seconds,
+            last_saved_at: dto.last_saved_at,
+            submitted_at: dto.submitted_at,
+            total_correct: dto.total_correct,
+            total_wrong: dto.total_wrong,
+            total_empty: dto.total_empty,
+            total_net: dto.total_net,
+            sections: dto
+                .sections
+                .into_iter()
+                .map(|s| MockExamAttemptSectionResponse {
+                    practice_test_id: s.practice_test_id,
+                    position: s.position,
+                    answers: s.answers,
+                    result: s.result.map(Into::into),
+                })
+                .collect(),
+        }
+    }
+}
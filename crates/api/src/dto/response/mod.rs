@@ -1,10 +1,31 @@
+mod achievement_response;
+mod analytics_response;
 mod auth_response;
+mod classroom_response;
+mod guardian_response;
 mod health_response;
+mod leaderboard_response;
+mod mock_exam_response;
+mod recommendation_response;
+mod result_import_response;
 mod role_response;
+mod score_response;
+mod study_plan_response;
 mod test_response;
+mod topic_response;
 
+pub use achievement_response::*;
+pub use analytics_response::*;
 pub use auth_response::*;
+pub use classroom_response::*;
+pub use guardian_response::*;
 pub use health_response::*;
+pub use leaderboard_response::*;
+pub use mock_exam_response::*;
+pub use recommendation_response::*;
+pub use result_import_response::*;
 pub use role_response::*;
+pub use score_response::*;
+pub use study_plan_response::*;
 pub use test_response::*;
-
+pub use topic_response::*;
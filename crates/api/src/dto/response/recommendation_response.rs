@@ -0,0 +1,57 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// A practice test proposed to a student to solve next.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RecommendationResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    #[schema(example = "Deneme 3")]
+    pub practice_test_name: String,
+    #[schema(example = 3)]
+    pub test_number: i32,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub test_book_id: Uuid,
+    #[schema(example = "TYT Matematik Soru Bankası")]
+    pub test_book_name: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub subject_id: Uuid,
+    /// Why the test is recommended: spaced_review, weak_subject or continue_book
+    #[schema(example = "spaced_review")]
+    pub reason: String,
+    /// Higher is more urgent
+    #[schema(example = 2.4)]
+    pub priority: f64,
+    /// Share of questions answered correctly in the latest attempt, null if never solved
+    #[schema(example = 0.45)]
+    pub last_accuracy: Option<f64>,
+    pub last_solved_at: Option<DateTime<Utc>>,
+    /// When the spaced-repetition review became due
+    pub review_due_at: Option<DateTime<Utc>>,
+    /// Share of questions answered correctly in the test's subject, null if never practiced
+    #[schema(example = 0.52)]
+    pub subject_accuracy: Option<f64>,
+}
+
+// Conversion implementations
+
+impl From<application::dto::RecommendationResponse> for RecommendationResponse {
+    fn from(dto: application::dto::RecommendationResponse) -> Self {
+        Self {
+            practice_test_id: dto.practice_test_id,
+            practice_test_name: dto.practice_test_name,
+            test_number: dto.test_number,
+            test_book_id: dto.test_book_id,
+            test_book_name: dto.test_book_name,
+            subject_id: dto.subject_id,
+            reason: dto.reason,
+            priority: dto.priority,
+            last_accuracy: dto.last_accuracy,
+            last_solved_at: dto.last_solved_at,
+            review_due_at: dto.review_due_at,
+            subject_accuracy: dto.subject_accuracy,
+        }
+    }
+}
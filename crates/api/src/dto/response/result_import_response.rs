@@ -0,0 +1,63 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Outcome of one row of an OMR import.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OmrImportRowResponse {
+    /// 1-based line of the row in the imported file
+    #[schema(example = 2)]
+    pub line_number: usize,
+    #[schema(example = "2024001")]
+    pub student_number: Option<String>,
+    #[schema(example = "imported")]
+    pub status: String,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub user_id: Option<Uuid>,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub result_id: Option<Uuid>,
+    #[schema(example = 32.75)]
+    pub net_score: Option<f64>,
+    /// Why the row was not imported
+    #[schema(example = "No user with this student number")]
+    pub error: Option<String>,
+}
+
+/// Per-row report of an OMR import.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct OmrImportReportResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub practice_test_id: Uuid,
+    #[schema(example = 30)]
+    pub total_rows: usize,
+    #[schema(example = 28)]
+    pub imported_count: usize,
+    #[schema(example = 2)]
+    pub failed_count: usize,
+    pub rows: Vec<OmrImportRowResponse>,
+}
+
+// Conversion implementations
+impl From<application::dto::OmrImportReportResponse> for OmrImportReportResponse {
+    fn from(dto: application::dto::OmrImportReportResponse) -> Self {
+        Self {
+            practice_test_id: dto.practice_test_id,
+            total_rows: dto.total_rows,
+            imported_count: dto.imported_count,
+            failed_count: dto.failed_count,
+            rows: dto
+                .rows
+                .into_iter()
+                .map(|r| OmrImportRowResponse {
+                    line_number: r.line_number,
+                    student_number: r.student_number,
+                    status: r.status,
+                    user_id: r.user_id,
+                    result_id: r.result_id,
+                    net_score: r.net_score,
+                    error: r.error,
+                })
+                .collect(),
+        }
+    }
+}
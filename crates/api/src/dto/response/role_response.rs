@@ -30,3 +30,25 @@ impl From<domain::entities::Role> for RoleResponse {
     }
 }
 
+/// Permission response DTO.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PermissionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "tests:write")]
+    pub name: String,
+    #[schema(example = "Create, update and delete tests and their content")]
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<domain::entities::Permission> for PermissionResponse {
+    fn from(permission: domain::entities::Permission) -> Self {
+        Self {
+            id: permission.id,
+            name: permission.name,
+            description: permission.description,
+            created_at: permission.created_at,
+        }
+    }
+}
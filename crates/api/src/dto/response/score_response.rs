@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Coefficient of a single lesson in a score table.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LessonCoefficientResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub lesson_id: Uuid,
+    #[schema(example = 3.3)]
+    pub coefficient: f64,
+}
+
+/// Response for a yearly score table.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ScoreTableResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub exam_type_id: Uuid,
+    #[schema(example = 2024)]
+    pub year: i32,
+    #[schema(example = 100.0)]
+    pub base_score: f64,
+    #[schema(example = 0.12)]
+    pub diploma_coefficient: f64,
+    pub coefficients: Vec<LessonCoefficientResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Contribution of one lesson to an estimated score.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct LessonScoreResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub lesson_id: Uuid,
+    #[schema(example = "Matematik")]
+    pub lesson_name: String,
+    #[schema(example = 32.5)]
+    pub net: f64,
+    #[schema(example = 3.3)]
+    pub coefficient: f64,
+    #[schema(example = 107.25)]
+    pub points: f64,
+}
+
+/// Response for an estimated standard score.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ScoreEstimateResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub exam_type_id: Uuid,
+    #[schema(example = 2024)]
+    pub year: i32,
+    #[schema(example = 100.0)]
+    pub base_score: f64,
+    pub lessons: Vec<LessonScoreResponse>,
+    /// Base score plus weighted nets (ham puan)
+    #[schema(example = 207.25)]
+    pub raw_score: f64,
+    /// Diploma grade scaled to 250-500 (OBP)
+    #[schema(example = 425.0)]
+    pub diploma_score: Option<f64>,
+    /// Raw score plus weighted diploma score (yerleştirme puanı)
+    #[schema(example = 258.25)]
+    pub placement_score: Option<f64>,
+}
+
+// Conversion implementations
+impl From<application::dto::ScoreTableResponse> for ScoreTableResponse {
+    fn from(dto: application::dto::ScoreTableResponse) -> Self {
+        Self {
+            id: dto.id,
+            exam_type_id: dto.exam_type_id,
+            year: dto.year,
+            base_score: dto.base_score,
+            diploma_coefficient: dto.diploma_coefficient,
+            coefficients: dto
+                .coefficients
+                .into_iter()
+                .map(|c| LessonCoefficientResponse {
+                    lesson_id: c.lesson_id,
+                    coefficient: c.coefficient,
+                })
+                .collect(),
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+impl From<application::dto::ScoreEstimateResponse> for ScoreEstimateResponse {
+    fn from(dto: application::dto::ScoreEstimateResponse) -> Self {
+        Self {
+            exam_type_id: dto.exam_type_id,
+            year: dto.year,
+            base_score: dto.base_score,
+            lessons: dto
+                .lessons
+                .into_iter()
+                .map(|l| LessonScoreResponse {
+                    lesson_id: l.lesson_id,
+                    lesson_name: l.lesson_name,
+                    net: l.net,
+                    coefficient: l.coefficient,
+                    points: l.points,
+                })
+                .collect(),
+            raw_score: dto.raw_score,
+            diploma_score: dto.diploma_score,
+            placement_score: dto.placement_score,
+        }
+    }
+}
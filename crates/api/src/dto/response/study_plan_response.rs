@@ -0,0 +1,158 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// The exam a student prepares for.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StudyPlanResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub exam_type_id: Uuid,
+    #[schema(example = "TYT")]
+    pub exam_type_name: String,
+    #[schema(example = "2025-06-21")]
+    pub target_date: NaiveDate,
+    /// Negative once the exam date has passed
+    #[schema(example = 120)]
+    pub days_until_exam: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A daily study goal.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StudyGoalResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "questions")]
+    pub metric: String,
+    #[schema(example = 40)]
+    pub daily_target: i32,
+    /// Lesson the goal is limited to, null for all lessons
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub lesson_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    /// Null while the goal is active
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+/// Progress towards one goal during a calendar entry.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct GoalProgressResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub goal_id: Uuid,
+    /// Tests or questions solved
+    #[schema(example = 95)]
+    pub achieved: i64,
+    /// Daily target times the days the goal applied
+    #[schema(example = 120)]
+    pub target: i64,
+    #[schema(example = 2)]
+    pub met_days: u32,
+    #[schema(example = 3)]
+    pub active_days: u32,
+}
+
+/// Goal completion during one day or week.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StudyCalendarEntryResponse {
+    #[schema(example = "2024-03-11")]
+    pub start: NaiveDate,
+    #[schema(example = "2024-03-17")]
+    pub end: NaiveDate,
+    /// Every goal was met on every day it applied
+    #[schema(example = false)]
+    pub completed: bool,
+    #[schema(example = 5)]
+    pub completed_days: u32,
+    /// Days on which at least one goal applied
+    #[schema(example = 7)]
+    pub active_days: u32,
+    pub goals: Vec<GoalProgressResponse>,
+}
+
+/// Daily or weekly goal completion calendar with streaks.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StudyCalendarResponse {
+    #[schema(example = "week")]
+    pub view: String,
+    #[schema(example = "2024-02-26")]
+    pub from: NaiveDate,
+    #[schema(example = "2024-03-17")]
+    pub to: NaiveDate,
+    /// Consecutive completed days up to today; today counts once completed
+    #[schema(example = 4)]
+    pub current_streak: u32,
+    #[schema(example = 12)]
+    pub longest_streak: u32,
+    pub entries: Vec<StudyCalendarEntryResponse>,
+}
+
+// Conversion implementations
+
+impl From<application::dto::StudyPlanResponse> for StudyPlanResponse {
+    fn from(dto: application::dto::StudyPlanResponse) -> Self {
+        Self {
+            id: dto.id,
+            exam_type_id: dto.exam_type_id,
+            exam_type_name: dto.exam_type_name,
+            target_date: dto.target_date,
+            days_until_exam: dto.days_until_exam,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+impl From<application::dto::StudyGoalResponse> for StudyGoalResponse {
+    fn from(dto: application::dto::StudyGoalResponse) -> Self {
+        Self {
+            id: dto.id,
+            metric: dto.metric,
+            daily_target: dto.daily_target,
+            lesson_id: dto.lesson_id,
+            created_at: dto.created_at,
+            archived_at: dto.archived_at,
+        }
+    }
+}
+
+impl From<application::dto::GoalProgressResponse> for GoalProgressResponse {
+    fn from(dto: application::dto::GoalProgressResponse) -> Self {
+        Self {
+            goal_id: dto.goal_id,
+            achieved: dto.achieved,
+            target: dto.target,
+            met_days: dto.met_days,
+            active_days: dto.active_days,
+        }
+    }
+}
+
+impl From<application::dto::StudyCalendarEntryResponse> for StudyCalendarEntryResponse {
+    fn from(dto: application::dto::StudyCalendarEntryResponse) -> Self {
+        Self {
+            start: dto.start,
+            end: dto.end,
+            completed: dto.completed,
+            completed_days: dto.completed_days,
+            active_days: dto.active_days,
+            goals: dto.goals.into_iter().map(|g| g.into()).collect(),
+        }
+    }
+}
+
+impl From<application::dto::StudyCalendarResponse> for StudyCalendarResponse {
+    fn from(dto: application::dto::StudyCalendarResponse) -> Self {
+        Self {
+            view: dto.view,
+            from: dto.from,
+            to: dto.to,
+            current_streak: dto.current_streak,
+            longest_streak: dto.longest_streak,
+            entries: dto.entries.into_iter().map(|e| e.into()).collect(),
+        }
+    }
+}
@@ -3,6 +3,8 @@ use serde::Serialize;
 use utoipa::ToSchema;
 use uuid::Uuid;
 
+use super::AchievementResponse;
+
 /// Response for lesson.
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct LessonResponse {
@@ -22,9 +24,54 @@ pub struct ExamTypeResponse {
     pub name: String,
     #[schema(example = "Temel Yeterlilik Testi")]
     pub description: Option<String>,
+    /// Whether students see answer keys before submitting a test
+    #[schema(example = false)]
+    pub reveal_answer_keys: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Response for a scoring policy version.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ScoringPolicyResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub exam_type_id: Uuid,
+    #[schema(example = 1)]
+    pub version: i32,
+    #[schema(example = 0.25)]
+    pub penalty_ratio: f64,
+    #[schema(example = 1.0)]
+    pub question_weight: f64,
+    #[schema(example = "none")]
+    pub rounding_mode: String,
+    #[schema(example = 2)]
+    pub decimal_places: i32,
     pub created_at: DateTime<Utc>,
 }
 
+/// Response for the retake policy of an exam type or test book.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct RetakePolicyResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    /// Set for exam type policies
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub exam_type_id: Option<Uuid>,
+    /// Set for test book policies, which take precedence over exam type policies
+    pub test_book_id: Option<Uuid>,
+    #[schema(example = 1440)]
+    pub cooldown_minutes: i32,
+    #[schema(example = 3)]
+    pub max_attempts: Option<i32>,
+    #[schema(example = "first")]
+    pub attempt_scoring: String,
+    #[schema(example = false)]
+    pub unlimited_practice: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 /// Response for subject.
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct SubjectResponse {
@@ -69,13 +116,97 @@ pub struct PracticeTestResponse {
     pub question_count: i32,
     #[schema(example = "ABCDABCDABCDABCDABCDABCDABCDABCDABCDABCD")]
     pub answer_key: String,
+    #[schema(example = 1)]
+    pub answer_key_revision: i32,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub test_book_id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub subject_id: Uuid,
+    /// Minutes allowed for an exam session, null if untimed
+    #[schema(example = 40)]
+    pub time_limit_minutes: Option<i32>,
+    /// Curriculum topic the test covers
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub topic_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Student-facing response for a practice test.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct StudentPracticeTestResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "Deneme 1")]
+    pub name: String,
+    #[schema(example = 1)]
+    pub test_number: i32,
+    #[schema(example = 40)]
+    pub question_count: i32,
+    /// Null until the test has been submitted, unless the exam type reveals answer keys
+    #[schema(example = "ABCDABCDABCDABCDABCDABCDABCDABCDABCDABCD")]
+    pub answer_key: Option<String>,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
     pub test_book_id: Uuid,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
     pub subject_id: Uuid,
+    /// Minutes allowed for an exam session, null if untimed
+    #[schema(example = 40)]
+    pub time_limit_minutes: Option<i32>,
+    /// Curriculum topic the test covers
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub topic_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
 }
 
+/// Grading of a single question.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnswerKeyEntryResponse {
+    /// Any of the listed options is correct
+    Accepted {
+        #[schema(example = json!(["B"]))]
+        options: Vec<char>,
+    },
+    /// Cancelled (iptal) question; everyone gets credit
+    Cancelled,
+    /// Any marked option is correct
+    AnyAnswer,
+}
+
+/// Response for a question of a practice test.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuestionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub practice_test_id: Uuid,
+    #[schema(example = 1)]
+    pub position: i32,
+    pub answer: AnswerKeyEntryResponse,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub topic_id: Option<Uuid>,
+    #[schema(example = "medium")]
+    pub difficulty: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Response for an answer key revision of a practice test.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AnswerKeyRevisionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub practice_test_id: Uuid,
+    #[schema(example = 2)]
+    pub revision: i32,
+    #[schema(example = "ABCDABCDABCDABCDABCDABCDABCDABCDABCDABCD")]
+    pub answer_key: String,
+    pub created_at: DateTime<Utc>,
+    /// When the results of this revision were re-graded; null while pending
+    pub regraded_at: Option<DateTime<Utc>>,
+}
+
 /// Response for test result.
 #[derive(Debug, Clone, Serialize, ToSchema)]
 pub struct TestResultResponse {
@@ -95,7 +226,88 @@ pub struct TestResultResponse {
     pub empty_count: i32,
     #[schema(example = 34.25)]
     pub net_score: f64,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub scoring_policy_id: Uuid,
+    #[schema(example = 1)]
+    pub scoring_policy_version: i32,
+    #[schema(example = 1)]
+    pub answer_key_revision: i32,
+    /// Set when a corrected answer key changed this result's score
+    pub score_changed_at: Option<DateTime<Utc>>,
+    /// Submission time
     pub solved_at: DateTime<Utc>,
+    /// Start of the exam session; null for results submitted without a session
+    pub started_at: Option<DateTime<Utc>>,
+    /// Seconds spent in the exam session
+    #[schema(example = 2280)]
+    pub elapsed_seconds: Option<i32>,
+}
+
+/// Response for a single graded question of a test result.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct QuestionResultResponse {
+    #[schema(example = 17)]
+    pub question_number: i32,
+    /// Answer given by the user; null if left empty
+    #[schema(value_type = Option<String>, example = "C")]
+    pub given_answer: Option<char>,
+    /// Accepted options; empty for cancelled and any-answer questions
+    #[schema(value_type = Vec<String>, example = json!(["B", "D"]))]
+    pub correct_answers: Vec<char>,
+    /// One of "accepted", "cancelled" or "any_answer"
+    #[schema(example = "accepted")]
+    pub key_type: String,
+    /// One of "correct", "wrong" or "empty"
+    #[schema(example = "wrong")]
+    pub outcome: String,
+}
+
+/// Response for a test result with its per-question breakdown.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TestResultDetailResponse {
+    #[serde(flatten)]
+    pub result: TestResultResponse,
+    pub questions: Vec<QuestionResultResponse>,
+    /// True for a mock exam section of a practice test whose answer key is still hidden
+    /// from the student; its per-question breakdown is left out
+    pub answer_key_hidden: bool,
+    /// Comparison with everyone's first attempt, null if nobody solved the test yet
+    pub cohort: Option<CohortStatisticsResponse>,
+}
+
+/// Comparison of a result with the first attempts of everyone who solved the practice test.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CohortStatisticsResponse {
+    /// Number of users who solved the practice test
+    #[schema(example = 128)]
+    pub first_attempt_count: i64,
+    #[schema(example = 21.4)]
+    pub average_net: f64,
+    #[schema(example = 22.25)]
+    pub median_net: f64,
+    /// Share of first attempts with a lower net, ties counting half, between 0 and 100
+    #[schema(example = 73.5)]
+    pub percentile: f64,
+}
+
+/// Response for a previous score of a re-graded test result.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TestResultHistoryResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub test_result_id: Uuid,
+    #[schema(example = 1)]
+    pub answer_key_revision: i32,
+    #[schema(example = 34)]
+    pub correct_count: i32,
+    #[schema(example = 4)]
+    pub wrong_count: i32,
+    #[schema(example = 2)]
+    pub empty_count: i32,
+    #[schema(example = 33.0)]
+    pub net_score: f64,
+    pub replaced_at: DateTime<Utc>,
 }
 
 /// Response for solving a test.
@@ -106,6 +318,13 @@ pub struct SolveTestResponse {
     pub can_retake: bool,
     #[schema(example = 0.0)]
     pub hours_until_retake: Option<f64>,
+    /// Attempts left under the retake policy, null if unlimited
+    #[schema(example = 2)]
+    pub attempts_remaining: Option<i32>,
+    /// Comparison with everyone's first attempt
+    pub cohort: Option<CohortStatisticsResponse>,
+    /// Achievements first earned with this result
+    pub new_achievements: Vec<AchievementResponse>,
 }
 
 // Conversion implementations
@@ -125,11 +344,43 @@ impl From<application::dto::ExamTypeResponse> for ExamTypeResponse {
             id: dto.id,
             name: dto.name,
             description: dto.description,
+            reveal_answer_keys: dto.reveal_answer_keys,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+impl From<application::dto::ScoringPolicyResponse> for ScoringPolicyResponse {
+    fn from(dto: application::dto::ScoringPolicyResponse) -> Self {
+        Self {
+            id: dto.id,
+            exam_type_id: dto.exam_type_id,
+            version: dto.version,
+            penalty_ratio: dto.penalty_ratio,
+            question_weight: dto.question_weight,
+            rounding_mode: dto.rounding_mode,
+            decimal_places: dto.decimal_places,
             created_at: dto.created_at,
         }
     }
 }
 
+impl From<application::dto::RetakePolicyResponse> for RetakePolicyResponse {
+    fn from(dto: application::dto::RetakePolicyResponse) -> Self {
+        Self {
+            id: dto.id,
+            exam_type_id: dto.exam_type_id,
+            test_book_id: dto.test_book_id,
+            cooldown_minutes: dto.cooldown_minutes,
+            max_attempts: dto.max_attempts,
+            attempt_scoring: dto.attempt_scoring,
+            unlimited_practice: dto.unlimited_practice,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
 impl From<application::dto::SubjectResponse> for SubjectResponse {
     fn from(dto: application::dto::SubjectResponse) -> Self {
         Self {
@@ -158,6 +409,24 @@ impl From<application::dto::TestBookResponse> for TestBookResponse {
 
 impl From<application::dto::PracticeTestResponse> for PracticeTestResponse {
     fn from(dto: application::dto::PracticeTestResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            test_number: dto.test_number,
+            question_count: dto.question_count,
+            answer_key: dto.answer_key,
+            answer_key_revision: dto.answer_key_revision,
+            test_book_id: dto.test_book_id,
+            subject_id: dto.subject_id,
+            time_limit_minutes: dto.time_limit_minutes,
+            topic_id: dto.topic_id,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+impl From<application::dto::StudentPracticeTestResponse> for StudentPracticeTestResponse {
+    fn from(dto: application::dto::StudentPracticeTestResponse) -> Self {
         Self {
             id: dto.id,
             name: dto.name,
@@ -166,7 +435,43 @@ impl From<application::dto::PracticeTestResponse> for PracticeTestResponse {
             answer_key: dto.answer_key,
             test_book_id: dto.test_book_id,
             subject_id: dto.subject_id,
+            time_limit_minutes: dto.time_limit_minutes,
+            topic_id: dto.topic_id,
+            created_at: dto.created_at,
+        }
+    }
+}
+
+impl From<application::dto::QuestionResponse> for QuestionResponse {
+    fn from(dto: application::dto::QuestionResponse) -> Self {
+        Self {
+            id: dto.id,
+            practice_test_id: dto.practice_test_id,
+            position: dto.position,
+            answer: match dto.answer {
+                application::dto::AnswerKeyEntryDto::Accepted { options } => {
+                    AnswerKeyEntryResponse::Accepted { options }
+                }
+                application::dto::AnswerKeyEntryDto::Cancelled => AnswerKeyEntryResponse::Cancelled,
+                application::dto::AnswerKeyEntryDto::AnyAnswer => AnswerKeyEntryResponse::AnyAnswer,
+            },
+            topic_id: dto.topic_id,
+            difficulty: dto.difficulty,
             created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+impl From<application::dto::AnswerKeyRevisionResponse> for AnswerKeyRevisionResponse {
+    fn from(dto: application::dto::AnswerKeyRevisionResponse) -> Self {
+        Self {
+            id: dto.id,
+            practice_test_id: dto.practice_test_id,
+            revision: dto.revision,
+            answer_key: dto.answer_key,
+            created_at: dto.created_at,
+            regraded_at: dto.regraded_at,
         }
     }
 }
@@ -182,7 +487,62 @@ impl From<application::dto::TestResultResponse> for TestResultResponse {
             wrong_count: dto.wrong_count,
             empty_count: dto.empty_count,
             net_score: dto.net_score,
+            scoring_policy_id: dto.scoring_policy_id,
+            scoring_policy_version: dto.scoring_policy_version,
+            answer_key_revision: dto.answer_key_revision,
+            score_changed_at: dto.score_changed_at,
             solved_at: dto.solved_at,
+            started_at: dto.started_at,
+            elapsed_seconds: dto.elapsed_seconds,
+        }
+    }
+}
+
+impl From<application::dto::QuestionResultResponse> for QuestionResultResponse {
+    fn from(dto: application::dto::QuestionResultResponse) -> Self {
+        Self {
+            question_number: dto.question_number,
+            given_answer: dto.given_answer,
+            correct_answers: dto.correct_answers,
+            key_type: dto.key_type,
+            outcome: dto.outcome,
+        }
+    }
+}
+
+impl From<application::dto::TestResultDetailResponse> for TestResultDetailResponse {
+    fn from(dto: application::dto::TestResultDetailResponse) -> Self {
+        Self {
+            result: dto.result.into(),
+            questions: dto.questions.into_iter().map(|q| q.into()).collect(),
+            answer_key_hidden: dto.answer_key_hidden,
+            cohort: dto.cohort.map(|c| c.into()),
+        }
+    }
+}
+
+impl From<application::dto::CohortStatisticsResponse> for CohortStatisticsResponse {
+    fn from(dto: application::dto::CohortStatisticsResponse) -> Self {
+        Self {
+            first_attempt_count: dto.first_attempt_count,
+            average_net: dto.average_net,
+            median_net: dto.median_net,
+            percentile: dto.percentile,
+        }
+    }
+}
+
+impl From<application::dto::TestResultHistoryResponse> for TestResultHistoryResponse {
+    fn from(dto: application::dto::TestResultHistoryResponse) -> Self {
+        Self {
+            id: dto.id,
+            test_result_id: dto.test_result_id,
+            answer_key_revision: dto.answer_key_revision,
+            correct_count: dto.correct_count,
+            wrong_count: dto.wrong_count,
+            empty_count: dto.empty_count,
+            net_score: dto.net_score,
+            replaced_at: dto.replaced_at,
         }
     }
 }
@@ -193,6 +553,51 @@ impl From<application::dto::SolveTestResponse> for SolveTestResponse {
             result: dto.result.into(),
             can_retake: dto.can_retake,
             hours_until_retake: dto.hours_until_retake,
+            attempts_remaining: dto.attempts_remaining,
+            cohort: dto.cohort.map(|c| c.into()),
+            new_achievements: dto.new_achievements.into_iter().map(|a| a.into()).collect(),
+        }
+    }
+}
+
+/// Response for an exam session.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ExamSessionResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub practice_test_id: Uuid,
+    /// Answers saved so far, '_' for empty
+    #[schema(example = "AB__CD__________________________________")]
+    pub answers: String,
+    /// One of "in_progress", "submitted" or "expired" (auto-submitted)
+    #[schema(example = "in_progress")]
+    pub status: String,
+    pub started_at: DateTime<Utc>,
+    /// Null if the test is untimed
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Seconds left while in progress; null if untimed or finished
+    #[schema(example = 1800)]
+    pub remaining_seconds: Option<i64>,
+    pub last_saved_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// Result created on submission
+    pub test_result_id: Option<Uuid>,
+}
+
+impl From<application::dto::ExamSessionResponse> for ExamSessionResponse {
+    fn from(dto: application::dto::ExamSessionResponse) -> Self {
+        Self {
+            id: dto.id,
+            practice_test_id: dto.practice_test_id,
+            answers: dto.answers,
+            status: dto.status,
+            started_at: dto.started_at,
+            expires_at: dto.expires_at,
+            remaining_seconds: dto.remaining_seconds,
+            last_saved_at: dto.last_saved_at,
+            submitted_at: dto.submitted_at,
+            test_result_id: dto.test_result_id,
         }
     }
 }
@@ -231,19 +636,34 @@ pub struct PracticeTestWithStatusResponse {
     pub test_number: i32,
     #[schema(example = 40)]
     pub question_count: i32,
+    /// Null until the test has been submitted, unless the exam type reveals answer keys
     #[schema(example = "ABCDABCDABCDABCDABCDABCDABCDABCDABCDABCD")]
-    pub answer_key: String,
+    pub answer_key: Option<String>,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
     pub test_book_id: Uuid,
     #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
     pub subject_id: Uuid,
+    /// Minutes allowed for an exam session, null if untimed
+    #[schema(example = 40)]
+    pub time_limit_minutes: Option<i32>,
+    /// Curriculum topic the test covers
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440003")]
+    pub topic_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
+    /// One of "available", "solved", "waiting" or "locked"
     #[schema(example = "available")]
     pub status: String,
     pub last_solved_at: Option<DateTime<Utc>>,
     pub result_id: Option<Uuid>,
     #[schema(example = 15.5)]
     pub hours_until_retake: Option<f64>,
+    #[schema(example = 1)]
+    pub attempts_used: i32,
+    /// Null if attempts are unlimited
+    #[schema(example = 2)]
+    pub attempts_remaining: Option<i32>,
+    /// The attempt that counts under the retake policy (first or best)
+    pub counted_result_id: Option<Uuid>,
 }
 
 impl From<application::dto::TestBookWithStatsResponse> for TestBookWithStatsResponse {
@@ -273,12 +693,16 @@ impl From<application::dto::PracticeTestWithStatusResponse> for PracticeTestWith
             answer_key: dto.answer_key,
             test_book_id: dto.test_book_id,
             subject_id: dto.subject_id,
+            time_limit_minutes: dto.time_limit_minutes,
+            topic_id: dto.topic_id,
             created_at: dto.created_at,
             status: dto.status,
             last_solved_at: dto.last_solved_at,
             result_id: dto.result_id,
             hours_until_retake: dto.hours_until_retake,
+            attempts_used: dto.attempts_used,
+            attempts_remaining: dto.attempts_remaining,
+            counted_result_id: dto.counted_result_id,
         }
     }
 }
-
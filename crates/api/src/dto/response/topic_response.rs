@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+use uuid::Uuid;
+
+/// Response for a curriculum topic.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct TopicResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440001")]
+    pub subject_id: Uuid,
+    /// Parent topic, null for a root topic of the subject
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440002")]
+    pub parent_id: Option<Uuid>,
+    #[schema(example = "Üslü Sayılar")]
+    pub name: String,
+    /// 1-based position among its siblings
+    #[schema(example = 1)]
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A topic with its child topics, ordered by position.
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(no_recursion)]
+pub struct TopicTreeNodeResponse {
+    #[schema(example = "550e8400-e29b-41d4-a716-446655440000")]
+    pub id: Uuid,
+    #[schema(example = "Üslü Sayılar")]
+    pub name: String,
+    #[schema(example = 1)]
+    pub position: i32,
+    pub children: Vec<TopicTreeNodeResponse>,
+}
+
+// Conversion implementations
+impl From<application::dto::TopicResponse> for TopicResponse {
+    fn from(dto: application::dto::TopicResponse) -> Self {
+        Self {
+            id: dto.id,
+            subject_id: dto.subject_id,
+            parent_id: dto.parent_id,
+            name: dto.name,
+            position: dto.position,
+            created_at: dto.created_at,
+            updated_at: dto.updated_at,
+        }
+    }
+}
+
+impl From<application::dto::TopicTreeNodeResponse> for TopicTreeNodeResponse {
+    fn from(dto: application::dto::TopicTreeNodeResponse) -> Self {
+        Self {
+            id: dto.id,
+            name: dto.name,
+            position: dto.position,
+            children: dto.children.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -7,7 +7,10 @@ use serde::Serialize;
 use thiserror::Error;
 
 use application::services::{
-    AuthError, ResultError, TestManagementError, TestSolvingError,
+    AchievementError, AnalyticsError, AssignmentError, AuthError, ClassroomError, GuardianError,
+    LeaderboardError, MockExamError, RecommendationError, ResultError, ResultImportError,
+    RetakePolicyError, ScoreCalculationError, StudyPlanError, TestManagementError,
+    TestSolvingError, TopicError,
 };
 use domain::errors::DomainError;
 use infrastructure::security::JwtError;
@@ -61,6 +64,13 @@ pub enum AppError {
     #[error("Cannot retake test yet")]
     CannotRetakeYet,
 
+    #[error("Maximum number of attempts reached")]
+    MaxAttemptsReached,
+
+    // Rate limiting errors
+    #[error("Too many requests")]
+    TooManyRequests,
+
     // Server errors
     #[error("Internal server error")]
     InternalServerError,
@@ -103,6 +113,8 @@ impl AppError {
             AppError::NotFound(_) => "NOT_FOUND",
             AppError::Conflict(_) => "CONFLICT",
             AppError::CannotRetakeYet => "CANNOT_RETAKE_YET",
+            AppError::MaxAttemptsReached => "MAX_ATTEMPTS_REACHED",
+            AppError::TooManyRequests => "TOO_MANY_REQUESTS",
             AppError::InternalServerError => "INTERNAL_ERROR",
             AppError::ServiceUnavailable => "SERVICE_UNAVAILABLE",
         }
@@ -124,7 +136,8 @@ impl AppError {
             AppError::ValidationError(_) => StatusCode::BAD_REQUEST,
             AppError::NotFound(_) => StatusCode::NOT_FOUND,
             AppError::Conflict(_) => StatusCode::CONFLICT,
-            AppError::CannotRetakeYet => StatusCode::FORBIDDEN,
+            AppError::CannotRetakeYet | AppError::MaxAttemptsReached => StatusCode::FORBIDDEN,
+            AppError::TooManyRequests => StatusCode::TOO_MANY_REQUESTS,
             AppError::InternalServerError => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::ServiceUnavailable => StatusCode::SERVICE_UNAVAILABLE,
         }
@@ -180,27 +193,176 @@ impl From<JwtError> for AppError {
 impl From<TestManagementError> for AppError {
     fn from(err: TestManagementError) -> Self {
         match err {
-            TestManagementError::LessonNotFound => AppError::NotFound("Lesson not found".to_string()),
-            TestManagementError::ExamTypeNotFound => AppError::NotFound("Exam type not found".to_string()),
-            TestManagementError::SubjectNotFound => AppError::NotFound("Subject not found".to_string()),
-            TestManagementError::TestBookNotFound => AppError::NotFound("Test book not found".to_string()),
-            TestManagementError::PracticeTestNotFound => AppError::NotFound("Practice test not found".to_string()),
-            TestManagementError::DuplicateLessonName => AppError::Conflict("Lesson name already exists".to_string()),
-            TestManagementError::DuplicateExamTypeName => AppError::Conflict("Exam type name already exists".to_string()),
-            TestManagementError::DuplicateSubjectName => AppError::Conflict("Subject name already exists for this exam type".to_string()),
-            TestManagementError::DuplicateTestNumber => AppError::Conflict("A test with this name and number already exists for this test book and subject".to_string()),
+            TestManagementError::LessonNotFound => {
+                AppError::NotFound("Lesson not found".to_string())
+            }
+            TestManagementError::ExamTypeNotFound => {
+                AppError::NotFound("Exam type not found".to_string())
+            }
+            TestManagementError::SubjectNotFound => {
+                AppError::NotFound("Subject not found".to_string())
+            }
+            TestManagementError::TestBookNotFound => {
+                AppError::NotFound("Test book not found".to_string())
+            }
+            TestManagementError::PracticeTestNotFound => {
+                AppError::NotFound("Practice test not found".to_string())
+            }
+            TestManagementError::TopicNotFound => AppError::NotFound("Topic not found".to_string()),
+            TestManagementError::DuplicateLessonName => {
+                AppError::Conflict("Lesson name already exists".to_string())
+            }
+            TestManagementError::DuplicateExamTypeName => {
+                AppError::Conflict("Exam type name already exists".to_string())
+            }
+            TestManagementError::DuplicateSubjectName => {
+                AppError::Conflict("Subject name already exists for this exam type".to_string())
+            }
+            TestManagementError::DuplicateTestNumber => AppError::Conflict(
+                "A test with this name and number already exists for this test book and subject"
+                    .to_string(),
+            ),
+            TestManagementError::ScoringPolicyNotFound => {
+                AppError::NotFound("Scoring policy not found".to_string())
+            }
+            TestManagementError::InvalidRoundingMode(mode) => {
+                AppError::ValidationError(format!("Invalid rounding mode: {}", mode))
+            }
+            TestManagementError::InvalidAnswerKey(msg) => {
+                AppError::ValidationError(format!("Invalid answer key: {}", msg))
+            }
+            TestManagementError::InvalidQuestion(msg) => {
+                AppError::ValidationError(format!("Invalid question: {}", msg))
+            }
+            TestManagementError::InvalidTopic(msg) => {
+                AppError::ValidationError(format!("Invalid topic: {}", msg))
+            }
             TestManagementError::InternalError(_) => AppError::InternalServerError,
         }
     }
 }
 
+impl From<TopicError> for AppError {
+    fn from(err: TopicError) -> Self {
+        match err {
+            TopicError::TopicNotFound => AppError::NotFound("Topic not found".to_string()),
+            TopicError::SubjectNotFound => AppError::NotFound("Subject not found".to_string()),
+            TopicError::ParentTopicNotFound => {
+                AppError::NotFound("Parent topic not found".to_string())
+            }
+            TopicError::InvalidPlacement(msg) => AppError::ValidationError(msg),
+            TopicError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<AnalyticsError> for AppError {
+    fn from(err: AnalyticsError) -> Self {
+        match err {
+            AnalyticsError::InvalidGranularity(value) => {
+                AppError::ValidationError(format!("Invalid granularity: {}", value))
+            }
+            AnalyticsError::InvalidGrouping(value) => {
+                AppError::ValidationError(format!("Invalid grouping: {}", value))
+            }
+            AnalyticsError::InvalidDateRange => {
+                AppError::ValidationError("from must be before to".to_string())
+            }
+            AnalyticsError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<LeaderboardError> for AppError {
+    fn from(err: LeaderboardError) -> Self {
+        match err {
+            LeaderboardError::PracticeTestNotFound => {
+                AppError::NotFound("Practice test not found".to_string())
+            }
+            LeaderboardError::TestBookNotFound => {
+                AppError::NotFound("Test book not found".to_string())
+            }
+            LeaderboardError::ExamTypeNotFound => {
+                AppError::NotFound("Exam type not found".to_string())
+            }
+            LeaderboardError::UserNotFound => AppError::NotFound("User not found".to_string()),
+            LeaderboardError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<RecommendationError> for AppError {
+    fn from(err: RecommendationError) -> Self {
+        match err {
+            RecommendationError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<StudyPlanError> for AppError {
+    fn from(err: StudyPlanError) -> Self {
+        match err {
+            StudyPlanError::PlanNotFound => AppError::NotFound("Study plan not found".to_string()),
+            StudyPlanError::GoalNotFound => AppError::NotFound("Study goal not found".to_string()),
+            StudyPlanError::ExamTypeNotFound => {
+                AppError::NotFound("Exam type not found".to_string())
+            }
+            StudyPlanError::LessonNotFound => AppError::NotFound("Lesson not found".to_string()),
+            StudyPlanError::TargetDateInPast => {
+                AppError::ValidationError("Target date must not be in the past".to_string())
+            }
+            StudyPlanError::InvalidMetric(value) => {
+                AppError::ValidationError(format!("Invalid goal metric: {}", value))
+            }
+            StudyPlanError::InvalidView(value) => {
+                AppError::ValidationError(format!("Invalid calendar view: {}", value))
+            }
+            StudyPlanError::InvalidDateRange(msg) => {
+                AppError::ValidationError(format!("Invalid date range: {}", msg))
+            }
+            StudyPlanError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<AchievementError> for AppError {
+    fn from(err: AchievementError) -> Self {
+        match err {
+            AchievementError::AchievementNotFound => {
+                AppError::NotFound("Achievement not found".to_string())
+            }
+            AchievementError::CodeAlreadyExists => {
+                AppError::Conflict("Achievement code already exists".to_string())
+            }
+            AchievementError::InvalidRule(value) => {
+                AppError::ValidationError(format!("Invalid achievement rule: {}", value))
+            }
+            AchievementError::InvalidRuleScope => AppError::ValidationError(
+                "Only net score achievements can be limited to an exam type or lesson".to_string(),
+            ),
+            AchievementError::ExamTypeNotFound => {
+                AppError::NotFound("Exam type not found".to_string())
+            }
+            AchievementError::LessonNotFound => AppError::NotFound("Lesson not found".to_string()),
+            AchievementError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
 impl From<TestSolvingError> for AppError {
     fn from(err: TestSolvingError) -> Self {
         match err {
             TestSolvingError::PracticeTestNotFound => AppError::NotFound("Practice test not found".to_string()),
+            TestSolvingError::TestBookNotFound => AppError::NotFound("Test book not found".to_string()),
             TestSolvingError::CannotRetakeYet => AppError::CannotRetakeYet,
+            TestSolvingError::MaxAttemptsReached => AppError::MaxAttemptsReached,
             TestSolvingError::AnswerKeyLengthMismatch => AppError::ValidationError("Answer key length mismatch".to_string()),
             TestSolvingError::UserAnswersLengthMismatch => AppError::ValidationError("User answers length mismatch".to_string()),
+            TestSolvingError::ScoringPolicyNotFound => AppError::NotFound("No scoring policy configured for this exam type".to_string()),
+            TestSolvingError::TimedTestRequiresSession => AppError::ValidationError("Timed tests must be solved in an exam session".to_string()),
+            TestSolvingError::ExamSessionNotFound => AppError::NotFound("Exam session not found".to_string()),
+            TestSolvingError::ExamSessionClosed => AppError::Conflict("Exam session is no longer in progress".to_string()),
+            TestSolvingError::InvalidStoredAnswerKey => AppError::Conflict("The answer key of this practice test is invalid and must be corrected by an administrator".to_string()),
             TestSolvingError::InternalError(_) => AppError::InternalServerError,
         }
     }
@@ -210,20 +372,119 @@ impl From<ResultError> for AppError {
     fn from(err: ResultError) -> Self {
         match err {
             ResultError::TestResultNotFound => AppError::NotFound("Test result not found".to_string()),
+            ResultError::InvalidStoredAnswerKey => AppError::Conflict("The answer key of this practice test is invalid and must be corrected by an administrator".to_string()),
             ResultError::InternalError(_) => AppError::InternalServerError,
         }
     }
 }
 
+impl From<RetakePolicyError> for AppError {
+    fn from(err: RetakePolicyError) -> Self {
+        match err {
+            RetakePolicyError::ExamTypeNotFound => {
+                AppError::NotFound("Exam type not found".to_string())
+            }
+            RetakePolicyError::TestBookNotFound => {
+                AppError::NotFound("Test book not found".to_string())
+            }
+            RetakePolicyError::RetakePolicyNotFound => {
+                AppError::NotFound("Retake policy not found".to_string())
+            }
+            RetakePolicyError::InvalidAttemptScoring(scoring) => {
+                AppError::ValidationError(format!("Invalid attempt scoring: {}", scoring))
+            }
+            RetakePolicyError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<MockExamError> for AppError {
+    fn from(err: MockExamError) -> Self {
+        match err {
+            MockExamError::MockExamNotFound => AppError::NotFound("Mock exam not found".to_string()),
+            MockExamError::ExamTypeNotFound => AppError::NotFound("Exam type not found".to_string()),
+            MockExamError::PracticeTestNotFound => AppError::NotFound("Practice test not found".to_string()),
+            MockExamError::InvalidMockExam(message) => AppError::ValidationError(message),
+            MockExamError::UserAnswersLengthMismatch => AppError::ValidationError("User answers length mismatch".to_string()),
+            MockExamError::ScoringPolicyNotFound => AppError::NotFound("No scoring policy configured for this exam type".to_string()),
+            MockExamError::AttemptNotFound => AppError::NotFound("Mock exam attempt not found".to_string()),
+            MockExamError::AttemptClosed => AppError::Conflict("Mock exam attempt is no longer in progress".to_string()),
+            MockExamError::CannotRetakeYet => AppError::CannotRetakeYet,
+            MockExamError::MaxAttemptsReached => AppError::MaxAttemptsReached,
+            MockExamError::InvalidStoredAnswerKey => AppError::Conflict("The answer key of this practice test is invalid and must be corrected by an administrator".to_string()),
+            MockExamError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<ResultImportError> for AppError {
+    fn from(err: ResultImportError) -> Self {
+        match err {
+            ResultImportError::PracticeTestNotFound => AppError::NotFound("Practice test not found".to_string()),
+            ResultImportError::InvalidFormat(message) => AppError::ValidationError(message),
+            ResultImportError::ScoringPolicyNotFound => AppError::NotFound("No scoring policy configured for this exam type".to_string()),
+            ResultImportError::InvalidStoredAnswerKey => AppError::Conflict("The answer key of this practice test is invalid and must be corrected by an administrator".to_string()),
+            ResultImportError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<ScoreCalculationError> for AppError {
+    fn from(err: ScoreCalculationError) -> Self {
+        match err {
+            ScoreCalculationError::ExamTypeNotFound => {
+                AppError::NotFound("Exam type not found".to_string())
+            }
+            ScoreCalculationError::ScoreTableNotFound => {
+                AppError::NotFound("Score table not found".to_string())
+            }
+            ScoreCalculationError::LessonNotFound => {
+                AppError::NotFound("Lesson not found".to_string())
+            }
+            ScoreCalculationError::SubjectNotFound => {
+                AppError::NotFound("Subject not found".to_string())
+            }
+            ScoreCalculationError::TestResultNotFound => {
+                AppError::NotFound("Test result not found".to_string())
+            }
+            ScoreCalculationError::ScoringPolicyNotFound => {
+                AppError::NotFound("No scoring policy configured for this exam type".to_string())
+            }
+            ScoreCalculationError::NetOutOfRange => AppError::ValidationError(
+                "Subject net is out of range for its question count".to_string(),
+            ),
+            ScoreCalculationError::ExamTypeMismatch => AppError::ValidationError(
+                "Result or subject does not belong to the requested exam type".to_string(),
+            ),
+            ScoreCalculationError::NoNetsProvided => AppError::ValidationError(
+                "Either result IDs or subject nets are required".to_string(),
+            ),
+            ScoreCalculationError::DuplicateResultId => {
+                AppError::ValidationError("Each test result can only be counted once".to_string())
+            }
+            ScoreCalculationError::DuplicateLessonCoefficient => {
+                AppError::ValidationError("Each lesson can only have one coefficient".to_string())
+            }
+            ScoreCalculationError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
 impl From<DomainError> for AppError {
     fn from(err: DomainError) -> Self {
         match err {
-            DomainError::UserNotFound(_) | DomainError::UserNotFoundByEmail(_) | DomainError::UserNotFoundByUsername(_) => {
+            DomainError::UserNotFound(_)
+            | DomainError::UserNotFoundByEmail(_)
+            | DomainError::UserNotFoundByUsername(_) => {
                 AppError::NotFound("User not found".to_string())
             }
-            DomainError::RefreshTokenNotFound => AppError::NotFound("Refresh token not found".to_string()),
+            DomainError::RefreshTokenNotFound => {
+                AppError::NotFound("Refresh token not found".to_string())
+            }
             DomainError::RoleNotFound(_) => AppError::NotFound("Role not found".to_string()),
-            DomainError::PermissionNotFound(_) => AppError::NotFound("Permission not found".to_string()),
+            DomainError::PermissionNotFound(_) => {
+                AppError::NotFound("Permission not found".to_string())
+            }
             DomainError::DuplicateEmail(_) | DomainError::DuplicateUsername(_) => {
                 AppError::Conflict("Resource already exists".to_string())
             }
@@ -234,9 +495,9 @@ impl From<DomainError> for AppError {
             DomainError::RefreshTokenRevoked => AppError::RefreshTokenRevoked,
             DomainError::InsufficientPermissions => AppError::Forbidden,
             DomainError::ValidationError(msg) => AppError::ValidationError(msg),
-            DomainError::DatabaseError(_) | DomainError::CacheError(_) | DomainError::InternalError(_) => {
-                AppError::InternalServerError
-            }
+            DomainError::DatabaseError(_)
+            | DomainError::CacheError(_)
+            | DomainError::InternalError(_) => AppError::InternalServerError,
             _ => AppError::InternalServerError,
         }
     }
@@ -247,3 +508,63 @@ pub fn validation_error(errors: Vec<String>) -> AppError {
     AppError::ValidationError(errors.join(", "))
 }
 
+impl From<ClassroomError> for AppError {
+    fn from(err: ClassroomError) -> Self {
+        match err {
+            ClassroomError::ClassroomNotFound => {
+                AppError::NotFound("Classroom not found".to_string())
+            }
+            ClassroomError::StudentNotFound => {
+                AppError::NotFound("Student not found in classroom".to_string())
+            }
+            ClassroomError::InvalidInviteCode => {
+                AppError::NotFound("Invalid invite code".to_string())
+            }
+            ClassroomError::AlreadyEnrolled => {
+                AppError::Conflict("Already enrolled in this classroom".to_string())
+            }
+            ClassroomError::OwnClassroom => {
+                AppError::ValidationError("Teachers cannot join their own classroom".to_string())
+            }
+            ClassroomError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<AssignmentError> for AppError {
+    fn from(err: AssignmentError) -> Self {
+        match err {
+            AssignmentError::ClassroomNotFound => {
+                AppError::NotFound("Classroom not found".to_string())
+            }
+            AssignmentError::AssignmentNotFound => {
+                AppError::NotFound("Assignment not found".to_string())
+            }
+            AssignmentError::PracticeTestNotFound => {
+                AppError::NotFound("Practice test not found".to_string())
+            }
+            AssignmentError::DueDateInPast => {
+                AppError::ValidationError("Due date must be in the future".to_string())
+            }
+            AssignmentError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
+
+impl From<GuardianError> for AppError {
+    fn from(err: GuardianError) -> Self {
+        match err {
+            GuardianError::StudentNotFound => AppError::NotFound("Student not found".to_string()),
+            GuardianError::LinkNotFound => {
+                AppError::NotFound("Guardian link not found".to_string())
+            }
+            GuardianError::SelfLink => {
+                AppError::ValidationError("Cannot link an account to itself".to_string())
+            }
+            GuardianError::AlreadyLinked => {
+                AppError::Conflict("Student is already linked or invited".to_string())
+            }
+            GuardianError::InternalError(_) => AppError::InternalServerError,
+        }
+    }
+}
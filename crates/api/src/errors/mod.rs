@@ -1,4 +1,3 @@
 mod app_error;
 
-pub use app_error::{AppError, ErrorDetail, ErrorResponse};
-
+pub use app_error::{validation_error, AppError, ErrorDetail, ErrorResponse};
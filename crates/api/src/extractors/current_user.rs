@@ -39,6 +39,16 @@ impl CurrentUser {
     pub fn is_admin(&self) -> bool {
         self.has_role("admin") || self.has_role("super_admin")
     }
+
+    /// Checks if the user can follow linked students (a guardian or an admin).
+    pub fn is_guardian(&self) -> bool {
+        self.has_role("guardian") || self.is_admin()
+    }
+
+    /// Checks if the user can manage classrooms (a teacher or an admin).
+    pub fn is_teacher(&self) -> bool {
+        self.has_role("teacher") || self.is_admin()
+    }
 }
 
 impl FromRequestParts<AppState> for CurrentUser {
@@ -94,4 +104,3 @@ impl FromRequestParts<AppState> for OptionalCurrentUser {
         }
     }
 }
-
@@ -1,6 +1,12 @@
 mod current_user;
-mod require_admin;
-
-pub use current_user::CurrentUser;
-pub use require_admin::RequireAdmin;
+mod require_guardian;
+mod require_permission;
+mod require_teacher;
 
+pub use current_user::{CurrentUser, OptionalCurrentUser};
+pub use require_guardian::RequireGuardian;
+pub use require_permission::{
+    AchievementsRead, AchievementsWrite, PermissionName, RequirePermission, ResultsImport,
+    RolesRead, RolesWrite, TestsRead, TestsWrite, UsersRead, UsersWrite,
+};
+pub use require_teacher::RequireTeacher;
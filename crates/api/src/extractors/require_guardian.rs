@@ -0,0 +1,27 @@
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+
+use crate::errors::AppError;
+use crate::extractors::CurrentUser;
+use crate::state::AppState;
+
+/// Extractor that requires the current user to be a guardian or an admin.
+/// Returns 403 Forbidden otherwise.
+pub struct RequireGuardian(pub CurrentUser);
+
+impl FromRequestParts<AppState> for RequireGuardian {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let current_user = CurrentUser::from_request_parts(parts, state).await?;
+
+        if !current_user.is_guardian() {
+            return Err(AppError::Forbidden);
+        }
+
+        Ok(RequireGuardian(current_user))
+    }
+}
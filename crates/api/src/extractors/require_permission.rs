@@ -0,0 +1,116 @@
+use std::marker::PhantomData;
+
+use axum::extract::FromRequestParts;
+use axum::http::request::Parts;
+
+use crate::errors::AppError;
+use crate::extractors::CurrentUser;
+use crate::state::AppState;
+
+/// A permission a handler can require, named like the rows of the `permissions` table.
+pub trait PermissionName {
+    const NAME: &'static str;
+}
+
+macro_rules! permissions {
+    ($($(#[$doc:meta])* $marker:ident => $name:literal;)*) => {
+        $(
+            $(#[$doc])*
+            pub struct $marker;
+
+            impl PermissionName for $marker {
+                const NAME: &'static str = $name;
+            }
+        )*
+    };
+}
+
+permissions! {
+    /// `tests:read` - view test content and its policies
+    TestsRead => "tests:read";
+    /// `tests:write` - manage test content, policies, score tables and mock exams
+    TestsWrite => "tests:write";
+    /// `results:import` - import optical form results
+    ResultsImport => "results:import";
+    /// `achievements:read` - view achievement definitions
+    AchievementsRead => "achievements:read";
+    /// `achievements:write` - create and delete achievements
+    AchievementsWrite => "achievements:write";
+    /// `users:read` - view user accounts
+    UsersRead => "users:read";
+    /// `users:write` - update, delete and restore user accounts
+    UsersWrite => "users:write";
+    /// `roles:read` - view roles and permissions
+    RolesRead => "roles:read";
+    /// `roles:write` - manage roles, role assignments and role permissions
+    RolesWrite => "roles:write";
+}
+
+/// Extractor that requires the access token to carry the permission `P`,
+/// e.g. `RequirePermission<TestsWrite>` for "tests:write".
+/// Returns 403 Forbidden if none of the user's roles grants it.
+pub struct RequirePermission<P: PermissionName>(pub CurrentUser, PhantomData<fn() -> P>);
+
+impl<P: PermissionName> RequirePermission<P> {
+    /// Accepts `current_user` if the access token carries the permission `P`.
+    /// Roles alone grant nothing, not even `admin`.
+    fn authorize(current_user: CurrentUser) -> Result<Self, AppError> {
+        if !current_user.has_permission(P::NAME) {
+            return Err(AppError::Forbidden);
+        }
+
+        Ok(RequirePermission(current_user, PhantomData))
+    }
+}
+
+impl<P: PermissionName> FromRequestParts<AppState> for RequirePermission<P> {
+    type Rejection = AppError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let current_user = CurrentUser::from_request_parts(parts, state).await?;
+
+        Self::authorize(current_user)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    fn user(roles: &[&str], permissions: &[&str]) -> CurrentUser {
+        CurrentUser {
+            id: Uuid::new_v4(),
+            roles: roles.iter().map(|role| role.to_string()).collect(),
+            permissions: permissions.iter().map(|name| name.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_accepts_user_with_permission() {
+        let current_user = user(&["teacher"], &["tests:read", "roles:write"]);
+
+        let RequirePermission(accepted, _) =
+            RequirePermission::<RolesWrite>::authorize(current_user.clone()).unwrap();
+
+        assert_eq!(accepted.id, current_user.id);
+    }
+
+    #[test]
+    fn test_rejects_user_without_permission() {
+        let result = RequirePermission::<RolesWrite>::authorize(user(&["user"], &["roles:read"]));
+
+        assert!(matches!(result, Err(AppError::Forbidden)));
+    }
+
+    #[test]
+    fn test_rejects_admin_role_without_permission() {
+        let result =
+            RequirePermission::<TestsWrite>::authorize(user(&["admin", "super_admin"], &[]));
+
+        assert!(matches!(result, Err(AppError::Forbidden)));
+    }
+}
@@ -5,11 +5,11 @@ use crate::errors::AppError;
 use crate::extractors::CurrentUser;
 use crate::state::AppState;
 
-/// Extractor that requires the current user to be an admin.
-/// Returns 403 Forbidden if the user is not an admin.
-pub struct RequireAdmin(pub CurrentUser);
+/// Extractor that requires the current user to be a teacher or an admin.
+/// Returns 403 Forbidden otherwise.
+pub struct RequireTeacher(pub CurrentUser);
 
-impl FromRequestParts<AppState> for RequireAdmin {
+impl FromRequestParts<AppState> for RequireTeacher {
     type Rejection = AppError;
 
     async fn from_request_parts(
@@ -18,11 +18,10 @@ impl FromRequestParts<AppState> for RequireAdmin {
     ) -> Result<Self, Self::Rejection> {
         let current_user = CurrentUser::from_request_parts(parts, state).await?;
 
-        if !current_user.is_admin() {
+        if !current_user.is_teacher() {
             return Err(AppError::Forbidden);
         }
 
-        Ok(RequireAdmin(current_user))
+        Ok(RequireTeacher(current_user))
     }
 }
-
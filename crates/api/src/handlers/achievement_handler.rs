@@ -0,0 +1,161 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::CreateAchievementRequest;
+use crate::dto::response::{
+    AchievementProgressResponse, AchievementResponse, ApiResponse, MessageResponse,
+};
+use crate::errors::AppError;
+use crate::extractors::{AchievementsRead, AchievementsWrite, CurrentUser, RequirePermission};
+use crate::state::AppState;
+
+// Achievement Handlers
+
+/// List every achievement with the current user's progress towards it
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/achievements",
+    responses(
+        (status = 200, description = "Achievements retrieved", body = ApiResponse<Vec<AchievementProgressResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn list_my_achievements(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<Vec<AchievementProgressResponse>>>, AppError> {
+    let achievements = state
+        .achievement_service
+        .list_my_achievements(user.id)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to list achievements: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        achievements.into_iter().map(|a| a.into()).collect(),
+    )))
+}
+
+/// List all achievements (requires achievements:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/achievements",
+    responses(
+        (status = 200, description = "Achievements retrieved", body = ApiResponse<Vec<AchievementResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - achievements:read permission required"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_achievements(
+    State(state): State<AppState>,
+    _admin: RequirePermission<AchievementsRead>,
+) -> Result<Json<ApiResponse<Vec<AchievementResponse>>>, AppError> {
+    let achievements = state
+        .achievement_service
+        .list_achievements()
+        .await
+        .map_err(|e| {
+            error!("Failed to list achievements: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        achievements.into_iter().map(|a| a.into()).collect(),
+    )))
+}
+
+/// Create an achievement (requires achievements:write)
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/achievements",
+    request_body = CreateAchievementRequest,
+    responses(
+        (status = 201, description = "Achievement created successfully", body = ApiResponse<AchievementResponse>),
+        (status = 400, description = "Validation error or invalid rule"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - achievements:write permission required"),
+        (status = 404, description = "Exam type or lesson not found"),
+        (status = 409, description = "Achievement code already exists"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn create_achievement(
+    State(state): State<AppState>,
+    _admin: RequirePermission<AchievementsWrite>,
+    Json(request): Json<CreateAchievementRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<AchievementResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .achievement_service
+        .create_achievement(request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!("Failed to create achievement: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Achievement created successfully",
+        )),
+    ))
+}
+
+/// Delete an achievement and every award of it (requires achievements:write)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/achievements/{id}",
+    params(("id" = Uuid, Path, description = "Achievement ID")),
+    responses(
+        (status = 200, description = "Achievement deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - achievements:write permission required"),
+        (status = 404, description = "Achievement not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_achievement(
+    State(state): State<AppState>,
+    _admin: RequirePermission<AchievementsWrite>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .achievement_service
+        .delete_achievement(id)
+        .await
+        .map_err(|e| {
+            error!(achievement_id = ?id, "Failed to delete achievement: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Achievement deleted successfully",
+    ))))
+}
@@ -0,0 +1,126 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tracing::error;
+
+use application::dto::ProgressTimelineRequest;
+
+use crate::dto::response::{ApiResponse, ProgressTimelineResponse, WeaknessReportResponse};
+use crate::errors::AppError;
+use crate::extractors::CurrentUser;
+use crate::state::AppState;
+
+// Analytics Handlers
+
+/// Get the current user's weakest lessons, subjects, topics and questions
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/analytics/weaknesses",
+    params(
+        ("limit" = Option<u32>, Query, description = "Maximum entries per list", example = 10)
+    ),
+    responses(
+        (status = 200, description = "Weakness report retrieved", body = ApiResponse<WeaknessReportResponse>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_my_weaknesses(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<WeaknessReportResponse>>, AppError> {
+    let limit = params
+        .get("limit")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(10)
+        .min(50);
+
+    let report = state
+        .analytics_service
+        .get_weaknesses(user.id, limit)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to get weakness report: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(report.into())))
+}
+
+/// Get the current user's net scores over time per lesson or exam type
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/analytics/progress",
+    params(
+        ("granularity" = Option<String>, Query, description = "Bucket size: day, week or month, in Turkey time", example = "week"),
+        ("group_by" = Option<String>, Query, description = "Series per lesson or exam_type", example = "lesson"),
+        ("from" = Option<DateTime<Utc>>, Query, description = "Only results solved at or after this time (RFC 3339)"),
+        ("to" = Option<DateTime<Utc>>, Query, description = "Only results solved before this time (RFC 3339)")
+    ),
+    responses(
+        (status = 200, description = "Progress timeline retrieved", body = ApiResponse<ProgressTimelineResponse>),
+        (status = 400, description = "Invalid granularity, grouping or date range"),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_my_progress_timeline(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<ProgressTimelineResponse>>, AppError> {
+    let request = progress_timeline_request(&params)?;
+
+    let timeline = state
+        .analytics_service
+        .get_progress_timeline(user.id, request)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to get progress timeline: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(timeline.into())))
+}
+
+/// Reads the granularity, grouping and time range of a progress timeline from query parameters.
+pub(crate) fn progress_timeline_request(
+    params: &HashMap<String, String>,
+) -> Result<ProgressTimelineRequest, AppError> {
+    let parse_time = |key: &str| -> Result<Option<DateTime<Utc>>, AppError> {
+        params
+            .get(key)
+            .map(|s| {
+                s.parse::<DateTime<Utc>>().map_err(|_| {
+                    AppError::ValidationError(format!(
+                        "Invalid {}: expected an RFC 3339 timestamp",
+                        key
+                    ))
+                })
+            })
+            .transpose()
+    };
+
+    Ok(ProgressTimelineRequest {
+        granularity: params
+            .get("granularity")
+            .cloned()
+            .unwrap_or_else(|| "week".to_string()),
+        group_by: params
+            .get("group_by")
+            .cloned()
+            .unwrap_or_else(|| "lesson".to_string()),
+        from: parse_time("from")?,
+        to: parse_time("to")?,
+    })
+}
@@ -0,0 +1,585 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use std::collections::HashMap;
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::{CreateAssignmentRequest, CreateClassroomRequest, JoinClassroomRequest};
+use crate::dto::response::{
+    ApiResponse, AssignmentResponse, AssignmentSubmissionsResponse, ClassroomDetailResponse,
+    ClassroomResponse, MessageResponse, PaginatedResponse, PaginationInfo,
+    StudentAssignmentResponse, TestResultResponse,
+};
+use crate::errors::AppError;
+use crate::extractors::{CurrentUser, RequireTeacher};
+use crate::state::AppState;
+
+// Teacher Classroom Handlers
+
+/// Create a classroom (Teacher only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/teacher/classrooms",
+    request_body = CreateClassroomRequest,
+    responses(
+        (status = 201, description = "Classroom created successfully", body = ApiResponse<ClassroomResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn create_classroom(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Json(request): Json<CreateClassroomRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<ClassroomResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .classroom_service
+        .create_classroom(teacher.id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(teacher_id = ?teacher.id, "Failed to create classroom: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Classroom created successfully",
+        )),
+    ))
+}
+
+/// List the current teacher's classrooms (Teacher only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/teacher/classrooms",
+    responses(
+        (status = 200, description = "Classrooms retrieved", body = ApiResponse<Vec<ClassroomResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn list_teacher_classrooms(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+) -> Result<Json<ApiResponse<Vec<ClassroomResponse>>>, AppError> {
+    let classrooms = state
+        .classroom_service
+        .list_teacher_classrooms(teacher.id)
+        .await
+        .map_err(|e| {
+            error!(teacher_id = ?teacher.id, "Failed to list classrooms: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        classrooms.into_iter().map(|c| c.into()).collect(),
+    )))
+}
+
+/// Get a classroom with its students (Teacher only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/teacher/classrooms/{id}",
+    params(("id" = Uuid, Path, description = "Classroom ID")),
+    responses(
+        (status = 200, description = "Classroom retrieved", body = ApiResponse<ClassroomDetailResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn get_classroom(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ClassroomDetailResponse>>, AppError> {
+    let classroom = state
+        .classroom_service
+        .get_classroom(teacher.id, id)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, "Failed to get classroom: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(classroom.into())))
+}
+
+/// Replace a classroom's invite code; the old code stops working (Teacher only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/teacher/classrooms/{id}/invite-code",
+    params(("id" = Uuid, Path, description = "Classroom ID")),
+    responses(
+        (status = 200, description = "Invite code regenerated", body = ApiResponse<ClassroomResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn regenerate_invite_code(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ClassroomResponse>>, AppError> {
+    let classroom = state
+        .classroom_service
+        .regenerate_invite_code(teacher.id, id)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, "Failed to regenerate invite code: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(classroom.into())))
+}
+
+/// Delete a classroom with its enrollments and assignments (Teacher only)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/teacher/classrooms/{id}",
+    params(("id" = Uuid, Path, description = "Classroom ID")),
+    responses(
+        (status = 200, description = "Classroom deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn delete_classroom(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .classroom_service
+        .delete_classroom(teacher.id, id)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, "Failed to delete classroom: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Classroom deleted successfully",
+    ))))
+}
+
+/// Remove a student from a classroom (Teacher only)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/teacher/classrooms/{id}/students/{student_id}",
+    params(
+        ("id" = Uuid, Path, description = "Classroom ID"),
+        ("student_id" = Uuid, Path, description = "Student user ID")
+    ),
+    responses(
+        (status = 200, description = "Student removed", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom or student not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn remove_classroom_student(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path((id, student_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .classroom_service
+        .remove_student(teacher.id, id, student_id)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, student_id = ?student_id, "Failed to remove student: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Student removed successfully",
+    ))))
+}
+
+/// List the test results of a student in the classroom (Teacher only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/teacher/classrooms/{id}/students/{student_id}/results",
+    params(
+        ("id" = Uuid, Path, description = "Classroom ID"),
+        ("student_id" = Uuid, Path, description = "Student user ID"),
+        ("page" = Option<u32>, Query, description = "Page number", example = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", example = 20)
+    ),
+    responses(
+        (status = 200, description = "Test results retrieved", body = ApiResponse<PaginatedResponse<TestResultResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom or student not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn list_classroom_student_results(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path((id, student_id)): Path<(Uuid, Uuid)>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<PaginatedResponse<TestResultResponse>>>, AppError> {
+    let page = params
+        .get("page")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let per_page = params
+        .get("per_page")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(20)
+        .clamp(1, 100);
+
+    let (results, total) = state
+        .classroom_service
+        .list_student_results(teacher.id, id, student_id, page, per_page)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, student_id = ?student_id, "Failed to list student results: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    let total_pages = (total as f64 / per_page as f64).ceil() as u32;
+
+    Ok(Json(ApiResponse::success(PaginatedResponse {
+        items: results.into_iter().map(|r| r.into()).collect(),
+        pagination: PaginationInfo {
+            page,
+            per_page,
+            total_items: total,
+            total_pages,
+        },
+    })))
+}
+
+// Teacher Assignment Handlers
+
+/// Assign a practice test to a classroom (Teacher only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/teacher/classrooms/{id}/assignments",
+    params(("id" = Uuid, Path, description = "Classroom ID")),
+    request_body = CreateAssignmentRequest,
+    responses(
+        (status = 201, description = "Assignment created successfully", body = ApiResponse<AssignmentResponse>),
+        (status = 400, description = "Validation error or due date in the past"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom or practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn create_assignment(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+    Json(request): Json<CreateAssignmentRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<AssignmentResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .assignment_service
+        .create_assignment(teacher.id, id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, "Failed to create assignment: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Assignment created successfully",
+        )),
+    ))
+}
+
+/// List a classroom's assignments with completion counts (Teacher only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/teacher/classrooms/{id}/assignments",
+    params(("id" = Uuid, Path, description = "Classroom ID")),
+    responses(
+        (status = 200, description = "Assignments retrieved", body = ApiResponse<Vec<AssignmentResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Classroom not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn list_assignments(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<AssignmentResponse>>>, AppError> {
+    let assignments = state
+        .assignment_service
+        .list_assignments(teacher.id, id)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, "Failed to list assignments: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        assignments.into_iter().map(|a| a.into()).collect(),
+    )))
+}
+
+/// List who completed an assignment and with what net (Teacher only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/teacher/assignments/{id}/submissions",
+    params(("id" = Uuid, Path, description = "Assignment ID")),
+    responses(
+        (status = 200, description = "Submissions retrieved", body = ApiResponse<AssignmentSubmissionsResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Assignment not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn get_assignment_submissions(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<AssignmentSubmissionsResponse>>, AppError> {
+    let submissions = state
+        .assignment_service
+        .get_submissions(teacher.id, id)
+        .await
+        .map_err(|e| {
+            error!(assignment_id = ?id, "Failed to get assignment submissions: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(submissions.into())))
+}
+
+/// Delete an assignment (Teacher only)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/teacher/assignments/{id}",
+    params(("id" = Uuid, Path, description = "Assignment ID")),
+    responses(
+        (status = 200, description = "Assignment deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Teacher access required"),
+        (status = 404, description = "Assignment not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "teacher"
+)]
+pub async fn delete_assignment(
+    State(state): State<AppState>,
+    RequireTeacher(teacher): RequireTeacher,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .assignment_service
+        .delete_assignment(teacher.id, id)
+        .await
+        .map_err(|e| {
+            error!(assignment_id = ?id, "Failed to delete assignment: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Assignment deleted successfully",
+    ))))
+}
+
+// Student Classroom Handlers
+
+/// Join a classroom with its invite code
+#[utoipa::path(
+    post,
+    path = "/api/v1/classrooms/join",
+    request_body = JoinClassroomRequest,
+    responses(
+        (status = 200, description = "Joined the classroom", body = ApiResponse<ClassroomResponse>),
+        (status = 400, description = "Validation error or own classroom"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Invalid invite code"),
+        (status = 409, description = "Already enrolled"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn join_classroom(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(request): Json<JoinClassroomRequest>,
+) -> Result<Json<ApiResponse<ClassroomResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let classroom = state
+        .classroom_service
+        .join_classroom(user.id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to join classroom: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        classroom.into(),
+        "Joined the classroom successfully",
+    )))
+}
+
+/// List the classrooms the current user is enrolled in
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/classrooms",
+    responses(
+        (status = 200, description = "Classrooms retrieved", body = ApiResponse<Vec<ClassroomResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn list_my_classrooms(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<Vec<ClassroomResponse>>>, AppError> {
+    let classrooms = state
+        .classroom_service
+        .list_my_classrooms(user.id)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to list classrooms: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        classrooms.into_iter().map(|c| c.into()).collect(),
+    )))
+}
+
+/// Leave a classroom
+#[utoipa::path(
+    delete,
+    path = "/api/v1/me/classrooms/{id}",
+    params(("id" = Uuid, Path, description = "Classroom ID")),
+    responses(
+        (status = 200, description = "Left the classroom", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Classroom not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn leave_classroom(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .classroom_service
+        .leave_classroom(user.id, id)
+        .await
+        .map_err(|e| {
+            error!(classroom_id = ?id, "Failed to leave classroom: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Left the classroom successfully",
+    ))))
+}
+
+/// List the current user's assignments across their classrooms
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/assignments",
+    responses(
+        (status = 200, description = "Assignments retrieved", body = ApiResponse<Vec<StudentAssignmentResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn list_my_assignments(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<Vec<StudentAssignmentResponse>>>, AppError> {
+    let assignments = state
+        .assignment_service
+        .list_my_assignments(user.id)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to list assignments: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        assignments.into_iter().map(|a| a.into()).collect(),
+    )))
+}
@@ -0,0 +1,443 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use std::collections::HashMap;
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::InviteStudentRequest;
+use crate::dto::response::{
+    ApiResponse, GuardianLinkResponse, MessageResponse, PaginatedResponse, PaginationInfo,
+    ProgressTimelineResponse, StudyCalendarResponse, TestResultResponse, WeaknessReportResponse,
+};
+use crate::errors::AppError;
+use crate::extractors::{CurrentUser, RequireGuardian};
+use crate::handlers::{progress_timeline_request, study_calendar_request};
+use crate::state::AppState;
+
+/// Stops a guardian from reading a student who has not accepted their invitation.
+async fn authorize_student(
+    state: &AppState,
+    guardian_id: Uuid,
+    student_id: Uuid,
+) -> Result<(), AppError> {
+    state
+        .guardian_service
+        .authorize_student(guardian_id, student_id)
+        .await
+        .map_err(|e| {
+            error!(guardian_id = ?guardian_id, student_id = ?student_id, "Guardian access denied: {:?}", e);
+            AppError::from(e)
+        })
+}
+
+// Guardian Handlers
+
+/// Invite a student to link with the current guardian (Guardian only)
+#[utoipa::path(
+    post,
+    path = "/api/v1/guardian/invitations",
+    request_body = InviteStudentRequest,
+    responses(
+        (status = 201, description = "Invitation sent", body = ApiResponse<GuardianLinkResponse>),
+        (status = 400, description = "Validation error or own account"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+        (status = 404, description = "Student not found"),
+        (status = 409, description = "Student already linked or invited"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn invite_student(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+    Json(request): Json<InviteStudentRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<GuardianLinkResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let link = state
+        .guardian_service
+        .invite_student(guardian.id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(guardian_id = ?guardian.id, "Failed to invite student: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            link.into(),
+            "Invitation sent successfully",
+        )),
+    ))
+}
+
+/// List the current guardian's students and pending invitations (Guardian only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/guardian/students",
+    responses(
+        (status = 200, description = "Students retrieved", body = ApiResponse<Vec<GuardianLinkResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn list_guardian_students(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+) -> Result<Json<ApiResponse<Vec<GuardianLinkResponse>>>, AppError> {
+    let links = state
+        .guardian_service
+        .list_students(guardian.id)
+        .await
+        .map_err(|e| {
+            error!(guardian_id = ?guardian.id, "Failed to list students: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        links.into_iter().map(|l| l.into()).collect(),
+    )))
+}
+
+/// Unlink a student or withdraw a pending invitation (Guardian only)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/guardian/students/{id}",
+    params(("id" = Uuid, Path, description = "Student user ID")),
+    responses(
+        (status = 200, description = "Student unlinked", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+        (status = 404, description = "Guardian link not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn remove_guardian_student(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .guardian_service
+        .remove_student(guardian.id, id)
+        .await
+        .map_err(|e| {
+            error!(student_id = ?id, "Failed to unlink student: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Student unlinked successfully",
+    ))))
+}
+
+/// List a linked student's test results (Guardian only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/guardian/students/{id}/results",
+    params(
+        ("id" = Uuid, Path, description = "Student user ID"),
+        ("page" = Option<u32>, Query, description = "Page number", example = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", example = 20)
+    ),
+    responses(
+        (status = 200, description = "Test results retrieved", body = ApiResponse<PaginatedResponse<TestResultResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+        (status = 404, description = "Student not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn list_guardian_student_results(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<PaginatedResponse<TestResultResponse>>>, AppError> {
+    authorize_student(&state, guardian.id, id).await?;
+
+    let page = params
+        .get("page")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let per_page = params
+        .get("per_page")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(20)
+        .clamp(1, 100);
+
+    let (results, total) = state
+        .result_service
+        .list_user_results(id, page, per_page)
+        .await
+        .map_err(|e| {
+            error!(student_id = ?id, "Failed to list student results: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    let total_pages = (total as f64 / per_page as f64).ceil() as u32;
+
+    Ok(Json(ApiResponse::success(PaginatedResponse {
+        items: results.into_iter().map(|r| r.into()).collect(),
+        pagination: PaginationInfo {
+            page,
+            per_page,
+            total_items: total,
+            total_pages,
+        },
+    })))
+}
+
+/// Get a linked student's goal completion calendar and streaks (Guardian only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/guardian/students/{id}/study-calendar",
+    params(
+        ("id" = Uuid, Path, description = "Student user ID"),
+        ("view" = Option<String>, Query, description = "Entry size: day or week", example = "day"),
+        ("from" = Option<String>, Query, description = "First day (YYYY-MM-DD); weeks start on the Monday before it", example = "2024-03-01"),
+        ("to" = Option<String>, Query, description = "Last day (YYYY-MM-DD), at most today, defaults to today", example = "2024-03-28")
+    ),
+    responses(
+        (status = 200, description = "Study calendar retrieved", body = ApiResponse<StudyCalendarResponse>),
+        (status = 400, description = "Invalid view or date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+        (status = 404, description = "Student not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn get_guardian_student_study_calendar(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<StudyCalendarResponse>>, AppError> {
+    authorize_student(&state, guardian.id, id).await?;
+    let request = study_calendar_request(&params)?;
+
+    let calendar = state
+        .study_plan_service
+        .get_calendar(id, request)
+        .await
+        .map_err(|e| {
+            error!(student_id = ?id, "Failed to get student study calendar: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(calendar.into())))
+}
+
+/// Get a linked student's weakest lessons, subjects, topics and questions (Guardian only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/guardian/students/{id}/analytics/weaknesses",
+    params(
+        ("id" = Uuid, Path, description = "Student user ID"),
+        ("limit" = Option<u32>, Query, description = "Maximum entries per list", example = 10)
+    ),
+    responses(
+        (status = 200, description = "Weakness report retrieved", body = ApiResponse<WeaknessReportResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+        (status = 404, description = "Student not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn get_guardian_student_weaknesses(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<WeaknessReportResponse>>, AppError> {
+    authorize_student(&state, guardian.id, id).await?;
+    let limit = params
+        .get("limit")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(10)
+        .min(50);
+
+    let report = state
+        .analytics_service
+        .get_weaknesses(id, limit)
+        .await
+        .map_err(|e| {
+            error!(student_id = ?id, "Failed to get student weakness report: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(report.into())))
+}
+
+/// Get a linked student's net scores over time (Guardian only)
+#[utoipa::path(
+    get,
+    path = "/api/v1/guardian/students/{id}/analytics/progress",
+    params(
+        ("id" = Uuid, Path, description = "Student user ID"),
+        ("granularity" = Option<String>, Query, description = "Bucket size: day, week or month", example = "week"),
+        ("group_by" = Option<String>, Query, description = "Series per lesson or exam_type", example = "lesson"),
+        ("from" = Option<DateTime<Utc>>, Query, description = "Only results solved at or after this time (RFC 3339)"),
+        ("to" = Option<DateTime<Utc>>, Query, description = "Only results solved before this time (RFC 3339)")
+    ),
+    responses(
+        (status = 200, description = "Progress timeline retrieved", body = ApiResponse<ProgressTimelineResponse>),
+        (status = 400, description = "Invalid granularity, grouping or date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - Guardian access required"),
+        (status = 404, description = "Student not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "guardian"
+)]
+pub async fn get_guardian_student_progress_timeline(
+    State(state): State<AppState>,
+    RequireGuardian(guardian): RequireGuardian,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<ProgressTimelineResponse>>, AppError> {
+    authorize_student(&state, guardian.id, id).await?;
+    let request = progress_timeline_request(&params)?;
+
+    let timeline = state
+        .analytics_service
+        .get_progress_timeline(id, request)
+        .await
+        .map_err(|e| {
+            error!(student_id = ?id, "Failed to get student progress timeline: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(timeline.into())))
+}
+
+// Student Guardian Handlers
+
+/// List the current user's guardians and pending invitations
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/guardians",
+    responses(
+        (status = 200, description = "Guardians retrieved", body = ApiResponse<Vec<GuardianLinkResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn list_my_guardians(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<Vec<GuardianLinkResponse>>>, AppError> {
+    let links = state
+        .guardian_service
+        .list_my_guardians(user.id)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to list guardians: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        links.into_iter().map(|l| l.into()).collect(),
+    )))
+}
+
+/// Accept a guardian's invitation, giving them read-only access to your progress
+#[utoipa::path(
+    post,
+    path = "/api/v1/me/guardians/{id}/accept",
+    params(("id" = Uuid, Path, description = "Guardian link ID")),
+    responses(
+        (status = 200, description = "Invitation accepted", body = ApiResponse<GuardianLinkResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Guardian link not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn accept_guardian_invitation(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<GuardianLinkResponse>>, AppError> {
+    let link = state
+        .guardian_service
+        .accept_invitation(user.id, id)
+        .await
+        .map_err(|e| {
+            error!(link_id = ?id, "Failed to accept guardian invitation: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        link.into(),
+        "Invitation accepted successfully",
+    )))
+}
+
+/// Decline a guardian's invitation or remove a guardian
+#[utoipa::path(
+    delete,
+    path = "/api/v1/me/guardians/{id}",
+    params(("id" = Uuid, Path, description = "Guardian link ID")),
+    responses(
+        (status = 200, description = "Guardian removed", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Guardian link not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn remove_my_guardian(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .guardian_service
+        .remove_guardian(user.id, id)
+        .await
+        .map_err(|e| {
+            error!(link_id = ?id, "Failed to remove guardian: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Guardian removed successfully",
+    ))))
+}
@@ -1,5 +1,7 @@
 use axum::{extract::State, http::StatusCode, Json};
-use std::time::Instant;
+use std::time::{Duration, Instant};
+
+use infrastructure::cache::ping_redis;
 
 use crate::dto::response::{HealthCheckResult, HealthChecks, LivenessResponse, ReadinessResponse};
 use crate::state::AppState;
@@ -23,7 +25,7 @@ pub async fn liveness() -> Json<LivenessResponse> {
     get,
     path = "/health/ready",
     responses(
-        (status = 200, description = "Application is ready", body = ReadinessResponse),
+        (status = 200, description = "Application is ready, or degraded when Redis is unavailable", body = ReadinessResponse),
         (status = 503, description = "Application is not ready"),
     ),
     tag = "health"
@@ -31,19 +33,26 @@ pub async fn liveness() -> Json<LivenessResponse> {
 pub async fn readiness(
     State(state): State<AppState>,
 ) -> Result<Json<ReadinessResponse>, (StatusCode, Json<ReadinessResponse>)> {
-    // Check database connection
+    // Check database and Redis connections
     let db_check = check_database(&state).await;
+    let redis_check = check_redis(&state).await;
 
-    let checks = HealthChecks { database: db_check };
+    let checks = HealthChecks {
+        database: db_check,
+        redis: redis_check,
+    };
 
-    // Determine overall status
-    if checks.database.status == "healthy" {
-        Ok(Json(ReadinessResponse::ready(checks)))
-    } else {
+    // Determine overall status: without Redis the cache and rate limiter
+    // fall back to the database and in-process counters, so keep serving
+    if checks.database.status != "healthy" {
         Err((
             StatusCode::SERVICE_UNAVAILABLE,
             Json(ReadinessResponse::not_ready(checks)),
         ))
+    } else if checks.redis.status != "healthy" {
+        Ok(Json(ReadinessResponse::degraded(checks)))
+    } else {
+        Ok(Json(ReadinessResponse::ready(checks)))
     }
 }
 
@@ -60,3 +69,21 @@ async fn check_database(state: &AppState) -> HealthCheckResult {
     }
 }
 
+/// Checks Redis connectivity and returns latency.
+async fn check_redis(state: &AppState) -> HealthCheckResult {
+    let Some(connection) = &state.redis else {
+        return HealthCheckResult::unhealthy("Not connected, using in-process stores");
+    };
+
+    let start = Instant::now();
+    let timeout = Duration::from_millis(state.settings.health.timeout_ms);
+
+    match tokio::time::timeout(timeout, ping_redis(connection)).await {
+        Ok(Ok(_)) => {
+            let latency_ms = start.elapsed().as_millis() as i64;
+            HealthCheckResult::healthy(latency_ms)
+        }
+        Ok(Err(e)) => HealthCheckResult::unhealthy(e.to_string()),
+        Err(_) => HealthCheckResult::unhealthy("Timed out"),
+    }
+}
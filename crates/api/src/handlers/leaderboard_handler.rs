@@ -0,0 +1,204 @@
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::error;
+use uuid::Uuid;
+
+use crate::dto::request::UpdateLeaderboardPreferenceRequest;
+use crate::dto::response::{ApiResponse, LeaderboardPreferenceResponse, LeaderboardResponse};
+use crate::errors::AppError;
+use crate::extractors::CurrentUser;
+use crate::state::AppState;
+
+// Leaderboard Handlers
+
+/// Reads the `page` and `per_page` query parameters.
+fn pagination_params(params: &HashMap<String, String>) -> (u32, u32) {
+    let page = params
+        .get("page")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let per_page = params
+        .get("per_page")
+        .and_then(|s| s.parse::<u32>().ok())
+        .unwrap_or(20)
+        .clamp(1, 100);
+    (page, per_page)
+}
+
+/// Rank users by the net of their first attempt of a practice test
+#[utoipa::path(
+    get,
+    path = "/api/v1/leaderboards/practice-tests/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Practice test ID"),
+        ("page" = Option<u32>, Query, description = "Page number", example = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", example = 20)
+    ),
+    responses(
+        (status = 200, description = "Leaderboard retrieved", body = ApiResponse<LeaderboardResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_practice_test_leaderboard(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<LeaderboardResponse>>, AppError> {
+    let (page, per_page) = pagination_params(&params);
+
+    let leaderboard = state
+        .leaderboard_service
+        .get_practice_test_leaderboard(user.id, id, page, per_page)
+        .await
+        .map_err(|e| {
+            error!(practice_test_id = ?id, "Failed to get practice test leaderboard: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(LeaderboardResponse::from_app(
+        leaderboard,
+        page,
+        per_page,
+    ))))
+}
+
+/// Rank users by their average first-attempt net over a test book
+#[utoipa::path(
+    get,
+    path = "/api/v1/leaderboards/test-books/{id}",
+    params(
+        ("id" = Uuid, Path, description = "Test book ID"),
+        ("page" = Option<u32>, Query, description = "Page number", example = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", example = 20)
+    ),
+    responses(
+        (status = 200, description = "Leaderboard retrieved", body = ApiResponse<LeaderboardResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Test book not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_test_book_leaderboard(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<LeaderboardResponse>>, AppError> {
+    let (page, per_page) = pagination_params(&params);
+
+    let leaderboard = state
+        .leaderboard_service
+        .get_test_book_leaderboard(user.id, id, page, per_page)
+        .await
+        .map_err(|e| {
+            error!(test_book_id = ?id, "Failed to get test book leaderboard: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(LeaderboardResponse::from_app(
+        leaderboard,
+        page,
+        per_page,
+    ))))
+}
+
+/// Rank users by their total first-attempt net in an exam type during a week
+#[utoipa::path(
+    get,
+    path = "/api/v1/leaderboards/exam-types/{id}/weekly",
+    params(
+        ("id" = Uuid, Path, description = "Exam type ID"),
+        ("week" = Option<String>, Query, description = "Any day of the week to rank (YYYY-MM-DD), defaults to the current week", example = "2024-03-14"),
+        ("page" = Option<u32>, Query, description = "Page number", example = 1),
+        ("per_page" = Option<u32>, Query, description = "Items per page", example = 20)
+    ),
+    responses(
+        (status = 200, description = "Leaderboard retrieved", body = ApiResponse<LeaderboardResponse>),
+        (status = 400, description = "Invalid week"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Exam type not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_exam_type_weekly_leaderboard(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<LeaderboardResponse>>, AppError> {
+    let (page, per_page) = pagination_params(&params);
+    let week = params
+        .get("week")
+        .map(|s| {
+            s.parse::<NaiveDate>().map_err(|_| {
+                AppError::ValidationError("Invalid week: expected a date as YYYY-MM-DD".to_string())
+            })
+        })
+        .transpose()?;
+
+    let leaderboard = state
+        .leaderboard_service
+        .get_exam_type_weekly_leaderboard(user.id, id, week, page, per_page)
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to get weekly exam type leaderboard: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(LeaderboardResponse::from_app(
+        leaderboard,
+        page,
+        per_page,
+    ))))
+}
+
+/// Hide the current user from, or show them on, public leaderboards
+#[utoipa::path(
+    put,
+    path = "/api/v1/me/leaderboard-preference",
+    request_body = UpdateLeaderboardPreferenceRequest,
+    responses(
+        (status = 200, description = "Leaderboard preference updated", body = ApiResponse<LeaderboardPreferenceResponse>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn update_leaderboard_preference(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(request): Json<UpdateLeaderboardPreferenceRequest>,
+) -> Result<Json<ApiResponse<LeaderboardPreferenceResponse>>, AppError> {
+    let preference = state
+        .leaderboard_service
+        .set_leaderboard_opt_out(user.id, request.leaderboard_opt_out)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to update leaderboard preference: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        preference.into(),
+        "Leaderboard preference updated",
+    )))
+}
@@ -0,0 +1,392 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::{
+    CreateMockExamRequest, SaveMockExamAnswersRequest, SubmitMockExamAttemptRequest,
+    UpdateMockExamRequest,
+};
+use crate::dto::response::{
+    ApiResponse, MessageResponse, MockExamAttemptResponse, MockExamResponse,
+};
+use crate::errors::AppError;
+use crate::extractors::{CurrentUser, RequirePermission, TestsWrite};
+use crate::state::AppState;
+
+// MockExam Handlers
+
+/// Create a mock exam from practice tests of one exam type (requires tests:write)
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/mock-exams",
+    request_body = CreateMockExamRequest,
+    responses(
+        (status = 201, description = "Mock exam created successfully", body = ApiResponse<MockExamResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Exam type or practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn create_mock_exam(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Json(request): Json<CreateMockExamRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<MockExamResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .mock_exam_service
+        .create_mock_exam(request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!("Failed to create mock exam: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Mock exam created successfully",
+        )),
+    ))
+}
+
+/// Update a mock exam (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/mock-exams/{id}",
+    params(("id" = Uuid, Path, description = "Mock exam ID")),
+    request_body = UpdateMockExamRequest,
+    responses(
+        (status = 200, description = "Mock exam updated", body = ApiResponse<MockExamResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Mock exam or practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn update_mock_exam(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateMockExamRequest>,
+) -> Result<Json<ApiResponse<MockExamResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .mock_exam_service
+        .update_mock_exam(id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(mock_exam_id = ?id, "Failed to update mock exam: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Mock exam updated successfully",
+    )))
+}
+
+/// Delete a mock exam (requires tests:write)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/mock-exams/{id}",
+    params(("id" = Uuid, Path, description = "Mock exam ID")),
+    responses(
+        (status = 200, description = "Mock exam deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Mock exam not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_mock_exam(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .mock_exam_service
+        .delete_mock_exam(id)
+        .await
+        .map_err(|e| {
+            error!(mock_exam_id = ?id, "Failed to delete mock exam: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        MessageResponse {
+            message: "Mock exam deleted successfully".to_string(),
+        },
+        "Mock exam deleted successfully",
+    )))
+}
+
+/// List mock exams
+#[utoipa::path(
+    get,
+    path = "/api/v1/mock-exams",
+    params(("exam_type_id" = Option<Uuid>, Query, description = "Filter by exam type ID")),
+    responses(
+        (status = 200, description = "Mock exams retrieved", body = ApiResponse<Vec<MockExamResponse>>),
+    ),
+    tag = "tests"
+)]
+pub async fn list_mock_exams(
+    State(state): State<AppState>,
+    Query(params): Query<std::collections::HashMap<String, String>>,
+) -> Result<Json<ApiResponse<Vec<MockExamResponse>>>, AppError> {
+    let exam_type_id = params
+        .get("exam_type_id")
+        .and_then(|s| Uuid::parse_str(s).ok());
+
+    let results = state
+        .mock_exam_service
+        .list_mock_exams(exam_type_id)
+        .await
+        .map_err(|e| {
+            error!("Failed to list mock exams: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        results.into_iter().map(|r| r.into()).collect(),
+    )))
+}
+
+/// Get a mock exam with its sections
+#[utoipa::path(
+    get,
+    path = "/api/v1/mock-exams/{id}",
+    params(("id" = Uuid, Path, description = "Mock exam ID")),
+    responses(
+        (status = 200, description = "Mock exam retrieved", body = ApiResponse<MockExamResponse>),
+        (status = 404, description = "Mock exam not found"),
+    ),
+    tag = "tests"
+)]
+pub async fn get_mock_exam(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MockExamResponse>>, AppError> {
+    let result = state
+        .mock_exam_service
+        .get_mock_exam(id)
+        .await
+        .map_err(|e| {
+            error!(mock_exam_id = ?id, "Failed to get mock exam: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+// MockExamAttempt Handlers
+
+/// Start a mock exam attempt, or resume the one in progress
+#[utoipa::path(
+    post,
+    path = "/api/v1/mock-exams/{id}/attempts",
+    params(("id" = Uuid, Path, description = "Mock exam ID")),
+    responses(
+        (status = 201, description = "Mock exam attempt started", body = ApiResponse<MockExamAttemptResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "The retake policy of a section's practice test does not allow another attempt yet"),
+        (status = 404, description = "Mock exam not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn start_mock_exam_attempt(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(mock_exam_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<ApiResponse<MockExamAttemptResponse>>), AppError> {
+    let result = state
+        .mock_exam_service
+        .start_attempt(user.id, mock_exam_id)
+        .await
+        .map_err(|e| {
+            error!(mock_exam_id = ?mock_exam_id, "Failed to start mock exam attempt: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Mock exam attempt started",
+        )),
+    ))
+}
+
+/// Get a mock exam attempt, auto-submitting it if its time has run out
+#[utoipa::path(
+    get,
+    path = "/api/v1/mock-exam-attempts/{id}",
+    params(("id" = Uuid, Path, description = "Mock exam attempt ID")),
+    responses(
+        (status = 200, description = "Mock exam attempt retrieved", body = ApiResponse<MockExamAttemptResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mock exam attempt not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_mock_exam_attempt(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(attempt_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MockExamAttemptResponse>>, AppError> {
+    let result = state
+        .mock_exam_service
+        .get_attempt(user.id, attempt_id)
+        .await
+        .map_err(|e| {
+            error!(attempt_id = ?attempt_id, "Failed to get mock exam attempt: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Autosave the answers of a mock exam attempt
+#[utoipa::path(
+    put,
+    path = "/api/v1/mock-exam-attempts/{id}/answers",
+    params(("id" = Uuid, Path, description = "Mock exam attempt ID")),
+    request_body = SaveMockExamAnswersRequest,
+    responses(
+        (status = 200, description = "Answers saved", body = ApiResponse<MockExamAttemptResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mock exam attempt not found"),
+        (status = 409, description = "Mock exam attempt is no longer in progress"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn save_mock_exam_answers(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(attempt_id): Path<Uuid>,
+    Json(request): Json<SaveMockExamAnswersRequest>,
+) -> Result<Json<ApiResponse<MockExamAttemptResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .mock_exam_service
+        .save_attempt_answers(user.id, attempt_id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(attempt_id = ?attempt_id, "Failed to save mock exam answers: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Submit a mock exam attempt and grade every section
+#[utoipa::path(
+    post,
+    path = "/api/v1/mock-exam-attempts/{id}/submit",
+    params(("id" = Uuid, Path, description = "Mock exam attempt ID")),
+    request_body = SubmitMockExamAttemptRequest,
+    responses(
+        (status = 200, description = "Mock exam attempt submitted", body = ApiResponse<MockExamAttemptResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Mock exam attempt not found"),
+        (status = 409, description = "Mock exam attempt is no longer in progress"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn submit_mock_exam_attempt(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(attempt_id): Path<Uuid>,
+    Json(request): Json<SubmitMockExamAttemptRequest>,
+) -> Result<Json<ApiResponse<MockExamAttemptResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .mock_exam_service
+        .submit_attempt(user.id, attempt_id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(attempt_id = ?attempt_id, "Failed to submit mock exam attempt: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Mock exam attempt submitted successfully",
+    )))
+}
+
+/// List the current user's mock exam attempts, newest first
+#[utoipa::path(
+    get,
+    path = "/api/v1/my-mock-exam-attempts",
+    responses(
+        (status = 200, description = "Mock exam attempts retrieved", body = ApiResponse<Vec<MockExamAttemptResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn list_my_mock_exam_attempts(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<Vec<MockExamAttemptResponse>>>, AppError> {
+    let results = state
+        .mock_exam_service
+        .list_my_attempts(user.id)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to list mock exam attempts: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        results.into_iter().map(|r| r.into()).collect(),
+    )))
+}
@@ -1,12 +1,33 @@
+mod achievement_handler;
+mod analytics_handler;
 mod auth_handler;
+mod classroom_handler;
+mod guardian_handler;
 mod health_handler;
+mod leaderboard_handler;
+mod mock_exam_handler;
+mod recommendation_handler;
+mod result_import_handler;
 mod role_handler;
+mod score_handler;
+mod study_plan_handler;
 mod test_handler;
+mod topic_handler;
 mod user_handler;
 
+pub use achievement_handler::*;
+pub use analytics_handler::*;
 pub use auth_handler::*;
+pub use classroom_handler::*;
+pub use guardian_handler::*;
 pub use health_handler::*;
+pub use leaderboard_handler::*;
+pub use mock_exam_handler::*;
+pub use recommendation_handler::*;
+pub use result_import_handler::*;
 pub use role_handler::*;
+pub use score_handler::*;
+pub use study_plan_handler::*;
 pub use test_handler::*;
+pub use topic_handler::*;
 pub use user_handler::*;
-
@@ -0,0 +1,53 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+use tracing::error;
+
+use crate::dto::response::{ApiResponse, RecommendationResponse};
+use crate::errors::AppError;
+use crate::extractors::CurrentUser;
+use crate::state::AppState;
+
+// Recommendation Handlers
+
+/// Get the practice tests the current user should solve next
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/recommendations",
+    params(
+        ("limit" = Option<u32>, Query, description = "Maximum number of recommendations", example = 10)
+    ),
+    responses(
+        (status = 200, description = "Recommendations retrieved", body = ApiResponse<Vec<RecommendationResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_my_recommendations(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(params): Query<std::collections::HashMap<String, String>>,
+) -> Result<Json<ApiResponse<Vec<RecommendationResponse>>>, AppError> {
+    let limit = params
+        .get("limit")
+        .and_then(|s| s.parse::<usize>().ok())
+        .unwrap_or(10)
+        .min(50);
+
+    let recommendations = state
+        .recommendation_service
+        .get_recommendations(user.id, limit)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to get recommendations: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        recommendations.into_iter().map(|r| r.into()).collect(),
+    )))
+}
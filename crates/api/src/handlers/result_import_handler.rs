@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::ImportOmrResultsRequest;
+use crate::dto::response::{ApiResponse, OmrImportReportResponse};
+use crate::errors::AppError;
+use crate::extractors::{RequirePermission, ResultsImport};
+use crate::state::AppState;
+
+// Result Import Handlers
+
+/// Import paper results of a practice test from an optical answer form export (requires results:import)
+///
+/// Students are matched by their student number and every row is graded like an online
+/// submission. Rows that cannot be imported are reported without failing the whole import.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/practice-tests/{id}/omr-import",
+    params(
+        ("id" = Uuid, Path, description = "Practice test ID")
+    ),
+    request_body = ImportOmrResultsRequest,
+    responses(
+        (status = 200, description = "Import finished, see the per-row report", body = ApiResponse<OmrImportReportResponse>),
+        (status = 400, description = "Validation error or invalid format"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - results:import permission required"),
+        (status = 404, description = "Practice test or scoring policy not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn import_omr_results(
+    State(state): State<AppState>,
+    _admin: RequirePermission<ResultsImport>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ImportOmrResultsRequest>,
+) -> Result<Json<ApiResponse<OmrImportReportResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let report = state
+        .result_import_service
+        .import_omr_results(id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(practice_test_id = ?id, "Failed to import OMR results: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(report.into())))
+}
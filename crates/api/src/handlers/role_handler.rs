@@ -4,15 +4,17 @@ use axum::{
     Json,
 };
 use domain::entities::Role;
-use domain::repositories::{RoleRepository, UserRepository};
+use domain::repositories::{PermissionRepository, RoleRepository, UserRepository};
+use tracing::error;
 use uuid::Uuid;
 use validator::Validate;
-use tracing::error;
 
-use crate::dto::request::{AssignRoleRequest, CreateRoleRequest, UpdateRoleRequest};
-use crate::dto::response::{ApiResponse, MessageResponse, RoleResponse};
+use crate::dto::request::{
+    AssignRoleRequest, CreateRoleRequest, GrantPermissionRequest, UpdateRoleRequest,
+};
+use crate::dto::response::{ApiResponse, MessageResponse, PermissionResponse, RoleResponse};
 use crate::errors::AppError;
-use crate::extractors::RequireAdmin;
+use crate::extractors::{RequirePermission, RolesRead, RolesWrite};
 use crate::state::AppState;
 
 /// List all roles
@@ -22,7 +24,7 @@ use crate::state::AppState;
     responses(
         (status = 200, description = "Roles retrieved", body = ApiResponse<Vec<RoleResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -31,16 +33,12 @@ use crate::state::AppState;
 )]
 pub async fn list_roles(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesRead>,
 ) -> Result<Json<ApiResponse<Vec<RoleResponse>>>, AppError> {
-    let roles = state
-        .role_repo
-        .list()
-        .await
-        .map_err(|e| {
-            error!("Failed to list roles: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    let roles = state.role_repo.list().await.map_err(|e| {
+        error!("Failed to list roles: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     let role_responses: Vec<RoleResponse> = roles.into_iter().map(RoleResponse::from).collect();
 
@@ -56,7 +54,7 @@ pub async fn list_roles(
         (status = 201, description = "Role created successfully", body = ApiResponse<RoleResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
         (status = 409, description = "Role name already exists"),
     ),
     security(
@@ -66,7 +64,7 @@ pub async fn list_roles(
 )]
 pub async fn create_role(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesWrite>,
     Json(request): Json<CreateRoleRequest>,
 ) -> Result<(StatusCode, Json<ApiResponse<RoleResponse>>), AppError> {
     request
@@ -74,14 +72,15 @@ pub async fn create_role(
         .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     // Check if role name already exists
-    if let Some(_existing_role) = state
-        .role_repo
-        .find_by_name(&request.name)
-        .await
-        .map_err(|e| {
-            error!("Failed to check role name existence: {:?}", e);
-            AppError::InternalServerError
-        })?
+    if let Some(_existing_role) =
+        state
+            .role_repo
+            .find_by_name(&request.name)
+            .await
+            .map_err(|e| {
+                error!("Failed to check role name existence: {:?}", e);
+                AppError::InternalServerError
+            })?
     {
         return Err(AppError::Conflict(format!(
             "Role '{}' already exists",
@@ -91,14 +90,10 @@ pub async fn create_role(
 
     // Create new role
     let role = Role::new(request.name, request.description);
-    let created_role = state
-        .role_repo
-        .create(&role)
-        .await
-        .map_err(|e| {
-            error!("Failed to create role: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    let created_role = state.role_repo.create(&role).await.map_err(|e| {
+        error!("Failed to create role: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     Ok((
         StatusCode::CREATED,
@@ -117,7 +112,7 @@ pub async fn create_role(
     responses(
         (status = 200, description = "Role retrieved", body = ApiResponse<RoleResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:read permission required"),
         (status = 404, description = "Role not found"),
     ),
     security(
@@ -127,7 +122,7 @@ pub async fn create_role(
 )]
 pub async fn get_role(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<RoleResponse>>, AppError> {
     let role = state
@@ -153,7 +148,7 @@ pub async fn get_role(
         (status = 200, description = "Role updated successfully", body = ApiResponse<RoleResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
         (status = 404, description = "Role not found"),
         (status = 409, description = "Role name already exists"),
     ),
@@ -164,7 +159,7 @@ pub async fn get_role(
 )]
 pub async fn update_role(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateRoleRequest>,
 ) -> Result<Json<ApiResponse<RoleResponse>>, AppError> {
@@ -193,11 +188,8 @@ pub async fn update_role(
     // Check if new name conflicts with existing role
     if let Some(new_name) = &request.name {
         if new_name != &role.name {
-            if let Some(_existing_role) = state
-                .role_repo
-                .find_by_name(new_name)
-                .await
-                .map_err(|e| {
+            if let Some(_existing_role) =
+                state.role_repo.find_by_name(new_name).await.map_err(|e| {
                     error!(role_name = new_name, "Failed to check role name: {:?}", e);
                     AppError::InternalServerError
                 })?
@@ -220,14 +212,10 @@ pub async fn update_role(
     role.updated_at = chrono::Utc::now();
 
     // Save updated role
-    let updated_role = state
-        .role_repo
-        .update(&role)
-        .await
-        .map_err(|e| {
-            error!(role_id = ?id, "Failed to update role: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    let updated_role = state.role_repo.update(&role).await.map_err(|e| {
+        error!(role_id = ?id, "Failed to update role: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     Ok(Json(ApiResponse::success_with_message(
         RoleResponse::from(updated_role),
@@ -243,7 +231,7 @@ pub async fn update_role(
     responses(
         (status = 200, description = "Role deleted successfully", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
         (status = 404, description = "Role not found"),
         (status = 400, description = "Cannot delete system role"),
     ),
@@ -254,7 +242,7 @@ pub async fn update_role(
 )]
 pub async fn delete_role(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     // Get role to check if it's a system role
@@ -276,14 +264,10 @@ pub async fn delete_role(
     }
 
     // Delete role
-    state
-        .role_repo
-        .delete(id)
-        .await
-        .map_err(|e| {
-            error!(role_id = ?id, role_name = %role.name, "Failed to delete role: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    state.role_repo.delete(id).await.map_err(|e| {
+        error!(role_id = ?id, role_name = %role.name, "Failed to delete role: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     Ok(Json(ApiResponse::success_with_message(
         MessageResponse::new("Role deleted successfully"),
@@ -301,7 +285,7 @@ pub async fn delete_role(
         (status = 200, description = "Role assigned successfully", body = ApiResponse<MessageResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
         (status = 404, description = "User or role not found"),
     ),
     security(
@@ -311,7 +295,7 @@ pub async fn delete_role(
 )]
 pub async fn assign_role_to_user(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesWrite>,
     Path(user_id): Path<Uuid>,
     Json(request): Json<AssignRoleRequest>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
@@ -319,9 +303,9 @@ pub async fn assign_role_to_user(
         .validate()
         .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
-    let role_id = request.role_id.ok_or_else(|| {
-        AppError::ValidationError("role_id is required".to_string())
-    })?;
+    let role_id = request
+        .role_id
+        .ok_or_else(|| AppError::ValidationError("role_id is required".to_string()))?;
 
     // Verify user exists
     let user = state
@@ -382,7 +366,7 @@ pub async fn assign_role_to_user(
     responses(
         (status = 200, description = "Role removed successfully", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
         (status = 404, description = "User or role not found"),
     ),
     security(
@@ -392,7 +376,7 @@ pub async fn assign_role_to_user(
 )]
 pub async fn remove_role_from_user(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<RolesWrite>,
     Path((user_id, role_id)): Path<(Uuid, Uuid)>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     // Verify user exists
@@ -450,3 +434,249 @@ pub async fn remove_role_from_user(
     )))
 }
 
+/// List all permissions
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/permissions",
+    responses(
+        (status = 200, description = "Permissions retrieved", body = ApiResponse<Vec<PermissionResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - roles:read permission required"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_permissions(
+    State(state): State<AppState>,
+    _admin: RequirePermission<RolesRead>,
+) -> Result<Json<ApiResponse<Vec<PermissionResponse>>>, AppError> {
+    let permissions = state.permission_repo.list().await.map_err(|e| {
+        error!("Failed to list permissions: {:?}", e);
+        AppError::InternalServerError
+    })?;
+
+    let permission_responses: Vec<PermissionResponse> = permissions
+        .into_iter()
+        .map(PermissionResponse::from)
+        .collect();
+
+    Ok(Json(ApiResponse::success(permission_responses)))
+}
+
+/// List the permissions granted to a role
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/roles/{id}/permissions",
+    params(("id" = Uuid, Path, description = "Role ID")),
+    responses(
+        (status = 200, description = "Role permissions retrieved", body = ApiResponse<Vec<PermissionResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - roles:read permission required"),
+        (status = 404, description = "Role not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_role_permissions(
+    State(state): State<AppState>,
+    _admin: RequirePermission<RolesRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<PermissionResponse>>>, AppError> {
+    // Verify role exists
+    state
+        .role_repo
+        .find_by_id(id)
+        .await
+        .map_err(|e| {
+            error!(role_id = ?id, "Failed to find role: {:?}", e);
+            AppError::InternalServerError
+        })?
+        .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+
+    let permissions = state
+        .permission_repo
+        .find_by_role_id(id)
+        .await
+        .map_err(|e| {
+            error!(role_id = ?id, "Failed to list role permissions: {:?}", e);
+            AppError::InternalServerError
+        })?;
+
+    let permission_responses: Vec<PermissionResponse> = permissions
+        .into_iter()
+        .map(PermissionResponse::from)
+        .collect();
+
+    Ok(Json(ApiResponse::success(permission_responses)))
+}
+
+/// Grant a permission to a role
+///
+/// Users holding the role receive the permission in their next access token.
+/// Permissions of the admin role cannot be changed.
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/roles/{id}/permissions",
+    params(("id" = Uuid, Path, description = "Role ID")),
+    request_body = GrantPermissionRequest,
+    responses(
+        (status = 200, description = "Permission granted successfully", body = ApiResponse<MessageResponse>),
+        (status = 400, description = "Validation error, or the role is the admin role whose permissions cannot be changed"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
+        (status = 404, description = "Role or permission not found"),
+        (status = 409, description = "Role already has the permission"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn grant_permission_to_role(
+    State(state): State<AppState>,
+    _admin: RequirePermission<RolesWrite>,
+    Path(role_id): Path<Uuid>,
+    Json(request): Json<GrantPermissionRequest>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let permission_id = request
+        .permission_id
+        .ok_or_else(|| AppError::ValidationError("permission_id is required".to_string()))?;
+
+    // Verify role exists
+    let role = state
+        .role_repo
+        .find_by_id(role_id)
+        .await
+        .map_err(|e| {
+            error!(role_id = ?role_id, "Failed to find role: {:?}", e);
+            AppError::InternalServerError
+        })?
+        .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+
+    if role.is_admin_role() {
+        return Err(AppError::ValidationError(
+            "Cannot change permissions of the admin role".to_string(),
+        ));
+    }
+
+    // Verify permission exists
+    let permission = state
+        .permission_repo
+        .find_by_id(permission_id)
+        .await
+        .map_err(|e| {
+            error!(permission_id = ?permission_id, "Failed to find permission: {:?}", e);
+            AppError::InternalServerError
+        })?
+        .ok_or_else(|| AppError::NotFound("Permission not found".to_string()))?;
+
+    // Grant permission
+    let granted = state
+        .permission_repo
+        .grant_to_role(role_id, permission_id)
+        .await
+        .map_err(|e| {
+            error!(
+                role_id = ?role_id,
+                permission_id = ?permission_id,
+                "Failed to grant permission: {:?}",
+                e
+            );
+            AppError::InternalServerError
+        })?;
+
+    if !granted {
+        return Err(AppError::Conflict(format!(
+            "Role '{}' already has permission '{}'",
+            role.name, permission.name
+        )));
+    }
+
+    Ok(Json(ApiResponse::success_with_message(
+        MessageResponse::new("Permission granted successfully"),
+        format!(
+            "Permission '{}' granted to role '{}'",
+            permission.name, role.name
+        ),
+    )))
+}
+
+/// Revoke a permission from a role
+///
+/// Permissions of the admin role cannot be changed.
+/// Access tokens already issued keep the permission until they expire.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/roles/{id}/permissions/{permission_id}",
+    params(
+        ("id" = Uuid, Path, description = "Role ID"),
+        ("permission_id" = Uuid, Path, description = "Permission ID")
+    ),
+    responses(
+        (status = 200, description = "Permission revoked successfully", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - roles:write permission required"),
+        (status = 404, description = "Role not found or does not have the permission"),
+        (status = 400, description = "Cannot change permissions of the admin role"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn revoke_permission_from_role(
+    State(state): State<AppState>,
+    _admin: RequirePermission<RolesWrite>,
+    Path((role_id, permission_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    // Verify role exists
+    let role = state
+        .role_repo
+        .find_by_id(role_id)
+        .await
+        .map_err(|e| {
+            error!(role_id = ?role_id, "Failed to find role: {:?}", e);
+            AppError::InternalServerError
+        })?
+        .ok_or_else(|| AppError::NotFound("Role not found".to_string()))?;
+
+    // Don't allow locking administrators out
+    if role.is_admin_role() {
+        return Err(AppError::ValidationError(
+            "Cannot change permissions of the admin role".to_string(),
+        ));
+    }
+
+    let revoked = state
+        .permission_repo
+        .revoke_from_role(role_id, permission_id)
+        .await
+        .map_err(|e| {
+            error!(
+                role_id = ?role_id,
+                permission_id = ?permission_id,
+                "Failed to revoke permission: {:?}",
+                e
+            );
+            AppError::InternalServerError
+        })?;
+
+    if !revoked {
+        return Err(AppError::NotFound(
+            "Role does not have this permission".to_string(),
+        ));
+    }
+
+    Ok(Json(ApiResponse::success_with_message(
+        MessageResponse::new("Permission revoked successfully"),
+        "Permission revoked from role",
+    )))
+}
@@ -0,0 +1,178 @@
+use axum::{
+    extract::{Path, State},
+    Json,
+};
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::{EstimateScoreRequest, SaveScoreTableRequest};
+use crate::dto::response::{
+    ApiResponse, MessageResponse, ScoreEstimateResponse, ScoreTableResponse,
+};
+use crate::errors::AppError;
+use crate::extractors::{CurrentUser, RequirePermission, TestsRead, TestsWrite};
+use crate::state::AppState;
+
+// ScoreTable Handlers
+
+/// List the yearly score tables of an exam type (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/exam-types/{id}/score-tables",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    responses(
+        (status = 200, description = "Score tables retrieved", body = ApiResponse<Vec<ScoreTableResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Exam type not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_score_tables(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ScoreTableResponse>>>, AppError> {
+    let results = state
+        .score_calculation_service
+        .list_score_tables(id)
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to list score tables: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        results.into_iter().map(|r| r.into()).collect(),
+    )))
+}
+
+/// Create or replace the score table of an exam type for a year (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/exam-types/{id}/score-tables/{year}",
+    params(
+        ("id" = Uuid, Path, description = "Exam type ID"),
+        ("year" = i32, Path, description = "Exam year")
+    ),
+    request_body = SaveScoreTableRequest,
+    responses(
+        (status = 200, description = "Score table saved", body = ApiResponse<ScoreTableResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Exam type or lesson not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn save_score_table(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path((id, year)): Path<(Uuid, i32)>,
+    Json(request): Json<SaveScoreTableRequest>,
+) -> Result<Json<ApiResponse<ScoreTableResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .score_calculation_service
+        .save_score_table(id, year, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, year = year, "Failed to save score table: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Score table saved successfully",
+    )))
+}
+
+/// Delete the score table of an exam type for a year (requires tests:write)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/exam-types/{id}/score-tables/{year}",
+    params(
+        ("id" = Uuid, Path, description = "Exam type ID"),
+        ("year" = i32, Path, description = "Exam year")
+    ),
+    responses(
+        (status = 200, description = "Score table deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Score table not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_score_table(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path((id, year)): Path<(Uuid, i32)>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .score_calculation_service
+        .delete_score_table(id, year)
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, year = year, "Failed to delete score table: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        MessageResponse {
+            message: "Score table deleted successfully".to_string(),
+        },
+        "Score table deleted successfully",
+    )))
+}
+
+// Score Estimate Handlers
+
+/// Estimate the standard score from own test results and/or subject nets
+#[utoipa::path(
+    post,
+    path = "/api/v1/score-estimates",
+    request_body = EstimateScoreRequest,
+    responses(
+        (status = 200, description = "Score estimated", body = ApiResponse<ScoreEstimateResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Exam type, score table, scoring policy, result or subject not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn estimate_score(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(request): Json<EstimateScoreRequest>,
+) -> Result<Json<ApiResponse<ScoreEstimateResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .score_calculation_service
+        .estimate_score(user.id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to estimate score: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
@@ -0,0 +1,277 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::NaiveDate;
+use std::collections::HashMap;
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use application::dto::StudyCalendarRequest;
+
+use crate::dto::request::{CreateStudyGoalRequest, SetStudyPlanRequest};
+use crate::dto::response::{
+    ApiResponse, MessageResponse, StudyCalendarResponse, StudyGoalResponse, StudyPlanResponse,
+};
+use crate::errors::AppError;
+use crate::extractors::CurrentUser;
+use crate::state::AppState;
+
+// Study Plan Handlers
+
+/// Get the current user's study plan
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/study-plan",
+    responses(
+        (status = 200, description = "Study plan retrieved", body = ApiResponse<StudyPlanResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "No study plan set"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_my_study_plan(
+    State(state): State<AppState>,
+    user: CurrentUser,
+) -> Result<Json<ApiResponse<StudyPlanResponse>>, AppError> {
+    let plan = state
+        .study_plan_service
+        .get_plan(user.id)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to get study plan: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(plan.into())))
+}
+
+/// Set the exam type and date the current user prepares for
+#[utoipa::path(
+    put,
+    path = "/api/v1/me/study-plan",
+    request_body = SetStudyPlanRequest,
+    responses(
+        (status = 200, description = "Study plan saved", body = ApiResponse<StudyPlanResponse>),
+        (status = 400, description = "Validation error or target date in the past"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Exam type not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn set_my_study_plan(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(request): Json<SetStudyPlanRequest>,
+) -> Result<Json<ApiResponse<StudyPlanResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let plan = state
+        .study_plan_service
+        .set_plan(user.id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to set study plan: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        plan.into(),
+        "Study plan saved successfully",
+    )))
+}
+
+/// List the current user's daily study goals
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/study-goals",
+    params(
+        ("include_archived" = Option<bool>, Query, description = "Also list archived goals", example = false)
+    ),
+    responses(
+        (status = 200, description = "Study goals retrieved", body = ApiResponse<Vec<StudyGoalResponse>>),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn list_my_study_goals(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<Vec<StudyGoalResponse>>>, AppError> {
+    let include_archived = params
+        .get("include_archived")
+        .and_then(|s| s.parse::<bool>().ok())
+        .unwrap_or(false);
+
+    let goals = state
+        .study_plan_service
+        .list_goals(user.id, include_archived)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to list study goals: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        goals.into_iter().map(|g| g.into()).collect(),
+    )))
+}
+
+/// Create a daily study goal for the current user
+#[utoipa::path(
+    post,
+    path = "/api/v1/me/study-goals",
+    request_body = CreateStudyGoalRequest,
+    responses(
+        (status = 201, description = "Study goal created", body = ApiResponse<StudyGoalResponse>),
+        (status = 400, description = "Validation error or invalid metric"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Lesson not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn create_my_study_goal(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Json(request): Json<CreateStudyGoalRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<StudyGoalResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let goal = state
+        .study_plan_service
+        .create_goal(user.id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to create study goal: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            goal.into(),
+            "Study goal created successfully",
+        )),
+    ))
+}
+
+/// Archive one of the current user's study goals
+///
+/// The goal stays in the calendar for the days it applied.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/me/study-goals/{id}",
+    params(("id" = Uuid, Path, description = "Study goal ID")),
+    responses(
+        (status = 200, description = "Study goal archived", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Study goal not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn archive_my_study_goal(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .study_plan_service
+        .archive_goal(user.id, id)
+        .await
+        .map_err(|e| {
+            error!(goal_id = ?id, "Failed to archive study goal: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Study goal archived successfully",
+    ))))
+}
+
+/// Get the current user's daily or weekly goal completion calendar and streaks
+#[utoipa::path(
+    get,
+    path = "/api/v1/me/study-calendar",
+    params(
+        ("view" = Option<String>, Query, description = "Entry size: day or week", example = "day"),
+        ("from" = Option<String>, Query, description = "First day (YYYY-MM-DD); weeks start on the Monday before it", example = "2024-03-01"),
+        ("to" = Option<String>, Query, description = "Last day (YYYY-MM-DD), at most today, defaults to today", example = "2024-03-28")
+    ),
+    responses(
+        (status = 200, description = "Study calendar retrieved", body = ApiResponse<StudyCalendarResponse>),
+        (status = 400, description = "Invalid view or date range"),
+        (status = 401, description = "Unauthorized"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_my_study_calendar(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Query(params): Query<HashMap<String, String>>,
+) -> Result<Json<ApiResponse<StudyCalendarResponse>>, AppError> {
+    let request = study_calendar_request(&params)?;
+
+    let calendar = state
+        .study_plan_service
+        .get_calendar(user.id, request)
+        .await
+        .map_err(|e| {
+            error!(user_id = ?user.id, "Failed to get study calendar: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(calendar.into())))
+}
+
+/// Reads the view and date range of a study calendar from query parameters.
+pub(crate) fn study_calendar_request(
+    params: &HashMap<String, String>,
+) -> Result<StudyCalendarRequest, AppError> {
+    let date_param = |name: &str| {
+        params
+            .get(name)
+            .map(|s| {
+                s.parse::<NaiveDate>().map_err(|_| {
+                    AppError::ValidationError(format!(
+                        "Invalid {}: expected a date as YYYY-MM-DD",
+                        name
+                    ))
+                })
+            })
+            .transpose()
+    };
+
+    Ok(StudyCalendarRequest {
+        view: params
+            .get("view")
+            .cloned()
+            .unwrap_or_else(|| "day".to_string()),
+        from: date_param("from")?,
+        to: date_param("to")?,
+    })
+}
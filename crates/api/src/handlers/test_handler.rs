@@ -3,24 +3,30 @@ use axum::{
     http::StatusCode,
     Json,
 };
+use tracing::{error, info};
 use uuid::Uuid;
 use validator::Validate;
-use tracing::error;
 
 use crate::dto::request::{
     CreateExamTypeRequest, CreateLessonRequest, CreatePracticeTestRequest, CreateSubjectRequest,
-    CreateTestBookRequest, SolveTestRequest, UpdateExamTypeRequest, UpdateLessonRequest,
-    UpdatePracticeTestRequest, UpdateSubjectRequest, UpdateTestBookRequest,
+    CreateTestBookRequest, ReplaceQuestionsRequest, SaveExamSessionAnswersRequest,
+    SolveTestRequest, SubmitExamSessionRequest, UpdateExamTypeRequest, UpdateLessonRequest,
+    UpdatePracticeTestRequest, UpdateRetakePolicyRequest, UpdateScoringPolicyRequest,
+    UpdateSubjectRequest, UpdateTestBookRequest,
 };
 use crate::dto::response::{
-    ApiResponse, ExamTypeResponse, LessonResponse, MessageResponse, PaginatedResponse,
-    PracticeTestResponse, PracticeTestWithStatusResponse, SolveTestResponse, SubjectResponse,
-    TestBookResponse, TestBookWithStatsResponse, TestResultResponse,
+    AnswerKeyRevisionResponse, ApiResponse, ExamSessionResponse, ExamTypeResponse, LessonResponse,
+    MessageResponse, PaginatedResponse, PracticeTestResponse, PracticeTestWithStatusResponse,
+    QuestionResponse, RetakePolicyResponse, ScoringPolicyResponse, SolveTestResponse,
+    StudentPracticeTestResponse, SubjectResponse, TestBookResponse, TestBookWithStatsResponse,
+    TestResultDetailResponse, TestResultHistoryResponse, TestResultResponse,
 };
 use crate::errors::AppError;
-use crate::extractors::{CurrentUser, RequireAdmin};
+use crate::extractors::{
+    CurrentUser, OptionalCurrentUser, RequirePermission, TestsRead, TestsWrite,
+};
 use crate::state::AppState;
-use domain::repositories::TestResultRepository;
+use domain::entities::RetakePolicyScope;
 
 /// Helper function to log service errors and convert to AppError
 fn handle_service_error<E: std::fmt::Debug + Into<AppError>>(operation: &str, e: E) -> AppError {
@@ -28,9 +34,32 @@ fn handle_service_error<E: std::fmt::Debug + Into<AppError>>(operation: &str, e:
     e.into()
 }
 
+/// Re-grades the results of a practice test in the background after its answer key changed.
+fn spawn_answer_key_regrade(state: &AppState, practice_test_id: Uuid) {
+    let regrade_service = state.regrade_service.clone();
+    tokio::spawn(async move {
+        match regrade_service
+            .regrade_practice_test(practice_test_id)
+            .await
+        {
+            Ok(summary) => info!(
+                practice_test_id = ?practice_test_id,
+                revision = summary.answer_key_revision,
+                regraded = summary.regraded_count,
+                changed = summary.changed_count,
+                skipped = summary.skipped_result_ids.len(),
+                "Answer key re-grade completed"
+            ),
+            Err(e) => {
+                error!(practice_test_id = ?practice_test_id, "Answer key re-grade failed: {:?}", e)
+            }
+        }
+    });
+}
+
 // Lesson Handlers
 
-/// Create a new lesson (Admin only)
+/// Create a new lesson (requires tests:write)
 #[utoipa::path(
     post,
     path = "/api/v1/admin/lessons",
@@ -39,7 +68,7 @@ fn handle_service_error<E: std::fmt::Debug + Into<AppError>>(operation: &str, e:
         (status = 201, description = "Lesson created successfully", body = ApiResponse<LessonResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -48,10 +77,12 @@ fn handle_service_error<E: std::fmt::Debug + Into<AppError>>(operation: &str, e:
 )]
 pub async fn create_lesson(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Json(request): Json<CreateLessonRequest>,
 ) -> Result<(StatusCode, Json<ApiResponse<LessonResponse>>), AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -68,7 +99,7 @@ pub async fn create_lesson(
     ))
 }
 
-/// Get lesson by ID (Admin only)
+/// Get lesson by ID (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/lessons/{id}",
@@ -76,7 +107,7 @@ pub async fn create_lesson(
     responses(
         (status = 200, description = "Lesson retrieved", body = ApiResponse<LessonResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
         (status = 404, description = "Lesson not found"),
     ),
     security(
@@ -86,7 +117,7 @@ pub async fn create_lesson(
 )]
 pub async fn get_lesson(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<LessonResponse>>, AppError> {
     let result = state
@@ -124,14 +155,14 @@ pub async fn list_lessons(
     )))
 }
 
-/// List all lessons (Admin only)
+/// List all lessons (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/lessons",
     responses(
         (status = 200, description = "Lessons retrieved", body = ApiResponse<Vec<LessonResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -140,7 +171,7 @@ pub async fn list_lessons(
 )]
 pub async fn list_admin_lessons(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
 ) -> Result<Json<ApiResponse<Vec<LessonResponse>>>, AppError> {
     let results = state
         .test_management_service
@@ -153,7 +184,7 @@ pub async fn list_admin_lessons(
     )))
 }
 
-/// Update lesson (Admin only)
+/// Update lesson (requires tests:write)
 #[utoipa::path(
     put,
     path = "/api/v1/admin/lessons/{id}",
@@ -162,7 +193,7 @@ pub async fn list_admin_lessons(
     responses(
         (status = 200, description = "Lesson updated", body = ApiResponse<LessonResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Lesson not found"),
     ),
     security(
@@ -172,11 +203,13 @@ pub async fn list_admin_lessons(
 )]
 pub async fn update_lesson(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateLessonRequest>,
 ) -> Result<Json<ApiResponse<LessonResponse>>, AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -193,7 +226,7 @@ pub async fn update_lesson(
     )))
 }
 
-/// Delete lesson (Admin only)
+/// Delete lesson (requires tests:write)
 #[utoipa::path(
     delete,
     path = "/api/v1/admin/lessons/{id}",
@@ -201,7 +234,7 @@ pub async fn update_lesson(
     responses(
         (status = 200, description = "Lesson deleted", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Lesson not found"),
     ),
     security(
@@ -211,7 +244,7 @@ pub async fn update_lesson(
 )]
 pub async fn delete_lesson(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     state
@@ -233,7 +266,7 @@ pub async fn delete_lesson(
 
 // ExamType Handlers
 
-/// Create a new exam type (Admin only)
+/// Create a new exam type (requires tests:write)
 #[utoipa::path(
     post,
     path = "/api/v1/admin/exam-types",
@@ -242,7 +275,7 @@ pub async fn delete_lesson(
         (status = 201, description = "Exam type created successfully", body = ApiResponse<ExamTypeResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -251,10 +284,12 @@ pub async fn delete_lesson(
 )]
 pub async fn create_exam_type(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Json(request): Json<CreateExamTypeRequest>,
 ) -> Result<(StatusCode, Json<ApiResponse<ExamTypeResponse>>), AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -279,7 +314,7 @@ pub async fn create_exam_type(
     responses(
         (status = 200, description = "Exam type retrieved", body = ApiResponse<ExamTypeResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
         (status = 404, description = "Exam type not found"),
     ),
     security(
@@ -289,7 +324,7 @@ pub async fn create_exam_type(
 )]
 pub async fn get_exam_type(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<ExamTypeResponse>>, AppError> {
     let result = state
@@ -327,14 +362,14 @@ pub async fn list_exam_types(
     )))
 }
 
-/// List all exam types (Admin only)
+/// List all exam types (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/exam-types",
     responses(
         (status = 200, description = "Exam types retrieved", body = ApiResponse<Vec<ExamTypeResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -343,7 +378,7 @@ pub async fn list_exam_types(
 )]
 pub async fn list_admin_exam_types(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
 ) -> Result<Json<ApiResponse<Vec<ExamTypeResponse>>>, AppError> {
     let results = state
         .test_management_service
@@ -356,7 +391,7 @@ pub async fn list_admin_exam_types(
     )))
 }
 
-/// Update exam type (Admin only)
+/// Update exam type (requires tests:write)
 #[utoipa::path(
     put,
     path = "/api/v1/admin/exam-types/{id}",
@@ -365,7 +400,7 @@ pub async fn list_admin_exam_types(
     responses(
         (status = 200, description = "Exam type updated", body = ApiResponse<ExamTypeResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Exam type not found"),
     ),
     security(
@@ -375,11 +410,13 @@ pub async fn list_admin_exam_types(
 )]
 pub async fn update_exam_type(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateExamTypeRequest>,
 ) -> Result<Json<ApiResponse<ExamTypeResponse>>, AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -396,7 +433,7 @@ pub async fn update_exam_type(
     )))
 }
 
-/// Delete exam type (Admin only)
+/// Delete exam type (requires tests:write)
 #[utoipa::path(
     delete,
     path = "/api/v1/admin/exam-types/{id}",
@@ -404,7 +441,7 @@ pub async fn update_exam_type(
     responses(
         (status = 200, description = "Exam type deleted", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Exam type not found"),
     ),
     security(
@@ -414,7 +451,7 @@ pub async fn update_exam_type(
 )]
 pub async fn delete_exam_type(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     state
@@ -434,9 +471,352 @@ pub async fn delete_exam_type(
     )))
 }
 
+// ScoringPolicy Handlers
+
+/// Get the current scoring policy of an exam type (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/exam-types/{id}/scoring-policy",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    responses(
+        (status = 200, description = "Scoring policy retrieved", body = ApiResponse<ScoringPolicyResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Scoring policy not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_scoring_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ScoringPolicyResponse>>, AppError> {
+    let result = state
+        .test_management_service
+        .get_scoring_policy(id)
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to get scoring policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// List all scoring policy versions of an exam type, newest first (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/exam-types/{id}/scoring-policies",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    responses(
+        (status = 200, description = "Scoring policies retrieved", body = ApiResponse<Vec<ScoringPolicyResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Exam type not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_scoring_policies(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<ScoringPolicyResponse>>>, AppError> {
+    let results = state
+        .test_management_service
+        .list_scoring_policies(id)
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to list scoring policies: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(
+        results.into_iter().map(|r| r.into()).collect(),
+    )))
+}
+
+/// Change the scoring policy of an exam type by creating a new version (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/exam-types/{id}/scoring-policy",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    request_body = UpdateScoringPolicyRequest,
+    responses(
+        (status = 200, description = "Scoring policy updated", body = ApiResponse<ScoringPolicyResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Exam type not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn update_scoring_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateScoringPolicyRequest>,
+) -> Result<Json<ApiResponse<ScoringPolicyResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .test_management_service
+        .update_scoring_policy(id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to update scoring policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Scoring policy updated successfully",
+    )))
+}
+
+// RetakePolicy Handlers
+
+/// Get the retake policy of an exam type (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/exam-types/{id}/retake-policy",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    responses(
+        (status = 200, description = "Retake policy retrieved", body = ApiResponse<RetakePolicyResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Exam type or retake policy not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_exam_type_retake_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<RetakePolicyResponse>>, AppError> {
+    let result = state
+        .retake_policy_service
+        .get_retake_policy(RetakePolicyScope::ExamType(id))
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to get retake policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Set the retake policy of an exam type (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/exam-types/{id}/retake-policy",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    request_body = UpdateRetakePolicyRequest,
+    responses(
+        (status = 200, description = "Retake policy saved", body = ApiResponse<RetakePolicyResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Exam type not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn update_exam_type_retake_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateRetakePolicyRequest>,
+) -> Result<Json<ApiResponse<RetakePolicyResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .retake_policy_service
+        .save_retake_policy(RetakePolicyScope::ExamType(id), request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to save retake policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Retake policy saved successfully",
+    )))
+}
+
+/// Remove the retake policy of an exam type (requires tests:write)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/exam-types/{id}/retake-policy",
+    params(("id" = Uuid, Path, description = "Exam type ID")),
+    responses(
+        (status = 200, description = "Retake policy deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Retake policy not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_exam_type_retake_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .retake_policy_service
+        .delete_retake_policy(RetakePolicyScope::ExamType(id))
+        .await
+        .map_err(|e| {
+            error!(exam_type_id = ?id, "Failed to delete retake policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        MessageResponse {
+            message: "Retake policy deleted successfully".to_string(),
+        },
+        "Retake policy deleted successfully",
+    )))
+}
+
+/// Get the retake policy of a test book, overriding its exam type's policy (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/test-books/{id}/retake-policy",
+    params(("id" = Uuid, Path, description = "Test book ID")),
+    responses(
+        (status = 200, description = "Retake policy retrieved", body = ApiResponse<RetakePolicyResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Test book or retake policy not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_test_book_retake_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<RetakePolicyResponse>>, AppError> {
+    let result = state
+        .retake_policy_service
+        .get_retake_policy(RetakePolicyScope::TestBook(id))
+        .await
+        .map_err(|e| {
+            error!(test_book_id = ?id, "Failed to get retake policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Set the retake policy of a test book, overriding its exam type's policy (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/test-books/{id}/retake-policy",
+    params(("id" = Uuid, Path, description = "Test book ID")),
+    request_body = UpdateRetakePolicyRequest,
+    responses(
+        (status = 200, description = "Retake policy saved", body = ApiResponse<RetakePolicyResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Test book not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn update_test_book_retake_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateRetakePolicyRequest>,
+) -> Result<Json<ApiResponse<RetakePolicyResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .retake_policy_service
+        .save_retake_policy(RetakePolicyScope::TestBook(id), request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(test_book_id = ?id, "Failed to save retake policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Retake policy saved successfully",
+    )))
+}
+
+/// Remove the retake policy of a test book, overriding its exam type's policy (requires tests:write)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/test-books/{id}/retake-policy",
+    params(("id" = Uuid, Path, description = "Test book ID")),
+    responses(
+        (status = 200, description = "Retake policy deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Retake policy not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_test_book_retake_policy(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state
+        .retake_policy_service
+        .delete_retake_policy(RetakePolicyScope::TestBook(id))
+        .await
+        .map_err(|e| {
+            error!(test_book_id = ?id, "Failed to delete retake policy: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        MessageResponse {
+            message: "Retake policy deleted successfully".to_string(),
+        },
+        "Retake policy deleted successfully",
+    )))
+}
+
 // Subject Handlers
 
-/// Create a new subject (Admin only)
+/// Create a new subject (requires tests:write)
 #[utoipa::path(
     post,
     path = "/api/v1/admin/subjects",
@@ -445,7 +825,7 @@ pub async fn delete_exam_type(
         (status = 201, description = "Subject created successfully", body = ApiResponse<SubjectResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -454,10 +834,12 @@ pub async fn delete_exam_type(
 )]
 pub async fn create_subject(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Json(request): Json<CreateSubjectRequest>,
 ) -> Result<(StatusCode, Json<ApiResponse<SubjectResponse>>), AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -482,7 +864,7 @@ pub async fn create_subject(
     responses(
         (status = 200, description = "Subject retrieved", body = ApiResponse<SubjectResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
         (status = 404, description = "Subject not found"),
     ),
     security(
@@ -492,7 +874,7 @@ pub async fn create_subject(
 )]
 pub async fn get_subject(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<SubjectResponse>>, AppError> {
     let result = state
@@ -533,29 +915,23 @@ pub async fn list_subjects(
 
     let results = match (lesson_id, exam_type_id) {
         // Both lesson_id and exam_type_id provided - use cascading filter
-        (Some(lesson_id), Some(exam_type_id)) => {
-            state
-                .test_management_service
-                .list_subjects_by_lesson_and_exam_type(lesson_id, exam_type_id)
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
+        (Some(lesson_id), Some(exam_type_id)) => state
+            .test_management_service
+            .list_subjects_by_lesson_and_exam_type(lesson_id, exam_type_id)
+            .await
+            .map_err(|e| handle_service_error("service_call", e))?,
         // Only exam_type_id provided
-        (None, Some(exam_type_id)) => {
-        state
+        (None, Some(exam_type_id)) => state
             .test_management_service
             .list_subjects_by_exam_type(exam_type_id)
             .await
-            .map_err(|e| handle_service_error("service_call", e))?
-        }
+            .map_err(|e| handle_service_error("service_call", e))?,
         // No filters or only lesson_id (list all in this case)
-        _ => {
-        state
+        _ => state
             .test_management_service
             .list_all_subjects()
             .await
-            .map_err(|e| handle_service_error("service_call", e))?
-        }
+            .map_err(|e| handle_service_error("service_call", e))?,
     };
 
     Ok(Json(ApiResponse::success(
@@ -563,7 +939,7 @@ pub async fn list_subjects(
     )))
 }
 
-/// List all subjects (Admin only)
+/// List all subjects (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/subjects",
@@ -574,7 +950,7 @@ pub async fn list_subjects(
     responses(
         (status = 200, description = "Subjects retrieved", body = ApiResponse<Vec<SubjectResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -583,7 +959,7 @@ pub async fn list_subjects(
 )]
 pub async fn list_admin_subjects(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<Vec<SubjectResponse>>>, AppError> {
     let exam_type_id = params
@@ -594,27 +970,21 @@ pub async fn list_admin_subjects(
         .and_then(|s| Uuid::parse_str(s).ok());
 
     let results = match (lesson_id, exam_type_id) {
-        (Some(lesson_id), Some(exam_type_id)) => {
-            state
-                .test_management_service
-                .list_subjects_by_lesson_and_exam_type(lesson_id, exam_type_id)
-                .await
-                .map_err(|e| handle_service_error("list_admin_subjects", e))?
-        }
-        (None, Some(exam_type_id)) => {
-            state
-                .test_management_service
-                .list_subjects_by_exam_type(exam_type_id)
-                .await
-                .map_err(|e| handle_service_error("list_admin_subjects", e))?
-        }
-        _ => {
-            state
-                .test_management_service
-                .list_all_subjects()
-                .await
-                .map_err(|e| handle_service_error("list_admin_subjects", e))?
-        }
+        (Some(lesson_id), Some(exam_type_id)) => state
+            .test_management_service
+            .list_subjects_by_lesson_and_exam_type(lesson_id, exam_type_id)
+            .await
+            .map_err(|e| handle_service_error("list_admin_subjects", e))?,
+        (None, Some(exam_type_id)) => state
+            .test_management_service
+            .list_subjects_by_exam_type(exam_type_id)
+            .await
+            .map_err(|e| handle_service_error("list_admin_subjects", e))?,
+        _ => state
+            .test_management_service
+            .list_all_subjects()
+            .await
+            .map_err(|e| handle_service_error("list_admin_subjects", e))?,
     };
 
     Ok(Json(ApiResponse::success(
@@ -622,7 +992,7 @@ pub async fn list_admin_subjects(
     )))
 }
 
-/// Update subject (Admin only)
+/// Update subject (requires tests:write)
 #[utoipa::path(
     put,
     path = "/api/v1/admin/subjects/{id}",
@@ -631,7 +1001,7 @@ pub async fn list_admin_subjects(
     responses(
         (status = 200, description = "Subject updated", body = ApiResponse<SubjectResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Subject not found"),
     ),
     security(
@@ -641,11 +1011,13 @@ pub async fn list_admin_subjects(
 )]
 pub async fn update_subject(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateSubjectRequest>,
 ) -> Result<Json<ApiResponse<SubjectResponse>>, AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -659,7 +1031,7 @@ pub async fn update_subject(
     )))
 }
 
-/// Delete subject (Admin only)
+/// Delete subject (requires tests:write)
 #[utoipa::path(
     delete,
     path = "/api/v1/admin/subjects/{id}",
@@ -667,7 +1039,7 @@ pub async fn update_subject(
     responses(
         (status = 200, description = "Subject deleted", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Subject not found"),
     ),
     security(
@@ -677,7 +1049,7 @@ pub async fn update_subject(
 )]
 pub async fn delete_subject(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     state
@@ -696,7 +1068,7 @@ pub async fn delete_subject(
 
 // TestBook Handlers
 
-/// Create a new test book (Admin only)
+/// Create a new test book (requires tests:write)
 #[utoipa::path(
     post,
     path = "/api/v1/admin/test-books",
@@ -705,7 +1077,7 @@ pub async fn delete_subject(
         (status = 201, description = "Test book created successfully", body = ApiResponse<TestBookResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -714,10 +1086,12 @@ pub async fn delete_subject(
 )]
 pub async fn create_test_book(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Json(request): Json<CreateTestBookRequest>,
 ) -> Result<(StatusCode, Json<ApiResponse<TestBookResponse>>), AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -742,7 +1116,7 @@ pub async fn create_test_book(
     responses(
         (status = 200, description = "Test book retrieved", body = ApiResponse<TestBookResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
         (status = 404, description = "Test book not found"),
     ),
     security(
@@ -752,7 +1126,7 @@ pub async fn create_test_book(
 )]
 pub async fn get_test_book(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<TestBookResponse>>, AppError> {
     let result = state
@@ -820,29 +1194,23 @@ pub async fn list_test_books(
 
     let results = match (subject_id, exam_type_id, lesson_id) {
         // If subject_id is provided, use subject filter (backward compatibility)
-        (Some(subject_id), _, _) => {
-            state
-                .test_management_service
-                .list_test_books_by_subject(subject_id)
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
+        (Some(subject_id), _, _) => state
+            .test_management_service
+            .list_test_books_by_subject(subject_id)
+            .await
+            .map_err(|e| handle_service_error("service_call", e))?,
         // If both exam_type_id and lesson_id are provided, use combined filter
-        (None, Some(exam_type_id), Some(lesson_id)) => {
-            state
-                .test_management_service
-                .list_test_books_by_exam_type_and_lesson(exam_type_id, lesson_id)
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
+        (None, Some(exam_type_id), Some(lesson_id)) => state
+            .test_management_service
+            .list_test_books_by_exam_type_and_lesson(exam_type_id, lesson_id)
+            .await
+            .map_err(|e| handle_service_error("service_call", e))?,
         // Otherwise, list all
-        _ => {
-            state
-                .test_management_service
-                .list_all_test_books()
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
+        _ => state
+            .test_management_service
+            .list_all_test_books()
+            .await
+            .map_err(|e| handle_service_error("service_call", e))?,
     };
 
     Ok(Json(ApiResponse::success(
@@ -850,7 +1218,7 @@ pub async fn list_test_books(
     )))
 }
 
-/// List all test books (Admin only)
+/// List all test books (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/test-books",
@@ -858,7 +1226,7 @@ pub async fn list_test_books(
     responses(
         (status = 200, description = "Test books retrieved", body = ApiResponse<Vec<TestBookResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -867,7 +1235,7 @@ pub async fn list_test_books(
 )]
 pub async fn list_admin_test_books(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<Vec<TestBookResponse>>>, AppError> {
     let subject_id = params
@@ -893,7 +1261,7 @@ pub async fn list_admin_test_books(
     )))
 }
 
-/// Update test book (Admin only)
+/// Update test book (requires tests:write)
 #[utoipa::path(
     put,
     path = "/api/v1/admin/test-books/{id}",
@@ -902,7 +1270,7 @@ pub async fn list_admin_test_books(
     responses(
         (status = 200, description = "Test book updated", body = ApiResponse<TestBookResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Test book not found"),
     ),
     security(
@@ -912,11 +1280,13 @@ pub async fn list_admin_test_books(
 )]
 pub async fn update_test_book(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateTestBookRequest>,
 ) -> Result<Json<ApiResponse<TestBookResponse>>, AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -930,7 +1300,7 @@ pub async fn update_test_book(
     )))
 }
 
-/// Delete test book (Admin only)
+/// Delete test book (requires tests:write)
 #[utoipa::path(
     delete,
     path = "/api/v1/admin/test-books/{id}",
@@ -938,7 +1308,7 @@ pub async fn update_test_book(
     responses(
         (status = 200, description = "Test book deleted", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Test book not found"),
     ),
     security(
@@ -948,7 +1318,7 @@ pub async fn update_test_book(
 )]
 pub async fn delete_test_book(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     state
@@ -967,7 +1337,7 @@ pub async fn delete_test_book(
 
 // PracticeTest Handlers
 
-/// Create a new practice test (Admin only)
+/// Create a new practice test (requires tests:write)
 #[utoipa::path(
     post,
     path = "/api/v1/admin/practice-tests",
@@ -976,7 +1346,7 @@ pub async fn delete_test_book(
         (status = 201, description = "Practice test created successfully", body = ApiResponse<PracticeTestResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -985,10 +1355,12 @@ pub async fn delete_test_book(
 )]
 pub async fn create_practice_test(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Json(request): Json<CreatePracticeTestRequest>,
 ) -> Result<(StatusCode, Json<ApiResponse<PracticeTestResponse>>), AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_management_service
@@ -1006,30 +1378,34 @@ pub async fn create_practice_test(
 }
 
 /// Get practice test by ID (Public)
+///
+/// The answer key is only included once the caller has submitted the test,
+/// unless the exam type reveals its answer keys.
 #[utoipa::path(
     get,
     path = "/api/v1/practice-tests/{id}",
     params(("id" = Uuid, Path, description = "Practice test ID")),
     responses(
-        (status = 200, description = "Practice test retrieved", body = ApiResponse<PracticeTestResponse>),
+        (status = 200, description = "Practice test retrieved", body = ApiResponse<StudentPracticeTestResponse>),
         (status = 404, description = "Practice test not found"),
     ),
     tag = "tests"
 )]
 pub async fn get_practice_test_public(
     State(state): State<AppState>,
+    OptionalCurrentUser(user): OptionalCurrentUser,
     Path(id): Path<Uuid>,
-) -> Result<Json<ApiResponse<PracticeTestResponse>>, AppError> {
+) -> Result<Json<ApiResponse<StudentPracticeTestResponse>>, AppError> {
     let result = state
-        .test_management_service
-        .get_practice_test(id)
+        .test_solving_service
+        .get_practice_test(user.map(|u| u.id), id)
         .await
         .map_err(|e| handle_service_error("service_call", e))?;
 
     Ok(Json(ApiResponse::success(result.into())))
 }
 
-/// Get practice test by ID (Admin only)
+/// Get practice test by ID (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/practice-tests/{id}",
@@ -1037,7 +1413,7 @@ pub async fn get_practice_test_public(
     responses(
         (status = 200, description = "Practice test retrieved", body = ApiResponse<PracticeTestResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
         (status = 404, description = "Practice test not found"),
     ),
     security(
@@ -1047,7 +1423,7 @@ pub async fn get_practice_test_public(
 )]
 pub async fn get_practice_test(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<PracticeTestResponse>>, AppError> {
     let result = state
@@ -1065,31 +1441,24 @@ pub async fn get_practice_test(
     path = "/api/v1/practice-tests",
     params(("test_book_id" = Option<Uuid>, Query, description = "Filter by test book ID")),
     responses(
-        (status = 200, description = "Practice tests retrieved", body = ApiResponse<Vec<PracticeTestResponse>>),
+        (status = 200, description = "Practice tests retrieved", body = ApiResponse<Vec<StudentPracticeTestResponse>>),
     ),
     tag = "tests"
 )]
 pub async fn list_practice_tests(
     State(state): State<AppState>,
+    OptionalCurrentUser(user): OptionalCurrentUser,
     Query(params): Query<std::collections::HashMap<String, String>>,
-) -> Result<Json<ApiResponse<Vec<PracticeTestResponse>>>, AppError> {
+) -> Result<Json<ApiResponse<Vec<StudentPracticeTestResponse>>>, AppError> {
     let test_book_id = params
         .get("test_book_id")
         .and_then(|s| Uuid::parse_str(s).ok());
 
-    let results = if let Some(test_book_id) = test_book_id {
-        state
-            .test_management_service
-            .list_practice_tests_by_test_book(test_book_id)
-            .await
-            .map_err(|e| handle_service_error("service_call", e))?
-    } else {
-        state
-            .test_management_service
-            .list_all_practice_tests()
-            .await
-            .map_err(|e| handle_service_error("service_call", e))?
-    };
+    let results = state
+        .test_solving_service
+        .list_practice_tests(user.map(|u| u.id), test_book_id)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
 
     Ok(Json(ApiResponse::success(
         results.into_iter().map(|r| r.into()).collect(),
@@ -1109,15 +1478,16 @@ pub async fn list_practice_tests(
 )]
 pub async fn list_practice_tests_grouped_by_subject(
     State(state): State<AppState>,
+    OptionalCurrentUser(user): OptionalCurrentUser,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<serde_json::Value>>, AppError> {
     let grouped = state
-        .test_management_service
-        .list_practice_tests_grouped_by_subject(id)
+        .test_solving_service
+        .list_practice_tests_grouped_by_subject(user.map(|u| u.id), id)
         .await
         .map_err(|e| handle_service_error("service_call", e))?;
 
-    // Convert HashMap<Uuid, Vec<PracticeTestResponse>> to HashMap<String, Vec<PracticeTestResponse>>
+    // Convert HashMap<Uuid, Vec<StudentPracticeTestResponse>> to HashMap<String, Vec<StudentPracticeTestResponse>>
     // for JSON serialization (Uuid keys need to be strings in JSON)
     let mut json_map = serde_json::Map::new();
     for (subject_id, tests) in grouped {
@@ -1126,16 +1496,18 @@ pub async fn list_practice_tests_grouped_by_subject(
             tests
                 .into_iter()
                 .map(|r| r.into())
-                .collect::<Vec<crate::dto::response::PracticeTestResponse>>(),
+                .collect::<Vec<StudentPracticeTestResponse>>(),
         )
         .map_err(|_| AppError::InternalServerError)?;
         json_map.insert(key, value);
     }
 
-    Ok(Json(ApiResponse::success(serde_json::Value::Object(json_map))))
+    Ok(Json(ApiResponse::success(serde_json::Value::Object(
+        json_map,
+    ))))
 }
 
-/// List all practice tests (Admin only)
+/// List all practice tests (requires tests:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/practice-tests",
@@ -1143,7 +1515,7 @@ pub async fn list_practice_tests_grouped_by_subject(
     responses(
         (status = 200, description = "Practice tests retrieved", body = ApiResponse<Vec<PracticeTestResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -1152,7 +1524,7 @@ pub async fn list_practice_tests_grouped_by_subject(
 )]
 pub async fn list_admin_practice_tests(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsRead>,
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<Vec<PracticeTestResponse>>>, AppError> {
     let test_book_id = params
@@ -1178,7 +1550,7 @@ pub async fn list_admin_practice_tests(
     )))
 }
 
-/// Update practice test (Admin only)
+/// Update practice test (requires tests:write)
 #[utoipa::path(
     put,
     path = "/api/v1/admin/practice-tests/{id}",
@@ -1186,8 +1558,9 @@ pub async fn list_admin_practice_tests(
     request_body = UpdatePracticeTestRequest,
     responses(
         (status = 200, description = "Practice test updated", body = ApiResponse<PracticeTestResponse>),
+        (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Practice test not found"),
     ),
     security(
@@ -1197,11 +1570,14 @@ pub async fn list_admin_practice_tests(
 )]
 pub async fn update_practice_test(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdatePracticeTestRequest>,
 ) -> Result<Json<ApiResponse<PracticeTestResponse>>, AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+    let answer_key_changed = request.answer_key.is_some();
 
     let result = state
         .test_management_service
@@ -1209,13 +1585,126 @@ pub async fn update_practice_test(
         .await
         .map_err(|e| handle_service_error("service_call", e))?;
 
+    // Re-grade existing results against the corrected key in the background
+    if answer_key_changed {
+        spawn_answer_key_regrade(&state, id);
+    }
+
     Ok(Json(ApiResponse::success_with_message(
         result.into(),
         "Practice test updated successfully",
     )))
 }
 
-/// Delete practice test (Admin only)
+/// List the answer key revisions of a practice test, newest first (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/practice-tests/{id}/answer-key-revisions",
+    params(("id" = Uuid, Path, description = "Practice test ID")),
+    responses(
+        (status = 200, description = "Answer key revisions retrieved", body = ApiResponse<Vec<AnswerKeyRevisionResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_answer_key_revisions(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<AnswerKeyRevisionResponse>>>, AppError> {
+    let results = state
+        .test_management_service
+        .list_answer_key_revisions(id)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok(Json(ApiResponse::success(
+        results.into_iter().map(|r| r.into()).collect(),
+    )))
+}
+
+/// List the questions of a practice test in position order (requires tests:read)
+///
+/// Practice tests that only have a plain answer key return an empty list.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/practice-tests/{id}/questions",
+    params(("id" = Uuid, Path, description = "Practice test ID")),
+    responses(
+        (status = 200, description = "Questions retrieved", body = ApiResponse<Vec<QuestionResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn list_questions(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<QuestionResponse>>>, AppError> {
+    let results = state
+        .test_management_service
+        .list_questions(id)
+        .await
+        .map_err(|e| handle_service_error("list_questions", e))?;
+
+    Ok(Json(ApiResponse::success(
+        results.into_iter().map(|r| r.into()).collect(),
+    )))
+}
+
+/// Replace the questions of a practice test (requires tests:write)
+///
+/// The practice test's answer key and question count are derived from the questions.
+/// If the answer key changes, existing results are re-graded in the background.
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/practice-tests/{id}/questions",
+    params(("id" = Uuid, Path, description = "Practice test ID")),
+    request_body = ReplaceQuestionsRequest,
+    responses(
+        (status = 200, description = "Questions replaced", body = ApiResponse<Vec<QuestionResponse>>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn replace_questions(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ReplaceQuestionsRequest>,
+) -> Result<Json<ApiResponse<Vec<QuestionResponse>>>, AppError> {
+    let results = state
+        .test_management_service
+        .replace_questions(id, request.into_app_request())
+        .await
+        .map_err(|e| handle_service_error("replace_questions", e))?;
+
+    // No-op unless the derived answer key started a new revision
+    spawn_answer_key_regrade(&state, id);
+
+    Ok(Json(ApiResponse::success_with_message(
+        results.into_iter().map(|r| r.into()).collect(),
+        "Questions updated successfully",
+    )))
+}
+
+/// Delete practice test (requires tests:write)
 #[utoipa::path(
     delete,
     path = "/api/v1/admin/practice-tests/{id}",
@@ -1223,7 +1712,7 @@ pub async fn update_practice_test(
     responses(
         (status = 200, description = "Practice test deleted", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
         (status = 404, description = "Practice test not found"),
     ),
     security(
@@ -1233,7 +1722,7 @@ pub async fn update_practice_test(
 )]
 pub async fn delete_practice_test(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<TestsWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     state
@@ -1272,7 +1761,9 @@ pub async fn solve_test(
     Path(practice_test_id): Path<Uuid>,
     Json(request): Json<SolveTestRequest>,
 ) -> Result<Json<ApiResponse<SolveTestResponse>>, AppError> {
-    request.validate().map_err(|e| AppError::ValidationError(e.to_string()))?;
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
 
     let result = state
         .test_solving_service
@@ -1286,15 +1777,162 @@ pub async fn solve_test(
     )))
 }
 
+// Exam Session Handlers
+
+/// Start an exam session for a practice test, or resume the one in progress
+#[utoipa::path(
+    post,
+    path = "/api/v1/tests/{id}/sessions",
+    params(("id" = Uuid, Path, description = "Practice test ID")),
+    responses(
+        (status = 201, description = "Exam session started", body = ApiResponse<ExamSessionResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Cannot retake test yet or maximum attempts reached"),
+        (status = 404, description = "Practice test not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn start_exam_session(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(practice_test_id): Path<Uuid>,
+) -> Result<(StatusCode, Json<ApiResponse<ExamSessionResponse>>), AppError> {
+    let result = state
+        .test_solving_service
+        .start_exam_session(user.id, practice_test_id)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Exam session started",
+        )),
+    ))
+}
+
+/// Get an exam session, auto-submitting it if its time has run out
+#[utoipa::path(
+    get,
+    path = "/api/v1/exam-sessions/{id}",
+    params(("id" = Uuid, Path, description = "Exam session ID")),
+    responses(
+        (status = 200, description = "Exam session retrieved", body = ApiResponse<ExamSessionResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Exam session not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn get_exam_session(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(session_id): Path<Uuid>,
+) -> Result<Json<ApiResponse<ExamSessionResponse>>, AppError> {
+    let result = state
+        .test_solving_service
+        .get_exam_session(user.id, session_id)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Autosave the answers of an exam session
+#[utoipa::path(
+    put,
+    path = "/api/v1/exam-sessions/{id}/answers",
+    params(("id" = Uuid, Path, description = "Exam session ID")),
+    request_body = SaveExamSessionAnswersRequest,
+    responses(
+        (status = 200, description = "Answers saved", body = ApiResponse<ExamSessionResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Exam session not found"),
+        (status = 409, description = "Exam session is no longer in progress"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn save_exam_session_answers(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(session_id): Path<Uuid>,
+    Json(request): Json<SaveExamSessionAnswersRequest>,
+) -> Result<Json<ApiResponse<ExamSessionResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .test_solving_service
+        .save_exam_session_answers(user.id, session_id, request.into_app_request())
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Submit an exam session and grade it
+#[utoipa::path(
+    post,
+    path = "/api/v1/exam-sessions/{id}/submit",
+    params(("id" = Uuid, Path, description = "Exam session ID")),
+    request_body = SubmitExamSessionRequest,
+    responses(
+        (status = 200, description = "Exam session submitted", body = ApiResponse<SolveTestResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Exam session not found"),
+        (status = 409, description = "Exam session is no longer in progress"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "tests"
+)]
+pub async fn submit_exam_session(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(session_id): Path<Uuid>,
+    Json(request): Json<SubmitExamSessionRequest>,
+) -> Result<Json<ApiResponse<SolveTestResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .test_solving_service
+        .submit_exam_session(user.id, session_id, request.into_app_request())
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Exam session submitted successfully",
+    )))
+}
+
 // Result Handlers
 
-/// Get test result by ID
+/// Get test result by ID with its per-question breakdown
 #[utoipa::path(
     get,
     path = "/api/v1/my-results/{id}",
-    params(("id" = Uuid, Path, description = "Test result ID")),
+    params(
+        ("id" = Uuid, Path, description = "Test result ID"),
+        ("only_wrong" = Option<bool>, Query, description = "Only include wrongly answered questions in the breakdown", example = false)
+    ),
     responses(
-        (status = 200, description = "Test result retrieved", body = ApiResponse<TestResultResponse>),
+        (status = 200, description = "Test result retrieved", body = ApiResponse<TestResultDetailResponse>),
         (status = 404, description = "Test result not found"),
     ),
     tag = "tests",
@@ -1304,7 +1942,39 @@ pub async fn get_result(
     State(state): State<AppState>,
     user: CurrentUser,
     Path(id): Path<Uuid>,
-) -> Result<Json<ApiResponse<TestResultResponse>>, AppError> {
+    Query(params): Query<std::collections::HashMap<String, String>>,
+) -> Result<Json<ApiResponse<TestResultDetailResponse>>, AppError> {
+    let only_wrong = params
+        .get("only_wrong")
+        .and_then(|s| s.parse::<bool>().ok())
+        .unwrap_or(false);
+
+    let result = state
+        .result_service
+        .get_result_detail(user.id, id, only_wrong)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Get the previous scores of a re-graded test result
+#[utoipa::path(
+    get,
+    path = "/api/v1/my-results/{id}/history",
+    params(("id" = Uuid, Path, description = "Test result ID")),
+    responses(
+        (status = 200, description = "Test result history retrieved", body = ApiResponse<Vec<TestResultHistoryResponse>>),
+        (status = 404, description = "Test result not found"),
+    ),
+    tag = "tests",
+    security(("bearer_auth" = []))
+)]
+pub async fn get_result_history(
+    State(state): State<AppState>,
+    user: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TestResultHistoryResponse>>>, AppError> {
     let result = state
         .result_service
         .get_result(id)
@@ -1316,7 +1986,15 @@ pub async fn get_result(
         return Err(AppError::NotFound("Test result not found".to_string()));
     }
 
-    Ok(Json(ApiResponse::success(result.into())))
+    let history = state
+        .result_service
+        .get_result_history(id)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?;
+
+    Ok(Json(ApiResponse::success(
+        history.into_iter().map(|h| h.into()).collect(),
+    )))
 }
 
 /// List user's test results
@@ -1396,94 +2074,14 @@ pub async fn list_test_books_with_stats(
         .and_then(|s| Uuid::parse_str(s).ok());
     let search = params.get("search").cloned();
 
-    // Get test books based on filters
-    let test_books = match (exam_type_id, lesson_id) {
-        (Some(exam_type_id), Some(lesson_id)) => {
-            state
-                .test_management_service
-                .list_test_books_by_exam_type_and_lesson(exam_type_id, lesson_id)
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
-        (Some(exam_type_id), None) => {
-            state
-                .test_management_service
-                .list_test_books_by_exam_type(exam_type_id)
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
-        _ => {
-            state
-                .test_management_service
-                .list_all_test_books()
-                .await
-                .map_err(|e| handle_service_error("service_call", e))?
-        }
-    };
-
-    // Filter by search if provided
-    let filtered_books: Vec<_> = if let Some(search_term) = search {
-        test_books
-            .into_iter()
-            .filter(|book| book.name.to_lowercase().contains(&search_term.to_lowercase()))
-            .collect()
-    } else {
-        test_books
-    };
-
-    // Get all practice tests for these books
-    let mut books_with_stats = Vec::new();
-    for book in filtered_books {
-        // Get practice tests for this book
-        let practice_tests = state
-            .test_management_service
-            .list_practice_tests_by_test_book(book.id)
-            .await
-            .map_err(|e| handle_service_error("service_call", e))?;
-
-        let total_test_count = practice_tests.len() as i32;
-
-        // Get user's results for these practice tests
-        let practice_test_ids: Vec<Uuid> = practice_tests.iter().map(|pt| pt.id).collect();
-        let mut solved_count = 0;
-        
-        for practice_test_id in practice_test_ids {
-            match state
-                .test_result_repo
-                .find_latest_by_user_and_practice_test(user.id, practice_test_id)
-                .await
-            {
-                Ok(Some(_)) => {
-                    solved_count += 1;
-                }
-                Ok(None) => {}
-                Err(e) => {
-                    // If there's an error checking, log it and skip this test
-                    // This shouldn't happen in normal operation
-                    error!("Error checking test result: {:?}", e);
-                }
-            }
-        }
-
-        let progress_percentage = if total_test_count > 0 {
-            (solved_count as f64 / total_test_count as f64) * 100.0
-        } else {
-            0.0
-        };
-
-        books_with_stats.push(TestBookWithStatsResponse {
-            id: book.id,
-            name: book.name,
-            lesson_id: book.lesson_id,
-            exam_type_id: book.exam_type_id,
-            subject_ids: book.subject_ids,
-            published_year: book.published_year,
-            created_at: book.created_at,
-            total_test_count,
-            solved_test_count: solved_count,
-            progress_percentage,
-        });
-    }
+    let books_with_stats = state
+        .test_solving_service
+        .list_test_books_with_stats(user.id, exam_type_id, lesson_id, search)
+        .await
+        .map_err(|e| handle_service_error("service_call", e))?
+        .into_iter()
+        .map(|book| book.into())
+        .collect();
 
     Ok(Json(ApiResponse::success(books_with_stats)))
 }
@@ -1514,88 +2112,14 @@ pub async fn list_practice_tests_with_status(
         .get("subject_id")
         .and_then(|s| Uuid::parse_str(s).ok());
 
-    // Verify test book exists
-    state
-        .test_management_service
-        .get_test_book(book_id)
-        .await
-        .map_err(|e| handle_service_error("service_call", e))?;
-
-    // Get practice tests for this book
-    let practice_tests = state
-        .test_management_service
-        .list_practice_tests_by_test_book(book_id)
+    // Status follows the retake policy of the test book; answer keys stay hidden until solved
+    let tests_with_status = state
+        .test_solving_service
+        .list_practice_tests_with_status(user.id, book_id, subject_id)
         .await
         .map_err(|e| handle_service_error("service_call", e))?;
 
-    // Filter by subject if provided
-    let filtered_tests: Vec<_> = if let Some(subject_id) = subject_id {
-        practice_tests
-            .into_iter()
-            .filter(|pt| pt.subject_id == subject_id)
-            .collect()
-    } else {
-        practice_tests
-    };
-
-    // Get status for each test
-    let mut tests_with_status = Vec::new();
-    for test in filtered_tests {
-        // Get latest result for this user and test
-        let latest_result = match state
-            .test_result_repo
-            .find_latest_by_user_and_practice_test(user.id, test.id)
-            .await
-        {
-            Ok(result) => result,
-            Err(e) => {
-                // Log error and continue with None (test is available)
-                error!("Error checking test result: {:?}", e);
-                None
-            }
-        };
-
-        let (status, last_solved_at, result_id, hours_until_retake) = if let Some(result) = latest_result {
-            // Check if 24 hours have passed
-            use chrono::{Utc, Duration};
-            let duration = Utc::now() - result.solved_at;
-            let hours_since = duration.num_seconds() as f64 / 3600.0;
-            
-            if hours_since < 24.0 {
-                (
-                    "waiting".to_string(),
-                    Some(result.solved_at),
-                    Some(result.id),
-                    Some(24.0 - hours_since),
-                )
-            } else {
-                (
-                    "solved".to_string(),
-                    Some(result.solved_at),
-                    Some(result.id),
-                    None,
-                )
-            }
-        } else {
-            ("available".to_string(), None, None, None)
-        };
-
-        tests_with_status.push(PracticeTestWithStatusResponse {
-            id: test.id,
-            name: test.name,
-            test_number: test.test_number,
-            question_count: test.question_count,
-            answer_key: test.answer_key,
-            test_book_id: test.test_book_id,
-            subject_id: test.subject_id,
-            created_at: test.created_at,
-            status,
-            last_solved_at,
-            result_id,
-            hours_until_retake,
-        });
-    }
-
-    Ok(Json(ApiResponse::success(tests_with_status)))
+    Ok(Json(ApiResponse::success(
+        tests_with_status.into_iter().map(|t| t.into()).collect(),
+    )))
 }
-
@@ -0,0 +1,278 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    Json,
+};
+use tracing::error;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::dto::request::{
+    CreateTopicRequest, MoveTopicRequest, ReorderTopicsRequest, UpdateTopicRequest,
+};
+use crate::dto::response::{ApiResponse, MessageResponse, TopicResponse, TopicTreeNodeResponse};
+use crate::errors::AppError;
+use crate::extractors::{RequirePermission, TestsRead, TestsWrite};
+use crate::state::AppState;
+
+// Topic Handlers
+
+/// Get the curriculum topic tree of a subject
+#[utoipa::path(
+    get,
+    path = "/api/v1/subjects/{id}/topics",
+    params(("id" = Uuid, Path, description = "Subject ID")),
+    responses(
+        (status = 200, description = "Topic tree retrieved", body = ApiResponse<Vec<TopicTreeNodeResponse>>),
+        (status = 404, description = "Subject not found"),
+    ),
+    tag = "tests"
+)]
+pub async fn get_topic_tree(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<Vec<TopicTreeNodeResponse>>>, AppError> {
+    let tree = state.topic_service.get_topic_tree(id).await.map_err(|e| {
+        error!(subject_id = ?id, "Failed to get topic tree: {:?}", e);
+        AppError::from(e)
+    })?;
+
+    Ok(Json(ApiResponse::success(
+        tree.into_iter().map(|n| n.into()).collect(),
+    )))
+}
+
+/// Create a curriculum topic as the last child of its parent (requires tests:write)
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/topics",
+    request_body = CreateTopicRequest,
+    responses(
+        (status = 201, description = "Topic created successfully", body = ApiResponse<TopicResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Subject or parent topic not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn create_topic(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Json(request): Json<CreateTopicRequest>,
+) -> Result<(StatusCode, Json<ApiResponse<TopicResponse>>), AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .topic_service
+        .create_topic(request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!("Failed to create topic: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(ApiResponse::success_with_message(
+            result.into(),
+            "Topic created successfully",
+        )),
+    ))
+}
+
+/// Get a curriculum topic (requires tests:read)
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/topics/{id}",
+    params(("id" = Uuid, Path, description = "Topic ID")),
+    responses(
+        (status = 200, description = "Topic retrieved", body = ApiResponse<TopicResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:read permission required"),
+        (status = 404, description = "Topic not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn get_topic(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsRead>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<TopicResponse>>, AppError> {
+    let result = state.topic_service.get_topic(id).await.map_err(|e| {
+        error!(topic_id = ?id, "Failed to get topic: {:?}", e);
+        AppError::from(e)
+    })?;
+
+    Ok(Json(ApiResponse::success(result.into())))
+}
+
+/// Rename a curriculum topic (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/topics/{id}",
+    params(("id" = Uuid, Path, description = "Topic ID")),
+    request_body = UpdateTopicRequest,
+    responses(
+        (status = 200, description = "Topic updated", body = ApiResponse<TopicResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Topic not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn update_topic(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<UpdateTopicRequest>,
+) -> Result<Json<ApiResponse<TopicResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .topic_service
+        .update_topic(id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(topic_id = ?id, "Failed to update topic: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Topic updated successfully",
+    )))
+}
+
+/// Delete a curriculum topic and its descendants (requires tests:write)
+///
+/// Questions and practice tests referencing a deleted topic lose their topic.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/topics/{id}",
+    params(("id" = Uuid, Path, description = "Topic ID")),
+    responses(
+        (status = 200, description = "Topic deleted", body = ApiResponse<MessageResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Topic not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn delete_topic(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
+    state.topic_service.delete_topic(id).await.map_err(|e| {
+        error!(topic_id = ?id, "Failed to delete topic: {:?}", e);
+        AppError::from(e)
+    })?;
+
+    Ok(Json(ApiResponse::success(MessageResponse::new(
+        "Topic deleted successfully",
+    ))))
+}
+
+/// Move a curriculum topic under another parent or to another position (requires tests:write)
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/topics/{id}/move",
+    params(("id" = Uuid, Path, description = "Topic ID")),
+    request_body = MoveTopicRequest,
+    responses(
+        (status = 200, description = "Topic moved", body = ApiResponse<TopicResponse>),
+        (status = 400, description = "Validation error or the move would create a cycle"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Topic or parent topic not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn move_topic(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<MoveTopicRequest>,
+) -> Result<Json<ApiResponse<TopicResponse>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let result = state
+        .topic_service
+        .move_topic(id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(topic_id = ?id, "Failed to move topic: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        result.into(),
+        "Topic moved successfully",
+    )))
+}
+
+/// Reorder the child topics of a parent within a subject (requires tests:write)
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/subjects/{id}/topics/order",
+    params(("id" = Uuid, Path, description = "Subject ID")),
+    request_body = ReorderTopicsRequest,
+    responses(
+        (status = 200, description = "Topics reordered, returns the subject's tree", body = ApiResponse<Vec<TopicTreeNodeResponse>>),
+        (status = 400, description = "Validation error or incomplete order"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden - tests:write permission required"),
+        (status = 404, description = "Subject or parent topic not found"),
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "admin"
+)]
+pub async fn reorder_topics(
+    State(state): State<AppState>,
+    _admin: RequirePermission<TestsWrite>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<ReorderTopicsRequest>,
+) -> Result<Json<ApiResponse<Vec<TopicTreeNodeResponse>>>, AppError> {
+    request
+        .validate()
+        .map_err(|e| AppError::ValidationError(e.to_string()))?;
+
+    let tree = state
+        .topic_service
+        .reorder_topics(id, request.into_app_request())
+        .await
+        .map_err(|e| {
+            error!(subject_id = ?id, "Failed to reorder topics: {:?}", e);
+            AppError::from(e)
+        })?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        tree.into_iter().map(|n| n.into()).collect(),
+        "Topics reordered successfully",
+    )))
+}
@@ -2,20 +2,22 @@ use axum::{
     extract::{Path, Query, State},
     Json,
 };
+use std::collections::HashMap;
+use tracing::error;
 use uuid::Uuid;
 use validator::Validate;
-use tracing::error;
-use std::collections::HashMap;
 
 use domain::repositories::UserRepository;
 
 use crate::dto::request::UpdateUserRequest;
-use crate::dto::response::{ApiResponse, MessageResponse, PaginatedResponse, UserResponse, PaginationInfo};
+use crate::dto::response::{
+    ApiResponse, MessageResponse, PaginatedResponse, PaginationInfo, UserResponse,
+};
 use crate::errors::AppError;
-use crate::extractors::RequireAdmin;
+use crate::extractors::{RequirePermission, UsersRead, UsersWrite};
 use crate::state::AppState;
 
-/// List all users (Admin only)
+/// List all users (requires users:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/users",
@@ -27,7 +29,7 @@ use crate::state::AppState;
     responses(
         (status = 200, description = "Users retrieved", body = ApiResponse<PaginatedResponse<UserResponse>>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - users:read permission required"),
     ),
     security(
         ("bearer_auth" = [])
@@ -36,7 +38,7 @@ use crate::state::AppState;
 )]
 pub async fn list_users(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<UsersRead>,
     Query(params): Query<HashMap<String, String>>,
 ) -> Result<Json<ApiResponse<PaginatedResponse<UserResponse>>>, AppError> {
     let page = params
@@ -65,20 +67,18 @@ pub async fn list_users(
     // Convert users to UserResponse with roles
     let mut user_responses = Vec::new();
     for user in users {
-        let roles = state
-            .user_repo
-            .get_user_roles(user.id)
-            .await
-            .map_err(|e| {
-                error!(user_id = ?user.id, "Failed to get user roles: {:?}", e);
-                AppError::InternalServerError
-            })?;
+        let roles = state.user_repo.get_user_roles(user.id).await.map_err(|e| {
+            error!(user_id = ?user.id, "Failed to get user roles: {:?}", e);
+            AppError::InternalServerError
+        })?;
 
         user_responses.push(UserResponse {
             id: user.id,
             username: user.username,
             email: user.email,
             is_active: user.is_active,
+            student_number: user.student_number,
+            leaderboard_opt_out: user.leaderboard_opt_out,
             roles,
             created_at: user.created_at,
             updated_at: user.updated_at,
@@ -102,7 +102,7 @@ pub async fn list_users(
     })))
 }
 
-/// Get user by ID (Admin only)
+/// Get user by ID (requires users:read)
 #[utoipa::path(
     get,
     path = "/api/v1/admin/users/{id}",
@@ -110,7 +110,7 @@ pub async fn list_users(
     responses(
         (status = 200, description = "User retrieved", body = ApiResponse<UserResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - users:read permission required"),
         (status = 404, description = "User not found"),
     ),
     security(
@@ -120,7 +120,7 @@ pub async fn list_users(
 )]
 pub async fn get_user(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<UsersRead>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<UserResponse>>, AppError> {
     let user = state
@@ -138,27 +138,25 @@ pub async fn get_user(
         return Err(AppError::NotFound("User not found".to_string()));
     }
 
-    let roles = state
-        .user_repo
-        .get_user_roles(user.id)
-        .await
-        .map_err(|e| {
-            error!(user_id = ?id, "Failed to get user roles: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    let roles = state.user_repo.get_user_roles(user.id).await.map_err(|e| {
+        error!(user_id = ?id, "Failed to get user roles: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     Ok(Json(ApiResponse::success(UserResponse {
         id: user.id,
         username: user.username,
         email: user.email,
         is_active: user.is_active,
+        student_number: user.student_number,
+        leaderboard_opt_out: user.leaderboard_opt_out,
         roles,
         created_at: user.created_at,
         updated_at: user.updated_at,
     })))
 }
 
-/// Update user (Admin only)
+/// Update user (requires users:write)
 #[utoipa::path(
     put,
     path = "/api/v1/admin/users/{id}",
@@ -168,9 +166,9 @@ pub async fn get_user(
         (status = 200, description = "User updated successfully", body = ApiResponse<UserResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - users:write permission required"),
         (status = 404, description = "User not found"),
-        (status = 409, description = "Email or username already exists"),
+        (status = 409, description = "Email, username or student number already exists"),
     ),
     security(
         ("bearer_auth" = [])
@@ -179,7 +177,7 @@ pub async fn get_user(
 )]
 pub async fn update_user(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<UsersWrite>,
     Path(id): Path<Uuid>,
     Json(request): Json<UpdateUserRequest>,
 ) -> Result<Json<ApiResponse<UserResponse>>, AppError> {
@@ -204,25 +202,50 @@ pub async fn update_user(
 
     // Check for duplicate email if email is being updated
     if let Some(ref new_email) = request.email {
-        if new_email != &user.email {
-            if state.user_repo.email_exists(new_email).await.map_err(|e| {
+        if new_email != &user.email
+            && state.user_repo.email_exists(new_email).await.map_err(|e| {
                 error!("Failed to check email existence: {:?}", e);
                 AppError::InternalServerError
-            })? {
-                return Err(AppError::Conflict("Email already exists".to_string()));
-            }
+            })?
+        {
+            return Err(AppError::Conflict("Email already exists".to_string()));
         }
     }
 
     // Check for duplicate username if username is being updated
     if let Some(ref new_username) = request.username {
-        if new_username != &user.username {
-            if state.user_repo.username_exists(new_username).await.map_err(|e| {
-                error!("Failed to check username existence: {:?}", e);
+        if new_username != &user.username
+            && state
+                .user_repo
+                .username_exists(new_username)
+                .await
+                .map_err(|e| {
+                    error!("Failed to check username existence: {:?}", e);
+                    AppError::InternalServerError
+                })?
+        {
+            return Err(AppError::Conflict("Username already exists".to_string()));
+        }
+    }
+
+    // Check for duplicate student number if it is being assigned
+    let student_number = request
+        .student_number
+        .map(|number| number.trim().to_string())
+        .map(|number| (!number.is_empty()).then_some(number));
+    if let Some(Some(ref new_number)) = student_number {
+        let holders = state
+            .user_repo
+            .find_by_student_numbers(std::slice::from_ref(new_number))
+            .await
+            .map_err(|e| {
+                error!("Failed to check student number existence: {:?}", e);
                 AppError::InternalServerError
-            })? {
-                return Err(AppError::Conflict("Username already exists".to_string()));
-            }
+            })?;
+        if holders.iter().any(|holder| holder.id != user.id) {
+            return Err(AppError::Conflict(
+                "Student number already exists".to_string(),
+            ));
         }
     }
 
@@ -236,17 +259,16 @@ pub async fn update_user(
     if let Some(is_active) = request.is_active {
         user.is_active = is_active;
     }
+    if let Some(student_number) = student_number {
+        user.student_number = student_number;
+    }
     user.updated_at = chrono::Utc::now();
 
     // Save updated user
-    let updated_user = state
-        .user_repo
-        .update(&user)
-        .await
-        .map_err(|e| {
-            error!(user_id = ?id, "Failed to update user: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    let updated_user = state.user_repo.update(&user).await.map_err(|e| {
+        error!(user_id = ?id, "Failed to update user: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     // Get user roles
     let roles = state
@@ -264,6 +286,8 @@ pub async fn update_user(
             username: updated_user.username,
             email: updated_user.email,
             is_active: updated_user.is_active,
+            student_number: updated_user.student_number,
+            leaderboard_opt_out: updated_user.leaderboard_opt_out,
             roles,
             created_at: updated_user.created_at,
             updated_at: updated_user.updated_at,
@@ -272,7 +296,7 @@ pub async fn update_user(
     )))
 }
 
-/// Delete user (soft delete, Admin only)
+/// Delete user (soft delete, requires users:write)
 #[utoipa::path(
     delete,
     path = "/api/v1/admin/users/{id}",
@@ -280,7 +304,7 @@ pub async fn update_user(
     responses(
         (status = 200, description = "User deleted successfully", body = ApiResponse<MessageResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - users:write permission required"),
         (status = 404, description = "User not found"),
         (status = 400, description = "Cannot delete own account"),
     ),
@@ -291,7 +315,7 @@ pub async fn update_user(
 )]
 pub async fn delete_user(
     State(state): State<AppState>,
-    admin: RequireAdmin,
+    admin: RequirePermission<UsersWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<MessageResponse>>, AppError> {
     // Prevent self-deletion
@@ -332,7 +356,7 @@ pub async fn delete_user(
     )))
 }
 
-/// Restore soft-deleted user (Admin only)
+/// Restore soft-deleted user (requires users:write)
 #[utoipa::path(
     post,
     path = "/api/v1/admin/users/{id}/restore",
@@ -340,7 +364,7 @@ pub async fn delete_user(
     responses(
         (status = 200, description = "User restored successfully", body = ApiResponse<UserResponse>),
         (status = 401, description = "Unauthorized"),
-        (status = 403, description = "Forbidden - Admin access required"),
+        (status = 403, description = "Forbidden - users:write permission required"),
         (status = 404, description = "User not found"),
         (status = 400, description = "User is not deleted"),
     ),
@@ -351,7 +375,7 @@ pub async fn delete_user(
 )]
 pub async fn restore_user(
     State(state): State<AppState>,
-    _admin: RequireAdmin,
+    _admin: RequirePermission<UsersWrite>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ApiResponse<UserResponse>>, AppError> {
     // Get user (including deleted ones)
@@ -367,20 +391,14 @@ pub async fn restore_user(
 
     // Check if user is actually deleted
     if !user.is_deleted() {
-        return Err(AppError::ValidationError(
-            "User is not deleted".to_string(),
-        ));
+        return Err(AppError::ValidationError("User is not deleted".to_string()));
     }
 
     // Restore user
-    state
-        .user_repo
-        .restore(id)
-        .await
-        .map_err(|e| {
-            error!(user_id = ?id, "Failed to restore user: {:?}", e);
-            AppError::InternalServerError
-        })?;
+    state.user_repo.restore(id).await.map_err(|e| {
+        error!(user_id = ?id, "Failed to restore user: {:?}", e);
+        AppError::InternalServerError
+    })?;
 
     // Get updated user
     let restored_user = state
@@ -409,6 +427,8 @@ pub async fn restore_user(
             username: restored_user.username,
             email: restored_user.email,
             is_active: restored_user.is_active,
+            student_number: restored_user.student_number,
+            leaderboard_opt_out: restored_user.leaderboard_opt_out,
             roles,
             created_at: restored_user.created_at,
             updated_at: restored_user.updated_at,
@@ -1,23 +1,16 @@
 use std::time::Duration;
 
-use tracing::info;
+use tracing::{error, info, warn};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
+use api::server::{create_app, run_server};
+use api::state::AppState;
+use infrastructure::cache::create_redis_connection;
 use infrastructure::config::Settings;
 use infrastructure::database::{create_pool, run_migrations};
 
-mod dto;
-mod errors;
-mod extractors;
-mod handlers;
-mod middleware;
-mod openapi;
-mod routes;
-mod server;
-mod state;
-
-use server::{create_app, run_server};
-use state::AppState;
+/// How often expired exam sessions and mock exam attempts are auto-submitted.
+const EXAM_SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(30);
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
@@ -45,10 +38,61 @@ async fn main() -> anyhow::Result<()> {
     run_migrations(&db_pool).await?;
     info!("Database migrations completed");
 
+    // Connect to Redis, falling back to in-process cache and rate limit stores
+    let redis = match create_redis_connection(&settings.redis.url).await {
+        Ok(connection) => Some(connection),
+        Err(e) => {
+            warn!(
+                "Redis unavailable, using in-process cache and rate limit store: {}",
+                e
+            );
+            None
+        }
+    };
+
     // Create application state
-    let state = AppState::new(db_pool, settings.clone());
+    let state = AppState::new(db_pool, settings.clone(), redis);
     info!("Application state initialized");
 
+    // Resume answer key re-grades interrupted by a previous shutdown
+    let regrade_service = state.regrade_service.clone();
+    tokio::spawn(async move {
+        match regrade_service.regrade_pending().await {
+            Ok(summaries) => {
+                for summary in summaries {
+                    info!(
+                        practice_test_id = ?summary.practice_test_id,
+                        revision = summary.answer_key_revision,
+                        regraded = summary.regraded_count,
+                        changed = summary.changed_count,
+                        "Pending answer key re-grade completed"
+                    );
+                }
+            }
+            Err(e) => error!("Failed to resume pending re-grades: {:?}", e),
+        }
+    });
+
+    // Auto-submit exam sessions and mock exam attempts whose time limit has run out
+    let test_solving_service = state.test_solving_service.clone();
+    let mock_exam_service = state.mock_exam_service.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(EXAM_SESSION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            match test_solving_service.expire_exam_sessions().await {
+                Ok(0) => {}
+                Ok(submitted) => info!(submitted, "Expired exam sessions auto-submitted"),
+                Err(e) => error!("Failed to auto-submit expired exam sessions: {:?}", e),
+            }
+            match mock_exam_service.expire_attempts().await {
+                Ok(0) => {}
+                Ok(submitted) => info!(submitted, "Expired mock exam attempts auto-submitted"),
+                Err(e) => error!("Failed to auto-submit expired mock exam attempts: {:?}", e),
+            }
+        }
+    });
+
     // Create the application router
     let app = create_app(state);
 
@@ -65,4 +109,3 @@ async fn main() -> anyhow::Result<()> {
 
     Ok(())
 }
-
@@ -1,4 +1,5 @@
 mod auth_middleware;
+mod rate_limit_middleware;
 
 pub use auth_middleware::*;
-
+pub use rate_limit_middleware::*;
@@ -0,0 +1,322 @@
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use axum::{
+    body::Body,
+    extract::{ConnectInfo, State},
+    http::{
+        header::{AUTHORIZATION, FORWARDED},
+        HeaderMap, HeaderName, HeaderValue, Request,
+    },
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use infrastructure::rate_limit::RateLimitDecision;
+use tracing::error;
+
+use crate::errors::AppError;
+use crate::state::AppState;
+
+/// Maximum requests allowed in the current window.
+pub const RATE_LIMIT_LIMIT: HeaderName = HeaderName::from_static("ratelimit-limit");
+/// Requests left in the current window.
+pub const RATE_LIMIT_REMAINING: HeaderName = HeaderName::from_static("ratelimit-remaining");
+/// Seconds until the window frees up another request.
+pub const RATE_LIMIT_RESET: HeaderName = HeaderName::from_static("ratelimit-reset");
+/// De facto standard header listing the client and the proxies a request passed.
+const X_FORWARDED_FOR: HeaderName = HeaderName::from_static("x-forwarded-for");
+
+/// Middleware that limits login and registration attempts per client IP.
+pub async fn auth_rate_limit(
+    State(state): State<AppState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let key = format!("auth:ip:{}", client_ip(&state, &request));
+    let settings = &state.settings.rate_limit;
+
+    enforce(
+        &state,
+        &key,
+        settings.auth_requests,
+        Duration::from_secs(settings.auth_window_seconds),
+        request,
+        next,
+    )
+    .await
+}
+
+/// Middleware that limits API requests per user, or per client IP for anonymous requests.
+pub async fn api_rate_limit(
+    State(state): State<AppState>,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let key = match bearer_user_id(&state, request.headers()) {
+        Some(user_id) => format!("api:user:{}", user_id),
+        None => format!("api:ip:{}", client_ip(&state, &request)),
+    };
+    let settings = &state.settings.rate_limit;
+
+    enforce(
+        &state,
+        &key,
+        settings.api_requests,
+        Duration::from_secs(settings.api_window_seconds),
+        request,
+        next,
+    )
+    .await
+}
+
+/// Counts the request and either rejects it with 429 or runs it, adding the rate limit headers.
+///
+/// Requests are let through without headers if the store fails, so a Redis outage
+/// does not take the API down with it.
+async fn enforce(
+    state: &AppState,
+    key: &str,
+    limit: u32,
+    window: Duration,
+    request: Request<Body>,
+    next: Next,
+) -> Response {
+    let decision = match state.rate_limit_store.hit(key, limit, window).await {
+        Ok(decision) => decision,
+        Err(e) => {
+            error!(key, "Failed to check rate limit: {:?}", e);
+            return next.run(request).await;
+        }
+    };
+
+    let mut response = if decision.allowed {
+        next.run(request).await
+    } else {
+        let mut response = AppError::TooManyRequests.into_response();
+        response.headers_mut().insert(
+            axum::http::header::RETRY_AFTER,
+            HeaderValue::from(reset_seconds(&decision)),
+        );
+        response
+    };
+
+    let headers = response.headers_mut();
+    headers.insert(RATE_LIMIT_LIMIT, HeaderValue::from(decision.limit));
+    headers.insert(RATE_LIMIT_REMAINING, HeaderValue::from(decision.remaining));
+    headers.insert(
+        RATE_LIMIT_RESET,
+        HeaderValue::from(reset_seconds(&decision)),
+    );
+
+    response
+}
+
+/// Whole seconds until the window resets, rounded up so clients never retry too early.
+fn reset_seconds(decision: &RateLimitDecision) -> u64 {
+    decision.reset_after.as_millis().div_ceil(1000) as u64
+}
+
+/// Gets the client IP of a request, or "unknown" when the server runs without connect info.
+fn client_ip(state: &AppState, request: &Request<Body>) -> String {
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| {
+            resolve_client_ip(
+                addr.ip(),
+                request.headers(),
+                &state.settings.rate_limit.trusted_proxies,
+            )
+            .to_string()
+        })
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// Resolves the client IP from the peer address and, if the peer is a trusted proxy,
+/// its forwarding headers.
+///
+/// The hops are walked from the nearest one and the first address that is not a
+/// trusted proxy wins, so addresses a client prepends itself are never used. When a
+/// hop cannot be parsed, the last trusted proxy stands in for the client.
+/// `Forwarded` takes precedence over `X-Forwarded-For`.
+fn resolve_client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpAddr]) -> IpAddr {
+    if !trusted_proxies.contains(&peer) {
+        return peer;
+    }
+
+    let hops = forwarded_for(headers).unwrap_or_else(|| x_forwarded_for(headers));
+    let mut client = peer;
+    for hop in hops.into_iter().rev() {
+        match hop {
+            Some(ip) => {
+                client = ip;
+                if !trusted_proxies.contains(&ip) {
+                    break;
+                }
+            }
+            None => break,
+        }
+    }
+    client
+}
+
+/// Gets the `for` addresses of the `Forwarded` headers in order, or `None` without the header.
+/// Elements without a parseable `for` address are kept as `None`.
+fn forwarded_for(headers: &HeaderMap) -> Option<Vec<Option<IpAddr>>> {
+    let values: Vec<&str> = headers
+        .get_all(FORWARDED)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .collect();
+    if values.is_empty() {
+        return None;
+    }
+
+    let hops = values
+        .iter()
+        .flat_map(|value| value.split(','))
+        .map(|element| {
+            element.split(';').find_map(|pair| {
+                let (name, value) = pair.split_once('=')?;
+                name.trim()
+                    .eq_ignore_ascii_case("for")
+                    .then(|| parse_node(value))
+                    .flatten()
+            })
+        })
+        .collect();
+    Some(hops)
+}
+
+/// Gets the addresses of the `X-Forwarded-For` headers in order.
+fn x_forwarded_for(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    headers
+        .get_all(X_FORWARDED_FOR)
+        .iter()
+        .flat_map(|value| value.to_str().unwrap_or_default().split(','))
+        .map(parse_node)
+        .collect()
+}
+
+/// Parses a forwarded node: an IP address, optionally quoted, bracketed or with a port.
+fn parse_node(value: &str) -> Option<IpAddr> {
+    let value = value.trim().trim_matches('"');
+    if let Some(rest) = value.strip_prefix('[') {
+        return rest.split_once(']')?.0.parse().ok();
+    }
+    value.parse().ok().or_else(|| {
+        let (ip, _port) = value.split_once(':')?;
+        ip.parse().ok()
+    })
+}
+
+/// Gets the user ID from a valid bearer token, if the request carries one.
+fn bearer_user_id(state: &AppState, headers: &HeaderMap) -> Option<uuid::Uuid> {
+    let token = headers
+        .get(AUTHORIZATION)?
+        .to_str()
+        .ok()?
+        .strip_prefix("Bearer ")?;
+
+    state
+        .jwt_service
+        .validate_access_token(token)
+        .ok()
+        .map(|claims| claims.sub)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROXY: &str = "10.0.0.1";
+    const EDGE_PROXY: &str = "10.0.0.2";
+
+    fn ip(value: &str) -> IpAddr {
+        value.parse().unwrap()
+    }
+
+    fn headers(entries: &[(&str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in entries {
+            headers.append(
+                HeaderName::from_bytes(name.as_bytes()).unwrap(),
+                HeaderValue::from_str(value).unwrap(),
+            );
+        }
+        headers
+    }
+
+    fn resolve(peer: &str, entries: &[(&str, &str)]) -> IpAddr {
+        resolve_client_ip(ip(peer), &headers(entries), &[ip(PROXY), ip(EDGE_PROXY)])
+    }
+
+    #[test]
+    fn test_untrusted_peer_is_the_client() {
+        let client = resolve("203.0.113.7", &[("x-forwarded-for", "198.51.100.1")]);
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn test_no_trusted_proxies_ignores_headers() {
+        let client = resolve_client_ip(
+            ip(PROXY),
+            &headers(&[("x-forwarded-for", "198.51.100.1")]),
+            &[],
+        );
+
+        assert_eq!(client, ip(PROXY));
+    }
+
+    #[test]
+    fn test_trusted_peer_without_headers_is_the_client() {
+        assert_eq!(resolve(PROXY, &[]), ip(PROXY));
+    }
+
+    #[test]
+    fn test_x_forwarded_for_skips_trusted_hops() {
+        let client = resolve(
+            PROXY,
+            &[("x-forwarded-for", "198.51.100.1, 203.0.113.7, 10.0.0.2")],
+        );
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn test_x_forwarded_for_spans_header_lines() {
+        let client = resolve(
+            PROXY,
+            &[
+                ("x-forwarded-for", "203.0.113.7"),
+                ("x-forwarded-for", "10.0.0.2"),
+            ],
+        );
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn test_forwarded_takes_precedence() {
+        let client = resolve(
+            PROXY,
+            &[
+                ("x-forwarded-for", "198.51.100.1"),
+                (
+                    "forwarded",
+                    r#"for=198.51.100.1, For="[2001:db8:cafe::17]:4711";proto=https, for=10.0.0.2:8080"#,
+                ),
+            ],
+        );
+
+        assert_eq!(client, ip("2001:db8:cafe::17"));
+    }
+
+    #[test]
+    fn test_unparseable_hop_falls_back_to_last_trusted_proxy() {
+        let client = resolve(PROXY, &[("forwarded", "for=unknown, for=10.0.0.2")]);
+
+        assert_eq!(client, ip(EDGE_PROXY));
+    }
+}
@@ -2,17 +2,39 @@ use utoipa::openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 
 use crate::dto::request::{
-    AssignRoleRequest, CreateExamTypeRequest, CreateLessonRequest, CreatePracticeTestRequest,
-    CreateRoleRequest, CreateSubjectRequest, CreateTestBookRequest, LoginRequest, LogoutRequest,
-    RefreshTokenRequest, RegisterRequest, SolveTestRequest, UpdateExamTypeRequest,
-    UpdateLessonRequest, UpdatePracticeTestRequest, UpdateRoleRequest, UpdateSubjectRequest,
-    UpdateTestBookRequest,
+    AnswerKeyEntryRequest, AnswerKeyRequest, AssignRoleRequest, CreateAchievementRequest,
+    CreateAssignmentRequest, CreateClassroomRequest, CreateExamTypeRequest, CreateLessonRequest,
+    CreateMockExamRequest, CreatePracticeTestRequest, CreateRoleRequest, CreateStudyGoalRequest,
+    CreateSubjectRequest, CreateTestBookRequest, CreateTopicRequest, EstimateScoreRequest,
+    GrantPermissionRequest, ImportOmrResultsRequest, InviteStudentRequest, JoinClassroomRequest,
+    LessonCoefficientRequest, LoginRequest, LogoutRequest, MockExamSectionAnswersRequest,
+    MoveTopicRequest, QuestionRequest, RefreshTokenRequest, RegisterRequest, ReorderTopicsRequest,
+    ReplaceQuestionsRequest, SaveExamSessionAnswersRequest, SaveMockExamAnswersRequest,
+    SaveScoreTableRequest, SetStudyPlanRequest, SolveTestRequest, SubjectNetRequest,
+    SubmitExamSessionRequest, SubmitMockExamAttemptRequest, UpdateExamTypeRequest,
+    UpdateLeaderboardPreferenceRequest, UpdateLessonRequest, UpdateMockExamRequest,
+    UpdatePracticeTestRequest, UpdateRetakePolicyRequest, UpdateRoleRequest,
+    UpdateScoringPolicyRequest, UpdateSubjectRequest, UpdateTestBookRequest, UpdateTopicRequest,
 };
 use crate::dto::response::{
-    AuthResponse, ExamTypeResponse, HealthCheckResult, HealthChecks, LessonResponse,
-    LivenessResponse, MessageResponse, PaginationInfo, PracticeTestResponse, ReadinessResponse,
-    RegisterResponse, RoleResponse, SolveTestResponse, SubjectResponse, TestBookResponse,
-    TestResultResponse, TokenResponse, UserResponse,
+    AchievementProgressResponse, AchievementResponse, AnswerKeyEntryResponse,
+    AnswerKeyRevisionResponse, AssignmentResponse, AssignmentSubmissionResponse,
+    AssignmentSubmissionsResponse, AuthResponse, ClassroomDetailResponse, ClassroomResponse,
+    ClassroomStudentResponse, CohortStatisticsResponse, ExamSessionResponse, ExamTypeResponse,
+    GoalProgressResponse, GuardianLinkResponse, HealthCheckResult, HealthChecks,
+    LeaderboardEntryResponse, LeaderboardPreferenceResponse, LeaderboardResponse,
+    LessonCoefficientResponse, LessonResponse, LessonScoreResponse, LivenessResponse,
+    MessageResponse, MockExamAttemptResponse, MockExamAttemptSectionResponse, MockExamResponse,
+    MockExamSectionResponse, OmrImportReportResponse, OmrImportRowResponse, PaginationInfo,
+    PerformanceStatResponse, PermissionResponse, PracticeTestResponse, ProgressPointResponse,
+    ProgressSeriesResponse, ProgressTimelineResponse, QuestionErrorRateResponse, QuestionResponse,
+    QuestionResultResponse, ReadinessResponse, RecommendationResponse, RegisterResponse,
+    RetakePolicyResponse, RoleResponse, ScoreEstimateResponse, ScoreTableResponse,
+    ScoringPolicyResponse, SolveTestResponse, StudentAssignmentResponse,
+    StudentPracticeTestResponse, StudyCalendarEntryResponse, StudyCalendarResponse,
+    StudyGoalResponse, StudyPlanResponse, SubjectResponse, TestBookResponse,
+    TestResultDetailResponse, TestResultHistoryResponse, TestResultResponse, TokenResponse,
+    TopicResponse, TopicTreeNodeResponse, UserResponse, WeaknessReportResponse,
 };
 use crate::errors::{ErrorDetail, ErrorResponse};
 
@@ -52,6 +74,19 @@ use crate::errors::{ErrorDetail, ErrorResponse};
         crate::handlers::list_admin_exam_types,
         crate::handlers::update_exam_type,
         crate::handlers::delete_exam_type,
+        crate::handlers::get_scoring_policy,
+        crate::handlers::list_scoring_policies,
+        crate::handlers::update_scoring_policy,
+        crate::handlers::get_exam_type_retake_policy,
+        crate::handlers::update_exam_type_retake_policy,
+        crate::handlers::delete_exam_type_retake_policy,
+        crate::handlers::get_test_book_retake_policy,
+        crate::handlers::update_test_book_retake_policy,
+        crate::handlers::delete_test_book_retake_policy,
+        crate::handlers::list_score_tables,
+        crate::handlers::save_score_table,
+        crate::handlers::delete_score_table,
+        crate::handlers::estimate_score,
         crate::handlers::create_subject,
         crate::handlers::get_subject,
         crate::handlers::list_subjects,
@@ -70,9 +105,77 @@ use crate::errors::{ErrorDetail, ErrorResponse};
         crate::handlers::list_practice_tests,
         crate::handlers::list_admin_practice_tests,
         crate::handlers::update_practice_test,
+        crate::handlers::list_answer_key_revisions,
+        crate::handlers::list_questions,
+        crate::handlers::replace_questions,
         crate::handlers::delete_practice_test,
         crate::handlers::solve_test,
+        crate::handlers::start_exam_session,
+        crate::handlers::get_exam_session,
+        crate::handlers::save_exam_session_answers,
+        crate::handlers::submit_exam_session,
+        crate::handlers::create_mock_exam,
+        crate::handlers::update_mock_exam,
+        crate::handlers::delete_mock_exam,
+        crate::handlers::list_mock_exams,
+        crate::handlers::get_mock_exam,
+        crate::handlers::start_mock_exam_attempt,
+        crate::handlers::get_mock_exam_attempt,
+        crate::handlers::save_mock_exam_answers,
+        crate::handlers::submit_mock_exam_attempt,
+        crate::handlers::list_my_mock_exam_attempts,
+        crate::handlers::import_omr_results,
+        crate::handlers::get_topic_tree,
+        crate::handlers::create_topic,
+        crate::handlers::get_topic,
+        crate::handlers::update_topic,
+        crate::handlers::delete_topic,
+        crate::handlers::move_topic,
+        crate::handlers::reorder_topics,
+        crate::handlers::get_my_weaknesses,
+        crate::handlers::get_my_progress_timeline,
+        crate::handlers::get_practice_test_leaderboard,
+        crate::handlers::get_test_book_leaderboard,
+        crate::handlers::get_exam_type_weekly_leaderboard,
+        crate::handlers::update_leaderboard_preference,
+        crate::handlers::get_my_recommendations,
+        crate::handlers::get_my_study_plan,
+        crate::handlers::set_my_study_plan,
+        crate::handlers::list_my_study_goals,
+        crate::handlers::create_my_study_goal,
+        crate::handlers::archive_my_study_goal,
+        crate::handlers::get_my_study_calendar,
+        crate::handlers::list_my_achievements,
+        crate::handlers::list_achievements,
+        crate::handlers::create_achievement,
+        crate::handlers::delete_achievement,
+        crate::handlers::create_classroom,
+        crate::handlers::list_teacher_classrooms,
+        crate::handlers::get_classroom,
+        crate::handlers::regenerate_invite_code,
+        crate::handlers::delete_classroom,
+        crate::handlers::remove_classroom_student,
+        crate::handlers::list_classroom_student_results,
+        crate::handlers::create_assignment,
+        crate::handlers::list_assignments,
+        crate::handlers::get_assignment_submissions,
+        crate::handlers::delete_assignment,
+        crate::handlers::join_classroom,
+        crate::handlers::list_my_classrooms,
+        crate::handlers::leave_classroom,
+        crate::handlers::list_my_assignments,
+        crate::handlers::invite_student,
+        crate::handlers::list_guardian_students,
+        crate::handlers::remove_guardian_student,
+        crate::handlers::list_guardian_student_results,
+        crate::handlers::get_guardian_student_study_calendar,
+        crate::handlers::get_guardian_student_weaknesses,
+        crate::handlers::get_guardian_student_progress_timeline,
+        crate::handlers::list_my_guardians,
+        crate::handlers::accept_guardian_invitation,
+        crate::handlers::remove_my_guardian,
         crate::handlers::get_result,
+        crate::handlers::get_result_history,
         crate::handlers::list_my_results,
         crate::handlers::list_roles,
         crate::handlers::create_role,
@@ -81,6 +184,10 @@ use crate::errors::{ErrorDetail, ErrorResponse};
         crate::handlers::delete_role,
         crate::handlers::assign_role_to_user,
         crate::handlers::remove_role_from_user,
+        crate::handlers::list_permissions,
+        crate::handlers::list_role_permissions,
+        crate::handlers::grant_permission_to_role,
+        crate::handlers::revoke_permission_from_role,
     ),
     components(
         schemas(
@@ -93,14 +200,45 @@ use crate::errors::{ErrorDetail, ErrorResponse};
             UpdateLessonRequest,
             CreateExamTypeRequest,
             UpdateExamTypeRequest,
+            UpdateScoringPolicyRequest,
+            UpdateRetakePolicyRequest,
+            SaveScoreTableRequest,
+            LessonCoefficientRequest,
+            EstimateScoreRequest,
+            SubjectNetRequest,
             CreateSubjectRequest,
             UpdateSubjectRequest,
             CreateTestBookRequest,
             UpdateTestBookRequest,
             CreatePracticeTestRequest,
             UpdatePracticeTestRequest,
+            AnswerKeyRequest,
+            QuestionRequest,
+            ReplaceQuestionsRequest,
+            AnswerKeyEntryRequest,
             SolveTestRequest,
+            SaveExamSessionAnswersRequest,
+            SubmitExamSessionRequest,
+            CreateMockExamRequest,
+            UpdateMockExamRequest,
+            MockExamSectionAnswersRequest,
+            SaveMockExamAnswersRequest,
+            SubmitMockExamAttemptRequest,
+            ImportOmrResultsRequest,
+            CreateTopicRequest,
+            UpdateTopicRequest,
+            MoveTopicRequest,
+            ReorderTopicsRequest,
+            UpdateLeaderboardPreferenceRequest,
+            SetStudyPlanRequest,
+            CreateStudyGoalRequest,
+            CreateAchievementRequest,
+            CreateClassroomRequest,
+            JoinClassroomRequest,
+            CreateAssignmentRequest,
+            InviteStudentRequest,
             AssignRoleRequest,
+            GrantPermissionRequest,
             CreateRoleRequest,
             UpdateRoleRequest,
             // Response schemas
@@ -111,12 +249,61 @@ use crate::errors::{ErrorDetail, ErrorResponse};
             MessageResponse,
             LessonResponse,
             ExamTypeResponse,
+            ScoringPolicyResponse,
+            RetakePolicyResponse,
+            ScoreTableResponse,
+            LessonCoefficientResponse,
+            ScoreEstimateResponse,
+            LessonScoreResponse,
             SubjectResponse,
             TestBookResponse,
             PracticeTestResponse,
+            StudentPracticeTestResponse,
+            AnswerKeyRevisionResponse,
+            AnswerKeyEntryResponse,
+            QuestionResponse,
             TestResultResponse,
+            TestResultDetailResponse,
+            CohortStatisticsResponse,
+            QuestionResultResponse,
+            TestResultHistoryResponse,
             SolveTestResponse,
+            ExamSessionResponse,
+            MockExamResponse,
+            MockExamSectionResponse,
+            MockExamAttemptResponse,
+            MockExamAttemptSectionResponse,
+            OmrImportReportResponse,
+            OmrImportRowResponse,
+            TopicResponse,
+            TopicTreeNodeResponse,
+            PerformanceStatResponse,
+            QuestionErrorRateResponse,
+            WeaknessReportResponse,
+            ProgressPointResponse,
+            ProgressSeriesResponse,
+            ProgressTimelineResponse,
+            LeaderboardEntryResponse,
+            LeaderboardResponse,
+            LeaderboardPreferenceResponse,
+            RecommendationResponse,
+            StudyPlanResponse,
+            StudyGoalResponse,
+            GoalProgressResponse,
+            StudyCalendarEntryResponse,
+            StudyCalendarResponse,
+            AchievementResponse,
+            AchievementProgressResponse,
+            ClassroomResponse,
+            ClassroomStudentResponse,
+            ClassroomDetailResponse,
+            AssignmentResponse,
+            AssignmentSubmissionResponse,
+            AssignmentSubmissionsResponse,
+            StudentAssignmentResponse,
+            GuardianLinkResponse,
             RoleResponse,
+            PermissionResponse,
             PaginationInfo,
             LivenessResponse,
             ReadinessResponse,
@@ -132,7 +319,9 @@ use crate::errors::{ErrorDetail, ErrorResponse};
         (name = "auth", description = "Authentication endpoints"),
         (name = "health", description = "Health check endpoints"),
         (name = "admin", description = "Admin endpoints (requires authentication)"),
-        (name = "tests", description = "Test management and solving endpoints")
+        (name = "tests", description = "Test management and solving endpoints"),
+        (name = "teacher", description = "Classroom and homework endpoints (requires teacher role)"),
+        (name = "guardian", description = "Read-only student progress endpoints (requires guardian role)")
     )
 )]
 pub struct ApiDoc;
@@ -156,4 +345,3 @@ impl Modify for SecurityAddon {
         }
     }
 }
-
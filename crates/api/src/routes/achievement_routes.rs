@@ -0,0 +1,27 @@
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use crate::handlers::{
+    create_achievement, delete_achievement, list_achievements, list_my_achievements,
+};
+use crate::state::AppState;
+
+/// Creates the achievement routes (requires authentication).
+pub fn achievement_routes() -> Router<AppState> {
+    Router::new().route("/api/v1/me/achievements", get(list_my_achievements))
+}
+
+/// Creates the admin achievement routes (protected, admin only).
+pub fn admin_achievement_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/v1/admin/achievements",
+            get(list_achievements).post(create_achievement),
+        )
+        .route(
+            "/api/v1/admin/achievements/{id}",
+            delete(delete_achievement),
+        )
+}
@@ -0,0 +1,14 @@
+use axum::{routing::get, Router};
+
+use crate::handlers::{get_my_progress_timeline, get_my_weaknesses};
+use crate::state::AppState;
+
+/// Creates the student analytics routes (requires authentication).
+pub fn analytics_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/me/analytics/weaknesses", get(get_my_weaknesses))
+        .route(
+            "/api/v1/me/analytics/progress",
+            get(get_my_progress_timeline),
+        )
+}
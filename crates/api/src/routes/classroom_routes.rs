@@ -0,0 +1,58 @@
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use crate::handlers::{
+    create_assignment, create_classroom, delete_assignment, delete_classroom,
+    get_assignment_submissions, get_classroom, join_classroom, leave_classroom, list_assignments,
+    list_classroom_student_results, list_my_assignments, list_my_classrooms,
+    list_teacher_classrooms, regenerate_invite_code, remove_classroom_student,
+};
+use crate::state::AppState;
+
+/// Creates the student classroom routes (requires authentication).
+pub fn classroom_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/classrooms/join", post(join_classroom))
+        .route("/api/v1/me/classrooms", get(list_my_classrooms))
+        .route("/api/v1/me/classrooms/{id}", delete(leave_classroom))
+        .route("/api/v1/me/assignments", get(list_my_assignments))
+}
+
+/// Creates the teacher classroom routes (protected, teacher or admin only).
+pub fn teacher_classroom_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/v1/teacher/classrooms",
+            get(list_teacher_classrooms).post(create_classroom),
+        )
+        .route(
+            "/api/v1/teacher/classrooms/{id}",
+            get(get_classroom).delete(delete_classroom),
+        )
+        .route(
+            "/api/v1/teacher/classrooms/{id}/invite-code",
+            post(regenerate_invite_code),
+        )
+        .route(
+            "/api/v1/teacher/classrooms/{id}/students/{student_id}",
+            delete(remove_classroom_student),
+        )
+        .route(
+            "/api/v1/teacher/classrooms/{id}/students/{student_id}/results",
+            get(list_classroom_student_results),
+        )
+        .route(
+            "/api/v1/teacher/classrooms/{id}/assignments",
+            get(list_assignments).post(create_assignment),
+        )
+        .route(
+            "/api/v1/teacher/assignments/{id}",
+            delete(delete_assignment),
+        )
+        .route(
+            "/api/v1/teacher/assignments/{id}/submissions",
+            get(get_assignment_submissions),
+        )
+}
@@ -0,0 +1,50 @@
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use crate::handlers::{
+    accept_guardian_invitation, get_guardian_student_progress_timeline,
+    get_guardian_student_study_calendar, get_guardian_student_weaknesses, invite_student,
+    list_guardian_student_results, list_guardian_students, list_my_guardians,
+    remove_guardian_student, remove_my_guardian,
+};
+use crate::state::AppState;
+
+/// Creates the guardian routes (protected, guardian or admin only).
+pub fn guardian_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/guardian/invitations", post(invite_student))
+        .route("/api/v1/guardian/students", get(list_guardian_students))
+        .route(
+            "/api/v1/guardian/students/{id}",
+            delete(remove_guardian_student),
+        )
+        .route(
+            "/api/v1/guardian/students/{id}/results",
+            get(list_guardian_student_results),
+        )
+        .route(
+            "/api/v1/guardian/students/{id}/study-calendar",
+            get(get_guardian_student_study_calendar),
+        )
+        .route(
+            "/api/v1/guardian/students/{id}/analytics/weaknesses",
+            get(get_guardian_student_weaknesses),
+        )
+        .route(
+            "/api/v1/guardian/students/{id}/analytics/progress",
+            get(get_guardian_student_progress_timeline),
+        )
+}
+
+/// Creates the routes students use to manage their guardians (requires authentication).
+pub fn student_guardian_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/me/guardians", get(list_my_guardians))
+        .route("/api/v1/me/guardians/{id}", delete(remove_my_guardian))
+        .route(
+            "/api/v1/me/guardians/{id}/accept",
+            post(accept_guardian_invitation),
+        )
+}
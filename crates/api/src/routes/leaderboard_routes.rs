@@ -0,0 +1,31 @@
+use axum::{
+    routing::{get, put},
+    Router,
+};
+
+use crate::handlers::{
+    get_exam_type_weekly_leaderboard, get_practice_test_leaderboard, get_test_book_leaderboard,
+    update_leaderboard_preference,
+};
+use crate::state::AppState;
+
+/// Creates the leaderboard routes (requires authentication).
+pub fn leaderboard_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/v1/leaderboards/practice-tests/{id}",
+            get(get_practice_test_leaderboard),
+        )
+        .route(
+            "/api/v1/leaderboards/test-books/{id}",
+            get(get_test_book_leaderboard),
+        )
+        .route(
+            "/api/v1/leaderboards/exam-types/{id}/weekly",
+            get(get_exam_type_weekly_leaderboard),
+        )
+        .route(
+            "/api/v1/me/leaderboard-preference",
+            put(update_leaderboard_preference),
+        )
+}
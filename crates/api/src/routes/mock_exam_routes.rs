@@ -0,0 +1,48 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::handlers::{
+    create_mock_exam, delete_mock_exam, get_mock_exam, get_mock_exam_attempt, list_mock_exams,
+    list_my_mock_exam_attempts, save_mock_exam_answers, start_mock_exam_attempt,
+    submit_mock_exam_attempt, update_mock_exam,
+};
+use crate::state::AppState;
+
+/// Creates the mock exam routes (public listing, solving requires authentication).
+pub fn mock_exam_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/mock-exams", get(list_mock_exams))
+        .route("/api/v1/mock-exams/{id}", get(get_mock_exam))
+        .route(
+            "/api/v1/mock-exams/{id}/attempts",
+            post(start_mock_exam_attempt),
+        )
+        .route(
+            "/api/v1/mock-exam-attempts/{id}",
+            get(get_mock_exam_attempt),
+        )
+        .route(
+            "/api/v1/mock-exam-attempts/{id}/answers",
+            put(save_mock_exam_answers),
+        )
+        .route(
+            "/api/v1/mock-exam-attempts/{id}/submit",
+            post(submit_mock_exam_attempt),
+        )
+        .route(
+            "/api/v1/my-mock-exam-attempts",
+            get(list_my_mock_exam_attempts),
+        )
+}
+
+/// Creates the admin mock exam routes (protected, admin only).
+pub fn admin_mock_exam_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/admin/mock-exams", post(create_mock_exam))
+        .route(
+            "/api/v1/admin/mock-exams/{id}",
+            put(update_mock_exam).delete(delete_mock_exam),
+        )
+}
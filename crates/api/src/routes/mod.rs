@@ -1,12 +1,33 @@
+mod achievement_routes;
+mod analytics_routes;
 mod auth_routes;
+mod classroom_routes;
+mod guardian_routes;
 mod health_routes;
+mod leaderboard_routes;
+mod mock_exam_routes;
+mod recommendation_routes;
+mod result_import_routes;
 mod role_routes;
+mod score_routes;
+mod study_plan_routes;
 mod test_routes;
+mod topic_routes;
 mod user_routes;
 
-pub use auth_routes::auth_routes;
+pub use achievement_routes::{achievement_routes, admin_achievement_routes};
+pub use analytics_routes::analytics_routes;
+pub use auth_routes::{auth_credential_routes, auth_routes};
+pub use classroom_routes::{classroom_routes, teacher_classroom_routes};
+pub use guardian_routes::{guardian_routes, student_guardian_routes};
 pub use health_routes::health_routes;
+pub use leaderboard_routes::leaderboard_routes;
+pub use mock_exam_routes::{admin_mock_exam_routes, mock_exam_routes};
+pub use recommendation_routes::recommendation_routes;
+pub use result_import_routes::admin_result_import_routes;
 pub use role_routes::admin_role_routes;
+pub use score_routes::{admin_score_routes, score_routes};
+pub use study_plan_routes::study_plan_routes;
 pub use test_routes::{admin_test_routes, test_routes};
+pub use topic_routes::{admin_topic_routes, topic_routes};
 pub use user_routes::admin_user_routes;
-
@@ -0,0 +1,9 @@
+use axum::{routing::get, Router};
+
+use crate::handlers::get_my_recommendations;
+use crate::state::AppState;
+
+/// Creates the practice test recommendation routes (requires authentication).
+pub fn recommendation_routes() -> Router<AppState> {
+    Router::new().route("/api/v1/me/recommendations", get(get_my_recommendations))
+}
@@ -0,0 +1,12 @@
+use axum::{routing::post, Router};
+
+use crate::handlers::import_omr_results;
+use crate::state::AppState;
+
+/// Creates the admin result import routes (protected, admin only).
+pub fn admin_result_import_routes() -> Router<AppState> {
+    Router::new().route(
+        "/api/v1/admin/practice-tests/{id}/omr-import",
+        post(import_omr_results),
+    )
+}
@@ -4,8 +4,9 @@ use axum::{
 };
 
 use crate::handlers::{
-    assign_role_to_user, create_role, delete_role, get_role, list_roles, remove_role_from_user,
-    update_role,
+    assign_role_to_user, create_role, delete_role, get_role, grant_permission_to_role,
+    list_permissions, list_role_permissions, list_roles, remove_role_from_user,
+    revoke_permission_from_role, update_role,
 };
 use crate::state::AppState;
 
@@ -18,6 +19,20 @@ pub fn admin_role_routes() -> Router<AppState> {
         .route("/api/v1/admin/roles/{id}", get(get_role))
         .route("/api/v1/admin/roles/{id}", put(update_role))
         .route("/api/v1/admin/roles/{id}", delete(delete_role))
+        // Role permission routes
+        .route("/api/v1/admin/permissions", get(list_permissions))
+        .route(
+            "/api/v1/admin/roles/{id}/permissions",
+            get(list_role_permissions),
+        )
+        .route(
+            "/api/v1/admin/roles/{id}/permissions",
+            post(grant_permission_to_role),
+        )
+        .route(
+            "/api/v1/admin/roles/{id}/permissions/{permission_id}",
+            delete(revoke_permission_from_role),
+        )
         // User role assignment routes
         .route("/api/v1/admin/users/{id}/roles", post(assign_role_to_user))
         .route(
@@ -25,4 +40,3 @@ pub fn admin_role_routes() -> Router<AppState> {
             delete(remove_role_from_user),
         )
 }
-
@@ -0,0 +1,25 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::handlers::{delete_score_table, estimate_score, list_score_tables, save_score_table};
+use crate::state::AppState;
+
+/// Creates the score estimate routes (authenticated users).
+pub fn score_routes() -> Router<AppState> {
+    Router::new().route("/api/v1/score-estimates", post(estimate_score))
+}
+
+/// Creates the admin score table routes (protected, admin only).
+pub fn admin_score_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/v1/admin/exam-types/{id}/score-tables",
+            get(list_score_tables),
+        )
+        .route(
+            "/api/v1/admin/exam-types/{id}/score-tables/{year}",
+            put(save_score_table).delete(delete_score_table),
+        )
+}
@@ -0,0 +1,25 @@
+use axum::{
+    routing::{delete, get},
+    Router,
+};
+
+use crate::handlers::{
+    archive_my_study_goal, create_my_study_goal, get_my_study_calendar, get_my_study_plan,
+    list_my_study_goals, set_my_study_plan,
+};
+use crate::state::AppState;
+
+/// Creates the study plan, goal and calendar routes (requires authentication).
+pub fn study_plan_routes() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/api/v1/me/study-plan",
+            get(get_my_study_plan).put(set_my_study_plan),
+        )
+        .route(
+            "/api/v1/me/study-goals",
+            get(list_my_study_goals).post(create_my_study_goal),
+        )
+        .route("/api/v1/me/study-goals/{id}", delete(archive_my_study_goal))
+        .route("/api/v1/me/study-calendar", get(get_my_study_calendar))
+}
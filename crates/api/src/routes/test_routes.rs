@@ -5,13 +5,19 @@ use axum::{
 
 use crate::handlers::{
     create_exam_type, create_lesson, create_practice_test, create_subject, create_test_book,
-    delete_exam_type, delete_lesson, delete_practice_test, delete_subject, delete_test_book,
-    get_exam_type, get_lesson, get_practice_test, get_practice_test_public, get_result, get_subject, get_test_book,
-    list_admin_exam_types, list_admin_lessons, list_admin_practice_tests, list_admin_subjects,
-    list_admin_test_books, list_exam_types, list_lessons, list_my_results, list_practice_tests,
-    list_practice_tests_grouped_by_subject, list_practice_tests_with_status, list_subjects,
-    list_test_book_subjects, list_test_books, list_test_books_with_stats, solve_test, update_exam_type,
-    update_lesson, update_practice_test, update_subject, update_test_book,
+    delete_exam_type, delete_exam_type_retake_policy, delete_lesson, delete_practice_test,
+    delete_subject, delete_test_book, delete_test_book_retake_policy, get_exam_session,
+    get_exam_type, get_exam_type_retake_policy, get_lesson, get_practice_test,
+    get_practice_test_public, get_result, get_result_history, get_scoring_policy, get_subject,
+    get_test_book, get_test_book_retake_policy, list_admin_exam_types, list_admin_lessons,
+    list_admin_practice_tests, list_admin_subjects, list_admin_test_books,
+    list_answer_key_revisions, list_exam_types, list_lessons, list_my_results, list_practice_tests,
+    list_practice_tests_grouped_by_subject, list_practice_tests_with_status, list_questions,
+    list_scoring_policies, list_subjects, list_test_book_subjects, list_test_books,
+    list_test_books_with_stats, replace_questions, save_exam_session_answers, solve_test,
+    start_exam_session, submit_exam_session, update_exam_type, update_exam_type_retake_policy,
+    update_lesson, update_practice_test, update_scoring_policy, update_subject, update_test_book,
+    update_test_book_retake_policy,
 };
 use crate::state::AppState;
 
@@ -22,44 +28,118 @@ pub fn test_routes() -> Router<AppState> {
         .route("/api/v1/exam-types", get(list_exam_types))
         .route("/api/v1/subjects", get(list_subjects))
         .route("/api/v1/test-books", get(list_test_books))
-        .route("/api/v1/test-books-with-stats", get(list_test_books_with_stats))
-        .route("/api/v1/test-books/{id}/subjects", get(list_test_book_subjects))
-        .route("/api/v1/test-books/{id}/practice-tests-grouped", get(list_practice_tests_grouped_by_subject))
-        .route("/api/v1/test-books/{id}/practice-tests-with-status", get(list_practice_tests_with_status))
+        .route(
+            "/api/v1/test-books-with-stats",
+            get(list_test_books_with_stats),
+        )
+        .route(
+            "/api/v1/test-books/{id}/subjects",
+            get(list_test_book_subjects),
+        )
+        .route(
+            "/api/v1/test-books/{id}/practice-tests-grouped",
+            get(list_practice_tests_grouped_by_subject),
+        )
+        .route(
+            "/api/v1/test-books/{id}/practice-tests-with-status",
+            get(list_practice_tests_with_status),
+        )
         .route("/api/v1/practice-tests", get(list_practice_tests))
         .route("/api/v1/practice-tests/{id}", get(get_practice_test_public))
         .route("/api/v1/tests/{id}/solve", post(solve_test))
+        // ExamSession routes
+        .route("/api/v1/tests/{id}/sessions", post(start_exam_session))
+        .route("/api/v1/exam-sessions/{id}", get(get_exam_session))
+        .route(
+            "/api/v1/exam-sessions/{id}/answers",
+            put(save_exam_session_answers),
+        )
+        .route(
+            "/api/v1/exam-sessions/{id}/submit",
+            post(submit_exam_session),
+        )
         .route("/api/v1/my-results", get(list_my_results))
         .route("/api/v1/my-results/{id}", get(get_result))
+        .route("/api/v1/my-results/{id}/history", get(get_result_history))
 }
 
 /// Creates the admin test management routes (protected, admin only).
 pub fn admin_test_routes() -> Router<AppState> {
     Router::new()
         // Lesson routes
-        .route("/api/v1/admin/lessons", get(list_admin_lessons).post(create_lesson))
+        .route(
+            "/api/v1/admin/lessons",
+            get(list_admin_lessons).post(create_lesson),
+        )
         .route("/api/v1/admin/lessons/{id}", get(get_lesson))
         .route("/api/v1/admin/lessons/{id}", put(update_lesson))
         .route("/api/v1/admin/lessons/{id}", delete(delete_lesson))
         // ExamType routes
-        .route("/api/v1/admin/exam-types", get(list_admin_exam_types).post(create_exam_type))
+        .route(
+            "/api/v1/admin/exam-types",
+            get(list_admin_exam_types).post(create_exam_type),
+        )
         .route("/api/v1/admin/exam-types/{id}", get(get_exam_type))
         .route("/api/v1/admin/exam-types/{id}", put(update_exam_type))
         .route("/api/v1/admin/exam-types/{id}", delete(delete_exam_type))
+        // ScoringPolicy routes
+        .route(
+            "/api/v1/admin/exam-types/{id}/scoring-policy",
+            get(get_scoring_policy).put(update_scoring_policy),
+        )
+        .route(
+            "/api/v1/admin/exam-types/{id}/scoring-policies",
+            get(list_scoring_policies),
+        )
+        // RetakePolicy routes
+        .route(
+            "/api/v1/admin/exam-types/{id}/retake-policy",
+            get(get_exam_type_retake_policy)
+                .put(update_exam_type_retake_policy)
+                .delete(delete_exam_type_retake_policy),
+        )
+        .route(
+            "/api/v1/admin/test-books/{id}/retake-policy",
+            get(get_test_book_retake_policy)
+                .put(update_test_book_retake_policy)
+                .delete(delete_test_book_retake_policy),
+        )
         // Subject routes
-        .route("/api/v1/admin/subjects", get(list_admin_subjects).post(create_subject))
+        .route(
+            "/api/v1/admin/subjects",
+            get(list_admin_subjects).post(create_subject),
+        )
         .route("/api/v1/admin/subjects/{id}", get(get_subject))
         .route("/api/v1/admin/subjects/{id}", put(update_subject))
         .route("/api/v1/admin/subjects/{id}", delete(delete_subject))
         // TestBook routes
-        .route("/api/v1/admin/test-books", get(list_admin_test_books).post(create_test_book))
+        .route(
+            "/api/v1/admin/test-books",
+            get(list_admin_test_books).post(create_test_book),
+        )
         .route("/api/v1/admin/test-books/{id}", get(get_test_book))
         .route("/api/v1/admin/test-books/{id}", put(update_test_book))
         .route("/api/v1/admin/test-books/{id}", delete(delete_test_book))
         // PracticeTest routes
-        .route("/api/v1/admin/practice-tests", get(list_admin_practice_tests).post(create_practice_test))
+        .route(
+            "/api/v1/admin/practice-tests",
+            get(list_admin_practice_tests).post(create_practice_test),
+        )
         .route("/api/v1/admin/practice-tests/{id}", get(get_practice_test))
-        .route("/api/v1/admin/practice-tests/{id}", put(update_practice_test))
-        .route("/api/v1/admin/practice-tests/{id}", delete(delete_practice_test))
+        .route(
+            "/api/v1/admin/practice-tests/{id}",
+            put(update_practice_test),
+        )
+        .route(
+            "/api/v1/admin/practice-tests/{id}",
+            delete(delete_practice_test),
+        )
+        .route(
+            "/api/v1/admin/practice-tests/{id}/answer-key-revisions",
+            get(list_answer_key_revisions),
+        )
+        .route(
+            "/api/v1/admin/practice-tests/{id}/questions",
+            get(list_questions).put(replace_questions),
+        )
 }
-
@@ -0,0 +1,29 @@
+use axum::{
+    routing::{get, post, put},
+    Router,
+};
+
+use crate::handlers::{
+    create_topic, delete_topic, get_topic, get_topic_tree, move_topic, reorder_topics, update_topic,
+};
+use crate::state::AppState;
+
+/// Creates the public topic routes.
+pub fn topic_routes() -> Router<AppState> {
+    Router::new().route("/api/v1/subjects/{id}/topics", get(get_topic_tree))
+}
+
+/// Creates the admin topic routes (protected, admin only).
+pub fn admin_topic_routes() -> Router<AppState> {
+    Router::new()
+        .route("/api/v1/admin/topics", post(create_topic))
+        .route(
+            "/api/v1/admin/topics/{id}",
+            get(get_topic).put(update_topic).delete(delete_topic),
+        )
+        .route("/api/v1/admin/topics/{id}/move", post(move_topic))
+        .route(
+            "/api/v1/admin/subjects/{id}/topics/order",
+            put(reorder_topics),
+        )
+}
@@ -72,24 +72,62 @@ pub fn create_app(state: AppState) -> Router {
     use utoipa::OpenApi;
     use utoipa_swagger_ui::SwaggerUi;
 
+    use axum::middleware::from_fn_with_state;
+
+    use crate::middleware::{
+        api_rate_limit, auth_rate_limit, RATE_LIMIT_LIMIT, RATE_LIMIT_REMAINING, RATE_LIMIT_RESET,
+    };
+
     // Build CORS layer
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
-        .allow_headers(Any);
-
-    // Build the router
-    Router::new()
-        .merge(routes::health_routes())
+        .allow_headers(Any)
+        .expose_headers([
+            RATE_LIMIT_LIMIT,
+            RATE_LIMIT_REMAINING,
+            RATE_LIMIT_RESET,
+            axum::http::header::RETRY_AFTER,
+        ]);
+
+    // Login and registration are limited per client IP
+    let credential_routes = routes::auth_credential_routes()
+        .route_layer(from_fn_with_state(state.clone(), auth_rate_limit));
+
+    // Every other API route is limited per user, or per client IP when anonymous
+    let api_routes = Router::new()
         .merge(routes::auth_routes())
         .merge(routes::test_routes())
         .merge(routes::admin_test_routes())
         .merge(routes::admin_user_routes())
         .merge(routes::admin_role_routes())
+        .merge(routes::score_routes())
+        .merge(routes::admin_score_routes())
+        .merge(routes::mock_exam_routes())
+        .merge(routes::admin_mock_exam_routes())
+        .merge(routes::admin_result_import_routes())
+        .merge(routes::topic_routes())
+        .merge(routes::admin_topic_routes())
+        .merge(routes::analytics_routes())
+        .merge(routes::leaderboard_routes())
+        .merge(routes::recommendation_routes())
+        .merge(routes::study_plan_routes())
+        .merge(routes::achievement_routes())
+        .merge(routes::admin_achievement_routes())
+        .merge(routes::classroom_routes())
+        .merge(routes::teacher_classroom_routes())
+        .merge(routes::guardian_routes())
+        .merge(routes::student_guardian_routes())
+        .route_layer(from_fn_with_state(state.clone(), api_rate_limit));
+
+    // Build the router
+    Router::new()
+        .merge(routes::health_routes())
+        .merge(credential_routes)
+        .merge(api_routes)
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
         .layer(CompressionLayer::new())
         .layer(TraceLayer::new_for_http())
         .layer(cors)
         .with_state(state)
 }
-
@@ -1,17 +1,34 @@
 use std::sync::Arc;
 
 use application::services::{
-    AuthService, AuthServiceImpl, JwtOperations, PasswordOperations, ResultService,
-    ResultServiceImpl, TestManagementService, TestManagementServiceImpl, TestSolvingService,
-    TestSolvingServiceImpl,
+    AchievementService, AchievementServiceImpl, AnalyticsService, AnalyticsServiceImpl,
+    AssignmentService, AssignmentServiceImpl, AuthService, AuthServiceImpl, ClassroomService,
+    ClassroomServiceImpl, GuardianService, GuardianServiceImpl, JwtOperations, LeaderboardService,
+    LeaderboardServiceImpl, MockExamService, MockExamServiceImpl, PasswordOperations,
+    RecommendationService, RecommendationServiceImpl, RegradeService, RegradeServiceImpl,
+    ResultImportService, ResultImportServiceImpl, ResultService, ResultServiceImpl,
+    RetakePolicyService, RetakePolicyServiceImpl, ScoreCalculationService,
+    ScoreCalculationServiceImpl, StudyPlanService, StudyPlanServiceImpl, TestManagementService,
+    TestManagementServiceImpl, TestSolvingService, TestSolvingServiceImpl, TopicService,
+    TopicServiceImpl,
+};
+use infrastructure::cache::{
+    create_catalog_cache, CachedExamTypeRepository, CachedLessonRepository,
+    CachedPracticeTestRepository, CachedSubjectRepository, CachedTestBookRepository,
+    CachedTestBookSubjectRepository, RedisConnection,
 };
 use infrastructure::config::Settings;
 use infrastructure::database::repositories::{
-    PgExamTypeRepository, PgLessonRepository, PgPracticeTestRepository, PgRefreshTokenRepository,
-    PgRoleRepository, PgSubjectRepository, PgTestBookRepository, PgTestBookSubjectRepository,
-    PgTestResultRepository, PgUserRepository,
+    PgAchievementRepository, PgAnalyticsRepository, PgAssignmentRepository, PgClassroomRepository,
+    PgExamSessionRepository, PgExamTypeRepository, PgGuardianLinkRepository, PgLessonRepository,
+    PgMockExamAttemptRepository, PgMockExamRepository, PgPermissionRepository,
+    PgPracticeTestRepository, PgRefreshTokenRepository, PgRetakePolicyRepository, PgRoleRepository,
+    PgScoreTableRepository, PgScoringPolicyRepository, PgStudyPlanRepository, PgSubjectRepository,
+    PgTestBookRepository, PgTestBookSubjectRepository, PgTestResultRepository, PgTopicRepository,
+    PgUserRepository,
 };
 use infrastructure::database::DatabasePool;
+use infrastructure::rate_limit::{create_rate_limit_store, RateLimitStore};
 use infrastructure::security::{JwtConfig, JwtService, PasswordService};
 use uuid::Uuid;
 
@@ -32,31 +49,67 @@ pub struct AppState {
     pub test_solving_service: Arc<dyn TestSolvingService>,
     /// Result service
     pub result_service: Arc<dyn ResultService>,
+    /// Standard score calculation service
+    pub score_calculation_service: Arc<dyn ScoreCalculationService>,
+    /// Answer key re-grade service
+    pub regrade_service: Arc<dyn RegradeService>,
+    /// Retake policy management service
+    pub retake_policy_service: Arc<dyn RetakePolicyService>,
+    /// Mock exam service
+    pub mock_exam_service: Arc<dyn MockExamService>,
+    /// Leaderboard service
+    pub leaderboard_service: Arc<dyn LeaderboardService>,
+    /// Paper result import service
+    pub result_import_service: Arc<dyn ResultImportService>,
+    /// Curriculum topic service
+    pub topic_service: Arc<dyn TopicService>,
+    /// Student performance analytics service
+    pub analytics_service: Arc<dyn AnalyticsService>,
+    /// Practice test recommendation service
+    pub recommendation_service: Arc<dyn RecommendationService>,
+    /// Study plan, goal and streak service
+    pub study_plan_service: Arc<dyn StudyPlanService>,
+    /// Achievement service
+    pub achievement_service: Arc<dyn AchievementService>,
+    /// Classroom and enrollment service
+    pub classroom_service: Arc<dyn ClassroomService>,
+    /// Classroom assignment service
+    pub assignment_service: Arc<dyn AssignmentService>,
+    /// Guardian link service
+    pub guardian_service: Arc<dyn GuardianService>,
     /// Application settings
     pub settings: Arc<Settings>,
+    /// Shared Redis connection, `None` when running on in-process stores
+    pub redis: Option<RedisConnection>,
+    /// Request counters for rate limiting
+    pub rate_limit_store: Arc<dyn RateLimitStore>,
     /// User repository (singleton)
     pub user_repo: Arc<PgUserRepository>,
     /// Role repository (singleton)
     pub role_repo: Arc<PgRoleRepository>,
+    /// Permission repository (singleton)
+    pub permission_repo: Arc<PgPermissionRepository>,
     /// Refresh token repository (singleton)
     pub refresh_token_repo: Arc<PgRefreshTokenRepository>,
     /// Lesson repository (singleton)
-    pub lesson_repo: Arc<PgLessonRepository>,
+    pub lesson_repo: Arc<CachedLessonRepository<PgLessonRepository>>,
     /// Exam type repository (singleton)
-    pub exam_type_repo: Arc<PgExamTypeRepository>,
+    pub exam_type_repo: Arc<CachedExamTypeRepository<PgExamTypeRepository>>,
     /// Subject repository (singleton)
-    pub subject_repo: Arc<PgSubjectRepository>,
+    pub subject_repo: Arc<CachedSubjectRepository<PgSubjectRepository>>,
     /// Test book repository (singleton)
-    pub test_book_repo: Arc<PgTestBookRepository>,
+    pub test_book_repo: Arc<CachedTestBookRepository<PgTestBookRepository>>,
     /// Practice test repository (singleton)
-    pub practice_test_repo: Arc<PgPracticeTestRepository>,
+    pub practice_test_repo: Arc<CachedPracticeTestRepository<PgPracticeTestRepository>>,
     /// Test result repository (singleton)
     pub test_result_repo: Arc<PgTestResultRepository>,
+    /// Scoring policy repository (singleton)
+    pub scoring_policy_repo: Arc<PgScoringPolicyRepository>,
 }
 
 impl AppState {
     /// Creates a new application state with all services initialized.
-    pub fn new(db_pool: DatabasePool, settings: Settings) -> Self {
+    pub fn new(db_pool: DatabasePool, settings: Settings, redis: Option<RedisConnection>) -> Self {
         // Initialize JWT service
         let jwt_config = JwtConfig::new(
             settings.jwt.secret.clone(),
@@ -68,17 +121,53 @@ impl AppState {
         // Initialize password service
         let password_service = Arc::new(PasswordService::new());
 
+        // Initialize rate limit store and catalog cache
+        let rate_limit_store = create_rate_limit_store(&settings, redis.clone());
+        let catalog_cache = create_catalog_cache(&settings, redis.clone());
+
         // Initialize repositories
         let user_repo = Arc::new(PgUserRepository::new(db_pool.clone()));
         let refresh_token_repo = Arc::new(PgRefreshTokenRepository::new(db_pool.clone()));
         let role_repo = Arc::new(PgRoleRepository::new(db_pool.clone()));
-        let lesson_repo = Arc::new(PgLessonRepository::new(db_pool.clone()));
-        let exam_type_repo = Arc::new(PgExamTypeRepository::new(db_pool.clone()));
-        let subject_repo = Arc::new(PgSubjectRepository::new(db_pool.clone()));
-        let test_book_repo = Arc::new(PgTestBookRepository::new(db_pool.clone()));
-        let test_book_subject_repo = Arc::new(PgTestBookSubjectRepository::new(db_pool.clone()));
-        let practice_test_repo = Arc::new(PgPracticeTestRepository::new(db_pool.clone()));
+        let permission_repo = Arc::new(PgPermissionRepository::new(db_pool.clone()));
+        let lesson_repo = Arc::new(CachedLessonRepository::new(
+            PgLessonRepository::new(db_pool.clone()),
+            catalog_cache.clone(),
+        ));
+        let exam_type_repo = Arc::new(CachedExamTypeRepository::new(
+            PgExamTypeRepository::new(db_pool.clone()),
+            catalog_cache.clone(),
+        ));
+        let subject_repo = Arc::new(CachedSubjectRepository::new(
+            PgSubjectRepository::new(db_pool.clone()),
+            catalog_cache.clone(),
+        ));
+        let test_book_repo = Arc::new(CachedTestBookRepository::new(
+            PgTestBookRepository::new(db_pool.clone()),
+            catalog_cache.clone(),
+        ));
+        let test_book_subject_repo = Arc::new(CachedTestBookSubjectRepository::new(
+            PgTestBookSubjectRepository::new(db_pool.clone()),
+            catalog_cache.clone(),
+        ));
+        let practice_test_repo = Arc::new(CachedPracticeTestRepository::new(
+            PgPracticeTestRepository::new(db_pool.clone()),
+            catalog_cache.clone(),
+        ));
         let test_result_repo = Arc::new(PgTestResultRepository::new(db_pool.clone()));
+        let scoring_policy_repo = Arc::new(PgScoringPolicyRepository::new(db_pool.clone()));
+        let score_table_repo = Arc::new(PgScoreTableRepository::new(db_pool.clone()));
+        let retake_policy_repo = Arc::new(PgRetakePolicyRepository::new(db_pool.clone()));
+        let exam_session_repo = Arc::new(PgExamSessionRepository::new(db_pool.clone()));
+        let mock_exam_repo = Arc::new(PgMockExamRepository::new(db_pool.clone()));
+        let mock_exam_attempt_repo = Arc::new(PgMockExamAttemptRepository::new(db_pool.clone()));
+        let topic_repo = Arc::new(PgTopicRepository::new(db_pool.clone()));
+        let analytics_repo = Arc::new(PgAnalyticsRepository::new(db_pool.clone()));
+        let study_plan_repo = Arc::new(PgStudyPlanRepository::new(db_pool.clone()));
+        let achievement_repo = Arc::new(PgAchievementRepository::new(db_pool.clone()));
+        let classroom_repo = Arc::new(PgClassroomRepository::new(db_pool.clone()));
+        let assignment_repo = Arc::new(PgAssignmentRepository::new(db_pool.clone()));
+        let guardian_link_repo = Arc::new(PgGuardianLinkRepository::new(db_pool.clone()));
 
         // Create adapters for the auth service
         let jwt_adapter = Arc::new(JwtAdapter(jwt_service.clone()));
@@ -91,30 +180,154 @@ impl AppState {
             jwt_adapter,
             password_adapter,
             role_repo.clone(),
+            permission_repo.clone(),
         ));
 
         // Initialize test management service
-        let test_management_service: Arc<dyn TestManagementService> = Arc::new(
-            TestManagementServiceImpl::new(
+        let test_management_service: Arc<dyn TestManagementService> =
+            Arc::new(TestManagementServiceImpl::new(
                 lesson_repo.clone(),
                 exam_type_repo.clone(),
                 subject_repo.clone(),
                 test_book_repo.clone(),
                 test_book_subject_repo.clone(),
                 practice_test_repo.clone(),
-            ),
-        );
+                scoring_policy_repo.clone(),
+                topic_repo.clone(),
+            ));
 
         // Initialize test solving service
         let test_solving_service: Arc<dyn TestSolvingService> =
             Arc::new(TestSolvingServiceImpl::new(
                 practice_test_repo.clone(),
                 test_result_repo.clone(),
+                test_book_repo.clone(),
+                test_book_subject_repo.clone(),
+                scoring_policy_repo.clone(),
+                exam_type_repo.clone(),
+                retake_policy_repo.clone(),
+                exam_session_repo,
+                achievement_repo.clone(),
             ));
 
         // Initialize result service
-        let result_service: Arc<dyn ResultService> =
-            Arc::new(ResultServiceImpl::new(test_result_repo.clone()));
+        let result_service: Arc<dyn ResultService> = Arc::new(ResultServiceImpl::new(
+            test_result_repo.clone(),
+            practice_test_repo.clone(),
+            test_book_repo.clone(),
+            exam_type_repo.clone(),
+        ));
+
+        // Initialize score calculation service
+        let score_calculation_service: Arc<dyn ScoreCalculationService> =
+            Arc::new(ScoreCalculationServiceImpl::new(
+                exam_type_repo.clone(),
+                score_table_repo,
+                lesson_repo.clone(),
+                subject_repo.clone(),
+                test_book_repo.clone(),
+                practice_test_repo.clone(),
+                test_result_repo.clone(),
+                scoring_policy_repo.clone(),
+            ));
+
+        // Initialize regrade service
+        let regrade_service: Arc<dyn RegradeService> = Arc::new(RegradeServiceImpl::new(
+            practice_test_repo.clone(),
+            test_result_repo.clone(),
+            scoring_policy_repo.clone(),
+        ));
+
+        // Initialize retake policy service
+        let retake_policy_service: Arc<dyn RetakePolicyService> =
+            Arc::new(RetakePolicyServiceImpl::new(
+                retake_policy_repo.clone(),
+                exam_type_repo.clone(),
+                test_book_repo.clone(),
+            ));
+
+        // Initialize mock exam service
+        let mock_exam_service: Arc<dyn MockExamService> = Arc::new(MockExamServiceImpl::new(
+            mock_exam_repo,
+            mock_exam_attempt_repo,
+            practice_test_repo.clone(),
+            test_book_repo.clone(),
+            exam_type_repo.clone(),
+            scoring_policy_repo.clone(),
+            test_result_repo.clone(),
+            retake_policy_repo.clone(),
+        ));
+
+        // Initialize leaderboard service
+        let leaderboard_service: Arc<dyn LeaderboardService> =
+            Arc::new(LeaderboardServiceImpl::new(
+                test_result_repo.clone(),
+                practice_test_repo.clone(),
+                test_book_repo.clone(),
+                exam_type_repo.clone(),
+                user_repo.clone(),
+            ));
+
+        // Initialize result import service
+        let result_import_service: Arc<dyn ResultImportService> =
+            Arc::new(ResultImportServiceImpl::new(
+                practice_test_repo.clone(),
+                test_book_repo.clone(),
+                scoring_policy_repo.clone(),
+                user_repo.clone(),
+                test_result_repo.clone(),
+            ));
+
+        // Initialize topic service
+        let topic_service: Arc<dyn TopicService> =
+            Arc::new(TopicServiceImpl::new(topic_repo, subject_repo.clone()));
+
+        // Initialize analytics service
+        let analytics_service: Arc<dyn AnalyticsService> =
+            Arc::new(AnalyticsServiceImpl::new(analytics_repo));
+
+        // Initialize recommendation service
+        let recommendation_service: Arc<dyn RecommendationService> =
+            Arc::new(RecommendationServiceImpl::new(
+                test_result_repo.clone(),
+                practice_test_repo.clone(),
+                test_book_repo.clone(),
+                retake_policy_repo,
+            ));
+
+        // Initialize study plan service
+        let study_plan_service: Arc<dyn StudyPlanService> = Arc::new(StudyPlanServiceImpl::new(
+            study_plan_repo,
+            exam_type_repo.clone(),
+            lesson_repo.clone(),
+        ));
+
+        // Initialize achievement service
+        let achievement_service: Arc<dyn AchievementService> =
+            Arc::new(AchievementServiceImpl::new(
+                achievement_repo,
+                exam_type_repo.clone(),
+                lesson_repo.clone(),
+            ));
+
+        // Initialize classroom service
+        let classroom_service: Arc<dyn ClassroomService> = Arc::new(ClassroomServiceImpl::new(
+            classroom_repo.clone(),
+            test_result_repo.clone(),
+        ));
+
+        // Initialize assignment service
+        let assignment_service: Arc<dyn AssignmentService> = Arc::new(AssignmentServiceImpl::new(
+            assignment_repo,
+            classroom_repo,
+            practice_test_repo.clone(),
+        ));
+
+        // Initialize guardian service
+        let guardian_service: Arc<dyn GuardianService> = Arc::new(GuardianServiceImpl::new(
+            guardian_link_repo,
+            user_repo.clone(),
+        ));
 
         Self {
             db_pool,
@@ -124,10 +337,27 @@ impl AppState {
             test_management_service,
             test_solving_service,
             result_service,
+            score_calculation_service,
+            regrade_service,
+            retake_policy_service,
+            mock_exam_service,
+            leaderboard_service,
+            result_import_service,
+            topic_service,
+            analytics_service,
+            recommendation_service,
+            study_plan_service,
+            achievement_service,
+            classroom_service,
+            assignment_service,
+            guardian_service,
             settings: Arc::new(settings),
+            redis,
+            rate_limit_store,
             // Repository singletons
             user_repo,
             role_repo,
+            permission_repo,
             refresh_token_repo,
             lesson_repo,
             exam_type_repo,
@@ -135,6 +365,7 @@ impl AppState {
             test_book_repo,
             practice_test_repo,
             test_result_repo,
+            scoring_policy_repo,
         }
     }
 }
@@ -177,4 +408,3 @@ impl PasswordOperations for PasswordAdapter {
             .map_err(|e| e.to_string())
     }
 }
-
@@ -0,0 +1,37 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Achievement DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAchievementRequest {
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub rule: String, // "solving_streak" | "tests_solved" | "test_books_completed" | "net_score"
+    pub threshold: f64,
+    pub exam_type_id: Option<Uuid>,
+    pub lesson_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementResponse {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub description: String,
+    pub rule: String,
+    pub threshold: f64,
+    pub exam_type_id: Option<Uuid>,
+    pub lesson_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AchievementProgressResponse {
+    pub achievement: AchievementResponse,
+    pub earned: bool,
+    pub awarded_at: Option<DateTime<Utc>>,
+    pub current: f64,
+    pub target: f64,
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Analytics DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceStatResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub attempt_count: i32,
+    pub correct_count: i32,
+    pub wrong_count: i32,
+    pub empty_count: i32,
+    pub accuracy: f64,
+    pub average_net: Option<f64>,
+    pub trend: String, // "improving" | "declining" | "stable" | "insufficient_data"
+    pub trend_delta: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionErrorRateResponse {
+    pub practice_test_id: Uuid,
+    pub practice_test_name: String,
+    pub question_number: i32,
+    pub attempt_count: i32,
+    pub wrong_count: i32,
+    pub empty_count: i32,
+    pub error_rate: f64,
+    pub topic_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WeaknessReportResponse {
+    pub total_attempts: i32,
+    pub lessons: Vec<PerformanceStatResponse>,
+    pub subjects: Vec<PerformanceStatResponse>,
+    pub topics: Vec<PerformanceStatResponse>,
+    pub missed_questions: Vec<QuestionErrorRateResponse>,
+}
+
+// Progress timeline DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProgressTimelineRequest {
+    pub granularity: String, // "day" | "week" | "month"
+    pub group_by: String,    // "lesson" | "exam_type"
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressPointResponse {
+    pub bucket_start: DateTime<Utc>,
+    pub average_net: f64,
+    pub test_count: i64,
+    pub best_net: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressSeriesResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub points: Vec<ProgressPointResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressTimelineResponse {
+    pub granularity: String,
+    pub group_by: String,
+    pub series: Vec<ProgressSeriesResponse>,
+}
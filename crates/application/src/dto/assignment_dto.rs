@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Assignment DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateAssignmentRequest {
+    pub practice_test_id: Uuid,
+    pub due_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentResponse {
+    pub id: Uuid,
+    pub classroom_id: Uuid,
+    pub practice_test_id: Uuid,
+    pub practice_test_name: String,
+    pub due_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+    pub student_count: i64,
+    pub submitted_count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentSubmissionResponse {
+    pub student_id: Uuid,
+    pub username: String,
+    pub status: String, // "pending" | "completed" | "late" | "missing"
+    pub test_result_id: Option<Uuid>,
+    pub net_score: Option<f64>,
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentSubmissionsResponse {
+    pub assignment_id: Uuid,
+    pub due_at: DateTime<Utc>,
+    pub submissions: Vec<AssignmentSubmissionResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentAssignmentResponse {
+    pub id: Uuid,
+    pub classroom_id: Uuid,
+    pub classroom_name: String,
+    pub practice_test_id: Uuid,
+    pub practice_test_name: String,
+    pub due_at: DateTime<Utc>,
+    pub status: String,
+    pub test_result_id: Option<Uuid>,
+    pub net_score: Option<f64>,
+    pub solved_at: Option<DateTime<Utc>>,
+}
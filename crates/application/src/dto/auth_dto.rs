@@ -7,7 +7,11 @@ use validator::Validate;
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct RegisterRequest {
     /// Username (3-50 characters, alphanumeric and underscores)
-    #[validate(length(min = 3, max = 50, message = "Username must be between 3 and 50 characters"))]
+    #[validate(length(
+        min = 3,
+        max = 50,
+        message = "Username must be between 3 and 50 characters"
+    ))]
     #[validate(regex(
         path = *USERNAME_REGEX,
         message = "Username can only contain letters, numbers, and underscores"
@@ -96,6 +100,10 @@ pub struct UserResponse {
     pub email: String,
     /// Whether the account is active
     pub is_active: bool,
+    /// Student number printed on optical answer forms
+    pub student_number: Option<String>,
+    /// Whether the user is hidden from public leaderboards
+    pub leaderboard_opt_out: bool,
     /// User's roles
     pub roles: Vec<String>,
     /// When the user was created
@@ -137,4 +145,3 @@ impl LoginRequest {
         self.email = self.email.trim().to_lowercase();
     }
 }
-
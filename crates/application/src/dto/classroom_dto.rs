@@ -0,0 +1,38 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Classroom DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateClassroomRequest {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinClassroomRequest {
+    pub invite_code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassroomResponse {
+    pub id: Uuid,
+    pub teacher_id: Uuid,
+    pub name: String,
+    /// Only shown to the classroom's teacher
+    pub invite_code: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassroomStudentResponse {
+    pub student_id: Uuid,
+    pub username: String,
+    pub enrolled_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassroomDetailResponse {
+    pub classroom: ClassroomResponse,
+    pub students: Vec<ClassroomStudentResponse>,
+}
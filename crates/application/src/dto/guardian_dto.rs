@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Guardian DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InviteStudentRequest {
+    pub student: String, // username or email
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianLinkResponse {
+    pub id: Uuid,
+    pub guardian_id: Uuid,
+    pub guardian_username: String,
+    pub student_id: Uuid,
+    pub student_username: String,
+    pub status: String,
+    pub invited_at: DateTime<Utc>,
+    pub accepted_at: Option<DateTime<Utc>>,
+}
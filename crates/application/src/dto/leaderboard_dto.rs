@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+// Leaderboard DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntryResponse {
+    pub rank: i64,
+    pub username: String,
+    pub score: f64,
+    pub test_count: i64,
+    /// Whether the entry belongs to the requesting user
+    pub is_me: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardResponse {
+    pub entries: Vec<LeaderboardEntryResponse>,
+    pub total_entries: u64,
+    pub my_entry: Option<LeaderboardEntryResponse>,
+    /// Start and end of the ranked week for weekly leaderboards
+    pub week_start: Option<DateTime<Utc>>,
+    pub week_end: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardPreferenceResponse {
+    pub leaderboard_opt_out: bool,
+}
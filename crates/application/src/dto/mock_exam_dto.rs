@@ -0,0 +1,109 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use super::TestResultResponse;
+
+// MockExam DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExamSectionResponse {
+    pub practice_test_id: Uuid,
+    pub position: i32,
+    pub name: String,
+    pub subject_id: Uuid,
+    pub question_count: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExamResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub exam_type_id: Uuid,
+    pub time_limit_minutes: i32,
+    pub question_count: i32,
+    pub sections: Vec<MockExamSectionResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CreateMockExamRequest {
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Name must be between 1 and 200 characters"
+    ))]
+    pub name: String,
+    pub exam_type_id: Uuid,
+    #[validate(range(min = 1, message = "Time limit must be at least 1 minute"))]
+    pub time_limit_minutes: i32,
+    /// Practice tests forming the sections, in solving order
+    #[validate(length(min = 1, message = "At least one section is required"))]
+    pub practice_test_ids: Vec<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct UpdateMockExamRequest {
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Name must be between 1 and 200 characters"
+    ))]
+    pub name: Option<String>,
+    #[validate(range(min = 1, message = "Time limit must be at least 1 minute"))]
+    pub time_limit_minutes: Option<i32>,
+    /// Replaces the sections, in solving order
+    #[validate(length(min = 1, message = "At least one section is required"))]
+    pub practice_test_ids: Option<Vec<Uuid>>,
+}
+
+// MockExamAttempt DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExamAttemptSectionResponse {
+    pub practice_test_id: Uuid,
+    pub position: i32,
+    pub answers: String,
+    /// Graded result of the section, set once the attempt is submitted
+    pub result: Option<TestResultResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExamAttemptResponse {
+    pub id: Uuid,
+    pub mock_exam_id: Uuid,
+    pub status: String, // "in_progress" | "submitted" | "expired"
+    pub started_at: DateTime<Utc>,
+    pub expires_at: DateTime<Utc>,
+    /// Seconds left while in progress; `None` once finished
+    pub remaining_seconds: Option<i64>,
+    pub last_saved_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub total_correct: Option<i32>,
+    pub total_wrong: Option<i32>,
+    pub total_empty: Option<i32>,
+    pub total_net: Option<f64>,
+    pub sections: Vec<MockExamAttemptSectionResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct MockExamSectionAnswersDto {
+    pub practice_test_id: Uuid,
+    #[validate(length(min = 1, message = "Answers cannot be empty"))]
+    pub answers: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SaveMockExamAnswersRequest {
+    /// Sections to autosave; sections left out keep their saved answers
+    #[validate(nested)]
+    pub sections: Vec<MockExamSectionAnswersDto>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SubmitMockExamAttemptRequest {
+    /// Final answers; sections left out are graded with their autosaved answers
+    #[serde(default)]
+    #[validate(nested)]
+    pub sections: Vec<MockExamSectionAnswersDto>,
+}
@@ -1,6 +1,29 @@
+mod achievement_dto;
+mod analytics_dto;
+mod assignment_dto;
 mod auth_dto;
+mod classroom_dto;
+mod guardian_dto;
+mod leaderboard_dto;
+mod mock_exam_dto;
+mod recommendation_dto;
+mod result_import_dto;
+mod score_dto;
+mod study_plan_dto;
 mod test_dto;
+mod topic_dto;
 
+pub use achievement_dto::*;
+pub use analytics_dto::*;
+pub use assignment_dto::*;
 pub use auth_dto::*;
+pub use classroom_dto::*;
+pub use guardian_dto::*;
+pub use leaderboard_dto::*;
+pub use mock_exam_dto::*;
+pub use recommendation_dto::*;
+pub use result_import_dto::*;
+pub use score_dto::*;
+pub use study_plan_dto::*;
 pub use test_dto::*;
-
+pub use topic_dto::*;
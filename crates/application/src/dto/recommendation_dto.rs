@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Recommendation DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecommendationResponse {
+    pub practice_test_id: Uuid,
+    pub practice_test_name: String,
+    pub test_number: i32,
+    pub test_book_id: Uuid,
+    pub test_book_name: String,
+    pub subject_id: Uuid,
+    pub reason: String, // "spaced_review" | "weak_subject" | "continue_book"
+    pub priority: f64,
+    pub last_accuracy: Option<f64>,
+    pub last_solved_at: Option<DateTime<Utc>>,
+    pub review_due_at: Option<DateTime<Utc>>,
+    pub subject_accuracy: Option<f64>,
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+// OMR import DTOs
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct ImportOmrResultsRequest {
+    pub format: String, // "csv" | "fixed_width"
+    #[validate(length(min = 1, message = "Content cannot be empty"))]
+    pub content: String,
+    /// CSV column delimiter; defaults to ','
+    pub delimiter: Option<char>,
+    /// Whether the first CSV line is a header
+    pub has_header: bool,
+    /// 1-based column where the student number starts (fixed width)
+    pub student_number_start: Option<usize>,
+    /// Length of the student number (fixed width)
+    pub student_number_length: Option<usize>,
+    /// 1-based column where the answers start (fixed width)
+    pub answers_start: Option<usize>,
+    /// Import rows of students who already have a result for the test
+    pub allow_existing: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OmrImportRowResponse {
+    pub line_number: usize,
+    pub student_number: Option<String>,
+    pub status: String, // "imported" | "failed"
+    pub user_id: Option<Uuid>,
+    pub result_id: Option<Uuid>,
+    pub net_score: Option<f64>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OmrImportReportResponse {
+    pub practice_test_id: Uuid,
+    pub total_rows: usize,
+    pub imported_count: usize,
+    pub failed_count: usize,
+    pub rows: Vec<OmrImportRowResponse>,
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+// ScoreTable DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LessonCoefficientDto {
+    pub lesson_id: Uuid,
+    pub coefficient: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreTableResponse {
+    pub id: Uuid,
+    pub exam_type_id: Uuid,
+    pub year: i32,
+    pub base_score: f64,
+    pub diploma_coefficient: f64,
+    pub coefficients: Vec<LessonCoefficientDto>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SaveScoreTableRequest {
+    #[validate(range(min = 0.0, message = "Base score cannot be negative"))]
+    pub base_score: f64,
+    #[validate(range(
+        min = 0.0,
+        max = 1.0,
+        message = "Diploma coefficient must be between 0 and 1"
+    ))]
+    pub diploma_coefficient: Option<f64>,
+    #[validate(length(min = 1, message = "At least one lesson coefficient is required"))]
+    pub coefficients: Vec<LessonCoefficientDto>,
+}
+
+// Score estimate DTOs
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubjectNetDto {
+    pub subject_id: Uuid,
+    pub net: f64,
+    /// Number of questions the net was scored out of
+    pub question_count: i32,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct EstimateScoreRequest {
+    pub exam_type_id: Uuid,
+    /// Coefficient year to use; the most recent table is used when omitted
+    pub year: Option<i32>,
+    #[serde(default)]
+    pub result_ids: Vec<Uuid>,
+    #[serde(default)]
+    pub subject_nets: Vec<SubjectNetDto>,
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "Diploma grade must be between 0 and 100"
+    ))]
+    pub diploma_grade: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LessonScoreResponse {
+    pub lesson_id: Uuid,
+    pub lesson_name: String,
+    pub net: f64,
+    pub coefficient: f64,
+    pub points: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreEstimateResponse {
+    pub exam_type_id: Uuid,
+    pub year: i32,
+    pub base_score: f64,
+    pub lessons: Vec<LessonScoreResponse>,
+    pub raw_score: f64,
+    pub diploma_score: Option<f64>,
+    pub placement_score: Option<f64>,
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// Study Plan DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetStudyPlanRequest {
+    pub exam_type_id: Uuid,
+    pub target_date: NaiveDate,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyPlanResponse {
+    pub id: Uuid,
+    pub exam_type_id: Uuid,
+    pub exam_type_name: String,
+    pub target_date: NaiveDate,
+    pub days_until_exam: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+// Study Goal DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateStudyGoalRequest {
+    pub metric: String, // "tests" | "questions"
+    pub daily_target: i32,
+    pub lesson_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyGoalResponse {
+    pub id: Uuid,
+    pub metric: String,
+    pub daily_target: i32,
+    pub lesson_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+// Study Calendar DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyCalendarRequest {
+    pub view: String, // "day" | "week"
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalProgressResponse {
+    pub goal_id: Uuid,
+    pub achieved: i64,
+    pub target: i64,
+    pub met_days: u32,
+    pub active_days: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyCalendarEntryResponse {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub completed: bool,
+    pub completed_days: u32,
+    pub active_days: u32,
+    pub goals: Vec<GoalProgressResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyCalendarResponse {
+    pub view: String,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub current_streak: u32,
+    pub longest_streak: u32,
+    pub entries: Vec<StudyCalendarEntryResponse>,
+}
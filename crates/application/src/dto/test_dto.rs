@@ -3,6 +3,8 @@ use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 use validator::Validate;
 
+use super::AchievementResponse;
+
 // Lesson DTOs
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LessonResponse {
@@ -13,13 +15,21 @@ pub struct LessonResponse {
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct CreateLessonRequest {
-    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
     pub name: String,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct UpdateLessonRequest {
-    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
     pub name: Option<String>,
 }
 
@@ -29,21 +39,87 @@ pub struct ExamTypeResponse {
     pub id: Uuid,
     pub name: String,
     pub description: Option<String>,
+    pub reveal_answer_keys: bool,
     pub created_at: DateTime<Utc>,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct CreateExamTypeRequest {
-    #[validate(length(min = 1, max = 50, message = "Name must be between 1 and 50 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Name must be between 1 and 50 characters"
+    ))]
     pub name: String,
     pub description: Option<String>,
+    pub reveal_answer_keys: Option<bool>,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct UpdateExamTypeRequest {
-    #[validate(length(min = 1, max = 50, message = "Name must be between 1 and 50 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Name must be between 1 and 50 characters"
+    ))]
     pub name: Option<String>,
     pub description: Option<String>,
+    pub reveal_answer_keys: Option<bool>,
+}
+
+// ScoringPolicy DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringPolicyResponse {
+    pub id: Uuid,
+    pub exam_type_id: Uuid,
+    pub version: i32,
+    pub penalty_ratio: f64,
+    pub question_weight: f64,
+    pub rounding_mode: String,
+    pub decimal_places: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct UpdateScoringPolicyRequest {
+    #[validate(range(
+        min = 0.0,
+        max = 1.0,
+        message = "Penalty ratio must be between 0 and 1"
+    ))]
+    pub penalty_ratio: f64,
+    #[validate(range(
+        exclusive_min = 0.0,
+        message = "Question weight must be greater than 0"
+    ))]
+    pub question_weight: f64,
+    pub rounding_mode: String, // "none" | "nearest" | "floor"
+    #[validate(range(min = 0, max = 4, message = "Decimal places must be between 0 and 4"))]
+    pub decimal_places: i32,
+}
+
+// RetakePolicy DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetakePolicyResponse {
+    pub id: Uuid,
+    pub exam_type_id: Option<Uuid>,
+    pub test_book_id: Option<Uuid>,
+    pub cooldown_minutes: i32,
+    pub max_attempts: Option<i32>,
+    pub attempt_scoring: String,
+    pub unlimited_practice: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct UpdateRetakePolicyRequest {
+    #[validate(range(min = 0, message = "Cooldown cannot be negative"))]
+    pub cooldown_minutes: i32,
+    #[validate(range(min = 1, message = "Max attempts must be at least 1"))]
+    pub max_attempts: Option<i32>,
+    pub attempt_scoring: String, // "first" | "best"
+    pub unlimited_practice: bool,
 }
 
 // Subject DTOs
@@ -58,7 +134,11 @@ pub struct SubjectResponse {
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct CreateSubjectRequest {
-    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
     pub name: String,
     pub lesson_id: Uuid,
     pub exam_type_id: Uuid,
@@ -66,7 +146,11 @@ pub struct CreateSubjectRequest {
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct UpdateSubjectRequest {
-    #[validate(length(min = 1, max = 100, message = "Name must be between 1 and 100 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
     pub name: Option<String>,
     pub lesson_id: Option<Uuid>,
     pub exam_type_id: Option<Uuid>,
@@ -86,24 +170,40 @@ pub struct TestBookResponse {
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct CreateTestBookRequest {
-    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Name must be between 1 and 255 characters"
+    ))]
     pub name: String,
     pub lesson_id: Uuid,
     pub exam_type_id: Uuid,
     #[validate(length(min = 1, message = "At least one subject is required"))]
     pub subject_ids: Vec<Uuid>,
-    #[validate(range(min = 2000, max = 2100, message = "Published year must be between 2000 and 2100"))]
+    #[validate(range(
+        min = 2000,
+        max = 2100,
+        message = "Published year must be between 2000 and 2100"
+    ))]
     pub published_year: u16,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct UpdateTestBookRequest {
-    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Name must be between 1 and 255 characters"
+    ))]
     pub name: Option<String>,
     pub lesson_id: Option<Uuid>,
     pub exam_type_id: Option<Uuid>,
     pub subject_ids: Option<Vec<Uuid>>,
-    #[validate(range(min = 2000, max = 2100, message = "Published year must be between 2000 and 2100"))]
+    #[validate(range(
+        min = 2000,
+        max = 2100,
+        message = "Published year must be between 2000 and 2100"
+    ))]
     pub published_year: Option<u16>,
 }
 
@@ -115,37 +215,128 @@ pub struct PracticeTestResponse {
     pub test_number: i32,
     pub question_count: i32,
     pub answer_key: String,
+    pub answer_key_revision: i32,
+    pub test_book_id: Uuid,
+    pub subject_id: Uuid,
+    pub time_limit_minutes: Option<i32>,
+    pub topic_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Student-facing projection of a practice test.
+///
+/// `answer_key` is only set once the student has submitted the test or the
+/// exam type reveals its answer keys.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentPracticeTestResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub test_number: i32,
+    pub question_count: i32,
+    pub answer_key: Option<String>,
     pub test_book_id: Uuid,
     pub subject_id: Uuid,
+    pub time_limit_minutes: Option<i32>,
+    pub topic_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
 }
 
+/// Grading of a single question in a detailed answer key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnswerKeyEntryDto {
+    Accepted { options: Vec<char> },
+    Cancelled,
+    AnyAnswer,
+}
+
+/// Answer key given either as plain letters ("ABCD...") or one entry per question.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AnswerKeyInput {
+    Plain(String),
+    Entries(Vec<AnswerKeyEntryDto>),
+}
+
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct CreatePracticeTestRequest {
-    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Name must be between 1 and 255 characters"
+    ))]
     pub name: String,
     #[validate(range(min = 1, message = "Test number must be at least 1"))]
     pub test_number: i32,
     #[validate(range(min = 1, message = "Question count must be at least 1"))]
     pub question_count: i32,
-    #[validate(length(min = 1, message = "Answer key is required"))]
-    pub answer_key: String,
+    pub answer_key: AnswerKeyInput,
     pub test_book_id: Uuid,
     pub subject_id: Uuid,
+    #[validate(range(min = 1, message = "Time limit must be at least 1 minute"))]
+    pub time_limit_minutes: Option<i32>,
+    pub topic_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
 pub struct UpdatePracticeTestRequest {
-    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Name must be between 1 and 255 characters"
+    ))]
     pub name: Option<String>,
     #[validate(range(min = 1, message = "Test number must be at least 1"))]
     pub test_number: Option<i32>,
     #[validate(range(min = 1, message = "Question count must be at least 1"))]
     pub question_count: Option<i32>,
-    #[validate(length(min = 1, message = "Answer key is required"))]
-    pub answer_key: Option<String>,
+    pub answer_key: Option<AnswerKeyInput>,
     pub test_book_id: Option<Uuid>,
     pub subject_id: Option<Uuid>,
+    /// 0 removes the time limit
+    #[validate(range(min = 0, message = "Time limit cannot be negative"))]
+    pub time_limit_minutes: Option<i32>,
+    pub topic_id: Option<Uuid>,
+    /// Removes the topic; cannot be combined with `topic_id`
+    #[serde(default)]
+    pub clear_topic: bool,
+}
+
+// Question DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionResponse {
+    pub id: Uuid,
+    pub practice_test_id: Uuid,
+    pub position: i32,
+    pub answer: AnswerKeyEntryDto,
+    pub topic_id: Option<Uuid>,
+    pub difficulty: Option<String>, // "easy" | "medium" | "hard"
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct QuestionInput {
+    /// Keeps the current answer key entry of the position when omitted
+    pub answer: Option<AnswerKeyEntryDto>,
+    pub topic_id: Option<Uuid>,
+    pub difficulty: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReplaceQuestionsRequest {
+    /// Questions in position order
+    pub questions: Vec<QuestionInput>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerKeyRevisionResponse {
+    pub id: Uuid,
+    pub practice_test_id: Uuid,
+    pub revision: i32,
+    pub answer_key: String,
+    pub created_at: DateTime<Utc>,
+    pub regraded_at: Option<DateTime<Utc>>,
 }
 
 // TestResult DTOs
@@ -159,7 +350,66 @@ pub struct TestResultResponse {
     pub wrong_count: i32,
     pub empty_count: i32,
     pub net_score: f64,
+    pub scoring_policy_id: Uuid,
+    pub scoring_policy_version: i32,
+    pub answer_key_revision: i32,
+    pub score_changed_at: Option<DateTime<Utc>>,
     pub solved_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub elapsed_seconds: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionResultResponse {
+    pub question_number: i32,
+    pub given_answer: Option<char>,
+    /// Accepted options; empty for cancelled and any-answer questions
+    pub correct_answers: Vec<char>,
+    /// "accepted", "cancelled" or "any_answer"
+    pub key_type: String,
+    /// "correct", "wrong" or "empty"
+    pub outcome: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResultDetailResponse {
+    pub result: TestResultResponse,
+    pub questions: Vec<QuestionResultResponse>,
+    /// True for mock exam sections whose breakdown is left out to keep the answer key hidden
+    pub answer_key_hidden: bool,
+    pub cohort: Option<CohortStatisticsResponse>,
+}
+
+/// Comparison of a result with the first attempts of everyone who solved the test.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CohortStatisticsResponse {
+    pub first_attempt_count: i64,
+    pub average_net: f64,
+    pub median_net: f64,
+    pub percentile: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResultHistoryResponse {
+    pub id: Uuid,
+    pub test_result_id: Uuid,
+    pub answer_key_revision: i32,
+    pub correct_count: i32,
+    pub wrong_count: i32,
+    pub empty_count: i32,
+    pub net_score: f64,
+    pub replaced_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegradeSummary {
+    pub practice_test_id: Uuid,
+    pub answer_key_revision: i32,
+    pub regraded_count: u32,
+    pub changed_count: u32,
+    /// Results whose answers no longer match the key length (e.g. question count changed).
+    /// They keep their previous grading and the revision stays pending until none are left.
+    pub skipped_result_ids: Vec<Uuid>,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
@@ -173,6 +423,39 @@ pub struct SolveTestResponse {
     pub result: TestResultResponse,
     pub can_retake: bool,
     pub hours_until_retake: Option<f64>,
+    pub attempts_remaining: Option<i32>,
+    pub cohort: Option<CohortStatisticsResponse>,
+    /// Achievements first earned with this result
+    pub new_achievements: Vec<AchievementResponse>,
+}
+
+// ExamSession DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExamSessionResponse {
+    pub id: Uuid,
+    pub practice_test_id: Uuid,
+    pub answers: String,
+    pub status: String, // "in_progress" | "submitted" | "expired"
+    pub started_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Seconds left while in progress; `None` if untimed or finished
+    pub remaining_seconds: Option<i64>,
+    pub last_saved_at: DateTime<Utc>,
+    pub submitted_at: Option<DateTime<Utc>>,
+    pub test_result_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SaveExamSessionAnswersRequest {
+    #[validate(length(min = 1, message = "Answers cannot be empty"))]
+    pub answers: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct SubmitExamSessionRequest {
+    /// Final answers; the last autosaved answers are used if omitted
+    #[validate(length(min = 1, message = "Answers cannot be empty"))]
+    pub answers: Option<String>,
 }
 
 // TestBook with statistics DTOs
@@ -197,13 +480,19 @@ pub struct PracticeTestWithStatusResponse {
     pub name: String,
     pub test_number: i32,
     pub question_count: i32,
-    pub answer_key: String,
+    /// Hidden until revealed, as in StudentPracticeTestResponse
+    pub answer_key: Option<String>,
     pub test_book_id: Uuid,
     pub subject_id: Uuid,
+    pub time_limit_minutes: Option<i32>,
+    pub topic_id: Option<Uuid>,
     pub created_at: DateTime<Utc>,
-    pub status: String, // "available" | "solved" | "waiting"
+    pub status: String, // "available" | "solved" | "waiting" | "locked"
     pub last_solved_at: Option<DateTime<Utc>>,
     pub result_id: Option<Uuid>,
     pub hours_until_retake: Option<f64>,
+    pub attempts_used: i32,
+    pub attempts_remaining: Option<i32>,
+    /// The attempt that counts under the retake policy (first or best)
+    pub counted_result_id: Option<Uuid>,
 }
-
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+// Topic DTOs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicResponse {
+    pub id: Uuid,
+    pub subject_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub name: String,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicTreeNodeResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub position: i32,
+    pub children: Vec<TopicTreeNodeResponse>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct CreateTopicRequest {
+    pub subject_id: Uuid,
+    /// Root topic of the subject when omitted
+    pub parent_id: Option<Uuid>,
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Name must be between 1 and 255 characters"
+    ))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct UpdateTopicRequest {
+    #[validate(length(
+        min = 1,
+        max = 255,
+        message = "Name must be between 1 and 255 characters"
+    ))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+pub struct MoveTopicRequest {
+    /// New parent; the root level of the subject when omitted
+    pub parent_id: Option<Uuid>,
+    /// 1-based position among the new siblings; last when omitted
+    #[validate(range(min = 1, message = "Position must be at least 1"))]
+    pub position: Option<i32>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReorderTopicsRequest {
+    /// Parent whose children are reordered; the root level of the subject when omitted
+    pub parent_id: Option<Uuid>,
+    /// Every child topic, in the new order
+    pub topic_ids: Vec<Uuid>,
+}
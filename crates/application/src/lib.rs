@@ -1,3 +1,5 @@
 pub mod dto;
 pub mod services;
 
+#[cfg(test)]
+mod test_support;
@@ -0,0 +1,227 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{Achievement, AchievementRule};
+use domain::errors::DomainError;
+use domain::repositories::{AchievementRepository, ExamTypeRepository, LessonRepository};
+use domain::services::AchievementEvaluator;
+
+use crate::dto::{AchievementProgressResponse, AchievementResponse, CreateAchievementRequest};
+
+/// Errors for achievement operations.
+#[derive(Debug, thiserror::Error)]
+pub enum AchievementError {
+    #[error("Achievement not found")]
+    AchievementNotFound,
+
+    #[error("Achievement code already exists")]
+    CodeAlreadyExists,
+
+    #[error("Invalid achievement rule: {0}")]
+    InvalidRule(String),
+
+    #[error("Only net score achievements can be limited to an exam type or lesson")]
+    InvalidRuleScope,
+
+    #[error("Exam type not found")]
+    ExamTypeNotFound,
+
+    #[error("Lesson not found")]
+    LessonNotFound,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for AchievementError {
+    fn from(err: DomainError) -> Self {
+        AchievementError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for achievements and the badges students earn.
+#[async_trait]
+pub trait AchievementService: Send + Sync {
+    /// Lists every achievement with the user's progress, awarding any newly reached ones.
+    async fn list_my_achievements(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<AchievementProgressResponse>, AchievementError>;
+
+    /// Lists all achievements.
+    async fn list_achievements(&self) -> Result<Vec<AchievementResponse>, AchievementError>;
+
+    /// Creates a new achievement.
+    async fn create_achievement(
+        &self,
+        request: CreateAchievementRequest,
+    ) -> Result<AchievementResponse, AchievementError>;
+
+    /// Deletes an achievement and every award of it.
+    async fn delete_achievement(&self, id: Uuid) -> Result<(), AchievementError>;
+}
+
+/// Implementation of AchievementService.
+pub struct AchievementServiceImpl<A, E, L>
+where
+    A: AchievementRepository,
+    E: ExamTypeRepository,
+    L: LessonRepository,
+{
+    achievement_repo: Arc<A>,
+    exam_type_repo: Arc<E>,
+    lesson_repo: Arc<L>,
+}
+
+impl<A, E, L> AchievementServiceImpl<A, E, L>
+where
+    A: AchievementRepository,
+    E: ExamTypeRepository,
+    L: LessonRepository,
+{
+    pub fn new(achievement_repo: Arc<A>, exam_type_repo: Arc<E>, lesson_repo: Arc<L>) -> Self {
+        Self {
+            achievement_repo,
+            exam_type_repo,
+            lesson_repo,
+        }
+    }
+
+    /// Converts an achievement entity into its response DTO.
+    fn to_achievement_response(achievement: Achievement) -> AchievementResponse {
+        AchievementResponse {
+            id: achievement.id,
+            code: achievement.code,
+            name: achievement.name,
+            description: achievement.description,
+            rule: achievement.rule.as_str().to_string(),
+            threshold: achievement.threshold,
+            exam_type_id: achievement.exam_type_id,
+            lesson_id: achievement.lesson_id,
+            created_at: achievement.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl<A, E, L> AchievementService for AchievementServiceImpl<A, E, L>
+where
+    A: AchievementRepository + 'static,
+    E: ExamTypeRepository + 'static,
+    L: LessonRepository + 'static,
+{
+    async fn list_my_achievements(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<AchievementProgressResponse>, AchievementError> {
+        let achievements = self.achievement_repo.list_all().await?;
+        let facts = self.achievement_repo.find_facts(user_id).await?;
+        let now = Utc::now();
+        let progress = AchievementEvaluator::evaluate(&achievements, &facts, now.date_naive());
+
+        let mut awarded: HashMap<Uuid, _> = self
+            .achievement_repo
+            .find_awarded(user_id)
+            .await?
+            .into_iter()
+            .map(|award| (award.achievement_id, award.awarded_at))
+            .collect();
+
+        // Results can also come from mock exams and paper imports, so catch up here
+        let newly_achieved: Vec<Uuid> = progress
+            .iter()
+            .filter(|p| p.achieved && !awarded.contains_key(&p.achievement_id))
+            .map(|p| p.achievement_id)
+            .collect();
+        if !newly_achieved.is_empty() {
+            self.achievement_repo
+                .award(user_id, &newly_achieved, now)
+                .await?;
+            awarded.extend(newly_achieved.into_iter().map(|id| (id, now)));
+        }
+
+        Ok(achievements
+            .into_iter()
+            .zip(progress)
+            .map(|(achievement, progress)| {
+                let awarded_at = awarded.get(&achievement.id).copied();
+                AchievementProgressResponse {
+                    achievement: Self::to_achievement_response(achievement),
+                    earned: awarded_at.is_some(),
+                    awarded_at,
+                    current: progress.current,
+                    target: progress.target,
+                }
+            })
+            .collect())
+    }
+
+    async fn list_achievements(&self) -> Result<Vec<AchievementResponse>, AchievementError> {
+        let achievements = self.achievement_repo.list_all().await?;
+
+        Ok(achievements
+            .into_iter()
+            .map(Self::to_achievement_response)
+            .collect())
+    }
+
+    async fn create_achievement(
+        &self,
+        request: CreateAchievementRequest,
+    ) -> Result<AchievementResponse, AchievementError> {
+        let rule = AchievementRule::parse(&request.rule)
+            .ok_or_else(|| AchievementError::InvalidRule(request.rule.clone()))?;
+        let scoped = request.exam_type_id.is_some() || request.lesson_id.is_some();
+        if scoped && rule != AchievementRule::NetScore {
+            return Err(AchievementError::InvalidRuleScope);
+        }
+        if let Some(exam_type_id) = request.exam_type_id {
+            if self
+                .exam_type_repo
+                .find_by_id(exam_type_id)
+                .await?
+                .is_none()
+            {
+                return Err(AchievementError::ExamTypeNotFound);
+            }
+        }
+        if let Some(lesson_id) = request.lesson_id {
+            if self.lesson_repo.find_by_id(lesson_id).await?.is_none() {
+                return Err(AchievementError::LessonNotFound);
+            }
+        }
+        if self
+            .achievement_repo
+            .find_by_code(&request.code)
+            .await?
+            .is_some()
+        {
+            return Err(AchievementError::CodeAlreadyExists);
+        }
+
+        let achievement = Achievement::new(
+            request.code,
+            request.name,
+            request.description,
+            rule,
+            request.threshold,
+            request.exam_type_id,
+            request.lesson_id,
+        );
+        let created = self.achievement_repo.create(&achievement).await?;
+
+        Ok(Self::to_achievement_response(created))
+    }
+
+    async fn delete_achievement(&self, id: Uuid) -> Result<(), AchievementError> {
+        if self.achievement_repo.find_by_id(id).await?.is_none() {
+            return Err(AchievementError::AchievementNotFound);
+        }
+
+        self.achievement_repo.delete(id).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,344 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{ProgressPoint, TimelineGranularity, TimelineGrouping};
+use domain::errors::DomainError;
+use domain::repositories::AnalyticsRepository;
+use domain::services::{PerformanceAnalyzer, PerformanceStat, QuestionErrorRate};
+
+use crate::dto::{
+    PerformanceStatResponse, ProgressPointResponse, ProgressSeriesResponse,
+    ProgressTimelineRequest, ProgressTimelineResponse, QuestionErrorRateResponse,
+    WeaknessReportResponse,
+};
+
+/// Number of most recent results a weakness report is built from.
+const WEAKNESS_RESULT_LIMIT: u32 = 500;
+
+/// Errors for student analytics operations.
+#[derive(Debug, thiserror::Error)]
+pub enum AnalyticsError {
+    #[error("Invalid granularity: {0}")]
+    InvalidGranularity(String),
+
+    #[error("Invalid grouping: {0}")]
+    InvalidGrouping(String),
+
+    #[error("Invalid date range: from must be before to")]
+    InvalidDateRange,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for AnalyticsError {
+    fn from(err: DomainError) -> Self {
+        AnalyticsError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for student performance analytics.
+#[async_trait]
+pub trait AnalyticsService: Send + Sync {
+    /// Returns the user's weakest lessons, subjects, topics and questions in their
+    /// most recent practice test results, at most `limit` entries per list.
+    async fn get_weaknesses(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+    ) -> Result<WeaknessReportResponse, AnalyticsError>;
+
+    /// Returns the user's net scores over time, one series per lesson or exam type.
+    async fn get_progress_timeline(
+        &self,
+        user_id: Uuid,
+        request: ProgressTimelineRequest,
+    ) -> Result<ProgressTimelineResponse, AnalyticsError>;
+}
+
+/// Implementation of AnalyticsService.
+pub struct AnalyticsServiceImpl<A>
+where
+    A: AnalyticsRepository,
+{
+    analytics_repo: Arc<A>,
+}
+
+impl<A> AnalyticsServiceImpl<A>
+where
+    A: AnalyticsRepository,
+{
+    pub fn new(analytics_repo: Arc<A>) -> Self {
+        Self { analytics_repo }
+    }
+
+    /// Converts a performance statistic into its response DTO.
+    fn to_stat_response(stat: PerformanceStat) -> PerformanceStatResponse {
+        PerformanceStatResponse {
+            id: stat.id,
+            name: stat.name,
+            attempt_count: stat.attempt_count,
+            correct_count: stat.correct_count,
+            wrong_count: stat.wrong_count,
+            empty_count: stat.empty_count,
+            accuracy: stat.accuracy,
+            average_net: stat.average_net,
+            trend: stat.trend.as_str().to_string(),
+            trend_delta: stat.trend_delta,
+        }
+    }
+
+    /// Converts a question error rate into its response DTO.
+    fn to_error_rate_response(rate: QuestionErrorRate) -> QuestionErrorRateResponse {
+        QuestionErrorRateResponse {
+            practice_test_id: rate.practice_test_id,
+            practice_test_name: rate.practice_test_name,
+            question_number: rate.question_number,
+            attempt_count: rate.attempt_count,
+            wrong_count: rate.wrong_count,
+            empty_count: rate.empty_count,
+            error_rate: rate.error_rate,
+            topic_id: rate.topic_id,
+        }
+    }
+
+    /// Splits bucketed points, ordered by group, into one series per group.
+    fn to_series_responses(points: Vec<ProgressPoint>) -> Vec<ProgressSeriesResponse> {
+        let mut series: Vec<ProgressSeriesResponse> = Vec::new();
+        for point in points {
+            let response = ProgressPointResponse {
+                bucket_start: point.bucket_start,
+                average_net: point.average_net,
+                test_count: point.test_count,
+                best_net: point.best_net,
+            };
+            match series.last_mut() {
+                Some(last) if last.id == point.group_id => last.points.push(response),
+                _ => series.push(ProgressSeriesResponse {
+                    id: point.group_id,
+                    name: point.group_name,
+                    points: vec![response],
+                }),
+            }
+        }
+        series
+    }
+
+    fn to_stat_responses(
+        stats: Vec<PerformanceStat>,
+        limit: usize,
+    ) -> Vec<PerformanceStatResponse> {
+        stats
+            .into_iter()
+            .take(limit)
+            .map(Self::to_stat_response)
+            .collect()
+    }
+}
+
+#[async_trait]
+impl<A> AnalyticsService for AnalyticsServiceImpl<A>
+where
+    A: AnalyticsRepository + 'static,
+{
+    async fn get_weaknesses(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+    ) -> Result<WeaknessReportResponse, AnalyticsError> {
+        let records = self
+            .analytics_repo
+            .find_records_by_user(user_id, WEAKNESS_RESULT_LIMIT)
+            .await?;
+
+        let practice_test_ids: Vec<Uuid> = records
+            .iter()
+            .map(|record| record.practice_test_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let question_topics = if practice_test_ids.is_empty() {
+            Vec::new()
+        } else {
+            self.analytics_repo
+                .find_question_topics(&practice_test_ids)
+                .await?
+        };
+
+        let report = PerformanceAnalyzer::analyze(&records, &question_topics, limit);
+
+        Ok(WeaknessReportResponse {
+            total_attempts: report.total_attempts,
+            lessons: Self::to_stat_responses(report.lessons, limit),
+            subjects: Self::to_stat_responses(report.subjects, limit),
+            topics: Self::to_stat_responses(report.topics, limit),
+            missed_questions: report
+                .missed_questions
+                .into_iter()
+                .map(Self::to_error_rate_response)
+                .collect(),
+        })
+    }
+
+    async fn get_progress_timeline(
+        &self,
+        user_id: Uuid,
+        request: ProgressTimelineRequest,
+    ) -> Result<ProgressTimelineResponse, AnalyticsError> {
+        let granularity = TimelineGranularity::parse(&request.granularity)
+            .ok_or_else(|| AnalyticsError::InvalidGranularity(request.granularity.clone()))?;
+        let grouping = TimelineGrouping::parse(&request.group_by)
+            .ok_or_else(|| AnalyticsError::InvalidGrouping(request.group_by.clone()))?;
+        if let (Some(from), Some(to)) = (request.from, request.to) {
+            if from >= to {
+                return Err(AnalyticsError::InvalidDateRange);
+            }
+        }
+
+        let points = self
+            .analytics_repo
+            .find_progress_points(user_id, grouping, granularity, request.from, request.to)
+            .await?;
+
+        Ok(ProgressTimelineResponse {
+            granularity: granularity.as_str().to_string(),
+            group_by: grouping.as_str().to_string(),
+            series: Self::to_series_responses(points),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use chrono::{Duration, Utc};
+
+    use crate::test_support::FakeAnalyticsRepository;
+
+    fn service() -> (
+        AnalyticsServiceImpl<FakeAnalyticsRepository>,
+        Arc<FakeAnalyticsRepository>,
+    ) {
+        let analytics_repo = Arc::new(FakeAnalyticsRepository::default());
+        (
+            AnalyticsServiceImpl::new(analytics_repo.clone()),
+            analytics_repo,
+        )
+    }
+
+    fn request(granularity: &str, group_by: &str) -> ProgressTimelineRequest {
+        ProgressTimelineRequest {
+            granularity: granularity.to_string(),
+            group_by: group_by.to_string(),
+            from: None,
+            to: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_progress_timeline_passes_parsed_request() {
+        let (service, analytics_repo) = service();
+        let from = Utc::now() - Duration::days(30);
+        let to = Utc::now();
+        let point = ProgressPoint {
+            group_id: Uuid::new_v4(),
+            group_name: "Matematik".to_string(),
+            bucket_start: from,
+            average_net: 12.5,
+            test_count: 2,
+            best_net: 15.0,
+        };
+        analytics_repo
+            .progress_points
+            .lock()
+            .unwrap()
+            .push(point.clone());
+
+        let timeline = service
+            .get_progress_timeline(
+                Uuid::new_v4(),
+                ProgressTimelineRequest {
+                    from: Some(from),
+                    to: Some(to),
+                    ..request("week", "exam_type")
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(timeline.granularity, "week");
+        assert_eq!(timeline.group_by, "exam_type");
+        assert_eq!(timeline.series.len(), 1);
+        assert_eq!(timeline.series[0].id, point.group_id);
+        assert_eq!(
+            *analytics_repo.progress_queries.lock().unwrap(),
+            vec![(
+                TimelineGrouping::ExamType,
+                TimelineGranularity::Week,
+                Some(from),
+                Some(to)
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_progress_timeline_rejects_unknown_granularity_and_grouping() {
+        let (service, analytics_repo) = service();
+
+        let granularity = service
+            .get_progress_timeline(Uuid::new_v4(), request("year", "lesson"))
+            .await;
+        assert!(
+            matches!(granularity, Err(AnalyticsError::InvalidGranularity(value)) if value == "year")
+        );
+
+        let grouping = service
+            .get_progress_timeline(Uuid::new_v4(), request("day", "subject"))
+            .await;
+        assert!(
+            matches!(grouping, Err(AnalyticsError::InvalidGrouping(value)) if value == "subject")
+        );
+
+        assert!(analytics_repo.progress_queries.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_progress_timeline_validates_date_range() {
+        let (service, analytics_repo) = service();
+        let now = Utc::now();
+
+        for (from, to) in [(now, now), (now, now - Duration::days(1))] {
+            let result = service
+                .get_progress_timeline(
+                    Uuid::new_v4(),
+                    ProgressTimelineRequest {
+                        from: Some(from),
+                        to: Some(to),
+                        ..request("day", "lesson")
+                    },
+                )
+                .await;
+            assert!(matches!(result, Err(AnalyticsError::InvalidDateRange)));
+        }
+        assert!(analytics_repo.progress_queries.lock().unwrap().is_empty());
+
+        // Open-ended ranges need no ordering
+        for (from, to) in [(Some(now), None), (None, Some(now)), (None, None)] {
+            let result = service
+                .get_progress_timeline(
+                    Uuid::new_v4(),
+                    ProgressTimelineRequest {
+                        from,
+                        to,
+                        ..request("month", "lesson")
+                    },
+                )
+                .await;
+            assert!(result.is_ok());
+        }
+        assert_eq!(analytics_repo.progress_queries.lock().unwrap().len(), 3);
+    }
+}
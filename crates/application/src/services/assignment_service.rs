@@ -0,0 +1,391 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{Assignment, AssignmentSummary, Classroom, SubmissionStatus};
+use domain::errors::DomainError;
+use domain::repositories::{AssignmentRepository, ClassroomRepository, PracticeTestRepository};
+
+use crate::dto::{
+    AssignmentResponse, AssignmentSubmissionResponse, AssignmentSubmissionsResponse,
+    CreateAssignmentRequest, StudentAssignmentResponse,
+};
+
+/// Errors for assignment operations.
+#[derive(Debug, thiserror::Error)]
+pub enum AssignmentError {
+    #[error("Classroom not found")]
+    ClassroomNotFound,
+
+    #[error("Assignment not found")]
+    AssignmentNotFound,
+
+    #[error("Practice test not found")]
+    PracticeTestNotFound,
+
+    #[error("Due date must be in the future")]
+    DueDateInPast,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for AssignmentError {
+    fn from(err: DomainError) -> Self {
+        AssignmentError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for practice tests assigned to classrooms as homework.
+#[async_trait]
+pub trait AssignmentService: Send + Sync {
+    /// Assigns a practice test to one of the teacher's classrooms.
+    async fn create_assignment(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+        request: CreateAssignmentRequest,
+    ) -> Result<AssignmentResponse, AssignmentError>;
+
+    /// Lists the assignments of one of the teacher's classrooms with completion counts.
+    async fn list_assignments(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<Vec<AssignmentResponse>, AssignmentError>;
+
+    /// Lists every student of the classroom with the result they submitted for the assignment.
+    async fn get_submissions(
+        &self,
+        teacher_id: Uuid,
+        assignment_id: Uuid,
+    ) -> Result<AssignmentSubmissionsResponse, AssignmentError>;
+
+    /// Deletes an assignment of one of the teacher's classrooms.
+    async fn delete_assignment(
+        &self,
+        teacher_id: Uuid,
+        assignment_id: Uuid,
+    ) -> Result<(), AssignmentError>;
+
+    /// Lists the student's assignments across their classrooms, by due date.
+    async fn list_my_assignments(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<StudentAssignmentResponse>, AssignmentError>;
+}
+
+/// Implementation of AssignmentService.
+pub struct AssignmentServiceImpl<A, C, P>
+where
+    A: AssignmentRepository,
+    C: ClassroomRepository,
+    P: PracticeTestRepository,
+{
+    assignment_repo: Arc<A>,
+    classroom_repo: Arc<C>,
+    practice_test_repo: Arc<P>,
+}
+
+impl<A, C, P> AssignmentServiceImpl<A, C, P>
+where
+    A: AssignmentRepository,
+    C: ClassroomRepository,
+    P: PracticeTestRepository,
+{
+    pub fn new(
+        assignment_repo: Arc<A>,
+        classroom_repo: Arc<C>,
+        practice_test_repo: Arc<P>,
+    ) -> Self {
+        Self {
+            assignment_repo,
+            classroom_repo,
+            practice_test_repo,
+        }
+    }
+
+    /// Finds a classroom owned by the teacher. Other teachers' classrooms are reported as missing.
+    async fn find_owned_classroom(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<Classroom, AssignmentError> {
+        self.classroom_repo
+            .find_by_id(classroom_id)
+            .await?
+            .filter(|classroom| classroom.teacher_id == teacher_id)
+            .ok_or(AssignmentError::ClassroomNotFound)
+    }
+
+    /// Finds an assignment of one of the teacher's classrooms.
+    async fn find_owned_assignment(
+        &self,
+        teacher_id: Uuid,
+        assignment_id: Uuid,
+    ) -> Result<Assignment, AssignmentError> {
+        let assignment = self
+            .assignment_repo
+            .find_by_id(assignment_id)
+            .await?
+            .ok_or(AssignmentError::AssignmentNotFound)?;
+        self.find_owned_classroom(teacher_id, assignment.classroom_id)
+            .await
+            .map_err(|e| match e {
+                AssignmentError::ClassroomNotFound => AssignmentError::AssignmentNotFound,
+                other => other,
+            })?;
+
+        Ok(assignment)
+    }
+
+    /// Converts an assignment summary into its response DTO.
+    fn to_assignment_response(summary: AssignmentSummary) -> AssignmentResponse {
+        AssignmentResponse {
+            id: summary.assignment.id,
+            classroom_id: summary.assignment.classroom_id,
+            practice_test_id: summary.assignment.practice_test_id,
+            practice_test_name: summary.practice_test_name,
+            due_at: summary.assignment.due_at,
+            created_at: summary.assignment.created_at,
+            student_count: summary.student_count,
+            submitted_count: summary.submitted_count,
+        }
+    }
+}
+
+#[async_trait]
+impl<A, C, P> AssignmentService for AssignmentServiceImpl<A, C, P>
+where
+    A: AssignmentRepository + 'static,
+    C: ClassroomRepository + 'static,
+    P: PracticeTestRepository + 'static,
+{
+    async fn create_assignment(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+        request: CreateAssignmentRequest,
+    ) -> Result<AssignmentResponse, AssignmentError> {
+        let classroom = self.find_owned_classroom(teacher_id, classroom_id).await?;
+        if request.due_at <= Utc::now() {
+            return Err(AssignmentError::DueDateInPast);
+        }
+        let practice_test = self
+            .practice_test_repo
+            .find_by_id(request.practice_test_id)
+            .await?
+            .ok_or(AssignmentError::PracticeTestNotFound)?;
+
+        let assignment = Assignment::new(classroom.id, practice_test.id, request.due_at);
+        let created = self.assignment_repo.create(&assignment).await?;
+        let students = self.classroom_repo.find_students(classroom.id).await?;
+
+        Ok(Self::to_assignment_response(AssignmentSummary {
+            assignment: created,
+            practice_test_name: practice_test.name,
+            student_count: students.len() as i64,
+            submitted_count: 0,
+        }))
+    }
+
+    async fn list_assignments(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<Vec<AssignmentResponse>, AssignmentError> {
+        let classroom = self.find_owned_classroom(teacher_id, classroom_id).await?;
+        let summaries = self
+            .assignment_repo
+            .find_summaries_by_classroom(classroom.id)
+            .await?;
+
+        Ok(summaries
+            .into_iter()
+            .map(Self::to_assignment_response)
+            .collect())
+    }
+
+    async fn get_submissions(
+        &self,
+        teacher_id: Uuid,
+        assignment_id: Uuid,
+    ) -> Result<AssignmentSubmissionsResponse, AssignmentError> {
+        let assignment = self
+            .find_owned_assignment(teacher_id, assignment_id)
+            .await?;
+        let submissions = self.assignment_repo.find_submissions(&assignment).await?;
+        let now = Utc::now();
+
+        Ok(AssignmentSubmissionsResponse {
+            assignment_id: assignment.id,
+            due_at: assignment.due_at,
+            submissions: submissions
+                .into_iter()
+                .map(|submission| AssignmentSubmissionResponse {
+                    student_id: submission.student_id,
+                    username: submission.username,
+                    status: SubmissionStatus::determine(
+                        assignment.due_at,
+                        submission.solved_at,
+                        now,
+                    )
+                    .as_str()
+                    .to_string(),
+                    test_result_id: submission.test_result_id,
+                    net_score: submission.net_score,
+                    solved_at: submission.solved_at,
+                })
+                .collect(),
+        })
+    }
+
+    async fn delete_assignment(
+        &self,
+        teacher_id: Uuid,
+        assignment_id: Uuid,
+    ) -> Result<(), AssignmentError> {
+        let assignment = self
+            .find_owned_assignment(teacher_id, assignment_id)
+            .await?;
+        self.assignment_repo.delete(assignment.id).await?;
+        Ok(())
+    }
+
+    async fn list_my_assignments(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<StudentAssignmentResponse>, AssignmentError> {
+        let assignments = self.assignment_repo.find_by_student(student_id).await?;
+        let now = Utc::now();
+
+        Ok(assignments
+            .into_iter()
+            .map(|item| StudentAssignmentResponse {
+                id: item.assignment.id,
+                classroom_id: item.assignment.classroom_id,
+                classroom_name: item.classroom_name,
+                practice_test_id: item.assignment.practice_test_id,
+                practice_test_name: item.practice_test_name,
+                due_at: item.assignment.due_at,
+                status: SubmissionStatus::determine(item.assignment.due_at, item.solved_at, now)
+                    .as_str()
+                    .to_string(),
+                test_result_id: item.test_result_id,
+                net_score: item.net_score,
+                solved_at: item.solved_at,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    use crate::test_support::{
+        FakeAssignmentRepository, FakeClassroomRepository, FakePracticeTestRepository,
+    };
+
+    type Service = AssignmentServiceImpl<
+        FakeAssignmentRepository,
+        FakeClassroomRepository,
+        FakePracticeTestRepository,
+    >;
+
+    /// Assignment service over in-memory repositories with one classroom and one
+    /// of its assignments.
+    struct Fixture {
+        service: Service,
+        assignment_repo: Arc<FakeAssignmentRepository>,
+        classroom: Classroom,
+        assignment: Assignment,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let classroom =
+                Classroom::new(Uuid::new_v4(), "12-A".to_string(), "ABCD2345".to_string());
+            let assignment =
+                Assignment::new(classroom.id, Uuid::new_v4(), Utc::now() + Duration::days(7));
+
+            let classroom_repo = Arc::new(FakeClassroomRepository::default());
+            classroom_repo
+                .classrooms
+                .lock()
+                .unwrap()
+                .push(classroom.clone());
+            let assignment_repo = Arc::new(FakeAssignmentRepository::default());
+            assignment_repo
+                .assignments
+                .lock()
+                .unwrap()
+                .push(assignment.clone());
+
+            Self {
+                service: AssignmentServiceImpl::new(
+                    assignment_repo.clone(),
+                    classroom_repo,
+                    Arc::new(FakePracticeTestRepository::default()),
+                ),
+                assignment_repo,
+                classroom,
+                assignment,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_other_teachers_classrooms_are_not_found() {
+        let fixture = Fixture::new();
+
+        let err = fixture
+            .service
+            .list_assignments(Uuid::new_v4(), fixture.classroom.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AssignmentError::ClassroomNotFound));
+
+        let assignments = fixture
+            .service
+            .list_assignments(fixture.classroom.teacher_id, fixture.classroom.id)
+            .await
+            .unwrap();
+        assert_eq!(assignments.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_other_teachers_assignments_are_not_found() {
+        let fixture = Fixture::new();
+        let other_teacher = Uuid::new_v4();
+
+        let err = fixture
+            .service
+            .get_submissions(other_teacher, fixture.assignment.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AssignmentError::AssignmentNotFound));
+
+        let err = fixture
+            .service
+            .delete_assignment(other_teacher, fixture.assignment.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, AssignmentError::AssignmentNotFound));
+        assert_eq!(fixture.assignment_repo.assignments.lock().unwrap().len(), 1);
+
+        fixture
+            .service
+            .delete_assignment(fixture.classroom.teacher_id, fixture.assignment.id)
+            .await
+            .unwrap();
+        assert!(fixture
+            .assignment_repo
+            .assignments
+            .lock()
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -7,7 +7,9 @@ use uuid::Uuid;
 
 use domain::entities::{RefreshToken, User};
 use domain::errors::DomainError;
-use domain::repositories::{RefreshTokenRepository, RoleRepository, UserRepository};
+use domain::repositories::{
+    PermissionRepository, RefreshTokenRepository, RoleRepository, UserRepository,
+};
 
 use crate::dto::{
     AuthResponse, LoginRequest, LogoutRequest, RefreshRequest, RegisterRequest, RegisterResponse,
@@ -119,28 +121,31 @@ pub trait AuthService: Send + Sync {
 }
 
 /// Implementation of the authentication service.
-pub struct AuthServiceImpl<U, R, J, P, RoleRepo>
+pub struct AuthServiceImpl<U, R, J, P, RoleRepo, PermRepo>
 where
     U: UserRepository,
     R: RefreshTokenRepository,
     J: JwtOperations,
     P: PasswordOperations,
     RoleRepo: RoleRepository,
+    PermRepo: PermissionRepository,
 {
     user_repo: Arc<U>,
     refresh_token_repo: Arc<R>,
     jwt_service: Arc<J>,
     password_service: Arc<P>,
     role_repo: Arc<RoleRepo>,
+    permission_repo: Arc<PermRepo>,
 }
 
-impl<U, R, J, P, RoleRepo> AuthServiceImpl<U, R, J, P, RoleRepo>
+impl<U, R, J, P, RoleRepo, PermRepo> AuthServiceImpl<U, R, J, P, RoleRepo, PermRepo>
 where
     U: UserRepository,
     R: RefreshTokenRepository,
     J: JwtOperations,
     P: PasswordOperations,
     RoleRepo: RoleRepository,
+    PermRepo: PermissionRepository,
 {
     /// Creates a new authentication service.
     pub fn new(
@@ -149,6 +154,7 @@ where
         jwt_service: Arc<J>,
         password_service: Arc<P>,
         role_repo: Arc<RoleRepo>,
+        permission_repo: Arc<PermRepo>,
     ) -> Self {
         Self {
             user_repo,
@@ -156,6 +162,7 @@ where
             jwt_service,
             password_service,
             role_repo,
+            permission_repo,
         }
     }
 
@@ -179,6 +186,14 @@ where
         format!("{:x}", hasher.finalize())
     }
 
+    /// Gets the names of the permissions granted by the user's roles.
+    async fn user_permissions(&self, user_id: Uuid) -> Result<Vec<String>, AuthError> {
+        self.permission_repo
+            .find_names_by_user_id(user_id)
+            .await
+            .map_err(|e| AuthError::InternalError(e.to_string()))
+    }
+
     /// Creates user response from user entity.
     async fn user_to_response(&self, user: &User) -> Result<UserResponse, AuthError> {
         let roles = self
@@ -192,6 +207,8 @@ where
             username: user.username.clone(),
             email: user.email.clone(),
             is_active: user.is_active,
+            student_number: user.student_number.clone(),
+            leaderboard_opt_out: user.leaderboard_opt_out,
             roles,
             created_at: user.created_at,
             updated_at: user.updated_at,
@@ -200,13 +217,14 @@ where
 }
 
 #[async_trait]
-impl<U, R, J, P, RoleRepo> AuthService for AuthServiceImpl<U, R, J, P, RoleRepo>
+impl<U, R, J, P, RoleRepo, PermRepo> AuthService for AuthServiceImpl<U, R, J, P, RoleRepo, PermRepo>
 where
     U: UserRepository + 'static,
     R: RefreshTokenRepository + 'static,
     J: JwtOperations + 'static,
     P: PasswordOperations + 'static,
     RoleRepo: RoleRepository + 'static,
+    PermRepo: PermissionRepository + 'static,
 {
     async fn register(&self, mut request: RegisterRequest) -> Result<RegisterResponse, AuthError> {
         // Normalize input
@@ -226,7 +244,7 @@ where
         let password_hash = self
             .password_service
             .hash_password(&request.password)
-            .map_err(|e| AuthError::InternalError(e))?;
+            .map_err(AuthError::InternalError)?;
 
         // Create user
         let user = User::new(request.username, request.email, password_hash);
@@ -283,7 +301,7 @@ where
         let is_valid = self
             .password_service
             .verify_password(&request.password, &user.password_hash)
-            .map_err(|e| AuthError::InternalError(e))?;
+            .map_err(AuthError::InternalError)?;
 
         if !is_valid {
             return Err(AuthError::InvalidCredentials);
@@ -296,11 +314,14 @@ where
             .await
             .map_err(|e| AuthError::InternalError(e.to_string()))?;
 
+        // Flatten the permissions of those roles into the token
+        let permissions = self.user_permissions(user.id).await?;
+
         // Generate tokens
         let access_token = self
             .jwt_service
-            .generate_access_token(user.id, roles.clone(), vec![])
-            .map_err(|e| AuthError::InternalError(e))?;
+            .generate_access_token(user.id, roles.clone(), permissions)
+            .map_err(AuthError::InternalError)?;
 
         let (raw_refresh_token, refresh_token_hash) = self.generate_refresh_token();
 
@@ -376,11 +397,14 @@ where
             .await
             .map_err(|e| AuthError::InternalError(e.to_string()))?;
 
+        // Flatten the permissions of those roles into the token
+        let permissions = self.user_permissions(user.id).await?;
+
         // Generate new tokens
         let access_token = self
             .jwt_service
-            .generate_access_token(user.id, roles, vec![])
-            .map_err(|e| AuthError::InternalError(e))?;
+            .generate_access_token(user.id, roles, permissions)
+            .map_err(AuthError::InternalError)?;
 
         let (new_raw_refresh_token, new_refresh_token_hash) = self.generate_refresh_token();
 
@@ -449,3 +473,190 @@ where
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    use crate::test_support::{
+        FakeJwtService, FakePasswordService, FakePermissionRepository, FakeRefreshTokenRepository,
+        FakeRoleRepository, FakeUserRepository, IssuedAccessToken,
+    };
+
+    type Service = AuthServiceImpl<
+        FakeUserRepository,
+        FakeRefreshTokenRepository,
+        FakeJwtService,
+        FakePasswordService,
+        FakeRoleRepository,
+        FakePermissionRepository,
+    >;
+
+    /// Auth service over in-memory repositories with one user holding `roles`.
+    struct Fixture {
+        service: Service,
+        jwt_service: Arc<FakeJwtService>,
+        permission_repo: Arc<FakePermissionRepository>,
+        refresh_token_repo: Arc<FakeRefreshTokenRepository>,
+        user: User,
+    }
+
+    impl Fixture {
+        fn new(roles: &[&str], role_permissions: &[(&str, &[&str])]) -> Self {
+            let user = User::new(
+                "ogrenci".to_string(),
+                "ogrenci@example.com".to_string(),
+                "Passw0rd!".to_string(),
+            );
+            let roles: Vec<String> = roles.iter().map(|role| role.to_string()).collect();
+
+            let user_repo = Arc::new(FakeUserRepository::default());
+            user_repo.users.lock().unwrap().push(user.clone());
+            user_repo
+                .roles
+                .lock()
+                .unwrap()
+                .insert(user.id, roles.clone());
+
+            let permission_repo = Arc::new(FakePermissionRepository::default());
+            permission_repo
+                .user_roles
+                .lock()
+                .unwrap()
+                .insert(user.id, roles);
+            *permission_repo.role_permissions.lock().unwrap() = role_permissions
+                .iter()
+                .map(|(role, names)| {
+                    (
+                        role.to_string(),
+                        names.iter().map(|name| name.to_string()).collect(),
+                    )
+                })
+                .collect::<HashMap<_, _>>();
+
+            let jwt_service = Arc::new(FakeJwtService::default());
+            let refresh_token_repo = Arc::new(FakeRefreshTokenRepository::default());
+            let service = AuthServiceImpl::new(
+                user_repo,
+                refresh_token_repo.clone(),
+                jwt_service.clone(),
+                Arc::new(FakePasswordService),
+                Arc::new(FakeRoleRepository),
+                permission_repo.clone(),
+            );
+
+            Self {
+                service,
+                jwt_service,
+                permission_repo,
+                refresh_token_repo,
+                user,
+            }
+        }
+
+        async fn login(&self) -> AuthResponse {
+            self.service
+                .login(
+                    LoginRequest {
+                        email: "  Ogrenci@Example.com".to_string(),
+                        password: "Passw0rd!".to_string(),
+                    },
+                    None,
+                    None,
+                )
+                .await
+                .unwrap()
+        }
+
+        fn last_issued(&self) -> IssuedAccessToken {
+            self.jwt_service
+                .issued
+                .lock()
+                .unwrap()
+                .last()
+                .cloned()
+                .unwrap()
+        }
+    }
+
+    fn names(values: &[&str]) -> Vec<String> {
+        values.iter().map(|value| value.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_login_flattens_permissions_of_all_roles() {
+        let fixture = Fixture::new(
+            &["user", "teacher"],
+            &[
+                ("user", &["tests:read"]),
+                ("teacher", &["classrooms:write", "tests:read"]),
+                ("admin", &["roles:write"]),
+            ],
+        );
+
+        fixture.login().await;
+
+        assert_eq!(
+            fixture.last_issued(),
+            IssuedAccessToken {
+                user_id: fixture.user.id,
+                roles: names(&["user", "teacher"]),
+                permissions: names(&["classrooms:write", "tests:read"]),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_login_without_roles_issues_no_permissions() {
+        let fixture = Fixture::new(&[], &[("admin", &["roles:write"])]);
+
+        fixture.login().await;
+
+        let issued = fixture.last_issued();
+        assert!(issued.roles.is_empty());
+        assert!(issued.permissions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_refresh_reissues_current_permissions() {
+        let fixture = Fixture::new(&["admin"], &[("admin", &["roles:read", "roles:write"])]);
+        let login = fixture.login().await;
+        assert_eq!(
+            fixture.last_issued().permissions,
+            names(&["roles:read", "roles:write"])
+        );
+
+        // Revoked from the role after the login
+        fixture
+            .permission_repo
+            .role_permissions
+            .lock()
+            .unwrap()
+            .insert("admin".to_string(), names(&["roles:read"]));
+
+        let refreshed = fixture
+            .service
+            .refresh_token(
+                RefreshRequest {
+                    refresh_token: login.refresh_token,
+                },
+                None,
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_ne!(refreshed.access_token, login.access_token);
+        assert_eq!(
+            fixture.last_issued(),
+            IssuedAccessToken {
+                user_id: fixture.user.id,
+                roles: names(&["admin"]),
+                permissions: names(&["roles:read"]),
+            }
+        );
+        let tokens = fixture.refresh_token_repo.tokens.lock().unwrap();
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].replaced_by, Some(tokens[1].id));
+    }
+}
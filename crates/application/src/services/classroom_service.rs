@@ -0,0 +1,520 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use rand::Rng;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{Classroom, ClassroomStudent, Enrollment, TestResult};
+use domain::errors::DomainError;
+use domain::repositories::{ClassroomRepository, TestResultRepository};
+
+use crate::dto::{
+    ClassroomDetailResponse, ClassroomResponse, ClassroomStudentResponse, CreateClassroomRequest,
+    JoinClassroomRequest, TestResultResponse,
+};
+
+/// Characters of an invite code; leaves out 0/O and 1/I so codes can be read aloud.
+const INVITE_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+const INVITE_CODE_LENGTH: usize = 8;
+
+/// Errors for classroom operations.
+#[derive(Debug, thiserror::Error)]
+pub enum ClassroomError {
+    #[error("Classroom not found")]
+    ClassroomNotFound,
+
+    #[error("Student not found in classroom")]
+    StudentNotFound,
+
+    #[error("Invalid invite code")]
+    InvalidInviteCode,
+
+    #[error("Already enrolled in this classroom")]
+    AlreadyEnrolled,
+
+    #[error("Teachers cannot join their own classroom")]
+    OwnClassroom,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for ClassroomError {
+    fn from(err: DomainError) -> Self {
+        ClassroomError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for classrooms, from the teacher's and the student's side.
+#[async_trait]
+pub trait ClassroomService: Send + Sync {
+    /// Creates a classroom owned by the teacher, with a fresh invite code.
+    async fn create_classroom(
+        &self,
+        teacher_id: Uuid,
+        request: CreateClassroomRequest,
+    ) -> Result<ClassroomResponse, ClassroomError>;
+
+    /// Lists the classrooms the teacher owns.
+    async fn list_teacher_classrooms(
+        &self,
+        teacher_id: Uuid,
+    ) -> Result<Vec<ClassroomResponse>, ClassroomError>;
+
+    /// Gets one of the teacher's classrooms with its students.
+    async fn get_classroom(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<ClassroomDetailResponse, ClassroomError>;
+
+    /// Replaces the invite code of one of the teacher's classrooms; the old code stops working.
+    async fn regenerate_invite_code(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<ClassroomResponse, ClassroomError>;
+
+    /// Deletes one of the teacher's classrooms with its enrollments and assignments.
+    async fn delete_classroom(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<(), ClassroomError>;
+
+    /// Removes a student from one of the teacher's classrooms.
+    async fn remove_student(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<(), ClassroomError>;
+
+    /// Lists the test results of a student enrolled in one of the teacher's classrooms.
+    async fn list_student_results(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+        student_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<TestResultResponse>, u64), ClassroomError>;
+
+    /// Enrolls the student in the classroom with the given invite code.
+    async fn join_classroom(
+        &self,
+        student_id: Uuid,
+        request: JoinClassroomRequest,
+    ) -> Result<ClassroomResponse, ClassroomError>;
+
+    /// Lists the classrooms the student is enrolled in.
+    async fn list_my_classrooms(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<ClassroomResponse>, ClassroomError>;
+
+    /// Removes the student from a classroom they are enrolled in.
+    async fn leave_classroom(
+        &self,
+        student_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<(), ClassroomError>;
+}
+
+/// Implementation of ClassroomService.
+pub struct ClassroomServiceImpl<C, R>
+where
+    C: ClassroomRepository,
+    R: TestResultRepository,
+{
+    classroom_repo: Arc<C>,
+    test_result_repo: Arc<R>,
+}
+
+impl<C, R> ClassroomServiceImpl<C, R>
+where
+    C: ClassroomRepository,
+    R: TestResultRepository,
+{
+    pub fn new(classroom_repo: Arc<C>, test_result_repo: Arc<R>) -> Self {
+        Self {
+            classroom_repo,
+            test_result_repo,
+        }
+    }
+
+    /// Finds a classroom owned by the teacher. Other teachers' classrooms are reported as missing.
+    async fn find_owned(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<Classroom, ClassroomError> {
+        self.classroom_repo
+            .find_by_id(classroom_id)
+            .await?
+            .filter(|classroom| classroom.teacher_id == teacher_id)
+            .ok_or(ClassroomError::ClassroomNotFound)
+    }
+
+    /// Generates an invite code not used by any classroom yet.
+    async fn generate_invite_code(&self) -> Result<String, ClassroomError> {
+        loop {
+            let code: String = {
+                let mut rng = rand::rng();
+                (0..INVITE_CODE_LENGTH)
+                    .map(|_| {
+                        INVITE_CODE_ALPHABET[rng.random_range(0..INVITE_CODE_ALPHABET.len())]
+                            as char
+                    })
+                    .collect()
+            };
+            if self
+                .classroom_repo
+                .find_by_invite_code(&code)
+                .await?
+                .is_none()
+            {
+                return Ok(code);
+            }
+        }
+    }
+
+    /// Converts a classroom entity into its response DTO; the invite code is for teachers only.
+    fn to_classroom_response(classroom: Classroom, with_invite_code: bool) -> ClassroomResponse {
+        ClassroomResponse {
+            id: classroom.id,
+            teacher_id: classroom.teacher_id,
+            name: classroom.name,
+            invite_code: with_invite_code.then_some(classroom.invite_code),
+            created_at: classroom.created_at,
+            updated_at: classroom.updated_at,
+        }
+    }
+
+    /// Converts an enrolled student into its response DTO.
+    fn to_student_response(student: ClassroomStudent) -> ClassroomStudentResponse {
+        ClassroomStudentResponse {
+            student_id: student.student_id,
+            username: student.username,
+            enrolled_at: student.enrolled_at,
+        }
+    }
+
+    /// Converts a test result entity into its response DTO.
+    fn to_test_result_response(result: TestResult) -> TestResultResponse {
+        TestResultResponse {
+            id: result.id,
+            user_id: result.user_id,
+            practice_test_id: result.practice_test_id,
+            user_answers: result.user_answers,
+            correct_count: result.correct_count,
+            wrong_count: result.wrong_count,
+            empty_count: result.empty_count,
+            net_score: result.net_score,
+            scoring_policy_id: result.scoring_policy_id,
+            scoring_policy_version: result.scoring_policy_version,
+            answer_key_revision: result.answer_key_revision,
+            score_changed_at: result.score_changed_at,
+            solved_at: result.solved_at,
+            started_at: result.started_at,
+            elapsed_seconds: result.elapsed_seconds,
+        }
+    }
+}
+
+#[async_trait]
+impl<C, R> ClassroomService for ClassroomServiceImpl<C, R>
+where
+    C: ClassroomRepository + 'static,
+    R: TestResultRepository + 'static,
+{
+    async fn create_classroom(
+        &self,
+        teacher_id: Uuid,
+        request: CreateClassroomRequest,
+    ) -> Result<ClassroomResponse, ClassroomError> {
+        let invite_code = self.generate_invite_code().await?;
+        let classroom = Classroom::new(teacher_id, request.name, invite_code);
+        let created = self.classroom_repo.create(&classroom).await?;
+
+        Ok(Self::to_classroom_response(created, true))
+    }
+
+    async fn list_teacher_classrooms(
+        &self,
+        teacher_id: Uuid,
+    ) -> Result<Vec<ClassroomResponse>, ClassroomError> {
+        let classrooms = self.classroom_repo.find_by_teacher(teacher_id).await?;
+
+        Ok(classrooms
+            .into_iter()
+            .map(|classroom| Self::to_classroom_response(classroom, true))
+            .collect())
+    }
+
+    async fn get_classroom(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<ClassroomDetailResponse, ClassroomError> {
+        let classroom = self.find_owned(teacher_id, classroom_id).await?;
+        let students = self.classroom_repo.find_students(classroom.id).await?;
+
+        Ok(ClassroomDetailResponse {
+            classroom: Self::to_classroom_response(classroom, true),
+            students: students
+                .into_iter()
+                .map(Self::to_student_response)
+                .collect(),
+        })
+    }
+
+    async fn regenerate_invite_code(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<ClassroomResponse, ClassroomError> {
+        let mut classroom = self.find_owned(teacher_id, classroom_id).await?;
+        classroom.invite_code = self.generate_invite_code().await?;
+        classroom.updated_at = Utc::now();
+        let updated = self.classroom_repo.update(&classroom).await?;
+
+        Ok(Self::to_classroom_response(updated, true))
+    }
+
+    async fn delete_classroom(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<(), ClassroomError> {
+        let classroom = self.find_owned(teacher_id, classroom_id).await?;
+        self.classroom_repo.delete(classroom.id).await?;
+        Ok(())
+    }
+
+    async fn remove_student(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<(), ClassroomError> {
+        let classroom = self.find_owned(teacher_id, classroom_id).await?;
+        if !self
+            .classroom_repo
+            .unenroll(classroom.id, student_id)
+            .await?
+        {
+            return Err(ClassroomError::StudentNotFound);
+        }
+        Ok(())
+    }
+
+    async fn list_student_results(
+        &self,
+        teacher_id: Uuid,
+        classroom_id: Uuid,
+        student_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<(Vec<TestResultResponse>, u64), ClassroomError> {
+        // Teachers only see the results of students enrolled in their own classrooms
+        let classroom = self.find_owned(teacher_id, classroom_id).await?;
+        if !self
+            .classroom_repo
+            .is_enrolled(classroom.id, student_id)
+            .await?
+        {
+            return Err(ClassroomError::StudentNotFound);
+        }
+
+        let (results, total) = self
+            .test_result_repo
+            .list(Some(student_id), None, page, per_page)
+            .await?;
+
+        Ok((
+            results
+                .into_iter()
+                .map(Self::to_test_result_response)
+                .collect(),
+            total,
+        ))
+    }
+
+    async fn join_classroom(
+        &self,
+        student_id: Uuid,
+        request: JoinClassroomRequest,
+    ) -> Result<ClassroomResponse, ClassroomError> {
+        let invite_code = request.invite_code.trim().to_uppercase();
+        let classroom = self
+            .classroom_repo
+            .find_by_invite_code(&invite_code)
+            .await?
+            .ok_or(ClassroomError::InvalidInviteCode)?;
+        if classroom.teacher_id == student_id {
+            return Err(ClassroomError::OwnClassroom);
+        }
+
+        let enrollment = Enrollment::new(classroom.id, student_id);
+        if !self.classroom_repo.enroll(&enrollment).await? {
+            return Err(ClassroomError::AlreadyEnrolled);
+        }
+
+        Ok(Self::to_classroom_response(classroom, false))
+    }
+
+    async fn list_my_classrooms(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<ClassroomResponse>, ClassroomError> {
+        let classrooms = self.classroom_repo.find_by_student(student_id).await?;
+
+        Ok(classrooms
+            .into_iter()
+            .map(|classroom| Self::to_classroom_response(classroom, false))
+            .collect())
+    }
+
+    async fn leave_classroom(
+        &self,
+        student_id: Uuid,
+        classroom_id: Uuid,
+    ) -> Result<(), ClassroomError> {
+        if !self
+            .classroom_repo
+            .unenroll(classroom_id, student_id)
+            .await?
+        {
+            return Err(ClassroomError::ClassroomNotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::{AnswerCounts, ScoringPolicy};
+
+    use crate::test_support::{FakeClassroomRepository, FakeTestResultRepository};
+
+    /// Classroom service over in-memory repositories with one classroom, an
+    /// enrolled student and a student from elsewhere, each with a result.
+    struct Fixture {
+        service: ClassroomServiceImpl<FakeClassroomRepository, FakeTestResultRepository>,
+        classroom: Classroom,
+        student_id: Uuid,
+        other_student_id: Uuid,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let classroom =
+                Classroom::new(Uuid::new_v4(), "12-A".to_string(), "ABCD2345".to_string());
+            let student_id = Uuid::new_v4();
+            let other_student_id = Uuid::new_v4();
+
+            let classroom_repo = Arc::new(FakeClassroomRepository::default());
+            classroom_repo
+                .classrooms
+                .lock()
+                .unwrap()
+                .push(classroom.clone());
+            classroom_repo
+                .enrollments
+                .lock()
+                .unwrap()
+                .push(Enrollment::new(classroom.id, student_id));
+
+            let test_result_repo = Arc::new(FakeTestResultRepository::default());
+            let policy = ScoringPolicy::standard(Uuid::new_v4());
+            test_result_repo.results.lock().unwrap().extend([
+                TestResult::new(
+                    student_id,
+                    Uuid::new_v4(),
+                    "AB".to_string(),
+                    AnswerCounts {
+                        correct: 2,
+                        wrong: 0,
+                        empty: 0,
+                    },
+                    &policy,
+                    1,
+                ),
+                TestResult::new(
+                    other_student_id,
+                    Uuid::new_v4(),
+                    "AB".to_string(),
+                    AnswerCounts {
+                        correct: 1,
+                        wrong: 1,
+                        empty: 0,
+                    },
+                    &policy,
+                    1,
+                ),
+            ]);
+
+            Self {
+                service: ClassroomServiceImpl::new(classroom_repo, test_result_repo),
+                classroom,
+                student_id,
+                other_student_id,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_other_teachers_classrooms_are_not_found() {
+        let fixture = Fixture::new();
+        let other_teacher = Uuid::new_v4();
+
+        let err = fixture
+            .service
+            .get_classroom(other_teacher, fixture.classroom.id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClassroomError::ClassroomNotFound));
+
+        let err = fixture
+            .service
+            .list_student_results(
+                other_teacher,
+                fixture.classroom.id,
+                fixture.student_id,
+                1,
+                20,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClassroomError::ClassroomNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_student_results_are_limited_to_enrolled_students() {
+        let fixture = Fixture::new();
+        let teacher_id = fixture.classroom.teacher_id;
+
+        let (results, total) = fixture
+            .service
+            .list_student_results(teacher_id, fixture.classroom.id, fixture.student_id, 1, 20)
+            .await
+            .unwrap();
+        assert_eq!(total, 1);
+        assert!(results.iter().all(|r| r.user_id == fixture.student_id));
+
+        let err = fixture
+            .service
+            .list_student_results(
+                teacher_id,
+                fixture.classroom.id,
+                fixture.other_student_id,
+                1,
+                20,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ClassroomError::StudentNotFound));
+    }
+}
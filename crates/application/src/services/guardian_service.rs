@@ -0,0 +1,353 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{GuardianLink, GuardianLinkDetail, User};
+use domain::errors::DomainError;
+use domain::repositories::{GuardianLinkRepository, UserRepository};
+
+use crate::dto::{GuardianLinkResponse, InviteStudentRequest};
+
+/// Errors for guardian link operations.
+#[derive(Debug, thiserror::Error)]
+pub enum GuardianError {
+    #[error("Student not found")]
+    StudentNotFound,
+
+    #[error("Guardian link not found")]
+    LinkNotFound,
+
+    #[error("Cannot link an account to itself")]
+    SelfLink,
+
+    #[error("Student is already linked or invited")]
+    AlreadyLinked,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for GuardianError {
+    fn from(err: DomainError) -> Self {
+        GuardianError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for guardian invitations and the access they grant.
+#[async_trait]
+pub trait GuardianService: Send + Sync {
+    /// Invites a student, found by username or email, to link with the guardian.
+    async fn invite_student(
+        &self,
+        guardian_id: Uuid,
+        request: InviteStudentRequest,
+    ) -> Result<GuardianLinkResponse, GuardianError>;
+
+    /// Lists the guardian's students, pending and accepted.
+    async fn list_students(
+        &self,
+        guardian_id: Uuid,
+    ) -> Result<Vec<GuardianLinkResponse>, GuardianError>;
+
+    /// Removes the guardian's link to a student, or withdraws a pending invitation.
+    async fn remove_student(
+        &self,
+        guardian_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<(), GuardianError>;
+
+    /// Lists the student's guardians and pending invitations.
+    async fn list_my_guardians(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<GuardianLinkResponse>, GuardianError>;
+
+    /// Accepts a pending invitation sent to the student.
+    async fn accept_invitation(
+        &self,
+        student_id: Uuid,
+        link_id: Uuid,
+    ) -> Result<GuardianLinkResponse, GuardianError>;
+
+    /// Declines an invitation or removes a guardian from the student's account.
+    async fn remove_guardian(&self, student_id: Uuid, link_id: Uuid) -> Result<(), GuardianError>;
+
+    /// Checks that the student accepted the guardian's invitation.
+    /// Unlinked and pending students are reported as missing.
+    async fn authorize_student(
+        &self,
+        guardian_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<(), GuardianError>;
+}
+
+/// Implementation of GuardianService.
+pub struct GuardianServiceImpl<G, U>
+where
+    G: GuardianLinkRepository,
+    U: UserRepository,
+{
+    guardian_link_repo: Arc<G>,
+    user_repo: Arc<U>,
+}
+
+impl<G, U> GuardianServiceImpl<G, U>
+where
+    G: GuardianLinkRepository,
+    U: UserRepository,
+{
+    pub fn new(guardian_link_repo: Arc<G>, user_repo: Arc<U>) -> Self {
+        Self {
+            guardian_link_repo,
+            user_repo,
+        }
+    }
+
+    /// Finds an active user by email when the identifier looks like one, else by username.
+    async fn find_student(&self, identifier: &str) -> Result<User, GuardianError> {
+        let identifier = identifier.trim();
+        let user = if identifier.contains('@') {
+            self.user_repo.find_by_email(identifier).await?
+        } else {
+            self.user_repo.find_by_username(identifier).await?
+        };
+
+        user.filter(|user| user.is_active && !user.is_deleted())
+            .ok_or(GuardianError::StudentNotFound)
+    }
+
+    /// Looks up the username of a user.
+    async fn username(&self, user_id: Uuid) -> Result<String, GuardianError> {
+        let user =
+            self.user_repo.find_by_id(user_id).await?.ok_or_else(|| {
+                GuardianError::InternalError(format!("User {} not found", user_id))
+            })?;
+
+        Ok(user.username)
+    }
+
+    /// Converts a link and the usernames of both sides into its response DTO.
+    fn to_link_response(detail: GuardianLinkDetail) -> GuardianLinkResponse {
+        GuardianLinkResponse {
+            id: detail.link.id,
+            guardian_id: detail.link.guardian_id,
+            guardian_username: detail.guardian_username,
+            student_id: detail.link.student_id,
+            student_username: detail.student_username,
+            status: detail.link.status.as_str().to_string(),
+            invited_at: detail.link.invited_at,
+            accepted_at: detail.link.accepted_at,
+        }
+    }
+}
+
+#[async_trait]
+impl<G, U> GuardianService for GuardianServiceImpl<G, U>
+where
+    G: GuardianLinkRepository + 'static,
+    U: UserRepository + 'static,
+{
+    async fn invite_student(
+        &self,
+        guardian_id: Uuid,
+        request: InviteStudentRequest,
+    ) -> Result<GuardianLinkResponse, GuardianError> {
+        let student = self.find_student(&request.student).await?;
+        if student.id == guardian_id {
+            return Err(GuardianError::SelfLink);
+        }
+        if self
+            .guardian_link_repo
+            .find_by_pair(guardian_id, student.id)
+            .await?
+            .is_some()
+        {
+            return Err(GuardianError::AlreadyLinked);
+        }
+
+        let link = GuardianLink::invite(guardian_id, student.id);
+        let created = self.guardian_link_repo.create(&link).await?;
+
+        Ok(Self::to_link_response(GuardianLinkDetail {
+            link: created,
+            guardian_username: self.username(guardian_id).await?,
+            student_username: student.username,
+        }))
+    }
+
+    async fn list_students(
+        &self,
+        guardian_id: Uuid,
+    ) -> Result<Vec<GuardianLinkResponse>, GuardianError> {
+        let links = self
+            .guardian_link_repo
+            .find_by_guardian(guardian_id)
+            .await?;
+
+        Ok(links.into_iter().map(Self::to_link_response).collect())
+    }
+
+    async fn remove_student(
+        &self,
+        guardian_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<(), GuardianError> {
+        let link = self
+            .guardian_link_repo
+            .find_by_pair(guardian_id, student_id)
+            .await?
+            .ok_or(GuardianError::LinkNotFound)?;
+
+        self.guardian_link_repo.delete(link.id).await?;
+        Ok(())
+    }
+
+    async fn list_my_guardians(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<GuardianLinkResponse>, GuardianError> {
+        let links = self.guardian_link_repo.find_by_student(student_id).await?;
+
+        Ok(links.into_iter().map(Self::to_link_response).collect())
+    }
+
+    async fn accept_invitation(
+        &self,
+        student_id: Uuid,
+        link_id: Uuid,
+    ) -> Result<GuardianLinkResponse, GuardianError> {
+        let mut link = self
+            .guardian_link_repo
+            .find_by_id(link_id)
+            .await?
+            .filter(|link| link.student_id == student_id)
+            .ok_or(GuardianError::LinkNotFound)?;
+
+        link.accept();
+        let updated = self.guardian_link_repo.update(&link).await?;
+
+        Ok(Self::to_link_response(GuardianLinkDetail {
+            guardian_username: self.username(updated.guardian_id).await?,
+            student_username: self.username(updated.student_id).await?,
+            link: updated,
+        }))
+    }
+
+    async fn remove_guardian(&self, student_id: Uuid, link_id: Uuid) -> Result<(), GuardianError> {
+        let link = self
+            .guardian_link_repo
+            .find_by_id(link_id)
+            .await?
+            .filter(|link| link.student_id == student_id)
+            .ok_or(GuardianError::LinkNotFound)?;
+
+        self.guardian_link_repo.delete(link.id).await?;
+        Ok(())
+    }
+
+    async fn authorize_student(
+        &self,
+        guardian_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<(), GuardianError> {
+        self.guardian_link_repo
+            .find_by_pair(guardian_id, student_id)
+            .await?
+            .filter(|link| link.is_accepted())
+            .map(|_| ())
+            .ok_or(GuardianError::StudentNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::test_support::{FakeGuardianLinkRepository, FakeUserRepository};
+
+    /// Guardian service over in-memory repositories with a guardian who has
+    /// invited a student.
+    struct Fixture {
+        service: GuardianServiceImpl<FakeGuardianLinkRepository, FakeUserRepository>,
+        guardian_link_repo: Arc<FakeGuardianLinkRepository>,
+        link: GuardianLink,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let guardian = User::new(
+                "veli".to_string(),
+                "veli@example.com".to_string(),
+                "hash".to_string(),
+            );
+            let student = User::new(
+                "ogrenci".to_string(),
+                "ogrenci@example.com".to_string(),
+                "hash".to_string(),
+            );
+            let link = GuardianLink::invite(guardian.id, student.id);
+
+            let guardian_link_repo = Arc::new(FakeGuardianLinkRepository::default());
+            guardian_link_repo.links.lock().unwrap().push(link.clone());
+            let user_repo = Arc::new(FakeUserRepository::default());
+            user_repo.users.lock().unwrap().extend([guardian, student]);
+
+            Self {
+                service: GuardianServiceImpl::new(guardian_link_repo.clone(), user_repo),
+                guardian_link_repo,
+                link,
+            }
+        }
+
+        fn stored_link(&self) -> GuardianLink {
+            self.guardian_link_repo.links.lock().unwrap()[0].clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_only_accepted_links_authorize_the_guardian() {
+        let fixture = Fixture::new();
+        let (guardian_id, student_id) = (fixture.link.guardian_id, fixture.link.student_id);
+
+        let err = fixture
+            .service
+            .authorize_student(guardian_id, student_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GuardianError::StudentNotFound));
+
+        fixture
+            .service
+            .accept_invitation(student_id, fixture.link.id)
+            .await
+            .unwrap();
+        fixture
+            .service
+            .authorize_student(guardian_id, student_id)
+            .await
+            .unwrap();
+
+        let err = fixture
+            .service
+            .authorize_student(Uuid::new_v4(), student_id)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GuardianError::StudentNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_only_the_invited_student_can_accept() {
+        let fixture = Fixture::new();
+
+        for user_id in [fixture.link.guardian_id, Uuid::new_v4()] {
+            let err = fixture
+                .service
+                .accept_invitation(user_id, fixture.link.id)
+                .await
+                .unwrap_err();
+            assert!(matches!(err, GuardianError::LinkNotFound));
+        }
+
+        assert!(!fixture.stored_link().is_accepted());
+    }
+}
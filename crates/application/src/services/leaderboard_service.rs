@@ -0,0 +1,254 @@
+use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{LeaderboardEntry, LeaderboardScope};
+use domain::errors::DomainError;
+use domain::repositories::{
+    ExamTypeRepository, PracticeTestRepository, TestBookRepository, TestResultRepository,
+    UserRepository,
+};
+
+use crate::dto::{LeaderboardEntryResponse, LeaderboardPreferenceResponse, LeaderboardResponse};
+
+/// Errors for leaderboard operations.
+#[derive(Debug, thiserror::Error)]
+pub enum LeaderboardError {
+    #[error("Practice test not found")]
+    PracticeTestNotFound,
+
+    #[error("Test book not found")]
+    TestBookNotFound,
+
+    #[error("Exam type not found")]
+    ExamTypeNotFound,
+
+    #[error("User not found")]
+    UserNotFound,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for LeaderboardError {
+    fn from(err: DomainError) -> Self {
+        LeaderboardError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for leaderboards ranking users by their results.
+#[async_trait]
+pub trait LeaderboardService: Send + Sync {
+    /// Ranks users by the net of their first attempt of a practice test.
+    async fn get_practice_test_leaderboard(
+        &self,
+        viewer_id: Uuid,
+        practice_test_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError>;
+
+    /// Ranks users by their average first-attempt net over a test book.
+    async fn get_test_book_leaderboard(
+        &self,
+        viewer_id: Uuid,
+        test_book_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError>;
+
+    /// Ranks users by their total first-attempt net in an exam type during the
+    /// week containing `week` (the current week if omitted).
+    async fn get_exam_type_weekly_leaderboard(
+        &self,
+        viewer_id: Uuid,
+        exam_type_id: Uuid,
+        week: Option<NaiveDate>,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError>;
+
+    /// Hides the user from, or shows them on, public leaderboards.
+    async fn set_leaderboard_opt_out(
+        &self,
+        user_id: Uuid,
+        opt_out: bool,
+    ) -> Result<LeaderboardPreferenceResponse, LeaderboardError>;
+}
+
+/// Implementation of LeaderboardService.
+pub struct LeaderboardServiceImpl<R, P, B, E, U>
+where
+    R: TestResultRepository,
+    P: PracticeTestRepository,
+    B: TestBookRepository,
+    E: ExamTypeRepository,
+    U: UserRepository,
+{
+    test_result_repo: Arc<R>,
+    practice_test_repo: Arc<P>,
+    test_book_repo: Arc<B>,
+    exam_type_repo: Arc<E>,
+    user_repo: Arc<U>,
+}
+
+impl<R, P, B, E, U> LeaderboardServiceImpl<R, P, B, E, U>
+where
+    R: TestResultRepository,
+    P: PracticeTestRepository,
+    B: TestBookRepository,
+    E: ExamTypeRepository,
+    U: UserRepository,
+{
+    pub fn new(
+        test_result_repo: Arc<R>,
+        practice_test_repo: Arc<P>,
+        test_book_repo: Arc<B>,
+        exam_type_repo: Arc<E>,
+        user_repo: Arc<U>,
+    ) -> Self {
+        Self {
+            test_result_repo,
+            practice_test_repo,
+            test_book_repo,
+            exam_type_repo,
+            user_repo,
+        }
+    }
+
+    /// Converts a leaderboard entry into its response DTO, exposing the username only.
+    fn to_entry_response(entry: LeaderboardEntry, viewer_id: Uuid) -> LeaderboardEntryResponse {
+        LeaderboardEntryResponse {
+            rank: entry.rank,
+            username: entry.username,
+            score: entry.score,
+            test_count: entry.test_count,
+            is_me: entry.user_id == viewer_id,
+        }
+    }
+
+    async fn load_leaderboard(
+        &self,
+        scope: LeaderboardScope,
+        viewer_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError> {
+        let leaderboard = self
+            .test_result_repo
+            .find_leaderboard(scope, viewer_id, page, per_page)
+            .await?;
+        let week = scope.week();
+
+        Ok(LeaderboardResponse {
+            entries: leaderboard
+                .entries
+                .into_iter()
+                .map(|entry| Self::to_entry_response(entry, viewer_id))
+                .collect(),
+            total_entries: leaderboard.total_entries,
+            my_entry: leaderboard
+                .viewer_entry
+                .map(|entry| Self::to_entry_response(entry, viewer_id)),
+            week_start: week.map(|(start, _)| start),
+            week_end: week.map(|(_, end)| end),
+        })
+    }
+}
+
+#[async_trait]
+impl<R, P, B, E, U> LeaderboardService for LeaderboardServiceImpl<R, P, B, E, U>
+where
+    R: TestResultRepository + 'static,
+    P: PracticeTestRepository + 'static,
+    B: TestBookRepository + 'static,
+    E: ExamTypeRepository + 'static,
+    U: UserRepository + 'static,
+{
+    async fn get_practice_test_leaderboard(
+        &self,
+        viewer_id: Uuid,
+        practice_test_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError> {
+        self.practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(LeaderboardError::PracticeTestNotFound)?;
+
+        self.load_leaderboard(
+            LeaderboardScope::PracticeTest(practice_test_id),
+            viewer_id,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    async fn get_test_book_leaderboard(
+        &self,
+        viewer_id: Uuid,
+        test_book_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError> {
+        self.test_book_repo
+            .find_by_id(test_book_id)
+            .await?
+            .ok_or(LeaderboardError::TestBookNotFound)?;
+
+        self.load_leaderboard(
+            LeaderboardScope::TestBook(test_book_id),
+            viewer_id,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    async fn get_exam_type_weekly_leaderboard(
+        &self,
+        viewer_id: Uuid,
+        exam_type_id: Uuid,
+        week: Option<NaiveDate>,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardResponse, LeaderboardError> {
+        self.exam_type_repo
+            .find_by_id(exam_type_id)
+            .await?
+            .ok_or(LeaderboardError::ExamTypeNotFound)?;
+
+        let day = week.unwrap_or_else(|| Utc::now().date_naive());
+        self.load_leaderboard(
+            LeaderboardScope::exam_type_week(exam_type_id, day),
+            viewer_id,
+            page,
+            per_page,
+        )
+        .await
+    }
+
+    async fn set_leaderboard_opt_out(
+        &self,
+        user_id: Uuid,
+        opt_out: bool,
+    ) -> Result<LeaderboardPreferenceResponse, LeaderboardError> {
+        let mut user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(LeaderboardError::UserNotFound)?;
+
+        if user.leaderboard_opt_out != opt_out {
+            user.leaderboard_opt_out = opt_out;
+            user = self.user_repo.update(&user).await?;
+        }
+
+        Ok(LeaderboardPreferenceResponse {
+            leaderboard_opt_out: user.leaderboard_opt_out,
+        })
+    }
+}
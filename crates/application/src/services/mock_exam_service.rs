@@ -0,0 +1,1247 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::error;
+use uuid::Uuid;
+
+use domain::entities::{
+    AnswerCounts, AnswerKey, MockExam, MockExamAttempt, MockExamAttemptSection, PracticeTest,
+    RetakePolicy, RetakePolicyScope, TestResult,
+};
+use domain::errors::DomainError;
+use domain::repositories::{
+    ExamTypeRepository, MockExamAttemptRepository, MockExamRepository, PracticeTestRepository,
+    RetakePolicyRepository, ScoringPolicyRepository, TestBookRepository, TestResultRepository,
+};
+use domain::services::{AttemptStatus, RetakeEvaluator};
+
+use crate::dto::{
+    CreateMockExamRequest, MockExamAttemptResponse, MockExamAttemptSectionResponse,
+    MockExamResponse, MockExamSectionAnswersDto, MockExamSectionResponse,
+    SaveMockExamAnswersRequest, SubmitMockExamAttemptRequest, TestResultResponse,
+    UpdateMockExamRequest,
+};
+
+/// Errors for mock exam operations.
+#[derive(Debug, thiserror::Error)]
+pub enum MockExamError {
+    #[error("Mock exam not found")]
+    MockExamNotFound,
+
+    #[error("Exam type not found")]
+    ExamTypeNotFound,
+
+    #[error("Practice test not found")]
+    PracticeTestNotFound,
+
+    #[error("Invalid mock exam: {0}")]
+    InvalidMockExam(String),
+
+    #[error("User answers length mismatch")]
+    UserAnswersLengthMismatch,
+
+    #[error("No scoring policy configured for this exam type")]
+    ScoringPolicyNotFound,
+
+    #[error("Mock exam attempt not found")]
+    AttemptNotFound,
+
+    #[error("Mock exam attempt is no longer in progress")]
+    AttemptClosed,
+
+    #[error("Cannot retake test yet. The retake cooldown has not passed")]
+    CannotRetakeYet,
+
+    #[error("Maximum number of attempts reached")]
+    MaxAttemptsReached,
+
+    #[error("The answer key of this practice test is invalid and must be corrected")]
+    InvalidStoredAnswerKey,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for MockExamError {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::InvalidStoredAnswerKey(..) => MockExamError::InvalidStoredAnswerKey,
+            other => MockExamError::InternalError(other.to_string()),
+        }
+    }
+}
+
+/// Trait for managing and solving mock exams.
+#[async_trait]
+pub trait MockExamService: Send + Sync {
+    /// Creates a mock exam from practice tests of one exam type.
+    async fn create_mock_exam(
+        &self,
+        request: CreateMockExamRequest,
+    ) -> Result<MockExamResponse, MockExamError>;
+
+    /// Gets a mock exam by ID.
+    async fn get_mock_exam(&self, id: Uuid) -> Result<MockExamResponse, MockExamError>;
+
+    /// Lists mock exams, optionally for a single exam type.
+    async fn list_mock_exams(
+        &self,
+        exam_type_id: Option<Uuid>,
+    ) -> Result<Vec<MockExamResponse>, MockExamError>;
+
+    /// Updates a mock exam.
+    async fn update_mock_exam(
+        &self,
+        id: Uuid,
+        request: UpdateMockExamRequest,
+    ) -> Result<MockExamResponse, MockExamError>;
+
+    /// Deletes a mock exam.
+    async fn delete_mock_exam(&self, id: Uuid) -> Result<(), MockExamError>;
+
+    /// Starts an attempt at a mock exam, or resumes the one in progress.
+    ///
+    /// A new attempt needs the retake policy of every section's practice test to allow
+    /// it, counting earlier mock exam sections along with the attempts at the test.
+    /// Section results are still not attempts at those practice tests.
+    async fn start_attempt(
+        &self,
+        user_id: Uuid,
+        mock_exam_id: Uuid,
+    ) -> Result<MockExamAttemptResponse, MockExamError>;
+
+    /// Gets one of the user's attempts, auto-submitting it if it has expired.
+    async fn get_attempt(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+    ) -> Result<MockExamAttemptResponse, MockExamError>;
+
+    /// Lists the user's attempts, newest first.
+    async fn list_my_attempts(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<MockExamAttemptResponse>, MockExamError>;
+
+    /// Autosaves the partial answers of an attempt in progress.
+    async fn save_attempt_answers(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+        request: SaveMockExamAnswersRequest,
+    ) -> Result<MockExamAttemptResponse, MockExamError>;
+
+    /// Submits an attempt, grading every section into its own test result.
+    ///
+    /// Answers sent after the deadline are ignored; the attempt is graded with
+    /// the answers saved before it expired.
+    async fn submit_attempt(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+        request: SubmitMockExamAttemptRequest,
+    ) -> Result<MockExamAttemptResponse, MockExamError>;
+
+    /// Auto-submits every attempt whose time limit has run out.
+    /// Attempts that fail to submit are logged and skipped.
+    /// Returns the number of attempts submitted.
+    async fn expire_attempts(&self) -> Result<usize, MockExamError>;
+}
+
+/// Implementation of MockExamService.
+pub struct MockExamServiceImpl<M, A, P, T, E, SP, R, RP>
+where
+    M: MockExamRepository,
+    A: MockExamAttemptRepository,
+    P: PracticeTestRepository,
+    T: TestBookRepository,
+    E: ExamTypeRepository,
+    SP: ScoringPolicyRepository,
+    R: TestResultRepository,
+    RP: RetakePolicyRepository,
+{
+    mock_exam_repo: Arc<M>,
+    attempt_repo: Arc<A>,
+    practice_test_repo: Arc<P>,
+    test_book_repo: Arc<T>,
+    exam_type_repo: Arc<E>,
+    scoring_policy_repo: Arc<SP>,
+    test_result_repo: Arc<R>,
+    retake_policy_repo: Arc<RP>,
+}
+
+impl<M, A, P, T, E, SP, R, RP> MockExamServiceImpl<M, A, P, T, E, SP, R, RP>
+where
+    M: MockExamRepository,
+    A: MockExamAttemptRepository,
+    P: PracticeTestRepository,
+    T: TestBookRepository,
+    E: ExamTypeRepository,
+    SP: ScoringPolicyRepository,
+    R: TestResultRepository,
+    RP: RetakePolicyRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        mock_exam_repo: Arc<M>,
+        attempt_repo: Arc<A>,
+        practice_test_repo: Arc<P>,
+        test_book_repo: Arc<T>,
+        exam_type_repo: Arc<E>,
+        scoring_policy_repo: Arc<SP>,
+        test_result_repo: Arc<R>,
+        retake_policy_repo: Arc<RP>,
+    ) -> Self {
+        Self {
+            mock_exam_repo,
+            attempt_repo,
+            practice_test_repo,
+            test_book_repo,
+            exam_type_repo,
+            scoring_policy_repo,
+            test_result_repo,
+            retake_policy_repo,
+        }
+    }
+
+    /// Converts a test result entity into its response DTO.
+    fn to_test_result_response(result: TestResult) -> TestResultResponse {
+        TestResultResponse {
+            id: result.id,
+            user_id: result.user_id,
+            practice_test_id: result.practice_test_id,
+            user_answers: result.user_answers,
+            correct_count: result.correct_count,
+            wrong_count: result.wrong_count,
+            empty_count: result.empty_count,
+            net_score: result.net_score,
+            scoring_policy_id: result.scoring_policy_id,
+            scoring_policy_version: result.scoring_policy_version,
+            answer_key_revision: result.answer_key_revision,
+            score_changed_at: result.score_changed_at,
+            solved_at: result.solved_at,
+            started_at: result.started_at,
+            elapsed_seconds: result.elapsed_seconds,
+        }
+    }
+
+    /// Converts a mock exam and the practice tests of its sections into its response DTO.
+    fn to_mock_exam_response(
+        mock_exam: MockExam,
+        practice_tests: &[PracticeTest],
+    ) -> MockExamResponse {
+        let sections: Vec<MockExamSectionResponse> = mock_exam
+            .sections
+            .iter()
+            .filter_map(|section| {
+                practice_tests
+                    .iter()
+                    .find(|pt| pt.id == section.practice_test_id)
+                    .map(|pt| MockExamSectionResponse {
+                        practice_test_id: pt.id,
+                        position: section.position,
+                        name: pt.name.clone(),
+                        subject_id: pt.subject_id,
+                        question_count: pt.question_count,
+                    })
+            })
+            .collect();
+
+        MockExamResponse {
+            id: mock_exam.id,
+            name: mock_exam.name,
+            exam_type_id: mock_exam.exam_type_id,
+            time_limit_minutes: mock_exam.time_limit_minutes,
+            question_count: sections.iter().map(|s| s.question_count).sum(),
+            sections,
+            created_at: mock_exam.created_at,
+            updated_at: mock_exam.updated_at,
+        }
+    }
+
+    /// Builds the response of an attempt, attaching the results of graded sections.
+    async fn to_attempt_response(
+        &self,
+        attempt: MockExamAttempt,
+    ) -> Result<MockExamAttemptResponse, MockExamError> {
+        Ok(self.to_attempt_responses(vec![attempt]).await?.remove(0))
+    }
+
+    /// Builds the responses of attempts, loading the results of all their graded
+    /// sections in one query.
+    async fn to_attempt_responses(
+        &self,
+        attempts: Vec<MockExamAttempt>,
+    ) -> Result<Vec<MockExamAttemptResponse>, MockExamError> {
+        let test_result_ids: Vec<Uuid> = attempts
+            .iter()
+            .flat_map(|attempt| &attempt.sections)
+            .filter_map(|section| section.test_result_id)
+            .collect();
+        let test_results: HashMap<Uuid, TestResult> = if test_result_ids.is_empty() {
+            HashMap::new()
+        } else {
+            self.test_result_repo
+                .find_by_ids(&test_result_ids)
+                .await?
+                .into_iter()
+                .map(|result| (result.id, result))
+                .collect()
+        };
+
+        let now = Utc::now();
+        Ok(attempts
+            .into_iter()
+            .map(|attempt| Self::build_attempt_response(attempt, &test_results, now))
+            .collect())
+    }
+
+    /// Converts an attempt into its response DTO with the given section results.
+    fn build_attempt_response(
+        attempt: MockExamAttempt,
+        test_results: &HashMap<Uuid, TestResult>,
+        now: DateTime<Utc>,
+    ) -> MockExamAttemptResponse {
+        let graded = !attempt.is_in_progress();
+        let remaining_seconds = attempt.remaining_seconds(now);
+        let mut sections = Vec::with_capacity(attempt.sections.len());
+        let mut results = Vec::new();
+        for section in attempt.sections {
+            let result = section
+                .test_result_id
+                .and_then(|test_result_id| test_results.get(&test_result_id))
+                .cloned();
+            if let Some(result) = &result {
+                results.push(result.clone());
+            }
+
+            sections.push(MockExamAttemptSectionResponse {
+                practice_test_id: section.practice_test_id,
+                position: section.position,
+                answers: section.answers,
+                result: result.map(Self::to_test_result_response),
+            });
+        }
+
+        // Totals are summed from the section results, so re-grades of a section show up in them
+        let total = |count: fn(&TestResult) -> i32| graded.then(|| results.iter().map(count).sum());
+
+        MockExamAttemptResponse {
+            remaining_seconds,
+            total_correct: total(|r| r.correct_count),
+            total_wrong: total(|r| r.wrong_count),
+            total_empty: total(|r| r.empty_count),
+            id: attempt.id,
+            mock_exam_id: attempt.mock_exam_id,
+            status: attempt.status.as_str().to_string(),
+            started_at: attempt.started_at,
+            expires_at: attempt.expires_at,
+            last_saved_at: attempt.last_saved_at,
+            submitted_at: attempt.submitted_at,
+            total_net: graded.then(|| results.iter().map(|r| r.net_score).sum()),
+            sections,
+        }
+    }
+
+    /// Finds the practice tests of the given sections, in order.
+    async fn find_practice_tests(
+        &self,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<PracticeTest>, MockExamError> {
+        let found: HashMap<Uuid, PracticeTest> = self
+            .practice_test_repo
+            .find_by_ids(practice_test_ids)
+            .await?
+            .into_iter()
+            .map(|pt| (pt.id, pt))
+            .collect();
+
+        practice_test_ids
+            .iter()
+            .map(|id| {
+                found
+                    .get(id)
+                    .cloned()
+                    .ok_or(MockExamError::PracticeTestNotFound)
+            })
+            .collect()
+    }
+
+    /// Checks that every practice test belongs to a test book of the exam type,
+    /// loading the test books in one query.
+    async fn ensure_same_exam_type(
+        &self,
+        exam_type_id: Uuid,
+        practice_tests: &[PracticeTest],
+    ) -> Result<(), MockExamError> {
+        let mut test_book_ids: Vec<Uuid> =
+            practice_tests.iter().map(|pt| pt.test_book_id).collect();
+        test_book_ids.sort();
+        test_book_ids.dedup();
+        let book_exam_types: HashMap<Uuid, Uuid> = self
+            .test_book_repo
+            .find_by_ids(&test_book_ids)
+            .await?
+            .into_iter()
+            .map(|tb| (tb.id, tb.exam_type_id))
+            .collect();
+
+        for practice_test in practice_tests {
+            let book_exam_type_id = book_exam_types
+                .get(&practice_test.test_book_id)
+                .ok_or_else(|| MockExamError::InternalError("Test book not found".to_string()))?;
+            if *book_exam_type_id != exam_type_id {
+                return Err(MockExamError::InvalidMockExam(format!(
+                    "Practice test '{}' does not belong to the mock exam's exam type",
+                    practice_test.name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Rejects a new attempt unless the retake policy of every section's practice
+    /// test allows it. Earlier mock exam sections count along with the attempts.
+    async fn ensure_sections_can_be_attempted(
+        &self,
+        user_id: Uuid,
+        practice_tests: &[PracticeTest],
+    ) -> Result<(), MockExamError> {
+        let mut test_book_ids: Vec<Uuid> =
+            practice_tests.iter().map(|pt| pt.test_book_id).collect();
+        test_book_ids.sort();
+        test_book_ids.dedup();
+
+        // Sections usually share one test book, so policies are resolved per book
+        let mut policies: HashMap<Uuid, RetakePolicy> = HashMap::new();
+        for test_book in self.test_book_repo.find_by_ids(&test_book_ids).await? {
+            let policy = self
+                .retake_policy_repo
+                .find_effective(test_book.id, test_book.exam_type_id)
+                .await?
+                .unwrap_or_else(|| {
+                    RetakePolicy::standard(RetakePolicyScope::ExamType(test_book.exam_type_id))
+                });
+            policies.insert(test_book.id, policy);
+        }
+
+        let practice_test_ids: Vec<Uuid> = practice_tests.iter().map(|pt| pt.id).collect();
+        let mut results_by_test: HashMap<Uuid, Vec<TestResult>> = HashMap::new();
+        for result in self
+            .test_result_repo
+            .find_results_by_user_and_practice_tests(user_id, &practice_test_ids)
+            .await?
+        {
+            results_by_test
+                .entry(result.practice_test_id)
+                .or_default()
+                .push(result);
+        }
+
+        let now = Utc::now();
+        for practice_test in practice_tests {
+            let policy = policies
+                .get(&practice_test.test_book_id)
+                .ok_or_else(|| MockExamError::InternalError("Test book not found".to_string()))?;
+            let results = results_by_test
+                .get(&practice_test.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            match RetakeEvaluator::evaluate(policy, results, now).status {
+                AttemptStatus::Waiting => return Err(MockExamError::CannotRetakeYet),
+                AttemptStatus::Locked => return Err(MockExamError::MaxAttemptsReached),
+                AttemptStatus::Available | AttemptStatus::Solved => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds a mock exam together with the practice tests of its sections.
+    async fn find_mock_exam(
+        &self,
+        id: Uuid,
+    ) -> Result<(MockExam, Vec<PracticeTest>), MockExamError> {
+        let mock_exam = self
+            .mock_exam_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(MockExamError::MockExamNotFound)?;
+        let practice_tests = self
+            .find_practice_tests(&mock_exam.practice_test_ids())
+            .await?;
+
+        Ok((mock_exam, practice_tests))
+    }
+
+    /// Finds an attempt belonging to the user.
+    async fn find_own_attempt(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+    ) -> Result<MockExamAttempt, MockExamError> {
+        self.attempt_repo
+            .find_by_id(attempt_id)
+            .await?
+            .filter(|attempt| attempt.user_id == user_id)
+            .ok_or(MockExamError::AttemptNotFound)
+    }
+
+    /// Applies answers to an attempt in progress.
+    fn apply_answers(
+        attempt: &mut MockExamAttempt,
+        sections: Vec<MockExamSectionAnswersDto>,
+        now: DateTime<Utc>,
+    ) -> Result<(), MockExamError> {
+        for section in sections {
+            if !attempt
+                .sections
+                .iter()
+                .any(|s| s.practice_test_id == section.practice_test_id)
+            {
+                return Err(MockExamError::InvalidMockExam(format!(
+                    "Practice test {} is not a section of this mock exam",
+                    section.practice_test_id
+                )));
+            }
+            attempt
+                .save_answers(section.practice_test_id, section.answers, now)
+                .map_err(|_| MockExamError::UserAnswersLengthMismatch)?;
+        }
+
+        Ok(())
+    }
+
+    /// Grades the answers of a section against the current answer key of its practice
+    /// test, returning the counts and the revision they were graded with.
+    ///
+    /// If the key no longer fits the answers, e.g. because questions were added while
+    /// the attempt was open, the section is graded against the revision it started with.
+    async fn grade_section(
+        &self,
+        practice_test: &PracticeTest,
+        section: &MockExamAttemptSection,
+    ) -> Result<(AnswerCounts, i32), MockExamError> {
+        let answer_key = practice_test.parsed_answer_key()?;
+        if let Some(counts) = AnswerCounts::evaluate(&answer_key, &section.answers) {
+            return Ok((counts, practice_test.answer_key_revision));
+        }
+
+        let revision = self
+            .practice_test_repo
+            .find_answer_key_revisions(practice_test.id)
+            .await?
+            .into_iter()
+            .find(|r| r.revision == section.answer_key_revision)
+            .ok_or_else(|| {
+                MockExamError::InternalError("Answer key revision not found".to_string())
+            })?;
+        let answer_key = AnswerKey::parse(&revision.answer_key).map_err(|e| {
+            MockExamError::from(DomainError::InvalidStoredAnswerKey(
+                practice_test.id,
+                e.to_string(),
+            ))
+        })?;
+        let counts = AnswerCounts::evaluate(&answer_key, &section.answers)
+            .ok_or(MockExamError::UserAnswersLengthMismatch)?;
+
+        Ok((counts, revision.revision))
+    }
+
+    /// Grades every section of an attempt with its current answers and submits it.
+    /// An attempt past its deadline is closed at the deadline as expired.
+    async fn finish_attempt(
+        &self,
+        mut attempt: MockExamAttempt,
+    ) -> Result<MockExamAttempt, MockExamError> {
+        let (mock_exam, practice_tests) = self.find_mock_exam(attempt.mock_exam_id).await?;
+
+        // Every section is scored with the current policy of the mock exam's exam type
+        let scoring_policy = self
+            .scoring_policy_repo
+            .find_current_by_exam_type(mock_exam.exam_type_id)
+            .await?
+            .ok_or(MockExamError::ScoringPolicyNotFound)?;
+
+        let mut test_results = Vec::with_capacity(attempt.sections.len());
+        for section in &attempt.sections {
+            // A section removed from the mock exam since the attempt started is skipped
+            let Some(practice_test) = practice_tests
+                .iter()
+                .find(|pt| pt.id == section.practice_test_id)
+            else {
+                continue;
+            };
+            let (counts, answer_key_revision) = self.grade_section(practice_test, section).await?;
+
+            test_results.push(TestResult::new(
+                attempt.user_id,
+                practice_test.id,
+                section.answers.clone(),
+                counts,
+                &scoring_policy,
+                answer_key_revision,
+            ));
+        }
+
+        let submitted_at = attempt.finish(&test_results, Utc::now());
+        for test_result in &mut test_results {
+            test_result.record_session_timing(attempt.started_at, submitted_at);
+            test_result.record_mock_exam_attempt(attempt.id);
+        }
+
+        // Loses against a concurrent submission of the same attempt
+        if !self.attempt_repo.submit(&attempt, &test_results).await? {
+            return Err(MockExamError::AttemptClosed);
+        }
+
+        Ok(attempt)
+    }
+
+    /// Auto-submits an attempt that has run out of time, tolerating a concurrent submission.
+    async fn submit_if_expired(&self, attempt: &MockExamAttempt) -> Result<bool, MockExamError> {
+        if !attempt.is_expired(Utc::now()) {
+            return Ok(false);
+        }
+        match self.finish_attempt(attempt.clone()).await {
+            Ok(_) | Err(MockExamError::AttemptClosed) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[async_trait]
+impl<M, A, P, T, E, SP, R, RP> MockExamService for MockExamServiceImpl<M, A, P, T, E, SP, R, RP>
+where
+    M: MockExamRepository + 'static,
+    A: MockExamAttemptRepository + 'static,
+    P: PracticeTestRepository + 'static,
+    T: TestBookRepository + 'static,
+    E: ExamTypeRepository + 'static,
+    SP: ScoringPolicyRepository + 'static,
+    R: TestResultRepository + 'static,
+    RP: RetakePolicyRepository + 'static,
+{
+    async fn create_mock_exam(
+        &self,
+        request: CreateMockExamRequest,
+    ) -> Result<MockExamResponse, MockExamError> {
+        self.exam_type_repo
+            .find_by_id(request.exam_type_id)
+            .await?
+            .ok_or(MockExamError::ExamTypeNotFound)?;
+
+        let practice_tests = self.find_practice_tests(&request.practice_test_ids).await?;
+        self.ensure_same_exam_type(request.exam_type_id, &practice_tests)
+            .await?;
+
+        let mock_exam = MockExam::new(
+            request.name,
+            request.exam_type_id,
+            request.time_limit_minutes,
+            request.practice_test_ids,
+        )
+        .map_err(|e| MockExamError::InvalidMockExam(e.to_string()))?;
+        let created = self.mock_exam_repo.create(&mock_exam).await?;
+
+        Ok(Self::to_mock_exam_response(created, &practice_tests))
+    }
+
+    async fn get_mock_exam(&self, id: Uuid) -> Result<MockExamResponse, MockExamError> {
+        let (mock_exam, practice_tests) = self.find_mock_exam(id).await?;
+        Ok(Self::to_mock_exam_response(mock_exam, &practice_tests))
+    }
+
+    async fn list_mock_exams(
+        &self,
+        exam_type_id: Option<Uuid>,
+    ) -> Result<Vec<MockExamResponse>, MockExamError> {
+        let mock_exams = self.mock_exam_repo.list(exam_type_id).await?;
+
+        // The practice tests of all listed mock exams are loaded in one query
+        let mut practice_test_ids: Vec<Uuid> = mock_exams
+            .iter()
+            .flat_map(MockExam::practice_test_ids)
+            .collect();
+        practice_test_ids.sort();
+        practice_test_ids.dedup();
+        let practice_tests = if practice_test_ids.is_empty() {
+            Vec::new()
+        } else {
+            self.practice_test_repo
+                .find_by_ids(&practice_test_ids)
+                .await?
+        };
+
+        Ok(mock_exams
+            .into_iter()
+            .map(|mock_exam| Self::to_mock_exam_response(mock_exam, &practice_tests))
+            .collect())
+    }
+
+    async fn update_mock_exam(
+        &self,
+        id: Uuid,
+        request: UpdateMockExamRequest,
+    ) -> Result<MockExamResponse, MockExamError> {
+        let (mut mock_exam, mut practice_tests) = self.find_mock_exam(id).await?;
+
+        if let Some(name) = request.name {
+            mock_exam.name = name;
+        }
+        if let Some(time_limit_minutes) = request.time_limit_minutes {
+            mock_exam
+                .set_time_limit(time_limit_minutes)
+                .map_err(|e| MockExamError::InvalidMockExam(e.to_string()))?;
+        }
+        if let Some(practice_test_ids) = request.practice_test_ids {
+            practice_tests = self.find_practice_tests(&practice_test_ids).await?;
+            self.ensure_same_exam_type(mock_exam.exam_type_id, &practice_tests)
+                .await?;
+            mock_exam
+                .set_sections(practice_test_ids)
+                .map_err(|e| MockExamError::InvalidMockExam(e.to_string()))?;
+        }
+        mock_exam.updated_at = Utc::now();
+
+        let updated = self.mock_exam_repo.update(&mock_exam).await?;
+        Ok(Self::to_mock_exam_response(updated, &practice_tests))
+    }
+
+    async fn delete_mock_exam(&self, id: Uuid) -> Result<(), MockExamError> {
+        self.mock_exam_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(MockExamError::MockExamNotFound)?;
+
+        self.mock_exam_repo.delete(id).await?;
+        Ok(())
+    }
+
+    async fn start_attempt(
+        &self,
+        user_id: Uuid,
+        mock_exam_id: Uuid,
+    ) -> Result<MockExamAttemptResponse, MockExamError> {
+        let (mock_exam, practice_tests) = self.find_mock_exam(mock_exam_id).await?;
+
+        // Resume the attempt in progress, unless its time has run out
+        if let Some(attempt) = self
+            .attempt_repo
+            .find_in_progress_by_user_and_mock_exam(user_id, mock_exam_id)
+            .await?
+        {
+            if !self.submit_if_expired(&attempt).await? {
+                return self.to_attempt_response(attempt).await;
+            }
+        }
+
+        self.ensure_sections_can_be_attempted(user_id, &practice_tests)
+            .await?;
+
+        let attempt = MockExamAttempt::start(user_id, &mock_exam, &practice_tests, Utc::now())
+            .map_err(|e| MockExamError::InternalError(e.to_string()))?;
+        let created = self.attempt_repo.create(&attempt).await?;
+
+        self.to_attempt_response(created).await
+    }
+
+    async fn get_attempt(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+    ) -> Result<MockExamAttemptResponse, MockExamError> {
+        let mut attempt = self.find_own_attempt(user_id, attempt_id).await?;
+
+        if self.submit_if_expired(&attempt).await? {
+            attempt = self.find_own_attempt(user_id, attempt_id).await?;
+        }
+
+        self.to_attempt_response(attempt).await
+    }
+
+    async fn list_my_attempts(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<MockExamAttemptResponse>, MockExamError> {
+        let attempts = self.attempt_repo.find_by_user_id(user_id).await?;
+        self.to_attempt_responses(attempts).await
+    }
+
+    async fn save_attempt_answers(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+        request: SaveMockExamAnswersRequest,
+    ) -> Result<MockExamAttemptResponse, MockExamError> {
+        let mut attempt = self.find_own_attempt(user_id, attempt_id).await?;
+
+        if !attempt.is_in_progress() || self.submit_if_expired(&attempt).await? {
+            return Err(MockExamError::AttemptClosed);
+        }
+
+        Self::apply_answers(&mut attempt, request.sections, Utc::now())?;
+        if !self.attempt_repo.save_answers(&attempt).await? {
+            return Err(MockExamError::AttemptClosed);
+        }
+
+        self.to_attempt_response(attempt).await
+    }
+
+    async fn submit_attempt(
+        &self,
+        user_id: Uuid,
+        attempt_id: Uuid,
+        request: SubmitMockExamAttemptRequest,
+    ) -> Result<MockExamAttemptResponse, MockExamError> {
+        let mut attempt = self.find_own_attempt(user_id, attempt_id).await?;
+
+        if !attempt.is_in_progress() {
+            return Err(MockExamError::AttemptClosed);
+        }
+
+        // Late answers are dropped; an expired attempt keeps its last autosave
+        if !attempt.is_expired(Utc::now()) {
+            Self::apply_answers(&mut attempt, request.sections, Utc::now())?;
+        }
+
+        let submitted = self.finish_attempt(attempt).await?;
+        self.to_attempt_response(submitted).await
+    }
+
+    async fn expire_attempts(&self) -> Result<usize, MockExamError> {
+        let expired = self.attempt_repo.find_expired(Utc::now()).await?;
+
+        let mut submitted = 0;
+        for attempt in expired {
+            let attempt_id = attempt.id;
+            match self.finish_attempt(attempt).await {
+                Ok(_) => submitted += 1,
+                // Submitted by the student in the meantime
+                Err(MockExamError::AttemptClosed) => {}
+                // One failing attempt must not hold back the others; it is retried next sweep
+                Err(e) => {
+                    error!(
+                        mock_exam_attempt_id = %attempt_id,
+                        "Failed to auto-submit expired mock exam attempt: {:?}",
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(submitted)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use domain::entities::{AnswerKeyRevision, ExamType, ScoringPolicy, TestBook};
+
+    use crate::test_support::{
+        FakeExamTypeRepository, FakeMockExamAttemptRepository, FakeMockExamRepository,
+        FakePracticeTestRepository, FakeRetakePolicyRepository, FakeScoringPolicyRepository,
+        FakeTestBookRepository, FakeTestResultRepository,
+    };
+
+    type Service = MockExamServiceImpl<
+        FakeMockExamRepository,
+        FakeMockExamAttemptRepository,
+        FakePracticeTestRepository,
+        FakeTestBookRepository,
+        FakeExamTypeRepository,
+        FakeScoringPolicyRepository,
+        FakeTestResultRepository,
+        FakeRetakePolicyRepository,
+    >;
+
+    /// Mock exam service over in-memory repositories with a TYT and an AYT test book.
+    struct Fixture {
+        service: Service,
+        practice_test_repo: Arc<FakePracticeTestRepository>,
+        attempt_repo: Arc<FakeMockExamAttemptRepository>,
+        test_result_repo: Arc<FakeTestResultRepository>,
+        tyt: ExamType,
+        tyt_book: TestBook,
+        ayt_book: TestBook,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let tyt = ExamType::new("TYT".to_string(), None);
+            let ayt = ExamType::new("AYT".to_string(), None);
+            let tyt_book =
+                TestBook::new("TYT Denemeleri".to_string(), Uuid::new_v4(), tyt.id, 2024);
+            let ayt_book =
+                TestBook::new("AYT Denemeleri".to_string(), Uuid::new_v4(), ayt.id, 2024);
+
+            let exam_type_repo = Arc::new(FakeExamTypeRepository::default());
+            exam_type_repo
+                .exam_types
+                .lock()
+                .unwrap()
+                .extend([tyt.clone(), ayt]);
+            let test_book_repo = Arc::new(FakeTestBookRepository::default());
+            test_book_repo
+                .test_books
+                .lock()
+                .unwrap()
+                .extend([tyt_book.clone(), ayt_book.clone()]);
+            let scoring_policy_repo = Arc::new(FakeScoringPolicyRepository::default());
+            scoring_policy_repo
+                .policies
+                .lock()
+                .unwrap()
+                .push(ScoringPolicy::standard(tyt.id));
+            let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+            let test_result_repo = Arc::new(FakeTestResultRepository::default());
+            let attempt_repo = Arc::new(FakeMockExamAttemptRepository {
+                test_results: test_result_repo.clone(),
+                ..Default::default()
+            });
+
+            let service = MockExamServiceImpl::new(
+                Arc::new(FakeMockExamRepository::default()),
+                attempt_repo.clone(),
+                practice_test_repo.clone(),
+                test_book_repo,
+                exam_type_repo,
+                scoring_policy_repo,
+                test_result_repo.clone(),
+                Arc::new(FakeRetakePolicyRepository),
+            );
+
+            Self {
+                service,
+                practice_test_repo,
+                attempt_repo,
+                test_result_repo,
+                tyt,
+                tyt_book,
+                ayt_book,
+            }
+        }
+
+        fn add_practice_test(&self, test_book: &TestBook, answer_key: &str) -> PracticeTest {
+            let practice_test = PracticeTest::new(
+                "Deneme 1".to_string(),
+                1,
+                answer_key.chars().count() as i32,
+                answer_key.to_string(),
+                test_book.id,
+                Uuid::new_v4(),
+                None,
+            );
+            self.practice_test_repo
+                .practice_tests
+                .lock()
+                .unwrap()
+                .push(practice_test.clone());
+            practice_test
+        }
+
+        async fn create_mock_exam(&self, practice_tests: &[&PracticeTest]) -> MockExamResponse {
+            self.service
+                .create_mock_exam(CreateMockExamRequest {
+                    name: "TYT Deneme 1".to_string(),
+                    exam_type_id: self.tyt.id,
+                    time_limit_minutes: 165,
+                    practice_test_ids: practice_tests.iter().map(|pt| pt.id).collect(),
+                })
+                .await
+                .unwrap()
+        }
+    }
+
+    fn answers(practice_test: &PracticeTest, answers: &str) -> MockExamSectionAnswersDto {
+        MockExamSectionAnswersDto {
+            practice_test_id: practice_test.id,
+            answers: answers.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_grades_each_section_and_sums_totals() {
+        let fixture = Fixture::new();
+        let turkce = fixture.add_practice_test(&fixture.tyt_book, "ABCD");
+        let matematik = fixture.add_practice_test(&fixture.tyt_book, "AB");
+        let mock_exam = fixture.create_mock_exam(&[&turkce, &matematik]).await;
+        assert_eq!(mock_exam.question_count, 6);
+        let student = Uuid::new_v4();
+
+        let attempt = fixture
+            .service
+            .start_attempt(student, mock_exam.id)
+            .await
+            .unwrap();
+        let submitted = fixture
+            .service
+            .submit_attempt(
+                student,
+                attempt.id,
+                SubmitMockExamAttemptRequest {
+                    sections: vec![answers(&turkce, "ABDD"), answers(&matematik, "A_")],
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(submitted.status, "submitted");
+        let nets: Vec<f64> = submitted
+            .sections
+            .iter()
+            .map(|s| s.result.as_ref().unwrap().net_score)
+            .collect();
+        assert_eq!(nets, vec![2.75, 1.0]);
+        assert_eq!(submitted.total_net, Some(3.75));
+        assert_eq!(submitted.total_correct, Some(4));
+        assert_eq!(submitted.total_wrong, Some(1));
+        assert_eq!(submitted.total_empty, Some(1));
+
+        // Section results belong to the attempt, not to the practice tests' attempts
+        let results = fixture.test_result_repo.results.lock().unwrap().clone();
+        assert_eq!(results.len(), 2);
+        assert!(results
+            .iter()
+            .all(|r| r.mock_exam_attempt_id == Some(attempt.id)));
+
+        let listed = fixture.service.list_my_attempts(student).await.unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].total_correct, Some(4));
+        assert_eq!(
+            listed[0].sections[1].result.as_ref().unwrap().net_score,
+            1.0
+        );
+
+        // A re-graded section changes the attempt's totals along with its counts
+        {
+            let mut results = fixture.test_result_repo.results.lock().unwrap();
+            let section = results
+                .iter_mut()
+                .find(|r| r.practice_test_id == matematik.id)
+                .unwrap();
+            section.correct_count = 2;
+            section.empty_count = 0;
+            section.net_score = 2.0;
+        }
+        let regraded = fixture
+            .service
+            .get_attempt(student, attempt.id)
+            .await
+            .unwrap();
+        assert_eq!(regraded.total_correct, Some(5));
+        assert_eq!(regraded.total_net, Some(4.75));
+    }
+
+    #[tokio::test]
+    async fn test_new_attempt_follows_the_retake_policy_of_each_section() {
+        let fixture = Fixture::new();
+        let turkce = fixture.add_practice_test(&fixture.tyt_book, "ABCD");
+        let matematik = fixture.add_practice_test(&fixture.tyt_book, "AB");
+        let mock_exam = fixture.create_mock_exam(&[&turkce, &matematik]).await;
+        let student = Uuid::new_v4();
+
+        let attempt = fixture
+            .service
+            .start_attempt(student, mock_exam.id)
+            .await
+            .unwrap();
+        // Resuming the attempt in progress is always allowed
+        let resumed = fixture
+            .service
+            .start_attempt(student, mock_exam.id)
+            .await
+            .unwrap();
+        assert_eq!(resumed.id, attempt.id);
+        fixture
+            .service
+            .submit_attempt(
+                student,
+                attempt.id,
+                SubmitMockExamAttemptRequest { sections: vec![] },
+            )
+            .await
+            .unwrap();
+
+        // The submitted sections start the standard cooldown of their practice tests
+        let result = fixture.service.start_attempt(student, mock_exam.id).await;
+        assert!(matches!(result, Err(MockExamError::CannotRetakeYet)));
+
+        for result in fixture.test_result_repo.results.lock().unwrap().iter_mut() {
+            result.solved_at -= Duration::days(2);
+        }
+        let retake = fixture
+            .service
+            .start_attempt(student, mock_exam.id)
+            .await
+            .unwrap();
+        assert_ne!(retake.id, attempt.id);
+    }
+
+    #[tokio::test]
+    async fn test_sections_share_one_time_limit() {
+        let fixture = Fixture::new();
+        let turkce = fixture.add_practice_test(&fixture.tyt_book, "ABCD");
+        let matematik = fixture.add_practice_test(&fixture.tyt_book, "AB");
+        let mock_exam = fixture.create_mock_exam(&[&turkce, &matematik]).await;
+        let student = Uuid::new_v4();
+
+        let attempt = fixture
+            .service
+            .start_attempt(student, mock_exam.id)
+            .await
+            .unwrap();
+        assert_eq!(
+            attempt.expires_at - attempt.started_at,
+            Duration::minutes(165)
+        );
+        assert!(attempt.remaining_seconds.unwrap() <= 165 * 60);
+
+        fixture
+            .service
+            .save_attempt_answers(
+                student,
+                attempt.id,
+                SaveMockExamAnswersRequest {
+                    sections: vec![answers(&turkce, "AB__")],
+                },
+            )
+            .await
+            .unwrap();
+
+        // Let the shared clock run out for every section at once
+        {
+            let mut attempts = fixture.attempt_repo.attempts.lock().unwrap();
+            attempts[0].started_at -= Duration::minutes(166);
+            attempts[0].expires_at -= Duration::minutes(166);
+        }
+
+        let result = fixture
+            .service
+            .save_attempt_answers(
+                student,
+                attempt.id,
+                SaveMockExamAnswersRequest {
+                    sections: vec![answers(&matematik, "AB")],
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(MockExamError::AttemptClosed)));
+
+        let expired = fixture
+            .service
+            .get_attempt(student, attempt.id)
+            .await
+            .unwrap();
+        assert_eq!(expired.status, "expired");
+        assert_eq!(expired.submitted_at, Some(expired.expires_at));
+        assert_eq!(expired.remaining_seconds, None);
+        // Graded with the answers saved before the deadline
+        assert_eq!(expired.total_correct, Some(2));
+        assert_eq!(expired.total_empty, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_grades_against_the_starting_key_when_questions_were_added() {
+        let fixture = Fixture::new();
+        let turkce = fixture.add_practice_test(&fixture.tyt_book, "ABCD");
+        let matematik = fixture.add_practice_test(&fixture.tyt_book, "AB");
+        let mock_exam = fixture.create_mock_exam(&[&turkce, &matematik]).await;
+        fixture
+            .practice_test_repo
+            .answer_key_revisions
+            .lock()
+            .unwrap()
+            .push(AnswerKeyRevision {
+                id: Uuid::new_v4(),
+                practice_test_id: turkce.id,
+                revision: turkce.answer_key_revision,
+                answer_key: turkce.answer_key.clone(),
+                created_at: Utc::now(),
+                regraded_at: None,
+            });
+        let student = Uuid::new_v4();
+        let attempt = fixture
+            .service
+            .start_attempt(student, mock_exam.id)
+            .await
+            .unwrap();
+
+        // A question is added to one section and the other's key is corrected
+        {
+            let mut practice_tests = fixture.practice_test_repo.practice_tests.lock().unwrap();
+            let extended = practice_tests
+                .iter_mut()
+                .find(|pt| pt.id == turkce.id)
+                .unwrap();
+            extended.revise_answer_key("ABCDE".to_string());
+            extended.question_count = 5;
+            let corrected = practice_tests
+                .iter_mut()
+                .find(|pt| pt.id == matematik.id)
+                .unwrap();
+            corrected.revise_answer_key("AA".to_string());
+        }
+
+        let submitted = fixture
+            .service
+            .submit_attempt(
+                student,
+                attempt.id,
+                SubmitMockExamAttemptRequest {
+                    sections: vec![answers(&turkce, "ABCD"), answers(&matematik, "AA")],
+                },
+            )
+            .await
+            .unwrap();
+
+        let results: Vec<&TestResultResponse> = submitted
+            .sections
+            .iter()
+            .map(|s| s.result.as_ref().unwrap())
+            .collect();
+        assert_eq!(results[0].correct_count, 4);
+        assert_eq!(results[0].answer_key_revision, turkce.answer_key_revision);
+        assert_eq!(results[1].correct_count, 2);
+        assert_eq!(
+            results[1].answer_key_revision,
+            matematik.answer_key_revision + 1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rejects_practice_tests_of_another_exam_type() {
+        let fixture = Fixture::new();
+        let turkce = fixture.add_practice_test(&fixture.tyt_book, "ABCD");
+        let fizik = fixture.add_practice_test(&fixture.ayt_book, "AB");
+
+        let result = fixture
+            .service
+            .create_mock_exam(CreateMockExamRequest {
+                name: "TYT Deneme 1".to_string(),
+                exam_type_id: fixture.tyt.id,
+                time_limit_minutes: 165,
+                practice_test_ids: vec![turkce.id, fizik.id],
+            })
+            .await;
+        assert!(matches!(result, Err(MockExamError::InvalidMockExam(_))));
+
+        let mock_exam = fixture.create_mock_exam(&[&turkce]).await;
+        let result = fixture
+            .service
+            .update_mock_exam(
+                mock_exam.id,
+                UpdateMockExamRequest {
+                    name: None,
+                    time_limit_minutes: None,
+                    practice_test_ids: Some(vec![turkce.id, fizik.id]),
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(MockExamError::InvalidMockExam(_))));
+    }
+}
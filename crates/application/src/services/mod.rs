@@ -1,12 +1,45 @@
+mod achievement_service;
+mod analytics_service;
+mod assignment_service;
 mod auth_service;
+mod classroom_service;
+mod guardian_service;
+mod leaderboard_service;
+mod mock_exam_service;
+mod recommendation_service;
+mod regrade_service;
+mod result_import_service;
 mod result_service;
+mod retake_policy_service;
+mod score_calculation_service;
+mod study_plan_service;
 mod test_management_service;
 mod test_solving_service;
+mod topic_service;
 
-pub use auth_service::{AuthError, AuthService, AuthServiceImpl, JwtOperations, PasswordOperations};
+pub use achievement_service::{AchievementError, AchievementService, AchievementServiceImpl};
+pub use analytics_service::{AnalyticsError, AnalyticsService, AnalyticsServiceImpl};
+pub use assignment_service::{AssignmentError, AssignmentService, AssignmentServiceImpl};
+pub use auth_service::{
+    AuthError, AuthService, AuthServiceImpl, JwtOperations, PasswordOperations,
+};
+pub use classroom_service::{ClassroomError, ClassroomService, ClassroomServiceImpl};
+pub use guardian_service::{GuardianError, GuardianService, GuardianServiceImpl};
+pub use leaderboard_service::{LeaderboardError, LeaderboardService, LeaderboardServiceImpl};
+pub use mock_exam_service::{MockExamError, MockExamService, MockExamServiceImpl};
+pub use recommendation_service::{
+    RecommendationError, RecommendationService, RecommendationServiceImpl,
+};
+pub use regrade_service::{RegradeError, RegradeService, RegradeServiceImpl};
+pub use result_import_service::{ResultImportError, ResultImportService, ResultImportServiceImpl};
 pub use result_service::{ResultError, ResultService, ResultServiceImpl};
+pub use retake_policy_service::{RetakePolicyError, RetakePolicyService, RetakePolicyServiceImpl};
+pub use score_calculation_service::{
+    ScoreCalculationError, ScoreCalculationService, ScoreCalculationServiceImpl,
+};
+pub use study_plan_service::{StudyPlanError, StudyPlanService, StudyPlanServiceImpl};
 pub use test_management_service::{
     TestManagementError, TestManagementService, TestManagementServiceImpl,
 };
 pub use test_solving_service::{TestSolvingError, TestSolvingService, TestSolvingServiceImpl};
-
+pub use topic_service::{TopicError, TopicService, TopicServiceImpl};
@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::collections::HashMap;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{RetakePolicy, RetakePolicyScope, TestBook};
+use domain::errors::DomainError;
+use domain::repositories::{
+    PracticeTestRepository, RetakePolicyRepository, TestBookRepository, TestResultRepository,
+};
+use domain::services::RecommendationEngine;
+
+use crate::dto::RecommendationResponse;
+
+/// Errors for practice test recommendations.
+#[derive(Debug, thiserror::Error)]
+pub enum RecommendationError {
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for RecommendationError {
+    fn from(err: DomainError) -> Self {
+        RecommendationError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for recommending what a student should solve next.
+#[async_trait]
+pub trait RecommendationService: Send + Sync {
+    /// Returns at most `limit` practice tests for the user to solve next, most urgent first.
+    async fn get_recommendations(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+    ) -> Result<Vec<RecommendationResponse>, RecommendationError>;
+}
+
+/// Implementation of RecommendationService.
+pub struct RecommendationServiceImpl<R, P, B, RP>
+where
+    R: TestResultRepository,
+    P: PracticeTestRepository,
+    B: TestBookRepository,
+    RP: RetakePolicyRepository,
+{
+    result_repo: Arc<R>,
+    practice_test_repo: Arc<P>,
+    test_book_repo: Arc<B>,
+    retake_policy_repo: Arc<RP>,
+}
+
+impl<R, P, B, RP> RecommendationServiceImpl<R, P, B, RP>
+where
+    R: TestResultRepository,
+    P: PracticeTestRepository,
+    B: TestBookRepository,
+    RP: RetakePolicyRepository,
+{
+    pub fn new(
+        result_repo: Arc<R>,
+        practice_test_repo: Arc<P>,
+        test_book_repo: Arc<B>,
+        retake_policy_repo: Arc<RP>,
+    ) -> Self {
+        Self {
+            result_repo,
+            practice_test_repo,
+            test_book_repo,
+            retake_policy_repo,
+        }
+    }
+
+    /// Finds the retake policy in effect for a test book, defaulting to the standard policy.
+    async fn effective_policy(
+        &self,
+        test_book: &TestBook,
+    ) -> Result<RetakePolicy, RecommendationError> {
+        let policy = self
+            .retake_policy_repo
+            .find_effective(test_book.id, test_book.exam_type_id)
+            .await?;
+
+        Ok(policy.unwrap_or_else(|| {
+            RetakePolicy::standard(RetakePolicyScope::ExamType(test_book.exam_type_id))
+        }))
+    }
+}
+
+#[async_trait]
+impl<R, P, B, RP> RecommendationService for RecommendationServiceImpl<R, P, B, RP>
+where
+    R: TestResultRepository + 'static,
+    P: PracticeTestRepository + 'static,
+    B: TestBookRepository + 'static,
+    RP: RetakePolicyRepository + 'static,
+{
+    async fn get_recommendations(
+        &self,
+        user_id: Uuid,
+        limit: usize,
+    ) -> Result<Vec<RecommendationResponse>, RecommendationError> {
+        let practice_tests = self
+            .practice_test_repo
+            .find_in_test_books_started_by(user_id)
+            .await?;
+        let practice_test_ids: Vec<Uuid> = practice_tests.iter().map(|t| t.id).collect();
+        let results = self
+            .result_repo
+            .find_attempts_by_user_and_practice_tests(user_id, &practice_test_ids)
+            .await?;
+
+        let mut test_books: HashMap<Uuid, TestBook> = HashMap::new();
+        let mut policies: HashMap<Uuid, RetakePolicy> = HashMap::new();
+        for test in &practice_tests {
+            if test_books.contains_key(&test.test_book_id) {
+                continue;
+            }
+            if let Some(test_book) = self.test_book_repo.find_by_id(test.test_book_id).await? {
+                policies.insert(test_book.id, self.effective_policy(&test_book).await?);
+                test_books.insert(test_book.id, test_book);
+            }
+        }
+
+        let recommendations = RecommendationEngine::recommend(
+            &practice_tests,
+            &results,
+            &policies,
+            Utc::now(),
+            limit,
+        );
+
+        let tests_by_id: HashMap<Uuid, _> = practice_tests.iter().map(|t| (t.id, t)).collect();
+        Ok(recommendations
+            .into_iter()
+            .filter_map(|recommendation| {
+                let test = tests_by_id.get(&recommendation.practice_test_id)?;
+                let test_book = test_books.get(&test.test_book_id)?;
+                Some(RecommendationResponse {
+                    practice_test_id: test.id,
+                    practice_test_name: test.name.clone(),
+                    test_number: test.test_number,
+                    test_book_id: test_book.id,
+                    test_book_name: test_book.name.clone(),
+                    subject_id: test.subject_id,
+                    reason: recommendation.reason.as_str().to_string(),
+                    priority: recommendation.priority,
+                    last_accuracy: recommendation.last_accuracy,
+                    last_solved_at: recommendation.last_solved_at,
+                    review_due_at: recommendation.review_due_at,
+                    subject_accuracy: recommendation.subject_accuracy,
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,357 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+use tracing::warn;
+use uuid::Uuid;
+
+use domain::entities::{AnswerCounts, TestResultHistory};
+use domain::errors::DomainError;
+use domain::repositories::{PracticeTestRepository, ScoringPolicyRepository, TestResultRepository};
+
+use crate::dto::RegradeSummary;
+
+/// Errors for re-grade operations.
+#[derive(Debug, thiserror::Error)]
+pub enum RegradeError {
+    #[error("Practice test not found")]
+    PracticeTestNotFound,
+
+    #[error("The answer key of this practice test is invalid and must be corrected")]
+    InvalidStoredAnswerKey,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for RegradeError {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::InvalidStoredAnswerKey(..) => RegradeError::InvalidStoredAnswerKey,
+            other => RegradeError::InternalError(other.to_string()),
+        }
+    }
+}
+
+/// Trait for re-grading results after an answer key revision.
+#[async_trait]
+pub trait RegradeService: Send + Sync {
+    /// Re-grades all results of a practice test that were graded with an older answer key revision.
+    ///
+    /// Re-grades of the same practice test run one at a time. The revision is only marked
+    /// as re-graded once every result was re-graded; skipped results are reported instead.
+    async fn regrade_practice_test(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<RegradeSummary, RegradeError>;
+
+    /// Re-grades every practice test that has an answer key revision still waiting for its re-grade.
+    async fn regrade_pending(&self) -> Result<Vec<RegradeSummary>, RegradeError>;
+}
+
+/// Implementation of RegradeService.
+pub struct RegradeServiceImpl<P, R, SP>
+where
+    P: PracticeTestRepository,
+    R: TestResultRepository,
+    SP: ScoringPolicyRepository,
+{
+    practice_test_repo: Arc<P>,
+    test_result_repo: Arc<R>,
+    scoring_policy_repo: Arc<SP>,
+}
+
+impl<P, R, SP> RegradeServiceImpl<P, R, SP>
+where
+    P: PracticeTestRepository,
+    R: TestResultRepository,
+    SP: ScoringPolicyRepository,
+{
+    pub fn new(
+        practice_test_repo: Arc<P>,
+        test_result_repo: Arc<R>,
+        scoring_policy_repo: Arc<SP>,
+    ) -> Self {
+        Self {
+            practice_test_repo,
+            test_result_repo,
+            scoring_policy_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl<P, R, SP> RegradeService for RegradeServiceImpl<P, R, SP>
+where
+    P: PracticeTestRepository + 'static,
+    R: TestResultRepository + 'static,
+    SP: ScoringPolicyRepository + 'static,
+{
+    async fn regrade_practice_test(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<RegradeSummary, RegradeError> {
+        // Held until the end, so a re-grade started for an older revision finishes first
+        // and this one reads the practice test's newest answer key afterwards
+        let _lock = self
+            .practice_test_repo
+            .lock_regrade(practice_test_id)
+            .await?;
+
+        let practice_test = self
+            .practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(RegradeError::PracticeTestNotFound)?;
+
+        let answer_key = practice_test.parsed_answer_key()?;
+
+        let outdated = self
+            .test_result_repo
+            .find_outdated_by_practice_test(practice_test.id, practice_test.answer_key_revision)
+            .await?;
+
+        let mut summary = RegradeSummary {
+            practice_test_id: practice_test.id,
+            answer_key_revision: practice_test.answer_key_revision,
+            regraded_count: 0,
+            changed_count: 0,
+            skipped_result_ids: Vec::new(),
+        };
+
+        for mut result in outdated {
+            let Some(counts) = AnswerCounts::evaluate(&answer_key, &result.user_answers) else {
+                summary.skipped_result_ids.push(result.id);
+                continue;
+            };
+
+            // Results keep the scoring policy version they were originally graded with
+            let scoring_policy = self
+                .scoring_policy_repo
+                .find_by_id(result.scoring_policy_id)
+                .await?
+                .ok_or_else(|| {
+                    RegradeError::InternalError("Scoring policy not found".to_string())
+                })?;
+
+            let previous = TestResultHistory::snapshot(&result);
+            let changed =
+                result.regrade(counts, &scoring_policy, practice_test.answer_key_revision);
+
+            // Left alone if it was graded with this or a newer revision in the meantime
+            if self
+                .test_result_repo
+                .save_regrade(&result, changed.then_some(&previous))
+                .await?
+                .is_none()
+            {
+                continue;
+            }
+
+            summary.regraded_count += 1;
+            if changed {
+                summary.changed_count += 1;
+            }
+        }
+
+        if summary.skipped_result_ids.is_empty() {
+            self.practice_test_repo
+                .mark_answer_key_revision_regraded(
+                    practice_test.id,
+                    practice_test.answer_key_revision,
+                )
+                .await?;
+        } else {
+            warn!(
+                practice_test_id = %practice_test.id,
+                revision = practice_test.answer_key_revision,
+                skipped_result_ids = ?summary.skipped_result_ids,
+                "Results do not match the answer key length and were not re-graded"
+            );
+        }
+
+        Ok(summary)
+    }
+
+    async fn regrade_pending(&self) -> Result<Vec<RegradeSummary>, RegradeError> {
+        let mut practice_test_ids: Vec<Uuid> = self
+            .practice_test_repo
+            .find_pending_answer_key_revisions()
+            .await?
+            .into_iter()
+            .map(|r| r.practice_test_id)
+            .collect();
+        practice_test_ids.sort();
+        practice_test_ids.dedup();
+
+        let mut summaries = Vec::with_capacity(practice_test_ids.len());
+        for practice_test_id in practice_test_ids {
+            match self.regrade_practice_test(practice_test_id).await {
+                Ok(summary) => summaries.push(summary),
+                // One unreadable key must not hold back the other re-grades
+                Err(RegradeError::InvalidStoredAnswerKey) => {
+                    warn!(
+                        practice_test_id = %practice_test_id,
+                        "Skipping re-grade, stored answer key is invalid"
+                    );
+                }
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(summaries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::{PracticeTest, ScoringPolicy, TestResult};
+
+    use crate::test_support::{
+        FakePracticeTestRepository, FakeScoringPolicyRepository, FakeTestResultRepository,
+    };
+
+    #[tokio::test]
+    async fn test_skipped_results_keep_the_revision_pending() {
+        let policy = ScoringPolicy::standard(Uuid::new_v4());
+        let mut practice_test = PracticeTest::new(
+            "Deneme 1".to_string(),
+            1,
+            4,
+            "ABCD".to_string(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            None,
+        );
+        let graded = |answers: &str, correct: i32| {
+            TestResult::new(
+                Uuid::new_v4(),
+                practice_test.id,
+                answers.to_string(),
+                AnswerCounts {
+                    correct,
+                    wrong: 4 - correct,
+                    empty: 0,
+                },
+                &policy,
+                practice_test.answer_key_revision,
+            )
+        };
+        let matching = graded("ABCA", 3);
+        // Solved from a printed booklet with one question less
+        let outdated_length = graded("ABC", 3);
+        assert!(practice_test.revise_answer_key("ABCA".to_string()));
+
+        let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+        practice_test_repo
+            .practice_tests
+            .lock()
+            .unwrap()
+            .push(practice_test.clone());
+        let test_result_repo = Arc::new(FakeTestResultRepository::default());
+        test_result_repo
+            .results
+            .lock()
+            .unwrap()
+            .extend([matching.clone(), outdated_length.clone()]);
+        let scoring_policy_repo = Arc::new(FakeScoringPolicyRepository::default());
+        scoring_policy_repo.policies.lock().unwrap().push(policy);
+        let service = RegradeServiceImpl::new(
+            practice_test_repo.clone(),
+            test_result_repo.clone(),
+            scoring_policy_repo,
+        );
+
+        let summary = service
+            .regrade_practice_test(practice_test.id)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            summary.answer_key_revision,
+            practice_test.answer_key_revision
+        );
+        assert_eq!(summary.regraded_count, 1);
+        assert_eq!(summary.changed_count, 1);
+        assert_eq!(summary.skipped_result_ids, vec![outdated_length.id]);
+        assert!(practice_test_repo
+            .regraded_revisions
+            .lock()
+            .unwrap()
+            .is_empty());
+
+        let results = test_result_repo.results.lock().unwrap().clone();
+        let regraded = results.iter().find(|r| r.id == matching.id).unwrap();
+        assert_eq!(regraded.correct_count, 4);
+        assert_eq!(
+            regraded.answer_key_revision,
+            practice_test.answer_key_revision
+        );
+        let skipped = results.iter().find(|r| r.id == outdated_length.id).unwrap();
+        assert_eq!(
+            skipped.answer_key_revision,
+            outdated_length.answer_key_revision
+        );
+    }
+
+    #[tokio::test]
+    async fn test_marks_the_revision_once_every_result_is_regraded() {
+        let policy = ScoringPolicy::standard(Uuid::new_v4());
+        let mut practice_test = PracticeTest::new(
+            "Deneme 1".to_string(),
+            1,
+            2,
+            "AB".to_string(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            None,
+        );
+        let result = TestResult::new(
+            Uuid::new_v4(),
+            practice_test.id,
+            "AA".to_string(),
+            AnswerCounts {
+                correct: 1,
+                wrong: 1,
+                empty: 0,
+            },
+            &policy,
+            practice_test.answer_key_revision,
+        );
+        assert!(practice_test.revise_answer_key("AA".to_string()));
+
+        let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+        practice_test_repo
+            .practice_tests
+            .lock()
+            .unwrap()
+            .push(practice_test.clone());
+        let test_result_repo = Arc::new(FakeTestResultRepository::default());
+        test_result_repo.results.lock().unwrap().push(result);
+        let scoring_policy_repo = Arc::new(FakeScoringPolicyRepository::default());
+        scoring_policy_repo.policies.lock().unwrap().push(policy);
+        let service = RegradeServiceImpl::new(
+            practice_test_repo.clone(),
+            test_result_repo.clone(),
+            scoring_policy_repo,
+        );
+
+        let summary = service
+            .regrade_practice_test(practice_test.id)
+            .await
+            .unwrap();
+        assert_eq!(summary.regraded_count, 1);
+        assert!(summary.skipped_result_ids.is_empty());
+        assert_eq!(
+            *practice_test_repo.regraded_revisions.lock().unwrap(),
+            vec![(practice_test.id, practice_test.answer_key_revision)]
+        );
+
+        // A second run finds nothing left on the old revision
+        let summary = service
+            .regrade_practice_test(practice_test.id)
+            .await
+            .unwrap();
+        assert_eq!(summary.regraded_count, 0);
+        assert_eq!(test_result_repo.results.lock().unwrap()[0].correct_count, 2);
+    }
+}
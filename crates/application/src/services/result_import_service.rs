@@ -0,0 +1,540 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use tracing::error;
+use uuid::Uuid;
+
+use domain::entities::{AnswerCounts, TestResult};
+use domain::errors::DomainError;
+use domain::repositories::{
+    PracticeTestRepository, ScoringPolicyRepository, TestBookRepository, TestResultRepository,
+    UserRepository,
+};
+use domain::services::{OmrFormat, OmrParser, OmrRow};
+
+use crate::dto::{ImportOmrResultsRequest, OmrImportReportResponse, OmrImportRowResponse};
+
+/// Errors for result import operations.
+#[derive(Debug, thiserror::Error)]
+pub enum ResultImportError {
+    #[error("Practice test not found")]
+    PracticeTestNotFound,
+
+    #[error("Invalid import format: {0}")]
+    InvalidFormat(String),
+
+    #[error("No scoring policy configured for this exam type")]
+    ScoringPolicyNotFound,
+
+    #[error("The answer key of this practice test is invalid and must be corrected")]
+    InvalidStoredAnswerKey,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for ResultImportError {
+    fn from(err: DomainError) -> Self {
+        match err {
+            DomainError::InvalidStoredAnswerKey(..) => ResultImportError::InvalidStoredAnswerKey,
+            other => ResultImportError::InternalError(other.to_string()),
+        }
+    }
+}
+
+/// Trait for importing results solved outside the application.
+#[async_trait]
+pub trait ResultImportService: Send + Sync {
+    /// Imports scanned optical answer forms (OMR) of a practice test.
+    ///
+    /// Every row is matched to a user by student number and graded like an
+    /// online submission. Rows fail independently; the report lists the
+    /// outcome of each one.
+    async fn import_omr_results(
+        &self,
+        practice_test_id: Uuid,
+        request: ImportOmrResultsRequest,
+    ) -> Result<OmrImportReportResponse, ResultImportError>;
+}
+
+/// Implementation of ResultImportService.
+pub struct ResultImportServiceImpl<P, T, SP, U, R>
+where
+    P: PracticeTestRepository,
+    T: TestBookRepository,
+    SP: ScoringPolicyRepository,
+    U: UserRepository,
+    R: TestResultRepository,
+{
+    practice_test_repo: Arc<P>,
+    test_book_repo: Arc<T>,
+    scoring_policy_repo: Arc<SP>,
+    user_repo: Arc<U>,
+    test_result_repo: Arc<R>,
+}
+
+impl<P, T, SP, U, R> ResultImportServiceImpl<P, T, SP, U, R>
+where
+    P: PracticeTestRepository,
+    T: TestBookRepository,
+    SP: ScoringPolicyRepository,
+    U: UserRepository,
+    R: TestResultRepository,
+{
+    pub fn new(
+        practice_test_repo: Arc<P>,
+        test_book_repo: Arc<T>,
+        scoring_policy_repo: Arc<SP>,
+        user_repo: Arc<U>,
+        test_result_repo: Arc<R>,
+    ) -> Self {
+        Self {
+            practice_test_repo,
+            test_book_repo,
+            scoring_policy_repo,
+            user_repo,
+            test_result_repo,
+        }
+    }
+
+    /// Builds the parser layout from the request.
+    fn to_omr_format(request: &ImportOmrResultsRequest) -> Result<OmrFormat, ResultImportError> {
+        match request.format.as_str() {
+            "csv" => Ok(OmrFormat::Csv {
+                delimiter: request.delimiter.unwrap_or(','),
+                has_header: request.has_header,
+            }),
+            "fixed_width" => {
+                let (Some(student_number_start), Some(student_number_length), Some(answers_start)) = (
+                    request.student_number_start,
+                    request.student_number_length,
+                    request.answers_start,
+                ) else {
+                    return Err(ResultImportError::InvalidFormat(
+                        "Fixed-width imports need student_number_start, student_number_length and answers_start".to_string(),
+                    ));
+                };
+                if student_number_start == 0 || student_number_length == 0 || answers_start == 0 {
+                    return Err(ResultImportError::InvalidFormat(
+                        "Column positions and lengths must be at least 1".to_string(),
+                    ));
+                }
+
+                Ok(OmrFormat::FixedWidth {
+                    student_number_start,
+                    student_number_length,
+                    answers_start,
+                })
+            }
+            other => Err(ResultImportError::InvalidFormat(format!(
+                "Unknown format '{}', expected 'csv' or 'fixed_width'",
+                other
+            ))),
+        }
+    }
+
+    fn failed_row(
+        line_number: usize,
+        student_number: Option<String>,
+        error: impl Into<String>,
+    ) -> OmrImportRowResponse {
+        OmrImportRowResponse {
+            line_number,
+            student_number,
+            status: "failed".to_string(),
+            user_id: None,
+            result_id: None,
+            net_score: None,
+            error: Some(error.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl<P, T, SP, U, R> ResultImportService for ResultImportServiceImpl<P, T, SP, U, R>
+where
+    P: PracticeTestRepository + 'static,
+    T: TestBookRepository + 'static,
+    SP: ScoringPolicyRepository + 'static,
+    U: UserRepository + 'static,
+    R: TestResultRepository + 'static,
+{
+    async fn import_omr_results(
+        &self,
+        practice_test_id: Uuid,
+        request: ImportOmrResultsRequest,
+    ) -> Result<OmrImportReportResponse, ResultImportError> {
+        let format = Self::to_omr_format(&request)?;
+
+        let practice_test = self
+            .practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(ResultImportError::PracticeTestNotFound)?;
+        let test_book = self
+            .test_book_repo
+            .find_by_id(practice_test.test_book_id)
+            .await?
+            .ok_or_else(|| ResultImportError::InternalError("Test book not found".to_string()))?;
+        let scoring_policy = self
+            .scoring_policy_repo
+            .find_current_by_exam_type(test_book.exam_type_id)
+            .await?
+            .ok_or(ResultImportError::ScoringPolicyNotFound)?;
+        let answer_key = practice_test.parsed_answer_key()?;
+
+        let parsed = OmrParser::parse(&request.content, &format);
+
+        // Resolve all student numbers with one lookup
+        let student_numbers: Vec<String> = parsed
+            .iter()
+            .filter_map(|row| row.as_ref().ok())
+            .map(|row| row.student_number.clone())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let users: HashMap<String, Uuid> = self
+            .user_repo
+            .find_by_student_numbers(&student_numbers)
+            .await?
+            .into_iter()
+            .filter_map(|user| user.student_number.map(|number| (number, user.id)))
+            .collect();
+
+        // Students who already have a result, so re-importing a file does not duplicate it
+        let mut existing: HashSet<Uuid> = if request.allow_existing || users.is_empty() {
+            HashSet::new()
+        } else {
+            let user_ids: Vec<Uuid> = users.values().copied().collect();
+            self.test_result_repo
+                .find_user_ids_with_result(practice_test_id, &user_ids)
+                .await?
+                .into_iter()
+                .collect()
+        };
+
+        let mut seen_students = HashSet::new();
+        let mut rows = Vec::with_capacity(parsed.len());
+        for row in parsed {
+            let OmrRow {
+                line_number,
+                student_number,
+                answers,
+            } = match row {
+                Ok(row) => row,
+                Err(e) => {
+                    rows.push(Self::failed_row(e.line_number, None, e.message));
+                    continue;
+                }
+            };
+
+            if !seen_students.insert(student_number.clone()) {
+                rows.push(Self::failed_row(
+                    line_number,
+                    Some(student_number),
+                    "Duplicate student number in the import",
+                ));
+                continue;
+            }
+            let Some(&user_id) = users.get(&student_number) else {
+                rows.push(Self::failed_row(
+                    line_number,
+                    Some(student_number),
+                    "No user with this student number",
+                ));
+                continue;
+            };
+            if existing.contains(&user_id) {
+                rows.push(Self::failed_row(
+                    line_number,
+                    Some(student_number),
+                    "Student already has a result for this test",
+                ));
+                continue;
+            }
+            let user_answers = match OmrParser::normalize_answers(&answers, answer_key.len()) {
+                Ok(user_answers) => user_answers,
+                Err(message) => {
+                    rows.push(Self::failed_row(line_number, Some(student_number), message));
+                    continue;
+                }
+            };
+            let Some(counts) = AnswerCounts::evaluate(&answer_key, &user_answers) else {
+                rows.push(Self::failed_row(
+                    line_number,
+                    Some(student_number),
+                    "Answer key length mismatch",
+                ));
+                continue;
+            };
+
+            let test_result = TestResult::new(
+                user_id,
+                practice_test.id,
+                user_answers,
+                counts,
+                &scoring_policy,
+                practice_test.answer_key_revision,
+            );
+            match self.test_result_repo.create(&test_result).await {
+                Ok(saved) => {
+                    existing.insert(user_id);
+                    rows.push(OmrImportRowResponse {
+                        line_number,
+                        student_number: Some(student_number),
+                        status: "imported".to_string(),
+                        user_id: Some(user_id),
+                        result_id: Some(saved.id),
+                        net_score: Some(saved.net_score),
+                        error: None,
+                    });
+                }
+                // Database details stay in the logs, out of the report
+                Err(e) => {
+                    error!(
+                        practice_test_id = %practice_test_id,
+                        line_number,
+                        "Failed to save imported result: {:?}",
+                        e
+                    );
+                    rows.push(Self::failed_row(
+                        line_number,
+                        Some(student_number),
+                        "The result could not be saved",
+                    ));
+                }
+            }
+        }
+
+        let imported_count = rows.iter().filter(|row| row.error.is_none()).count();
+        Ok(OmrImportReportResponse {
+            practice_test_id,
+            total_rows: rows.len(),
+            imported_count,
+            failed_count: rows.len() - imported_count,
+            rows,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::{PracticeTest, ScoringPolicy, TestBook, User};
+
+    use crate::test_support::{
+        FakePracticeTestRepository, FakeScoringPolicyRepository, FakeTestBookRepository,
+        FakeTestResultRepository, FakeUserRepository,
+    };
+
+    type Service = ResultImportServiceImpl<
+        FakePracticeTestRepository,
+        FakeTestBookRepository,
+        FakeScoringPolicyRepository,
+        FakeUserRepository,
+        FakeTestResultRepository,
+    >;
+
+    /// Result import service over in-memory repositories with one practice test.
+    struct Fixture {
+        service: Service,
+        user_repo: Arc<FakeUserRepository>,
+        test_result_repo: Arc<FakeTestResultRepository>,
+        practice_test: PracticeTest,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            Self::with_test_result_repo(FakeTestResultRepository::default())
+        }
+
+        fn with_test_result_repo(test_result_repo: FakeTestResultRepository) -> Self {
+            let exam_type_id = Uuid::new_v4();
+            let test_book = TestBook::new(
+                "Deneme Kitabı".to_string(),
+                Uuid::new_v4(),
+                exam_type_id,
+                2024,
+            );
+            let practice_test = PracticeTest::new(
+                "Deneme 1".to_string(),
+                1,
+                4,
+                "ABCD".to_string(),
+                test_book.id,
+                Uuid::new_v4(),
+                None,
+            );
+
+            let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+            practice_test_repo
+                .practice_tests
+                .lock()
+                .unwrap()
+                .push(practice_test.clone());
+            let test_book_repo = Arc::new(FakeTestBookRepository::default());
+            test_book_repo.test_books.lock().unwrap().push(test_book);
+            let scoring_policy_repo = Arc::new(FakeScoringPolicyRepository::default());
+            scoring_policy_repo
+                .policies
+                .lock()
+                .unwrap()
+                .push(ScoringPolicy::standard(exam_type_id));
+            let user_repo = Arc::new(FakeUserRepository::default());
+            let test_result_repo = Arc::new(test_result_repo);
+
+            let service = ResultImportServiceImpl::new(
+                practice_test_repo,
+                test_book_repo,
+                scoring_policy_repo,
+                user_repo.clone(),
+                test_result_repo.clone(),
+            );
+
+            Self {
+                service,
+                user_repo,
+                test_result_repo,
+                practice_test,
+            }
+        }
+
+        fn add_student(&self, student_number: &str) -> User {
+            let mut user = User::new(
+                format!("ogrenci{}", student_number),
+                format!("{}@example.com", student_number),
+                "hash".to_string(),
+            );
+            user.student_number = Some(student_number.to_string());
+            self.user_repo.users.lock().unwrap().push(user.clone());
+            user
+        }
+
+        async fn import_csv(&self, content: &str) -> OmrImportReportResponse {
+            self.service
+                .import_omr_results(
+                    self.practice_test.id,
+                    ImportOmrResultsRequest {
+                        format: "csv".to_string(),
+                        content: content.to_string(),
+                        delimiter: Some(';'),
+                        has_header: true,
+                        student_number_start: None,
+                        student_number_length: None,
+                        answers_start: None,
+                        allow_existing: false,
+                    },
+                )
+                .await
+                .unwrap()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_import_matches_rows_by_student_number() {
+        let fixture = Fixture::new();
+        let ayse = fixture.add_student("1001");
+        let mehmet = fixture.add_student("1002");
+
+        let report = fixture
+            .import_csv("ogrenci_no;cevaplar\n1002;ABCD\n1001;AB-a\n")
+            .await;
+
+        assert_eq!(report.imported_count, 2);
+        assert_eq!(report.rows[0].user_id, Some(mehmet.id));
+        assert_eq!(report.rows[0].net_score, Some(4.0));
+        assert_eq!(report.rows[1].user_id, Some(ayse.id));
+        assert_eq!(report.rows[1].net_score, Some(1.75));
+
+        let results = fixture.test_result_repo.results.lock().unwrap().clone();
+        let ayse_result = results.iter().find(|r| r.user_id == ayse.id).unwrap();
+        assert_eq!(ayse_result.user_answers, "AB_A");
+        assert_eq!(ayse_result.practice_test_id, fixture.practice_test.id);
+        assert_eq!(Some(ayse_result.id), report.rows[1].result_id);
+    }
+
+    #[tokio::test]
+    async fn test_import_reports_each_bad_row() {
+        let fixture = Fixture::new();
+        let ayse = fixture.add_student("1001");
+        fixture.add_student("1002");
+        let mehmet = fixture.add_student("1003");
+        fixture
+            .test_result_repo
+            .create(&TestResult::new(
+                mehmet.id,
+                fixture.practice_test.id,
+                "ABCD".to_string(),
+                AnswerCounts {
+                    correct: 4,
+                    wrong: 0,
+                    empty: 0,
+                },
+                &ScoringPolicy::standard(Uuid::new_v4()),
+                1,
+            ))
+            .await
+            .unwrap();
+
+        let report = fixture
+            .import_csv(
+                "ogrenci_no;cevaplar\n\
+                 1001;ABCD\n\
+                 1001;ABCD\n\
+                 9999;ABCD\n\
+                 1002;AB?D\n\
+                 1003;ABCD\n\
+                 1004\n",
+            )
+            .await;
+
+        assert_eq!(report.total_rows, 6);
+        assert_eq!(report.imported_count, 1);
+        assert_eq!(report.failed_count, 5);
+        let outcomes: Vec<(usize, &str, Option<&str>)> = report
+            .rows
+            .iter()
+            .map(|row| (row.line_number, row.status.as_str(), row.error.as_deref()))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (2, "imported", None),
+                (3, "failed", Some("Duplicate student number in the import")),
+                (4, "failed", Some("No user with this student number")),
+                (5, "failed", Some("Unreadable mark '?' for question 3")),
+                (
+                    6,
+                    "failed",
+                    Some("Student already has a result for this test")
+                ),
+                (
+                    7,
+                    "failed",
+                    Some("Expected a student number and an answers column")
+                ),
+            ]
+        );
+        assert_eq!(report.rows[0].user_id, Some(ayse.id));
+        assert_eq!(report.rows[5].student_number, None);
+        // Only the good row was saved next to the existing result
+        assert_eq!(fixture.test_result_repo.results.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_import_keeps_save_errors_out_of_the_report() {
+        let fixture = Fixture::with_test_result_repo(FakeTestResultRepository {
+            fail_create: true,
+            ..Default::default()
+        });
+        fixture.add_student("1001");
+
+        let report = fixture.import_csv("ogrenci_no;cevaplar\n1001;ABCD\n").await;
+
+        assert_eq!(report.failed_count, 1);
+        assert_eq!(
+            report.rows[0].error.as_deref(),
+            Some("The result could not be saved")
+        );
+    }
+}
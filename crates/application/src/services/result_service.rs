@@ -2,10 +2,19 @@ use async_trait::async_trait;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use domain::entities::{
+    AnswerKey, AnswerKeyEntry, PracticeTest, QuestionOutcome, QuestionResult, TestResult,
+};
 use domain::errors::DomainError;
-use domain::repositories::TestResultRepository;
+use domain::repositories::{
+    ExamTypeRepository, PracticeTestRepository, TestBookRepository, TestResultRepository,
+};
+use domain::services::CohortStatistics;
 
-use crate::dto::TestResultResponse;
+use crate::dto::{
+    CohortStatisticsResponse, QuestionResultResponse, TestResultDetailResponse,
+    TestResultHistoryResponse, TestResultResponse,
+};
 
 /// Errors for result operations.
 #[derive(Debug, thiserror::Error)]
@@ -13,13 +22,19 @@ pub enum ResultError {
     #[error("Test result not found")]
     TestResultNotFound,
 
+    #[error("The answer key of this practice test is invalid and must be corrected")]
+    InvalidStoredAnswerKey,
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
 
 impl From<DomainError> for ResultError {
     fn from(err: DomainError) -> Self {
-        ResultError::InternalError(err.to_string())
+        match err {
+            DomainError::InvalidStoredAnswerKey(..) => ResultError::InvalidStoredAnswerKey,
+            other => ResultError::InternalError(other.to_string()),
+        }
     }
 }
 
@@ -29,6 +44,18 @@ pub trait ResultService: Send + Sync {
     /// Gets a test result by ID.
     async fn get_result(&self, id: Uuid) -> Result<TestResultResponse, ResultError>;
 
+    /// Gets one of the user's test results with its per-question breakdown, optionally
+    /// only the wrong answers. Other users' results are reported as missing.
+    ///
+    /// Mock exam sections are not attempts at their practice test, so their breakdown is
+    /// left out while the answer key of the practice test is hidden from the student.
+    async fn get_result_detail(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        only_wrong: bool,
+    ) -> Result<TestResultDetailResponse, ResultError>;
+
     /// Lists test results for a user.
     async fn list_user_results(
         &self,
@@ -45,29 +72,172 @@ pub trait ResultService: Send + Sync {
         page: u32,
         per_page: u32,
     ) -> Result<(Vec<TestResultResponse>, u64), ResultError>;
+
+    /// Lists the previous scores of a re-graded test result, newest first.
+    async fn get_result_history(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<TestResultHistoryResponse>, ResultError>;
 }
 
 /// Implementation of ResultService.
-pub struct ResultServiceImpl<R>
+pub struct ResultServiceImpl<R, P, T, E>
 where
     R: TestResultRepository,
+    P: PracticeTestRepository,
+    T: TestBookRepository,
+    E: ExamTypeRepository,
 {
     test_result_repo: Arc<R>,
+    practice_test_repo: Arc<P>,
+    test_book_repo: Arc<T>,
+    exam_type_repo: Arc<E>,
 }
 
-impl<R> ResultServiceImpl<R>
+impl<R, P, T, E> ResultServiceImpl<R, P, T, E>
 where
     R: TestResultRepository,
+    P: PracticeTestRepository,
+    T: TestBookRepository,
+    E: ExamTypeRepository,
 {
-    pub fn new(test_result_repo: Arc<R>) -> Self {
-        Self { test_result_repo }
+    pub fn new(
+        test_result_repo: Arc<R>,
+        practice_test_repo: Arc<P>,
+        test_book_repo: Arc<T>,
+        exam_type_repo: Arc<E>,
+    ) -> Self {
+        Self {
+            test_result_repo,
+            practice_test_repo,
+            test_book_repo,
+            exam_type_repo,
+        }
+    }
+
+    /// Converts a test result entity into its response DTO.
+    fn to_test_result_response(result: TestResult) -> TestResultResponse {
+        TestResultResponse {
+            id: result.id,
+            user_id: result.user_id,
+            practice_test_id: result.practice_test_id,
+            user_answers: result.user_answers,
+            correct_count: result.correct_count,
+            wrong_count: result.wrong_count,
+            empty_count: result.empty_count,
+            net_score: result.net_score,
+            scoring_policy_id: result.scoring_policy_id,
+            scoring_policy_version: result.scoring_policy_version,
+            answer_key_revision: result.answer_key_revision,
+            score_changed_at: result.score_changed_at,
+            solved_at: result.solved_at,
+            started_at: result.started_at,
+            elapsed_seconds: result.elapsed_seconds,
+        }
+    }
+
+    /// Compares a result with the first attempts of its practice test.
+    async fn cohort_statistics(
+        &self,
+        result: &TestResult,
+    ) -> Result<Option<CohortStatisticsResponse>, ResultError> {
+        let distribution = self
+            .test_result_repo
+            .find_net_distribution(result.practice_test_id)
+            .await?;
+
+        Ok(
+            CohortStatistics::compute(&distribution, result.net_score).map(|stats| {
+                CohortStatisticsResponse {
+                    first_attempt_count: stats.first_attempt_count,
+                    average_net: stats.average_net,
+                    median_net: stats.median_net,
+                    percentile: stats.percentile,
+                }
+            }),
+        )
+    }
+
+    /// Converts a graded question into its response DTO.
+    fn to_question_result_response(question: QuestionResult) -> QuestionResultResponse {
+        let (correct_answers, key_type) = match question.key {
+            AnswerKeyEntry::Accepted { options } => (options, "accepted"),
+            AnswerKeyEntry::Cancelled => (Vec::new(), "cancelled"),
+            AnswerKeyEntry::AnyAnswer => (Vec::new(), "any_answer"),
+        };
+
+        QuestionResultResponse {
+            question_number: question.question_number,
+            given_answer: question.given_answer,
+            correct_answers,
+            key_type: key_type.to_string(),
+            outcome: question.outcome.as_str().to_string(),
+        }
+    }
+
+    /// Checks whether the student of a result may see the answer key of its practice
+    /// test: its exam type reveals keys up front or the student has attempted the test.
+    async fn answer_key_revealed(
+        &self,
+        result: &TestResult,
+        practice_test: &PracticeTest,
+    ) -> Result<bool, ResultError> {
+        let attempts = self
+            .test_result_repo
+            .find_attempts_by_user_and_practice_test(result.user_id, practice_test.id)
+            .await?;
+        if !attempts.is_empty() {
+            return Ok(true);
+        }
+
+        let test_book = self
+            .test_book_repo
+            .find_by_id(practice_test.test_book_id)
+            .await?
+            .ok_or_else(|| ResultError::InternalError("Test book not found".to_string()))?;
+        let exam_type = self
+            .exam_type_repo
+            .find_by_id(test_book.exam_type_id)
+            .await?
+            .ok_or_else(|| ResultError::InternalError("Exam type not found".to_string()))?;
+
+        Ok(exam_type.reveal_answer_keys)
+    }
+
+    /// Loads the answer key revision a result was graded against.
+    async fn find_graded_answer_key(
+        &self,
+        result: &TestResult,
+        practice_test: &PracticeTest,
+    ) -> Result<AnswerKey, ResultError> {
+        if practice_test.answer_key_revision == result.answer_key_revision {
+            return Ok(practice_test.parsed_answer_key()?);
+        }
+
+        // The result is waiting to be re-graded against a newer key
+        let revision = self
+            .practice_test_repo
+            .find_answer_key_revisions(practice_test.id)
+            .await?
+            .into_iter()
+            .find(|r| r.revision == result.answer_key_revision)
+            .ok_or_else(|| {
+                ResultError::InternalError("Answer key revision not found".to_string())
+            })?;
+
+        AnswerKey::parse(&revision.answer_key).map_err(|e| {
+            DomainError::InvalidStoredAnswerKey(revision.practice_test_id, e.to_string()).into()
+        })
     }
 }
 
 #[async_trait]
-impl<R> ResultService for ResultServiceImpl<R>
+impl<R, P, T, E> ResultService for ResultServiceImpl<R, P, T, E>
 where
     R: TestResultRepository + 'static,
+    P: PracticeTestRepository + 'static,
+    T: TestBookRepository + 'static,
+    E: ExamTypeRepository + 'static,
 {
     async fn get_result(&self, id: Uuid) -> Result<TestResultResponse, ResultError> {
         let result = self
@@ -76,16 +246,50 @@ where
             .await?
             .ok_or(ResultError::TestResultNotFound)?;
 
-        Ok(TestResultResponse {
-            id: result.id,
-            user_id: result.user_id,
-            practice_test_id: result.practice_test_id,
-            user_answers: result.user_answers,
-            correct_count: result.correct_count,
-            wrong_count: result.wrong_count,
-            empty_count: result.empty_count,
-            net_score: result.net_score,
-            solved_at: result.solved_at,
+        Ok(Self::to_test_result_response(result))
+    }
+
+    async fn get_result_detail(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        only_wrong: bool,
+    ) -> Result<TestResultDetailResponse, ResultError> {
+        let result = self
+            .test_result_repo
+            .find_by_id(id)
+            .await?
+            .filter(|r| r.user_id == user_id)
+            .ok_or(ResultError::TestResultNotFound)?;
+
+        let practice_test = self
+            .practice_test_repo
+            .find_by_id(result.practice_test_id)
+            .await?
+            .ok_or_else(|| ResultError::InternalError("Practice test not found".to_string()))?;
+
+        let answer_key_hidden = result.mock_exam_attempt_id.is_some()
+            && !self.answer_key_revealed(&result, &practice_test).await?;
+        let questions = if answer_key_hidden {
+            Vec::new()
+        } else {
+            let answer_key = self.find_graded_answer_key(&result, &practice_test).await?;
+
+            // Answers that no longer fit the key cannot be broken down per question
+            QuestionResult::grade_all(&answer_key, &result.user_answers)
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|q| !only_wrong || q.outcome == QuestionOutcome::Wrong)
+                .map(Self::to_question_result_response)
+                .collect()
+        };
+        let cohort = self.cohort_statistics(&result).await?;
+
+        Ok(TestResultDetailResponse {
+            result: Self::to_test_result_response(result),
+            questions,
+            answer_key_hidden,
+            cohort,
         })
     }
 
@@ -103,17 +307,7 @@ where
         Ok((
             results
                 .into_iter()
-                .map(|r| TestResultResponse {
-                    id: r.id,
-                    user_id: r.user_id,
-                    practice_test_id: r.practice_test_id,
-                    user_answers: r.user_answers,
-                    correct_count: r.correct_count,
-                    wrong_count: r.wrong_count,
-                    empty_count: r.empty_count,
-                    net_score: r.net_score,
-                    solved_at: r.solved_at,
-                })
+                .map(Self::to_test_result_response)
                 .collect(),
             total,
         ))
@@ -134,20 +328,209 @@ where
         Ok((
             results
                 .into_iter()
-                .map(|r| TestResultResponse {
-                    id: r.id,
-                    user_id: r.user_id,
-                    practice_test_id: r.practice_test_id,
-                    user_answers: r.user_answers,
-                    correct_count: r.correct_count,
-                    wrong_count: r.wrong_count,
-                    empty_count: r.empty_count,
-                    net_score: r.net_score,
-                    solved_at: r.solved_at,
-                })
+                .map(Self::to_test_result_response)
                 .collect(),
             total,
         ))
     }
+
+    async fn get_result_history(
+        &self,
+        id: Uuid,
+    ) -> Result<Vec<TestResultHistoryResponse>, ResultError> {
+        let history = self
+            .test_result_repo
+            .find_history_by_test_result_id(id)
+            .await?;
+
+        Ok(history
+            .into_iter()
+            .map(|h| TestResultHistoryResponse {
+                id: h.id,
+                test_result_id: h.test_result_id,
+                answer_key_revision: h.answer_key_revision,
+                correct_count: h.correct_count,
+                wrong_count: h.wrong_count,
+                empty_count: h.empty_count,
+                net_score: h.net_score,
+                replaced_at: h.replaced_at,
+            })
+            .collect())
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::{AnswerCounts, ExamType, ScoringPolicy, TestBook};
+
+    use crate::test_support::{
+        FakeExamTypeRepository, FakePracticeTestRepository, FakeTestBookRepository,
+        FakeTestResultRepository,
+    };
+
+    type Service = ResultServiceImpl<
+        FakeTestResultRepository,
+        FakePracticeTestRepository,
+        FakeTestBookRepository,
+        FakeExamTypeRepository,
+    >;
+
+    /// Result service over in-memory repositories with one practice test keyed "AB".
+    struct Fixture {
+        service: Service,
+        test_result_repo: Arc<FakeTestResultRepository>,
+        exam_type_repo: Arc<FakeExamTypeRepository>,
+        practice_test: PracticeTest,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let exam_type = ExamType::new("TYT".to_string(), None);
+            let test_book = TestBook::new(
+                "TYT Denemeleri".to_string(),
+                Uuid::new_v4(),
+                exam_type.id,
+                2024,
+            );
+            let practice_test = PracticeTest::new(
+                "Deneme 1".to_string(),
+                1,
+                2,
+                "AB".to_string(),
+                test_book.id,
+                Uuid::new_v4(),
+                None,
+            );
+
+            let exam_type_repo = Arc::new(FakeExamTypeRepository::default());
+            exam_type_repo.exam_types.lock().unwrap().push(exam_type);
+            let test_book_repo = Arc::new(FakeTestBookRepository::default());
+            test_book_repo.test_books.lock().unwrap().push(test_book);
+            let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+            practice_test_repo
+                .practice_tests
+                .lock()
+                .unwrap()
+                .push(practice_test.clone());
+            let test_result_repo = Arc::new(FakeTestResultRepository::default());
+
+            let service = ResultServiceImpl::new(
+                test_result_repo.clone(),
+                practice_test_repo,
+                test_book_repo,
+                exam_type_repo.clone(),
+            );
+
+            Self {
+                service,
+                test_result_repo,
+                exam_type_repo,
+                practice_test,
+            }
+        }
+
+        fn add_result(&self, user_id: Uuid, mock_exam_attempt_id: Option<Uuid>) -> TestResult {
+            let mut result = TestResult::new(
+                user_id,
+                self.practice_test.id,
+                "A_".to_string(),
+                AnswerCounts {
+                    correct: 1,
+                    wrong: 0,
+                    empty: 1,
+                },
+                &ScoringPolicy::standard(Uuid::new_v4()),
+                self.practice_test.answer_key_revision,
+            );
+            if let Some(mock_exam_attempt_id) = mock_exam_attempt_id {
+                result.record_mock_exam_attempt(mock_exam_attempt_id);
+            }
+            self.test_result_repo
+                .results
+                .lock()
+                .unwrap()
+                .push(result.clone());
+            result
+        }
+    }
+
+    #[tokio::test]
+    async fn test_mock_exam_sections_hide_the_breakdown_until_the_key_is_revealed() {
+        let fixture = Fixture::new();
+        let student = Uuid::new_v4();
+        let section = fixture.add_result(student, Some(Uuid::new_v4()));
+
+        let detail = fixture
+            .service
+            .get_result_detail(student, section.id, false)
+            .await
+            .unwrap();
+        assert!(detail.answer_key_hidden);
+        assert!(detail.questions.is_empty());
+        assert_eq!(detail.result.correct_count, 1);
+
+        // Exam types showing keys up front show the breakdown of sections as well
+        fixture.exam_type_repo.exam_types.lock().unwrap()[0].reveal_answer_keys = true;
+        let detail = fixture
+            .service
+            .get_result_detail(student, section.id, false)
+            .await
+            .unwrap();
+        assert!(!detail.answer_key_hidden);
+        assert_eq!(detail.questions.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_attempting_the_practice_test_reveals_the_breakdown_of_sections() {
+        let fixture = Fixture::new();
+        let student = Uuid::new_v4();
+        let section = fixture.add_result(student, Some(Uuid::new_v4()));
+        // Another student's attempt does not reveal the key to this one
+        fixture.add_result(Uuid::new_v4(), None);
+
+        let detail = fixture
+            .service
+            .get_result_detail(student, section.id, false)
+            .await
+            .unwrap();
+        assert!(detail.answer_key_hidden);
+
+        let attempt = fixture.add_result(student, None);
+        let detail = fixture
+            .service
+            .get_result_detail(student, section.id, true)
+            .await
+            .unwrap();
+        assert!(!detail.answer_key_hidden);
+        assert!(detail.questions.is_empty(), "no wrong answers");
+        let detail = fixture
+            .service
+            .get_result_detail(student, section.id, false)
+            .await
+            .unwrap();
+        assert_eq!(detail.questions.len(), 2);
+
+        // A regular attempt always shows its own breakdown
+        let detail = fixture
+            .service
+            .get_result_detail(student, attempt.id, false)
+            .await
+            .unwrap();
+        assert!(!detail.answer_key_hidden);
+        assert_eq!(detail.questions[0].correct_answers, vec!['A']);
+    }
+
+    #[tokio::test]
+    async fn test_other_users_results_are_not_found() {
+        let fixture = Fixture::new();
+        let result = fixture.add_result(Uuid::new_v4(), None);
+
+        let err = fixture
+            .service
+            .get_result_detail(Uuid::new_v4(), result.id, false)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ResultError::TestResultNotFound));
+    }
+}
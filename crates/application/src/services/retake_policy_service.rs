@@ -0,0 +1,197 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+
+use domain::entities::{AttemptScoring, RetakePolicy, RetakePolicyScope};
+use domain::errors::DomainError;
+use domain::repositories::{ExamTypeRepository, RetakePolicyRepository, TestBookRepository};
+
+use crate::dto::{RetakePolicyResponse, UpdateRetakePolicyRequest};
+
+/// Errors for retake policy operations.
+#[derive(Debug, thiserror::Error)]
+pub enum RetakePolicyError {
+    #[error("Exam type not found")]
+    ExamTypeNotFound,
+
+    #[error("Test book not found")]
+    TestBookNotFound,
+
+    #[error("Retake policy not found")]
+    RetakePolicyNotFound,
+
+    #[error("Invalid attempt scoring: {0}")]
+    InvalidAttemptScoring(String),
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for RetakePolicyError {
+    fn from(err: DomainError) -> Self {
+        RetakePolicyError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for managing the retake policies of exam types and test books.
+#[async_trait]
+pub trait RetakePolicyService: Send + Sync {
+    /// Gets the policy defined directly on an exam type or test book.
+    async fn get_retake_policy(
+        &self,
+        scope: RetakePolicyScope,
+    ) -> Result<RetakePolicyResponse, RetakePolicyError>;
+
+    /// Creates or replaces the policy of an exam type or test book.
+    async fn save_retake_policy(
+        &self,
+        scope: RetakePolicyScope,
+        request: UpdateRetakePolicyRequest,
+    ) -> Result<RetakePolicyResponse, RetakePolicyError>;
+
+    /// Removes the policy of an exam type or test book, falling back to the
+    /// exam type's policy or the standard 24-hour rule.
+    async fn delete_retake_policy(&self, scope: RetakePolicyScope)
+        -> Result<(), RetakePolicyError>;
+}
+
+/// Implementation of RetakePolicyService.
+pub struct RetakePolicyServiceImpl<RP, E, T>
+where
+    RP: RetakePolicyRepository,
+    E: ExamTypeRepository,
+    T: TestBookRepository,
+{
+    retake_policy_repo: Arc<RP>,
+    exam_type_repo: Arc<E>,
+    test_book_repo: Arc<T>,
+}
+
+impl<RP, E, T> RetakePolicyServiceImpl<RP, E, T>
+where
+    RP: RetakePolicyRepository,
+    E: ExamTypeRepository,
+    T: TestBookRepository,
+{
+    pub fn new(
+        retake_policy_repo: Arc<RP>,
+        exam_type_repo: Arc<E>,
+        test_book_repo: Arc<T>,
+    ) -> Self {
+        Self {
+            retake_policy_repo,
+            exam_type_repo,
+            test_book_repo,
+        }
+    }
+
+    /// Verifies that the exam type or test book of a scope exists.
+    async fn ensure_scope_exists(&self, scope: RetakePolicyScope) -> Result<(), RetakePolicyError> {
+        match scope {
+            RetakePolicyScope::ExamType(id) => {
+                self.exam_type_repo
+                    .find_by_id(id)
+                    .await?
+                    .ok_or(RetakePolicyError::ExamTypeNotFound)?;
+            }
+            RetakePolicyScope::TestBook(id) => {
+                self.test_book_repo
+                    .find_by_id(id)
+                    .await?
+                    .ok_or(RetakePolicyError::TestBookNotFound)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn to_retake_policy_response(policy: RetakePolicy) -> RetakePolicyResponse {
+        let (exam_type_id, test_book_id) = match policy.scope {
+            RetakePolicyScope::ExamType(id) => (Some(id), None),
+            RetakePolicyScope::TestBook(id) => (None, Some(id)),
+        };
+
+        RetakePolicyResponse {
+            id: policy.id,
+            exam_type_id,
+            test_book_id,
+            cooldown_minutes: policy.cooldown_minutes,
+            max_attempts: policy.max_attempts,
+            attempt_scoring: policy.attempt_scoring.as_str().to_string(),
+            unlimited_practice: policy.unlimited_practice,
+            created_at: policy.created_at,
+            updated_at: policy.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl<RP, E, T> RetakePolicyService for RetakePolicyServiceImpl<RP, E, T>
+where
+    RP: RetakePolicyRepository + 'static,
+    E: ExamTypeRepository + 'static,
+    T: TestBookRepository + 'static,
+{
+    async fn get_retake_policy(
+        &self,
+        scope: RetakePolicyScope,
+    ) -> Result<RetakePolicyResponse, RetakePolicyError> {
+        self.ensure_scope_exists(scope).await?;
+
+        let policy = self
+            .retake_policy_repo
+            .find_by_scope(scope)
+            .await?
+            .ok_or(RetakePolicyError::RetakePolicyNotFound)?;
+
+        Ok(Self::to_retake_policy_response(policy))
+    }
+
+    async fn save_retake_policy(
+        &self,
+        scope: RetakePolicyScope,
+        request: UpdateRetakePolicyRequest,
+    ) -> Result<RetakePolicyResponse, RetakePolicyError> {
+        self.ensure_scope_exists(scope).await?;
+
+        let attempt_scoring = AttemptScoring::parse(&request.attempt_scoring).ok_or_else(|| {
+            RetakePolicyError::InvalidAttemptScoring(request.attempt_scoring.clone())
+        })?;
+
+        let policy = match self.retake_policy_repo.find_by_scope(scope).await? {
+            Some(mut existing) => {
+                existing.cooldown_minutes = request.cooldown_minutes;
+                existing.max_attempts = request.max_attempts;
+                existing.attempt_scoring = attempt_scoring;
+                existing.unlimited_practice = request.unlimited_practice;
+                existing.updated_at = Utc::now();
+                existing
+            }
+            None => RetakePolicy::new(
+                scope,
+                request.cooldown_minutes,
+                request.max_attempts,
+                attempt_scoring,
+                request.unlimited_practice,
+            ),
+        };
+
+        let saved = self.retake_policy_repo.save(&policy).await?;
+
+        Ok(Self::to_retake_policy_response(saved))
+    }
+
+    async fn delete_retake_policy(
+        &self,
+        scope: RetakePolicyScope,
+    ) -> Result<(), RetakePolicyError> {
+        let policy = self
+            .retake_policy_repo
+            .find_by_scope(scope)
+            .await?
+            .ok_or(RetakePolicyError::RetakePolicyNotFound)?;
+
+        self.retake_policy_repo.delete(policy.id).await?;
+
+        Ok(())
+    }
+}
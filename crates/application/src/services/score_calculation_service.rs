@@ -0,0 +1,661 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{LessonCoefficient, ScoreTable};
+use domain::errors::DomainError;
+use domain::repositories::{
+    ExamTypeRepository, LessonRepository, PracticeTestRepository, ScoreTableRepository,
+    ScoringPolicyRepository, SubjectRepository, TestBookRepository, TestResultRepository,
+};
+
+use crate::dto::{
+    EstimateScoreRequest, LessonCoefficientDto, LessonScoreResponse, SaveScoreTableRequest,
+    ScoreEstimateResponse, ScoreTableResponse, SubjectNetDto,
+};
+
+/// Errors for score calculation operations.
+#[derive(Debug, thiserror::Error)]
+pub enum ScoreCalculationError {
+    #[error("Exam type not found")]
+    ExamTypeNotFound,
+
+    #[error("Score table not found")]
+    ScoreTableNotFound,
+
+    #[error("Lesson not found")]
+    LessonNotFound,
+
+    #[error("Subject not found")]
+    SubjectNotFound,
+
+    #[error("Test result not found")]
+    TestResultNotFound,
+
+    #[error("No scoring policy configured for this exam type")]
+    ScoringPolicyNotFound,
+
+    #[error("Subject net is out of range for its question count")]
+    NetOutOfRange,
+
+    #[error("Result or subject does not belong to the requested exam type")]
+    ExamTypeMismatch,
+
+    #[error("Either result IDs or subject nets are required")]
+    NoNetsProvided,
+
+    #[error("Each test result can only be counted once")]
+    DuplicateResultId,
+
+    #[error("Each lesson can only have one coefficient")]
+    DuplicateLessonCoefficient,
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for ScoreCalculationError {
+    fn from(err: DomainError) -> Self {
+        ScoreCalculationError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for standard score (puan) calculation operations.
+#[async_trait]
+pub trait ScoreCalculationService: Send + Sync {
+    /// Lists the coefficient tables of an exam type, newest year first.
+    async fn list_score_tables(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<ScoreTableResponse>, ScoreCalculationError>;
+
+    /// Creates or replaces the coefficient table of an exam type for a year.
+    async fn save_score_table(
+        &self,
+        exam_type_id: Uuid,
+        year: i32,
+        request: SaveScoreTableRequest,
+    ) -> Result<ScoreTableResponse, ScoreCalculationError>;
+
+    /// Deletes the coefficient table of an exam type for a year.
+    async fn delete_score_table(
+        &self,
+        exam_type_id: Uuid,
+        year: i32,
+    ) -> Result<(), ScoreCalculationError>;
+
+    /// Estimates a user's score from their test results and/or entered subject nets.
+    async fn estimate_score(
+        &self,
+        user_id: Uuid,
+        request: EstimateScoreRequest,
+    ) -> Result<ScoreEstimateResponse, ScoreCalculationError>;
+}
+
+/// Implementation of ScoreCalculationService.
+pub struct ScoreCalculationServiceImpl<E, ST, L, S, T, P, R, SP>
+where
+    E: ExamTypeRepository,
+    ST: ScoreTableRepository,
+    L: LessonRepository,
+    S: SubjectRepository,
+    T: TestBookRepository,
+    P: PracticeTestRepository,
+    R: TestResultRepository,
+    SP: ScoringPolicyRepository,
+{
+    exam_type_repo: Arc<E>,
+    score_table_repo: Arc<ST>,
+    lesson_repo: Arc<L>,
+    subject_repo: Arc<S>,
+    test_book_repo: Arc<T>,
+    practice_test_repo: Arc<P>,
+    test_result_repo: Arc<R>,
+    scoring_policy_repo: Arc<SP>,
+}
+
+impl<E, ST, L, S, T, P, R, SP> ScoreCalculationServiceImpl<E, ST, L, S, T, P, R, SP>
+where
+    E: ExamTypeRepository,
+    ST: ScoreTableRepository,
+    L: LessonRepository,
+    S: SubjectRepository,
+    T: TestBookRepository,
+    P: PracticeTestRepository,
+    R: TestResultRepository,
+    SP: ScoringPolicyRepository,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        exam_type_repo: Arc<E>,
+        score_table_repo: Arc<ST>,
+        lesson_repo: Arc<L>,
+        subject_repo: Arc<S>,
+        test_book_repo: Arc<T>,
+        practice_test_repo: Arc<P>,
+        test_result_repo: Arc<R>,
+        scoring_policy_repo: Arc<SP>,
+    ) -> Self {
+        Self {
+            exam_type_repo,
+            score_table_repo,
+            lesson_repo,
+            subject_repo,
+            test_book_repo,
+            practice_test_repo,
+            test_result_repo,
+            scoring_policy_repo,
+        }
+    }
+
+    /// Converts a score table entity into its response DTO.
+    fn to_score_table_response(table: ScoreTable) -> ScoreTableResponse {
+        ScoreTableResponse {
+            id: table.id,
+            exam_type_id: table.exam_type_id,
+            year: table.year,
+            base_score: table.base_score,
+            diploma_coefficient: table.diploma_coefficient,
+            coefficients: table
+                .coefficients
+                .into_iter()
+                .map(|c| LessonCoefficientDto {
+                    lesson_id: c.lesson_id,
+                    coefficient: c.coefficient,
+                })
+                .collect(),
+            created_at: table.created_at,
+            updated_at: table.updated_at,
+        }
+    }
+
+    /// Sums the user's result nets per lesson, checking ownership and exam type.
+    async fn add_result_nets(
+        &self,
+        user_id: Uuid,
+        exam_type_id: Uuid,
+        result_ids: &[Uuid],
+        lesson_nets: &mut HashMap<Uuid, f64>,
+    ) -> Result<(), ScoreCalculationError> {
+        if result_ids.is_empty() {
+            return Ok(());
+        }
+
+        let results = self.test_result_repo.find_by_ids(result_ids).await?;
+        if results.len() != result_ids.len() || results.iter().any(|r| r.user_id != user_id) {
+            return Err(ScoreCalculationError::TestResultNotFound);
+        }
+
+        let practice_test_ids: Vec<Uuid> = results
+            .iter()
+            .map(|r| r.practice_test_id)
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let practice_tests: HashMap<Uuid, Uuid> = self
+            .practice_test_repo
+            .find_by_ids(&practice_test_ids)
+            .await?
+            .into_iter()
+            .map(|pt| (pt.id, pt.test_book_id))
+            .collect();
+
+        let test_book_ids: Vec<Uuid> = practice_tests
+            .values()
+            .copied()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        let test_books: HashMap<Uuid, _> = self
+            .test_book_repo
+            .find_by_ids(&test_book_ids)
+            .await?
+            .into_iter()
+            .map(|tb| (tb.id, tb))
+            .collect();
+
+        for result in &results {
+            let test_book = practice_tests
+                .get(&result.practice_test_id)
+                .and_then(|test_book_id| test_books.get(test_book_id))
+                .ok_or_else(|| {
+                    ScoreCalculationError::InternalError("Practice test not found".to_string())
+                })?;
+
+            if test_book.exam_type_id != exam_type_id {
+                return Err(ScoreCalculationError::ExamTypeMismatch);
+            }
+
+            *lesson_nets.entry(test_book.lesson_id).or_default() += result.net_score;
+        }
+
+        Ok(())
+    }
+
+    /// Sums the entered subject nets per lesson, checking exam type and that
+    /// each net is reachable with the subject's question count under the exam
+    /// type's current scoring policy.
+    async fn add_subject_nets(
+        &self,
+        exam_type_id: Uuid,
+        subject_nets: &[SubjectNetDto],
+        lesson_nets: &mut HashMap<Uuid, f64>,
+    ) -> Result<(), ScoreCalculationError> {
+        if subject_nets.is_empty() {
+            return Ok(());
+        }
+
+        let policy = self
+            .scoring_policy_repo
+            .find_current_by_exam_type(exam_type_id)
+            .await?
+            .ok_or(ScoreCalculationError::ScoringPolicyNotFound)?;
+
+        let subject_ids: Vec<Uuid> = subject_nets.iter().map(|s| s.subject_id).collect();
+        let subjects: HashMap<Uuid, _> = self
+            .subject_repo
+            .find_by_ids(&subject_ids)
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+
+        for subject_net in subject_nets {
+            let subject = subjects
+                .get(&subject_net.subject_id)
+                .ok_or(ScoreCalculationError::SubjectNotFound)?;
+
+            if subject.exam_type_id != exam_type_id {
+                return Err(ScoreCalculationError::ExamTypeMismatch);
+            }
+
+            let min_net = policy.net_score(0, subject_net.question_count);
+            let max_net = policy.net_score(subject_net.question_count, 0);
+            if !(min_net..=max_net).contains(&subject_net.net) {
+                return Err(ScoreCalculationError::NetOutOfRange);
+            }
+
+            *lesson_nets.entry(subject.lesson_id).or_default() += subject_net.net;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<E, ST, L, S, T, P, R, SP> ScoreCalculationService
+    for ScoreCalculationServiceImpl<E, ST, L, S, T, P, R, SP>
+where
+    E: ExamTypeRepository + 'static,
+    ST: ScoreTableRepository + 'static,
+    L: LessonRepository + 'static,
+    S: SubjectRepository + 'static,
+    T: TestBookRepository + 'static,
+    P: PracticeTestRepository + 'static,
+    R: TestResultRepository + 'static,
+    SP: ScoringPolicyRepository + 'static,
+{
+    async fn list_score_tables(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<ScoreTableResponse>, ScoreCalculationError> {
+        self.exam_type_repo
+            .find_by_id(exam_type_id)
+            .await?
+            .ok_or(ScoreCalculationError::ExamTypeNotFound)?;
+
+        let tables = self
+            .score_table_repo
+            .list_by_exam_type(exam_type_id)
+            .await?;
+
+        Ok(tables
+            .into_iter()
+            .map(Self::to_score_table_response)
+            .collect())
+    }
+
+    async fn save_score_table(
+        &self,
+        exam_type_id: Uuid,
+        year: i32,
+        request: SaveScoreTableRequest,
+    ) -> Result<ScoreTableResponse, ScoreCalculationError> {
+        self.exam_type_repo
+            .find_by_id(exam_type_id)
+            .await?
+            .ok_or(ScoreCalculationError::ExamTypeNotFound)?;
+
+        let mut lesson_ids = HashSet::new();
+        if !request
+            .coefficients
+            .iter()
+            .all(|c| lesson_ids.insert(c.lesson_id))
+        {
+            return Err(ScoreCalculationError::DuplicateLessonCoefficient);
+        }
+
+        for coefficient in &request.coefficients {
+            self.lesson_repo
+                .find_by_id(coefficient.lesson_id)
+                .await?
+                .ok_or(ScoreCalculationError::LessonNotFound)?;
+        }
+
+        let score_table = ScoreTable::new(
+            exam_type_id,
+            year,
+            request.base_score,
+            request
+                .diploma_coefficient
+                .unwrap_or(ScoreTable::STANDARD_DIPLOMA_COEFFICIENT),
+            request
+                .coefficients
+                .into_iter()
+                .map(|c| LessonCoefficient {
+                    lesson_id: c.lesson_id,
+                    coefficient: c.coefficient,
+                })
+                .collect(),
+        );
+        let saved = self.score_table_repo.save(&score_table).await?;
+
+        Ok(Self::to_score_table_response(saved))
+    }
+
+    async fn delete_score_table(
+        &self,
+        exam_type_id: Uuid,
+        year: i32,
+    ) -> Result<(), ScoreCalculationError> {
+        let table = self
+            .score_table_repo
+            .find_by_exam_type_and_year(exam_type_id, year)
+            .await?
+            .ok_or(ScoreCalculationError::ScoreTableNotFound)?;
+
+        self.score_table_repo.delete(table.id).await?;
+        Ok(())
+    }
+
+    async fn estimate_score(
+        &self,
+        user_id: Uuid,
+        request: EstimateScoreRequest,
+    ) -> Result<ScoreEstimateResponse, ScoreCalculationError> {
+        if request.result_ids.is_empty() && request.subject_nets.is_empty() {
+            return Err(ScoreCalculationError::NoNetsProvided);
+        }
+
+        let mut result_ids = HashSet::new();
+        if !request.result_ids.iter().all(|id| result_ids.insert(*id)) {
+            return Err(ScoreCalculationError::DuplicateResultId);
+        }
+
+        self.exam_type_repo
+            .find_by_id(request.exam_type_id)
+            .await?
+            .ok_or(ScoreCalculationError::ExamTypeNotFound)?;
+
+        let score_table = match request.year {
+            Some(year) => {
+                self.score_table_repo
+                    .find_by_exam_type_and_year(request.exam_type_id, year)
+                    .await?
+            }
+            None => {
+                self.score_table_repo
+                    .find_latest_by_exam_type(request.exam_type_id)
+                    .await?
+            }
+        }
+        .ok_or(ScoreCalculationError::ScoreTableNotFound)?;
+
+        // Collect nets per lesson from both sources
+        let mut lesson_nets: HashMap<Uuid, f64> = HashMap::new();
+        self.add_result_nets(
+            user_id,
+            request.exam_type_id,
+            &request.result_ids,
+            &mut lesson_nets,
+        )
+        .await?;
+
+        self.add_subject_nets(
+            request.exam_type_id,
+            &request.subject_nets,
+            &mut lesson_nets,
+        )
+        .await?;
+
+        let estimate = score_table.estimate(&lesson_nets, request.diploma_grade);
+
+        let lesson_names: HashMap<Uuid, String> = self
+            .lesson_repo
+            .list_all()
+            .await?
+            .into_iter()
+            .map(|l| (l.id, l.name))
+            .collect();
+
+        Ok(ScoreEstimateResponse {
+            exam_type_id: score_table.exam_type_id,
+            year: score_table.year,
+            base_score: score_table.base_score,
+            lessons: estimate
+                .lessons
+                .into_iter()
+                .map(|l| LessonScoreResponse {
+                    lesson_id: l.lesson_id,
+                    lesson_name: lesson_names.get(&l.lesson_id).cloned().unwrap_or_default(),
+                    net: l.net,
+                    coefficient: l.coefficient,
+                    points: l.points,
+                })
+                .collect(),
+            raw_score: estimate.raw_score,
+            diploma_score: estimate.diploma_score,
+            placement_score: estimate.placement_score,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::{
+        AnswerCounts, ExamType, PracticeTest, ScoringPolicy, Subject, TestBook, TestResult,
+    };
+
+    use crate::test_support::{
+        FakeExamTypeRepository, FakeLessonRepository, FakePracticeTestRepository,
+        FakeScoreTableRepository, FakeScoringPolicyRepository, FakeSubjectRepository,
+        FakeTestBookRepository, FakeTestResultRepository,
+    };
+
+    type Service = ScoreCalculationServiceImpl<
+        FakeExamTypeRepository,
+        FakeScoreTableRepository,
+        FakeLessonRepository,
+        FakeSubjectRepository,
+        FakeTestBookRepository,
+        FakePracticeTestRepository,
+        FakeTestResultRepository,
+        FakeScoringPolicyRepository,
+    >;
+
+    /// Score calculation service over in-memory repositories with a TYT score
+    /// table, a Turkish subject and a graded Turkish result.
+    struct Fixture {
+        service: Service,
+        tyt: ExamType,
+        turkish: Subject,
+        result: TestResult,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let tyt = ExamType::new("TYT".to_string(), None);
+            let lesson_id = Uuid::new_v4();
+            let turkish = Subject::new("Türkçe".to_string(), lesson_id, tyt.id);
+            let test_book = TestBook::new("TYT Türkçe".to_string(), lesson_id, tyt.id, 2024);
+            let practice_test = PracticeTest::new(
+                "Deneme 1".to_string(),
+                1,
+                4,
+                "ABCD".to_string(),
+                test_book.id,
+                Uuid::new_v4(),
+                None,
+            );
+            let policy = ScoringPolicy::standard(tyt.id);
+            let result = TestResult::new(
+                Uuid::new_v4(),
+                practice_test.id,
+                "ABDD".to_string(),
+                AnswerCounts {
+                    correct: 3,
+                    wrong: 1,
+                    empty: 0,
+                },
+                &policy,
+                1,
+            );
+
+            let exam_type_repo = Arc::new(FakeExamTypeRepository::default());
+            exam_type_repo.exam_types.lock().unwrap().push(tyt.clone());
+            let score_table_repo = Arc::new(FakeScoreTableRepository::default());
+            score_table_repo
+                .tables
+                .lock()
+                .unwrap()
+                .push(ScoreTable::new(
+                    tyt.id,
+                    2024,
+                    100.0,
+                    ScoreTable::STANDARD_DIPLOMA_COEFFICIENT,
+                    vec![LessonCoefficient {
+                        lesson_id,
+                        coefficient: 3.3,
+                    }],
+                ));
+            let subject_repo = Arc::new(FakeSubjectRepository::default());
+            subject_repo.subjects.lock().unwrap().push(turkish.clone());
+            let test_book_repo = Arc::new(FakeTestBookRepository::default());
+            test_book_repo.test_books.lock().unwrap().push(test_book);
+            let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+            practice_test_repo
+                .practice_tests
+                .lock()
+                .unwrap()
+                .push(practice_test);
+            let test_result_repo = Arc::new(FakeTestResultRepository::default());
+            test_result_repo
+                .results
+                .lock()
+                .unwrap()
+                .push(result.clone());
+            let scoring_policy_repo = Arc::new(FakeScoringPolicyRepository::default());
+            scoring_policy_repo.policies.lock().unwrap().push(policy);
+
+            let service = ScoreCalculationServiceImpl::new(
+                exam_type_repo,
+                score_table_repo,
+                Arc::new(FakeLessonRepository),
+                subject_repo,
+                test_book_repo,
+                practice_test_repo,
+                test_result_repo,
+                scoring_policy_repo,
+            );
+
+            Self {
+                service,
+                tyt,
+                turkish,
+                result,
+            }
+        }
+
+        fn request(
+            &self,
+            result_ids: Vec<Uuid>,
+            subject_nets: Vec<SubjectNetDto>,
+        ) -> EstimateScoreRequest {
+            EstimateScoreRequest {
+                exam_type_id: self.tyt.id,
+                year: None,
+                result_ids,
+                subject_nets,
+                diploma_grade: None,
+            }
+        }
+
+        fn subject_net(&self, net: f64, question_count: i32) -> SubjectNetDto {
+            SubjectNetDto {
+                subject_id: self.turkish.id,
+                net,
+                question_count,
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sums_result_and_subject_nets_per_lesson() {
+        let fixture = Fixture::new();
+
+        let estimate = fixture
+            .service
+            .estimate_score(
+                fixture.result.user_id,
+                fixture.request(vec![fixture.result.id], vec![fixture.subject_net(30.0, 40)]),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(estimate.lessons.len(), 1);
+        assert_eq!(estimate.lessons[0].net, 32.75);
+    }
+
+    #[tokio::test]
+    async fn test_rejects_results_of_other_users() {
+        let fixture = Fixture::new();
+
+        let err = fixture
+            .service
+            .estimate_score(
+                Uuid::new_v4(),
+                fixture.request(vec![fixture.result.id], vec![]),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ScoreCalculationError::TestResultNotFound));
+    }
+
+    #[tokio::test]
+    async fn test_subject_nets_are_bounded_by_their_question_count() {
+        let fixture = Fixture::new();
+
+        for (net, in_range) in [(40.0, true), (-10.0, true), (40.5, false), (-10.25, false)] {
+            let outcome = fixture
+                .service
+                .estimate_score(
+                    Uuid::new_v4(),
+                    fixture.request(vec![], vec![fixture.subject_net(net, 40)]),
+                )
+                .await;
+
+            if in_range {
+                assert!(outcome.is_ok(), "net {} should be accepted", net);
+            } else {
+                assert!(
+                    matches!(outcome, Err(ScoreCalculationError::NetOutOfRange)),
+                    "net {} should be rejected",
+                    net
+                );
+            }
+        }
+    }
+}
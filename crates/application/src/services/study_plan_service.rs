@@ -0,0 +1,333 @@
+use async_trait::async_trait;
+use chrono::{Datelike, Duration, Utc};
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{GoalMetric, StudyGoal, StudyPlan};
+use domain::errors::DomainError;
+use domain::repositories::{ExamTypeRepository, LessonRepository, StudyPlanRepository};
+use domain::services::{GoalPeriod, GoalTracker};
+
+use crate::dto::{
+    CreateStudyGoalRequest, GoalProgressResponse, SetStudyPlanRequest, StudyCalendarEntryResponse,
+    StudyCalendarRequest, StudyCalendarResponse, StudyGoalResponse, StudyPlanResponse,
+};
+
+/// Longest range, in days, a study calendar covers.
+const MAX_CALENDAR_DAYS: i64 = 366;
+
+/// Errors for study plan and goal operations.
+#[derive(Debug, thiserror::Error)]
+pub enum StudyPlanError {
+    #[error("Study plan not found")]
+    PlanNotFound,
+
+    #[error("Study goal not found")]
+    GoalNotFound,
+
+    #[error("Exam type not found")]
+    ExamTypeNotFound,
+
+    #[error("Lesson not found")]
+    LessonNotFound,
+
+    #[error("Target date must not be in the past")]
+    TargetDateInPast,
+
+    #[error("Invalid goal metric: {0}")]
+    InvalidMetric(String),
+
+    #[error("Invalid calendar view: {0}")]
+    InvalidView(String),
+
+    #[error("Invalid date range: {0}")]
+    InvalidDateRange(String),
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for StudyPlanError {
+    fn from(err: DomainError) -> Self {
+        StudyPlanError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for study plans, daily goals and goal tracking.
+#[async_trait]
+pub trait StudyPlanService: Send + Sync {
+    /// Returns the user's study plan.
+    async fn get_plan(&self, user_id: Uuid) -> Result<StudyPlanResponse, StudyPlanError>;
+
+    /// Sets the exam type and date the user prepares for.
+    async fn set_plan(
+        &self,
+        user_id: Uuid,
+        request: SetStudyPlanRequest,
+    ) -> Result<StudyPlanResponse, StudyPlanError>;
+
+    /// Lists the user's goals, optionally including archived ones.
+    async fn list_goals(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<StudyGoalResponse>, StudyPlanError>;
+
+    /// Creates a daily goal for the user.
+    async fn create_goal(
+        &self,
+        user_id: Uuid,
+        request: CreateStudyGoalRequest,
+    ) -> Result<StudyGoalResponse, StudyPlanError>;
+
+    /// Archives one of the user's goals; it stays in the calendar for the days it applied.
+    async fn archive_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<(), StudyPlanError>;
+
+    /// Returns the user's goal completion per day or week, together with their streaks.
+    async fn get_calendar(
+        &self,
+        user_id: Uuid,
+        request: StudyCalendarRequest,
+    ) -> Result<StudyCalendarResponse, StudyPlanError>;
+}
+
+/// Implementation of StudyPlanService.
+pub struct StudyPlanServiceImpl<S, E, L>
+where
+    S: StudyPlanRepository,
+    E: ExamTypeRepository,
+    L: LessonRepository,
+{
+    study_plan_repo: Arc<S>,
+    exam_type_repo: Arc<E>,
+    lesson_repo: Arc<L>,
+}
+
+impl<S, E, L> StudyPlanServiceImpl<S, E, L>
+where
+    S: StudyPlanRepository,
+    E: ExamTypeRepository,
+    L: LessonRepository,
+{
+    pub fn new(study_plan_repo: Arc<S>, exam_type_repo: Arc<E>, lesson_repo: Arc<L>) -> Self {
+        Self {
+            study_plan_repo,
+            exam_type_repo,
+            lesson_repo,
+        }
+    }
+
+    /// Builds the plan response, looking up the exam type name.
+    async fn to_plan_response(&self, plan: StudyPlan) -> Result<StudyPlanResponse, StudyPlanError> {
+        let exam_type = self
+            .exam_type_repo
+            .find_by_id(plan.exam_type_id)
+            .await?
+            .ok_or(StudyPlanError::ExamTypeNotFound)?;
+
+        Ok(StudyPlanResponse {
+            id: plan.id,
+            exam_type_id: plan.exam_type_id,
+            exam_type_name: exam_type.name,
+            target_date: plan.target_date,
+            days_until_exam: plan.days_until_exam(Utc::now().date_naive()),
+            created_at: plan.created_at,
+            updated_at: plan.updated_at,
+        })
+    }
+
+    /// Converts a goal entity into its response DTO.
+    fn to_goal_response(goal: StudyGoal) -> StudyGoalResponse {
+        StudyGoalResponse {
+            id: goal.id,
+            metric: goal.metric.as_str().to_string(),
+            daily_target: goal.daily_target,
+            lesson_id: goal.lesson_id,
+            created_at: goal.created_at,
+            archived_at: goal.archived_at,
+        }
+    }
+
+    /// Converts an evaluated period into a calendar entry.
+    fn to_entry_response(period: GoalPeriod) -> StudyCalendarEntryResponse {
+        StudyCalendarEntryResponse {
+            start: period.start,
+            end: period.end,
+            completed: period.is_completed(),
+            completed_days: period.completed_days,
+            active_days: period.active_days,
+            goals: period
+                .goals
+                .into_iter()
+                .map(|goal| GoalProgressResponse {
+                    goal_id: goal.goal_id,
+                    achieved: goal.achieved,
+                    target: goal.target,
+                    met_days: goal.met_days,
+                    active_days: goal.active_days,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[async_trait]
+impl<S, E, L> StudyPlanService for StudyPlanServiceImpl<S, E, L>
+where
+    S: StudyPlanRepository + 'static,
+    E: ExamTypeRepository + 'static,
+    L: LessonRepository + 'static,
+{
+    async fn get_plan(&self, user_id: Uuid) -> Result<StudyPlanResponse, StudyPlanError> {
+        let plan = self
+            .study_plan_repo
+            .find_plan_by_user(user_id)
+            .await?
+            .ok_or(StudyPlanError::PlanNotFound)?;
+
+        self.to_plan_response(plan).await
+    }
+
+    async fn set_plan(
+        &self,
+        user_id: Uuid,
+        request: SetStudyPlanRequest,
+    ) -> Result<StudyPlanResponse, StudyPlanError> {
+        if request.target_date < Utc::now().date_naive() {
+            return Err(StudyPlanError::TargetDateInPast);
+        }
+        if self
+            .exam_type_repo
+            .find_by_id(request.exam_type_id)
+            .await?
+            .is_none()
+        {
+            return Err(StudyPlanError::ExamTypeNotFound);
+        }
+
+        let plan = match self.study_plan_repo.find_plan_by_user(user_id).await? {
+            Some(mut plan) => {
+                plan.exam_type_id = request.exam_type_id;
+                plan.target_date = request.target_date;
+                plan.updated_at = Utc::now();
+                plan
+            }
+            None => StudyPlan::new(user_id, request.exam_type_id, request.target_date),
+        };
+        let saved = self.study_plan_repo.save_plan(&plan).await?;
+
+        self.to_plan_response(saved).await
+    }
+
+    async fn list_goals(
+        &self,
+        user_id: Uuid,
+        include_archived: bool,
+    ) -> Result<Vec<StudyGoalResponse>, StudyPlanError> {
+        let goals = self.study_plan_repo.find_goals_by_user(user_id).await?;
+
+        Ok(goals
+            .into_iter()
+            .filter(|goal| include_archived || goal.archived_at.is_none())
+            .map(Self::to_goal_response)
+            .collect())
+    }
+
+    async fn create_goal(
+        &self,
+        user_id: Uuid,
+        request: CreateStudyGoalRequest,
+    ) -> Result<StudyGoalResponse, StudyPlanError> {
+        let metric = GoalMetric::parse(&request.metric)
+            .ok_or_else(|| StudyPlanError::InvalidMetric(request.metric.clone()))?;
+        if let Some(lesson_id) = request.lesson_id {
+            if self.lesson_repo.find_by_id(lesson_id).await?.is_none() {
+                return Err(StudyPlanError::LessonNotFound);
+            }
+        }
+
+        let goal = StudyGoal::new(user_id, metric, request.daily_target, request.lesson_id);
+        let created = self.study_plan_repo.create_goal(&goal).await?;
+
+        Ok(Self::to_goal_response(created))
+    }
+
+    async fn archive_goal(&self, user_id: Uuid, goal_id: Uuid) -> Result<(), StudyPlanError> {
+        let goal = self
+            .study_plan_repo
+            .find_goal_by_id(goal_id)
+            .await?
+            .filter(|goal| goal.user_id == user_id && goal.archived_at.is_none())
+            .ok_or(StudyPlanError::GoalNotFound)?;
+
+        self.study_plan_repo
+            .archive_goal(goal.id, Utc::now())
+            .await?;
+        Ok(())
+    }
+
+    async fn get_calendar(
+        &self,
+        user_id: Uuid,
+        request: StudyCalendarRequest,
+    ) -> Result<StudyCalendarResponse, StudyPlanError> {
+        // Period length and how many periods to show when no range is given
+        let (period_days, default_periods) = match request.view.as_str() {
+            "day" => (1, 28),
+            "week" => (7, 8),
+            other => return Err(StudyPlanError::InvalidView(other.to_string())),
+        };
+
+        // Days after today have nothing to evaluate yet
+        let today = Utc::now().date_naive();
+        let to = request.to.unwrap_or(today).min(today);
+        let from = request
+            .from
+            .unwrap_or_else(|| to - Duration::days(default_periods * period_days - 1));
+        // Weeks start on Monday
+        let from = if period_days == 7 {
+            from - Duration::days(from.weekday().num_days_from_monday() as i64)
+        } else {
+            from
+        };
+        if from > to {
+            return Err(StudyPlanError::InvalidDateRange(
+                "from must not be after to or today".to_string(),
+            ));
+        }
+        if (to - from).num_days() >= MAX_CALENDAR_DAYS {
+            return Err(StudyPlanError::InvalidDateRange(format!(
+                "a calendar covers at most {} days",
+                MAX_CALENDAR_DAYS
+            )));
+        }
+
+        let goals = self.study_plan_repo.find_goals_by_user(user_id).await?;
+        // Streaks reach back to the first goal, so load activity from there
+        let activity_from = goals
+            .iter()
+            .map(|goal| goal.created_at.date_naive())
+            .min()
+            .map_or(from, |first_day| first_day.min(from));
+        let activity = self
+            .study_plan_repo
+            .find_daily_activity(user_id, activity_from, today)
+            .await?;
+
+        let tracker = GoalTracker::new(goals, activity);
+        let streak = tracker.streak(today);
+
+        Ok(StudyCalendarResponse {
+            view: request.view,
+            from,
+            to,
+            current_streak: streak.current,
+            longest_streak: streak.longest,
+            entries: tracker
+                .periods(from, to, period_days)
+                .into_iter()
+                .map(Self::to_entry_response)
+                .collect(),
+        })
+    }
+}
@@ -1,19 +1,25 @@
 use async_trait::async_trait;
+use std::collections::HashSet;
 use std::sync::Arc;
 use uuid::Uuid;
 
-use domain::entities::{ExamType, Lesson, PracticeTest, Subject, TestBook};
+use domain::entities::{
+    AnswerKey, AnswerKeyEntry, ExamType, Lesson, PracticeTest, Question, QuestionDifficulty,
+    RoundingMode, ScoringPolicy, Subject, TestBook,
+};
 use domain::errors::DomainError;
 use domain::repositories::{
-    ExamTypeRepository, LessonRepository, PracticeTestRepository, SubjectRepository,
-    TestBookRepository, TestBookSubjectRepository,
+    ExamTypeRepository, LessonRepository, PracticeTestRepository, ScoringPolicyRepository,
+    SubjectRepository, TestBookRepository, TestBookSubjectRepository, TopicRepository,
 };
 
 use crate::dto::{
-    CreateExamTypeRequest, CreateLessonRequest, CreatePracticeTestRequest, CreateSubjectRequest,
-    CreateTestBookRequest, ExamTypeResponse, LessonResponse, PracticeTestResponse, SubjectResponse,
-    TestBookResponse, UpdateExamTypeRequest, UpdateLessonRequest, UpdatePracticeTestRequest,
-    UpdateSubjectRequest, UpdateTestBookRequest,
+    AnswerKeyEntryDto, AnswerKeyInput, AnswerKeyRevisionResponse, CreateExamTypeRequest,
+    CreateLessonRequest, CreatePracticeTestRequest, CreateSubjectRequest, CreateTestBookRequest,
+    ExamTypeResponse, LessonResponse, PracticeTestResponse, QuestionResponse,
+    ReplaceQuestionsRequest, ScoringPolicyResponse, SubjectResponse, TestBookResponse,
+    UpdateExamTypeRequest, UpdateLessonRequest, UpdatePracticeTestRequest,
+    UpdateScoringPolicyRequest, UpdateSubjectRequest, UpdateTestBookRequest,
 };
 
 /// Errors for test management operations.
@@ -34,6 +40,9 @@ pub enum TestManagementError {
     #[error("Practice test not found")]
     PracticeTestNotFound,
 
+    #[error("Topic not found")]
+    TopicNotFound,
+
     #[error("Duplicate lesson name")]
     DuplicateLessonName,
 
@@ -46,17 +55,35 @@ pub enum TestManagementError {
     #[error("A test with this name and number already exists for this test book and subject")]
     DuplicateTestNumber,
 
+    #[error("Scoring policy not found")]
+    ScoringPolicyNotFound,
+
+    #[error("Invalid rounding mode: {0}")]
+    InvalidRoundingMode(String),
+
+    #[error("Invalid answer key: {0}")]
+    InvalidAnswerKey(String),
+
+    #[error("Invalid question: {0}")]
+    InvalidQuestion(String),
+
+    #[error("Invalid topic: {0}")]
+    InvalidTopic(String),
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
 
 impl From<DomainError> for TestManagementError {
     fn from(err: DomainError) -> Self {
-        // Check for duplicate practice test constraint violation
+        // Check for constraint violations of practice tests and their questions
         if let DomainError::DatabaseError(ref msg) = err {
             if msg.contains("practice_tests_book_subject_name_number_unique") {
                 return TestManagementError::DuplicateTestNumber;
             }
+            if msg.contains("topic_id_fkey") {
+                return TestManagementError::TopicNotFound;
+            }
         }
         TestManagementError::InternalError(err.to_string())
     }
@@ -93,6 +120,21 @@ pub trait TestManagementService: Send + Sync {
     ) -> Result<ExamTypeResponse, TestManagementError>;
     async fn delete_exam_type(&self, id: Uuid) -> Result<(), TestManagementError>;
 
+    // ScoringPolicy operations
+    async fn get_scoring_policy(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<ScoringPolicyResponse, TestManagementError>;
+    async fn list_scoring_policies(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<ScoringPolicyResponse>, TestManagementError>;
+    async fn update_scoring_policy(
+        &self,
+        exam_type_id: Uuid,
+        request: UpdateScoringPolicyRequest,
+    ) -> Result<ScoringPolicyResponse, TestManagementError>;
+
     // Subject operations
     async fn create_subject(
         &self,
@@ -152,7 +194,10 @@ pub trait TestManagementService: Send + Sync {
         &self,
         request: CreatePracticeTestRequest,
     ) -> Result<PracticeTestResponse, TestManagementError>;
-    async fn get_practice_test(&self, id: Uuid) -> Result<PracticeTestResponse, TestManagementError>;
+    async fn get_practice_test(
+        &self,
+        id: Uuid,
+    ) -> Result<PracticeTestResponse, TestManagementError>;
     async fn list_practice_tests_by_test_book(
         &self,
         test_book_id: Uuid,
@@ -161,17 +206,35 @@ pub trait TestManagementService: Send + Sync {
         &self,
         test_book_id: Uuid,
     ) -> Result<std::collections::HashMap<Uuid, Vec<PracticeTestResponse>>, TestManagementError>;
-    async fn list_all_practice_tests(&self) -> Result<Vec<PracticeTestResponse>, TestManagementError>;
+    async fn list_all_practice_tests(
+        &self,
+    ) -> Result<Vec<PracticeTestResponse>, TestManagementError>;
     async fn update_practice_test(
         &self,
         id: Uuid,
         request: UpdatePracticeTestRequest,
     ) -> Result<PracticeTestResponse, TestManagementError>;
     async fn delete_practice_test(&self, id: Uuid) -> Result<(), TestManagementError>;
+    async fn list_answer_key_revisions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<AnswerKeyRevisionResponse>, TestManagementError>;
+
+    // Question operations
+    async fn list_questions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<QuestionResponse>, TestManagementError>;
+    /// Replaces the questions of a practice test, deriving its answer key from them.
+    async fn replace_questions(
+        &self,
+        practice_test_id: Uuid,
+        request: ReplaceQuestionsRequest,
+    ) -> Result<Vec<QuestionResponse>, TestManagementError>;
 }
 
 /// Implementation of TestManagementService.
-pub struct TestManagementServiceImpl<L, E, S, T, TB, P>
+pub struct TestManagementServiceImpl<L, E, S, T, TB, P, SP, TP>
 where
     L: LessonRepository,
     E: ExamTypeRepository,
@@ -179,6 +242,8 @@ where
     T: TestBookRepository,
     TB: TestBookSubjectRepository,
     P: PracticeTestRepository,
+    SP: ScoringPolicyRepository,
+    TP: TopicRepository,
 {
     lesson_repo: Arc<L>,
     exam_type_repo: Arc<E>,
@@ -186,9 +251,11 @@ where
     test_book_repo: Arc<T>,
     test_book_subject_repo: Arc<TB>,
     practice_test_repo: Arc<P>,
+    scoring_policy_repo: Arc<SP>,
+    topic_repo: Arc<TP>,
 }
 
-impl<L, E, S, T, TB, P> TestManagementServiceImpl<L, E, S, T, TB, P>
+impl<L, E, S, T, TB, P, SP, TP> TestManagementServiceImpl<L, E, S, T, TB, P, SP, TP>
 where
     L: LessonRepository,
     E: ExamTypeRepository,
@@ -196,7 +263,10 @@ where
     T: TestBookRepository,
     TB: TestBookSubjectRepository,
     P: PracticeTestRepository,
+    SP: ScoringPolicyRepository,
+    TP: TopicRepository,
 {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         lesson_repo: Arc<L>,
         exam_type_repo: Arc<E>,
@@ -204,6 +274,8 @@ where
         test_book_repo: Arc<T>,
         test_book_subject_repo: Arc<TB>,
         practice_test_repo: Arc<P>,
+        scoring_policy_repo: Arc<SP>,
+        topic_repo: Arc<TP>,
     ) -> Self {
         Self {
             lesson_repo,
@@ -212,12 +284,133 @@ where
             test_book_repo,
             test_book_subject_repo,
             practice_test_repo,
+            scoring_policy_repo,
+            topic_repo,
+        }
+    }
+
+    /// Converts a scoring policy entity into its response DTO.
+    fn to_scoring_policy_response(policy: ScoringPolicy) -> ScoringPolicyResponse {
+        ScoringPolicyResponse {
+            id: policy.id,
+            exam_type_id: policy.exam_type_id,
+            version: policy.version,
+            penalty_ratio: policy.penalty_ratio,
+            question_weight: policy.question_weight,
+            rounding_mode: policy.rounding_mode.as_str().to_string(),
+            decimal_places: policy.decimal_places,
+            created_at: policy.created_at,
+        }
+    }
+
+    /// Builds an answer key from its request form, checking it covers every question.
+    fn to_answer_key(
+        input: AnswerKeyInput,
+        question_count: i32,
+    ) -> Result<AnswerKey, TestManagementError> {
+        let answer_key = match input {
+            AnswerKeyInput::Plain(raw) => AnswerKey::parse(&raw),
+            AnswerKeyInput::Entries(entries) => {
+                AnswerKey::new(entries.into_iter().map(Self::to_answer_key_entry).collect())
+            }
+        }
+        .map_err(Self::invalid_answer_key)?;
+
+        Self::check_answer_key_length(&answer_key, question_count)?;
+        Ok(answer_key)
+    }
+
+    /// Converts an answer key entry from its request form.
+    fn to_answer_key_entry(entry: AnswerKeyEntryDto) -> AnswerKeyEntry {
+        match entry {
+            AnswerKeyEntryDto::Accepted { options } => AnswerKeyEntry::Accepted { options },
+            AnswerKeyEntryDto::Cancelled => AnswerKeyEntry::Cancelled,
+            AnswerKeyEntryDto::AnyAnswer => AnswerKeyEntry::AnyAnswer,
+        }
+    }
+
+    /// Converts a question entity into its response DTO.
+    fn to_question_response(question: Question) -> QuestionResponse {
+        QuestionResponse {
+            id: question.id,
+            practice_test_id: question.practice_test_id,
+            position: question.position,
+            answer: match question.answer {
+                AnswerKeyEntry::Accepted { options } => AnswerKeyEntryDto::Accepted { options },
+                AnswerKeyEntry::Cancelled => AnswerKeyEntryDto::Cancelled,
+                AnswerKeyEntry::AnyAnswer => AnswerKeyEntryDto::AnyAnswer,
+            },
+            topic_id: question.topic_id,
+            difficulty: question.difficulty.map(|d| d.as_str().to_string()),
+            created_at: question.created_at,
+            updated_at: question.updated_at,
+        }
+    }
+
+    /// Maps an answer key validation failure to its service error.
+    fn invalid_answer_key(err: DomainError) -> TestManagementError {
+        match err {
+            DomainError::ValidationError(msg) => TestManagementError::InvalidAnswerKey(msg),
+            err @ DomainError::InvalidStoredAnswerKey(..) => {
+                TestManagementError::InvalidAnswerKey(err.to_string())
+            }
+            other => other.into(),
+        }
+    }
+
+    /// Checks that the given topics exist and belong to the practice test's subject,
+    /// loading the subject's topics in one query.
+    async fn ensure_topics_in_subject(
+        &self,
+        subject_id: Uuid,
+        topic_ids: impl IntoIterator<Item = Uuid>,
+    ) -> Result<(), TestManagementError> {
+        let topic_ids: HashSet<Uuid> = topic_ids.into_iter().collect();
+        if topic_ids.is_empty() {
+            return Ok(());
+        }
+
+        let subject_topics: HashSet<Uuid> = self
+            .topic_repo
+            .find_by_subject_id(subject_id)
+            .await?
+            .into_iter()
+            .map(|topic| topic.id)
+            .collect();
+        let Some(&outside) = topic_ids.iter().find(|id| !subject_topics.contains(id)) else {
+            return Ok(());
+        };
+
+        // Tell a topic of another subject apart from one that does not exist
+        self.topic_repo
+            .find_by_id(outside)
+            .await?
+            .ok_or(TestManagementError::TopicNotFound)?;
+        Err(TestManagementError::InvalidTopic(format!(
+            "Topic {} does not belong to the practice test's subject",
+            outside
+        )))
+    }
+
+    /// Checks that an answer key has exactly one entry per question.
+    fn check_answer_key_length(
+        answer_key: &AnswerKey,
+        question_count: i32,
+    ) -> Result<(), TestManagementError> {
+        if answer_key.len() != question_count as usize {
+            return Err(TestManagementError::InvalidAnswerKey(format!(
+                "Answer key has {} questions but the test has {}",
+                answer_key.len(),
+                question_count
+            )));
         }
+        Ok(())
     }
 }
 
 #[async_trait]
-impl<L, E, S, T, TB, P> TestManagementService for TestManagementServiceImpl<L, E, S, T, TB, P>
+impl<L, E, S, T, TB, P, SP, TP> TestManagementService
+    for TestManagementServiceImpl<L, E, S, T, TB, P, SP, TP>
 where
     L: LessonRepository + 'static,
     E: ExamTypeRepository + 'static,
@@ -225,6 +418,8 @@ where
     T: TestBookRepository + 'static,
     TB: TestBookSubjectRepository + 'static,
     P: PracticeTestRepository + 'static,
+    SP: ScoringPolicyRepository + 'static,
+    TP: TopicRepository + 'static,
 {
     // Lesson operations
     async fn create_lesson(
@@ -232,7 +427,12 @@ where
         request: CreateLessonRequest,
     ) -> Result<LessonResponse, TestManagementError> {
         // Check for duplicate name
-        if self.lesson_repo.find_by_name(&request.name).await?.is_some() {
+        if self
+            .lesson_repo
+            .find_by_name(&request.name)
+            .await?
+            .is_some()
+        {
             return Err(TestManagementError::DuplicateLessonName);
         }
 
@@ -311,17 +511,31 @@ where
         request: CreateExamTypeRequest,
     ) -> Result<ExamTypeResponse, TestManagementError> {
         // Check for duplicate name
-        if self.exam_type_repo.find_by_name(&request.name).await?.is_some() {
+        if self
+            .exam_type_repo
+            .find_by_name(&request.name)
+            .await?
+            .is_some()
+        {
             return Err(TestManagementError::DuplicateExamTypeName);
         }
 
-        let exam_type = ExamType::new(request.name, request.description);
+        let mut exam_type = ExamType::new(request.name, request.description);
+        if let Some(reveal_answer_keys) = request.reveal_answer_keys {
+            exam_type.reveal_answer_keys = reveal_answer_keys;
+        }
         let created = self.exam_type_repo.create(&exam_type).await?;
 
+        // Every exam type starts with the standard net rule
+        self.scoring_policy_repo
+            .create(&ScoringPolicy::standard(created.id))
+            .await?;
+
         Ok(ExamTypeResponse {
             id: created.id,
             name: created.name,
             description: created.description,
+            reveal_answer_keys: created.reveal_answer_keys,
             created_at: created.created_at,
         })
     }
@@ -337,6 +551,7 @@ where
             id: exam_type.id,
             name: exam_type.name,
             description: exam_type.description,
+            reveal_answer_keys: exam_type.reveal_answer_keys,
             created_at: exam_type.created_at,
         })
     }
@@ -350,6 +565,7 @@ where
                 id: et.id,
                 name: et.name,
                 description: et.description,
+                reveal_answer_keys: et.reveal_answer_keys,
                 created_at: et.created_at,
             })
             .collect())
@@ -372,6 +588,9 @@ where
         if let Some(description) = request.description {
             exam_type.description = Some(description);
         }
+        if let Some(reveal_answer_keys) = request.reveal_answer_keys {
+            exam_type.reveal_answer_keys = reveal_answer_keys;
+        }
 
         let updated = self.exam_type_repo.update(&exam_type).await?;
 
@@ -379,6 +598,7 @@ where
             id: updated.id,
             name: updated.name,
             description: updated.description,
+            reveal_answer_keys: updated.reveal_answer_keys,
             created_at: updated.created_at,
         })
     }
@@ -391,6 +611,74 @@ where
         Ok(())
     }
 
+    // ScoringPolicy operations
+    async fn get_scoring_policy(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<ScoringPolicyResponse, TestManagementError> {
+        let policy = self
+            .scoring_policy_repo
+            .find_current_by_exam_type(exam_type_id)
+            .await?
+            .ok_or(TestManagementError::ScoringPolicyNotFound)?;
+
+        Ok(Self::to_scoring_policy_response(policy))
+    }
+
+    async fn list_scoring_policies(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<ScoringPolicyResponse>, TestManagementError> {
+        self.exam_type_repo
+            .find_by_id(exam_type_id)
+            .await?
+            .ok_or(TestManagementError::ExamTypeNotFound)?;
+
+        let policies = self
+            .scoring_policy_repo
+            .list_by_exam_type(exam_type_id)
+            .await?;
+
+        Ok(policies
+            .into_iter()
+            .map(Self::to_scoring_policy_response)
+            .collect())
+    }
+
+    async fn update_scoring_policy(
+        &self,
+        exam_type_id: Uuid,
+        request: UpdateScoringPolicyRequest,
+    ) -> Result<ScoringPolicyResponse, TestManagementError> {
+        self.exam_type_repo
+            .find_by_id(exam_type_id)
+            .await?
+            .ok_or(TestManagementError::ExamTypeNotFound)?;
+
+        let rounding_mode = RoundingMode::parse(&request.rounding_mode).ok_or(
+            TestManagementError::InvalidRoundingMode(request.rounding_mode),
+        )?;
+
+        // Policies are never edited in place; a change creates the next version
+        let next_version = self
+            .scoring_policy_repo
+            .find_current_by_exam_type(exam_type_id)
+            .await?
+            .map_or(1, |current| current.version + 1);
+
+        let policy = ScoringPolicy::new(
+            exam_type_id,
+            next_version,
+            request.penalty_ratio,
+            request.question_weight,
+            rounding_mode,
+            request.decimal_places,
+        );
+        let created = self.scoring_policy_repo.create(&policy).await?;
+
+        Ok(Self::to_scoring_policy_response(created))
+    }
+
     async fn create_subject(
         &self,
         request: CreateSubjectRequest,
@@ -864,14 +1152,21 @@ where
             return Err(TestManagementError::SubjectNotFound);
         }
 
-        let practice_test = PracticeTest::new(
+        self.ensure_topics_in_subject(request.subject_id, request.topic_id)
+            .await?;
+
+        let answer_key = Self::to_answer_key(request.answer_key, request.question_count)?;
+
+        let mut practice_test = PracticeTest::new(
             request.name,
             request.test_number,
             request.question_count,
-            request.answer_key,
+            answer_key.encode(),
             request.test_book_id,
             request.subject_id,
+            request.time_limit_minutes,
         );
+        practice_test.topic_id = request.topic_id;
         let created = self.practice_test_repo.create(&practice_test).await?;
 
         Ok(PracticeTestResponse {
@@ -880,13 +1175,19 @@ where
             test_number: created.test_number,
             question_count: created.question_count,
             answer_key: created.answer_key,
+            answer_key_revision: created.answer_key_revision,
             test_book_id: created.test_book_id,
             subject_id: created.subject_id,
+            time_limit_minutes: created.time_limit_minutes,
+            topic_id: created.topic_id,
             created_at: created.created_at,
         })
     }
 
-    async fn get_practice_test(&self, id: Uuid) -> Result<PracticeTestResponse, TestManagementError> {
+    async fn get_practice_test(
+        &self,
+        id: Uuid,
+    ) -> Result<PracticeTestResponse, TestManagementError> {
         let practice_test = self
             .practice_test_repo
             .find_by_id(id)
@@ -899,8 +1200,11 @@ where
             test_number: practice_test.test_number,
             question_count: practice_test.question_count,
             answer_key: practice_test.answer_key,
+            answer_key_revision: practice_test.answer_key_revision,
             test_book_id: practice_test.test_book_id,
             subject_id: practice_test.subject_id,
+            time_limit_minutes: practice_test.time_limit_minutes,
+            topic_id: practice_test.topic_id,
             created_at: practice_test.created_at,
         })
     }
@@ -922,8 +1226,11 @@ where
                 test_number: pt.test_number,
                 question_count: pt.question_count,
                 answer_key: pt.answer_key,
+                answer_key_revision: pt.answer_key_revision,
                 test_book_id: pt.test_book_id,
                 subject_id: pt.subject_id,
+                time_limit_minutes: pt.time_limit_minutes,
+                topic_id: pt.topic_id,
                 created_at: pt.created_at,
             })
             .collect())
@@ -932,7 +1239,8 @@ where
     async fn list_practice_tests_grouped_by_subject(
         &self,
         test_book_id: Uuid,
-    ) -> Result<std::collections::HashMap<Uuid, Vec<PracticeTestResponse>>, TestManagementError> {
+    ) -> Result<std::collections::HashMap<Uuid, Vec<PracticeTestResponse>>, TestManagementError>
+    {
         // Verify test book exists
         self.test_book_repo
             .find_by_id(test_book_id)
@@ -955,21 +1263,23 @@ where
                 test_number: pt.test_number,
                 question_count: pt.question_count,
                 answer_key: pt.answer_key,
+                answer_key_revision: pt.answer_key_revision,
                 test_book_id: pt.test_book_id,
                 subject_id: pt.subject_id,
+                time_limit_minutes: pt.time_limit_minutes,
+                topic_id: pt.topic_id,
                 created_at: pt.created_at,
             };
 
-            grouped
-                .entry(pt.subject_id)
-                .or_insert_with(Vec::new)
-                .push(response);
+            grouped.entry(pt.subject_id).or_default().push(response);
         }
 
         Ok(grouped)
     }
 
-    async fn list_all_practice_tests(&self) -> Result<Vec<PracticeTestResponse>, TestManagementError> {
+    async fn list_all_practice_tests(
+        &self,
+    ) -> Result<Vec<PracticeTestResponse>, TestManagementError> {
         let practice_tests = self.practice_test_repo.list_all().await?;
 
         Ok(practice_tests
@@ -980,8 +1290,11 @@ where
                 test_number: pt.test_number,
                 question_count: pt.question_count,
                 answer_key: pt.answer_key,
+                answer_key_revision: pt.answer_key_revision,
                 test_book_id: pt.test_book_id,
                 subject_id: pt.subject_id,
+                time_limit_minutes: pt.time_limit_minutes,
+                topic_id: pt.topic_id,
                 created_at: pt.created_at,
             })
             .collect())
@@ -998,8 +1311,16 @@ where
             .await?
             .ok_or(TestManagementError::PracticeTestNotFound)?;
 
+        if request.clear_topic && request.topic_id.is_some() {
+            return Err(TestManagementError::InvalidTopic(
+                "A topic cannot be set and cleared at once".to_string(),
+            ));
+        }
+
         // Determine which test_book_id to use for subject validation
-        let test_book_id_for_validation = request.test_book_id.unwrap_or(practice_test.test_book_id);
+        let test_book_id_for_validation =
+            request.test_book_id.unwrap_or(practice_test.test_book_id);
+        let previous_subject_id = practice_test.subject_id;
 
         if let Some(name) = request.name {
             practice_test.name = name;
@@ -1010,8 +1331,16 @@ where
         if let Some(question_count) = request.question_count {
             practice_test.question_count = question_count;
         }
+        let mut answer_key_revised = false;
         if let Some(answer_key) = request.answer_key {
-            practice_test.answer_key = answer_key;
+            let answer_key = Self::to_answer_key(answer_key, practice_test.question_count)?;
+            // Keeps the old key as history; results are re-graded separately
+            answer_key_revised = practice_test.revise_answer_key(answer_key.encode());
+        } else if request.question_count.is_some() {
+            let answer_key = practice_test
+                .parsed_answer_key()
+                .map_err(Self::invalid_answer_key)?;
+            Self::check_answer_key_length(&answer_key, practice_test.question_count)?;
         }
         if let Some(test_book_id) = request.test_book_id {
             self.test_book_repo
@@ -1038,8 +1367,46 @@ where
 
             practice_test.subject_id = subject_id;
         }
+        if let Some(time_limit_minutes) = request.time_limit_minutes {
+            practice_test.time_limit_minutes =
+                (time_limit_minutes > 0).then_some(time_limit_minutes);
+        }
+        if request.topic_id.is_some() {
+            practice_test.topic_id = request.topic_id;
+        } else if request.clear_topic {
+            practice_test.topic_id = None;
+        }
 
-        let updated = self.practice_test_repo.update(&practice_test).await?;
+        // Questions follow a changed answer key so it stays derivable from them
+        let subject_changed = practice_test.subject_id != previous_subject_id;
+        let questions = if answer_key_revised || subject_changed {
+            self.practice_test_repo.find_questions(id).await?
+        } else {
+            Vec::new()
+        };
+
+        // The topics of the test and its questions must stay within its subject
+        if subject_changed || request.topic_id.is_some() {
+            let question_topic_ids = questions.iter().filter_map(|q| q.topic_id);
+            self.ensure_topics_in_subject(
+                practice_test.subject_id,
+                practice_test.topic_id.into_iter().chain(question_topic_ids),
+            )
+            .await?;
+        }
+
+        let updated = if !answer_key_revised || questions.is_empty() {
+            self.practice_test_repo.update(&practice_test).await?
+        } else {
+            let answer_key = practice_test
+                .parsed_answer_key()
+                .map_err(Self::invalid_answer_key)?;
+            let questions = Question::sync_with_answer_key(id, questions, &answer_key);
+            self.practice_test_repo
+                .replace_questions(&practice_test, &questions)
+                .await?
+                .0
+        };
 
         Ok(PracticeTestResponse {
             id: updated.id,
@@ -1047,8 +1414,11 @@ where
             test_number: updated.test_number,
             question_count: updated.question_count,
             answer_key: updated.answer_key,
+            answer_key_revision: updated.answer_key_revision,
             test_book_id: updated.test_book_id,
             subject_id: updated.subject_id,
+            time_limit_minutes: updated.time_limit_minutes,
+            topic_id: updated.topic_id,
             created_at: updated.created_at,
         })
     }
@@ -1060,5 +1430,299 @@ where
             .map_err(|_| TestManagementError::PracticeTestNotFound)?;
         Ok(())
     }
+
+    async fn list_answer_key_revisions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<AnswerKeyRevisionResponse>, TestManagementError> {
+        self.practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(TestManagementError::PracticeTestNotFound)?;
+
+        let revisions = self
+            .practice_test_repo
+            .find_answer_key_revisions(practice_test_id)
+            .await?;
+
+        Ok(revisions
+            .into_iter()
+            .map(|r| AnswerKeyRevisionResponse {
+                id: r.id,
+                practice_test_id: r.practice_test_id,
+                revision: r.revision,
+                answer_key: r.answer_key,
+                created_at: r.created_at,
+                regraded_at: r.regraded_at,
+            })
+            .collect())
+    }
+
+    async fn list_questions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<QuestionResponse>, TestManagementError> {
+        self.practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(TestManagementError::PracticeTestNotFound)?;
+
+        let questions = self
+            .practice_test_repo
+            .find_questions(practice_test_id)
+            .await?;
+
+        Ok(questions
+            .into_iter()
+            .map(Self::to_question_response)
+            .collect())
+    }
+
+    async fn replace_questions(
+        &self,
+        practice_test_id: Uuid,
+        request: ReplaceQuestionsRequest,
+    ) -> Result<Vec<QuestionResponse>, TestManagementError> {
+        let mut practice_test = self
+            .practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(TestManagementError::PracticeTestNotFound)?;
+        if request.questions.is_empty() {
+            return Err(TestManagementError::InvalidQuestion(
+                "At least one question is required".to_string(),
+            ));
+        }
+        let current_key = practice_test
+            .parsed_answer_key()
+            .map_err(Self::invalid_answer_key)?;
+
+        let mut questions = Vec::with_capacity(request.questions.len());
+        for (index, input) in request.questions.into_iter().enumerate() {
+            let position = index + 1;
+            // Lets tests with a plain answer key be classified without retyping it
+            let answer = match input.answer {
+                Some(answer) => Self::to_answer_key_entry(answer),
+                None => current_key.entries().get(index).cloned().ok_or_else(|| {
+                    TestManagementError::InvalidQuestion(format!(
+                        "Question {} needs an answer, the current answer key has {} questions",
+                        position,
+                        current_key.len()
+                    ))
+                })?,
+            };
+            let difficulty = input
+                .difficulty
+                .map(|value| {
+                    QuestionDifficulty::parse(&value).ok_or_else(|| {
+                        TestManagementError::InvalidQuestion(format!(
+                            "Unknown difficulty '{}' for question {}",
+                            value, position
+                        ))
+                    })
+                })
+                .transpose()?;
+
+            let question = Question::new(
+                practice_test_id,
+                position as i32,
+                answer,
+                input.topic_id,
+                difficulty,
+            )
+            .map_err(Self::invalid_answer_key)?;
+            questions.push(question);
+        }
+
+        self.ensure_topics_in_subject(
+            practice_test.subject_id,
+            questions.iter().filter_map(|q| q.topic_id),
+        )
+        .await?;
+
+        practice_test
+            .apply_questions(&questions)
+            .map_err(Self::invalid_answer_key)?;
+        let (_, saved) = self
+            .practice_test_repo
+            .replace_questions(&practice_test, &questions)
+            .await?;
+
+        Ok(saved.into_iter().map(Self::to_question_response).collect())
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::Topic;
+
+    use crate::dto::QuestionInput;
+    use crate::test_support::{
+        FakeExamTypeRepository, FakeLessonRepository, FakePracticeTestRepository,
+        FakeScoringPolicyRepository, FakeSubjectRepository, FakeTestBookRepository,
+        FakeTestBookSubjectRepository, FakeTopicRepository,
+    };
+
+    type Service = TestManagementServiceImpl<
+        FakeLessonRepository,
+        FakeExamTypeRepository,
+        FakeSubjectRepository,
+        FakeTestBookRepository,
+        FakeTestBookSubjectRepository,
+        FakePracticeTestRepository,
+        FakeScoringPolicyRepository,
+        FakeTopicRepository,
+    >;
+
+    /// Test management service over in-memory repositories with one practice test
+    /// and a topic in its subject and in another subject.
+    struct Fixture {
+        service: Service,
+        practice_test_repo: Arc<FakePracticeTestRepository>,
+        practice_test: PracticeTest,
+        own_topic: Topic,
+        other_topic: Topic,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let practice_test = PracticeTest::new(
+                "Deneme 1".to_string(),
+                1,
+                2,
+                "AB".to_string(),
+                Uuid::new_v4(),
+                Uuid::new_v4(),
+                None,
+            );
+            let own_topic = Topic::new(practice_test.subject_id, None, "Sayılar".to_string(), 1);
+            let other_topic = Topic::new(Uuid::new_v4(), None, "Hücre".to_string(), 1);
+
+            let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+            practice_test_repo
+                .practice_tests
+                .lock()
+                .unwrap()
+                .push(practice_test.clone());
+            let topic_repo = Arc::new(FakeTopicRepository::default());
+            topic_repo
+                .topics
+                .lock()
+                .unwrap()
+                .extend([own_topic.clone(), other_topic.clone()]);
+
+            let service = TestManagementServiceImpl::new(
+                Arc::new(FakeLessonRepository),
+                Arc::new(FakeExamTypeRepository::default()),
+                Arc::new(FakeSubjectRepository::default()),
+                Arc::new(FakeTestBookRepository::default()),
+                Arc::new(FakeTestBookSubjectRepository),
+                practice_test_repo.clone(),
+                Arc::new(FakeScoringPolicyRepository::default()),
+                topic_repo,
+            );
+
+            Self {
+                service,
+                practice_test_repo,
+                practice_test,
+                own_topic,
+                other_topic,
+            }
+        }
+
+        async fn replace_questions(
+            &self,
+            topic_ids: [Option<Uuid>; 2],
+        ) -> Result<Vec<QuestionResponse>, TestManagementError> {
+            let questions = topic_ids
+                .into_iter()
+                .map(|topic_id| QuestionInput {
+                    answer: None,
+                    topic_id,
+                    difficulty: None,
+                })
+                .collect();
+            self.service
+                .replace_questions(self.practice_test.id, ReplaceQuestionsRequest { questions })
+                .await
+        }
+
+        async fn update_topic(
+            &self,
+            topic_id: Option<Uuid>,
+            clear_topic: bool,
+        ) -> Result<PracticeTestResponse, TestManagementError> {
+            self.service
+                .update_practice_test(
+                    self.practice_test.id,
+                    UpdatePracticeTestRequest {
+                        name: None,
+                        test_number: None,
+                        question_count: None,
+                        answer_key: None,
+                        test_book_id: None,
+                        subject_id: None,
+                        time_limit_minutes: None,
+                        topic_id,
+                        clear_topic,
+                    },
+                )
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replace_questions_keeps_topics_within_the_subject() {
+        let fixture = Fixture::new();
+
+        let result = fixture
+            .replace_questions([Some(fixture.own_topic.id), Some(fixture.other_topic.id)])
+            .await;
+        assert!(matches!(result, Err(TestManagementError::InvalidTopic(_))));
+        let result = fixture
+            .replace_questions([Some(Uuid::new_v4()), None])
+            .await;
+        assert!(matches!(result, Err(TestManagementError::TopicNotFound)));
+        assert!(fixture
+            .practice_test_repo
+            .questions
+            .lock()
+            .unwrap()
+            .is_empty());
+
+        let saved = fixture
+            .replace_questions([Some(fixture.own_topic.id), None])
+            .await
+            .unwrap();
+        assert_eq!(saved[0].topic_id, Some(fixture.own_topic.id));
+        assert_eq!(saved[1].topic_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_update_sets_and_clears_the_topic() {
+        let fixture = Fixture::new();
+
+        let result = fixture
+            .update_topic(Some(fixture.other_topic.id), false)
+            .await;
+        assert!(matches!(result, Err(TestManagementError::InvalidTopic(_))));
+
+        let updated = fixture
+            .update_topic(Some(fixture.own_topic.id), false)
+            .await
+            .unwrap();
+        assert_eq!(updated.topic_id, Some(fixture.own_topic.id));
+
+        // Leaving both fields out keeps the topic
+        let updated = fixture.update_topic(None, false).await.unwrap();
+        assert_eq!(updated.topic_id, Some(fixture.own_topic.id));
+
+        let result = fixture.update_topic(Some(fixture.own_topic.id), true).await;
+        assert!(matches!(result, Err(TestManagementError::InvalidTopic(_))));
+
+        let updated = fixture.update_topic(None, true).await.unwrap();
+        assert_eq!(updated.topic_id, None);
+    }
+}
@@ -1,13 +1,30 @@
 use async_trait::async_trait;
 use chrono::Utc;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use tracing::{error, warn};
 use uuid::Uuid;
 
-use domain::entities::TestResult;
+use domain::entities::{
+    Achievement, AnswerCounts, AnswerKey, ExamSession, PracticeTest, RetakePolicy,
+    RetakePolicyScope, TestBook, TestBookProgress, TestResult,
+};
 use domain::errors::DomainError;
-use domain::repositories::{PracticeTestRepository, TestResultRepository};
+use domain::repositories::{
+    AchievementRepository, ExamSessionRepository, ExamTypeRepository, PracticeTestRepository,
+    RetakePolicyRepository, ScoringPolicyRepository, TestBookRepository, TestBookSubjectRepository,
+    TestResultRepository,
+};
+use domain::services::{
+    AchievementEvaluator, AttemptStatus, CohortStatistics, RetakeDecision, RetakeEvaluator,
+};
 
-use crate::dto::{SolveTestRequest, SolveTestResponse, TestResultResponse};
+use crate::dto::{
+    AchievementResponse, CohortStatisticsResponse, ExamSessionResponse,
+    PracticeTestWithStatusResponse, SaveExamSessionAnswersRequest, SolveTestRequest,
+    SolveTestResponse, StudentPracticeTestResponse, SubmitExamSessionRequest,
+    TestBookWithStatsResponse, TestResultResponse,
+};
 
 /// Errors for test solving operations.
 #[derive(Debug, thiserror::Error)]
@@ -15,22 +32,46 @@ pub enum TestSolvingError {
     #[error("Practice test not found")]
     PracticeTestNotFound,
 
-    #[error("Cannot retake test yet. Must wait 24 hours since last attempt")]
+    #[error("Test book not found")]
+    TestBookNotFound,
+
+    #[error("Cannot retake test yet. The retake cooldown has not passed")]
     CannotRetakeYet,
 
+    #[error("Maximum number of attempts reached")]
+    MaxAttemptsReached,
+
     #[error("Answer key length mismatch")]
     AnswerKeyLengthMismatch,
 
     #[error("User answers length mismatch")]
     UserAnswersLengthMismatch,
 
+    #[error("No scoring policy configured for this exam type")]
+    ScoringPolicyNotFound,
+
+    #[error("Timed tests must be solved in an exam session")]
+    TimedTestRequiresSession,
+
+    #[error("Exam session not found")]
+    ExamSessionNotFound,
+
+    #[error("Exam session is no longer in progress")]
+    ExamSessionClosed,
+
+    #[error("The answer key of this practice test is invalid and must be corrected")]
+    InvalidStoredAnswerKey,
+
     #[error("Internal error: {0}")]
     InternalError(String),
 }
 
 impl From<DomainError> for TestSolvingError {
     fn from(err: DomainError) -> Self {
-        TestSolvingError::InternalError(err.to_string())
+        match err {
+            DomainError::InvalidStoredAnswerKey(..) => TestSolvingError::InvalidStoredAnswerKey,
+            other => TestSolvingError::InternalError(other.to_string()),
+        }
     }
 }
 
@@ -44,84 +85,556 @@ pub trait TestSolvingService: Send + Sync {
         practice_test_id: Uuid,
         request: SolveTestRequest,
     ) -> Result<SolveTestResponse, TestSolvingError>;
+
+    /// Gets a practice test as seen by a student (or an anonymous visitor).
+    async fn get_practice_test(
+        &self,
+        user_id: Option<Uuid>,
+        practice_test_id: Uuid,
+    ) -> Result<StudentPracticeTestResponse, TestSolvingError>;
+
+    /// Lists practice tests as seen by a student, optionally for a single test book.
+    async fn list_practice_tests(
+        &self,
+        user_id: Option<Uuid>,
+        test_book_id: Option<Uuid>,
+    ) -> Result<Vec<StudentPracticeTestResponse>, TestSolvingError>;
+
+    /// Lists the practice tests of a test book as seen by a student, grouped by subject.
+    async fn list_practice_tests_grouped_by_subject(
+        &self,
+        user_id: Option<Uuid>,
+        test_book_id: Uuid,
+    ) -> Result<HashMap<Uuid, Vec<StudentPracticeTestResponse>>, TestSolvingError>;
+
+    /// Lists the practice tests of a test book with the user's attempt status,
+    /// optionally for a single subject.
+    async fn list_practice_tests_with_status(
+        &self,
+        user_id: Uuid,
+        test_book_id: Uuid,
+        subject_id: Option<Uuid>,
+    ) -> Result<Vec<PracticeTestWithStatusResponse>, TestSolvingError>;
+
+    /// Lists test books with the user's progress, optionally filtered by exam type,
+    /// lesson (together with the exam type) and a case-insensitive name search.
+    async fn list_test_books_with_stats(
+        &self,
+        user_id: Uuid,
+        exam_type_id: Option<Uuid>,
+        lesson_id: Option<Uuid>,
+        search: Option<String>,
+    ) -> Result<Vec<TestBookWithStatsResponse>, TestSolvingError>;
+
+    /// Starts an exam session for a practice test, or resumes the one in progress.
+    async fn start_exam_session(
+        &self,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<ExamSessionResponse, TestSolvingError>;
+
+    /// Gets one of the user's exam sessions, auto-submitting it if it has expired.
+    async fn get_exam_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<ExamSessionResponse, TestSolvingError>;
+
+    /// Autosaves the partial answers of an exam session in progress.
+    async fn save_exam_session_answers(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        request: SaveExamSessionAnswersRequest,
+    ) -> Result<ExamSessionResponse, TestSolvingError>;
+
+    /// Submits an exam session and grades it.
+    ///
+    /// Answers sent after the deadline are ignored; the session is graded with
+    /// the answers saved before it expired.
+    async fn submit_exam_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        request: SubmitExamSessionRequest,
+    ) -> Result<SolveTestResponse, TestSolvingError>;
+
+    /// Auto-submits every exam session whose time limit has run out.
+    /// Sessions that fail to submit are logged and skipped.
+    /// Returns the number of sessions submitted.
+    async fn expire_exam_sessions(&self) -> Result<usize, TestSolvingError>;
 }
 
 /// Implementation of TestSolvingService.
-pub struct TestSolvingServiceImpl<P, R>
+pub struct TestSolvingServiceImpl<P, R, T, TB, SP, E, RP, ES, AC>
 where
     P: PracticeTestRepository,
     R: TestResultRepository,
+    T: TestBookRepository,
+    TB: TestBookSubjectRepository,
+    SP: ScoringPolicyRepository,
+    E: ExamTypeRepository,
+    RP: RetakePolicyRepository,
+    ES: ExamSessionRepository,
+    AC: AchievementRepository,
 {
     practice_test_repo: Arc<P>,
     test_result_repo: Arc<R>,
+    test_book_repo: Arc<T>,
+    test_book_subject_repo: Arc<TB>,
+    scoring_policy_repo: Arc<SP>,
+    exam_type_repo: Arc<E>,
+    retake_policy_repo: Arc<RP>,
+    exam_session_repo: Arc<ES>,
+    achievement_repo: Arc<AC>,
 }
 
-impl<P, R> TestSolvingServiceImpl<P, R>
+impl<P, R, T, TB, SP, E, RP, ES, AC> TestSolvingServiceImpl<P, R, T, TB, SP, E, RP, ES, AC>
 where
     P: PracticeTestRepository,
     R: TestResultRepository,
+    T: TestBookRepository,
+    TB: TestBookSubjectRepository,
+    SP: ScoringPolicyRepository,
+    E: ExamTypeRepository,
+    RP: RetakePolicyRepository,
+    ES: ExamSessionRepository,
+    AC: AchievementRepository,
 {
-    pub fn new(practice_test_repo: Arc<P>, test_result_repo: Arc<R>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        practice_test_repo: Arc<P>,
+        test_result_repo: Arc<R>,
+        test_book_repo: Arc<T>,
+        test_book_subject_repo: Arc<TB>,
+        scoring_policy_repo: Arc<SP>,
+        exam_type_repo: Arc<E>,
+        retake_policy_repo: Arc<RP>,
+        exam_session_repo: Arc<ES>,
+        achievement_repo: Arc<AC>,
+    ) -> Self {
         Self {
             practice_test_repo,
             test_result_repo,
+            test_book_repo,
+            test_book_subject_repo,
+            scoring_policy_repo,
+            exam_type_repo,
+            retake_policy_repo,
+            exam_session_repo,
+            achievement_repo,
         }
     }
 
-    /// Evaluates user answers against the answer key.
-    fn evaluate_answers(
-        &self,
-        answer_key: &str,
-        user_answers: &str,
-    ) -> Result<(i32, i32, i32), TestSolvingError> {
-        if answer_key.len() != user_answers.len() {
-            return Err(TestSolvingError::AnswerKeyLengthMismatch);
-        }
-
-        let mut correct = 0;
-        let mut wrong = 0;
-        let mut empty = 0;
-
-        for (key_char, user_char) in answer_key.chars().zip(user_answers.chars()) {
-            if user_char == '_' || user_char == ' ' {
-                empty += 1;
-            } else if key_char.to_uppercase().next() == user_char.to_uppercase().next() {
-                correct += 1;
-            } else {
-                wrong += 1;
-            }
+    /// Converts a test result entity into its response DTO.
+    fn to_test_result_response(result: TestResult) -> TestResultResponse {
+        TestResultResponse {
+            id: result.id,
+            user_id: result.user_id,
+            practice_test_id: result.practice_test_id,
+            user_answers: result.user_answers,
+            correct_count: result.correct_count,
+            wrong_count: result.wrong_count,
+            empty_count: result.empty_count,
+            net_score: result.net_score,
+            scoring_policy_id: result.scoring_policy_id,
+            scoring_policy_version: result.scoring_policy_version,
+            answer_key_revision: result.answer_key_revision,
+            score_changed_at: result.score_changed_at,
+            solved_at: result.solved_at,
+            started_at: result.started_at,
+            elapsed_seconds: result.elapsed_seconds,
         }
+    }
 
-        Ok((correct, wrong, empty))
+    /// Converts an exam session entity into its response DTO.
+    fn to_exam_session_response(session: ExamSession) -> ExamSessionResponse {
+        ExamSessionResponse {
+            remaining_seconds: session.remaining_seconds(Utc::now()),
+            id: session.id,
+            practice_test_id: session.practice_test_id,
+            answers: session.answers,
+            status: session.status.as_str().to_string(),
+            started_at: session.started_at,
+            expires_at: session.expires_at,
+            last_saved_at: session.last_saved_at,
+            submitted_at: session.submitted_at,
+            test_result_id: session.test_result_id,
+        }
     }
 
-    /// Checks if user can retake the test (24 hour rule).
-    async fn can_retake_test(
+    /// Finds the practice test and its test book.
+    async fn find_practice_test(
         &self,
+        practice_test_id: Uuid,
+    ) -> Result<(PracticeTest, TestBook), TestSolvingError> {
+        let practice_test = self
+            .practice_test_repo
+            .find_by_id(practice_test_id)
+            .await?
+            .ok_or(TestSolvingError::PracticeTestNotFound)?;
+        let test_book = self
+            .test_book_repo
+            .find_by_id(practice_test.test_book_id)
+            .await?
+            .ok_or_else(|| TestSolvingError::InternalError("Test book not found".to_string()))?;
+
+        Ok((practice_test, test_book))
+    }
+
+    /// Rejects a new attempt the retake policy does not allow yet.
+    async fn ensure_can_attempt(
+        &self,
+        retake_policy: &RetakePolicy,
         user_id: Uuid,
         practice_test_id: Uuid,
-    ) -> Result<(bool, Option<f64>), TestSolvingError> {
-        let latest_result = self
+    ) -> Result<(), TestSolvingError> {
+        let decision = self
+            .retake_decision(retake_policy, user_id, practice_test_id)
+            .await?;
+        match decision.status {
+            AttemptStatus::Waiting => Err(TestSolvingError::CannotRetakeYet),
+            AttemptStatus::Locked => Err(TestSolvingError::MaxAttemptsReached),
+            AttemptStatus::Available | AttemptStatus::Solved => Ok(()),
+        }
+    }
+
+    /// Grades answers into a new, unsaved test result using the current
+    /// scoring policy of the test's exam type.
+    async fn grade(
+        &self,
+        user_id: Uuid,
+        practice_test: &PracticeTest,
+        test_book: &TestBook,
+        user_answers: String,
+    ) -> Result<TestResult, TestSolvingError> {
+        let answer_key = practice_test.parsed_answer_key()?;
+
+        // Validate answer lengths match
+        if user_answers.chars().count() != answer_key.len() {
+            return Err(TestSolvingError::UserAnswersLengthMismatch);
+        }
+
+        // Evaluate answers
+        let counts = self.evaluate_answers(&answer_key, &user_answers)?;
+
+        // Resolve the scoring policy of the test's exam type
+        let scoring_policy = self
+            .scoring_policy_repo
+            .find_current_by_exam_type(test_book.exam_type_id)
+            .await?
+            .ok_or(TestSolvingError::ScoringPolicyNotFound)?;
+
+        Ok(TestResult::new(
+            user_id,
+            practice_test.id,
+            user_answers,
+            counts,
+            &scoring_policy,
+            practice_test.answer_key_revision,
+        ))
+    }
+
+    /// Builds the response for a saved result, reporting when the next attempt is possible.
+    async fn to_solve_response(
+        &self,
+        retake_policy: &RetakePolicy,
+        result: TestResult,
+    ) -> Result<SolveTestResponse, TestSolvingError> {
+        let decision = self
+            .retake_decision(retake_policy, result.user_id, result.practice_test_id)
+            .await?;
+        // The result is already saved, so neither of these may fail the submission
+        let cohort = match self.cohort_statistics(&result).await {
+            Ok(cohort) => cohort,
+            Err(e) => {
+                warn!(test_result_id = %result.id, "Failed to compute cohort statistics: {:?}", e);
+                None
+            }
+        };
+        let new_achievements = match self.award_achievements(result.user_id).await {
+            Ok(new_achievements) => new_achievements,
+            Err(e) => {
+                warn!(user_id = %result.user_id, "Failed to award achievements: {:?}", e);
+                Vec::new()
+            }
+        };
+
+        Ok(SolveTestResponse {
+            result: Self::to_test_result_response(result),
+            can_retake: decision.can_attempt(),
+            hours_until_retake: decision.hours_until_retake,
+            attempts_remaining: decision.attempts_remaining,
+            cohort,
+            new_achievements,
+        })
+    }
+
+    /// Awards the achievements the user has reached and not been awarded yet.
+    async fn award_achievements(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<AchievementResponse>, TestSolvingError> {
+        let achievements = self.achievement_repo.list_all().await?;
+        let facts = self.achievement_repo.find_facts(user_id).await?;
+        let now = Utc::now();
+        let progress = AchievementEvaluator::evaluate(&achievements, &facts, now.date_naive());
+
+        let awarded: Vec<Uuid> = self
+            .achievement_repo
+            .find_awarded(user_id)
+            .await?
+            .into_iter()
+            .map(|award| award.achievement_id)
+            .collect();
+        let new_achievements: Vec<Achievement> = achievements
+            .into_iter()
+            .zip(progress)
+            .filter(|(achievement, progress)| {
+                progress.achieved && !awarded.contains(&achievement.id)
+            })
+            .map(|(achievement, _)| achievement)
+            .collect();
+        if new_achievements.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<Uuid> = new_achievements.iter().map(|a| a.id).collect();
+        self.achievement_repo.award(user_id, &ids, now).await?;
+
+        Ok(new_achievements
+            .into_iter()
+            .map(|achievement| AchievementResponse {
+                id: achievement.id,
+                code: achievement.code,
+                name: achievement.name,
+                description: achievement.description,
+                rule: achievement.rule.as_str().to_string(),
+                threshold: achievement.threshold,
+                exam_type_id: achievement.exam_type_id,
+                lesson_id: achievement.lesson_id,
+                created_at: achievement.created_at,
+            })
+            .collect())
+    }
+
+    /// Compares a result with the first attempts of its practice test.
+    async fn cohort_statistics(
+        &self,
+        result: &TestResult,
+    ) -> Result<Option<CohortStatisticsResponse>, TestSolvingError> {
+        let distribution = self
             .test_result_repo
-            .find_latest_by_user_and_practice_test(user_id, practice_test_id)
+            .find_net_distribution(result.practice_test_id)
             .await?;
 
-        if let Some(result) = latest_result {
-            let hours_since = (Utc::now() - result.solved_at).num_seconds() as f64 / 3600.0;
-            if hours_since < 24.0 {
-                return Ok((false, Some(24.0 - hours_since)));
+        Ok(
+            CohortStatistics::compute(&distribution, result.net_score).map(|stats| {
+                CohortStatisticsResponse {
+                    first_attempt_count: stats.first_attempt_count,
+                    average_net: stats.average_net,
+                    median_net: stats.median_net,
+                    percentile: stats.percentile,
+                }
+            }),
+        )
+    }
+
+    /// Finds an exam session belonging to the user.
+    async fn find_own_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<ExamSession, TestSolvingError> {
+        self.exam_session_repo
+            .find_by_id(session_id)
+            .await?
+            .filter(|session| session.user_id == user_id)
+            .ok_or(TestSolvingError::ExamSessionNotFound)
+    }
+
+    /// Grades a session with its current answers and submits it. A session past
+    /// its deadline is closed at the deadline as expired.
+    async fn finish_session(
+        &self,
+        mut session: ExamSession,
+    ) -> Result<SolveTestResponse, TestSolvingError> {
+        let (practice_test, test_book) = self.find_practice_test(session.practice_test_id).await?;
+
+        let mut test_result = self
+            .grade(
+                session.user_id,
+                &practice_test,
+                &test_book,
+                session.answers.clone(),
+            )
+            .await?;
+        let submitted_at = session.finish(test_result.id, Utc::now());
+        test_result.record_session_timing(session.started_at, submitted_at);
+
+        // Loses against a concurrent submission of the same session
+        if !self
+            .exam_session_repo
+            .submit(&session, &test_result)
+            .await?
+        {
+            return Err(TestSolvingError::ExamSessionClosed);
+        }
+
+        let retake_policy = self.retake_policy_for(&test_book).await?;
+        self.to_solve_response(&retake_policy, test_result).await
+    }
+
+    /// Auto-submits a session that has run out of time, tolerating a concurrent submission.
+    async fn submit_if_expired(&self, session: &ExamSession) -> Result<bool, TestSolvingError> {
+        if !session.is_expired(Utc::now()) {
+            return Ok(false);
+        }
+        match self.finish_session(session.clone()).await {
+            Ok(_) | Err(TestSolvingError::ExamSessionClosed) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Builds the student projections of practice tests, hiding answer keys
+    /// the student may not see yet.
+    ///
+    /// A key is revealed once the student has a result for the test, or when
+    /// the test's exam type reveals its answer keys.
+    async fn to_student_responses(
+        &self,
+        user_id: Option<Uuid>,
+        practice_tests: Vec<PracticeTest>,
+    ) -> Result<Vec<StudentPracticeTestResponse>, TestSolvingError> {
+        let solved_ids: HashSet<Uuid> = match user_id {
+            Some(user_id) => {
+                let practice_test_ids: Vec<Uuid> = practice_tests.iter().map(|pt| pt.id).collect();
+                self.test_result_repo
+                    .find_latest_by_user_and_practice_tests(user_id, &practice_test_ids)
+                    .await?
+                    .into_iter()
+                    .map(|result| result.practice_test_id)
+                    .collect()
             }
+            None => HashSet::new(),
+        };
+
+        let revealing_books = self
+            .test_books_revealing_answer_keys(&practice_tests)
+            .await?;
+
+        let mut responses = Vec::with_capacity(practice_tests.len());
+        for pt in practice_tests {
+            let revealed_by_exam_type = *revealing_books
+                .get(&pt.test_book_id)
+                .ok_or(TestSolvingError::TestBookNotFound)?;
+            let revealed = revealed_by_exam_type || solved_ids.contains(&pt.id);
+
+            responses.push(StudentPracticeTestResponse {
+                id: pt.id,
+                name: pt.name,
+                test_number: pt.test_number,
+                question_count: pt.question_count,
+                answer_key: revealed.then_some(pt.answer_key),
+                test_book_id: pt.test_book_id,
+                subject_id: pt.subject_id,
+                time_limit_minutes: pt.time_limit_minutes,
+                topic_id: pt.topic_id,
+                created_at: pt.created_at,
+            });
         }
 
-        Ok((true, None))
+        Ok(responses)
+    }
+
+    /// Checks for the test book of each practice test whether its exam type shows
+    /// answer keys up front, loading the test books and exam types in one query each.
+    async fn test_books_revealing_answer_keys(
+        &self,
+        practice_tests: &[PracticeTest],
+    ) -> Result<HashMap<Uuid, bool>, TestSolvingError> {
+        let mut test_book_ids: Vec<Uuid> =
+            practice_tests.iter().map(|pt| pt.test_book_id).collect();
+        test_book_ids.sort();
+        test_book_ids.dedup();
+        if test_book_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let test_books = self.test_book_repo.find_by_ids(&test_book_ids).await?;
+        let mut exam_type_ids: Vec<Uuid> = test_books.iter().map(|tb| tb.exam_type_id).collect();
+        exam_type_ids.sort();
+        exam_type_ids.dedup();
+        let revealing_exam_types: HashSet<Uuid> = self
+            .exam_type_repo
+            .find_by_ids(&exam_type_ids)
+            .await?
+            .into_iter()
+            .filter(|exam_type| exam_type.reveal_answer_keys)
+            .map(|exam_type| exam_type.id)
+            .collect();
+
+        Ok(test_books
+            .into_iter()
+            .map(|tb| (tb.id, revealing_exam_types.contains(&tb.exam_type_id)))
+            .collect())
+    }
+
+    /// Evaluates user answers against the answer key.
+    fn evaluate_answers(
+        &self,
+        answer_key: &AnswerKey,
+        user_answers: &str,
+    ) -> Result<AnswerCounts, TestSolvingError> {
+        AnswerCounts::evaluate(answer_key, user_answers)
+            .ok_or(TestSolvingError::AnswerKeyLengthMismatch)
+    }
+
+    /// Resolves the retake policy of a test book: its own policy, else its exam
+    /// type's, else the standard 24-hour policy.
+    async fn retake_policy_for(
+        &self,
+        test_book: &TestBook,
+    ) -> Result<RetakePolicy, TestSolvingError> {
+        let policy = self
+            .retake_policy_repo
+            .find_effective(test_book.id, test_book.exam_type_id)
+            .await?;
+
+        Ok(policy.unwrap_or_else(|| {
+            RetakePolicy::standard(RetakePolicyScope::ExamType(test_book.exam_type_id))
+        }))
+    }
+
+    /// Applies a retake policy to the user's attempts of a practice test.
+    async fn retake_decision(
+        &self,
+        policy: &RetakePolicy,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<RetakeDecision, TestSolvingError> {
+        let attempts = self
+            .test_result_repo
+            .find_attempts_by_user_and_practice_test(user_id, practice_test_id)
+            .await?;
+
+        Ok(RetakeEvaluator::evaluate(policy, &attempts, Utc::now()))
     }
 }
 
 #[async_trait]
-impl<P, R> TestSolvingService for TestSolvingServiceImpl<P, R>
+impl<P, R, T, TB, SP, E, RP, ES, AC> TestSolvingService
+    for TestSolvingServiceImpl<P, R, T, TB, SP, E, RP, ES, AC>
 where
     P: PracticeTestRepository + 'static,
     R: TestResultRepository + 'static,
+    T: TestBookRepository + 'static,
+    TB: TestBookSubjectRepository + 'static,
+    SP: ScoringPolicyRepository + 'static,
+    E: ExamTypeRepository + 'static,
+    RP: RetakePolicyRepository + 'static,
+    ES: ExamSessionRepository + 'static,
+    AC: AchievementRepository + 'static,
 {
     async fn solve_test(
         &self,
@@ -129,56 +642,588 @@ where
         practice_test_id: Uuid,
         request: SolveTestRequest,
     ) -> Result<SolveTestResponse, TestSolvingError> {
-        // Get practice test
+        let (practice_test, test_book) = self.find_practice_test(practice_test_id).await?;
+
+        // The time limit is only enforced through exam sessions
+        if practice_test.time_limit_minutes.is_some() {
+            return Err(TestSolvingError::TimedTestRequiresSession);
+        }
+
+        // Check the retake policy against previous attempts
+        let retake_policy = self.retake_policy_for(&test_book).await?;
+        self.ensure_can_attempt(&retake_policy, user_id, practice_test_id)
+            .await?;
+
+        let test_result = self
+            .grade(user_id, &practice_test, &test_book, request.user_answers)
+            .await?;
+
+        // Save result
+        let saved_result = self.test_result_repo.create(&test_result).await?;
+
+        self.to_solve_response(&retake_policy, saved_result).await
+    }
+
+    async fn get_practice_test(
+        &self,
+        user_id: Option<Uuid>,
+        practice_test_id: Uuid,
+    ) -> Result<StudentPracticeTestResponse, TestSolvingError> {
         let practice_test = self
             .practice_test_repo
             .find_by_id(practice_test_id)
             .await?
             .ok_or(TestSolvingError::PracticeTestNotFound)?;
 
-        // Check if user can retake (24 hour rule)
-        let (can_retake, _hours_until) = self.can_retake_test(user_id, practice_test_id).await?;
-        if !can_retake {
-            return Err(TestSolvingError::CannotRetakeYet);
+        let mut responses = self
+            .to_student_responses(user_id, vec![practice_test])
+            .await?;
+        responses
+            .pop()
+            .ok_or(TestSolvingError::PracticeTestNotFound)
+    }
+
+    async fn list_practice_tests(
+        &self,
+        user_id: Option<Uuid>,
+        test_book_id: Option<Uuid>,
+    ) -> Result<Vec<StudentPracticeTestResponse>, TestSolvingError> {
+        let practice_tests = match test_book_id {
+            Some(test_book_id) => {
+                self.practice_test_repo
+                    .find_by_test_book_id(test_book_id)
+                    .await?
+            }
+            None => self.practice_test_repo.list_all().await?,
+        };
+
+        self.to_student_responses(user_id, practice_tests).await
+    }
+
+    async fn list_practice_tests_grouped_by_subject(
+        &self,
+        user_id: Option<Uuid>,
+        test_book_id: Uuid,
+    ) -> Result<HashMap<Uuid, Vec<StudentPracticeTestResponse>>, TestSolvingError> {
+        // Verify test book exists
+        self.test_book_repo
+            .find_by_id(test_book_id)
+            .await?
+            .ok_or(TestSolvingError::TestBookNotFound)?;
+
+        let practice_tests = self
+            .practice_test_repo
+            .find_by_test_book_id(test_book_id)
+            .await?;
+
+        let mut grouped: HashMap<Uuid, Vec<StudentPracticeTestResponse>> = HashMap::new();
+        for response in self.to_student_responses(user_id, practice_tests).await? {
+            grouped
+                .entry(response.subject_id)
+                .or_default()
+                .push(response);
         }
 
-        // Validate answer lengths match
-        if request.user_answers.len() != practice_test.answer_key.len() {
-            return Err(TestSolvingError::UserAnswersLengthMismatch);
+        Ok(grouped)
+    }
+
+    async fn list_practice_tests_with_status(
+        &self,
+        user_id: Uuid,
+        test_book_id: Uuid,
+        subject_id: Option<Uuid>,
+    ) -> Result<Vec<PracticeTestWithStatusResponse>, TestSolvingError> {
+        let test_book = self
+            .test_book_repo
+            .find_by_id(test_book_id)
+            .await?
+            .ok_or(TestSolvingError::TestBookNotFound)?;
+        let retake_policy = self.retake_policy_for(&test_book).await?;
+
+        let practice_tests: Vec<PracticeTest> = self
+            .practice_test_repo
+            .find_by_test_book_id(test_book_id)
+            .await?
+            .into_iter()
+            .filter(|pt| subject_id.is_none_or(|subject_id| pt.subject_id == subject_id))
+            .collect();
+
+        let practice_test_ids: Vec<Uuid> = practice_tests.iter().map(|pt| pt.id).collect();
+        let mut attempts_by_test: HashMap<Uuid, Vec<TestResult>> = HashMap::new();
+        for attempt in self
+            .test_result_repo
+            .find_attempts_by_user_and_practice_tests(user_id, &practice_test_ids)
+            .await?
+        {
+            attempts_by_test
+                .entry(attempt.practice_test_id)
+                .or_default()
+                .push(attempt);
         }
 
-        // Evaluate answers
-        let (correct, wrong, empty) =
-            self.evaluate_answers(&practice_test.answer_key, &request.user_answers)?;
+        let now = Utc::now();
+        let mut responses = Vec::with_capacity(practice_tests.len());
+        for test in self
+            .to_student_responses(Some(user_id), practice_tests)
+            .await?
+        {
+            let attempts = attempts_by_test
+                .get(&test.id)
+                .map(Vec::as_slice)
+                .unwrap_or_default();
+            let decision = RetakeEvaluator::evaluate(&retake_policy, attempts, now);
 
-        // Create test result
-        let test_result = TestResult::new(
-            user_id,
-            practice_test_id,
-            request.user_answers,
-            correct,
-            wrong,
-            empty,
-        );
+            responses.push(PracticeTestWithStatusResponse {
+                id: test.id,
+                name: test.name,
+                test_number: test.test_number,
+                question_count: test.question_count,
+                answer_key: test.answer_key,
+                test_book_id: test.test_book_id,
+                subject_id: test.subject_id,
+                time_limit_minutes: test.time_limit_minutes,
+                topic_id: test.topic_id,
+                created_at: test.created_at,
+                status: decision.status.as_str().to_string(),
+                last_solved_at: decision.last_solved_at,
+                result_id: decision.last_result_id,
+                hours_until_retake: decision.hours_until_retake,
+                attempts_used: decision.attempts_used,
+                attempts_remaining: decision.attempts_remaining,
+                counted_result_id: decision.counted_result_id,
+            });
+        }
 
-        // Save result
-        let saved_result = self.test_result_repo.create(&test_result).await?;
+        Ok(responses)
+    }
 
-        Ok(SolveTestResponse {
-            result: TestResultResponse {
-                id: saved_result.id,
-                user_id: saved_result.user_id,
-                practice_test_id: saved_result.practice_test_id,
-                user_answers: saved_result.user_answers,
-                correct_count: saved_result.correct_count,
-                wrong_count: saved_result.wrong_count,
-                empty_count: saved_result.empty_count,
-                net_score: saved_result.net_score,
-                solved_at: saved_result.solved_at,
-            },
-            can_retake: true,
-            hours_until_retake: None,
-        })
+    async fn list_test_books_with_stats(
+        &self,
+        user_id: Uuid,
+        exam_type_id: Option<Uuid>,
+        lesson_id: Option<Uuid>,
+        search: Option<String>,
+    ) -> Result<Vec<TestBookWithStatsResponse>, TestSolvingError> {
+        let test_books = match (exam_type_id, lesson_id) {
+            (Some(exam_type_id), Some(lesson_id)) => {
+                self.test_book_repo
+                    .find_by_exam_type_and_lesson(exam_type_id, lesson_id)
+                    .await?
+            }
+            (Some(exam_type_id), None) => {
+                self.test_book_repo
+                    .find_by_exam_type_id(exam_type_id)
+                    .await?
+            }
+            _ => self.test_book_repo.list_all().await?,
+        };
+
+        let test_books: Vec<TestBook> = match search {
+            Some(search) => {
+                let search = search.to_lowercase();
+                test_books
+                    .into_iter()
+                    .filter(|book| book.name.to_lowercase().contains(&search))
+                    .collect()
+            }
+            None => test_books,
+        };
+
+        let test_book_ids: Vec<Uuid> = test_books.iter().map(|book| book.id).collect();
+        let progress_by_book: HashMap<Uuid, TestBookProgress> = self
+            .test_result_repo
+            .find_test_book_progress(user_id, &test_book_ids)
+            .await?
+            .into_iter()
+            .map(|progress| (progress.test_book_id, progress))
+            .collect();
+        let mut subject_ids_by_book = self
+            .test_book_subject_repo
+            .find_subject_ids_by_test_book_ids(&test_book_ids)
+            .await?;
+
+        let mut responses = Vec::with_capacity(test_books.len());
+        for book in test_books {
+            let subject_ids = subject_ids_by_book.remove(&book.id).unwrap_or_default();
+            let progress = progress_by_book
+                .get(&book.id)
+                .copied()
+                .unwrap_or(TestBookProgress {
+                    test_book_id: book.id,
+                    total_test_count: 0,
+                    solved_test_count: 0,
+                });
+
+            responses.push(TestBookWithStatsResponse {
+                id: book.id,
+                name: book.name,
+                lesson_id: book.lesson_id,
+                exam_type_id: book.exam_type_id,
+                subject_ids,
+                published_year: book.published_year,
+                created_at: book.created_at,
+                total_test_count: progress.total_test_count as i32,
+                solved_test_count: progress.solved_test_count as i32,
+                progress_percentage: progress.progress_percentage(),
+            });
+        }
+
+        Ok(responses)
+    }
+
+    async fn start_exam_session(
+        &self,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<ExamSessionResponse, TestSolvingError> {
+        let (practice_test, test_book) = self.find_practice_test(practice_test_id).await?;
+
+        // Resume the session in progress, unless its time has run out
+        if let Some(session) = self
+            .exam_session_repo
+            .find_in_progress_by_user_and_practice_test(user_id, practice_test_id)
+            .await?
+        {
+            if !self.submit_if_expired(&session).await? {
+                return Ok(Self::to_exam_session_response(session));
+            }
+        }
+
+        let retake_policy = self.retake_policy_for(&test_book).await?;
+        self.ensure_can_attempt(&retake_policy, user_id, practice_test_id)
+            .await?;
+
+        let session = ExamSession::start(user_id, &practice_test, Utc::now());
+        let created = self.exam_session_repo.create(&session).await?;
+
+        Ok(Self::to_exam_session_response(created))
+    }
+
+    async fn get_exam_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+    ) -> Result<ExamSessionResponse, TestSolvingError> {
+        let mut session = self.find_own_session(user_id, session_id).await?;
+
+        if self.submit_if_expired(&session).await? {
+            session = self.find_own_session(user_id, session_id).await?;
+        }
+
+        Ok(Self::to_exam_session_response(session))
+    }
+
+    async fn save_exam_session_answers(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        request: SaveExamSessionAnswersRequest,
+    ) -> Result<ExamSessionResponse, TestSolvingError> {
+        let mut session = self.find_own_session(user_id, session_id).await?;
+
+        if !session.is_in_progress() || self.submit_if_expired(&session).await? {
+            return Err(TestSolvingError::ExamSessionClosed);
+        }
+
+        session
+            .save_answers(request.answers, Utc::now())
+            .map_err(|_| TestSolvingError::UserAnswersLengthMismatch)?;
+        if !self.exam_session_repo.save_answers(&session).await? {
+            return Err(TestSolvingError::ExamSessionClosed);
+        }
+
+        Ok(Self::to_exam_session_response(session))
+    }
+
+    async fn submit_exam_session(
+        &self,
+        user_id: Uuid,
+        session_id: Uuid,
+        request: SubmitExamSessionRequest,
+    ) -> Result<SolveTestResponse, TestSolvingError> {
+        let mut session = self.find_own_session(user_id, session_id).await?;
+
+        if !session.is_in_progress() {
+            return Err(TestSolvingError::ExamSessionClosed);
+        }
+
+        // Late answers are dropped; an expired session keeps its last autosave
+        if let Some(answers) = request.answers {
+            if !session.is_expired(Utc::now()) {
+                session
+                    .save_answers(answers, Utc::now())
+                    .map_err(|_| TestSolvingError::UserAnswersLengthMismatch)?;
+            }
+        }
+
+        self.finish_session(session).await
+    }
+
+    async fn expire_exam_sessions(&self) -> Result<usize, TestSolvingError> {
+        let expired = self.exam_session_repo.find_expired(Utc::now()).await?;
+
+        let mut submitted = 0;
+        for session in expired {
+            let session_id = session.id;
+            match self.finish_session(session).await {
+                Ok(_) => submitted += 1,
+                // Submitted by the student in the meantime
+                Err(TestSolvingError::ExamSessionClosed) => {}
+                // One failing session must not hold back the others; it is retried next sweep
+                Err(e) => {
+                    error!(
+                        exam_session_id = %session_id,
+                        "Failed to auto-submit expired exam session: {:?}",
+                        e
+                    );
+                }
+            }
+        }
+
+        Ok(submitted)
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::{ExamType, ScoringPolicy};
+
+    use crate::test_support::{
+        FakeAchievementRepository, FakeExamSessionRepository, FakeExamTypeRepository,
+        FakePracticeTestRepository, FakeRetakePolicyRepository, FakeScoringPolicyRepository,
+        FakeTestBookRepository, FakeTestBookSubjectRepository, FakeTestResultRepository,
+    };
+
+    type Service = TestSolvingServiceImpl<
+        FakePracticeTestRepository,
+        FakeTestResultRepository,
+        FakeTestBookRepository,
+        FakeTestBookSubjectRepository,
+        FakeScoringPolicyRepository,
+        FakeExamTypeRepository,
+        FakeRetakePolicyRepository,
+        FakeExamSessionRepository,
+        FakeAchievementRepository,
+    >;
+
+    /// Test solving service over in-memory repositories with one exam type and test book.
+    struct Fixture {
+        service: Service,
+        practice_test_repo: Arc<FakePracticeTestRepository>,
+        exam_session_repo: Arc<FakeExamSessionRepository>,
+        test_book: TestBook,
+    }
+
+    impl Fixture {
+        fn new(reveal_answer_keys: bool) -> Self {
+            Self::with_repositories(
+                reveal_answer_keys,
+                FakeTestResultRepository::default(),
+                FakeAchievementRepository::default(),
+            )
+        }
+
+        fn with_repositories(
+            reveal_answer_keys: bool,
+            test_result_repo: FakeTestResultRepository,
+            achievement_repo: FakeAchievementRepository,
+        ) -> Self {
+            let mut exam_type = ExamType::new("TYT".to_string(), None);
+            exam_type.reveal_answer_keys = reveal_answer_keys;
+            let test_book = TestBook::new(
+                "Deneme Kitabı".to_string(),
+                Uuid::new_v4(),
+                exam_type.id,
+                2024,
+            );
+
+            let practice_test_repo = Arc::new(FakePracticeTestRepository::default());
+            let test_book_repo = Arc::new(FakeTestBookRepository::default());
+            test_book_repo
+                .test_books
+                .lock()
+                .unwrap()
+                .push(test_book.clone());
+            let scoring_policy_repo = Arc::new(FakeScoringPolicyRepository::default());
+            scoring_policy_repo
+                .policies
+                .lock()
+                .unwrap()
+                .push(ScoringPolicy::standard(exam_type.id));
+            let exam_type_repo = Arc::new(FakeExamTypeRepository::default());
+            exam_type_repo.exam_types.lock().unwrap().push(exam_type);
+            let exam_session_repo = Arc::new(FakeExamSessionRepository::default());
+
+            let service = TestSolvingServiceImpl::new(
+                practice_test_repo.clone(),
+                Arc::new(test_result_repo),
+                test_book_repo,
+                Arc::new(FakeTestBookSubjectRepository),
+                scoring_policy_repo,
+                exam_type_repo,
+                Arc::new(FakeRetakePolicyRepository),
+                exam_session_repo.clone(),
+                Arc::new(achievement_repo),
+            );
+
+            Self {
+                service,
+                practice_test_repo,
+                exam_session_repo,
+                test_book,
+            }
+        }
+
+        fn new_practice_test(
+            &self,
+            answer_key: &str,
+            time_limit_minutes: Option<i32>,
+        ) -> PracticeTest {
+            PracticeTest::new(
+                "Deneme 1".to_string(),
+                1,
+                answer_key.chars().count() as i32,
+                answer_key.to_string(),
+                self.test_book.id,
+                Uuid::new_v4(),
+                time_limit_minutes,
+            )
+        }
+
+        fn add_practice_test(
+            &self,
+            answer_key: &str,
+            time_limit_minutes: Option<i32>,
+        ) -> PracticeTest {
+            let practice_test = self.new_practice_test(answer_key, time_limit_minutes);
+            self.practice_test_repo
+                .practice_tests
+                .lock()
+                .unwrap()
+                .push(practice_test.clone());
+            practice_test
+        }
+    }
+
+    #[tokio::test]
+    async fn test_answer_key_hidden_until_submitted() {
+        let fixture = Fixture::new(false);
+        let practice_test = fixture.add_practice_test("ABCD", None);
+        let student = Uuid::new_v4();
+
+        let before = fixture
+            .service
+            .get_practice_test(Some(student), practice_test.id)
+            .await
+            .unwrap();
+        assert_eq!(before.answer_key, None);
+        let anonymous = fixture
+            .service
+            .get_practice_test(None, practice_test.id)
+            .await
+            .unwrap();
+        assert_eq!(anonymous.answer_key, None);
+
+        fixture
+            .service
+            .solve_test(
+                student,
+                practice_test.id,
+                SolveTestRequest {
+                    user_answers: "AB_D".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        let after = fixture
+            .service
+            .get_practice_test(Some(student), practice_test.id)
+            .await
+            .unwrap();
+        assert_eq!(after.answer_key.as_deref(), Some("ABCD"));
+
+        // Other students still have to solve it first
+        let classmate = fixture
+            .service
+            .get_practice_test(Some(Uuid::new_v4()), practice_test.id)
+            .await
+            .unwrap();
+        assert_eq!(classmate.answer_key, None);
+    }
+
+    #[tokio::test]
+    async fn test_answer_key_revealed_up_front_by_exam_type() {
+        let fixture = Fixture::new(true);
+        let practice_test = fixture.add_practice_test("ABCD", None);
+
+        let listed = fixture
+            .service
+            .list_practice_tests(None, Some(fixture.test_book.id))
+            .await
+            .unwrap();
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].id, practice_test.id);
+        assert_eq!(listed[0].answer_key.as_deref(), Some("ABCD"));
+    }
+
+    #[tokio::test]
+    async fn test_solve_succeeds_when_cohort_statistics_and_achievements_fail() {
+        let fixture = Fixture::with_repositories(
+            false,
+            FakeTestResultRepository {
+                fail_net_distribution: true,
+                ..Default::default()
+            },
+            FakeAchievementRepository { fail: true },
+        );
+        let practice_test = fixture.add_practice_test("ABCD", None);
+
+        let response = fixture
+            .service
+            .solve_test(
+                Uuid::new_v4(),
+                practice_test.id,
+                SolveTestRequest {
+                    user_answers: "AB_D".to_string(),
+                },
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.result.correct_count, 3);
+        assert!(response.cohort.is_none());
+        assert!(response.new_achievements.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_expire_exam_sessions_skips_failing_session() {
+        let fixture = Fixture::new(false);
+        let practice_test = fixture.add_practice_test("ABCD", Some(30));
+        // Deleted after the session was started
+        let deleted_test = fixture.new_practice_test("ABCD", Some(30));
+        let started_at = Utc::now() - chrono::Duration::hours(2);
+
+        let first = ExamSession::start(Uuid::new_v4(), &practice_test, started_at);
+        let failing = ExamSession::start(Uuid::new_v4(), &deleted_test, started_at);
+        let last = ExamSession::start(Uuid::new_v4(), &practice_test, started_at);
+        fixture.exam_session_repo.sessions.lock().unwrap().extend([
+            first.clone(),
+            failing.clone(),
+            last.clone(),
+        ]);
+
+        let submitted = fixture.service.expire_exam_sessions().await.unwrap();
+
+        assert_eq!(submitted, 2);
+        let sessions = fixture.exam_session_repo.sessions.lock().unwrap();
+        let in_progress: Vec<Uuid> = sessions
+            .iter()
+            .filter(|s| s.is_in_progress())
+            .map(|s| s.id)
+            .collect();
+        assert_eq!(in_progress, vec![failing.id]);
+    }
+}
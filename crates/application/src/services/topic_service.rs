@@ -0,0 +1,460 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+use domain::entities::{Topic, TopicNode, TopicTree};
+use domain::errors::DomainError;
+use domain::repositories::{SubjectRepository, TopicRepository};
+
+use crate::dto::{
+    CreateTopicRequest, MoveTopicRequest, ReorderTopicsRequest, TopicResponse,
+    TopicTreeNodeResponse, UpdateTopicRequest,
+};
+
+/// Errors for curriculum topic operations.
+#[derive(Debug, thiserror::Error)]
+pub enum TopicError {
+    #[error("Topic not found")]
+    TopicNotFound,
+
+    #[error("Subject not found")]
+    SubjectNotFound,
+
+    #[error("Parent topic not found")]
+    ParentTopicNotFound,
+
+    #[error("Invalid topic placement: {0}")]
+    InvalidPlacement(String),
+
+    #[error("Internal error: {0}")]
+    InternalError(String),
+}
+
+impl From<DomainError> for TopicError {
+    fn from(err: DomainError) -> Self {
+        TopicError::InternalError(err.to_string())
+    }
+}
+
+/// Trait for managing the curriculum topic trees of subjects.
+#[async_trait]
+pub trait TopicService: Send + Sync {
+    /// Creates a topic as the last child of its parent.
+    async fn create_topic(&self, request: CreateTopicRequest) -> Result<TopicResponse, TopicError>;
+    async fn get_topic(&self, id: Uuid) -> Result<TopicResponse, TopicError>;
+    async fn update_topic(
+        &self,
+        id: Uuid,
+        request: UpdateTopicRequest,
+    ) -> Result<TopicResponse, TopicError>;
+    /// Deletes a topic together with its descendants.
+    async fn delete_topic(&self, id: Uuid) -> Result<(), TopicError>;
+    /// Moves a topic under another parent of the same subject or to another position.
+    async fn move_topic(
+        &self,
+        id: Uuid,
+        request: MoveTopicRequest,
+    ) -> Result<TopicResponse, TopicError>;
+    /// Reorders the children of a parent and returns the subject's tree.
+    async fn reorder_topics(
+        &self,
+        subject_id: Uuid,
+        request: ReorderTopicsRequest,
+    ) -> Result<Vec<TopicTreeNodeResponse>, TopicError>;
+    /// Returns the topic tree of a subject.
+    async fn get_topic_tree(
+        &self,
+        subject_id: Uuid,
+    ) -> Result<Vec<TopicTreeNodeResponse>, TopicError>;
+}
+
+/// Implementation of TopicService.
+pub struct TopicServiceImpl<TR, S>
+where
+    TR: TopicRepository,
+    S: SubjectRepository,
+{
+    topic_repo: Arc<TR>,
+    subject_repo: Arc<S>,
+}
+
+impl<TR, S> TopicServiceImpl<TR, S>
+where
+    TR: TopicRepository,
+    S: SubjectRepository,
+{
+    pub fn new(topic_repo: Arc<TR>, subject_repo: Arc<S>) -> Self {
+        Self {
+            topic_repo,
+            subject_repo,
+        }
+    }
+
+    /// Converts a topic entity into its response DTO.
+    fn to_topic_response(topic: Topic) -> TopicResponse {
+        TopicResponse {
+            id: topic.id,
+            subject_id: topic.subject_id,
+            parent_id: topic.parent_id,
+            name: topic.name,
+            position: topic.position,
+            created_at: topic.created_at,
+            updated_at: topic.updated_at,
+        }
+    }
+
+    /// Converts a tree node and its descendants into their response DTO.
+    fn to_tree_node_response(node: TopicNode) -> TopicTreeNodeResponse {
+        TopicTreeNodeResponse {
+            id: node.topic.id,
+            name: node.topic.name,
+            position: node.topic.position,
+            children: node
+                .children
+                .into_iter()
+                .map(Self::to_tree_node_response)
+                .collect(),
+        }
+    }
+
+    /// Maps a placement validation failure to its service error.
+    fn invalid_placement(err: DomainError) -> TopicError {
+        match err {
+            DomainError::ValidationError(msg) => TopicError::InvalidPlacement(msg),
+            other => other.into(),
+        }
+    }
+
+    async fn find_topic(&self, id: Uuid) -> Result<Topic, TopicError> {
+        self.topic_repo
+            .find_by_id(id)
+            .await?
+            .ok_or(TopicError::TopicNotFound)
+    }
+
+    async fn load_tree(&self, subject_id: Uuid) -> Result<TopicTree, TopicError> {
+        Ok(TopicTree::new(
+            self.topic_repo.find_by_subject_id(subject_id).await?,
+        ))
+    }
+}
+
+#[async_trait]
+impl<TR, S> TopicService for TopicServiceImpl<TR, S>
+where
+    TR: TopicRepository + 'static,
+    S: SubjectRepository + 'static,
+{
+    async fn create_topic(&self, request: CreateTopicRequest) -> Result<TopicResponse, TopicError> {
+        self.subject_repo
+            .find_by_id(request.subject_id)
+            .await?
+            .ok_or(TopicError::SubjectNotFound)?;
+
+        let tree = self.load_tree(request.subject_id).await?;
+        if let Some(parent_id) = request.parent_id {
+            // Parents of other subjects are not part of the tree
+            tree.get(parent_id).ok_or(TopicError::ParentTopicNotFound)?;
+        }
+
+        let topic = Topic::new(
+            request.subject_id,
+            request.parent_id,
+            request.name,
+            tree.next_position(request.parent_id),
+        );
+        let created = self.topic_repo.create(&topic).await?;
+
+        Ok(Self::to_topic_response(created))
+    }
+
+    async fn get_topic(&self, id: Uuid) -> Result<TopicResponse, TopicError> {
+        Ok(Self::to_topic_response(self.find_topic(id).await?))
+    }
+
+    async fn update_topic(
+        &self,
+        id: Uuid,
+        request: UpdateTopicRequest,
+    ) -> Result<TopicResponse, TopicError> {
+        let mut topic = self.find_topic(id).await?;
+        topic.name = request.name;
+        topic.updated_at = Utc::now();
+
+        let updated = self.topic_repo.update(&topic).await?;
+        Ok(Self::to_topic_response(updated))
+    }
+
+    async fn delete_topic(&self, id: Uuid) -> Result<(), TopicError> {
+        let topic = self.find_topic(id).await?;
+        self.topic_repo.delete(id).await?;
+
+        // Close the gap left among the remaining siblings
+        let tree = self.load_tree(topic.subject_id).await?;
+        let siblings: Vec<Uuid> = tree
+            .children(topic.parent_id)
+            .into_iter()
+            .map(|sibling| sibling.id)
+            .collect();
+        let renumbered = tree
+            .reorder(topic.parent_id, &siblings)
+            .map_err(Self::invalid_placement)?;
+        self.topic_repo.update_placements(&renumbered).await?;
+
+        Ok(())
+    }
+
+    async fn move_topic(
+        &self,
+        id: Uuid,
+        request: MoveTopicRequest,
+    ) -> Result<TopicResponse, TopicError> {
+        let topic = self.find_topic(id).await?;
+        let tree = self.load_tree(topic.subject_id).await?;
+        if let Some(parent_id) = request.parent_id {
+            tree.get(parent_id).ok_or(TopicError::ParentTopicNotFound)?;
+        }
+
+        let changed = tree
+            .move_topic(
+                id,
+                request.parent_id,
+                request.position.map(|position| position.max(1) as usize),
+            )
+            .map_err(Self::invalid_placement)?;
+        self.topic_repo.update_placements(&changed).await?;
+
+        let moved = changed
+            .into_iter()
+            .find(|changed| changed.id == id)
+            .unwrap_or(topic);
+        Ok(Self::to_topic_response(moved))
+    }
+
+    async fn reorder_topics(
+        &self,
+        subject_id: Uuid,
+        request: ReorderTopicsRequest,
+    ) -> Result<Vec<TopicTreeNodeResponse>, TopicError> {
+        self.subject_repo
+            .find_by_id(subject_id)
+            .await?
+            .ok_or(TopicError::SubjectNotFound)?;
+
+        let tree = self.load_tree(subject_id).await?;
+        if let Some(parent_id) = request.parent_id {
+            tree.get(parent_id).ok_or(TopicError::ParentTopicNotFound)?;
+        }
+
+        let changed = tree
+            .reorder(request.parent_id, &request.topic_ids)
+            .map_err(Self::invalid_placement)?;
+        self.topic_repo.update_placements(&changed).await?;
+
+        self.get_topic_tree(subject_id).await
+    }
+
+    async fn get_topic_tree(
+        &self,
+        subject_id: Uuid,
+    ) -> Result<Vec<TopicTreeNodeResponse>, TopicError> {
+        self.subject_repo
+            .find_by_id(subject_id)
+            .await?
+            .ok_or(TopicError::SubjectNotFound)?;
+
+        let tree = self.load_tree(subject_id).await?;
+        Ok(tree
+            .into_nodes()
+            .into_iter()
+            .map(Self::to_tree_node_response)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::entities::Subject;
+
+    use crate::test_support::{FakeSubjectRepository, FakeTopicRepository};
+
+    /// Topic service over in-memory repositories with two units of one subject,
+    /// each with children, and a unit of another subject.
+    struct Fixture {
+        service: TopicServiceImpl<FakeTopicRepository, FakeSubjectRepository>,
+        topic_repo: Arc<FakeTopicRepository>,
+        subject_id: Uuid,
+        unit_a: Topic,
+        unit_b: Topic,
+        a1: Topic,
+        a2: Topic,
+        a1_outcome: Topic,
+        b1: Topic,
+        other_unit: Topic,
+    }
+
+    impl Fixture {
+        fn new() -> Self {
+            let subject = Subject::new("Matematik".to_string(), Uuid::new_v4(), Uuid::new_v4());
+            let subject_id = subject.id;
+            let topic = |parent: Option<&Topic>, name: &str, position| {
+                Topic::new(subject_id, parent.map(|p| p.id), name.to_string(), position)
+            };
+            let unit_a = topic(None, "Sayılar", 1);
+            let unit_b = topic(None, "Geometri", 2);
+            let a1 = topic(Some(&unit_a), "Üslü Sayılar", 1);
+            let a2 = topic(Some(&unit_a), "Köklü Sayılar", 2);
+            let a1_outcome = topic(Some(&a1), "Üs kurallarını uygular", 1);
+            let b1 = topic(Some(&unit_b), "Üçgenler", 1);
+            let other_unit = Topic::new(Uuid::new_v4(), None, "Hücre".to_string(), 1);
+
+            let topic_repo = Arc::new(FakeTopicRepository::default());
+            topic_repo.topics.lock().unwrap().extend([
+                unit_a.clone(),
+                unit_b.clone(),
+                a1.clone(),
+                a2.clone(),
+                a1_outcome.clone(),
+                b1.clone(),
+                other_unit.clone(),
+            ]);
+            let subject_repo = Arc::new(FakeSubjectRepository::default());
+            subject_repo.subjects.lock().unwrap().push(subject);
+
+            Self {
+                service: TopicServiceImpl::new(topic_repo.clone(), subject_repo),
+                topic_repo,
+                subject_id,
+                unit_a,
+                unit_b,
+                a1,
+                a2,
+                a1_outcome,
+                b1,
+                other_unit,
+            }
+        }
+
+        /// Returns the stored parent and position of a topic.
+        fn placement(&self, id: Uuid) -> (Option<Uuid>, i32) {
+            let topics = self.topic_repo.topics.lock().unwrap();
+            let topic = topics.iter().find(|t| t.id == id).unwrap();
+            (topic.parent_id, topic.position)
+        }
+
+        async fn move_topic(
+            &self,
+            id: Uuid,
+            parent_id: Option<Uuid>,
+            position: Option<i32>,
+        ) -> Result<TopicResponse, TopicError> {
+            self.service
+                .move_topic(
+                    id,
+                    MoveTopicRequest {
+                        parent_id,
+                        position,
+                    },
+                )
+                .await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_renumbers_old_and_new_siblings() {
+        let fixture = Fixture::new();
+
+        let moved = fixture
+            .move_topic(fixture.a1.id, Some(fixture.unit_b.id), Some(1))
+            .await
+            .unwrap();
+
+        assert_eq!(moved.parent_id, Some(fixture.unit_b.id));
+        assert_eq!(moved.position, 1);
+        assert_eq!(
+            fixture.placement(fixture.b1.id),
+            (Some(fixture.unit_b.id), 2)
+        );
+        assert_eq!(
+            fixture.placement(fixture.a2.id),
+            (Some(fixture.unit_a.id), 1)
+        );
+        // Descendants move along with the topic
+        assert_eq!(
+            fixture.placement(fixture.a1_outcome.id),
+            (Some(fixture.a1.id), 1)
+        );
+
+        let moved = fixture.move_topic(fixture.a2.id, None, None).await.unwrap();
+        assert_eq!((moved.parent_id, moved.position), (None, 3));
+    }
+
+    #[tokio::test]
+    async fn test_move_rejects_cycles_and_other_subjects() {
+        let fixture = Fixture::new();
+
+        for parent in [&fixture.unit_a, &fixture.a1, &fixture.a1_outcome] {
+            let result = fixture
+                .move_topic(fixture.unit_a.id, Some(parent.id), None)
+                .await;
+            assert!(matches!(result, Err(TopicError::InvalidPlacement(_))));
+        }
+
+        let result = fixture
+            .move_topic(fixture.a1.id, Some(fixture.other_unit.id), None)
+            .await;
+        assert!(matches!(result, Err(TopicError::ParentTopicNotFound)));
+        let result = fixture
+            .move_topic(fixture.other_unit.id, Some(fixture.unit_a.id), None)
+            .await;
+        assert!(matches!(result, Err(TopicError::ParentTopicNotFound)));
+
+        assert_eq!(fixture.placement(fixture.unit_a.id), (None, 1));
+        assert_eq!(
+            fixture.placement(fixture.a1.id),
+            (Some(fixture.unit_a.id), 1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reorder_children_of_a_parent() {
+        let fixture = Fixture::new();
+        let reorder = |parent_id: Option<Uuid>, topic_ids: Vec<Uuid>| {
+            fixture.service.reorder_topics(
+                fixture.subject_id,
+                ReorderTopicsRequest {
+                    parent_id,
+                    topic_ids,
+                },
+            )
+        };
+
+        let result = reorder(Some(fixture.unit_a.id), vec![fixture.a2.id]).await;
+        assert!(matches!(result, Err(TopicError::InvalidPlacement(_))));
+        let result = reorder(
+            Some(fixture.unit_a.id),
+            vec![fixture.a2.id, fixture.a1.id, fixture.b1.id],
+        )
+        .await;
+        assert!(matches!(result, Err(TopicError::InvalidPlacement(_))));
+        let result = reorder(Some(fixture.other_unit.id), vec![]).await;
+        assert!(matches!(result, Err(TopicError::ParentTopicNotFound)));
+
+        let tree = reorder(Some(fixture.unit_a.id), vec![fixture.a2.id, fixture.a1.id])
+            .await
+            .unwrap();
+        let unit_a = tree.iter().find(|n| n.id == fixture.unit_a.id).unwrap();
+        let children: Vec<(Uuid, i32)> =
+            unit_a.children.iter().map(|n| (n.id, n.position)).collect();
+        assert_eq!(children, vec![(fixture.a2.id, 1), (fixture.a1.id, 2)]);
+        assert_eq!(unit_a.children[1].children[0].id, fixture.a1_outcome.id);
+
+        let tree = reorder(None, vec![fixture.unit_b.id, fixture.unit_a.id])
+            .await
+            .unwrap();
+        let roots: Vec<Uuid> = tree.iter().map(|n| n.id).collect();
+        assert_eq!(roots, vec![fixture.unit_b.id, fixture.unit_a.id]);
+    }
+}
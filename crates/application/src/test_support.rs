@@ -0,0 +1,1103 @@
+//! In-memory fakes shared by the service tests.
+//!
+//! Each fake answers the calls the tests rely on and fails every other call with
+//! an error naming it, so a service that starts making a new query breaks its
+//! tests instead of silently reading an empty result.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use domain::entities::{
+    Achievement, AchievementFacts, AnalyticsRecord, AnswerKeyRevision, Assignment,
+    AssignmentSubmission, AssignmentSummary, Classroom, ClassroomStudent, Enrollment, ExamSession,
+    ExamSessionStatus, ExamType, GuardianLink, GuardianLinkDetail, LeaderboardPage,
+    LeaderboardScope, Lesson, MockExam, MockExamAttempt, NetDistributionBucket, Permission,
+    PracticeTest, ProgressPoint, Question, QuestionTopic, RefreshToken, RetakePolicy,
+    RetakePolicyScope, Role, ScoreTable, ScoringPolicy, StudentAssignment, Subject, TestBook,
+    TestBookProgress, TestResult, TestResultHistory, TimelineGranularity, TimelineGrouping, Topic,
+    User, UserAchievement,
+};
+use domain::errors::DomainError;
+use domain::repositories::{
+    AchievementRepository, AnalyticsRepository, AssignmentRepository, ClassroomRepository,
+    ExamSessionRepository, ExamTypeRepository, GuardianLinkRepository, LessonRepository,
+    MockExamAttemptRepository, MockExamRepository, PermissionRepository, PracticeTestRepository,
+    RefreshTokenRepository, RegradeLock, RetakePolicyRepository, RoleRepository,
+    ScoreTableRepository, ScoringPolicyRepository, SubjectRepository, TestBookRepository,
+    TestBookSubjectRepository, TestResultRepository, TopicRepository, UserRepository,
+};
+
+use crate::services::{JwtOperations, PasswordOperations};
+
+/// Error returned for calls the tests do not expect.
+fn unexpected(call: &str) -> DomainError {
+    DomainError::DatabaseError(format!("unexpected call to {}", call))
+}
+
+/// Error returned by fakes configured to fail.
+pub fn unavailable() -> DomainError {
+    DomainError::DatabaseError("unavailable".to_string())
+}
+
+/// Implements a repository trait for a fake. Methods in the first block are
+/// written out; methods listed under `unexpected` fail with [`unexpected`].
+macro_rules! fake_repository {
+    (
+        impl $trait:ident for $fake:ty { $($implemented:tt)* }
+        unexpected { $(async fn $name:ident(&self $(, $arg:ident: $arg_ty:ty)*) -> $ret:ty;)* }
+    ) => {
+        #[async_trait::async_trait]
+        impl $trait for $fake {
+            $($implemented)*
+
+            $(
+                async fn $name(&self $(, $arg: $arg_ty)*) -> $ret {
+                    $(let _ = $arg;)*
+                    Err(unexpected(concat!(stringify!($trait), "::", stringify!($name))))
+                }
+            )*
+        }
+    };
+}
+
+#[derive(Default)]
+pub struct FakePracticeTestRepository {
+    pub practice_tests: Mutex<Vec<PracticeTest>>,
+    pub questions: Mutex<Vec<Question>>,
+    pub answer_key_revisions: Mutex<Vec<AnswerKeyRevision>>,
+    /// Answer key revisions marked as re-graded, by practice test
+    pub regraded_revisions: Mutex<Vec<(Uuid, i32)>>,
+}
+
+fake_repository! {
+    impl PracticeTestRepository for FakePracticeTestRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<PracticeTest>, DomainError> {
+            let practice_tests = self.practice_tests.lock().unwrap();
+            Ok(practice_tests.iter().find(|pt| pt.id == id).cloned())
+        }
+
+        async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<PracticeTest>, DomainError> {
+            let practice_tests = self.practice_tests.lock().unwrap();
+            Ok(practice_tests.iter().filter(|pt| ids.contains(&pt.id)).cloned().collect())
+        }
+
+        async fn find_by_test_book_id(
+            &self,
+            test_book_id: Uuid,
+        ) -> Result<Vec<PracticeTest>, DomainError> {
+            let practice_tests = self.practice_tests.lock().unwrap();
+            Ok(practice_tests
+                .iter()
+                .filter(|pt| pt.test_book_id == test_book_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn update(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError> {
+            let mut practice_tests = self.practice_tests.lock().unwrap();
+            if let Some(existing) = practice_tests.iter_mut().find(|pt| pt.id == practice_test.id) {
+                *existing = practice_test.clone();
+            }
+            Ok(practice_test.clone())
+        }
+
+        async fn find_questions(&self, practice_test_id: Uuid) -> Result<Vec<Question>, DomainError> {
+            let questions = self.questions.lock().unwrap();
+            Ok(questions
+                .iter()
+                .filter(|q| q.practice_test_id == practice_test_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn replace_questions(
+            &self,
+            practice_test: &PracticeTest,
+            questions: &[Question],
+        ) -> Result<(PracticeTest, Vec<Question>), DomainError> {
+            let updated = self.update(practice_test).await?;
+            let mut stored = self.questions.lock().unwrap();
+            stored.retain(|q| q.practice_test_id != practice_test.id);
+            stored.extend_from_slice(questions);
+            Ok((updated, questions.to_vec()))
+        }
+
+        async fn find_answer_key_revisions(
+            &self,
+            practice_test_id: Uuid,
+        ) -> Result<Vec<AnswerKeyRevision>, DomainError> {
+            let mut revisions: Vec<AnswerKeyRevision> = self
+                .answer_key_revisions
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.practice_test_id == practice_test_id)
+                .cloned()
+                .collect();
+            revisions.sort_by_key(|r| std::cmp::Reverse(r.revision));
+            Ok(revisions)
+        }
+
+        async fn lock_regrade(&self, _practice_test_id: Uuid) -> Result<RegradeLock, DomainError> {
+            Ok(RegradeLock::new(()))
+        }
+
+        async fn mark_answer_key_revision_regraded(
+            &self,
+            practice_test_id: Uuid,
+            revision: i32,
+        ) -> Result<(), DomainError> {
+            self.regraded_revisions
+                .lock()
+                .unwrap()
+                .push((practice_test_id, revision));
+            Ok(())
+        }
+    }
+    unexpected {
+        async fn create(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError>;
+        async fn find_in_test_books_started_by(&self, user_id: Uuid) -> Result<Vec<PracticeTest>, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn list_all(&self) -> Result<Vec<PracticeTest>, DomainError>;
+        async fn find_pending_answer_key_revisions(&self) -> Result<Vec<AnswerKeyRevision>, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeTestResultRepository {
+    pub results: Mutex<Vec<TestResult>>,
+    /// Makes cohort statistics queries fail
+    pub fail_net_distribution: bool,
+    /// Makes saving new results fail
+    pub fail_create: bool,
+}
+
+impl FakeTestResultRepository {
+    /// Attempts of a user at the given practice tests, oldest first per test.
+    fn attempts(&self, user_id: Uuid, practice_test_ids: &[Uuid]) -> Vec<TestResult> {
+        let mut attempts: Vec<TestResult> = self
+            .results
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|r| r.user_id == user_id && practice_test_ids.contains(&r.practice_test_id))
+            .filter(|r| r.mock_exam_attempt_id.is_none())
+            .cloned()
+            .collect();
+        attempts.sort_by_key(|r| (r.practice_test_id, r.solved_at));
+        attempts
+    }
+}
+
+fake_repository! {
+    impl TestResultRepository for FakeTestResultRepository {
+        async fn create(&self, test_result: &TestResult) -> Result<TestResult, DomainError> {
+            if self.fail_create {
+                return Err(unavailable());
+            }
+            self.results.lock().unwrap().push(test_result.clone());
+            Ok(test_result.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<TestResult>, DomainError> {
+            let results = self.results.lock().unwrap();
+            Ok(results.iter().find(|r| r.id == id).cloned())
+        }
+
+        async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestResult>, DomainError> {
+            let results = self.results.lock().unwrap();
+            Ok(results.iter().filter(|r| ids.contains(&r.id)).cloned().collect())
+        }
+
+        async fn find_user_ids_with_result(
+            &self,
+            practice_test_id: Uuid,
+            user_ids: &[Uuid],
+        ) -> Result<Vec<Uuid>, DomainError> {
+            let results = self.results.lock().unwrap();
+            Ok(results
+                .iter()
+                .filter(|r| r.practice_test_id == practice_test_id && user_ids.contains(&r.user_id))
+                .map(|r| r.user_id)
+                .collect())
+        }
+
+        async fn find_attempts_by_user_and_practice_test(
+            &self,
+            user_id: Uuid,
+            practice_test_id: Uuid,
+        ) -> Result<Vec<TestResult>, DomainError> {
+            Ok(self.attempts(user_id, &[practice_test_id]))
+        }
+
+        async fn find_results_by_user_and_practice_tests(
+            &self,
+            user_id: Uuid,
+            practice_test_ids: &[Uuid],
+        ) -> Result<Vec<TestResult>, DomainError> {
+            let mut results: Vec<TestResult> = self
+                .results
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| r.user_id == user_id && practice_test_ids.contains(&r.practice_test_id))
+                .cloned()
+                .collect();
+            results.sort_by_key(|r| (r.practice_test_id, r.solved_at));
+            Ok(results)
+        }
+
+        async fn find_latest_by_user_and_practice_tests(
+            &self,
+            user_id: Uuid,
+            practice_test_ids: &[Uuid],
+        ) -> Result<Vec<TestResult>, DomainError> {
+            let mut latest: HashMap<Uuid, TestResult> = HashMap::new();
+            for attempt in self.attempts(user_id, practice_test_ids) {
+                latest.insert(attempt.practice_test_id, attempt);
+            }
+            Ok(latest.into_values().collect())
+        }
+
+        async fn find_net_distribution(
+            &self,
+            _practice_test_id: Uuid,
+        ) -> Result<Vec<NetDistributionBucket>, DomainError> {
+            if self.fail_net_distribution {
+                return Err(unavailable());
+            }
+            Ok(Vec::new())
+        }
+
+        async fn find_outdated_by_practice_test(
+            &self,
+            practice_test_id: Uuid,
+            answer_key_revision: i32,
+        ) -> Result<Vec<TestResult>, DomainError> {
+            let results = self.results.lock().unwrap();
+            Ok(results
+                .iter()
+                .filter(|r| {
+                    r.practice_test_id == practice_test_id
+                        && r.answer_key_revision < answer_key_revision
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn save_regrade(
+            &self,
+            test_result: &TestResult,
+            _previous: Option<&TestResultHistory>,
+        ) -> Result<Option<TestResult>, DomainError> {
+            let mut results = self.results.lock().unwrap();
+            let Some(stored) = results.iter_mut().find(|r| {
+                r.id == test_result.id && r.answer_key_revision < test_result.answer_key_revision
+            }) else {
+                return Ok(None);
+            };
+            *stored = test_result.clone();
+            Ok(Some(test_result.clone()))
+        }
+
+        async fn list(
+            &self,
+            user_id: Option<Uuid>,
+            practice_test_id: Option<Uuid>,
+            page: u32,
+            per_page: u32,
+        ) -> Result<(Vec<TestResult>, u64), DomainError> {
+            let results: Vec<TestResult> = self
+                .results
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|r| user_id.is_none_or(|id| r.user_id == id))
+                .filter(|r| practice_test_id.is_none_or(|id| r.practice_test_id == id))
+                .cloned()
+                .collect();
+            let total = results.len() as u64;
+            let page = results
+                .into_iter()
+                .skip((page.saturating_sub(1) * per_page) as usize)
+                .take(per_page as usize)
+                .collect();
+            Ok((page, total))
+        }
+    }
+    unexpected {
+        async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<TestResult>, DomainError>;
+        async fn find_by_practice_test_id(&self, practice_test_id: Uuid) -> Result<Vec<TestResult>, DomainError>;
+        async fn find_by_user_and_practice_test(&self, user_id: Uuid, practice_test_id: Uuid) -> Result<Option<TestResult>, DomainError>;
+        async fn find_latest_by_user_and_practice_test(&self, user_id: Uuid, practice_test_id: Uuid) -> Result<Option<TestResult>, DomainError>;
+        async fn find_attempts_by_user_and_practice_tests(&self, user_id: Uuid, practice_test_ids: &[Uuid]) -> Result<Vec<TestResult>, DomainError>;
+        async fn find_test_book_progress(&self, user_id: Uuid, test_book_ids: &[Uuid]) -> Result<Vec<TestBookProgress>, DomainError>;
+        async fn find_history_by_test_result_id(&self, test_result_id: Uuid) -> Result<Vec<TestResultHistory>, DomainError>;
+        async fn find_leaderboard(&self, scope: LeaderboardScope, viewer_id: Uuid, page: u32, per_page: u32) -> Result<LeaderboardPage, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeClassroomRepository {
+    pub classrooms: Mutex<Vec<Classroom>>,
+    pub enrollments: Mutex<Vec<Enrollment>>,
+}
+
+fake_repository! {
+    impl ClassroomRepository for FakeClassroomRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Classroom>, DomainError> {
+            let classrooms = self.classrooms.lock().unwrap();
+            Ok(classrooms.iter().find(|c| c.id == id).cloned())
+        }
+
+        async fn is_enrolled(&self, classroom_id: Uuid, student_id: Uuid) -> Result<bool, DomainError> {
+            let enrollments = self.enrollments.lock().unwrap();
+            Ok(enrollments
+                .iter()
+                .any(|e| e.classroom_id == classroom_id && e.student_id == student_id))
+        }
+
+        async fn find_students(&self, _classroom_id: Uuid) -> Result<Vec<ClassroomStudent>, DomainError> {
+            Ok(Vec::new())
+        }
+    }
+    unexpected {
+        async fn create(&self, classroom: &Classroom) -> Result<Classroom, DomainError>;
+        async fn find_by_invite_code(&self, invite_code: &str) -> Result<Option<Classroom>, DomainError>;
+        async fn find_by_teacher(&self, teacher_id: Uuid) -> Result<Vec<Classroom>, DomainError>;
+        async fn find_by_student(&self, student_id: Uuid) -> Result<Vec<Classroom>, DomainError>;
+        async fn update(&self, classroom: &Classroom) -> Result<Classroom, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn enroll(&self, enrollment: &Enrollment) -> Result<bool, DomainError>;
+        async fn unenroll(&self, classroom_id: Uuid, student_id: Uuid) -> Result<bool, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeAssignmentRepository {
+    pub assignments: Mutex<Vec<Assignment>>,
+}
+
+fake_repository! {
+    impl AssignmentRepository for FakeAssignmentRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Assignment>, DomainError> {
+            let assignments = self.assignments.lock().unwrap();
+            Ok(assignments.iter().find(|a| a.id == id).cloned())
+        }
+
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+            self.assignments.lock().unwrap().retain(|a| a.id != id);
+            Ok(())
+        }
+
+        async fn find_summaries_by_classroom(
+            &self,
+            classroom_id: Uuid,
+        ) -> Result<Vec<AssignmentSummary>, DomainError> {
+            let assignments = self.assignments.lock().unwrap();
+            Ok(assignments
+                .iter()
+                .filter(|a| a.classroom_id == classroom_id)
+                .map(|a| AssignmentSummary {
+                    assignment: a.clone(),
+                    practice_test_name: String::new(),
+                    student_count: 0,
+                    submitted_count: 0,
+                })
+                .collect())
+        }
+
+        async fn find_submissions(
+            &self,
+            _assignment: &Assignment,
+        ) -> Result<Vec<AssignmentSubmission>, DomainError> {
+            Ok(Vec::new())
+        }
+    }
+    unexpected {
+        async fn create(&self, assignment: &Assignment) -> Result<Assignment, DomainError>;
+        async fn find_by_student(&self, student_id: Uuid) -> Result<Vec<StudentAssignment>, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeTestBookRepository {
+    pub test_books: Mutex<Vec<TestBook>>,
+}
+
+fake_repository! {
+    impl TestBookRepository for FakeTestBookRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<TestBook>, DomainError> {
+            let test_books = self.test_books.lock().unwrap();
+            Ok(test_books.iter().find(|tb| tb.id == id).cloned())
+        }
+
+        async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestBook>, DomainError> {
+            let test_books = self.test_books.lock().unwrap();
+            Ok(test_books.iter().filter(|tb| ids.contains(&tb.id)).cloned().collect())
+        }
+    }
+    unexpected {
+        async fn create(&self, test_book: &TestBook) -> Result<TestBook, DomainError>;
+        async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<TestBook>, DomainError>;
+        async fn find_by_exam_type_id(&self, exam_type_id: Uuid) -> Result<Vec<TestBook>, DomainError>;
+        async fn find_by_lesson_id(&self, lesson_id: Uuid) -> Result<Vec<TestBook>, DomainError>;
+        async fn find_by_exam_type_and_lesson(&self, exam_type_id: Uuid, lesson_id: Uuid) -> Result<Vec<TestBook>, DomainError>;
+        async fn update(&self, test_book: &TestBook) -> Result<TestBook, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn list_all(&self) -> Result<Vec<TestBook>, DomainError>;
+    }
+}
+
+/// Test book subject repository for paths that never read test book subjects.
+pub struct FakeTestBookSubjectRepository;
+
+fake_repository! {
+    impl TestBookSubjectRepository for FakeTestBookSubjectRepository {}
+    unexpected {
+        async fn add_subject(&self, test_book_id: Uuid, subject_id: Uuid) -> Result<(), DomainError>;
+        async fn remove_subject(&self, test_book_id: Uuid, subject_id: Uuid) -> Result<(), DomainError>;
+        async fn set_subjects(&self, test_book_id: Uuid, subject_ids: &[Uuid]) -> Result<(), DomainError>;
+        async fn find_subject_ids_by_test_book_id(&self, test_book_id: Uuid) -> Result<Vec<Uuid>, DomainError>;
+        async fn find_subject_ids_by_test_book_ids(&self, test_book_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>, DomainError>;
+        async fn find_test_book_ids_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<Uuid>, DomainError>;
+        async fn delete_by_test_book_id(&self, test_book_id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeScoringPolicyRepository {
+    pub policies: Mutex<Vec<ScoringPolicy>>,
+}
+
+fake_repository! {
+    impl ScoringPolicyRepository for FakeScoringPolicyRepository {
+        async fn find_current_by_exam_type(
+            &self,
+            exam_type_id: Uuid,
+        ) -> Result<Option<ScoringPolicy>, DomainError> {
+            let policies = self.policies.lock().unwrap();
+            Ok(policies
+                .iter()
+                .filter(|p| p.exam_type_id == exam_type_id)
+                .max_by_key(|p| p.version)
+                .cloned())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<ScoringPolicy>, DomainError> {
+            let policies = self.policies.lock().unwrap();
+            Ok(policies.iter().find(|p| p.id == id).cloned())
+        }
+    }
+    unexpected {
+        async fn create(&self, policy: &ScoringPolicy) -> Result<ScoringPolicy, DomainError>;
+        async fn list_by_exam_type(&self, exam_type_id: Uuid) -> Result<Vec<ScoringPolicy>, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeExamTypeRepository {
+    pub exam_types: Mutex<Vec<ExamType>>,
+}
+
+fake_repository! {
+    impl ExamTypeRepository for FakeExamTypeRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamType>, DomainError> {
+            let exam_types = self.exam_types.lock().unwrap();
+            Ok(exam_types.iter().find(|e| e.id == id).cloned())
+        }
+
+        async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<ExamType>, DomainError> {
+            let exam_types = self.exam_types.lock().unwrap();
+            Ok(exam_types.iter().filter(|e| ids.contains(&e.id)).cloned().collect())
+        }
+    }
+    unexpected {
+        async fn create(&self, exam_type: &ExamType) -> Result<ExamType, DomainError>;
+        async fn find_by_name(&self, name: &str) -> Result<Option<ExamType>, DomainError>;
+        async fn update(&self, exam_type: &ExamType) -> Result<ExamType, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn list_all(&self) -> Result<Vec<ExamType>, DomainError>;
+    }
+}
+
+/// Lesson repository without any lessons.
+pub struct FakeLessonRepository;
+
+fake_repository! {
+    impl LessonRepository for FakeLessonRepository {
+        async fn list_all(&self) -> Result<Vec<Lesson>, DomainError> {
+            Ok(Vec::new())
+        }
+    }
+    unexpected {
+        async fn create(&self, lesson: &Lesson) -> Result<Lesson, DomainError>;
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Lesson>, DomainError>;
+        async fn find_by_name(&self, name: &str) -> Result<Option<Lesson>, DomainError>;
+        async fn update(&self, lesson: &Lesson) -> Result<Lesson, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeScoreTableRepository {
+    pub tables: Mutex<Vec<ScoreTable>>,
+}
+
+fake_repository! {
+    impl ScoreTableRepository for FakeScoreTableRepository {
+        async fn find_latest_by_exam_type(&self, exam_type_id: Uuid) -> Result<Option<ScoreTable>, DomainError> {
+            let tables = self.tables.lock().unwrap();
+            Ok(tables
+                .iter()
+                .filter(|t| t.exam_type_id == exam_type_id)
+                .max_by_key(|t| t.year)
+                .cloned())
+        }
+    }
+    unexpected {
+        async fn save(&self, score_table: &ScoreTable) -> Result<ScoreTable, DomainError>;
+        async fn find_by_exam_type_and_year(&self, exam_type_id: Uuid, year: i32) -> Result<Option<ScoreTable>, DomainError>;
+        async fn list_by_exam_type(&self, exam_type_id: Uuid) -> Result<Vec<ScoreTable>, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeSubjectRepository {
+    pub subjects: Mutex<Vec<Subject>>,
+}
+
+fake_repository! {
+    impl SubjectRepository for FakeSubjectRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Subject>, DomainError> {
+            let subjects = self.subjects.lock().unwrap();
+            Ok(subjects.iter().find(|s| s.id == id).cloned())
+        }
+
+        async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Subject>, DomainError> {
+            let subjects = self.subjects.lock().unwrap();
+            Ok(subjects.iter().filter(|s| ids.contains(&s.id)).cloned().collect())
+        }
+    }
+    unexpected {
+        async fn create(&self, subject: &Subject) -> Result<Subject, DomainError>;
+        async fn find_by_exam_type_id(&self, exam_type_id: Uuid) -> Result<Vec<Subject>, DomainError>;
+        async fn find_by_lesson_and_exam_type(&self, lesson_id: Uuid, exam_type_id: Uuid) -> Result<Vec<Subject>, DomainError>;
+        async fn update(&self, subject: &Subject) -> Result<Subject, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn list_all(&self) -> Result<Vec<Subject>, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeTopicRepository {
+    pub topics: Mutex<Vec<Topic>>,
+}
+
+fake_repository! {
+    impl TopicRepository for FakeTopicRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Topic>, DomainError> {
+            let topics = self.topics.lock().unwrap();
+            Ok(topics.iter().find(|t| t.id == id).cloned())
+        }
+
+        async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<Topic>, DomainError> {
+            let topics = self.topics.lock().unwrap();
+            Ok(topics.iter().filter(|t| t.subject_id == subject_id).cloned().collect())
+        }
+
+        async fn update_placements(&self, topics: &[Topic]) -> Result<(), DomainError> {
+            let mut stored = self.topics.lock().unwrap();
+            for topic in topics {
+                if let Some(existing) = stored.iter_mut().find(|t| t.id == topic.id) {
+                    existing.parent_id = topic.parent_id;
+                    existing.position = topic.position;
+                }
+            }
+            Ok(())
+        }
+    }
+    unexpected {
+        async fn create(&self, topic: &Topic) -> Result<Topic, DomainError>;
+        async fn update(&self, topic: &Topic) -> Result<Topic, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+/// Retake policy repository without custom policies, so the standard policy applies.
+pub struct FakeRetakePolicyRepository;
+
+fake_repository! {
+    impl RetakePolicyRepository for FakeRetakePolicyRepository {
+        async fn find_effective(
+            &self,
+            _test_book_id: Uuid,
+            _exam_type_id: Uuid,
+        ) -> Result<Option<RetakePolicy>, DomainError> {
+            Ok(None)
+        }
+    }
+    unexpected {
+        async fn save(&self, policy: &RetakePolicy) -> Result<RetakePolicy, DomainError>;
+        async fn find_by_scope(&self, scope: RetakePolicyScope) -> Result<Option<RetakePolicy>, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeExamSessionRepository {
+    pub sessions: Mutex<Vec<ExamSession>>,
+}
+
+fake_repository! {
+    impl ExamSessionRepository for FakeExamSessionRepository {
+        async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<ExamSession>, DomainError> {
+            let sessions = self.sessions.lock().unwrap();
+            Ok(sessions.iter().filter(|s| s.is_expired(now)).cloned().collect())
+        }
+
+        async fn submit(
+            &self,
+            session: &ExamSession,
+            _test_result: &TestResult,
+        ) -> Result<bool, DomainError> {
+            let mut sessions = self.sessions.lock().unwrap();
+            let Some(existing) = sessions
+                .iter_mut()
+                .find(|s| s.id == session.id && s.status == ExamSessionStatus::InProgress)
+            else {
+                return Ok(false);
+            };
+            *existing = session.clone();
+            Ok(true)
+        }
+    }
+    unexpected {
+        async fn create(&self, session: &ExamSession) -> Result<ExamSession, DomainError>;
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamSession>, DomainError>;
+        async fn find_in_progress_by_user_and_practice_test(&self, user_id: Uuid, practice_test_id: Uuid) -> Result<Option<ExamSession>, DomainError>;
+        async fn save_answers(&self, session: &ExamSession) -> Result<bool, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeMockExamRepository {
+    pub mock_exams: Mutex<Vec<MockExam>>,
+}
+
+fake_repository! {
+    impl MockExamRepository for FakeMockExamRepository {
+        async fn create(&self, mock_exam: &MockExam) -> Result<MockExam, DomainError> {
+            self.mock_exams.lock().unwrap().push(mock_exam.clone());
+            Ok(mock_exam.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<MockExam>, DomainError> {
+            let mock_exams = self.mock_exams.lock().unwrap();
+            Ok(mock_exams.iter().find(|m| m.id == id).cloned())
+        }
+
+        async fn list(&self, exam_type_id: Option<Uuid>) -> Result<Vec<MockExam>, DomainError> {
+            let mock_exams = self.mock_exams.lock().unwrap();
+            Ok(mock_exams
+                .iter()
+                .filter(|m| exam_type_id.is_none_or(|id| m.exam_type_id == id))
+                .cloned()
+                .collect())
+        }
+    }
+    unexpected {
+        async fn update(&self, mock_exam: &MockExam) -> Result<MockExam, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeMockExamAttemptRepository {
+    pub attempts: Mutex<Vec<MockExamAttempt>>,
+    /// Receives the section results of submitted attempts
+    pub test_results: Arc<FakeTestResultRepository>,
+}
+
+fake_repository! {
+    impl MockExamAttemptRepository for FakeMockExamAttemptRepository {
+        async fn create(&self, attempt: &MockExamAttempt) -> Result<MockExamAttempt, DomainError> {
+            self.attempts.lock().unwrap().push(attempt.clone());
+            Ok(attempt.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<MockExamAttempt>, DomainError> {
+            let attempts = self.attempts.lock().unwrap();
+            Ok(attempts.iter().find(|a| a.id == id).cloned())
+        }
+
+        async fn find_in_progress_by_user_and_mock_exam(
+            &self,
+            user_id: Uuid,
+            mock_exam_id: Uuid,
+        ) -> Result<Option<MockExamAttempt>, DomainError> {
+            let attempts = self.attempts.lock().unwrap();
+            Ok(attempts
+                .iter()
+                .find(|a| a.user_id == user_id && a.mock_exam_id == mock_exam_id && a.is_in_progress())
+                .cloned())
+        }
+
+        async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<MockExamAttempt>, DomainError> {
+            let attempts = self.attempts.lock().unwrap();
+            Ok(attempts.iter().rev().filter(|a| a.user_id == user_id).cloned().collect())
+        }
+
+        async fn save_answers(&self, attempt: &MockExamAttempt) -> Result<bool, DomainError> {
+            let mut attempts = self.attempts.lock().unwrap();
+            let Some(existing) = attempts
+                .iter_mut()
+                .find(|a| a.id == attempt.id && a.is_in_progress())
+            else {
+                return Ok(false);
+            };
+            *existing = attempt.clone();
+            Ok(true)
+        }
+
+        async fn submit(
+            &self,
+            attempt: &MockExamAttempt,
+            test_results: &[TestResult],
+        ) -> Result<bool, DomainError> {
+            if !self.save_answers(attempt).await? {
+                return Ok(false);
+            }
+            self.test_results
+                .results
+                .lock()
+                .unwrap()
+                .extend_from_slice(test_results);
+            Ok(true)
+        }
+    }
+    unexpected {
+        async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<MockExamAttempt>, DomainError>;
+    }
+}
+
+/// Achievement repository without achievements to award.
+#[derive(Default)]
+pub struct FakeAchievementRepository {
+    /// Makes every query fail
+    pub fail: bool,
+}
+
+impl FakeAchievementRepository {
+    fn check(&self) -> Result<(), DomainError> {
+        if self.fail {
+            return Err(unavailable());
+        }
+        Ok(())
+    }
+}
+
+fake_repository! {
+    impl AchievementRepository for FakeAchievementRepository {
+        async fn list_all(&self) -> Result<Vec<Achievement>, DomainError> {
+            self.check().map(|_| Vec::new())
+        }
+
+        async fn find_awarded(&self, _user_id: Uuid) -> Result<Vec<UserAchievement>, DomainError> {
+            self.check().map(|_| Vec::new())
+        }
+
+        async fn award(
+            &self,
+            _user_id: Uuid,
+            _achievement_ids: &[Uuid],
+            _awarded_at: DateTime<Utc>,
+        ) -> Result<(), DomainError> {
+            self.check()
+        }
+
+        async fn find_facts(&self, _user_id: Uuid) -> Result<AchievementFacts, DomainError> {
+            self.check().map(|_| AchievementFacts::default())
+        }
+    }
+    unexpected {
+        async fn create(&self, achievement: &Achievement) -> Result<Achievement, DomainError>;
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Achievement>, DomainError>;
+        async fn find_by_code(&self, code: &str) -> Result<Option<Achievement>, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeGuardianLinkRepository {
+    pub links: Mutex<Vec<GuardianLink>>,
+}
+
+fake_repository! {
+    impl GuardianLinkRepository for FakeGuardianLinkRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<GuardianLink>, DomainError> {
+            let links = self.links.lock().unwrap();
+            Ok(links.iter().find(|l| l.id == id).cloned())
+        }
+
+        async fn find_by_pair(
+            &self,
+            guardian_id: Uuid,
+            student_id: Uuid,
+        ) -> Result<Option<GuardianLink>, DomainError> {
+            let links = self.links.lock().unwrap();
+            Ok(links
+                .iter()
+                .find(|l| l.guardian_id == guardian_id && l.student_id == student_id)
+                .cloned())
+        }
+
+        async fn update(&self, link: &GuardianLink) -> Result<GuardianLink, DomainError> {
+            let mut links = self.links.lock().unwrap();
+            if let Some(existing) = links.iter_mut().find(|l| l.id == link.id) {
+                *existing = link.clone();
+            }
+            Ok(link.clone())
+        }
+    }
+    unexpected {
+        async fn create(&self, link: &GuardianLink) -> Result<GuardianLink, DomainError>;
+        async fn find_by_guardian(&self, guardian_id: Uuid) -> Result<Vec<GuardianLinkDetail>, DomainError>;
+        async fn find_by_student(&self, student_id: Uuid) -> Result<Vec<GuardianLinkDetail>, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeUserRepository {
+    pub users: Mutex<Vec<User>>,
+    /// Role names per user ID
+    pub roles: Mutex<HashMap<Uuid, Vec<String>>>,
+}
+
+fake_repository! {
+    impl UserRepository for FakeUserRepository {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, DomainError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.iter().find(|u| u.id == id).cloned())
+        }
+
+        async fn find_by_email(&self, email: &str) -> Result<Option<User>, DomainError> {
+            let users = self.users.lock().unwrap();
+            Ok(users.iter().find(|u| u.email == email).cloned())
+        }
+
+        async fn find_by_student_numbers(
+            &self,
+            student_numbers: &[String],
+        ) -> Result<Vec<User>, DomainError> {
+            let users = self.users.lock().unwrap();
+            Ok(users
+                .iter()
+                .filter(|u| {
+                    u.student_number
+                        .as_ref()
+                        .is_some_and(|number| student_numbers.contains(number))
+                })
+                .cloned()
+                .collect())
+        }
+
+        async fn get_user_roles(&self, user_id: Uuid) -> Result<Vec<String>, DomainError> {
+            let roles = self.roles.lock().unwrap();
+            Ok(roles.get(&user_id).cloned().unwrap_or_default())
+        }
+    }
+    unexpected {
+        async fn create(&self, user: &User) -> Result<User, DomainError>;
+        async fn find_by_username(&self, username: &str) -> Result<Option<User>, DomainError>;
+        async fn update(&self, user: &User) -> Result<User, DomainError>;
+        async fn soft_delete(&self, id: Uuid, deleted_by: Uuid) -> Result<(), DomainError>;
+        async fn restore(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn hard_delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn email_exists(&self, email: &str) -> Result<bool, DomainError>;
+        async fn username_exists(&self, username: &str) -> Result<bool, DomainError>;
+        async fn list(&self, page: u32, per_page: u32, include_deleted: bool) -> Result<(Vec<User>, u64), DomainError>;
+        async fn assign_role(&self, user_id: Uuid, role_id: Uuid, assigned_by: Option<Uuid>) -> Result<(), DomainError>;
+        async fn remove_role(&self, user_id: Uuid, role_id: Uuid) -> Result<(), DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakeRefreshTokenRepository {
+    pub tokens: Mutex<Vec<RefreshToken>>,
+}
+
+fake_repository! {
+    impl RefreshTokenRepository for FakeRefreshTokenRepository {
+        async fn create(&self, token: &RefreshToken) -> Result<RefreshToken, DomainError> {
+            self.tokens.lock().unwrap().push(token.clone());
+            Ok(token.clone())
+        }
+
+        async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>, DomainError> {
+            let tokens = self.tokens.lock().unwrap();
+            Ok(tokens.iter().find(|t| t.token_hash == token_hash).cloned())
+        }
+
+        async fn rotate(&self, old_token_id: Uuid, new_token_id: Uuid) -> Result<(), DomainError> {
+            let mut tokens = self.tokens.lock().unwrap();
+            if let Some(token) = tokens.iter_mut().find(|t| t.id == old_token_id) {
+                token.rotate(new_token_id);
+            }
+            Ok(())
+        }
+    }
+    unexpected {
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<RefreshToken>, DomainError>;
+        async fn revoke(&self, id: Uuid, reason: &str) -> Result<(), DomainError>;
+        async fn revoke_all_for_user(&self, user_id: Uuid, reason: &str) -> Result<u64, DomainError>;
+        async fn delete_expired(&self) -> Result<u64, DomainError>;
+        async fn count_active_for_user(&self, user_id: Uuid) -> Result<u64, DomainError>;
+    }
+}
+
+/// Role repository for paths that never read roles.
+pub struct FakeRoleRepository;
+
+fake_repository! {
+    impl RoleRepository for FakeRoleRepository {}
+    unexpected {
+        async fn create(&self, role: &Role) -> Result<Role, DomainError>;
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Role>, DomainError>;
+        async fn find_by_name(&self, name: &str) -> Result<Option<Role>, DomainError>;
+        async fn list(&self) -> Result<Vec<Role>, DomainError>;
+        async fn update(&self, role: &Role) -> Result<Role, DomainError>;
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+        async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<Role>, DomainError>;
+    }
+}
+
+#[derive(Default)]
+pub struct FakePermissionRepository {
+    /// Permission names per role name
+    pub role_permissions: Mutex<HashMap<String, Vec<String>>>,
+    /// Role names per user ID, as assigned in the user repository
+    pub user_roles: Mutex<HashMap<Uuid, Vec<String>>>,
+}
+
+fake_repository! {
+    impl PermissionRepository for FakePermissionRepository {
+        async fn find_names_by_user_id(&self, user_id: Uuid) -> Result<Vec<String>, DomainError> {
+            let role_permissions = self.role_permissions.lock().unwrap();
+            let user_roles = self.user_roles.lock().unwrap();
+            let mut names: Vec<String> = user_roles
+                .get(&user_id)
+                .into_iter()
+                .flatten()
+                .filter_map(|role| role_permissions.get(role))
+                .flatten()
+                .cloned()
+                .collect();
+            names.sort();
+            names.dedup();
+            Ok(names)
+        }
+    }
+    unexpected {
+        async fn list(&self) -> Result<Vec<Permission>, DomainError>;
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Permission>, DomainError>;
+        async fn find_by_role_id(&self, role_id: Uuid) -> Result<Vec<Permission>, DomainError>;
+        async fn grant_to_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<bool, DomainError>;
+        async fn revoke_from_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<bool, DomainError>;
+    }
+}
+
+/// Roles and permissions an access token was issued with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IssuedAccessToken {
+    pub user_id: Uuid,
+    pub roles: Vec<String>,
+    pub permissions: Vec<String>,
+}
+
+/// JWT service recording the claims of every access token it issues.
+#[derive(Default)]
+pub struct FakeJwtService {
+    pub issued: Mutex<Vec<IssuedAccessToken>>,
+}
+
+impl JwtOperations for FakeJwtService {
+    fn generate_access_token(
+        &self,
+        user_id: Uuid,
+        roles: Vec<String>,
+        permissions: Vec<String>,
+    ) -> Result<String, String> {
+        let mut issued = self.issued.lock().unwrap();
+        issued.push(IssuedAccessToken {
+            user_id,
+            roles,
+            permissions,
+        });
+        Ok(format!("access-token-{}", issued.len()))
+    }
+
+    fn access_token_expiration_minutes(&self) -> i64 {
+        15
+    }
+
+    fn refresh_token_expiration_days(&self) -> i64 {
+        7
+    }
+}
+
+/// Password service storing passwords as they are.
+pub struct FakePasswordService;
+
+impl PasswordOperations for FakePasswordService {
+    fn hash_password(&self, password: &str) -> Result<String, String> {
+        Ok(password.to_string())
+    }
+
+    fn verify_password(&self, password: &str, hash: &str) -> Result<bool, String> {
+        Ok(password == hash)
+    }
+}
+
+/// Grouping, granularity and range of a progress point query.
+pub type ProgressQuery = (
+    TimelineGrouping,
+    TimelineGranularity,
+    Option<DateTime<Utc>>,
+    Option<DateTime<Utc>>,
+);
+
+#[derive(Default)]
+pub struct FakeAnalyticsRepository {
+    pub progress_points: Mutex<Vec<ProgressPoint>>,
+    /// Every progress point query received
+    pub progress_queries: Mutex<Vec<ProgressQuery>>,
+}
+
+fake_repository! {
+    impl AnalyticsRepository for FakeAnalyticsRepository {
+        async fn find_progress_points(
+            &self,
+            _user_id: Uuid,
+            grouping: TimelineGrouping,
+            granularity: TimelineGranularity,
+            from: Option<DateTime<Utc>>,
+            to: Option<DateTime<Utc>>,
+        ) -> Result<Vec<ProgressPoint>, DomainError> {
+            self.progress_queries
+                .lock()
+                .unwrap()
+                .push((grouping, granularity, from, to));
+            Ok(self.progress_points.lock().unwrap().clone())
+        }
+    }
+    unexpected {
+        async fn find_records_by_user(&self, user_id: Uuid, limit: u32) -> Result<Vec<AnalyticsRecord>, DomainError>;
+        async fn find_question_topics(&self, practice_test_ids: &[Uuid]) -> Result<Vec<QuestionTopic>, DomainError>;
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What an achievement rule measures; its threshold is in the rule's unit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AchievementRule {
+    /// Consecutive UTC days with at least one solved test
+    SolvingStreak,
+    /// Distinct practice tests solved
+    TestsSolved,
+    /// Test books with every practice test solved
+    TestBooksCompleted,
+    /// Best net on a single test, optionally limited to an exam type and a lesson
+    NetScore,
+}
+
+impl AchievementRule {
+    /// Returns the string stored in the database for this rule.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AchievementRule::SolvingStreak => "solving_streak",
+            AchievementRule::TestsSolved => "tests_solved",
+            AchievementRule::TestBooksCompleted => "test_books_completed",
+            AchievementRule::NetScore => "net_score",
+        }
+    }
+
+    /// Parses an achievement rule from its database representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "solving_streak" => Some(AchievementRule::SolvingStreak),
+            "tests_solved" => Some(AchievementRule::TestsSolved),
+            "test_books_completed" => Some(AchievementRule::TestBooksCompleted),
+            "net_score" => Some(AchievementRule::NetScore),
+            _ => None,
+        }
+    }
+}
+
+/// Achievement entity: a badge students earn by reaching a rule's threshold.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Achievement {
+    /// Unique identifier for the achievement
+    pub id: Uuid,
+    /// Stable identifier used by clients (e.g., "streak_7")
+    pub code: String,
+    /// Display name of the badge
+    pub name: String,
+    /// What the student has to do to earn it
+    pub description: String,
+    /// What the rule measures
+    pub rule: AchievementRule,
+    /// Value to reach, in the rule's unit
+    pub threshold: f64,
+    /// Only count tests of this exam type (net score rules)
+    pub exam_type_id: Option<Uuid>,
+    /// Only count tests of this lesson (net score rules)
+    pub lesson_id: Option<Uuid>,
+    /// Timestamp when the achievement was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl Achievement {
+    /// Creates a new achievement with the given details.
+    pub fn new(
+        code: String,
+        name: String,
+        description: String,
+        rule: AchievementRule,
+        threshold: f64,
+        exam_type_id: Option<Uuid>,
+        lesson_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            code,
+            name,
+            description,
+            rule,
+            threshold,
+            exam_type_id,
+            lesson_id,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// An achievement awarded to a user.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserAchievement {
+    pub user_id: Uuid,
+    pub achievement_id: Uuid,
+    pub awarded_at: DateTime<Utc>,
+}
+
+/// A user's best net on a single test of one exam type and lesson.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BestNet {
+    pub exam_type_id: Uuid,
+    pub lesson_id: Uuid,
+    pub best_net: f64,
+}
+
+/// What achievement rules are evaluated against, aggregated from a user's results.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AchievementFacts {
+    /// UTC days with at least one solved test, ascending
+    pub solve_days: Vec<NaiveDate>,
+    /// Distinct practice tests solved
+    pub tests_solved: i64,
+    /// Test books with every practice test solved
+    pub test_books_completed: i64,
+    pub best_nets: Vec<BestNet>,
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A user's test result together with the curriculum it belongs to.
+///
+/// Read model for analytics, joined from `test_results` through `practice_tests`,
+/// `subjects` and `lessons`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalyticsRecord {
+    pub result_id: Uuid,
+    pub practice_test_id: Uuid,
+    pub practice_test_name: String,
+    pub subject_id: Uuid,
+    pub subject_name: String,
+    pub lesson_id: Uuid,
+    pub lesson_name: String,
+    /// Answer key revision the result was graded against, in its stored form
+    pub answer_key: String,
+    pub user_answers: String,
+    pub correct_count: i32,
+    pub wrong_count: i32,
+    pub empty_count: i32,
+    pub net_score: f64,
+    pub solved_at: DateTime<Utc>,
+}
+
+/// Curriculum topic of a question, used to break results down by topic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionTopic {
+    pub practice_test_id: Uuid,
+    /// 1-based position of the question within its practice test
+    pub position: i32,
+    pub topic_id: Uuid,
+    pub topic_name: String,
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AnswerKey, QuestionOutcome, QuestionResult};
+
+/// Correct, wrong and empty answer counts of a graded answer sheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerCounts {
+    pub correct: i32,
+    pub wrong: i32,
+    pub empty: i32,
+}
+
+impl AnswerCounts {
+    /// Grades user answers against an answer key and counts the outcomes.
+    ///
+    /// Returns `None` if the answers and the key differ in length.
+    pub fn evaluate(answer_key: &AnswerKey, user_answers: &str) -> Option<Self> {
+        QuestionResult::grade_all(answer_key, user_answers).map(|results| Self::tally(&results))
+    }
+
+    /// Counts the outcomes of graded questions.
+    pub fn tally(results: &[QuestionResult]) -> Self {
+        let mut counts = Self {
+            correct: 0,
+            wrong: 0,
+            empty: 0,
+        };
+
+        for result in results {
+            match result.outcome {
+                QuestionOutcome::Correct => counts.correct += 1,
+                QuestionOutcome::Wrong => counts.wrong += 1,
+                QuestionOutcome::Empty => counts.empty += 1,
+            }
+        }
+
+        counts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::AnswerKeyEntry;
+
+    fn key(raw: &str) -> AnswerKey {
+        AnswerKey::parse(raw).unwrap()
+    }
+
+    #[test]
+    fn test_evaluate_counts_answers() {
+        let counts = AnswerCounts::evaluate(&key("ABCDE"), "AbX_ ").unwrap();
+
+        assert_eq!(
+            counts,
+            AnswerCounts {
+                correct: 2,
+                wrong: 1,
+                empty: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_evaluate_rejects_length_mismatch() {
+        assert!(AnswerCounts::evaluate(&key("ABCD"), "ABC").is_none());
+    }
+
+    #[test]
+    fn test_evaluate_rich_answer_key() {
+        let answer_key = AnswerKey::new(vec![
+            AnswerKeyEntry::Accepted {
+                options: vec!['B', 'D'],
+            },
+            AnswerKeyEntry::Accepted {
+                options: vec!['B', 'D'],
+            },
+            AnswerKeyEntry::Cancelled,
+            AnswerKeyEntry::AnyAnswer,
+            AnswerKeyEntry::AnyAnswer,
+        ])
+        .unwrap();
+
+        let counts = AnswerCounts::evaluate(&answer_key, "dA_E_").unwrap();
+
+        assert_eq!(
+            counts,
+            AnswerCounts {
+                correct: 3,
+                wrong: 1,
+                empty: 1
+            }
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+use crate::errors::DomainError;
+
+/// How a single question of an answer key is graded.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AnswerKeyEntry {
+    /// Any of the listed options is correct
+    Accepted { options: Vec<char> },
+    /// Cancelled (iptal) question; every candidate gets credit, even for an empty answer
+    Cancelled,
+    /// Any marked option is correct; an empty answer stays empty
+    AnyAnswer,
+}
+
+impl AnswerKeyEntry {
+    /// Validates the entry of the question at the given 1-based position,
+    /// normalizing options to sorted, unique uppercase letters.
+    pub fn normalize(self, position: usize) -> Result<Self, DomainError> {
+        match self {
+            AnswerKeyEntry::Accepted { options } => {
+                if options.is_empty() || !options.iter().all(char::is_ascii_alphabetic) {
+                    return Err(DomainError::ValidationError(format!(
+                        "Question {} must accept one or more option letters",
+                        position
+                    )));
+                }
+                let mut options: Vec<char> = options.iter().map(char::to_ascii_uppercase).collect();
+                options.sort_unstable();
+                options.dedup();
+                Ok(AnswerKeyEntry::Accepted { options })
+            }
+            other => Ok(other),
+        }
+    }
+}
+
+/// Answer key of a practice test, one entry per question.
+///
+/// Keys with a single correct option per question are stored as plain letters
+/// (e.g. "ABCD..."); any other key is stored as a JSON list of entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerKey {
+    entries: Vec<AnswerKeyEntry>,
+}
+
+impl AnswerKey {
+    /// Creates an answer key, normalizing options to uppercase.
+    pub fn new(entries: Vec<AnswerKeyEntry>) -> Result<Self, DomainError> {
+        if entries.is_empty() {
+            return Err(DomainError::ValidationError(
+                "Answer key must have at least one question".to_string(),
+            ));
+        }
+
+        let normalized = entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| entry.normalize(index + 1))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            entries: normalized,
+        })
+    }
+
+    /// Parses an answer key from its stored form: plain letters or a JSON list of entries.
+    pub fn parse(raw: &str) -> Result<Self, DomainError> {
+        let raw = raw.trim();
+        if raw.starts_with('[') {
+            let entries: Vec<AnswerKeyEntry> = serde_json::from_str(raw)
+                .map_err(|e| DomainError::ValidationError(format!("Invalid answer key: {}", e)))?;
+            return Self::new(entries);
+        }
+
+        Self::new(
+            raw.chars()
+                .map(|c| AnswerKeyEntry::Accepted { options: vec![c] })
+                .collect(),
+        )
+    }
+
+    /// Returns the stored form: plain letters when every question has a single
+    /// correct option, JSON otherwise.
+    pub fn encode(&self) -> String {
+        let plain: Option<String> = self
+            .entries
+            .iter()
+            .map(|entry| match entry {
+                AnswerKeyEntry::Accepted { options } if options.len() == 1 => Some(options[0]),
+                _ => None,
+            })
+            .collect();
+
+        match plain {
+            Some(letters) => letters,
+            None => serde_json::to_string(&self.entries)
+                .expect("answer key entries are always serializable"),
+        }
+    }
+
+    /// Returns the entries, one per question.
+    pub fn entries(&self) -> &[AnswerKeyEntry] {
+        &self.entries
+    }
+
+    /// Returns the number of questions.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if the key has no questions.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plain_key_round_trips() {
+        let key = AnswerKey::parse("abCD").unwrap();
+
+        assert_eq!(key.len(), 4);
+        assert_eq!(key.encode(), "ABCD");
+    }
+
+    #[test]
+    fn test_rich_key_round_trips_as_json() {
+        let key = AnswerKey::new(vec![
+            AnswerKeyEntry::Accepted {
+                options: vec!['d', 'B', 'B'],
+            },
+            AnswerKeyEntry::Cancelled,
+            AnswerKeyEntry::AnyAnswer,
+        ])
+        .unwrap();
+
+        assert_eq!(
+            key.entries()[0],
+            AnswerKeyEntry::Accepted {
+                options: vec!['B', 'D']
+            }
+        );
+        assert_eq!(AnswerKey::parse(&key.encode()).unwrap(), key);
+    }
+
+    #[test]
+    fn test_rejects_invalid_keys() {
+        assert!(AnswerKey::parse("").is_err());
+        assert!(AnswerKey::parse("AB1").is_err());
+        assert!(AnswerKey::new(vec![AnswerKeyEntry::Accepted { options: vec![] }]).is_err());
+    }
+}
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// AnswerKeyRevision entity recording one version of a practice test's answer key.
+///
+/// A new revision is stored whenever the answer key changes (e.g. after a publisher
+/// erratum); results graded with an older revision are re-graded against it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerKeyRevision {
+    /// Unique identifier for the revision
+    pub id: Uuid,
+    /// ID of the practice test the answer key belongs to
+    pub practice_test_id: Uuid,
+    /// Revision number, starting at 1 and increasing per practice test
+    pub revision: i32,
+    /// Answer key as of this revision
+    pub answer_key: String,
+    /// Timestamp when the revision was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when existing results were re-graded against this revision
+    pub regraded_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,135 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Assignment entity: a practice test a teacher assigns to a classroom as homework.
+///
+/// A student completes an assignment with their first result of the practice test
+/// solved after the assignment was created.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assignment {
+    /// Unique identifier for the assignment
+    pub id: Uuid,
+    /// ID of the classroom the test is assigned to
+    pub classroom_id: Uuid,
+    /// ID of the assigned practice test
+    pub practice_test_id: Uuid,
+    /// Deadline for solving the test
+    pub due_at: DateTime<Utc>,
+    /// Timestamp when the assignment was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl Assignment {
+    /// Creates a new assignment with the given details.
+    pub fn new(classroom_id: Uuid, practice_test_id: Uuid, due_at: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            classroom_id,
+            practice_test_id,
+            due_at,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+/// Where a student stands on an assignment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionStatus {
+    /// Not solved yet, still before the deadline
+    Pending,
+    /// Solved before the deadline
+    Completed,
+    /// Solved after the deadline
+    Late,
+    /// Not solved and the deadline has passed
+    Missing,
+}
+
+impl SubmissionStatus {
+    /// Returns the string representation of this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubmissionStatus::Pending => "pending",
+            SubmissionStatus::Completed => "completed",
+            SubmissionStatus::Late => "late",
+            SubmissionStatus::Missing => "missing",
+        }
+    }
+
+    /// Determines the status from when the student solved the test, if at all.
+    pub fn determine(
+        due_at: DateTime<Utc>,
+        solved_at: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Self {
+        match solved_at {
+            Some(solved_at) if solved_at <= due_at => SubmissionStatus::Completed,
+            Some(_) => SubmissionStatus::Late,
+            None if now <= due_at => SubmissionStatus::Pending,
+            None => SubmissionStatus::Missing,
+        }
+    }
+}
+
+/// The result a student submitted for an assignment, if any.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentSubmission {
+    pub student_id: Uuid,
+    pub username: String,
+    pub test_result_id: Option<Uuid>,
+    pub net_score: Option<f64>,
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+/// An assignment with its practice test and how many students completed it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentSummary {
+    pub assignment: Assignment,
+    pub practice_test_name: String,
+    pub student_count: i64,
+    /// Students with a result, on time or late
+    pub submitted_count: i64,
+}
+
+/// An assignment as seen by one of the students it was given to.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentAssignment {
+    pub assignment: Assignment,
+    pub classroom_name: String,
+    pub practice_test_name: String,
+    pub test_result_id: Option<Uuid>,
+    pub net_score: Option<f64>,
+    pub solved_at: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn test_submission_status() {
+        let due_at = Utc::now();
+        let before = due_at - Duration::hours(1);
+        let after = due_at + Duration::hours(1);
+
+        assert_eq!(
+            SubmissionStatus::determine(due_at, Some(before), after),
+            SubmissionStatus::Completed
+        );
+        assert_eq!(
+            SubmissionStatus::determine(due_at, Some(after), after),
+            SubmissionStatus::Late
+        );
+        assert_eq!(
+            SubmissionStatus::determine(due_at, None, before),
+            SubmissionStatus::Pending
+        );
+        assert_eq!(
+            SubmissionStatus::determine(due_at, None, after),
+            SubmissionStatus::Missing
+        );
+    }
+}
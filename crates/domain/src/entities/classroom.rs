@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Classroom entity: a teacher's group of students, joined with an invite code.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Classroom {
+    /// Unique identifier for the classroom
+    pub id: Uuid,
+    /// ID of the teacher who owns the classroom
+    pub teacher_id: Uuid,
+    /// Name of the classroom (e.g., "12-A Sayısal")
+    pub name: String,
+    /// Code students enter to join the classroom
+    pub invite_code: String,
+    /// Timestamp when the classroom was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the classroom was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Classroom {
+    /// Creates a new classroom with the given details.
+    pub fn new(teacher_id: Uuid, name: String, invite_code: String) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            teacher_id,
+            name,
+            invite_code,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// A student enrolled in a classroom.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Enrollment {
+    pub classroom_id: Uuid,
+    pub student_id: Uuid,
+    pub enrolled_at: DateTime<Utc>,
+}
+
+impl Enrollment {
+    /// Enrolls a student in a classroom now.
+    pub fn new(classroom_id: Uuid, student_id: Uuid) -> Self {
+        Self {
+            classroom_id,
+            student_id,
+            enrolled_at: Utc::now(),
+        }
+    }
+}
+
+/// An enrolled student with their username, for the teacher's roster.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClassroomStudent {
+    pub student_id: Uuid,
+    pub username: String,
+    pub enrolled_at: DateTime<Utc>,
+}
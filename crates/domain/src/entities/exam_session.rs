@@ -0,0 +1,196 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::PracticeTest;
+use crate::errors::DomainError;
+
+/// Lifecycle state of an exam session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExamSessionStatus {
+    /// Started and accepting answers
+    InProgress,
+    /// Submitted by the student
+    Submitted,
+    /// Submitted by the server when the time limit ran out
+    Expired,
+}
+
+impl ExamSessionStatus {
+    /// Returns the string representation of this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExamSessionStatus::InProgress => "in_progress",
+            ExamSessionStatus::Submitted => "submitted",
+            ExamSessionStatus::Expired => "expired",
+        }
+    }
+
+    /// Parses a status from its string representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "in_progress" => Some(ExamSessionStatus::InProgress),
+            "submitted" => Some(ExamSessionStatus::Submitted),
+            "expired" => Some(ExamSessionStatus::Expired),
+            _ => None,
+        }
+    }
+}
+
+/// ExamSession entity representing a student's timed attempt at a practice test.
+///
+/// The server owns the clock: the session starts when it is created, answers are
+/// autosaved while it is in progress, and it is submitted either by the student or,
+/// once `expires_at` has passed, by the server with the last saved answers.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExamSession {
+    /// Unique identifier for the session
+    pub id: Uuid,
+    /// ID of the user taking the test
+    pub user_id: Uuid,
+    /// ID of the practice test being taken
+    pub practice_test_id: Uuid,
+    /// Answers saved so far, one character per question ('_' for empty)
+    pub answers: String,
+    /// Current lifecycle state
+    pub status: ExamSessionStatus,
+    /// Timestamp when the session started
+    pub started_at: DateTime<Utc>,
+    /// Deadline derived from the test's time limit, `None` if untimed
+    pub expires_at: Option<DateTime<Utc>>,
+    /// Timestamp of the last autosave
+    pub last_saved_at: DateTime<Utc>,
+    /// Timestamp when the session was submitted
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// ID of the test result created on submission
+    pub test_result_id: Option<Uuid>,
+}
+
+impl ExamSession {
+    /// Starts a new session for a practice test with all answers empty.
+    pub fn start(user_id: Uuid, practice_test: &PracticeTest, now: DateTime<Utc>) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            practice_test_id: practice_test.id,
+            answers: "_".repeat(practice_test.question_count.max(0) as usize),
+            status: ExamSessionStatus::InProgress,
+            started_at: now,
+            expires_at: practice_test
+                .time_limit_minutes
+                .map(|minutes| now + Duration::minutes(minutes as i64)),
+            last_saved_at: now,
+            submitted_at: None,
+            test_result_id: None,
+        }
+    }
+
+    /// Returns true if the session still accepts answers.
+    pub fn is_in_progress(&self) -> bool {
+        self.status == ExamSessionStatus::InProgress
+    }
+
+    /// Returns true if the session is in progress but its time limit has run out.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.is_in_progress() && self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// Returns the seconds left before the session expires, `None` if untimed or finished.
+    pub fn remaining_seconds(&self, now: DateTime<Utc>) -> Option<i64> {
+        if !self.is_in_progress() {
+            return None;
+        }
+        self.expires_at
+            .map(|expires_at| (expires_at - now).num_seconds().max(0))
+    }
+
+    /// Autosaves partial answers.
+    pub fn save_answers(&mut self, answers: String, now: DateTime<Utc>) -> Result<(), DomainError> {
+        if !self.is_in_progress() || self.is_expired(now) {
+            return Err(DomainError::ValidationError(
+                "Exam session is no longer in progress".to_string(),
+            ));
+        }
+        if answers.chars().count() != self.answers.chars().count() {
+            return Err(DomainError::ValidationError(format!(
+                "Answers must have exactly {} characters",
+                self.answers.chars().count()
+            )));
+        }
+
+        self.answers = answers;
+        self.last_saved_at = now;
+        Ok(())
+    }
+
+    /// Marks the session as submitted and returns the submission time.
+    ///
+    /// An expired session is closed at its deadline, so late submissions never
+    /// count extra time.
+    pub fn finish(&mut self, test_result_id: Uuid, now: DateTime<Utc>) -> DateTime<Utc> {
+        let (status, submitted_at) = match self.expires_at {
+            Some(expires_at) if now >= expires_at => (ExamSessionStatus::Expired, expires_at),
+            _ => (ExamSessionStatus::Submitted, now),
+        };
+
+        self.status = status;
+        self.submitted_at = Some(submitted_at);
+        self.test_result_id = Some(test_result_id);
+        submitted_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn practice_test(time_limit_minutes: Option<i32>) -> PracticeTest {
+        PracticeTest::new(
+            "Deneme 1".to_string(),
+            1,
+            4,
+            "ABCD".to_string(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            time_limit_minutes,
+        )
+    }
+
+    #[test]
+    fn test_session_autosaves_until_expiry() {
+        let now = Utc::now();
+        let mut session = ExamSession::start(Uuid::new_v4(), &practice_test(Some(30)), now);
+
+        assert_eq!(session.answers, "____");
+        assert_eq!(session.remaining_seconds(now), Some(1800));
+        assert!(session.save_answers("AB__".to_string(), now).is_ok());
+        assert!(session.save_answers("AB".to_string(), now).is_err());
+
+        let late = now + Duration::minutes(31);
+        assert!(session.is_expired(late));
+        assert!(session.save_answers("ABC_".to_string(), late).is_err());
+        assert_eq!(session.answers, "AB__");
+    }
+
+    #[test]
+    fn test_late_submission_closes_at_deadline() {
+        let now = Utc::now();
+        let mut session = ExamSession::start(Uuid::new_v4(), &practice_test(Some(30)), now);
+
+        let submitted_at = session.finish(Uuid::new_v4(), now + Duration::minutes(45));
+
+        assert_eq!(session.status, ExamSessionStatus::Expired);
+        assert_eq!(submitted_at, now + Duration::minutes(30));
+    }
+
+    #[test]
+    fn test_untimed_session_never_expires() {
+        let now = Utc::now();
+        let mut session = ExamSession::start(Uuid::new_v4(), &practice_test(None), now);
+
+        assert!(!session.is_expired(now + Duration::days(7)));
+        session.finish(Uuid::new_v4(), now + Duration::days(7));
+        assert_eq!(session.status, ExamSessionStatus::Submitted);
+    }
+}
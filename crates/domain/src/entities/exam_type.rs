@@ -11,6 +11,8 @@ pub struct ExamType {
     pub name: String,
     /// Optional description of the exam type
     pub description: Option<String>,
+    /// Whether students may see answer keys before submitting a test
+    pub reveal_answer_keys: bool,
     /// Timestamp when the exam type was created
     pub created_at: DateTime<Utc>,
 }
@@ -22,8 +24,8 @@ impl ExamType {
             id: Uuid::new_v4(),
             name,
             description,
+            reveal_answer_keys: false,
             created_at: Utc::now(),
         }
     }
 }
-
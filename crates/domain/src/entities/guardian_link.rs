@@ -0,0 +1,105 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// State of a guardian link.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardianLinkStatus {
+    /// Invited by the guardian, waiting for the student
+    Pending,
+    /// Accepted by the student; the guardian can see their progress
+    Accepted,
+}
+
+impl GuardianLinkStatus {
+    /// Returns the string representation of this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GuardianLinkStatus::Pending => "pending",
+            GuardianLinkStatus::Accepted => "accepted",
+        }
+    }
+
+    /// Parses a status from its string representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "pending" => Some(GuardianLinkStatus::Pending),
+            "accepted" => Some(GuardianLinkStatus::Accepted),
+            _ => None,
+        }
+    }
+}
+
+/// GuardianLink entity: gives a parent or guardian read-only access to a student.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianLink {
+    /// Unique identifier for the link
+    pub id: Uuid,
+    /// ID of the guardian user
+    pub guardian_id: Uuid,
+    /// ID of the student user
+    pub student_id: Uuid,
+    /// Whether the student accepted the invitation
+    pub status: GuardianLinkStatus,
+    /// Timestamp when the guardian sent the invitation
+    pub invited_at: DateTime<Utc>,
+    /// Timestamp when the student accepted the invitation
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+impl GuardianLink {
+    /// Creates a pending invitation from a guardian to a student.
+    pub fn invite(guardian_id: Uuid, student_id: Uuid) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            guardian_id,
+            student_id,
+            status: GuardianLinkStatus::Pending,
+            invited_at: Utc::now(),
+            accepted_at: None,
+        }
+    }
+
+    /// Accepts the invitation on behalf of the student.
+    pub fn accept(&mut self) {
+        if self.status == GuardianLinkStatus::Pending {
+            self.status = GuardianLinkStatus::Accepted;
+            self.accepted_at = Some(Utc::now());
+        }
+    }
+
+    /// Checks if the guardian may see the student's progress.
+    pub fn is_accepted(&self) -> bool {
+        self.status == GuardianLinkStatus::Accepted
+    }
+}
+
+/// A guardian link with the usernames of both sides.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GuardianLinkDetail {
+    pub link: GuardianLink,
+    pub guardian_username: String,
+    pub student_username: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accept_invitation() {
+        let mut link = GuardianLink::invite(Uuid::new_v4(), Uuid::new_v4());
+        assert!(!link.is_accepted());
+        assert!(link.accepted_at.is_none());
+
+        link.accept();
+        let accepted_at = link.accepted_at;
+        assert!(link.is_accepted());
+        assert!(accepted_at.is_some());
+
+        // Accepting again keeps the original time
+        link.accept();
+        assert_eq!(link.accepted_at, accepted_at);
+    }
+}
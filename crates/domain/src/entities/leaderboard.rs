@@ -0,0 +1,83 @@
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What a leaderboard ranks users on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LeaderboardScope {
+    /// Net of each user's first attempt of a practice test
+    PracticeTest(Uuid),
+    /// Average first-attempt net over the practice tests of a test book
+    TestBook(Uuid),
+    /// Total first-attempt net of an exam type's practice tests first solved
+    /// within the week starting at `week_start`
+    ExamTypeWeek {
+        exam_type_id: Uuid,
+        week_start: DateTime<Utc>,
+    },
+}
+
+impl LeaderboardScope {
+    /// Creates the weekly scope of an exam type for the week (Monday to Sunday, UTC)
+    /// containing the given day.
+    pub fn exam_type_week(exam_type_id: Uuid, day: NaiveDate) -> Self {
+        let monday = day - Duration::days(day.weekday().num_days_from_monday() as i64);
+        LeaderboardScope::ExamTypeWeek {
+            exam_type_id,
+            week_start: monday.and_hms_opt(0, 0, 0).unwrap_or_default().and_utc(),
+        }
+    }
+
+    /// Returns the start and end of the ranked week, if the scope is weekly.
+    pub fn week(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        match self {
+            LeaderboardScope::ExamTypeWeek { week_start, .. } => {
+                Some((*week_start, *week_start + Duration::weeks(1)))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A user's position on a leaderboard.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardEntry {
+    /// 1-based rank; users with equal scores share a rank
+    pub rank: i64,
+    pub user_id: Uuid,
+    pub username: String,
+    pub score: f64,
+    /// Number of practice tests counted in the score
+    pub test_count: i64,
+}
+
+/// One page of a leaderboard together with the viewing user's own entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeaderboardPage {
+    pub entries: Vec<LeaderboardEntry>,
+    /// Number of ranked users
+    pub total_entries: u64,
+    /// The viewing user's entry, `None` if they have no score in the scope
+    pub viewer_entry: Option<LeaderboardEntry>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exam_type_week_starts_on_monday() {
+        let exam_type_id = Uuid::new_v4();
+        let sunday = NaiveDate::from_ymd_opt(2024, 3, 17).unwrap();
+
+        let scope = LeaderboardScope::exam_type_week(exam_type_id, sunday);
+        let (start, end) = scope.week().unwrap();
+
+        assert_eq!(
+            start.date_naive(),
+            NaiveDate::from_ymd_opt(2024, 3, 11).unwrap()
+        );
+        assert_eq!(end - start, Duration::weeks(1));
+        assert_eq!(LeaderboardScope::PracticeTest(exam_type_id).week(), None);
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::errors::DomainError;
+
+/// One practice test of a mock exam.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MockExamSection {
+    /// ID of the practice test forming the section
+    pub practice_test_id: Uuid,
+    /// 1-based order of the section within the mock exam
+    pub position: i32,
+}
+
+/// MockExam entity representing a full mock exam (deneme sınavı), e.g. a TYT
+/// deneme made of Türkçe, Sosyal, Matematik and Fen practice tests solved under
+/// one shared clock.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExam {
+    /// Unique identifier for the mock exam
+    pub id: Uuid,
+    /// Name of the mock exam (e.g., "TYT Deneme 1")
+    pub name: String,
+    /// ID of the exam type all sections belong to
+    pub exam_type_id: Uuid,
+    /// Time allowed for the whole mock exam in minutes
+    pub time_limit_minutes: i32,
+    /// Sections in solving order
+    pub sections: Vec<MockExamSection>,
+    /// Timestamp when the mock exam was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the mock exam was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl MockExam {
+    /// Creates a new mock exam with one section per practice test, in the given order.
+    pub fn new(
+        name: String,
+        exam_type_id: Uuid,
+        time_limit_minutes: i32,
+        practice_test_ids: Vec<Uuid>,
+    ) -> Result<Self, DomainError> {
+        Self::validate_time_limit(time_limit_minutes)?;
+        let now = Utc::now();
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            name,
+            exam_type_id,
+            time_limit_minutes,
+            sections: Self::build_sections(practice_test_ids)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Replaces the time limit of the mock exam.
+    pub fn set_time_limit(&mut self, time_limit_minutes: i32) -> Result<(), DomainError> {
+        Self::validate_time_limit(time_limit_minutes)?;
+        self.time_limit_minutes = time_limit_minutes;
+        Ok(())
+    }
+
+    /// Replaces the sections of the mock exam, in the given order.
+    pub fn set_sections(&mut self, practice_test_ids: Vec<Uuid>) -> Result<(), DomainError> {
+        self.sections = Self::build_sections(practice_test_ids)?;
+        Ok(())
+    }
+
+    /// Returns the practice test IDs of the sections in order.
+    pub fn practice_test_ids(&self) -> Vec<Uuid> {
+        self.sections.iter().map(|s| s.practice_test_id).collect()
+    }
+
+    fn validate_time_limit(time_limit_minutes: i32) -> Result<(), DomainError> {
+        if time_limit_minutes <= 0 {
+            return Err(DomainError::ValidationError(
+                "Mock exam time limit must be positive".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn build_sections(practice_test_ids: Vec<Uuid>) -> Result<Vec<MockExamSection>, DomainError> {
+        if practice_test_ids.is_empty() {
+            return Err(DomainError::ValidationError(
+                "A mock exam needs at least one section".to_string(),
+            ));
+        }
+
+        let mut seen = HashSet::new();
+        if let Some(duplicate) = practice_test_ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(DomainError::ValidationError(format!(
+                "Practice test {} appears in more than one section",
+                duplicate
+            )));
+        }
+
+        Ok(practice_test_ids
+            .into_iter()
+            .enumerate()
+            .map(|(index, practice_test_id)| MockExamSection {
+                practice_test_id,
+                position: index as i32 + 1,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_numbers_sections_in_order() {
+        let ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+
+        let mock_exam =
+            MockExam::new("TYT Deneme 1".to_string(), Uuid::new_v4(), 165, ids.clone()).unwrap();
+
+        assert_eq!(mock_exam.practice_test_ids(), ids);
+        assert_eq!(mock_exam.sections[1].position, 2);
+    }
+
+    #[test]
+    fn test_new_rejects_invalid_sections() {
+        let id = Uuid::new_v4();
+        let exam_type_id = Uuid::new_v4();
+
+        assert!(MockExam::new("Deneme".to_string(), exam_type_id, 165, vec![]).is_err());
+        assert!(MockExam::new("Deneme".to_string(), exam_type_id, 165, vec![id, id]).is_err());
+        assert!(MockExam::new("Deneme".to_string(), exam_type_id, 0, vec![id]).is_err());
+    }
+}
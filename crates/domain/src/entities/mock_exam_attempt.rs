@@ -0,0 +1,254 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{ExamSessionStatus, MockExam, PracticeTest, TestResult};
+use crate::errors::DomainError;
+
+/// Answers of one section of a mock exam attempt.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExamAttemptSection {
+    /// ID of the practice test forming the section
+    pub practice_test_id: Uuid,
+    /// 1-based order of the section within the mock exam
+    pub position: i32,
+    /// Answers saved so far, one character per question ('_' for empty)
+    pub answers: String,
+    /// Answer key revision of the practice test when the attempt started.
+    /// The section is graded against it, so a key edited meanwhile cannot
+    /// leave the saved answers without a key to match.
+    pub answer_key_revision: i32,
+    /// ID of the test result the section was graded into on submission
+    pub test_result_id: Option<Uuid>,
+}
+
+/// MockExamAttempt entity representing a student's attempt at a mock exam.
+///
+/// Works like an [`ExamSession`](super::ExamSession) spanning several practice tests:
+/// all sections share one clock, answers are autosaved per section, and on
+/// submission every section is graded into its own test result.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MockExamAttempt {
+    /// Unique identifier for the attempt
+    pub id: Uuid,
+    /// ID of the user taking the mock exam
+    pub user_id: Uuid,
+    /// ID of the mock exam being taken
+    pub mock_exam_id: Uuid,
+    /// Current lifecycle state
+    pub status: ExamSessionStatus,
+    /// Timestamp when the attempt started
+    pub started_at: DateTime<Utc>,
+    /// Deadline derived from the mock exam's time limit
+    pub expires_at: DateTime<Utc>,
+    /// Timestamp of the last autosave
+    pub last_saved_at: DateTime<Utc>,
+    /// Timestamp when the attempt was submitted
+    pub submitted_at: Option<DateTime<Utc>>,
+    /// Sections in solving order
+    pub sections: Vec<MockExamAttemptSection>,
+}
+
+impl MockExamAttempt {
+    /// Starts a new attempt with all answers empty.
+    ///
+    /// `practice_tests` must contain the practice test of every section of the mock exam.
+    pub fn start(
+        user_id: Uuid,
+        mock_exam: &MockExam,
+        practice_tests: &[PracticeTest],
+        now: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let sections = mock_exam
+            .sections
+            .iter()
+            .map(|section| {
+                let practice_test = practice_tests
+                    .iter()
+                    .find(|pt| pt.id == section.practice_test_id)
+                    .ok_or_else(|| {
+                        DomainError::ValidationError(format!(
+                            "Practice test {} of the mock exam is missing",
+                            section.practice_test_id
+                        ))
+                    })?;
+
+                Ok(MockExamAttemptSection {
+                    practice_test_id: section.practice_test_id,
+                    position: section.position,
+                    answers: "_".repeat(practice_test.question_count.max(0) as usize),
+                    answer_key_revision: practice_test.answer_key_revision,
+                    test_result_id: None,
+                })
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        Ok(Self {
+            id: Uuid::new_v4(),
+            user_id,
+            mock_exam_id: mock_exam.id,
+            status: ExamSessionStatus::InProgress,
+            started_at: now,
+            expires_at: now + Duration::minutes(mock_exam.time_limit_minutes as i64),
+            last_saved_at: now,
+            submitted_at: None,
+            sections,
+        })
+    }
+
+    /// Returns true if the attempt still accepts answers.
+    pub fn is_in_progress(&self) -> bool {
+        self.status == ExamSessionStatus::InProgress
+    }
+
+    /// Returns true if the attempt is in progress but its time limit has run out.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.is_in_progress() && now >= self.expires_at
+    }
+
+    /// Returns the seconds left before the attempt expires, `None` once finished.
+    pub fn remaining_seconds(&self, now: DateTime<Utc>) -> Option<i64> {
+        self.is_in_progress()
+            .then(|| (self.expires_at - now).num_seconds().max(0))
+    }
+
+    /// Autosaves the partial answers of one section.
+    pub fn save_answers(
+        &mut self,
+        practice_test_id: Uuid,
+        answers: String,
+        now: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        if !self.is_in_progress() || self.is_expired(now) {
+            return Err(DomainError::ValidationError(
+                "Mock exam attempt is no longer in progress".to_string(),
+            ));
+        }
+
+        let section = self
+            .sections
+            .iter_mut()
+            .find(|s| s.practice_test_id == practice_test_id)
+            .ok_or_else(|| {
+                DomainError::ValidationError(format!(
+                    "Practice test {} is not a section of this mock exam",
+                    practice_test_id
+                ))
+            })?;
+        if answers.chars().count() != section.answers.chars().count() {
+            return Err(DomainError::ValidationError(format!(
+                "Answers must have exactly {} characters",
+                section.answers.chars().count()
+            )));
+        }
+
+        section.answers = answers;
+        self.last_saved_at = now;
+        Ok(())
+    }
+
+    /// Marks the attempt as submitted with the test results of its sections and
+    /// returns the submission time.
+    ///
+    /// An expired attempt is closed at its deadline, so late submissions never
+    /// count extra time.
+    pub fn finish(&mut self, test_results: &[TestResult], now: DateTime<Utc>) -> DateTime<Utc> {
+        let (status, submitted_at) = if now >= self.expires_at {
+            (ExamSessionStatus::Expired, self.expires_at)
+        } else {
+            (ExamSessionStatus::Submitted, now)
+        };
+
+        for section in &mut self.sections {
+            section.test_result_id = test_results
+                .iter()
+                .find(|r| r.practice_test_id == section.practice_test_id)
+                .map(|r| r.id);
+        }
+
+        self.status = status;
+        self.submitted_at = Some(submitted_at);
+        submitted_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{AnswerCounts, ScoringPolicy};
+
+    fn practice_test(question_count: i32) -> PracticeTest {
+        PracticeTest::new(
+            "Deneme 1".to_string(),
+            1,
+            question_count,
+            "A".repeat(question_count as usize),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            None,
+        )
+    }
+
+    fn start(now: DateTime<Utc>) -> (MockExamAttempt, Vec<PracticeTest>) {
+        let practice_tests = vec![practice_test(4), practice_test(2)];
+        let mock_exam = MockExam::new(
+            "TYT Deneme 1".to_string(),
+            Uuid::new_v4(),
+            165,
+            practice_tests.iter().map(|pt| pt.id).collect(),
+        )
+        .unwrap();
+
+        let attempt =
+            MockExamAttempt::start(Uuid::new_v4(), &mock_exam, &practice_tests, now).unwrap();
+        (attempt, practice_tests)
+    }
+
+    #[test]
+    fn test_attempt_autosaves_each_section() {
+        let now = Utc::now();
+        let (mut attempt, practice_tests) = start(now);
+
+        assert_eq!(attempt.sections[0].answers, "____");
+        assert_eq!(attempt.sections[1].answers, "__");
+        assert!(attempt
+            .save_answers(practice_tests[1].id, "AB".to_string(), now)
+            .is_ok());
+        assert!(attempt
+            .save_answers(practice_tests[1].id, "ABC".to_string(), now)
+            .is_err());
+        assert!(attempt
+            .save_answers(Uuid::new_v4(), "AB".to_string(), now)
+            .is_err());
+
+        let late = now + Duration::minutes(166);
+        assert!(attempt
+            .save_answers(practice_tests[0].id, "AAAA".to_string(), late)
+            .is_err());
+        assert_eq!(attempt.sections[1].answers, "AB");
+    }
+
+    #[test]
+    fn test_finish_links_section_results() {
+        let now = Utc::now();
+        let (mut attempt, practice_tests) = start(now);
+        let policy = ScoringPolicy::standard(Uuid::new_v4());
+        let results: Vec<TestResult> = practice_tests
+            .iter()
+            .map(|pt| {
+                let counts = AnswerCounts {
+                    correct: 2,
+                    wrong: 0,
+                    empty: pt.question_count - 2,
+                };
+                TestResult::new(attempt.user_id, pt.id, "AA".to_string(), counts, &policy, 1)
+            })
+            .collect();
+
+        let submitted_at = attempt.finish(&results, now + Duration::minutes(200));
+
+        assert_eq!(attempt.status, ExamSessionStatus::Expired);
+        assert_eq!(submitted_at, attempt.expires_at);
+        assert_eq!(attempt.sections[1].test_result_id, Some(results[1].id));
+    }
+}
@@ -1,20 +1,69 @@
+mod achievement;
+mod analytics_record;
+mod answer_counts;
+mod answer_key;
+mod answer_key_revision;
+mod assignment;
+mod classroom;
+mod exam_session;
 mod exam_type;
+mod guardian_link;
+mod leaderboard;
 mod lesson;
+mod mock_exam;
+mod mock_exam_attempt;
+mod net_distribution;
+mod permission;
 mod practice_test;
+mod progress_point;
+mod question;
+mod question_result;
 mod refresh_token;
+mod retake_policy;
 mod role;
+mod score_table;
+mod scoring_policy;
+mod study_plan;
 mod subject;
 mod test_book;
+mod test_book_progress;
 mod test_result;
+mod test_result_history;
+mod topic;
 mod user;
 
+pub use achievement::{Achievement, AchievementFacts, AchievementRule, BestNet, UserAchievement};
+pub use analytics_record::{AnalyticsRecord, QuestionTopic};
+pub use answer_counts::AnswerCounts;
+pub use answer_key::{AnswerKey, AnswerKeyEntry};
+pub use answer_key_revision::AnswerKeyRevision;
+pub use assignment::{
+    Assignment, AssignmentSubmission, AssignmentSummary, StudentAssignment, SubmissionStatus,
+};
+pub use classroom::{Classroom, ClassroomStudent, Enrollment};
+pub use exam_session::{ExamSession, ExamSessionStatus};
 pub use exam_type::ExamType;
+pub use guardian_link::{GuardianLink, GuardianLinkDetail, GuardianLinkStatus};
+pub use leaderboard::{LeaderboardEntry, LeaderboardPage, LeaderboardScope};
 pub use lesson::Lesson;
+pub use mock_exam::{MockExam, MockExamSection};
+pub use mock_exam_attempt::{MockExamAttempt, MockExamAttemptSection};
+pub use net_distribution::NetDistributionBucket;
+pub use permission::Permission;
 pub use practice_test::PracticeTest;
-pub use refresh_token::RefreshToken;
+pub use progress_point::{ProgressPoint, TimelineGranularity, TimelineGrouping};
+pub use question::{Question, QuestionDifficulty};
+pub use question_result::{QuestionOutcome, QuestionResult};
+pub use refresh_token::{revocation_reasons, RefreshToken};
+pub use retake_policy::{AttemptScoring, RetakePolicy, RetakePolicyScope};
 pub use role::Role;
+pub use score_table::{LessonCoefficient, LessonScore, ScoreEstimate, ScoreTable};
+pub use scoring_policy::{RoundingMode, ScoringPolicy};
+pub use study_plan::{DailyActivity, GoalMetric, StudyGoal, StudyPlan};
 pub use subject::Subject;
 pub use test_book::TestBook;
+pub use test_book_progress::TestBookProgress;
 pub use test_result::TestResult;
+pub use test_result_history::TestResultHistory;
+pub use topic::{Topic, TopicNode, TopicTree};
 pub use user::User;
-
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// Number of first attempts of a practice test that scored one net.
+///
+/// Read model of the `practice_test_net_distribution` aggregate.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NetDistributionBucket {
+    pub net_score: f64,
+    pub first_attempt_count: i64,
+}
@@ -0,0 +1,16 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Permission entity: an action that roles can be granted, named like "tests:write".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Permission {
+    /// Unique identifier for the permission
+    pub id: Uuid,
+    /// Unique permission name in "resource:action" form
+    pub name: String,
+    /// Optional description of what the permission allows
+    pub description: Option<String>,
+    /// Timestamp when the permission was created
+    pub created_at: DateTime<Utc>,
+}
@@ -2,6 +2,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{AnswerKey, Question};
+use crate::errors::DomainError;
+
 /// PracticeTest entity representing a practice test within a test book.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PracticeTest {
@@ -13,12 +16,18 @@ pub struct PracticeTest {
     pub test_number: i32,
     /// Number of questions in this test
     pub question_count: i32,
-    /// Answer key as a string (e.g., "ABCDABCD..." or JSON)
+    /// Stored answer key: plain letters (e.g., "ABCDABCD...") or JSON, see [`AnswerKey`]
     pub answer_key: String,
+    /// Current answer key revision number, increased whenever the answer key changes
+    pub answer_key_revision: i32,
     /// ID of the test book this practice test belongs to
     pub test_book_id: Uuid,
     /// ID of the subject this practice test belongs to
     pub subject_id: Uuid,
+    /// Time allowed for an exam session in minutes, `None` if untimed
+    pub time_limit_minutes: Option<i32>,
+    /// Curriculum topic the practice test covers, if any
+    pub topic_id: Option<Uuid>,
     /// Timestamp when the practice test was created
     pub created_at: DateTime<Utc>,
 }
@@ -32,6 +41,7 @@ impl PracticeTest {
         answer_key: String,
         test_book_id: Uuid,
         subject_id: Uuid,
+        time_limit_minutes: Option<i32>,
     ) -> Self {
         Self {
             id: Uuid::new_v4(),
@@ -39,10 +49,78 @@ impl PracticeTest {
             test_number,
             question_count,
             answer_key,
+            answer_key_revision: 1,
             test_book_id,
             subject_id,
+            time_limit_minutes,
+            topic_id: None,
             created_at: Utc::now(),
         }
     }
+
+    /// Parses the stored answer key.
+    ///
+    /// Fails with [`DomainError::InvalidStoredAnswerKey`] for keys saved in a
+    /// format the parser no longer accepts, so callers can tell them apart from
+    /// infrastructure failures.
+    pub fn parsed_answer_key(&self) -> Result<AnswerKey, DomainError> {
+        AnswerKey::parse(&self.answer_key)
+            .map_err(|e| DomainError::InvalidStoredAnswerKey(self.id, e.to_string()))
+    }
+
+    /// Replaces the answer key, starting a new revision if it actually changed.
+    ///
+    /// Returns true if a new revision was started.
+    pub fn revise_answer_key(&mut self, answer_key: String) -> bool {
+        if answer_key == self.answer_key {
+            return false;
+        }
+        self.answer_key = answer_key;
+        self.answer_key_revision += 1;
+        true
+    }
+
+    /// Takes the answer key and question count from the test's questions.
+    ///
+    /// Returns true if a new answer key revision was started.
+    pub fn apply_questions(&mut self, questions: &[Question]) -> Result<bool, DomainError> {
+        let answer_key = Question::answer_key(questions)?;
+        self.question_count = answer_key.len() as i32;
+        Ok(self.revise_answer_key(answer_key.encode()))
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn practice_test(answer_key: &str) -> PracticeTest {
+        PracticeTest::new(
+            "Deneme 1".to_string(),
+            1,
+            4,
+            answer_key.to_string(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            None,
+        )
+    }
+
+    #[test]
+    fn test_parsed_answer_key_reports_legacy_key() {
+        // Saved before answer keys were validated: blanks and digits were accepted
+        let legacy = practice_test("AB_3");
+
+        match legacy.parsed_answer_key() {
+            Err(DomainError::InvalidStoredAnswerKey(id, _)) => assert_eq!(id, legacy.id),
+            other => panic!("expected InvalidStoredAnswerKey, got {:?}", other),
+        }
+        assert_eq!(
+            practice_test(" abcd\n")
+                .parsed_answer_key()
+                .unwrap()
+                .encode(),
+            "ABCD"
+        );
+    }
+}
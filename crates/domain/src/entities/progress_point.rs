@@ -0,0 +1,114 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Length of the time buckets of a progress timeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineGranularity {
+    Day,
+    Week,
+    Month,
+}
+
+impl TimelineGranularity {
+    /// Returns the string representation, also the PostgreSQL `date_trunc` field.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineGranularity::Day => "day",
+            TimelineGranularity::Week => "week",
+            TimelineGranularity::Month => "month",
+        }
+    }
+
+    /// Parses a granularity from its string representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "day" => Some(TimelineGranularity::Day),
+            "week" => Some(TimelineGranularity::Week),
+            "month" => Some(TimelineGranularity::Month),
+            _ => None,
+        }
+    }
+}
+
+/// What the series of a progress timeline are split by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimelineGrouping {
+    Lesson,
+    ExamType,
+}
+
+impl TimelineGrouping {
+    /// Returns the string representation of this grouping.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TimelineGrouping::Lesson => "lesson",
+            TimelineGrouping::ExamType => "exam_type",
+        }
+    }
+
+    /// Parses a grouping from its string representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "lesson" => Some(TimelineGrouping::Lesson),
+            "exam_type" => Some(TimelineGrouping::ExamType),
+            _ => None,
+        }
+    }
+}
+
+/// Aggregated results of one lesson or exam type within one time bucket.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProgressPoint {
+    /// ID of the lesson or exam type
+    pub group_id: Uuid,
+    pub group_name: String,
+    /// Start of the day, week (Monday) or month in Turkey time
+    pub bucket_start: DateTime<Utc>,
+    pub average_net: f64,
+    /// Number of tests solved in the bucket
+    pub test_count: i64,
+    pub best_net: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_granularity_round_trips() {
+        for granularity in [
+            TimelineGranularity::Day,
+            TimelineGranularity::Week,
+            TimelineGranularity::Month,
+        ] {
+            assert_eq!(
+                TimelineGranularity::parse(granularity.as_str()),
+                Some(granularity)
+            );
+        }
+    }
+
+    #[test]
+    fn test_granularity_rejects_unknown_values() {
+        assert_eq!(TimelineGranularity::parse("year"), None);
+        assert_eq!(TimelineGranularity::parse("Week"), None);
+        assert_eq!(TimelineGranularity::parse(""), None);
+    }
+
+    #[test]
+    fn test_grouping_round_trips() {
+        for grouping in [TimelineGrouping::Lesson, TimelineGrouping::ExamType] {
+            assert_eq!(TimelineGrouping::parse(grouping.as_str()), Some(grouping));
+        }
+    }
+
+    #[test]
+    fn test_grouping_rejects_unknown_values() {
+        assert_eq!(TimelineGrouping::parse("subject"), None);
+        assert_eq!(TimelineGrouping::parse("exam-type"), None);
+        assert_eq!(TimelineGrouping::parse("Lesson"), None);
+    }
+}
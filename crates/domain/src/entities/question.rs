@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::{AnswerKey, AnswerKeyEntry};
+use crate::errors::DomainError;
+
+/// Difficulty level of a question.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionDifficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl QuestionDifficulty {
+    /// Returns the string representation used for storage and APIs.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuestionDifficulty::Easy => "easy",
+            QuestionDifficulty::Medium => "medium",
+            QuestionDifficulty::Hard => "hard",
+        }
+    }
+
+    /// Parses a difficulty from its string representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "easy" => Some(QuestionDifficulty::Easy),
+            "medium" => Some(QuestionDifficulty::Medium),
+            "hard" => Some(QuestionDifficulty::Hard),
+            _ => None,
+        }
+    }
+}
+
+/// Question entity representing a single question of a practice test.
+///
+/// When a practice test has questions, its answer key is derived from them,
+/// see [`Question::answer_key`]. Tests without questions keep working from
+/// their plain answer key.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Question {
+    /// Unique identifier for the question
+    pub id: Uuid,
+    /// ID of the practice test this question belongs to
+    pub practice_test_id: Uuid,
+    /// 1-based position of the question within the test
+    pub position: i32,
+    /// How the question is graded
+    pub answer: AnswerKeyEntry,
+    /// Curriculum topic (kazanım) the question measures
+    pub topic_id: Option<Uuid>,
+    /// Difficulty level, if classified
+    pub difficulty: Option<QuestionDifficulty>,
+    /// Timestamp when the question was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the question was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Question {
+    /// Creates a new question, validating and normalizing its answer.
+    pub fn new(
+        practice_test_id: Uuid,
+        position: i32,
+        answer: AnswerKeyEntry,
+        topic_id: Option<Uuid>,
+        difficulty: Option<QuestionDifficulty>,
+    ) -> Result<Self, DomainError> {
+        if position < 1 {
+            return Err(DomainError::ValidationError(
+                "Question position must be at least 1".to_string(),
+            ));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: Uuid::new_v4(),
+            practice_test_id,
+            position,
+            answer: answer.normalize(position as usize)?,
+            topic_id,
+            difficulty,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Derives the answer key of a practice test from its questions.
+    ///
+    /// The questions must cover positions 1..=n exactly once; their order in the
+    /// slice does not matter.
+    pub fn answer_key(questions: &[Question]) -> Result<AnswerKey, DomainError> {
+        let mut ordered: Vec<&Question> = questions.iter().collect();
+        ordered.sort_by_key(|question| question.position);
+
+        for (index, question) in ordered.iter().enumerate() {
+            if question.position != index as i32 + 1 {
+                return Err(DomainError::ValidationError(format!(
+                    "Questions must be numbered 1 to {} without gaps or duplicates",
+                    questions.len()
+                )));
+            }
+        }
+
+        AnswerKey::new(ordered.into_iter().map(|q| q.answer.clone()).collect())
+    }
+
+    /// Aligns the questions of a practice test with a changed answer key.
+    ///
+    /// Questions keep their identity, topic and difficulty by position and take
+    /// the key's answer; positions beyond the key are dropped and new positions
+    /// get unclassified questions.
+    pub fn sync_with_answer_key(
+        practice_test_id: Uuid,
+        existing: Vec<Question>,
+        answer_key: &AnswerKey,
+    ) -> Vec<Question> {
+        let now = Utc::now();
+        let mut existing = existing;
+        existing.sort_by_key(|question| question.position);
+        let mut existing = existing.into_iter().peekable();
+
+        answer_key
+            .entries()
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let position = index as i32 + 1;
+                while existing.next_if(|q| q.position < position).is_some() {}
+
+                match existing.next_if(|q| q.position == position) {
+                    Some(mut question) => {
+                        if question.answer != *entry {
+                            question.answer = entry.clone();
+                            question.updated_at = now;
+                        }
+                        question
+                    }
+                    None => Question {
+                        id: Uuid::new_v4(),
+                        practice_test_id,
+                        position,
+                        answer: entry.clone(),
+                        topic_id: None,
+                        difficulty: None,
+                        created_at: now,
+                        updated_at: now,
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn accepted(option: char) -> AnswerKeyEntry {
+        AnswerKeyEntry::Accepted {
+            options: vec![option],
+        }
+    }
+
+    #[test]
+    fn test_answer_key_is_derived_in_position_order() {
+        let test_id = Uuid::new_v4();
+        let questions = vec![
+            Question::new(test_id, 2, AnswerKeyEntry::Cancelled, None, None).unwrap(),
+            Question::new(
+                test_id,
+                1,
+                accepted('b'),
+                None,
+                Some(QuestionDifficulty::Hard),
+            )
+            .unwrap(),
+        ];
+
+        let key = Question::answer_key(&questions).unwrap();
+
+        assert_eq!(key.entries(), &[accepted('B'), AnswerKeyEntry::Cancelled]);
+    }
+
+    #[test]
+    fn test_answer_key_rejects_gaps() {
+        let test_id = Uuid::new_v4();
+        let questions = vec![
+            Question::new(test_id, 1, accepted('A'), None, None).unwrap(),
+            Question::new(test_id, 3, accepted('B'), None, None).unwrap(),
+        ];
+
+        assert!(Question::answer_key(&questions).is_err());
+    }
+
+    #[test]
+    fn test_sync_keeps_classification_by_position() {
+        let test_id = Uuid::new_v4();
+        let topic_id = Uuid::new_v4();
+        let first = Question::new(test_id, 1, accepted('A'), Some(topic_id), None).unwrap();
+        let second = Question::new(test_id, 2, accepted('B'), None, None).unwrap();
+        let key = AnswerKey::parse("C").unwrap();
+
+        let synced = Question::sync_with_answer_key(test_id, vec![second, first.clone()], &key);
+
+        assert_eq!(synced.len(), 1);
+        assert_eq!(synced[0].id, first.id);
+        assert_eq!(synced[0].topic_id, Some(topic_id));
+        assert_eq!(synced[0].answer, accepted('C'));
+
+        let grown =
+            Question::sync_with_answer_key(test_id, synced, &AnswerKey::parse("CD").unwrap());
+        assert_eq!(grown.len(), 2);
+        assert_eq!(grown[1].position, 2);
+        assert_eq!(grown[1].topic_id, None);
+    }
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+
+use super::{AnswerKey, AnswerKeyEntry};
+
+/// Outcome of a single graded question.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionOutcome {
+    Correct,
+    Wrong,
+    Empty,
+}
+
+impl QuestionOutcome {
+    /// Returns the string representation of this outcome.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuestionOutcome::Correct => "correct",
+            QuestionOutcome::Wrong => "wrong",
+            QuestionOutcome::Empty => "empty",
+        }
+    }
+}
+
+/// Grading of one question of an answer sheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuestionResult {
+    /// 1-based question number
+    pub question_number: i32,
+    /// Answer given by the user, `None` if left empty
+    pub given_answer: Option<char>,
+    /// Answer key entry the question was graded against
+    pub key: AnswerKeyEntry,
+    /// Whether the answer was correct, wrong or empty
+    pub outcome: QuestionOutcome,
+}
+
+impl QuestionResult {
+    /// Grades user answers against an answer key, position by position.
+    ///
+    /// `_` and spaces mark empty answers and letters are compared case-insensitively.
+    /// Returns `None` if the answers and the key differ in length.
+    pub fn grade_all(answer_key: &AnswerKey, user_answers: &str) -> Option<Vec<Self>> {
+        if answer_key.len() != user_answers.chars().count() {
+            return None;
+        }
+
+        Some(
+            answer_key
+                .entries()
+                .iter()
+                .zip(user_answers.chars())
+                .enumerate()
+                .map(|(index, (entry, user_char))| {
+                    let given_answer = (user_char != '_' && user_char != ' ')
+                        .then(|| user_char.to_ascii_uppercase());
+                    let outcome = match (entry, given_answer) {
+                        (AnswerKeyEntry::Cancelled, _) => QuestionOutcome::Correct,
+                        (_, None) => QuestionOutcome::Empty,
+                        (AnswerKeyEntry::AnyAnswer, Some(_)) => QuestionOutcome::Correct,
+                        (AnswerKeyEntry::Accepted { options }, Some(answer)) => {
+                            if options.contains(&answer) {
+                                QuestionOutcome::Correct
+                            } else {
+                                QuestionOutcome::Wrong
+                            }
+                        }
+                    };
+
+                    Self {
+                        question_number: index as i32 + 1,
+                        given_answer,
+                        key: entry.clone(),
+                        outcome,
+                    }
+                })
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grade_all_reports_each_question() {
+        let answer_key = AnswerKey::parse("ABC").unwrap();
+
+        let results = QuestionResult::grade_all(&answer_key, "aD_").unwrap();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].outcome, QuestionOutcome::Correct);
+        assert_eq!(results[1].given_answer, Some('D'));
+        assert_eq!(results[1].outcome, QuestionOutcome::Wrong);
+        assert_eq!(results[2].question_number, 3);
+        assert_eq!(results[2].given_answer, None);
+        assert_eq!(results[2].outcome, QuestionOutcome::Empty);
+    }
+}
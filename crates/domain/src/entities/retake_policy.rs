@@ -0,0 +1,104 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Which attempt of a practice test counts as the student's result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptScoring {
+    /// The first submitted attempt counts; retakes are for practice only
+    First,
+    /// The attempt with the highest net score counts
+    Best,
+}
+
+impl AttemptScoring {
+    /// Returns the string stored in the database for this mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttemptScoring::First => "first",
+            AttemptScoring::Best => "best",
+        }
+    }
+
+    /// Parses an attempt scoring mode from its database representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "first" => Some(AttemptScoring::First),
+            "best" => Some(AttemptScoring::Best),
+            _ => None,
+        }
+    }
+}
+
+/// What a retake policy applies to. Test book policies override exam type policies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "id", rename_all = "snake_case")]
+pub enum RetakePolicyScope {
+    ExamType(Uuid),
+    TestBook(Uuid),
+}
+
+/// RetakePolicy entity describing when a practice test may be solved again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetakePolicy {
+    /// Unique identifier for the retake policy
+    pub id: Uuid,
+    /// Exam type or test book the policy applies to
+    pub scope: RetakePolicyScope,
+    /// Minutes to wait after an attempt before the next one; 0 disables the cooldown
+    pub cooldown_minutes: i32,
+    /// Maximum number of attempts per practice test, unlimited if `None`
+    pub max_attempts: Option<i32>,
+    /// Which attempt counts as the result
+    pub attempt_scoring: AttemptScoring,
+    /// Unlimited practice mode: ignores the cooldown and the attempt limit
+    pub unlimited_practice: bool,
+    /// Timestamp when the policy was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the policy was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RetakePolicy {
+    /// Cooldown of the default policy, matching the original 24-hour rule.
+    pub const STANDARD_COOLDOWN_MINUTES: i32 = 24 * 60;
+
+    /// Creates a new retake policy for the given scope.
+    pub fn new(
+        scope: RetakePolicyScope,
+        cooldown_minutes: i32,
+        max_attempts: Option<i32>,
+        attempt_scoring: AttemptScoring,
+        unlimited_practice: bool,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            scope,
+            cooldown_minutes,
+            max_attempts,
+            attempt_scoring,
+            unlimited_practice,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Creates the policy used when neither the test book nor its exam type has one:
+    /// a 24-hour cooldown, unlimited attempts and the first attempt counting.
+    pub fn standard(scope: RetakePolicyScope) -> Self {
+        Self::new(
+            scope,
+            Self::STANDARD_COOLDOWN_MINUTES,
+            None,
+            AttemptScoring::First,
+            false,
+        )
+    }
+
+    /// Returns the cooldown as a duration.
+    pub fn cooldown(&self) -> Duration {
+        Duration::minutes(self.cooldown_minutes as i64)
+    }
+}
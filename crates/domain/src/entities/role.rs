@@ -20,6 +20,9 @@ pub struct Role {
 }
 
 impl Role {
+    /// Name of the seeded administrator role.
+    pub const ADMIN: &'static str = "admin";
+
     /// Creates a new role with the given details.
     pub fn new(name: String, description: Option<String>) -> Self {
         let now = Utc::now();
@@ -37,5 +40,10 @@ impl Role {
     pub fn is_system_role(&self) -> bool {
         self.is_system
     }
-}
 
+    /// Checks if this is the administrator role, whose permissions cannot be
+    /// changed so that administrators are never locked out.
+    pub fn is_admin_role(&self) -> bool {
+        self.name == Self::ADMIN
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Weight of a single lesson's net in a score table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LessonCoefficient {
+    /// ID of the lesson the coefficient applies to
+    pub lesson_id: Uuid,
+    /// Points added to the raw score per net question of the lesson
+    pub coefficient: f64,
+}
+
+/// ScoreTable entity holding the ÖSYM coefficients of an exam type for one year
+/// (e.g. TYT 2024: base score 100, Türkçe 3.3, Matematik 3.3 ...).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreTable {
+    /// Unique identifier for the score table
+    pub id: Uuid,
+    /// ID of the exam type this table applies to
+    pub exam_type_id: Uuid,
+    /// Exam year the coefficients were published for
+    pub year: i32,
+    /// Base score every candidate starts with
+    pub base_score: f64,
+    /// Multiplier applied to the diploma score (OBP) for the placement score
+    pub diploma_coefficient: f64,
+    /// Per-lesson coefficients
+    pub coefficients: Vec<LessonCoefficient>,
+    /// Timestamp when the table was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the table was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Contribution of one lesson to an estimated score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LessonScore {
+    pub lesson_id: Uuid,
+    pub net: f64,
+    pub coefficient: f64,
+    pub points: f64,
+}
+
+/// Result of applying a score table to a set of lesson nets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoreEstimate {
+    pub lessons: Vec<LessonScore>,
+    /// Base score plus the weighted nets (ham puan)
+    pub raw_score: f64,
+    /// Diploma grade scaled to the 250-500 OBP range, if a grade was given
+    pub diploma_score: Option<f64>,
+    /// Raw score plus the weighted diploma score (yerleştirme puanı), if a grade was given
+    pub placement_score: Option<f64>,
+}
+
+impl ScoreTable {
+    /// Diploma coefficient used by ÖSYM for regular placements.
+    pub const STANDARD_DIPLOMA_COEFFICIENT: f64 = 0.12;
+
+    /// Creates a new score table for an exam type and year.
+    pub fn new(
+        exam_type_id: Uuid,
+        year: i32,
+        base_score: f64,
+        diploma_coefficient: f64,
+        coefficients: Vec<LessonCoefficient>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            exam_type_id,
+            year,
+            base_score,
+            diploma_coefficient,
+            coefficients,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Estimates the score for the given nets per lesson and optional diploma grade (0-100).
+    ///
+    /// Negative lesson nets count as zero, and lessons without a coefficient in the
+    /// table do not contribute.
+    pub fn estimate(
+        &self,
+        lesson_nets: &HashMap<Uuid, f64>,
+        diploma_grade: Option<f64>,
+    ) -> ScoreEstimate {
+        let lessons: Vec<LessonScore> = self
+            .coefficients
+            .iter()
+            .map(|c| {
+                let net = lesson_nets
+                    .get(&c.lesson_id)
+                    .copied()
+                    .unwrap_or(0.0)
+                    .max(0.0);
+                LessonScore {
+                    lesson_id: c.lesson_id,
+                    net,
+                    coefficient: c.coefficient,
+                    points: net * c.coefficient,
+                }
+            })
+            .collect();
+
+        let raw_score = self.base_score + lessons.iter().map(|l| l.points).sum::<f64>();
+        let diploma_score = diploma_grade.map(|grade| grade * 5.0);
+        let placement_score = diploma_score.map(|obp| raw_score + obp * self.diploma_coefficient);
+
+        ScoreEstimate {
+            lessons,
+            raw_score,
+            diploma_score,
+            placement_score,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_raw_and_placement_score() {
+        let turkish = Uuid::new_v4();
+        let math = Uuid::new_v4();
+        let table = ScoreTable::new(
+            Uuid::new_v4(),
+            2024,
+            100.0,
+            ScoreTable::STANDARD_DIPLOMA_COEFFICIENT,
+            vec![
+                LessonCoefficient {
+                    lesson_id: turkish,
+                    coefficient: 3.3,
+                },
+                LessonCoefficient {
+                    lesson_id: math,
+                    coefficient: 3.3,
+                },
+            ],
+        );
+        let nets = HashMap::from([(turkish, 30.0), (math, 20.0)]);
+
+        let estimate = table.estimate(&nets, Some(80.0));
+
+        assert!((estimate.raw_score - 265.0).abs() < 1e-9);
+        assert_eq!(estimate.diploma_score, Some(400.0));
+        assert!((estimate.placement_score.unwrap() - 313.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_estimate_ignores_negative_and_unknown_nets() {
+        let turkish = Uuid::new_v4();
+        let table = ScoreTable::new(
+            Uuid::new_v4(),
+            2024,
+            100.0,
+            ScoreTable::STANDARD_DIPLOMA_COEFFICIENT,
+            vec![LessonCoefficient {
+                lesson_id: turkish,
+                coefficient: 3.3,
+            }],
+        );
+        let nets = HashMap::from([(turkish, -2.5), (Uuid::new_v4(), 40.0)]);
+
+        let estimate = table.estimate(&nets, None);
+
+        assert_eq!(estimate.raw_score, 100.0);
+        assert_eq!(estimate.lessons.len(), 1);
+        assert_eq!(estimate.lessons[0].net, 0.0);
+        assert_eq!(estimate.placement_score, None);
+    }
+}
@@ -0,0 +1,157 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How a computed net score is rounded before it is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RoundingMode {
+    /// Keep the exact value
+    None,
+    /// Round half away from zero
+    Nearest,
+    /// Always round towards negative infinity
+    Floor,
+}
+
+impl RoundingMode {
+    /// Returns the string stored in the database for this mode.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundingMode::None => "none",
+            RoundingMode::Nearest => "nearest",
+            RoundingMode::Floor => "floor",
+        }
+    }
+
+    /// Parses a rounding mode from its database representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "none" => Some(RoundingMode::None),
+            "nearest" => Some(RoundingMode::Nearest),
+            "floor" => Some(RoundingMode::Floor),
+            _ => None,
+        }
+    }
+}
+
+/// ScoringPolicy entity describing how nets are calculated for an exam type.
+///
+/// Policies are versioned: changing the rules of an exam type creates a new
+/// version instead of editing the old one, so stored results can always be
+/// traced back to the rules that produced them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScoringPolicy {
+    /// Unique identifier for the scoring policy
+    pub id: Uuid,
+    /// ID of the exam type this policy applies to
+    pub exam_type_id: Uuid,
+    /// Version number, starting at 1 and increasing per exam type
+    pub version: i32,
+    /// Fraction of a correct answer cancelled by each wrong answer (e.g. 0.25 for TYT)
+    pub penalty_ratio: f64,
+    /// Points awarded per net question
+    pub question_weight: f64,
+    /// Rounding applied to the final net score
+    pub rounding_mode: RoundingMode,
+    /// Number of decimal places kept when rounding
+    pub decimal_places: i32,
+    /// Timestamp when the policy version was created
+    pub created_at: DateTime<Utc>,
+}
+
+impl ScoringPolicy {
+    /// Penalty ratio of the classic ÖSYM rule where four wrong answers cancel one correct.
+    pub const STANDARD_PENALTY_RATIO: f64 = 0.25;
+
+    /// Creates a new scoring policy version for an exam type.
+    pub fn new(
+        exam_type_id: Uuid,
+        version: i32,
+        penalty_ratio: f64,
+        question_weight: f64,
+        rounding_mode: RoundingMode,
+        decimal_places: i32,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            exam_type_id,
+            version,
+            penalty_ratio,
+            question_weight,
+            rounding_mode,
+            decimal_places,
+            created_at: Utc::now(),
+        }
+    }
+
+    /// Creates the first policy version for an exam type using the standard `correct - wrong / 4` rule.
+    pub fn standard(exam_type_id: Uuid) -> Self {
+        Self::new(
+            exam_type_id,
+            1,
+            Self::STANDARD_PENALTY_RATIO,
+            1.0,
+            RoundingMode::None,
+            2,
+        )
+    }
+
+    /// Calculates the net score for the given answer counts.
+    pub fn net_score(&self, correct_count: i32, wrong_count: i32) -> f64 {
+        let net =
+            (correct_count as f64 - wrong_count as f64 * self.penalty_ratio) * self.question_weight;
+        self.round(net)
+    }
+
+    /// Applies the policy's rounding rule to a raw score.
+    fn round(&self, value: f64) -> f64 {
+        let factor = 10f64.powi(self.decimal_places);
+        match self.rounding_mode {
+            RoundingMode::None => value,
+            RoundingMode::Nearest => (value * factor).round() / factor,
+            RoundingMode::Floor => (value * factor).floor() / factor,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_policy_matches_legacy_rule() {
+        let policy = ScoringPolicy::standard(Uuid::new_v4());
+
+        assert_eq!(policy.net_score(35, 3), 34.25);
+        assert_eq!(policy.net_score(0, 4), -1.0);
+    }
+
+    #[test]
+    fn test_lgs_style_penalty_with_rounding() {
+        let policy =
+            ScoringPolicy::new(Uuid::new_v4(), 2, 1.0 / 3.0, 1.0, RoundingMode::Nearest, 2);
+
+        assert_eq!(policy.net_score(18, 2), 17.33);
+        assert_eq!(policy.net_score(18, 3), 17.0);
+    }
+
+    #[test]
+    fn test_no_penalty_with_weight_and_floor() {
+        let policy = ScoringPolicy::new(Uuid::new_v4(), 1, 0.0, 1.5, RoundingMode::Floor, 0);
+
+        assert_eq!(policy.net_score(7, 10), 10.0);
+    }
+
+    #[test]
+    fn test_rounding_mode_round_trip() {
+        for mode in [
+            RoundingMode::None,
+            RoundingMode::Nearest,
+            RoundingMode::Floor,
+        ] {
+            assert_eq!(RoundingMode::parse(mode.as_str()), Some(mode));
+        }
+        assert_eq!(RoundingMode::parse("ceil"), None);
+    }
+}
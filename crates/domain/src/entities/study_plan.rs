@@ -0,0 +1,130 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// A student's study plan: the exam they prepare for and when it takes place.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyPlan {
+    /// Unique identifier for the plan
+    pub id: Uuid,
+    /// ID of the student; a student has at most one plan
+    pub user_id: Uuid,
+    /// ID of the exam type the student prepares for
+    pub exam_type_id: Uuid,
+    /// Date of the exam
+    pub target_date: NaiveDate,
+    /// Timestamp when the plan was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the plan was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl StudyPlan {
+    /// Creates a new study plan for a student.
+    pub fn new(user_id: Uuid, exam_type_id: Uuid, target_date: NaiveDate) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            exam_type_id,
+            target_date,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Returns the number of days left until the exam, negative once it has passed.
+    pub fn days_until_exam(&self, today: NaiveDate) -> i64 {
+        (self.target_date - today).num_days()
+    }
+}
+
+/// What a daily study goal counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalMetric {
+    /// Number of practice tests solved
+    Tests,
+    /// Number of questions solved, answered or left empty
+    Questions,
+}
+
+impl GoalMetric {
+    /// Returns the string stored in the database for this metric.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalMetric::Tests => "tests",
+            GoalMetric::Questions => "questions",
+        }
+    }
+
+    /// Parses a goal metric from its database representation.
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "tests" => Some(GoalMetric::Tests),
+            "questions" => Some(GoalMetric::Questions),
+            _ => None,
+        }
+    }
+}
+
+/// A daily study goal, e.g. "3 tests a day" or "40 Matematik questions a day".
+///
+/// A goal applies from the day it was created until the day it was archived
+/// (exclusive), so archiving a goal does not rewrite past days.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudyGoal {
+    /// Unique identifier for the goal
+    pub id: Uuid,
+    /// ID of the student who set the goal
+    pub user_id: Uuid,
+    /// What the goal counts
+    pub metric: GoalMetric,
+    /// Amount to reach every day
+    pub daily_target: i32,
+    /// Only count results of this lesson, `None` for all lessons
+    pub lesson_id: Option<Uuid>,
+    /// Timestamp when the goal was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the goal was archived, `None` while active
+    pub archived_at: Option<DateTime<Utc>>,
+}
+
+impl StudyGoal {
+    /// Creates a new active goal.
+    pub fn new(
+        user_id: Uuid,
+        metric: GoalMetric,
+        daily_target: i32,
+        lesson_id: Option<Uuid>,
+    ) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            user_id,
+            metric,
+            daily_target,
+            lesson_id,
+            created_at: Utc::now(),
+            archived_at: None,
+        }
+    }
+
+    /// Returns true if the goal applies to the given day (UTC).
+    pub fn applies_on(&self, day: NaiveDate) -> bool {
+        self.created_at.date_naive() <= day
+            && self
+                .archived_at
+                .is_none_or(|archived_at| day < archived_at.date_naive())
+    }
+}
+
+/// A student's solved tests and questions in one lesson on one day (UTC).
+///
+/// Read model aggregated from `test_results`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DailyActivity {
+    pub day: NaiveDate,
+    pub lesson_id: Uuid,
+    pub test_count: i64,
+    pub question_count: i64,
+}
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// How many practice tests of a test book a user has solved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TestBookProgress {
+    /// ID of the test book
+    pub test_book_id: Uuid,
+    /// Number of practice tests in the test book
+    pub total_test_count: i64,
+    /// Number of those practice tests the user solved at least once
+    pub solved_test_count: i64,
+}
+
+impl TestBookProgress {
+    /// Share of the test book's practice tests the user has solved, from 0 to 100.
+    pub fn progress_percentage(&self) -> f64 {
+        if self.total_test_count > 0 {
+            (self.solved_test_count as f64 / self.total_test_count as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_percentage() {
+        let progress = TestBookProgress {
+            test_book_id: Uuid::new_v4(),
+            total_test_count: 40,
+            solved_test_count: 10,
+        };
+        assert_eq!(progress.progress_percentage(), 25.0);
+
+        let empty = TestBookProgress {
+            total_test_count: 0,
+            solved_test_count: 0,
+            ..progress
+        };
+        assert_eq!(empty.progress_percentage(), 0.0);
+    }
+}
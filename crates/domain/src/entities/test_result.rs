@@ -2,6 +2,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::{AnswerCounts, ScoringPolicy};
+
 /// TestResult entity representing a user's test result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TestResult {
@@ -19,36 +21,100 @@ pub struct TestResult {
     pub wrong_count: i32,
     /// Number of empty answers
     pub empty_count: i32,
-    /// Net score calculated as: correct - (wrong / 4.0)
+    /// Net score calculated by the exam type's scoring policy
     pub net_score: f64,
-    /// Timestamp when the test was solved
+    /// ID of the scoring policy version that produced the net score
+    pub scoring_policy_id: Uuid,
+    /// Version number of the scoring policy that produced the net score
+    pub scoring_policy_version: i32,
+    /// Answer key revision the result was graded against
+    pub answer_key_revision: i32,
+    /// Timestamp when a re-grade last changed the score, if ever
+    pub score_changed_at: Option<DateTime<Utc>>,
+    /// Timestamp when the test was solved (submitted)
     pub solved_at: DateTime<Utc>,
+    /// Timestamp when the exam session started, `None` for results submitted without a session
+    pub started_at: Option<DateTime<Utc>>,
+    /// Seconds between the session start and submission, `None` without a session
+    pub elapsed_seconds: Option<i32>,
+    /// Mock exam attempt the result was graded in, `None` for an attempt at the practice test itself
+    pub mock_exam_attempt_id: Option<Uuid>,
 }
 
 impl TestResult {
-    /// Creates a new test result with the given details.
+    /// Creates a new test result, scoring it with the given policy.
     pub fn new(
         user_id: Uuid,
         practice_test_id: Uuid,
         user_answers: String,
-        correct_count: i32,
-        wrong_count: i32,
-        empty_count: i32,
+        counts: AnswerCounts,
+        scoring_policy: &ScoringPolicy,
+        answer_key_revision: i32,
     ) -> Self {
-        // Calculate net score: correct - (wrong / 4.0)
-        let net_score = correct_count as f64 - (wrong_count as f64 / 4.0);
+        let net_score = scoring_policy.net_score(counts.correct, counts.wrong);
 
         Self {
             id: Uuid::new_v4(),
             user_id,
             practice_test_id,
             user_answers,
-            correct_count,
-            wrong_count,
-            empty_count,
+            correct_count: counts.correct,
+            wrong_count: counts.wrong,
+            empty_count: counts.empty,
             net_score,
+            scoring_policy_id: scoring_policy.id,
+            scoring_policy_version: scoring_policy.version,
+            answer_key_revision,
+            score_changed_at: None,
             solved_at: Utc::now(),
+            started_at: None,
+            elapsed_seconds: None,
+            mock_exam_attempt_id: None,
         }
     }
-}
 
+    /// Records the timing of the exam session the result was submitted from.
+    pub fn record_session_timing(
+        &mut self,
+        started_at: DateTime<Utc>,
+        submitted_at: DateTime<Utc>,
+    ) {
+        self.started_at = Some(started_at);
+        self.solved_at = submitted_at;
+        self.elapsed_seconds = Some((submitted_at - started_at).num_seconds().max(0) as i32);
+    }
+
+    /// Marks the result as a section of a mock exam attempt. Such results are not
+    /// attempts at the practice test: they neither count toward its retake policy
+    /// nor reveal its answer key.
+    pub fn record_mock_exam_attempt(&mut self, mock_exam_attempt_id: Uuid) {
+        self.mock_exam_attempt_id = Some(mock_exam_attempt_id);
+    }
+
+    /// Applies counts from a newer answer key revision, keeping the original scoring policy.
+    ///
+    /// Returns true if the score changed, in which case `score_changed_at` is set.
+    pub fn regrade(
+        &mut self,
+        counts: AnswerCounts,
+        scoring_policy: &ScoringPolicy,
+        answer_key_revision: i32,
+    ) -> bool {
+        let net_score = scoring_policy.net_score(counts.correct, counts.wrong);
+        let changed = counts.correct != self.correct_count
+            || counts.wrong != self.wrong_count
+            || counts.empty != self.empty_count
+            || net_score != self.net_score;
+
+        self.correct_count = counts.correct;
+        self.wrong_count = counts.wrong;
+        self.empty_count = counts.empty;
+        self.net_score = net_score;
+        self.answer_key_revision = answer_key_revision;
+        if changed {
+            self.score_changed_at = Some(Utc::now());
+        }
+
+        changed
+    }
+}
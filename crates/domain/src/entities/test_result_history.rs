@@ -0,0 +1,42 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::TestResult;
+
+/// TestResultHistory entity keeping a test result's previous score after a re-grade.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TestResultHistory {
+    /// Unique identifier for the history entry
+    pub id: Uuid,
+    /// ID of the re-graded test result
+    pub test_result_id: Uuid,
+    /// Answer key revision the previous score was graded with
+    pub answer_key_revision: i32,
+    /// Previous number of correct answers
+    pub correct_count: i32,
+    /// Previous number of wrong answers
+    pub wrong_count: i32,
+    /// Previous number of empty answers
+    pub empty_count: i32,
+    /// Previous net score
+    pub net_score: f64,
+    /// Timestamp when the score was replaced
+    pub replaced_at: DateTime<Utc>,
+}
+
+impl TestResultHistory {
+    /// Snapshots the current score of a test result before it is re-graded.
+    pub fn snapshot(result: &TestResult) -> Self {
+        Self {
+            id: Uuid::new_v4(),
+            test_result_id: result.id,
+            answer_key_revision: result.answer_key_revision,
+            correct_count: result.correct_count,
+            wrong_count: result.wrong_count,
+            empty_count: result.empty_count,
+            net_score: result.net_score,
+            replaced_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,307 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use uuid::Uuid;
+
+use crate::errors::DomainError;
+
+/// Topic entity representing a node of a subject's curriculum tree.
+///
+/// Topics form an adjacency list under a subject: units at the root, then topics
+/// and learning outcomes (kazanımlar) below them. Siblings are ordered by `position`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Topic {
+    /// Unique identifier for the topic
+    pub id: Uuid,
+    /// ID of the subject this topic belongs to
+    pub subject_id: Uuid,
+    /// ID of the parent topic, `None` for a root topic of the subject
+    pub parent_id: Option<Uuid>,
+    /// Name of the topic (e.g., "Üslü Sayılar")
+    pub name: String,
+    /// 1-based position among its siblings
+    pub position: i32,
+    /// Timestamp when the topic was created
+    pub created_at: DateTime<Utc>,
+    /// Timestamp when the topic was last updated
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Topic {
+    /// Creates a new topic with the given details.
+    pub fn new(subject_id: Uuid, parent_id: Option<Uuid>, name: String, position: i32) -> Self {
+        let now = Utc::now();
+        Self {
+            id: Uuid::new_v4(),
+            subject_id,
+            parent_id,
+            name,
+            position,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+/// A topic with its child topics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicNode {
+    pub topic: Topic,
+    pub children: Vec<TopicNode>,
+}
+
+/// Curriculum tree of one subject, used to place topics consistently.
+#[derive(Debug, Clone)]
+pub struct TopicTree {
+    topics: Vec<Topic>,
+}
+
+impl TopicTree {
+    /// Creates the tree from all topics of a subject.
+    pub fn new(topics: Vec<Topic>) -> Self {
+        Self { topics }
+    }
+
+    /// Returns the topic with the given ID.
+    pub fn get(&self, id: Uuid) -> Option<&Topic> {
+        self.topics.iter().find(|topic| topic.id == id)
+    }
+
+    /// Returns the children of a parent (`None` for the roots), ordered by position.
+    pub fn children(&self, parent_id: Option<Uuid>) -> Vec<&Topic> {
+        let mut children: Vec<&Topic> = self
+            .topics
+            .iter()
+            .filter(|topic| topic.parent_id == parent_id)
+            .collect();
+        children.sort_by_key(|topic| (topic.position, topic.created_at));
+        children
+    }
+
+    /// Returns the position a new child of the parent gets: after its last sibling.
+    pub fn next_position(&self, parent_id: Option<Uuid>) -> i32 {
+        self.children(parent_id)
+            .last()
+            .map_or(1, |topic| topic.position + 1)
+    }
+
+    /// Returns the IDs of a topic and all of its descendants.
+    pub fn subtree_ids(&self, id: Uuid) -> HashSet<Uuid> {
+        let mut ids = HashSet::from([id]);
+        let mut pending = vec![id];
+        while let Some(parent_id) = pending.pop() {
+            for child in self.children(Some(parent_id)) {
+                if ids.insert(child.id) {
+                    pending.push(child.id);
+                }
+            }
+        }
+        ids
+    }
+
+    /// Moves a topic under a new parent (`None` for the root level) at a 1-based
+    /// position among its new siblings, appending it when no position is given.
+    ///
+    /// Old and new siblings are renumbered without gaps. Returns the topics
+    /// whose parent or position changed.
+    pub fn move_topic(
+        &self,
+        id: Uuid,
+        parent_id: Option<Uuid>,
+        position: Option<usize>,
+    ) -> Result<Vec<Topic>, DomainError> {
+        let topic = self
+            .get(id)
+            .ok_or_else(|| DomainError::ValidationError("Topic not found".to_string()))?;
+        if let Some(parent_id) = parent_id {
+            if self.get(parent_id).is_none() {
+                return Err(DomainError::ValidationError(
+                    "Parent topic must belong to the same subject".to_string(),
+                ));
+            }
+            if self.subtree_ids(id).contains(&parent_id) {
+                return Err(DomainError::ValidationError(
+                    "A topic cannot be moved under itself or one of its descendants".to_string(),
+                ));
+            }
+        }
+
+        let mut new_siblings: Vec<Uuid> = self
+            .children(parent_id)
+            .into_iter()
+            .map(|sibling| sibling.id)
+            .filter(|sibling_id| *sibling_id != id)
+            .collect();
+        let index = position
+            .map_or(new_siblings.len(), |p| p.saturating_sub(1))
+            .min(new_siblings.len());
+        new_siblings.insert(index, id);
+
+        let mut placements = self.placements(parent_id, &new_siblings);
+        if topic.parent_id != parent_id {
+            let old_siblings: Vec<Uuid> = self
+                .children(topic.parent_id)
+                .into_iter()
+                .map(|sibling| sibling.id)
+                .filter(|sibling_id| *sibling_id != id)
+                .collect();
+            placements.extend(self.placements(topic.parent_id, &old_siblings));
+        }
+
+        Ok(self.apply(placements))
+    }
+
+    /// Reorders the children of a parent (`None` for the root level).
+    ///
+    /// `ordered_ids` must list every child exactly once. Returns the topics
+    /// whose position changed.
+    pub fn reorder(
+        &self,
+        parent_id: Option<Uuid>,
+        ordered_ids: &[Uuid],
+    ) -> Result<Vec<Topic>, DomainError> {
+        let current: HashSet<Uuid> = self
+            .children(parent_id)
+            .into_iter()
+            .map(|topic| topic.id)
+            .collect();
+        let requested: HashSet<Uuid> = ordered_ids.iter().copied().collect();
+        if requested.len() != ordered_ids.len() || requested != current {
+            return Err(DomainError::ValidationError(
+                "The new order must list every child topic exactly once".to_string(),
+            ));
+        }
+
+        Ok(self.apply(self.placements(parent_id, ordered_ids)))
+    }
+
+    /// Builds the nested tree, children ordered by position.
+    pub fn into_nodes(self) -> Vec<TopicNode> {
+        let known: HashSet<Uuid> = self.topics.iter().map(|topic| topic.id).collect();
+        let mut by_parent: HashMap<Option<Uuid>, Vec<Topic>> = HashMap::new();
+        for topic in self.topics {
+            // Topics whose parent is missing are shown at the root rather than lost
+            let parent_id = topic
+                .parent_id
+                .filter(|parent_id| known.contains(parent_id));
+            by_parent.entry(parent_id).or_default().push(topic);
+        }
+
+        fn build(
+            parent_id: Option<Uuid>,
+            by_parent: &mut HashMap<Option<Uuid>, Vec<Topic>>,
+        ) -> Vec<TopicNode> {
+            let mut topics = by_parent.remove(&parent_id).unwrap_or_default();
+            topics.sort_by_key(|topic| (topic.position, topic.created_at));
+            topics
+                .into_iter()
+                .map(|topic| {
+                    let children = build(Some(topic.id), by_parent);
+                    TopicNode { topic, children }
+                })
+                .collect()
+        }
+
+        build(None, &mut by_parent)
+    }
+
+    fn placements(
+        &self,
+        parent_id: Option<Uuid>,
+        ordered_ids: &[Uuid],
+    ) -> Vec<(Uuid, Option<Uuid>, i32)> {
+        ordered_ids
+            .iter()
+            .enumerate()
+            .map(|(index, id)| (*id, parent_id, index as i32 + 1))
+            .collect()
+    }
+
+    fn apply(&self, placements: Vec<(Uuid, Option<Uuid>, i32)>) -> Vec<Topic> {
+        let now = Utc::now();
+        placements
+            .into_iter()
+            .filter_map(|(id, parent_id, position)| {
+                let topic = self.get(id)?;
+                if topic.parent_id == parent_id && topic.position == position {
+                    return None;
+                }
+                let mut topic = topic.clone();
+                topic.parent_id = parent_id;
+                topic.position = position;
+                topic.updated_at = now;
+                Some(topic)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn topic(subject_id: Uuid, parent_id: Option<Uuid>, name: &str, position: i32) -> Topic {
+        Topic::new(subject_id, parent_id, name.to_string(), position)
+    }
+
+    #[test]
+    fn test_builds_ordered_tree() {
+        let subject_id = Uuid::new_v4();
+        let unit = topic(subject_id, None, "Sayılar", 1);
+        let second = topic(subject_id, Some(unit.id), "Üslü Sayılar", 2);
+        let first = topic(subject_id, Some(unit.id), "Temel Kavramlar", 1);
+
+        let nodes = TopicTree::new(vec![second, unit.clone(), first]).into_nodes();
+
+        assert_eq!(nodes.len(), 1);
+        assert_eq!(nodes[0].topic.id, unit.id);
+        let names: Vec<&str> = nodes[0]
+            .children
+            .iter()
+            .map(|n| n.topic.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["Temel Kavramlar", "Üslü Sayılar"]);
+    }
+
+    #[test]
+    fn test_move_renumbers_siblings_and_rejects_cycles() {
+        let subject_id = Uuid::new_v4();
+        let unit_a = topic(subject_id, None, "A", 1);
+        let unit_b = topic(subject_id, None, "B", 2);
+        let a1 = topic(subject_id, Some(unit_a.id), "A1", 1);
+        let a2 = topic(subject_id, Some(unit_a.id), "A2", 2);
+        let b1 = topic(subject_id, Some(unit_b.id), "B1", 1);
+        let tree = TopicTree::new(vec![
+            unit_a.clone(),
+            unit_b.clone(),
+            a1.clone(),
+            a2.clone(),
+            b1.clone(),
+        ]);
+
+        let changed = tree.move_topic(a1.id, Some(unit_b.id), Some(1)).unwrap();
+        let placed: HashMap<Uuid, (Option<Uuid>, i32)> = changed
+            .iter()
+            .map(|t| (t.id, (t.parent_id, t.position)))
+            .collect();
+
+        assert_eq!(placed[&a1.id], (Some(unit_b.id), 1));
+        assert_eq!(placed[&b1.id], (Some(unit_b.id), 2));
+        assert_eq!(placed[&a2.id], (Some(unit_a.id), 1));
+        assert!(tree.move_topic(unit_a.id, Some(a2.id), None).is_err());
+    }
+
+    #[test]
+    fn test_reorder_requires_every_child() {
+        let subject_id = Uuid::new_v4();
+        let first = topic(subject_id, None, "A", 1);
+        let second = topic(subject_id, None, "B", 2);
+        let tree = TopicTree::new(vec![first.clone(), second.clone()]);
+
+        assert!(tree.reorder(None, &[second.id]).is_err());
+
+        let changed = tree.reorder(None, &[second.id, first.id]).unwrap();
+        assert_eq!(changed.len(), 2);
+        assert!(changed.iter().any(|t| t.id == second.id && t.position == 1));
+    }
+}
@@ -23,6 +23,10 @@ pub struct User {
     pub deleted_at: Option<DateTime<Utc>>,
     /// ID of the user who performed the deletion
     pub deleted_by: Option<Uuid>,
+    /// Student number printed on optical answer forms, if assigned
+    pub student_number: Option<String>,
+    /// Whether the user is hidden from public leaderboards
+    pub leaderboard_opt_out: bool,
 }
 
 impl User {
@@ -39,6 +43,8 @@ impl User {
             updated_at: now,
             deleted_at: None,
             deleted_by: None,
+            student_number: None,
+            leaderboard_opt_out: false,
         }
     }
 
@@ -73,4 +79,3 @@ impl User {
         self.updated_at = Utc::now();
     }
 }
-
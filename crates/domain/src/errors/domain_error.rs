@@ -17,6 +17,9 @@ pub enum DomainError {
     #[error("Password does not meet requirements: {0}")]
     InvalidPassword(String),
 
+    #[error("Stored answer key of practice test {0} is invalid: {1}")]
+    InvalidStoredAnswerKey(Uuid, String),
+
     // Not found errors
     #[error("User not found: {0}")]
     UserNotFound(Uuid),
@@ -133,4 +136,3 @@ impl DomainError {
         )
     }
 }
-
@@ -1,4 +1,4 @@
 pub mod entities;
 pub mod errors;
 pub mod repositories;
-
+pub mod services;
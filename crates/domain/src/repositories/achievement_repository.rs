@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::entities::{Achievement, AchievementFacts, UserAchievement};
+use crate::errors::DomainError;
+
+/// Repository trait for achievements, awarded badges and the facts they are earned by.
+#[async_trait]
+pub trait AchievementRepository: Send + Sync {
+    /// Creates a new achievement.
+    async fn create(&self, achievement: &Achievement) -> Result<Achievement, DomainError>;
+
+    /// Finds an achievement by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Achievement>, DomainError>;
+
+    /// Finds an achievement by its code.
+    async fn find_by_code(&self, code: &str) -> Result<Option<Achievement>, DomainError>;
+
+    /// Lists all achievements.
+    async fn list_all(&self) -> Result<Vec<Achievement>, DomainError>;
+
+    /// Deletes an achievement and every award of it.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+
+    /// Finds the achievements awarded to a user.
+    async fn find_awarded(&self, user_id: Uuid) -> Result<Vec<UserAchievement>, DomainError>;
+
+    /// Awards achievements to a user, skipping ones already awarded.
+    async fn award(
+        &self,
+        user_id: Uuid,
+        achievement_ids: &[Uuid],
+        awarded_at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+
+    /// Aggregates the facts achievement rules are evaluated against from the user's results.
+    async fn find_facts(&self, user_id: Uuid) -> Result<AchievementFacts, DomainError>;
+}
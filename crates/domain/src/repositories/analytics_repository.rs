@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::entities::{
+    AnalyticsRecord, ProgressPoint, QuestionTopic, TimelineGranularity, TimelineGrouping,
+};
+use crate::errors::DomainError;
+
+/// Repository trait for read-only queries feeding student analytics.
+#[async_trait]
+pub trait AnalyticsRepository: Send + Sync {
+    /// Finds the user's `limit` most recent test results with their practice test,
+    /// subject and lesson, ordered by solve time. Mock exam results are left out,
+    /// as their answer keys may still be hidden.
+    async fn find_records_by_user(
+        &self,
+        user_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<AnalyticsRecord>, DomainError>;
+
+    /// Finds the topics of the classified questions of the given practice tests.
+    async fn find_question_topics(
+        &self,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<QuestionTopic>, DomainError>;
+
+    /// Aggregates a user's net scores per lesson or exam type and time bucket in
+    /// Turkey time, optionally limited to results solved in `[from, to)`.
+    async fn find_progress_points(
+        &self,
+        user_id: Uuid,
+        grouping: TimelineGrouping,
+        granularity: TimelineGranularity,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<ProgressPoint>, DomainError>;
+}
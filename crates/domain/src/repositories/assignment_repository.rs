@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::{Assignment, AssignmentSubmission, AssignmentSummary, StudentAssignment};
+use crate::errors::DomainError;
+
+/// Repository trait for classroom assignments and the results submitted for them.
+#[async_trait]
+pub trait AssignmentRepository: Send + Sync {
+    /// Creates a new assignment.
+    async fn create(&self, assignment: &Assignment) -> Result<Assignment, DomainError>;
+
+    /// Finds an assignment by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Assignment>, DomainError>;
+
+    /// Deletes an assignment.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+
+    /// Lists the assignments of a classroom with completion counts, by due date.
+    async fn find_summaries_by_classroom(
+        &self,
+        classroom_id: Uuid,
+    ) -> Result<Vec<AssignmentSummary>, DomainError>;
+
+    /// Lists every enrolled student with their first result solved for the assignment.
+    async fn find_submissions(
+        &self,
+        assignment: &Assignment,
+    ) -> Result<Vec<AssignmentSubmission>, DomainError>;
+
+    /// Lists the assignments of every classroom a student is enrolled in, with their result.
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<StudentAssignment>, DomainError>;
+}
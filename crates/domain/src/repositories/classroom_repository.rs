@@ -0,0 +1,46 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::{Classroom, ClassroomStudent, Enrollment};
+use crate::errors::DomainError;
+
+/// Repository trait for classrooms and their enrolled students.
+#[async_trait]
+pub trait ClassroomRepository: Send + Sync {
+    /// Creates a new classroom.
+    async fn create(&self, classroom: &Classroom) -> Result<Classroom, DomainError>;
+
+    /// Finds a classroom by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Classroom>, DomainError>;
+
+    /// Finds a classroom by its invite code.
+    async fn find_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Option<Classroom>, DomainError>;
+
+    /// Finds the classrooms a teacher owns.
+    async fn find_by_teacher(&self, teacher_id: Uuid) -> Result<Vec<Classroom>, DomainError>;
+
+    /// Finds the classrooms a student is enrolled in.
+    async fn find_by_student(&self, student_id: Uuid) -> Result<Vec<Classroom>, DomainError>;
+
+    /// Updates the name and invite code of a classroom.
+    async fn update(&self, classroom: &Classroom) -> Result<Classroom, DomainError>;
+
+    /// Deletes a classroom with its enrollments and assignments.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+
+    /// Enrolls a student. Returns false if the student was already enrolled.
+    async fn enroll(&self, enrollment: &Enrollment) -> Result<bool, DomainError>;
+
+    /// Removes a student from a classroom. Returns false if they were not enrolled.
+    async fn unenroll(&self, classroom_id: Uuid, student_id: Uuid) -> Result<bool, DomainError>;
+
+    /// Checks whether a student is enrolled in a classroom.
+    async fn is_enrolled(&self, classroom_id: Uuid, student_id: Uuid) -> Result<bool, DomainError>;
+
+    /// Lists the students enrolled in a classroom, by username.
+    async fn find_students(&self, classroom_id: Uuid)
+        -> Result<Vec<ClassroomStudent>, DomainError>;
+}
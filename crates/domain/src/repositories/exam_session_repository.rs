@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::entities::{ExamSession, TestResult};
+use crate::errors::DomainError;
+
+/// Repository trait for exam session data access operations.
+#[async_trait]
+pub trait ExamSessionRepository: Send + Sync {
+    /// Creates a new exam session.
+    async fn create(&self, session: &ExamSession) -> Result<ExamSession, DomainError>;
+
+    /// Finds an exam session by its ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamSession>, DomainError>;
+
+    /// Finds the in-progress session of a user for a practice test, if any.
+    async fn find_in_progress_by_user_and_practice_test(
+        &self,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<Option<ExamSession>, DomainError>;
+
+    /// Finds in-progress sessions whose deadline has passed.
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<ExamSession>, DomainError>;
+
+    /// Saves the autosaved answers of a session that is still in progress.
+    /// Returns false if the session was submitted in the meantime.
+    async fn save_answers(&self, session: &ExamSession) -> Result<bool, DomainError>;
+
+    /// Marks a session as submitted and stores its test result in one transaction.
+    /// Returns false, storing nothing, if the session was already submitted.
+    async fn submit(
+        &self,
+        session: &ExamSession,
+        test_result: &TestResult,
+    ) -> Result<bool, DomainError>;
+}
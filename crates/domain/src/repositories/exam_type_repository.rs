@@ -13,6 +13,9 @@ pub trait ExamTypeRepository: Send + Sync {
     /// Finds an exam type by its unique ID.
     async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamType>, DomainError>;
 
+    /// Finds the exam types with the given IDs. Unknown IDs are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<ExamType>, DomainError>;
+
     /// Finds an exam type by its name (case-insensitive).
     async fn find_by_name(&self, name: &str) -> Result<Option<ExamType>, DomainError>;
 
@@ -25,4 +28,3 @@ pub trait ExamTypeRepository: Send + Sync {
     /// Lists all exam types.
     async fn list_all(&self) -> Result<Vec<ExamType>, DomainError>;
 }
-
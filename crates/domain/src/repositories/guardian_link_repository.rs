@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::{GuardianLink, GuardianLinkDetail};
+use crate::errors::DomainError;
+
+/// Repository trait for links between guardians and students.
+#[async_trait]
+pub trait GuardianLinkRepository: Send + Sync {
+    /// Creates a new link.
+    async fn create(&self, link: &GuardianLink) -> Result<GuardianLink, DomainError>;
+
+    /// Finds a link by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<GuardianLink>, DomainError>;
+
+    /// Finds the link between a guardian and a student, pending or accepted.
+    async fn find_by_pair(
+        &self,
+        guardian_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<Option<GuardianLink>, DomainError>;
+
+    /// Lists a guardian's links with usernames, by student username.
+    async fn find_by_guardian(
+        &self,
+        guardian_id: Uuid,
+    ) -> Result<Vec<GuardianLinkDetail>, DomainError>;
+
+    /// Lists a student's links with usernames, by guardian username.
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<GuardianLinkDetail>, DomainError>;
+
+    /// Updates the status of a link.
+    async fn update(&self, link: &GuardianLink) -> Result<GuardianLink, DomainError>;
+
+    /// Deletes a link.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
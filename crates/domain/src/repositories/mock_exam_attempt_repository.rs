@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::entities::{MockExamAttempt, TestResult};
+use crate::errors::DomainError;
+
+/// Repository trait for mock exam attempt data access operations.
+#[async_trait]
+pub trait MockExamAttemptRepository: Send + Sync {
+    /// Creates a new attempt together with its sections.
+    async fn create(&self, attempt: &MockExamAttempt) -> Result<MockExamAttempt, DomainError>;
+
+    /// Finds an attempt by its ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<MockExamAttempt>, DomainError>;
+
+    /// Finds the in-progress attempt of a user for a mock exam, if any.
+    async fn find_in_progress_by_user_and_mock_exam(
+        &self,
+        user_id: Uuid,
+        mock_exam_id: Uuid,
+    ) -> Result<Option<MockExamAttempt>, DomainError>;
+
+    /// Lists the attempts of a user, newest first.
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<MockExamAttempt>, DomainError>;
+
+    /// Finds in-progress attempts whose deadline has passed.
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<MockExamAttempt>, DomainError>;
+
+    /// Saves the autosaved answers of an attempt that is still in progress.
+    /// Returns false if the attempt was submitted in the meantime.
+    async fn save_answers(&self, attempt: &MockExamAttempt) -> Result<bool, DomainError>;
+
+    /// Marks an attempt as submitted and stores the test results of its sections
+    /// in one transaction. Returns false, storing nothing, if the attempt was
+    /// already submitted.
+    async fn submit(
+        &self,
+        attempt: &MockExamAttempt,
+        test_results: &[TestResult],
+    ) -> Result<bool, DomainError>;
+}
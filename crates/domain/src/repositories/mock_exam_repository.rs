@@ -0,0 +1,24 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::MockExam;
+use crate::errors::DomainError;
+
+/// Repository trait for mock exam data access operations.
+#[async_trait]
+pub trait MockExamRepository: Send + Sync {
+    /// Creates a new mock exam together with its sections.
+    async fn create(&self, mock_exam: &MockExam) -> Result<MockExam, DomainError>;
+
+    /// Finds a mock exam by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<MockExam>, DomainError>;
+
+    /// Lists mock exams, optionally for a single exam type.
+    async fn list(&self, exam_type_id: Option<Uuid>) -> Result<Vec<MockExam>, DomainError>;
+
+    /// Updates an existing mock exam, replacing its sections.
+    async fn update(&self, mock_exam: &MockExam) -> Result<MockExam, DomainError>;
+
+    /// Deletes a mock exam by ID.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
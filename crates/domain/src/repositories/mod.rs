@@ -1,22 +1,49 @@
+mod achievement_repository;
+mod analytics_repository;
+mod assignment_repository;
+mod classroom_repository;
+mod exam_session_repository;
 mod exam_type_repository;
+mod guardian_link_repository;
 mod lesson_repository;
+mod mock_exam_attempt_repository;
+mod mock_exam_repository;
+mod permission_repository;
 mod practice_test_repository;
 mod refresh_token_repository;
+mod retake_policy_repository;
 mod role_repository;
+mod score_table_repository;
+mod scoring_policy_repository;
+mod study_plan_repository;
 mod subject_repository;
 mod test_book_repository;
 mod test_book_subject_repository;
 mod test_result_repository;
+mod topic_repository;
 mod user_repository;
 
+pub use achievement_repository::AchievementRepository;
+pub use analytics_repository::AnalyticsRepository;
+pub use assignment_repository::AssignmentRepository;
+pub use classroom_repository::ClassroomRepository;
+pub use exam_session_repository::ExamSessionRepository;
 pub use exam_type_repository::ExamTypeRepository;
+pub use guardian_link_repository::GuardianLinkRepository;
 pub use lesson_repository::LessonRepository;
-pub use practice_test_repository::PracticeTestRepository;
+pub use mock_exam_attempt_repository::MockExamAttemptRepository;
+pub use mock_exam_repository::MockExamRepository;
+pub use permission_repository::PermissionRepository;
+pub use practice_test_repository::{PracticeTestRepository, RegradeLock};
 pub use refresh_token_repository::RefreshTokenRepository;
+pub use retake_policy_repository::RetakePolicyRepository;
 pub use role_repository::RoleRepository;
+pub use score_table_repository::ScoreTableRepository;
+pub use scoring_policy_repository::ScoringPolicyRepository;
+pub use study_plan_repository::StudyPlanRepository;
 pub use subject_repository::SubjectRepository;
 pub use test_book_repository::TestBookRepository;
 pub use test_book_subject_repository::TestBookSubjectRepository;
 pub use test_result_repository::TestResultRepository;
+pub use topic_repository::TopicRepository;
 pub use user_repository::UserRepository;
-
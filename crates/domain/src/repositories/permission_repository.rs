@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::Permission;
+use crate::errors::DomainError;
+
+/// Repository trait for permissions and the roles they are granted to.
+#[async_trait]
+pub trait PermissionRepository: Send + Sync {
+    /// Lists all permissions.
+    async fn list(&self) -> Result<Vec<Permission>, DomainError>;
+
+    /// Finds a permission by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Permission>, DomainError>;
+
+    /// Lists the permissions granted to a role.
+    async fn find_by_role_id(&self, role_id: Uuid) -> Result<Vec<Permission>, DomainError>;
+
+    /// Lists the names of every permission granted to any of a user's roles.
+    async fn find_names_by_user_id(&self, user_id: Uuid) -> Result<Vec<String>, DomainError>;
+
+    /// Grants a permission to a role. Returns false if the role already had it.
+    async fn grant_to_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<bool, DomainError>;
+
+    /// Revokes a permission from a role. Returns false if the role did not have it.
+    async fn revoke_from_role(
+        &self,
+        role_id: Uuid,
+        permission_id: Uuid,
+    ) -> Result<bool, DomainError>;
+}
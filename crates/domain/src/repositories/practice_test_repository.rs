@@ -1,22 +1,50 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::entities::PracticeTest;
+use crate::entities::{AnswerKeyRevision, PracticeTest, Question};
 use crate::errors::DomainError;
 
+/// Exclusive right to re-grade the results of one practice test, released when dropped.
+pub struct RegradeLock {
+    _guard: Box<dyn Send>,
+}
+
+impl RegradeLock {
+    /// Wraps whatever keeps the lock held, such as an open database transaction.
+    pub fn new(guard: impl Send + 'static) -> Self {
+        Self {
+            _guard: Box::new(guard),
+        }
+    }
+}
+
 /// Repository trait for practice test data access operations.
 #[async_trait]
 pub trait PracticeTestRepository: Send + Sync {
-    /// Creates a new practice test in the database.
+    /// Creates a new practice test in the database, recording its first answer key revision.
     async fn create(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError>;
 
     /// Finds a practice test by its unique ID.
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PracticeTest>, DomainError>;
 
+    /// Finds the practice tests with the given IDs. Unknown IDs are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<PracticeTest>, DomainError>;
+
     /// Finds practice tests by test book ID.
-    async fn find_by_test_book_id(&self, test_book_id: Uuid) -> Result<Vec<PracticeTest>, DomainError>;
+    async fn find_by_test_book_id(
+        &self,
+        test_book_id: Uuid,
+    ) -> Result<Vec<PracticeTest>, DomainError>;
+
+    /// Finds all practice tests of the test books in which a user solved at least one test.
+    /// Results of mock exam sections do not start a test book.
+    async fn find_in_test_books_started_by(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<PracticeTest>, DomainError>;
 
-    /// Updates an existing practice test.
+    /// Updates an existing practice test, recording a new answer key revision if the
+    /// practice test's revision number has not been stored yet.
     async fn update(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError>;
 
     /// Deletes a practice test by ID.
@@ -24,5 +52,38 @@ pub trait PracticeTestRepository: Send + Sync {
 
     /// Lists all practice tests.
     async fn list_all(&self) -> Result<Vec<PracticeTest>, DomainError>;
-}
 
+    /// Lists the questions of a practice test by position; empty for tests that
+    /// only have a plain answer key.
+    async fn find_questions(&self, practice_test_id: Uuid) -> Result<Vec<Question>, DomainError>;
+
+    /// Replaces the questions of a practice test and updates the practice test,
+    /// whose answer key is derived from them, in a single transaction.
+    async fn replace_questions(
+        &self,
+        practice_test: &PracticeTest,
+        questions: &[Question],
+    ) -> Result<(PracticeTest, Vec<Question>), DomainError>;
+
+    /// Lists the answer key revisions of a practice test, newest first.
+    async fn find_answer_key_revisions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<AnswerKeyRevision>, DomainError>;
+
+    /// Finds answer key revisions whose re-grade has not completed yet.
+    async fn find_pending_answer_key_revisions(
+        &self,
+    ) -> Result<Vec<AnswerKeyRevision>, DomainError>;
+
+    /// Waits until no other re-grade of the practice test runs, on any instance, and
+    /// holds it off until the returned lock is dropped.
+    async fn lock_regrade(&self, practice_test_id: Uuid) -> Result<RegradeLock, DomainError>;
+
+    /// Marks an answer key revision as re-graded.
+    async fn mark_answer_key_revision_regraded(
+        &self,
+        practice_test_id: Uuid,
+        revision: i32,
+    ) -> Result<(), DomainError>;
+}
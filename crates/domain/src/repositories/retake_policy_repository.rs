@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::{RetakePolicy, RetakePolicyScope};
+use crate::errors::DomainError;
+
+/// Repository trait for retake policy data access operations.
+#[async_trait]
+pub trait RetakePolicyRepository: Send + Sync {
+    /// Creates or replaces the policy of the policy's scope.
+    async fn save(&self, policy: &RetakePolicy) -> Result<RetakePolicy, DomainError>;
+
+    /// Finds the policy defined directly on an exam type or test book.
+    async fn find_by_scope(
+        &self,
+        scope: RetakePolicyScope,
+    ) -> Result<Option<RetakePolicy>, DomainError>;
+
+    /// Finds the policy in effect for a test book: its own policy, else its exam type's.
+    async fn find_effective(
+        &self,
+        test_book_id: Uuid,
+        exam_type_id: Uuid,
+    ) -> Result<Option<RetakePolicy>, DomainError>;
+
+    /// Deletes a retake policy by its ID.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
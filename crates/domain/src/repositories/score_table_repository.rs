@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::ScoreTable;
+use crate::errors::DomainError;
+
+/// Repository trait for score coefficient table data access operations.
+#[async_trait]
+pub trait ScoreTableRepository: Send + Sync {
+    /// Creates or replaces the table of an exam type for the table's year, including its coefficients.
+    async fn save(&self, score_table: &ScoreTable) -> Result<ScoreTable, DomainError>;
+
+    /// Finds the table of an exam type for a given year.
+    async fn find_by_exam_type_and_year(
+        &self,
+        exam_type_id: Uuid,
+        year: i32,
+    ) -> Result<Option<ScoreTable>, DomainError>;
+
+    /// Finds the table with the most recent year for an exam type.
+    async fn find_latest_by_exam_type(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Option<ScoreTable>, DomainError>;
+
+    /// Lists all tables of an exam type, newest year first.
+    async fn list_by_exam_type(&self, exam_type_id: Uuid) -> Result<Vec<ScoreTable>, DomainError>;
+
+    /// Deletes a table and its coefficients by ID.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::ScoringPolicy;
+use crate::errors::DomainError;
+
+/// Repository trait for scoring policy data access operations.
+#[async_trait]
+pub trait ScoringPolicyRepository: Send + Sync {
+    /// Creates a new scoring policy version in the database.
+    async fn create(&self, policy: &ScoringPolicy) -> Result<ScoringPolicy, DomainError>;
+
+    /// Finds a scoring policy version by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScoringPolicy>, DomainError>;
+
+    /// Finds the latest scoring policy version for an exam type.
+    async fn find_current_by_exam_type(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Option<ScoringPolicy>, DomainError>;
+
+    /// Lists all scoring policy versions for an exam type, newest first.
+    async fn list_by_exam_type(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<ScoringPolicy>, DomainError>;
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use uuid::Uuid;
+
+use crate::entities::{DailyActivity, StudyGoal, StudyPlan};
+use crate::errors::DomainError;
+
+/// Repository trait for study plans, daily goals and the activity they are measured by.
+#[async_trait]
+pub trait StudyPlanRepository: Send + Sync {
+    /// Finds the study plan of a user.
+    async fn find_plan_by_user(&self, user_id: Uuid) -> Result<Option<StudyPlan>, DomainError>;
+
+    /// Creates the user's study plan, or replaces its exam type and date if one exists.
+    async fn save_plan(&self, plan: &StudyPlan) -> Result<StudyPlan, DomainError>;
+
+    /// Creates a new daily goal.
+    async fn create_goal(&self, goal: &StudyGoal) -> Result<StudyGoal, DomainError>;
+
+    /// Finds a goal by its unique ID.
+    async fn find_goal_by_id(&self, id: Uuid) -> Result<Option<StudyGoal>, DomainError>;
+
+    /// Finds all goals of a user, including archived ones, oldest first.
+    async fn find_goals_by_user(&self, user_id: Uuid) -> Result<Vec<StudyGoal>, DomainError>;
+
+    /// Marks a goal as archived at the given time.
+    async fn archive_goal(&self, id: Uuid, archived_at: DateTime<Utc>) -> Result<(), DomainError>;
+
+    /// Aggregates the user's solved tests and questions per UTC day and lesson,
+    /// from `from` to `to` (inclusive).
+    async fn find_daily_activity(
+        &self,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyActivity>, DomainError>;
+}
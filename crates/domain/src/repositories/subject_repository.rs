@@ -13,6 +13,9 @@ pub trait SubjectRepository: Send + Sync {
     /// Finds a subject by its unique ID.
     async fn find_by_id(&self, id: Uuid) -> Result<Option<Subject>, DomainError>;
 
+    /// Finds the subjects with the given IDs. Unknown IDs are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Subject>, DomainError>;
+
     /// Finds subjects by exam type ID.
     async fn find_by_exam_type_id(&self, exam_type_id: Uuid) -> Result<Vec<Subject>, DomainError>;
 
@@ -32,4 +35,3 @@ pub trait SubjectRepository: Send + Sync {
     /// Lists all subjects.
     async fn list_all(&self) -> Result<Vec<Subject>, DomainError>;
 }
-
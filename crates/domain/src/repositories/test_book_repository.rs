@@ -13,6 +13,9 @@ pub trait TestBookRepository: Send + Sync {
     /// Finds a test book by its unique ID.
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TestBook>, DomainError>;
 
+    /// Finds the test books with the given IDs. Unknown IDs are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestBook>, DomainError>;
+
     /// Finds test books by subject ID.
     async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<TestBook>, DomainError>;
 
@@ -38,4 +41,3 @@ pub trait TestBookRepository: Send + Sync {
     /// Lists all test books.
     async fn list_all(&self) -> Result<Vec<TestBook>, DomainError>;
 }
-
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use crate::errors::DomainError;
@@ -7,18 +8,11 @@ use crate::errors::DomainError;
 #[async_trait]
 pub trait TestBookSubjectRepository: Send + Sync {
     /// Adds a subject to a test book.
-    async fn add_subject(
-        &self,
-        test_book_id: Uuid,
-        subject_id: Uuid,
-    ) -> Result<(), DomainError>;
+    async fn add_subject(&self, test_book_id: Uuid, subject_id: Uuid) -> Result<(), DomainError>;
 
     /// Removes a subject from a test book.
-    async fn remove_subject(
-        &self,
-        test_book_id: Uuid,
-        subject_id: Uuid,
-    ) -> Result<(), DomainError>;
+    async fn remove_subject(&self, test_book_id: Uuid, subject_id: Uuid)
+        -> Result<(), DomainError>;
 
     /// Sets all subjects for a test book (replaces existing relationships).
     async fn set_subjects(
@@ -33,6 +27,13 @@ pub trait TestBookSubjectRepository: Send + Sync {
         test_book_id: Uuid,
     ) -> Result<Vec<Uuid>, DomainError>;
 
+    /// Finds the subject IDs of several test books at once, keyed by test book ID.
+    /// Test books without subjects are absent from the map.
+    async fn find_subject_ids_by_test_book_ids(
+        &self,
+        test_book_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Uuid>>, DomainError>;
+
     /// Finds all test book IDs for a subject.
     async fn find_test_book_ids_by_subject_id(
         &self,
@@ -42,4 +43,3 @@ pub trait TestBookSubjectRepository: Send + Sync {
     /// Deletes all relationships for a test book.
     async fn delete_by_test_book_id(&self, test_book_id: Uuid) -> Result<(), DomainError>;
 }
-
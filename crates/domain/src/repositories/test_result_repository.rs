@@ -1,7 +1,10 @@
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::entities::TestResult;
+use crate::entities::{
+    LeaderboardPage, LeaderboardScope, NetDistributionBucket, TestBookProgress, TestResult,
+    TestResultHistory,
+};
 use crate::errors::DomainError;
 
 /// Repository trait for test result data access operations.
@@ -13,11 +16,24 @@ pub trait TestResultRepository: Send + Sync {
     /// Finds a test result by its unique ID.
     async fn find_by_id(&self, id: Uuid) -> Result<Option<TestResult>, DomainError>;
 
+    /// Finds the test results with the given IDs. Unknown IDs are left out.
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestResult>, DomainError>;
+
     /// Finds test results by user ID.
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<TestResult>, DomainError>;
 
     /// Finds test results by practice test ID.
-    async fn find_by_practice_test_id(&self, practice_test_id: Uuid) -> Result<Vec<TestResult>, DomainError>;
+    async fn find_by_practice_test_id(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<TestResult>, DomainError>;
+
+    /// Finds which of the given users have a result for a practice test.
+    async fn find_user_ids_with_result(
+        &self,
+        practice_test_id: Uuid,
+        user_ids: &[Uuid],
+    ) -> Result<Vec<Uuid>, DomainError>;
 
     /// Finds a test result by user ID and practice test ID.
     async fn find_by_user_and_practice_test(
@@ -26,14 +42,54 @@ pub trait TestResultRepository: Send + Sync {
         practice_test_id: Uuid,
     ) -> Result<Option<TestResult>, DomainError>;
 
+    /// Finds all attempts of a user at a practice test, oldest first.
+    /// Used to apply the retake policy of the test; mock exam results are left out.
+    async fn find_attempts_by_user_and_practice_test(
+        &self,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<TestResult>, DomainError>;
+
     /// Finds the most recent test result for a user and practice test.
-    /// Used to check if user can retake the test (24 hour rule).
     async fn find_latest_by_user_and_practice_test(
         &self,
         user_id: Uuid,
         practice_test_id: Uuid,
     ) -> Result<Option<TestResult>, DomainError>;
 
+    /// Finds all attempts of a user at any of the given practice tests,
+    /// oldest first within each practice test. Mock exam results are left out.
+    async fn find_attempts_by_user_and_practice_tests(
+        &self,
+        user_id: Uuid,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<TestResult>, DomainError>;
+
+    /// Finds all results of a user at any of the given practice tests, mock exam
+    /// sections included, oldest first within each practice test.
+    async fn find_results_by_user_and_practice_tests(
+        &self,
+        user_id: Uuid,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<TestResult>, DomainError>;
+
+    /// Finds the most recent attempt of a user at each of the given practice
+    /// tests. Practice tests the user has not solved and mock exam results are left out.
+    async fn find_latest_by_user_and_practice_tests(
+        &self,
+        user_id: Uuid,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<TestResult>, DomainError>;
+
+    /// Counts the practice tests of each given test book and how many of them
+    /// a user has solved outside mock exams. Returns one entry per test book,
+    /// including empty ones.
+    async fn find_test_book_progress(
+        &self,
+        user_id: Uuid,
+        test_book_ids: &[Uuid],
+    ) -> Result<Vec<TestBookProgress>, DomainError>;
+
     /// Lists test results with pagination.
     async fn list(
         &self,
@@ -42,5 +98,43 @@ pub trait TestResultRepository: Send + Sync {
         page: u32,
         per_page: u32,
     ) -> Result<(Vec<TestResult>, u64), DomainError>;
-}
 
+    /// Finds results of a practice test graded with an answer key revision older than the given one.
+    async fn find_outdated_by_practice_test(
+        &self,
+        practice_test_id: Uuid,
+        answer_key_revision: i32,
+    ) -> Result<Vec<TestResult>, DomainError>;
+
+    /// Saves a re-graded result, storing the previous score in its history if given.
+    /// Returns `None` without saving if the stored result was already graded with the
+    /// same or a newer answer key revision.
+    async fn save_regrade(
+        &self,
+        test_result: &TestResult,
+        previous: Option<&TestResultHistory>,
+    ) -> Result<Option<TestResult>, DomainError>;
+
+    /// Lists the previous scores of a test result, newest first.
+    async fn find_history_by_test_result_id(
+        &self,
+        test_result_id: Uuid,
+    ) -> Result<Vec<TestResultHistory>, DomainError>;
+
+    /// Ranks users within a leaderboard scope and returns one page of it together
+    /// with the viewer's entry. Opted-out users are only ranked for themselves.
+    async fn find_leaderboard(
+        &self,
+        scope: LeaderboardScope,
+        viewer_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardPage, DomainError>;
+
+    /// Returns how many first attempts of a practice test scored each net,
+    /// read from the maintained aggregate rather than the results themselves.
+    async fn find_net_distribution(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<NetDistributionBucket>, DomainError>;
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::entities::Topic;
+use crate::errors::DomainError;
+
+/// Repository trait for curriculum topic data access operations.
+#[async_trait]
+pub trait TopicRepository: Send + Sync {
+    /// Creates a new topic in the database.
+    async fn create(&self, topic: &Topic) -> Result<Topic, DomainError>;
+
+    /// Finds a topic by its unique ID.
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Topic>, DomainError>;
+
+    /// Finds all topics of a subject.
+    async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<Topic>, DomainError>;
+
+    /// Updates the name of an existing topic.
+    async fn update(&self, topic: &Topic) -> Result<Topic, DomainError>;
+
+    /// Stores the parent and position of the given topics in a single transaction.
+    async fn update_placements(&self, topics: &[Topic]) -> Result<(), DomainError>;
+
+    /// Deletes a topic and its descendants by ID.
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
@@ -19,6 +19,12 @@ pub trait UserRepository: Send + Sync {
     /// Finds a user by their username (case-insensitive).
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, DomainError>;
 
+    /// Finds the non-deleted users holding any of the given student numbers.
+    async fn find_by_student_numbers(
+        &self,
+        student_numbers: &[String],
+    ) -> Result<Vec<User>, DomainError>;
+
     /// Updates an existing user.
     async fn update(&self, user: &User) -> Result<User, DomainError>;
 
@@ -59,4 +65,3 @@ pub trait UserRepository: Send + Sync {
     /// Gets all role names for a user.
     async fn get_user_roles(&self, user_id: Uuid) -> Result<Vec<String>, DomainError>;
 }
-
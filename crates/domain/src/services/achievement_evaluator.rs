@@ -0,0 +1,184 @@
+use chrono::{Duration, NaiveDate};
+use uuid::Uuid;
+
+use crate::entities::{Achievement, AchievementFacts, AchievementRule};
+
+/// How far a user got towards an achievement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AchievementProgress {
+    pub achievement_id: Uuid,
+    /// Current value in the rule's unit; the current streak for streak rules
+    pub current: f64,
+    pub target: f64,
+    /// Whether the threshold has been reached; streak rules count the longest streak
+    pub achieved: bool,
+}
+
+/// Domain service evaluating achievement rules against a user's results.
+pub struct AchievementEvaluator;
+
+impl AchievementEvaluator {
+    /// Evaluates every achievement against the user's facts as of `today`.
+    pub fn evaluate(
+        achievements: &[Achievement],
+        facts: &AchievementFacts,
+        today: NaiveDate,
+    ) -> Vec<AchievementProgress> {
+        let (current_streak, longest_streak) = Self::solving_streaks(&facts.solve_days, today);
+
+        achievements
+            .iter()
+            .map(|achievement| {
+                let (current, best) = match achievement.rule {
+                    AchievementRule::SolvingStreak => {
+                        (current_streak as f64, longest_streak as f64)
+                    }
+                    AchievementRule::TestsSolved => {
+                        (facts.tests_solved as f64, facts.tests_solved as f64)
+                    }
+                    AchievementRule::TestBooksCompleted => (
+                        facts.test_books_completed as f64,
+                        facts.test_books_completed as f64,
+                    ),
+                    AchievementRule::NetScore => {
+                        let best_net = facts
+                            .best_nets
+                            .iter()
+                            .filter(|net| {
+                                achievement
+                                    .exam_type_id
+                                    .is_none_or(|id| net.exam_type_id == id)
+                                    && achievement.lesson_id.is_none_or(|id| net.lesson_id == id)
+                            })
+                            .map(|net| net.best_net)
+                            .fold(0.0, f64::max);
+                        (best_net, best_net)
+                    }
+                };
+
+                AchievementProgress {
+                    achievement_id: achievement.id,
+                    current,
+                    target: achievement.threshold,
+                    achieved: best >= achievement.threshold,
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the current and longest runs of consecutive solve days.
+    ///
+    /// The current run is still alive if its last day is today or yesterday.
+    pub fn solving_streaks(solve_days: &[NaiveDate], today: NaiveDate) -> (u32, u32) {
+        let mut longest = 0;
+        let mut run = 0;
+        let mut previous: Option<NaiveDate> = None;
+        for day in solve_days {
+            run = match previous {
+                Some(previous) if *day == previous => run,
+                Some(previous) if *day == previous + Duration::days(1) => run + 1,
+                _ => 1,
+            };
+            longest = longest.max(run);
+            previous = Some(*day);
+        }
+
+        let current = match previous {
+            Some(last) if last >= today - Duration::days(1) => run,
+            _ => 0,
+        };
+        (current, longest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::BestNet;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn achievement(rule: AchievementRule, threshold: f64) -> Achievement {
+        Achievement::new(
+            "code".to_string(),
+            "Name".to_string(),
+            "Description".to_string(),
+            rule,
+            threshold,
+            None,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_solving_streaks() {
+        let days = [date(1), date(2), date(3), date(5), date(6)];
+
+        assert_eq!(
+            AchievementEvaluator::solving_streaks(&days, date(7)),
+            (2, 3)
+        );
+        assert_eq!(
+            AchievementEvaluator::solving_streaks(&days, date(8)),
+            (0, 3)
+        );
+        assert_eq!(AchievementEvaluator::solving_streaks(&[], date(8)), (0, 0));
+    }
+
+    #[test]
+    fn test_streak_achievement_counts_longest_streak() {
+        let facts = AchievementFacts {
+            solve_days: vec![date(1), date(2), date(3), date(10)],
+            ..Default::default()
+        };
+        let achievements = [
+            achievement(AchievementRule::SolvingStreak, 3.0),
+            achievement(AchievementRule::SolvingStreak, 7.0),
+        ];
+
+        let progress = AchievementEvaluator::evaluate(&achievements, &facts, date(10));
+
+        assert!(progress[0].achieved);
+        assert!(!progress[1].achieved);
+        assert_eq!(progress[1].current, 1.0);
+        assert_eq!(progress[1].target, 7.0);
+    }
+
+    #[test]
+    fn test_net_score_achievement_is_limited_to_exam_type_and_lesson() {
+        let (tyt, turkish, math) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let facts = AchievementFacts {
+            tests_solved: 12,
+            best_nets: vec![
+                BestNet {
+                    exam_type_id: tyt,
+                    lesson_id: turkish,
+                    best_net: 33.5,
+                },
+                BestNet {
+                    exam_type_id: tyt,
+                    lesson_id: math,
+                    best_net: 37.0,
+                },
+            ],
+            ..Default::default()
+        };
+        let mut turkish_net = achievement(AchievementRule::NetScore, 35.0);
+        turkish_net.exam_type_id = Some(tyt);
+        turkish_net.lesson_id = Some(turkish);
+        let achievements = [
+            turkish_net,
+            achievement(AchievementRule::NetScore, 35.0),
+            achievement(AchievementRule::TestsSolved, 10.0),
+        ];
+
+        let progress = AchievementEvaluator::evaluate(&achievements, &facts, date(10));
+
+        assert!(!progress[0].achieved);
+        assert_eq!(progress[0].current, 33.5);
+        assert!(progress[1].achieved);
+        assert!(progress[2].achieved);
+    }
+}
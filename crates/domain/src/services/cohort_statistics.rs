@@ -0,0 +1,123 @@
+use crate::entities::NetDistributionBucket;
+
+/// How a net score compares to the first attempts of everyone who solved a practice test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CohortStatistics {
+    /// Number of users who solved the practice test
+    pub first_attempt_count: i64,
+    pub average_net: f64,
+    pub median_net: f64,
+    /// Percentile rank of the net among first attempts, between 0 and 100;
+    /// first attempts with the same net count half
+    pub percentile: f64,
+}
+
+impl CohortStatistics {
+    /// Computes the statistics of a practice test's first-attempt distribution and the
+    /// percentile of `net_score` within it. Returns `None` if nobody solved the test yet.
+    pub fn compute(distribution: &[NetDistributionBucket], net_score: f64) -> Option<Self> {
+        let mut buckets: Vec<NetDistributionBucket> = distribution
+            .iter()
+            .copied()
+            .filter(|bucket| bucket.first_attempt_count > 0)
+            .collect();
+        buckets.sort_by(|a, b| a.net_score.total_cmp(&b.net_score));
+
+        let total: i64 = buckets.iter().map(|b| b.first_attempt_count).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let sum: f64 = buckets
+            .iter()
+            .map(|b| b.net_score * b.first_attempt_count as f64)
+            .sum();
+        let below: i64 = buckets
+            .iter()
+            .filter(|b| b.net_score < net_score)
+            .map(|b| b.first_attempt_count)
+            .sum();
+        let equal: i64 = buckets
+            .iter()
+            .filter(|b| b.net_score == net_score)
+            .map(|b| b.first_attempt_count)
+            .sum();
+
+        // Median of the expanded distribution: the middle value, or the mean of the two middle values
+        let nth = |index: i64| {
+            let mut seen = 0;
+            for bucket in &buckets {
+                seen += bucket.first_attempt_count;
+                if index < seen {
+                    return bucket.net_score;
+                }
+            }
+            buckets[buckets.len() - 1].net_score
+        };
+        let median_net = if total % 2 == 1 {
+            nth(total / 2)
+        } else {
+            (nth(total / 2 - 1) + nth(total / 2)) / 2.0
+        };
+
+        Some(Self {
+            first_attempt_count: total,
+            average_net: sum / total as f64,
+            median_net,
+            percentile: (below as f64 + equal as f64 / 2.0) / total as f64 * 100.0,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket(net_score: f64, first_attempt_count: i64) -> NetDistributionBucket {
+        NetDistributionBucket {
+            net_score,
+            first_attempt_count,
+        }
+    }
+
+    #[test]
+    fn test_computes_average_median_and_percentile() {
+        let distribution = vec![bucket(10.0, 1), bucket(2.5, 2), bucket(5.0, 1)];
+
+        let stats = CohortStatistics::compute(&distribution, 5.0).unwrap();
+
+        assert_eq!(stats.first_attempt_count, 4);
+        assert_eq!(stats.average_net, 5.0);
+        assert_eq!(stats.median_net, 3.75);
+        assert_eq!(stats.percentile, 62.5);
+    }
+
+    #[test]
+    fn test_odd_count_median_and_extremes() {
+        let distribution = vec![bucket(1.0, 2), bucket(7.0, 1)];
+
+        assert_eq!(
+            CohortStatistics::compute(&distribution, 1.0)
+                .unwrap()
+                .median_net,
+            1.0
+        );
+        assert_eq!(
+            CohortStatistics::compute(&distribution, 8.0)
+                .unwrap()
+                .percentile,
+            100.0
+        );
+        assert_eq!(
+            CohortStatistics::compute(&distribution, 0.0)
+                .unwrap()
+                .percentile,
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_empty_distribution_has_no_statistics() {
+        assert_eq!(CohortStatistics::compute(&[], 3.0), None);
+    }
+}
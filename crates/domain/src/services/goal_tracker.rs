@@ -0,0 +1,282 @@
+use chrono::{Duration, NaiveDate};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::entities::{DailyActivity, GoalMetric, StudyGoal};
+
+/// How far a student got towards one goal in a day or period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalProgress {
+    pub goal_id: Uuid,
+    /// Tests or questions solved
+    pub achieved: i64,
+    /// Sum of the daily targets over the days the goal applied
+    pub target: i64,
+    /// Days on which the daily target was reached
+    pub met_days: u32,
+    /// Days on which the goal applied
+    pub active_days: u32,
+}
+
+/// Goal completion over a day or a range of days.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GoalPeriod {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    /// Progress of every goal that applied during the period
+    pub goals: Vec<GoalProgress>,
+    /// Days on which at least one goal applied
+    pub active_days: u32,
+    /// Days on which every applying goal was met
+    pub completed_days: u32,
+}
+
+impl GoalPeriod {
+    /// Returns true if every goal was met on every day it applied.
+    pub fn is_completed(&self) -> bool {
+        self.active_days > 0 && self.completed_days == self.active_days
+    }
+}
+
+/// Current and longest run of consecutive completed days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct GoalStreak {
+    /// Completed days up to today; today only counts once completed
+    pub current: u32,
+    pub longest: u32,
+}
+
+/// Domain service evaluating daily study goals against a student's activity.
+///
+/// Days are UTC calendar days. A day is completed when at least one goal applies
+/// to it and every applying goal is met.
+pub struct GoalTracker {
+    goals: Vec<StudyGoal>,
+    activity: HashMap<NaiveDate, Vec<DailyActivity>>,
+}
+
+impl GoalTracker {
+    /// Creates a tracker for a student's goals, including archived ones, and activity.
+    pub fn new(goals: Vec<StudyGoal>, activity: Vec<DailyActivity>) -> Self {
+        let mut by_day: HashMap<NaiveDate, Vec<DailyActivity>> = HashMap::new();
+        for entry in activity {
+            by_day.entry(entry.day).or_default().push(entry);
+        }
+        Self {
+            goals,
+            activity: by_day,
+        }
+    }
+
+    /// Evaluates the goals on each day from `start` to `end` (inclusive).
+    pub fn period(&self, start: NaiveDate, end: NaiveDate) -> GoalPeriod {
+        let mut progress: Vec<GoalProgress> = Vec::new();
+        let mut active_days = 0;
+        let mut completed_days = 0;
+
+        for day in start.iter_days().take_while(|day| *day <= end) {
+            let day_progress = self.day(day);
+            if day_progress.is_empty() {
+                continue;
+            }
+            active_days += 1;
+            if day_progress.iter().all(|p| p.met_days == 1) {
+                completed_days += 1;
+            }
+            for entry in day_progress {
+                match progress.iter_mut().find(|p| p.goal_id == entry.goal_id) {
+                    Some(total) => {
+                        total.achieved += entry.achieved;
+                        total.target += entry.target;
+                        total.met_days += entry.met_days;
+                        total.active_days += entry.active_days;
+                    }
+                    None => progress.push(entry),
+                }
+            }
+        }
+
+        GoalPeriod {
+            start,
+            end,
+            goals: progress,
+            active_days,
+            completed_days,
+        }
+    }
+
+    /// Splits the days from `start` to `end` into periods of `days` days each.
+    pub fn periods(&self, start: NaiveDate, end: NaiveDate, days: i64) -> Vec<GoalPeriod> {
+        let mut periods = Vec::new();
+        let mut period_start = start;
+        while period_start <= end {
+            let period_end = (period_start + Duration::days(days - 1)).min(end);
+            periods.push(self.period(period_start, period_end));
+            period_start = period_end + Duration::days(1);
+        }
+        periods
+    }
+
+    /// Computes the student's streaks as of `today`.
+    pub fn streak(&self, today: NaiveDate) -> GoalStreak {
+        let Some(first_day) = self
+            .goals
+            .iter()
+            .map(|goal| goal.created_at.date_naive())
+            .min()
+        else {
+            return GoalStreak::default();
+        };
+
+        let mut streak = GoalStreak::default();
+        let mut run = 0;
+        for day in first_day.iter_days().take_while(|day| *day <= today) {
+            if self.is_completed(day) {
+                run += 1;
+                streak.longest = streak.longest.max(run);
+            } else if day < today {
+                run = 0;
+            }
+        }
+        streak.current = run;
+        streak
+    }
+
+    /// Returns true if at least one goal applies to the day and all of them were met.
+    fn is_completed(&self, day: NaiveDate) -> bool {
+        let progress = self.day(day);
+        !progress.is_empty() && progress.iter().all(|p| p.met_days == 1)
+    }
+
+    /// Evaluates the goals applying to one day.
+    fn day(&self, day: NaiveDate) -> Vec<GoalProgress> {
+        let activity = self
+            .activity
+            .get(&day)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        self.goals
+            .iter()
+            .filter(|goal| goal.applies_on(day))
+            .map(|goal| {
+                let achieved: i64 = activity
+                    .iter()
+                    .filter(|entry| {
+                        goal.lesson_id
+                            .is_none_or(|lesson_id| entry.lesson_id == lesson_id)
+                    })
+                    .map(|entry| match goal.metric {
+                        GoalMetric::Tests => entry.test_count,
+                        GoalMetric::Questions => entry.question_count,
+                    })
+                    .sum();
+                let target = i64::from(goal.daily_target);
+                GoalProgress {
+                    goal_id: goal.id,
+                    achieved,
+                    target,
+                    met_days: u32::from(achieved >= target),
+                    active_days: 1,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2024, 3, day).unwrap()
+    }
+
+    fn goal(
+        metric: GoalMetric,
+        daily_target: i32,
+        lesson_id: Option<Uuid>,
+        from_day: u32,
+    ) -> StudyGoal {
+        let mut goal = StudyGoal::new(Uuid::new_v4(), metric, daily_target, lesson_id);
+        goal.created_at = Utc.with_ymd_and_hms(2024, 3, from_day, 9, 0, 0).unwrap();
+        goal
+    }
+
+    fn activity(day: u32, lesson_id: Uuid, test_count: i64, question_count: i64) -> DailyActivity {
+        DailyActivity {
+            day: date(day),
+            lesson_id,
+            test_count,
+            question_count,
+        }
+    }
+
+    #[test]
+    fn test_goals_count_tests_and_lesson_questions() {
+        let (math, physics) = (Uuid::new_v4(), Uuid::new_v4());
+        let tests = goal(GoalMetric::Tests, 3, None, 1);
+        let math_questions = goal(GoalMetric::Questions, 40, Some(math), 1);
+        let tracker = GoalTracker::new(
+            vec![tests.clone(), math_questions.clone()],
+            vec![activity(4, math, 2, 30), activity(4, physics, 1, 20)],
+        );
+
+        let day = tracker.period(date(4), date(4));
+
+        assert_eq!(day.goals[0].achieved, 3);
+        assert_eq!(day.goals[0].met_days, 1);
+        assert_eq!(day.goals[1].achieved, 30);
+        assert_eq!(day.goals[1].met_days, 0);
+        assert!(!day.is_completed());
+    }
+
+    #[test]
+    fn test_goal_only_applies_between_creation_and_archival() {
+        let lesson = Uuid::new_v4();
+        let mut goal = goal(GoalMetric::Tests, 1, None, 3);
+        goal.archived_at = Some(Utc.with_ymd_and_hms(2024, 3, 5, 18, 0, 0).unwrap());
+        let tracker = GoalTracker::new(vec![goal], vec![activity(3, lesson, 1, 10)]);
+
+        let week = tracker.period(date(1), date(7));
+
+        assert_eq!(week.active_days, 2);
+        assert_eq!(week.completed_days, 1);
+        assert_eq!(week.goals[0].target, 2);
+        let periods = tracker.periods(date(1), date(10), 7);
+        assert_eq!(periods.len(), 2);
+        assert_eq!(periods[1].start, date(8));
+        assert_eq!(periods[1].end, date(10));
+    }
+
+    #[test]
+    fn test_streak_ignores_an_unfinished_today() {
+        let lesson = Uuid::new_v4();
+        let tracker = GoalTracker::new(
+            vec![goal(GoalMetric::Tests, 1, None, 1)],
+            vec![
+                activity(1, lesson, 1, 10),
+                activity(2, lesson, 1, 10),
+                activity(3, lesson, 1, 10),
+                activity(5, lesson, 2, 20),
+                activity(6, lesson, 1, 10),
+            ],
+        );
+
+        assert_eq!(
+            tracker.streak(date(7)),
+            GoalStreak {
+                current: 2,
+                longest: 3
+            }
+        );
+        assert_eq!(
+            tracker.streak(date(8)),
+            GoalStreak {
+                current: 0,
+                longest: 3
+            }
+        );
+    }
+}
@@ -0,0 +1,17 @@
+mod achievement_evaluator;
+mod cohort_statistics;
+mod goal_tracker;
+mod omr_parser;
+mod performance_analyzer;
+mod recommendation_engine;
+mod retake_evaluator;
+
+pub use achievement_evaluator::{AchievementEvaluator, AchievementProgress};
+pub use cohort_statistics::CohortStatistics;
+pub use goal_tracker::{GoalPeriod, GoalProgress, GoalStreak, GoalTracker};
+pub use omr_parser::{OmrFormat, OmrParser, OmrRow, OmrRowError};
+pub use performance_analyzer::{
+    PerformanceAnalyzer, PerformanceStat, PerformanceTrend, QuestionErrorRate, WeaknessReport,
+};
+pub use recommendation_engine::{Recommendation, RecommendationEngine, RecommendationReason};
+pub use retake_evaluator::{AttemptStatus, RetakeDecision, RetakeEvaluator};
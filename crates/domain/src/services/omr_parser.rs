@@ -0,0 +1,252 @@
+/// Layout of an optical answer form (OMR) export produced by a scanning station.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OmrFormat {
+    /// Delimited rows with the student number in the first column and the
+    /// answers in the second; quoted fields may contain the delimiter.
+    Csv { delimiter: char, has_header: bool },
+    /// Fixed-width rows; positions are 1-based character columns and the
+    /// answers run to the end of the line.
+    FixedWidth {
+        student_number_start: usize,
+        student_number_length: usize,
+        answers_start: usize,
+    },
+}
+
+/// A successfully parsed form row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OmrRow {
+    /// 1-based line number in the export
+    pub line_number: usize,
+    pub student_number: String,
+    /// Answers as scanned, blanks not yet normalized
+    pub answers: String,
+}
+
+/// A form row that could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OmrRowError {
+    /// 1-based line number in the export
+    pub line_number: usize,
+    pub message: String,
+}
+
+/// Parser for scanned optical answer form exports.
+pub struct OmrParser;
+
+impl OmrParser {
+    /// Parses every non-empty line of an export, keeping row order.
+    pub fn parse(content: &str, format: &OmrFormat) -> Vec<Result<OmrRow, OmrRowError>> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let skip = match format {
+            OmrFormat::Csv {
+                has_header: true, ..
+            } => 1,
+            _ => 0,
+        };
+
+        content
+            .lines()
+            .enumerate()
+            .skip(skip)
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                let line_number = index + 1;
+                let parsed = match format {
+                    OmrFormat::Csv { delimiter, .. } => Self::parse_csv_line(line, *delimiter),
+                    OmrFormat::FixedWidth {
+                        student_number_start,
+                        student_number_length,
+                        answers_start,
+                    } => Self::parse_fixed_width_line(
+                        line,
+                        *student_number_start,
+                        *student_number_length,
+                        *answers_start,
+                    ),
+                };
+
+                parsed
+                    .and_then(|(student_number, answers)| {
+                        if student_number.is_empty() {
+                            Err("Student number is missing".to_string())
+                        } else {
+                            Ok((student_number, answers))
+                        }
+                    })
+                    .map(|(student_number, answers)| OmrRow {
+                        line_number,
+                        student_number,
+                        answers,
+                    })
+                    .map_err(|message| OmrRowError {
+                        line_number,
+                        message,
+                    })
+            })
+            .collect()
+    }
+
+    /// Marks scanners use for an unanswered question.
+    const BLANK_MARKS: [char; 5] = [' ', '_', '-', '.', '*'];
+
+    /// Normalizes scanned answers for grading against a test of `question_count` questions.
+    ///
+    /// Option letters A-E are upper-cased and blank marks (space, `_`, `-`, `.`
+    /// and `*`) become `_`. Scanners often drop trailing blanks, so short answer
+    /// strings are padded with `_`. Fails if the answers are longer than the test
+    /// or contain any other mark, e.g. a double-marked question.
+    pub fn normalize_answers(answers: &str, question_count: usize) -> Result<String, String> {
+        let mut normalized = answers
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(index, c)| match c.to_ascii_uppercase() {
+                c @ 'A'..='E' => Ok(c),
+                c if Self::BLANK_MARKS.contains(&c) => Ok('_'),
+                c => Err(format!(
+                    "Unreadable mark '{}' for question {}",
+                    c,
+                    index + 1
+                )),
+            })
+            .collect::<Result<Vec<char>, String>>()?;
+        if normalized.len() > question_count {
+            return Err(format!(
+                "Answers exceed the test's {} questions",
+                question_count
+            ));
+        }
+
+        normalized.resize(question_count, '_');
+        Ok(normalized.into_iter().collect())
+    }
+
+    fn parse_csv_line(line: &str, delimiter: char) -> Result<(String, String), String> {
+        let fields = Self::split_csv_line(line, delimiter)?;
+        match fields.as_slice() {
+            [student_number, answers, ..] => {
+                Ok((student_number.trim().to_string(), answers.clone()))
+            }
+            _ => Err("Expected a student number and an answers column".to_string()),
+        }
+    }
+
+    /// Splits a delimited line, honouring double-quoted fields and `""` escapes.
+    fn split_csv_line(line: &str, delimiter: char) -> Result<Vec<String>, String> {
+        let mut fields = Vec::new();
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut chars = line.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if in_quotes && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => in_quotes = !in_quotes,
+                c if c == delimiter && !in_quotes => fields.push(std::mem::take(&mut field)),
+                c => field.push(c),
+            }
+        }
+        if in_quotes {
+            return Err("Unterminated quoted field".to_string());
+        }
+        fields.push(field);
+
+        Ok(fields)
+    }
+
+    fn parse_fixed_width_line(
+        line: &str,
+        student_number_start: usize,
+        student_number_length: usize,
+        answers_start: usize,
+    ) -> Result<(String, String), String> {
+        let chars: Vec<char> = line.chars().collect();
+        let answers_from = answers_start.saturating_sub(1);
+        if chars.len() <= answers_from {
+            return Err(format!(
+                "Line is too short, answers start at column {}",
+                answers_start
+            ));
+        }
+
+        let number_from = student_number_start.saturating_sub(1).min(chars.len());
+        let number_to = (number_from + student_number_length).min(chars.len());
+        let student_number: String = chars[number_from..number_to].iter().collect();
+        let answers: String = chars[answers_from..].iter().collect();
+
+        Ok((student_number.trim().to_string(), answers))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_with_header_and_quotes() {
+        let content =
+            "ogrenci_no;cevaplar\r\n1001;ABCD\r\n\r\n\"1002\";\"A C \"\r\n;ABCD\r\n1004\r\n";
+        let format = OmrFormat::Csv {
+            delimiter: ';',
+            has_header: true,
+        };
+
+        let rows = OmrParser::parse(content, &format);
+
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[0],
+            Ok(OmrRow {
+                line_number: 2,
+                student_number: "1001".to_string(),
+                answers: "ABCD".to_string(),
+            })
+        );
+        assert_eq!(rows[1].as_ref().unwrap().answers, "A C ");
+        assert_eq!(rows[2].as_ref().unwrap_err().line_number, 5);
+        assert!(rows[3].is_err());
+    }
+
+    #[test]
+    fn test_parse_fixed_width() {
+        let content = "S  1001  ABCD\nS  1002  A C\nS  1003";
+        let format = OmrFormat::FixedWidth {
+            student_number_start: 4,
+            student_number_length: 4,
+            answers_start: 10,
+        };
+
+        let rows = OmrParser::parse(content, &format);
+
+        assert_eq!(rows[0].as_ref().unwrap().student_number, "1001");
+        assert_eq!(rows[0].as_ref().unwrap().answers, "ABCD");
+        assert_eq!(rows[1].as_ref().unwrap().answers, "A C");
+        assert!(rows[2].is_err());
+    }
+
+    #[test]
+    fn test_normalize_answers_pads_blanks() {
+        assert_eq!(
+            OmrParser::normalize_answers("a C", 5),
+            Ok("A_C__".to_string())
+        );
+        assert_eq!(
+            OmrParser::normalize_answers("A-.*e", 5),
+            Ok("A___E".to_string())
+        );
+        assert!(OmrParser::normalize_answers("ABCDEA", 5).is_err());
+    }
+
+    #[test]
+    fn test_normalize_answers_rejects_unknown_marks() {
+        assert_eq!(
+            OmrParser::normalize_answers("AB?D", 5),
+            Err("Unreadable mark '?' for question 3".to_string())
+        );
+        assert!(OmrParser::normalize_answers("ABF", 5).is_err());
+    }
+}
@@ -0,0 +1,417 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::entities::{AnalyticsRecord, AnswerKey, QuestionOutcome, QuestionResult, QuestionTopic};
+
+/// Minimum change in accuracy between older and recent attempts that counts as a trend.
+const TREND_THRESHOLD: f64 = 0.05;
+
+/// Direction of a student's accuracy over time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PerformanceTrend {
+    Improving,
+    Declining,
+    Stable,
+    /// Fewer than two attempts
+    InsufficientData,
+}
+
+impl PerformanceTrend {
+    /// Returns the string representation of this trend.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PerformanceTrend::Improving => "improving",
+            PerformanceTrend::Declining => "declining",
+            PerformanceTrend::Stable => "stable",
+            PerformanceTrend::InsufficientData => "insufficient_data",
+        }
+    }
+}
+
+/// Aggregated performance of a student in one lesson, subject or topic.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PerformanceStat {
+    pub id: Uuid,
+    pub name: String,
+    /// Number of test results that included this lesson, subject or topic
+    pub attempt_count: i32,
+    pub correct_count: i32,
+    pub wrong_count: i32,
+    pub empty_count: i32,
+    /// Share of questions answered correctly, between 0 and 1
+    pub accuracy: f64,
+    /// Average net score per attempt, `None` for topics which have no net of their own
+    pub average_net: Option<f64>,
+    pub trend: PerformanceTrend,
+    /// Recent accuracy minus older accuracy, `None` with fewer than two attempts
+    pub trend_delta: Option<f64>,
+}
+
+/// How often a student missed one question of a practice test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestionErrorRate {
+    pub practice_test_id: Uuid,
+    pub practice_test_name: String,
+    /// 1-based question number
+    pub question_number: i32,
+    pub attempt_count: i32,
+    pub wrong_count: i32,
+    pub empty_count: i32,
+    /// Share of attempts answered wrong or left empty, between 0 and 1
+    pub error_rate: f64,
+    /// Curriculum topic of the question, if classified
+    pub topic_id: Option<Uuid>,
+}
+
+/// A student's strengths and weaknesses, each list ordered weakest first.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WeaknessReport {
+    /// Number of test results analyzed
+    pub total_attempts: i32,
+    pub lessons: Vec<PerformanceStat>,
+    pub subjects: Vec<PerformanceStat>,
+    pub topics: Vec<PerformanceStat>,
+    /// Questions with the highest error rates
+    pub missed_questions: Vec<QuestionErrorRate>,
+}
+
+/// Counts of one attempt within a lesson, subject or topic.
+#[derive(Debug, Clone, Copy, Default)]
+struct AttemptCounts {
+    correct: i32,
+    wrong: i32,
+    empty: i32,
+    net: f64,
+}
+
+impl AttemptCounts {
+    fn add(&mut self, outcome: QuestionOutcome) {
+        match outcome {
+            QuestionOutcome::Correct => self.correct += 1,
+            QuestionOutcome::Wrong => self.wrong += 1,
+            QuestionOutcome::Empty => self.empty += 1,
+        }
+    }
+}
+
+/// Attempts of one group, in chronological order.
+struct StatBuilder {
+    name: String,
+    attempts: Vec<AttemptCounts>,
+}
+
+impl StatBuilder {
+    fn accuracy(attempts: &[AttemptCounts]) -> f64 {
+        let correct: i32 = attempts.iter().map(|a| a.correct).sum();
+        let total: i32 = attempts.iter().map(|a| a.correct + a.wrong + a.empty).sum();
+        if total == 0 {
+            0.0
+        } else {
+            correct as f64 / total as f64
+        }
+    }
+
+    fn build(self, id: Uuid, with_net: bool) -> PerformanceStat {
+        let attempt_count = self.attempts.len() as i32;
+        let (trend, trend_delta) = if self.attempts.len() < 2 {
+            (PerformanceTrend::InsufficientData, None)
+        } else {
+            let (older, recent) = self.attempts.split_at(self.attempts.len() / 2);
+            let delta = Self::accuracy(recent) - Self::accuracy(older);
+            let trend = if delta >= TREND_THRESHOLD {
+                PerformanceTrend::Improving
+            } else if delta <= -TREND_THRESHOLD {
+                PerformanceTrend::Declining
+            } else {
+                PerformanceTrend::Stable
+            };
+            (trend, Some(delta))
+        };
+
+        PerformanceStat {
+            id,
+            name: self.name,
+            attempt_count,
+            correct_count: self.attempts.iter().map(|a| a.correct).sum(),
+            wrong_count: self.attempts.iter().map(|a| a.wrong).sum(),
+            empty_count: self.attempts.iter().map(|a| a.empty).sum(),
+            accuracy: Self::accuracy(&self.attempts),
+            average_net: (with_net && attempt_count > 0)
+                .then(|| self.attempts.iter().map(|a| a.net).sum::<f64>() / attempt_count as f64),
+            trend,
+            trend_delta,
+        }
+    }
+}
+
+/// Attempts grouped by lesson, subject or topic ID.
+#[derive(Default)]
+struct StatGroups {
+    groups: HashMap<Uuid, StatBuilder>,
+}
+
+impl StatGroups {
+    fn push(&mut self, id: Uuid, name: &str, counts: AttemptCounts) {
+        self.groups
+            .entry(id)
+            .or_insert_with(|| StatBuilder {
+                name: name.to_string(),
+                attempts: Vec::new(),
+            })
+            .attempts
+            .push(counts);
+    }
+
+    fn into_ranked(self, with_net: bool) -> Vec<PerformanceStat> {
+        let mut stats: Vec<PerformanceStat> = self
+            .groups
+            .into_iter()
+            .map(|(id, builder)| builder.build(id, with_net))
+            .collect();
+        // Weakest first; with equal accuracy the more practiced group is the clearer weakness
+        stats.sort_by(|a, b| {
+            a.accuracy
+                .total_cmp(&b.accuracy)
+                .then(b.attempt_count.cmp(&a.attempt_count))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        stats
+    }
+}
+
+/// Domain service computing per-lesson, per-subject and per-topic performance.
+pub struct PerformanceAnalyzer;
+
+impl PerformanceAnalyzer {
+    /// Analyzes a student's test results.
+    ///
+    /// Lesson and subject statistics use the stored answer counts. Topic statistics
+    /// and question error rates regrade the stored answers against the current answer
+    /// key; results whose answers no longer match the key length are skipped there.
+    pub fn analyze(
+        records: &[AnalyticsRecord],
+        question_topics: &[QuestionTopic],
+        missed_limit: usize,
+    ) -> WeaknessReport {
+        let mut ordered: Vec<&AnalyticsRecord> = records.iter().collect();
+        ordered.sort_by_key(|record| record.solved_at);
+
+        let topics: HashMap<(Uuid, i32), &QuestionTopic> = question_topics
+            .iter()
+            .map(|topic| ((topic.practice_test_id, topic.position), topic))
+            .collect();
+
+        let mut lessons = StatGroups::default();
+        let mut subjects = StatGroups::default();
+        let mut topic_groups = StatGroups::default();
+        let mut questions: HashMap<(Uuid, i32), QuestionErrorRate> = HashMap::new();
+
+        for record in ordered {
+            let counts = AttemptCounts {
+                correct: record.correct_count,
+                wrong: record.wrong_count,
+                empty: record.empty_count,
+                net: record.net_score,
+            };
+            lessons.push(record.lesson_id, &record.lesson_name, counts);
+            subjects.push(record.subject_id, &record.subject_name, counts);
+
+            let Some(graded) = AnswerKey::parse(&record.answer_key)
+                .ok()
+                .and_then(|key| QuestionResult::grade_all(&key, &record.user_answers))
+            else {
+                continue;
+            };
+
+            let mut per_topic: HashMap<Uuid, (&str, AttemptCounts)> = HashMap::new();
+            for question in graded {
+                let topic = topics
+                    .get(&(record.practice_test_id, question.question_number))
+                    .copied();
+                if let Some(topic) = topic {
+                    per_topic
+                        .entry(topic.topic_id)
+                        .or_insert((topic.topic_name.as_str(), AttemptCounts::default()))
+                        .1
+                        .add(question.outcome);
+                }
+
+                let rate = questions
+                    .entry((record.practice_test_id, question.question_number))
+                    .or_insert_with(|| QuestionErrorRate {
+                        practice_test_id: record.practice_test_id,
+                        practice_test_name: record.practice_test_name.clone(),
+                        question_number: question.question_number,
+                        attempt_count: 0,
+                        wrong_count: 0,
+                        empty_count: 0,
+                        error_rate: 0.0,
+                        topic_id: topic.map(|t| t.topic_id),
+                    });
+                rate.attempt_count += 1;
+                match question.outcome {
+                    QuestionOutcome::Wrong => rate.wrong_count += 1,
+                    QuestionOutcome::Empty => rate.empty_count += 1,
+                    QuestionOutcome::Correct => {}
+                }
+            }
+
+            for (topic_id, (name, counts)) in per_topic {
+                topic_groups.push(topic_id, name, counts);
+            }
+        }
+
+        let mut missed_questions: Vec<QuestionErrorRate> = questions
+            .into_values()
+            .filter(|rate| rate.wrong_count + rate.empty_count > 0)
+            .map(|mut rate| {
+                rate.error_rate =
+                    (rate.wrong_count + rate.empty_count) as f64 / rate.attempt_count as f64;
+                rate
+            })
+            .collect();
+        missed_questions.sort_by(|a, b| {
+            b.error_rate
+                .total_cmp(&a.error_rate)
+                .then(b.attempt_count.cmp(&a.attempt_count))
+                .then_with(|| a.practice_test_name.cmp(&b.practice_test_name))
+                .then(a.question_number.cmp(&b.question_number))
+        });
+        missed_questions.truncate(missed_limit);
+
+        WeaknessReport {
+            total_attempts: records.len() as i32,
+            lessons: lessons.into_ranked(true),
+            subjects: subjects.into_ranked(true),
+            topics: topic_groups.into_ranked(false),
+            missed_questions,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration, Utc};
+
+    fn record(
+        lesson_id: Uuid,
+        subject_id: Uuid,
+        practice_test_id: Uuid,
+        user_answers: &str,
+        solved_at: DateTime<Utc>,
+    ) -> AnalyticsRecord {
+        let key = AnswerKey::parse("ABCD").unwrap();
+        let graded = QuestionResult::grade_all(&key, user_answers).unwrap();
+        let count = |outcome| graded.iter().filter(|q| q.outcome == outcome).count() as i32;
+        AnalyticsRecord {
+            result_id: Uuid::new_v4(),
+            practice_test_id,
+            practice_test_name: "Deneme 1".to_string(),
+            subject_id,
+            subject_name: format!("Konu {}", subject_id),
+            lesson_id,
+            lesson_name: "Matematik".to_string(),
+            answer_key: key.encode(),
+            user_answers: user_answers.to_string(),
+            correct_count: count(QuestionOutcome::Correct),
+            wrong_count: count(QuestionOutcome::Wrong),
+            empty_count: count(QuestionOutcome::Empty),
+            net_score: count(QuestionOutcome::Correct) as f64
+                - count(QuestionOutcome::Wrong) as f64 / 4.0,
+            solved_at,
+        }
+    }
+
+    #[test]
+    fn test_ranks_weakest_subject_first_with_trend() {
+        let now = Utc::now();
+        let lesson_id = Uuid::new_v4();
+        let (strong, weak) = (Uuid::new_v4(), Uuid::new_v4());
+        let records = vec![
+            record(
+                lesson_id,
+                weak,
+                Uuid::new_v4(),
+                "AB__",
+                now - Duration::days(2),
+            ),
+            record(
+                lesson_id,
+                strong,
+                Uuid::new_v4(),
+                "ABCD",
+                now - Duration::days(1),
+            ),
+            record(lesson_id, weak, Uuid::new_v4(), "DDDD", now),
+        ];
+
+        let report = PerformanceAnalyzer::analyze(&records, &[], 10);
+
+        assert_eq!(report.total_attempts, 3);
+        assert_eq!(report.subjects[0].id, weak);
+        assert_eq!(report.subjects[0].attempt_count, 2);
+        assert_eq!(report.subjects[0].accuracy, 3.0 / 8.0);
+        assert_eq!(report.subjects[0].trend, PerformanceTrend::Declining);
+        assert_eq!(report.subjects[1].trend, PerformanceTrend::InsufficientData);
+        assert_eq!(
+            report.lessons[0].average_net,
+            Some((2.0 + 4.0 + 0.25) / 3.0)
+        );
+    }
+
+    #[test]
+    fn test_question_error_rates_and_topics() {
+        let now = Utc::now();
+        let test_id = Uuid::new_v4();
+        let topic_id = Uuid::new_v4();
+        let (lesson_id, subject_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let records = vec![
+            record(
+                lesson_id,
+                subject_id,
+                test_id,
+                "ABCA",
+                now - Duration::days(1),
+            ),
+            record(lesson_id, subject_id, test_id, "AB_A", now),
+        ];
+        let topics = vec![QuestionTopic {
+            practice_test_id: test_id,
+            position: 4,
+            topic_id,
+            topic_name: "Üslü Sayılar".to_string(),
+        }];
+
+        let report = PerformanceAnalyzer::analyze(&records, &topics, 10);
+
+        assert_eq!(report.missed_questions.len(), 2);
+        assert_eq!(report.missed_questions[0].question_number, 4);
+        assert_eq!(report.missed_questions[0].error_rate, 1.0);
+        assert_eq!(report.missed_questions[0].topic_id, Some(topic_id));
+        assert_eq!(report.missed_questions[1].question_number, 3);
+        assert_eq!(report.missed_questions[1].error_rate, 0.5);
+        assert_eq!(report.topics.len(), 1);
+        assert_eq!(report.topics[0].accuracy, 0.0);
+        assert_eq!(report.topics[0].average_net, None);
+    }
+
+    #[test]
+    fn test_skips_answers_not_matching_key() {
+        let mut stale = record(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            "ABCD",
+            Utc::now(),
+        );
+        stale.user_answers = "AB".to_string();
+
+        let report = PerformanceAnalyzer::analyze(&[stale], &[], 10);
+
+        assert_eq!(report.subjects.len(), 1);
+        assert!(report.missed_questions.is_empty());
+    }
+}
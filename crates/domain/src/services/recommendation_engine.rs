@@ -0,0 +1,306 @@
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+use crate::entities::{PracticeTest, RetakePolicy, TestResult};
+use crate::services::RetakeEvaluator;
+
+/// Accuracy below which a solved test is scheduled for review, and a subject counts as weak.
+const WEAK_ACCURACY: f64 = 0.7;
+
+/// Days to wait before reviewing a poorly scored test, by number of attempts so far.
+const REVIEW_INTERVAL_DAYS: [i64; 5] = [1, 3, 7, 14, 30];
+
+/// Why a practice test is recommended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecommendationReason {
+    /// A poorly scored test whose spaced-repetition review is due
+    SpacedReview,
+    /// An unsolved test of a started book, in one of the student's weak subjects
+    WeakSubject,
+    /// An unsolved test of a started book
+    ContinueBook,
+}
+
+impl RecommendationReason {
+    /// Returns the string representation of this reason.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RecommendationReason::SpacedReview => "spaced_review",
+            RecommendationReason::WeakSubject => "weak_subject",
+            RecommendationReason::ContinueBook => "continue_book",
+        }
+    }
+}
+
+/// A practice test proposed to a student.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Recommendation {
+    pub practice_test_id: Uuid,
+    pub reason: RecommendationReason,
+    /// Higher is more urgent
+    pub priority: f64,
+    /// Accuracy of the latest attempt, if solved before
+    pub last_accuracy: Option<f64>,
+    pub last_solved_at: Option<DateTime<Utc>>,
+    /// When the review became due, for spaced reviews
+    pub review_due_at: Option<DateTime<Utc>>,
+    /// Accuracy in the test's subject over all attempts, if the subject was practiced
+    pub subject_accuracy: Option<f64>,
+}
+
+/// Domain service proposing which practice tests a student should solve next.
+pub struct RecommendationEngine;
+
+impl RecommendationEngine {
+    /// Recommends practice tests from the books a student started.
+    ///
+    /// `practice_tests` are the tests of those books, `results` the student's attempts
+    /// at them and `policies` the retake policy of each book. Tests the student may not
+    /// retake yet are never recommended. Reviews that are due come first, then unsolved
+    /// tests ordered by how weak the student is in their subject.
+    pub fn recommend(
+        practice_tests: &[PracticeTest],
+        results: &[TestResult],
+        policies: &HashMap<Uuid, RetakePolicy>,
+        now: DateTime<Utc>,
+        limit: usize,
+    ) -> Vec<Recommendation> {
+        let mut attempts: HashMap<Uuid, Vec<&TestResult>> = HashMap::new();
+        for result in results {
+            attempts
+                .entry(result.practice_test_id)
+                .or_default()
+                .push(result);
+        }
+
+        let subject_of: HashMap<Uuid, Uuid> = practice_tests
+            .iter()
+            .map(|test| (test.id, test.subject_id))
+            .collect();
+        let mut subject_counts: HashMap<Uuid, (i32, i32)> = HashMap::new();
+        for result in results {
+            if let Some(subject_id) = subject_of.get(&result.practice_test_id) {
+                let counts = subject_counts.entry(*subject_id).or_default();
+                counts.0 += result.correct_count;
+                counts.1 += result.correct_count + result.wrong_count + result.empty_count;
+            }
+        }
+        let subject_accuracy = |subject_id: Uuid| {
+            subject_counts
+                .get(&subject_id)
+                .filter(|(_, total)| *total > 0)
+                .map(|(correct, total)| *correct as f64 / *total as f64)
+        };
+
+        let mut recommendations: Vec<(Recommendation, &PracticeTest)> = Vec::new();
+        for test in practice_tests {
+            let subject_accuracy = subject_accuracy(test.subject_id);
+
+            let Some(test_attempts) = attempts.get(&test.id) else {
+                // Unknown subjects rank between weak and strong ones
+                let weakness = 1.0 - subject_accuracy.unwrap_or(0.5);
+                let reason = if subject_accuracy.is_some_and(|a| a < WEAK_ACCURACY) {
+                    RecommendationReason::WeakSubject
+                } else {
+                    RecommendationReason::ContinueBook
+                };
+                recommendations.push((
+                    Recommendation {
+                        practice_test_id: test.id,
+                        reason,
+                        priority: 1.0 + weakness,
+                        last_accuracy: None,
+                        last_solved_at: None,
+                        review_due_at: None,
+                        subject_accuracy,
+                    },
+                    test,
+                ));
+                continue;
+            };
+
+            let Some(policy) = policies.get(&test.test_book_id) else {
+                continue;
+            };
+            let owned: Vec<TestResult> = test_attempts.iter().map(|r| (*r).clone()).collect();
+            if !RetakeEvaluator::evaluate(policy, &owned, now).can_attempt() {
+                continue;
+            }
+
+            let Some(latest) = test_attempts.iter().max_by_key(|r| r.solved_at) else {
+                continue;
+            };
+            let total = latest.correct_count + latest.wrong_count + latest.empty_count;
+            if total == 0 {
+                continue;
+            }
+            let accuracy = latest.correct_count as f64 / total as f64;
+            if accuracy >= WEAK_ACCURACY {
+                continue;
+            }
+
+            let interval_index = (test_attempts.len() - 1).min(REVIEW_INTERVAL_DAYS.len() - 1);
+            let due_at = latest.solved_at + Duration::days(REVIEW_INTERVAL_DAYS[interval_index]);
+            if now < due_at {
+                continue;
+            }
+            let overdue_weeks = (now - due_at).num_hours() as f64 / (24.0 * 7.0);
+
+            recommendations.push((
+                Recommendation {
+                    practice_test_id: test.id,
+                    reason: RecommendationReason::SpacedReview,
+                    priority: 2.0 + (WEAK_ACCURACY - accuracy) + overdue_weeks.min(1.0) / 2.0,
+                    last_accuracy: Some(accuracy),
+                    last_solved_at: Some(latest.solved_at),
+                    review_due_at: Some(due_at),
+                    subject_accuracy,
+                },
+                test,
+            ));
+        }
+
+        // Within equal priority keep the book order
+        recommendations.sort_by(|(a, a_test), (b, b_test)| {
+            b.priority
+                .total_cmp(&a.priority)
+                .then(a_test.test_book_id.cmp(&b_test.test_book_id))
+                .then(a_test.test_number.cmp(&b_test.test_number))
+        });
+        recommendations
+            .into_iter()
+            .take(limit)
+            .map(|(recommendation, _)| recommendation)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{AnswerCounts, RetakePolicyScope, ScoringPolicy};
+
+    fn practice_test(test_book_id: Uuid, subject_id: Uuid, test_number: i32) -> PracticeTest {
+        PracticeTest::new(
+            format!("Test {}", test_number),
+            test_number,
+            10,
+            "ABCDEABCDE".to_string(),
+            test_book_id,
+            subject_id,
+            None,
+        )
+    }
+
+    fn result(test: &PracticeTest, correct: i32, solved_at: DateTime<Utc>) -> TestResult {
+        let counts = AnswerCounts {
+            correct,
+            wrong: 10 - correct,
+            empty: 0,
+        };
+        let mut result = TestResult::new(
+            Uuid::new_v4(),
+            test.id,
+            String::new(),
+            counts,
+            &ScoringPolicy::standard(Uuid::new_v4()),
+            1,
+        );
+        result.solved_at = solved_at;
+        result
+    }
+
+    fn policies(test_book_id: Uuid, max_attempts: Option<i32>) -> HashMap<Uuid, RetakePolicy> {
+        let mut policy = RetakePolicy::standard(RetakePolicyScope::TestBook(test_book_id));
+        policy.max_attempts = max_attempts;
+        HashMap::from([(test_book_id, policy)])
+    }
+
+    #[test]
+    fn test_due_review_comes_before_unsolved_tests() {
+        let now = Utc::now();
+        let (book, subject) = (Uuid::new_v4(), Uuid::new_v4());
+        let poor = practice_test(book, subject, 1);
+        let unsolved = practice_test(book, subject, 2);
+        let results = vec![result(&poor, 4, now - Duration::days(2))];
+
+        let recommendations = RecommendationEngine::recommend(
+            &[poor.clone(), unsolved.clone()],
+            &results,
+            &policies(book, None),
+            now,
+            10,
+        );
+
+        assert_eq!(recommendations.len(), 2);
+        assert_eq!(recommendations[0].practice_test_id, poor.id);
+        assert_eq!(
+            recommendations[0].reason,
+            RecommendationReason::SpacedReview
+        );
+        assert_eq!(recommendations[1].reason, RecommendationReason::WeakSubject);
+    }
+
+    #[test]
+    fn test_review_waits_for_interval_and_retake_policy() {
+        let now = Utc::now();
+        let book = Uuid::new_v4();
+        let test = practice_test(book, Uuid::new_v4(), 1);
+        // Second attempt: the review is due three days later
+        let results = vec![
+            result(&test, 3, now - Duration::days(5)),
+            result(&test, 4, now - Duration::days(2)),
+        ];
+
+        let tests = vec![test];
+
+        let not_due =
+            RecommendationEngine::recommend(&tests, &results, &policies(book, None), now, 10);
+        assert!(not_due.is_empty());
+
+        let later = now + Duration::days(2);
+        let due =
+            RecommendationEngine::recommend(&tests, &results, &policies(book, None), later, 10);
+        assert_eq!(due.len(), 1);
+
+        let locked =
+            RecommendationEngine::recommend(&tests, &results, &policies(book, Some(2)), later, 10);
+        assert!(locked.is_empty());
+    }
+
+    #[test]
+    fn test_unsolved_tests_in_weaker_subjects_rank_higher() {
+        let now = Utc::now() - Duration::days(60);
+        let book = Uuid::new_v4();
+        let (weak, strong) = (Uuid::new_v4(), Uuid::new_v4());
+        let weak_solved = practice_test(book, weak, 1);
+        let strong_solved = practice_test(book, strong, 2);
+        let strong_next = practice_test(book, strong, 3);
+        let weak_next = practice_test(book, weak, 4);
+        let results = vec![
+            result(&weak_solved, 8, now),
+            result(&strong_solved, 10, now),
+        ];
+
+        let recommendations = RecommendationEngine::recommend(
+            &[
+                weak_solved,
+                strong_solved,
+                strong_next.clone(),
+                weak_next.clone(),
+            ],
+            &results,
+            &policies(book, None),
+            now,
+            10,
+        );
+
+        let ids: Vec<Uuid> = recommendations.iter().map(|r| r.practice_test_id).collect();
+        assert_eq!(ids, vec![weak_next.id, strong_next.id]);
+        assert_eq!(
+            recommendations[1].reason,
+            RecommendationReason::ContinueBook
+        );
+    }
+}
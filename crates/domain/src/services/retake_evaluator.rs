@@ -0,0 +1,212 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::entities::{AttemptScoring, RetakePolicy, TestResult};
+
+/// Attempt status of a practice test for a student.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttemptStatus {
+    /// Never attempted
+    Available,
+    /// Attempted and may be solved again now
+    Solved,
+    /// Attempted; the cooldown has not passed yet
+    Waiting,
+    /// The maximum number of attempts has been used
+    Locked,
+}
+
+impl AttemptStatus {
+    /// Returns the string representation of this status.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AttemptStatus::Available => "available",
+            AttemptStatus::Solved => "solved",
+            AttemptStatus::Waiting => "waiting",
+            AttemptStatus::Locked => "locked",
+        }
+    }
+}
+
+/// Outcome of applying a retake policy to a student's attempts of a practice test.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetakeDecision {
+    pub status: AttemptStatus,
+    /// Number of attempts made so far
+    pub attempts_used: i32,
+    /// Attempts left, `None` if unlimited
+    pub attempts_remaining: Option<i32>,
+    /// When the next attempt becomes possible, if the student is waiting
+    pub retake_available_at: Option<DateTime<Utc>>,
+    /// Hours until the next attempt becomes possible, if the student is waiting
+    pub hours_until_retake: Option<f64>,
+    /// The latest attempt, if any
+    pub last_result_id: Option<Uuid>,
+    pub last_solved_at: Option<DateTime<Utc>>,
+    /// The attempt that counts under the policy's attempt scoring
+    pub counted_result_id: Option<Uuid>,
+}
+
+impl RetakeDecision {
+    /// Returns true if the student may start a new attempt.
+    pub fn can_attempt(&self) -> bool {
+        matches!(
+            self.status,
+            AttemptStatus::Available | AttemptStatus::Solved
+        )
+    }
+}
+
+/// Domain service deciding whether a student may solve a practice test again.
+pub struct RetakeEvaluator;
+
+impl RetakeEvaluator {
+    /// Applies a retake policy to a student's previous attempts of one practice test.
+    pub fn evaluate(
+        policy: &RetakePolicy,
+        attempts: &[TestResult],
+        now: DateTime<Utc>,
+    ) -> RetakeDecision {
+        let attempts_used = attempts.len() as i32;
+        let latest = attempts.iter().max_by_key(|r| r.solved_at);
+        let counted = match policy.attempt_scoring {
+            AttemptScoring::First => attempts.iter().min_by_key(|r| r.solved_at),
+            // Ties go to the earlier attempt
+            AttemptScoring::Best => attempts.iter().reduce(|best, r| {
+                if r.net_score > best.net_score
+                    || (r.net_score == best.net_score && r.solved_at < best.solved_at)
+                {
+                    r
+                } else {
+                    best
+                }
+            }),
+        };
+
+        let attempts_remaining = match (policy.unlimited_practice, policy.max_attempts) {
+            (false, Some(max)) => Some((max - attempts_used).max(0)),
+            _ => None,
+        };
+
+        let mut decision = RetakeDecision {
+            status: AttemptStatus::Available,
+            attempts_used,
+            attempts_remaining,
+            retake_available_at: None,
+            hours_until_retake: None,
+            last_result_id: latest.map(|r| r.id),
+            last_solved_at: latest.map(|r| r.solved_at),
+            counted_result_id: counted.map(|r| r.id),
+        };
+
+        let Some(latest) = latest else {
+            return decision;
+        };
+
+        decision.status = AttemptStatus::Solved;
+        if policy.unlimited_practice {
+            return decision;
+        }
+
+        if attempts_remaining == Some(0) {
+            decision.status = AttemptStatus::Locked;
+            return decision;
+        }
+
+        let available_at = latest.solved_at + policy.cooldown();
+        if now < available_at {
+            decision.status = AttemptStatus::Waiting;
+            decision.retake_available_at = Some(available_at);
+            decision.hours_until_retake = Some((available_at - now).num_seconds() as f64 / 3600.0);
+        }
+
+        decision
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{AnswerCounts, RetakePolicyScope, ScoringPolicy};
+    use chrono::Duration;
+
+    fn attempt(net_correct: i32, solved_at: DateTime<Utc>) -> TestResult {
+        let counts = AnswerCounts {
+            correct: net_correct,
+            wrong: 0,
+            empty: 0,
+        };
+        let mut result = TestResult::new(
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            String::new(),
+            counts,
+            &ScoringPolicy::standard(Uuid::new_v4()),
+            1,
+        );
+        result.solved_at = solved_at;
+        result
+    }
+
+    fn policy(max_attempts: Option<i32>, attempt_scoring: AttemptScoring) -> RetakePolicy {
+        RetakePolicy::new(
+            RetakePolicyScope::ExamType(Uuid::new_v4()),
+            RetakePolicy::STANDARD_COOLDOWN_MINUTES,
+            max_attempts,
+            attempt_scoring,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_standard_policy_waits_24_hours() {
+        let now = Utc::now();
+        let policy = RetakePolicy::standard(RetakePolicyScope::ExamType(Uuid::new_v4()));
+
+        let fresh = RetakeEvaluator::evaluate(&policy, &[], now);
+        assert_eq!(fresh.status, AttemptStatus::Available);
+
+        let waiting =
+            RetakeEvaluator::evaluate(&policy, &[attempt(10, now - Duration::hours(20))], now);
+        assert_eq!(waiting.status, AttemptStatus::Waiting);
+        assert_eq!(waiting.hours_until_retake, Some(4.0));
+        assert!(!waiting.can_attempt());
+
+        let solved =
+            RetakeEvaluator::evaluate(&policy, &[attempt(10, now - Duration::hours(25))], now);
+        assert_eq!(solved.status, AttemptStatus::Solved);
+        assert!(solved.can_attempt());
+    }
+
+    #[test]
+    fn test_max_attempts_and_best_attempt() {
+        let now = Utc::now();
+        let first = attempt(10, now - Duration::days(3));
+        let best = attempt(15, now - Duration::days(2));
+        let attempts = [first.clone(), best.clone()];
+
+        let decision =
+            RetakeEvaluator::evaluate(&policy(Some(2), AttemptScoring::Best), &attempts, now);
+        assert_eq!(decision.status, AttemptStatus::Locked);
+        assert_eq!(decision.attempts_remaining, Some(0));
+        assert_eq!(decision.counted_result_id, Some(best.id));
+
+        let decision =
+            RetakeEvaluator::evaluate(&policy(Some(3), AttemptScoring::First), &attempts, now);
+        assert_eq!(decision.status, AttemptStatus::Solved);
+        assert_eq!(decision.counted_result_id, Some(first.id));
+    }
+
+    #[test]
+    fn test_unlimited_practice_ignores_limits() {
+        let now = Utc::now();
+        let mut policy = policy(Some(1), AttemptScoring::First);
+        policy.unlimited_practice = true;
+
+        let decision = RetakeEvaluator::evaluate(&policy, &[attempt(10, now)], now);
+        assert_eq!(decision.status, AttemptStatus::Solved);
+        assert_eq!(decision.attempts_remaining, None);
+    }
+}
@@ -0,0 +1,783 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{error, warn};
+use uuid::Uuid;
+
+use domain::entities::{
+    AnswerKeyRevision, ExamType, Lesson, PracticeTest, Question, Subject, TestBook,
+};
+use domain::errors::DomainError;
+use domain::repositories::{
+    ExamTypeRepository, LessonRepository, PracticeTestRepository, RegradeLock, SubjectRepository,
+    TestBookRepository, TestBookSubjectRepository,
+};
+
+use super::{get_json, set_json, Cache};
+
+/// Prefix shared by every catalog cache key.
+const KEY_PREFIX: &str = "catalog:";
+
+/// Key of the catalog generation counter, bumped by every catalog write.
+const GENERATION_KEY: &str = "catalog:generation";
+
+/// Read-through cache shared by the catalog repositories: lessons, exam types,
+/// subjects, test books and practice tests.
+///
+/// Every catalog key embeds the current generation and any catalog write bumps
+/// it, since list results span several tables. Entries of older generations are
+/// never read again, even when clearing them fails, and expire on their own.
+/// Rows changed outside these repositories (e.g. a deleted topic clearing
+/// `practice_tests.topic_id`) are picked up when their entries expire.
+#[derive(Clone)]
+pub struct CatalogCache {
+    cache: Arc<dyn Cache>,
+    ttl: Duration,
+    shared: bool,
+}
+
+impl CatalogCache {
+    /// Creates a catalog cache whose entries expire after `ttl`.
+    ///
+    /// `shared` tells whether every instance of the API sees the same cache, and
+    /// so each other's writes. Practice tests and exam types are only cached
+    /// when it does.
+    pub fn new(cache: Arc<dyn Cache>, ttl: Duration, shared: bool) -> Self {
+        Self { cache, ttl, shared }
+    }
+
+    /// Reads the current catalog generation, or `None` when it is unavailable.
+    async fn generation(&self) -> Option<u64> {
+        match self.cache.get(GENERATION_KEY).await {
+            Ok(Some(value)) => match value.parse() {
+                Ok(generation) => Some(generation),
+                Err(e) => {
+                    warn!("Invalid catalog cache generation {:?}: {}", value, e);
+                    None
+                }
+            },
+            Ok(None) => Some(0),
+            Err(e) => {
+                warn!("Failed to read catalog cache generation: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Returns the cached value for `key`, or runs `load` and caches its result.
+    ///
+    /// Cache failures are logged and fall through to `load`, so the database
+    /// stays the source of truth when the cache is unavailable. Without a
+    /// generation nothing is read or written, as entries could be stale.
+    async fn get_or_load<T, F>(&self, key: &str, load: F) -> Result<T, DomainError>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: Future<Output = Result<T, DomainError>> + Send,
+    {
+        let Some(generation) = self.generation().await else {
+            return load.await;
+        };
+        let key = format!("{}{}:{}", KEY_PREFIX, generation, key);
+
+        match get_json::<T>(self.cache.as_ref(), &key).await {
+            Ok(Some(value)) => return Ok(value),
+            Ok(None) => {}
+            Err(e) => warn!(key = %key, "Failed to read catalog cache: {}", e),
+        }
+
+        let value = load.await?;
+        if let Err(e) = set_json(self.cache.as_ref(), &key, &value, self.ttl).await {
+            warn!(key = %key, "Failed to write catalog cache: {}", e);
+        }
+
+        Ok(value)
+    }
+
+    /// Like `get_or_load`, but only caches when the cache is shared, for reads
+    /// that must not outlive another instance's write.
+    async fn get_or_load_shared<T, F>(&self, key: &str, load: F) -> Result<T, DomainError>
+    where
+        T: Serialize + DeserializeOwned + Send + Sync,
+        F: Future<Output = Result<T, DomainError>> + Send,
+    {
+        if !self.shared {
+            return load.await;
+        }
+        self.get_or_load(key, load).await
+    }
+
+    /// Moves the catalog to a new generation after a write and clears the
+    /// entries of the previous one.
+    async fn invalidate(&self) {
+        match self.cache.increment(GENERATION_KEY).await {
+            Ok(generation) => {
+                let previous = format!("{}{}:", KEY_PREFIX, generation - 1);
+                if let Err(e) = self.cache.delete_prefix(&previous).await {
+                    warn!("Failed to clear previous catalog cache generation: {}", e);
+                }
+            }
+            Err(e) => error!("Failed to invalidate catalog cache: {}", e),
+        }
+    }
+}
+
+/// Key part for a set of IDs, independent of their order.
+fn ids_key(ids: &[Uuid]) -> String {
+    let mut sorted_ids = ids.to_vec();
+    sorted_ids.sort();
+    let key_ids: Vec<String> = sorted_ids.iter().map(Uuid::to_string).collect();
+    key_ids.join(",")
+}
+
+/// Lesson repository that caches reads and invalidates the catalog on writes.
+pub struct CachedLessonRepository<R: LessonRepository> {
+    inner: R,
+    cache: CatalogCache,
+}
+
+impl<R: LessonRepository> CachedLessonRepository<R> {
+    /// Wraps `inner` with the catalog cache.
+    pub fn new(inner: R, cache: CatalogCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: LessonRepository> LessonRepository for CachedLessonRepository<R> {
+    async fn create(&self, lesson: &Lesson) -> Result<Lesson, DomainError> {
+        let created = self.inner.create(lesson).await?;
+        self.cache.invalidate().await;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Lesson>, DomainError> {
+        self.cache
+            .get_or_load(&format!("lessons:id:{}", id), self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<Lesson>, DomainError> {
+        self.inner.find_by_name(name).await
+    }
+
+    async fn list_all(&self) -> Result<Vec<Lesson>, DomainError> {
+        self.cache
+            .get_or_load("lessons:all", self.inner.list_all())
+            .await
+    }
+
+    async fn update(&self, lesson: &Lesson) -> Result<Lesson, DomainError> {
+        let updated = self.inner.update(lesson).await?;
+        self.cache.invalidate().await;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+}
+
+/// Exam type repository that caches reads and invalidates the catalog on writes.
+///
+/// Exam types decide whether answer keys are revealed, so they are only cached
+/// when the cache is shared.
+pub struct CachedExamTypeRepository<R: ExamTypeRepository> {
+    inner: R,
+    cache: CatalogCache,
+}
+
+impl<R: ExamTypeRepository> CachedExamTypeRepository<R> {
+    /// Wraps `inner` with the catalog cache.
+    pub fn new(inner: R, cache: CatalogCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: ExamTypeRepository> ExamTypeRepository for CachedExamTypeRepository<R> {
+    async fn create(&self, exam_type: &ExamType) -> Result<ExamType, DomainError> {
+        let created = self.inner.create(exam_type).await?;
+        self.cache.invalidate().await;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamType>, DomainError> {
+        self.cache
+            .get_or_load_shared(&format!("exam_types:id:{}", id), self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<ExamType>, DomainError> {
+        self.cache
+            .get_or_load_shared(
+                &format!("exam_types:ids:{}", ids_key(ids)),
+                self.inner.find_by_ids(ids),
+            )
+            .await
+    }
+
+    async fn find_by_name(&self, name: &str) -> Result<Option<ExamType>, DomainError> {
+        self.inner.find_by_name(name).await
+    }
+
+    async fn update(&self, exam_type: &ExamType) -> Result<ExamType, DomainError> {
+        let updated = self.inner.update(exam_type).await?;
+        self.cache.invalidate().await;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<ExamType>, DomainError> {
+        self.cache
+            .get_or_load_shared("exam_types:all", self.inner.list_all())
+            .await
+    }
+}
+
+/// Subject repository that caches reads and invalidates the catalog on writes.
+pub struct CachedSubjectRepository<R: SubjectRepository> {
+    inner: R,
+    cache: CatalogCache,
+}
+
+impl<R: SubjectRepository> CachedSubjectRepository<R> {
+    /// Wraps `inner` with the catalog cache.
+    pub fn new(inner: R, cache: CatalogCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: SubjectRepository> SubjectRepository for CachedSubjectRepository<R> {
+    async fn create(&self, subject: &Subject) -> Result<Subject, DomainError> {
+        let created = self.inner.create(subject).await?;
+        self.cache.invalidate().await;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Subject>, DomainError> {
+        self.cache
+            .get_or_load(&format!("subjects:id:{}", id), self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Subject>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("subjects:ids:{}", ids_key(ids)),
+                self.inner.find_by_ids(ids),
+            )
+            .await
+    }
+
+    async fn find_by_exam_type_id(&self, exam_type_id: Uuid) -> Result<Vec<Subject>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("subjects:exam_type:{}", exam_type_id),
+                self.inner.find_by_exam_type_id(exam_type_id),
+            )
+            .await
+    }
+
+    async fn find_by_lesson_and_exam_type(
+        &self,
+        lesson_id: Uuid,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<Subject>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("subjects:lesson:{}:exam_type:{}", lesson_id, exam_type_id),
+                self.inner
+                    .find_by_lesson_and_exam_type(lesson_id, exam_type_id),
+            )
+            .await
+    }
+
+    async fn update(&self, subject: &Subject) -> Result<Subject, DomainError> {
+        let updated = self.inner.update(subject).await?;
+        self.cache.invalidate().await;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<Subject>, DomainError> {
+        self.cache
+            .get_or_load("subjects:all", self.inner.list_all())
+            .await
+    }
+}
+
+/// Test book repository that caches reads and invalidates the catalog on writes.
+pub struct CachedTestBookRepository<R: TestBookRepository> {
+    inner: R,
+    cache: CatalogCache,
+}
+
+impl<R: TestBookRepository> CachedTestBookRepository<R> {
+    /// Wraps `inner` with the catalog cache.
+    pub fn new(inner: R, cache: CatalogCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: TestBookRepository> TestBookRepository for CachedTestBookRepository<R> {
+    async fn create(&self, test_book: &TestBook) -> Result<TestBook, DomainError> {
+        let created = self.inner.create(test_book).await?;
+        self.cache.invalidate().await;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TestBook>, DomainError> {
+        self.cache
+            .get_or_load(&format!("test_books:id:{}", id), self.inner.find_by_id(id))
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestBook>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_books:ids:{}", ids_key(ids)),
+                self.inner.find_by_ids(ids),
+            )
+            .await
+    }
+
+    async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<TestBook>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_books:subject:{}", subject_id),
+                self.inner.find_by_subject_id(subject_id),
+            )
+            .await
+    }
+
+    async fn find_by_exam_type_id(&self, exam_type_id: Uuid) -> Result<Vec<TestBook>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_books:exam_type:{}", exam_type_id),
+                self.inner.find_by_exam_type_id(exam_type_id),
+            )
+            .await
+    }
+
+    async fn find_by_lesson_id(&self, lesson_id: Uuid) -> Result<Vec<TestBook>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_books:lesson:{}", lesson_id),
+                self.inner.find_by_lesson_id(lesson_id),
+            )
+            .await
+    }
+
+    async fn find_by_exam_type_and_lesson(
+        &self,
+        exam_type_id: Uuid,
+        lesson_id: Uuid,
+    ) -> Result<Vec<TestBook>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_books:exam_type:{}:lesson:{}", exam_type_id, lesson_id),
+                self.inner
+                    .find_by_exam_type_and_lesson(exam_type_id, lesson_id),
+            )
+            .await
+    }
+
+    async fn update(&self, test_book: &TestBook) -> Result<TestBook, DomainError> {
+        let updated = self.inner.update(test_book).await?;
+        self.cache.invalidate().await;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<TestBook>, DomainError> {
+        self.cache
+            .get_or_load("test_books:all", self.inner.list_all())
+            .await
+    }
+}
+
+/// Test book-subject link repository that caches reads and invalidates the catalog on writes.
+pub struct CachedTestBookSubjectRepository<R: TestBookSubjectRepository> {
+    inner: R,
+    cache: CatalogCache,
+}
+
+impl<R: TestBookSubjectRepository> CachedTestBookSubjectRepository<R> {
+    /// Wraps `inner` with the catalog cache.
+    pub fn new(inner: R, cache: CatalogCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: TestBookSubjectRepository> TestBookSubjectRepository
+    for CachedTestBookSubjectRepository<R>
+{
+    async fn add_subject(&self, test_book_id: Uuid, subject_id: Uuid) -> Result<(), DomainError> {
+        self.inner.add_subject(test_book_id, subject_id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn remove_subject(
+        &self,
+        test_book_id: Uuid,
+        subject_id: Uuid,
+    ) -> Result<(), DomainError> {
+        self.inner.remove_subject(test_book_id, subject_id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn set_subjects(
+        &self,
+        test_book_id: Uuid,
+        subject_ids: &[Uuid],
+    ) -> Result<(), DomainError> {
+        self.inner.set_subjects(test_book_id, subject_ids).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn find_subject_ids_by_test_book_id(
+        &self,
+        test_book_id: Uuid,
+    ) -> Result<Vec<Uuid>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_book_subjects:test_book:{}", test_book_id),
+                self.inner.find_subject_ids_by_test_book_id(test_book_id),
+            )
+            .await
+    }
+
+    async fn find_subject_ids_by_test_book_ids(
+        &self,
+        test_book_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Uuid>>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_book_subjects:test_books:{}", ids_key(test_book_ids)),
+                self.inner.find_subject_ids_by_test_book_ids(test_book_ids),
+            )
+            .await
+    }
+
+    async fn find_test_book_ids_by_subject_id(
+        &self,
+        subject_id: Uuid,
+    ) -> Result<Vec<Uuid>, DomainError> {
+        self.cache
+            .get_or_load(
+                &format!("test_book_subjects:subject:{}", subject_id),
+                self.inner.find_test_book_ids_by_subject_id(subject_id),
+            )
+            .await
+    }
+
+    async fn delete_by_test_book_id(&self, test_book_id: Uuid) -> Result<(), DomainError> {
+        self.inner.delete_by_test_book_id(test_book_id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+}
+
+/// Practice test repository that caches catalog reads and invalidates the catalog on writes.
+///
+/// Practice tests carry their answer key, so they are only cached when the cache
+/// is shared. Questions, answer key revisions and per-user lookups are not cached.
+pub struct CachedPracticeTestRepository<R: PracticeTestRepository> {
+    inner: R,
+    cache: CatalogCache,
+}
+
+impl<R: PracticeTestRepository> CachedPracticeTestRepository<R> {
+    /// Wraps `inner` with the catalog cache.
+    pub fn new(inner: R, cache: CatalogCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<R: PracticeTestRepository> PracticeTestRepository for CachedPracticeTestRepository<R> {
+    async fn create(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError> {
+        let created = self.inner.create(practice_test).await?;
+        self.cache.invalidate().await;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<PracticeTest>, DomainError> {
+        self.cache
+            .get_or_load_shared(
+                &format!("practice_tests:id:{}", id),
+                self.inner.find_by_id(id),
+            )
+            .await
+    }
+
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<PracticeTest>, DomainError> {
+        self.cache
+            .get_or_load_shared(
+                &format!("practice_tests:ids:{}", ids_key(ids)),
+                self.inner.find_by_ids(ids),
+            )
+            .await
+    }
+
+    async fn find_by_test_book_id(
+        &self,
+        test_book_id: Uuid,
+    ) -> Result<Vec<PracticeTest>, DomainError> {
+        self.cache
+            .get_or_load_shared(
+                &format!("practice_tests:test_book:{}", test_book_id),
+                self.inner.find_by_test_book_id(test_book_id),
+            )
+            .await
+    }
+
+    async fn find_in_test_books_started_by(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<PracticeTest>, DomainError> {
+        self.inner.find_in_test_books_started_by(user_id).await
+    }
+
+    async fn update(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError> {
+        let updated = self.inner.update(practice_test).await?;
+        self.cache.invalidate().await;
+        Ok(updated)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        self.inner.delete(id).await?;
+        self.cache.invalidate().await;
+        Ok(())
+    }
+
+    async fn list_all(&self) -> Result<Vec<PracticeTest>, DomainError> {
+        self.cache
+            .get_or_load_shared("practice_tests:all", self.inner.list_all())
+            .await
+    }
+
+    async fn find_questions(&self, practice_test_id: Uuid) -> Result<Vec<Question>, DomainError> {
+        self.inner.find_questions(practice_test_id).await
+    }
+
+    async fn replace_questions(
+        &self,
+        practice_test: &PracticeTest,
+        questions: &[Question],
+    ) -> Result<(PracticeTest, Vec<Question>), DomainError> {
+        let replaced = self
+            .inner
+            .replace_questions(practice_test, questions)
+            .await?;
+        self.cache.invalidate().await;
+        Ok(replaced)
+    }
+
+    async fn find_answer_key_revisions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<AnswerKeyRevision>, DomainError> {
+        self.inner.find_answer_key_revisions(practice_test_id).await
+    }
+
+    async fn find_pending_answer_key_revisions(
+        &self,
+    ) -> Result<Vec<AnswerKeyRevision>, DomainError> {
+        self.inner.find_pending_answer_key_revisions().await
+    }
+
+    async fn lock_regrade(&self, practice_test_id: Uuid) -> Result<RegradeLock, DomainError> {
+        self.inner.lock_regrade(practice_test_id).await
+    }
+
+    async fn mark_answer_key_revision_regraded(
+        &self,
+        practice_test_id: Uuid,
+        revision: i32,
+    ) -> Result<(), DomainError> {
+        self.inner
+            .mark_answer_key_revision_regraded(practice_test_id, revision)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::cache::{CacheError, InMemoryCache};
+
+    /// Lesson repository that counts how often lessons are loaded.
+    #[derive(Default)]
+    struct CountingLessonRepository {
+        lessons: Mutex<Vec<Lesson>>,
+        loads: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl LessonRepository for &CountingLessonRepository {
+        async fn create(&self, lesson: &Lesson) -> Result<Lesson, DomainError> {
+            self.lessons.lock().unwrap().push(lesson.clone());
+            Ok(lesson.clone())
+        }
+
+        async fn find_by_id(&self, id: Uuid) -> Result<Option<Lesson>, DomainError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            Ok(self
+                .lessons
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|l| l.id == id)
+                .cloned())
+        }
+
+        async fn find_by_name(&self, name: &str) -> Result<Option<Lesson>, DomainError> {
+            Ok(self
+                .lessons
+                .lock()
+                .unwrap()
+                .iter()
+                .find(|l| l.name == name)
+                .cloned())
+        }
+
+        async fn list_all(&self) -> Result<Vec<Lesson>, DomainError> {
+            self.loads.fetch_add(1, Ordering::SeqCst);
+            Ok(self.lessons.lock().unwrap().clone())
+        }
+
+        async fn update(&self, lesson: &Lesson) -> Result<Lesson, DomainError> {
+            Ok(lesson.clone())
+        }
+
+        async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+            self.lessons.lock().unwrap().retain(|l| l.id != id);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_are_cached_until_a_write() {
+        let inner = CountingLessonRepository::default();
+        let cache = CatalogCache::new(
+            Arc::new(InMemoryCache::new()),
+            Duration::from_secs(60),
+            true,
+        );
+        let repo = CachedLessonRepository::new(&inner, cache);
+
+        repo.create(&Lesson::new("Matematik".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(repo.list_all().await.unwrap().len(), 1);
+        assert_eq!(repo.list_all().await.unwrap().len(), 1);
+        assert_eq!(inner.loads.load(Ordering::SeqCst), 1);
+
+        repo.create(&Lesson::new("Türkçe".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(repo.list_all().await.unwrap().len(), 2);
+        assert_eq!(inner.loads.load(Ordering::SeqCst), 2);
+    }
+
+    /// In-process cache whose prefix deletes always fail.
+    #[derive(Default)]
+    struct UndeletableCache {
+        inner: InMemoryCache,
+    }
+
+    #[async_trait]
+    impl Cache for UndeletableCache {
+        async fn get(&self, key: &str) -> Result<Option<String>, CacheError> {
+            self.inner.get(key).await
+        }
+
+        async fn set(&self, key: &str, value: String, ttl: Duration) -> Result<(), CacheError> {
+            self.inner.set(key, value, ttl).await
+        }
+
+        async fn delete_prefix(&self, _prefix: &str) -> Result<(), CacheError> {
+            Err(CacheError::StoreError("delete failed".to_string()))
+        }
+
+        async fn increment(&self, key: &str) -> Result<u64, CacheError> {
+            self.inner.increment(key).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_writes_invalidate_when_clearing_entries_fails() {
+        let inner = CountingLessonRepository::default();
+        let cache = CatalogCache::new(
+            Arc::new(UndeletableCache::default()),
+            Duration::from_secs(60),
+            true,
+        );
+        let repo = CachedLessonRepository::new(&inner, cache);
+
+        assert!(repo.list_all().await.unwrap().is_empty());
+
+        repo.create(&Lesson::new("Matematik".to_string()))
+            .await
+            .unwrap();
+        assert_eq!(repo.list_all().await.unwrap().len(), 1);
+        assert_eq!(inner.loads.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_shared_reads_are_only_cached_in_a_shared_cache() {
+        for (shared, expected_loads) in [(true, 1), (false, 2)] {
+            let cache = CatalogCache::new(
+                Arc::new(InMemoryCache::new()),
+                Duration::from_secs(60),
+                shared,
+            );
+            let loads = AtomicUsize::new(0);
+
+            for _ in 0..2 {
+                let value: Vec<u32> = cache
+                    .get_or_load_shared("practice_tests:all", async {
+                        loads.fetch_add(1, Ordering::SeqCst);
+                        Ok(vec![1])
+                    })
+                    .await
+                    .unwrap();
+                assert_eq!(value, vec![1]);
+            }
+
+            assert_eq!(loads.load(Ordering::SeqCst), expected_loads);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use std::time::Duration;
+
+use redis::aio::ConnectionManager;
+use tracing::info;
+
+use super::CacheError;
+
+/// How long to wait for Redis at startup.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Type alias for the shared, automatically reconnecting Redis connection.
+pub type RedisConnection = ConnectionManager;
+
+/// Creates the Redis connection shared by the cache and the rate limiter.
+///
+/// # Arguments
+///
+/// * `redis_url` - The Redis connection string
+pub async fn create_redis_connection(redis_url: &str) -> Result<RedisConnection, CacheError> {
+    info!("Connecting to Redis");
+
+    let client =
+        redis::Client::open(redis_url).map_err(|e| CacheError::ConnectionError(e.to_string()))?;
+    let connection = tokio::time::timeout(CONNECT_TIMEOUT, ConnectionManager::new(client))
+        .await
+        .map_err(|_| CacheError::ConnectionError("timed out".to_string()))?
+        .map_err(|e| CacheError::ConnectionError(e.to_string()))?;
+
+    info!("Redis connection established");
+
+    Ok(connection)
+}
+
+/// Checks that Redis answers a PING.
+pub async fn ping_redis(connection: &RedisConnection) -> Result<(), CacheError> {
+    let mut connection = connection.clone();
+    redis::cmd("PING")
+        .query_async::<String>(&mut connection)
+        .await
+        .map(|_| ())
+        .map_err(|e| CacheError::StoreError(e.to_string()))
+}
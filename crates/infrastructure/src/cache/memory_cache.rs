@@ -0,0 +1,165 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use super::{Cache, CacheError};
+
+/// In-process cache for tests and single-node deployments.
+///
+/// Entries without an expiry are counters written by `increment`.
+#[derive(Default)]
+pub struct InMemoryCache {
+    entries: Mutex<HashMap<String, (String, Option<Instant>)>>,
+}
+
+fn is_live(expires_at: Option<Instant>, now: Instant) -> bool {
+    expires_at.is_none_or(|expires_at| expires_at > now)
+}
+
+impl InMemoryCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl Cache for InMemoryCache {
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError> {
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|e| CacheError::StoreError(e.to_string()))?;
+
+        match entries.get(key) {
+            Some((value, expires_at)) if is_live(*expires_at, Instant::now()) => {
+                Ok(Some(value.clone()))
+            }
+            Some(_) => {
+                entries.remove(key);
+                Ok(None)
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: Duration) -> Result<(), CacheError> {
+        let now = Instant::now();
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|e| CacheError::StoreError(e.to_string()))?;
+
+        // Drop expired entries so keys that are never read again do not pile up
+        entries.retain(|_, (_, expires_at)| is_live(*expires_at, now));
+        entries.insert(key.to_string(), (value, Some(now + ttl)));
+        Ok(())
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), CacheError> {
+        self.entries
+            .lock()
+            .map_err(|e| CacheError::StoreError(e.to_string()))?
+            .retain(|key, _| !key.starts_with(prefix));
+        Ok(())
+    }
+
+    async fn increment(&self, key: &str) -> Result<u64, CacheError> {
+        let now = Instant::now();
+        let mut entries = self
+            .entries
+            .lock()
+            .map_err(|e| CacheError::StoreError(e.to_string()))?;
+
+        let current = match entries.get(key) {
+            Some((value, expires_at)) if is_live(*expires_at, now) => value
+                .parse::<u64>()
+                .map_err(|e| CacheError::StoreError(e.to_string()))?,
+            _ => 0,
+        };
+        entries.insert(key.to_string(), ((current + 1).to_string(), None));
+        Ok(current + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_set_get_and_expire() {
+        let cache = InMemoryCache::new();
+
+        cache
+            .set(
+                "catalog:lessons:all",
+                "[]".to_string(),
+                Duration::from_millis(50),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            cache.get("catalog:lessons:all").await.unwrap(),
+            Some("[]".to_string())
+        );
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+        assert_eq!(cache.get("catalog:lessons:all").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn test_delete_prefix() {
+        let cache = InMemoryCache::new();
+        let ttl = Duration::from_secs(60);
+
+        cache
+            .set("catalog:lessons:all", "[]".to_string(), ttl)
+            .await
+            .unwrap();
+        cache
+            .set("catalog:subjects:all", "[]".to_string(), ttl)
+            .await
+            .unwrap();
+        cache.set("other:key", "1".to_string(), ttl).await.unwrap();
+
+        cache.delete_prefix("catalog:").await.unwrap();
+
+        assert_eq!(cache.get("catalog:lessons:all").await.unwrap(), None);
+        assert_eq!(cache.get("catalog:subjects:all").await.unwrap(), None);
+        assert_eq!(cache.get("other:key").await.unwrap(), Some("1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_increment_starts_from_zero_and_does_not_expire() {
+        let cache = InMemoryCache::new();
+
+        assert_eq!(cache.increment("catalog:generation").await.unwrap(), 1);
+        assert_eq!(cache.increment("catalog:generation").await.unwrap(), 2);
+
+        // Writing an entry drops expired ones but keeps counters
+        cache
+            .set(
+                "catalog:lessons:all",
+                "[]".to_string(),
+                Duration::from_millis(1),
+            )
+            .await
+            .unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        cache
+            .set(
+                "catalog:subjects:all",
+                "[]".to_string(),
+                Duration::from_secs(60),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            cache.get("catalog:generation").await.unwrap(),
+            Some("2".to_string())
+        );
+    }
+}
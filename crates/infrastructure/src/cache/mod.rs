@@ -0,0 +1,103 @@
+mod catalog;
+mod connection;
+mod memory_cache;
+mod redis_cache;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::config::Settings;
+
+pub use catalog::{
+    CachedExamTypeRepository, CachedLessonRepository, CachedPracticeTestRepository,
+    CachedSubjectRepository, CachedTestBookRepository, CachedTestBookSubjectRepository,
+    CatalogCache,
+};
+pub use connection::{create_redis_connection, ping_redis, RedisConnection};
+pub use memory_cache::InMemoryCache;
+pub use redis_cache::RedisCache;
+
+/// Cache errors.
+#[derive(Debug, Error)]
+pub enum CacheError {
+    #[error("Cache connection error: {0}")]
+    ConnectionError(String),
+
+    #[error("Cache store error: {0}")]
+    StoreError(String),
+
+    #[error("Cache serialization error: {0}")]
+    SerializationError(String),
+}
+
+/// Key-value cache for serialized values with a time to live.
+#[async_trait]
+pub trait Cache: Send + Sync {
+    /// Gets the value stored under `key`, if present and not expired.
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError>;
+
+    /// Stores `value` under `key` for `ttl`.
+    async fn set(&self, key: &str, value: String, ttl: Duration) -> Result<(), CacheError>;
+
+    /// Removes every key starting with `prefix`.
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), CacheError>;
+
+    /// Increments the counter stored under `key`, starting from 0, and returns
+    /// its new value. Counters do not expire.
+    async fn increment(&self, key: &str) -> Result<u64, CacheError>;
+}
+
+/// Gets a JSON value from the cache.
+pub async fn get_json<T: DeserializeOwned>(
+    cache: &dyn Cache,
+    key: &str,
+) -> Result<Option<T>, CacheError> {
+    match cache.get(key).await? {
+        Some(value) => serde_json::from_str(&value)
+            .map(Some)
+            .map_err(|e| CacheError::SerializationError(e.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// Stores a value in the cache as JSON.
+pub async fn set_json<T: Serialize>(
+    cache: &dyn Cache,
+    key: &str,
+    value: &T,
+    ttl: Duration,
+) -> Result<(), CacheError> {
+    let value =
+        serde_json::to_string(value).map_err(|e| CacheError::SerializationError(e.to_string()))?;
+    cache.set(key, value, ttl).await
+}
+
+/// Creates the catalog cache for the configured store.
+///
+/// When Redis is configured but not connected, each instance falls back to its
+/// own in-process cache that other instances' writes never invalidate, so
+/// practice tests and exam types are not cached there.
+pub fn create_catalog_cache(settings: &Settings, redis: Option<RedisConnection>) -> CatalogCache {
+    let ttl = Duration::from_secs(settings.redis.default_ttl_seconds);
+
+    match redis {
+        Some(connection) if settings.redis.cache_store == "redis" => {
+            info!("Using Redis catalog cache");
+            CatalogCache::new(Arc::new(RedisCache::new(connection)), ttl, true)
+        }
+        _ if settings.redis.cache_store == "memory" => {
+            info!("Using in-process catalog cache");
+            CatalogCache::new(Arc::new(InMemoryCache::new()), ttl, true)
+        }
+        _ => {
+            warn!("Redis is not connected, using in-process catalog cache without practice tests and exam types");
+            CatalogCache::new(Arc::new(InMemoryCache::new()), ttl, false)
+        }
+    }
+}
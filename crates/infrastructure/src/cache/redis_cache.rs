@@ -0,0 +1,85 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use super::{Cache, CacheError, RedisConnection};
+
+/// Number of keys requested per SCAN round trip when deleting by prefix.
+const SCAN_BATCH_SIZE: usize = 100;
+
+/// Redis-backed cache shared by every API instance.
+#[derive(Clone)]
+pub struct RedisCache {
+    connection: RedisConnection,
+}
+
+impl RedisCache {
+    /// Creates a cache using the shared Redis connection.
+    pub fn new(connection: RedisConnection) -> Self {
+        Self { connection }
+    }
+}
+
+#[async_trait]
+impl Cache for RedisCache {
+    async fn get(&self, key: &str) -> Result<Option<String>, CacheError> {
+        let mut connection = self.connection.clone();
+        redis::cmd("GET")
+            .arg(key)
+            .query_async(&mut connection)
+            .await
+            .map_err(|e| CacheError::StoreError(e.to_string()))
+    }
+
+    async fn set(&self, key: &str, value: String, ttl: Duration) -> Result<(), CacheError> {
+        let mut connection = self.connection.clone();
+        redis::cmd("SET")
+            .arg(key)
+            .arg(value)
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut connection)
+            .await
+            .map_err(|e| CacheError::StoreError(e.to_string()))
+    }
+
+    async fn delete_prefix(&self, prefix: &str) -> Result<(), CacheError> {
+        let mut connection = self.connection.clone();
+        let pattern = format!("{}*", prefix);
+        let mut cursor = 0u64;
+
+        loop {
+            let (next_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
+                .cursor_arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(SCAN_BATCH_SIZE)
+                .query_async(&mut connection)
+                .await
+                .map_err(|e| CacheError::StoreError(e.to_string()))?;
+
+            if !keys.is_empty() {
+                redis::cmd("UNLINK")
+                    .arg(&keys)
+                    .query_async::<()>(&mut connection)
+                    .await
+                    .map_err(|e| CacheError::StoreError(e.to_string()))?;
+            }
+
+            if next_cursor == 0 {
+                return Ok(());
+            }
+            cursor = next_cursor;
+        }
+    }
+
+    async fn increment(&self, key: &str) -> Result<u64, CacheError> {
+        let mut connection = self.connection.clone();
+        redis::cmd("INCR")
+            .arg(key)
+            .query_async(&mut connection)
+            .await
+            .map_err(|e| CacheError::StoreError(e.to_string()))
+    }
+}
@@ -1,4 +1,5 @@
 use std::env;
+use std::net::IpAddr;
 
 /// Application settings loaded from environment variables.
 #[derive(Debug, Clone)]
@@ -54,6 +55,8 @@ pub struct RedisSettings {
     pub pool_size: u32,
     /// Default cache TTL in seconds
     pub default_ttl_seconds: u64,
+    /// Catalog cache store ("redis", or "memory" for a single API instance)
+    pub cache_store: String,
 }
 
 /// JWT authentication settings.
@@ -78,6 +81,11 @@ pub struct RateLimitSettings {
     pub api_requests: u32,
     /// API endpoint window in seconds
     pub api_window_seconds: u64,
+    /// Counter store ("redis" or "memory")
+    pub store: String,
+    /// Reverse proxies whose `Forwarded`/`X-Forwarded-For` headers name the client;
+    /// empty to key anonymous requests by the connection's peer address
+    pub trusted_proxies: Vec<IpAddr>,
 }
 
 /// Health check settings.
@@ -120,12 +128,13 @@ impl Settings {
                 version: env_or_default("APP_VERSION", "1.0.0"),
             },
             database: DatabaseSettings {
-                url: env::var("DATABASE_URL").map_err(|_| {
-                    SettingsError::MissingEnvVar("DATABASE_URL".to_string())
-                })?,
+                url: env::var("DATABASE_URL")
+                    .map_err(|_| SettingsError::MissingEnvVar("DATABASE_URL".to_string()))?,
                 max_connections: env_or_default("DATABASE_MAX_CONNECTIONS", "10")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("DATABASE_MAX_CONNECTIONS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("DATABASE_MAX_CONNECTIONS".to_string())
+                    })?,
             },
             redis: RedisSettings {
                 url: env_or_default("REDIS_URL", "redis://localhost:6379"),
@@ -134,41 +143,82 @@ impl Settings {
                     .map_err(|_| SettingsError::InvalidValue("REDIS_POOL_SIZE".to_string()))?,
                 default_ttl_seconds: env_or_default("CACHE_DEFAULT_TTL_SECONDS", "300")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("CACHE_DEFAULT_TTL_SECONDS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("CACHE_DEFAULT_TTL_SECONDS".to_string())
+                    })?,
+                cache_store: match env_or_default("CACHE_STORE", "redis").as_str() {
+                    store @ ("redis" | "memory") => store.to_string(),
+                    _ => return Err(SettingsError::InvalidValue("CACHE_STORE".to_string())),
+                },
             },
             jwt: JwtSettings {
                 secret: env::var("JWT_SECRET")
                     .map_err(|_| SettingsError::MissingEnvVar("JWT_SECRET".to_string()))?,
-                access_token_expiration_minutes: env_or_default("JWT_ACCESS_TOKEN_EXPIRATION_MINUTES", "15")
-                    .parse()
-                    .map_err(|_| SettingsError::InvalidValue("JWT_ACCESS_TOKEN_EXPIRATION_MINUTES".to_string()))?,
-                refresh_token_expiration_days: env_or_default("JWT_REFRESH_TOKEN_EXPIRATION_DAYS", "7")
-                    .parse()
-                    .map_err(|_| SettingsError::InvalidValue("JWT_REFRESH_TOKEN_EXPIRATION_DAYS".to_string()))?,
+                access_token_expiration_minutes: env_or_default(
+                    "JWT_ACCESS_TOKEN_EXPIRATION_MINUTES",
+                    "15",
+                )
+                .parse()
+                .map_err(|_| {
+                    SettingsError::InvalidValue("JWT_ACCESS_TOKEN_EXPIRATION_MINUTES".to_string())
+                })?,
+                refresh_token_expiration_days: env_or_default(
+                    "JWT_REFRESH_TOKEN_EXPIRATION_DAYS",
+                    "7",
+                )
+                .parse()
+                .map_err(|_| {
+                    SettingsError::InvalidValue("JWT_REFRESH_TOKEN_EXPIRATION_DAYS".to_string())
+                })?,
             },
             rate_limit: RateLimitSettings {
                 auth_requests: env_or_default("RATE_LIMIT_AUTH_REQUESTS", "5")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("RATE_LIMIT_AUTH_REQUESTS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("RATE_LIMIT_AUTH_REQUESTS".to_string())
+                    })?,
                 auth_window_seconds: env_or_default("RATE_LIMIT_AUTH_WINDOW_SECONDS", "60")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("RATE_LIMIT_AUTH_WINDOW_SECONDS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("RATE_LIMIT_AUTH_WINDOW_SECONDS".to_string())
+                    })?,
                 api_requests: env_or_default("RATE_LIMIT_API_REQUESTS", "100")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("RATE_LIMIT_API_REQUESTS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("RATE_LIMIT_API_REQUESTS".to_string())
+                    })?,
                 api_window_seconds: env_or_default("RATE_LIMIT_API_WINDOW_SECONDS", "60")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("RATE_LIMIT_API_WINDOW_SECONDS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("RATE_LIMIT_API_WINDOW_SECONDS".to_string())
+                    })?,
+                store: match env_or_default("RATE_LIMIT_STORE", "redis").as_str() {
+                    store @ ("redis" | "memory") => store.to_string(),
+                    _ => return Err(SettingsError::InvalidValue("RATE_LIMIT_STORE".to_string())),
+                },
+                trusted_proxies: env_or_default("RATE_LIMIT_TRUSTED_PROXIES", "")
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(|s| s.parse())
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("RATE_LIMIT_TRUSTED_PROXIES".to_string())
+                    })?,
             },
             health: HealthSettings {
                 timeout_ms: env_or_default("HEALTH_CHECK_TIMEOUT_MS", "5000")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("HEALTH_CHECK_TIMEOUT_MS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("HEALTH_CHECK_TIMEOUT_MS".to_string())
+                    })?,
             },
             shutdown: ShutdownSettings {
                 timeout_seconds: env_or_default("SHUTDOWN_TIMEOUT_SECONDS", "30")
                     .parse()
-                    .map_err(|_| SettingsError::InvalidValue("SHUTDOWN_TIMEOUT_SECONDS".to_string()))?,
+                    .map_err(|_| {
+                        SettingsError::InvalidValue("SHUTDOWN_TIMEOUT_SECONDS".to_string())
+                    })?,
             },
             cors: CorsSettings {
                 allowed_origins: env_or_default("CORS_ALLOWED_ORIGINS", "http://localhost:3000")
@@ -227,4 +277,3 @@ mod tests {
         env::remove_var("TEST_VAR_12345");
     }
 }
-
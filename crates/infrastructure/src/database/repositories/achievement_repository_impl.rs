@@ -0,0 +1,290 @@
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{Achievement, AchievementFacts, AchievementRule, BestNet, UserAchievement};
+use domain::errors::DomainError;
+use domain::repositories::AchievementRepository;
+
+/// PostgreSQL implementation of the AchievementRepository trait.
+pub struct PgAchievementRepository {
+    pool: PgPool,
+}
+
+impl PgAchievementRepository {
+    /// Creates a new PostgreSQL achievement repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for achievement queries.
+#[derive(sqlx::FromRow)]
+struct AchievementRow {
+    id: Uuid,
+    code: String,
+    name: String,
+    description: String,
+    rule: String,
+    threshold: f64,
+    exam_type_id: Option<Uuid>,
+    lesson_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<AchievementRow> for Achievement {
+    type Error = DomainError;
+
+    fn try_from(row: AchievementRow) -> Result<Self, Self::Error> {
+        let rule = AchievementRule::parse(&row.rule).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown achievement rule: {}", row.rule))
+        })?;
+
+        Ok(Achievement {
+            id: row.id,
+            code: row.code,
+            name: row.name,
+            description: row.description,
+            rule,
+            threshold: row.threshold,
+            exam_type_id: row.exam_type_id,
+            lesson_id: row.lesson_id,
+            created_at: row.created_at,
+        })
+    }
+}
+
+/// Internal row structure for awarded achievement queries.
+#[derive(sqlx::FromRow)]
+struct UserAchievementRow {
+    user_id: Uuid,
+    achievement_id: Uuid,
+    awarded_at: DateTime<Utc>,
+}
+
+impl From<UserAchievementRow> for UserAchievement {
+    fn from(row: UserAchievementRow) -> Self {
+        UserAchievement {
+            user_id: row.user_id,
+            achievement_id: row.achievement_id,
+            awarded_at: row.awarded_at,
+        }
+    }
+}
+
+/// Internal row structure for result counts.
+#[derive(sqlx::FromRow)]
+struct ResultCountsRow {
+    tests_solved: i64,
+    test_books_completed: i64,
+}
+
+/// Internal row structure for best net queries.
+#[derive(sqlx::FromRow)]
+struct BestNetRow {
+    exam_type_id: Uuid,
+    lesson_id: Uuid,
+    best_net: f64,
+}
+
+impl From<BestNetRow> for BestNet {
+    fn from(row: BestNetRow) -> Self {
+        BestNet {
+            exam_type_id: row.exam_type_id,
+            lesson_id: row.lesson_id,
+            best_net: row.best_net,
+        }
+    }
+}
+
+#[async_trait]
+impl AchievementRepository for PgAchievementRepository {
+    async fn create(&self, achievement: &Achievement) -> Result<Achievement, DomainError> {
+        let row = sqlx::query_as::<_, AchievementRow>(
+            r#"
+            INSERT INTO achievements (id, code, name, description, rule, threshold, exam_type_id, lesson_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, code, name, description, rule, threshold, exam_type_id, lesson_id, created_at
+            "#,
+        )
+        .bind(achievement.id)
+        .bind(&achievement.code)
+        .bind(&achievement.name)
+        .bind(&achievement.description)
+        .bind(achievement.rule.as_str())
+        .bind(achievement.threshold)
+        .bind(achievement.exam_type_id)
+        .bind(achievement.lesson_id)
+        .bind(achievement.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Achievement>, DomainError> {
+        let row = sqlx::query_as::<_, AchievementRow>(
+            r#"
+            SELECT id, code, name, description, rule, threshold, exam_type_id, lesson_id, created_at
+            FROM achievements
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_by_code(&self, code: &str) -> Result<Option<Achievement>, DomainError> {
+        let row = sqlx::query_as::<_, AchievementRow>(
+            r#"
+            SELECT id, code, name, description, rule, threshold, exam_type_id, lesson_id, created_at
+            FROM achievements
+            WHERE code = $1
+            "#,
+        )
+        .bind(code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn list_all(&self) -> Result<Vec<Achievement>, DomainError> {
+        let rows = sqlx::query_as::<_, AchievementRow>(
+            r#"
+            SELECT id, code, name, description, rule, threshold, exam_type_id, lesson_id, created_at
+            FROM achievements
+            ORDER BY rule ASC, threshold ASC, created_at ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM achievements WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_awarded(&self, user_id: Uuid) -> Result<Vec<UserAchievement>, DomainError> {
+        let rows = sqlx::query_as::<_, UserAchievementRow>(
+            r#"
+            SELECT user_id, achievement_id, awarded_at
+            FROM user_achievements
+            WHERE user_id = $1
+            ORDER BY awarded_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn award(
+        &self,
+        user_id: Uuid,
+        achievement_ids: &[Uuid],
+        awarded_at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO user_achievements (user_id, achievement_id, awarded_at)
+            SELECT $1, achievement_id, $3
+            FROM UNNEST($2::uuid[]) AS achievement_id
+            ON CONFLICT (user_id, achievement_id) DO NOTHING
+            "#,
+        )
+        .bind(user_id)
+        .bind(achievement_ids)
+        .bind(awarded_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_facts(&self, user_id: Uuid) -> Result<AchievementFacts, DomainError> {
+        let solve_days = sqlx::query_scalar::<_, NaiveDate>(
+            r#"
+            SELECT DISTINCT (solved_at AT TIME ZONE 'UTC')::date AS day
+            FROM test_results
+            WHERE user_id = $1
+            ORDER BY day ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        // A book counts once every one of its practice tests has a result
+        let counts = sqlx::query_as::<_, ResultCountsRow>(
+            r#"
+            WITH solved AS (
+                SELECT DISTINCT practice_test_id
+                FROM test_results
+                WHERE user_id = $1
+            ),
+            completed_books AS (
+                SELECT pt.test_book_id
+                FROM practice_tests pt
+                LEFT JOIN solved s ON s.practice_test_id = pt.id
+                WHERE pt.test_book_id IN (
+                    SELECT test_book_id FROM practice_tests
+                    WHERE id IN (SELECT practice_test_id FROM solved)
+                )
+                GROUP BY pt.test_book_id
+                HAVING COUNT(s.practice_test_id) = COUNT(*)
+            )
+            SELECT (SELECT COUNT(*) FROM solved) AS tests_solved,
+                   (SELECT COUNT(*) FROM completed_books) AS test_books_completed
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let best_nets = sqlx::query_as::<_, BestNetRow>(
+            r#"
+            SELECT tb.exam_type_id, s.lesson_id, MAX(tr.net_score) AS best_net
+            FROM test_results tr
+            JOIN practice_tests pt ON pt.id = tr.practice_test_id
+            JOIN test_books tb ON tb.id = pt.test_book_id
+            JOIN subjects s ON s.id = pt.subject_id
+            WHERE tr.user_id = $1
+            GROUP BY tb.exam_type_id, s.lesson_id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(AchievementFacts {
+            solve_days,
+            tests_solved: counts.tests_solved,
+            test_books_completed: counts.test_books_completed,
+            best_nets: best_nets.into_iter().map(|r| r.into()).collect(),
+        })
+    }
+}
@@ -0,0 +1,212 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{
+    AnalyticsRecord, ProgressPoint, QuestionTopic, TimelineGranularity, TimelineGrouping,
+};
+use domain::errors::DomainError;
+use domain::repositories::AnalyticsRepository;
+
+/// Time zone whose days, weeks and months progress timelines are bucketed by.
+const TIMELINE_TIME_ZONE: &str = "Europe/Istanbul";
+
+/// PostgreSQL implementation of the AnalyticsRepository trait.
+pub struct PgAnalyticsRepository {
+    pool: PgPool,
+}
+
+impl PgAnalyticsRepository {
+    /// Creates a new PostgreSQL analytics repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct AnalyticsRecordRow {
+    result_id: Uuid,
+    practice_test_id: Uuid,
+    practice_test_name: String,
+    subject_id: Uuid,
+    subject_name: String,
+    lesson_id: Uuid,
+    lesson_name: String,
+    answer_key: String,
+    user_answers: String,
+    correct_count: i32,
+    wrong_count: i32,
+    empty_count: i32,
+    net_score: f64,
+    solved_at: DateTime<Utc>,
+}
+
+impl From<AnalyticsRecordRow> for AnalyticsRecord {
+    fn from(row: AnalyticsRecordRow) -> Self {
+        AnalyticsRecord {
+            result_id: row.result_id,
+            practice_test_id: row.practice_test_id,
+            practice_test_name: row.practice_test_name,
+            subject_id: row.subject_id,
+            subject_name: row.subject_name,
+            lesson_id: row.lesson_id,
+            lesson_name: row.lesson_name,
+            answer_key: row.answer_key,
+            user_answers: row.user_answers,
+            correct_count: row.correct_count,
+            wrong_count: row.wrong_count,
+            empty_count: row.empty_count,
+            net_score: row.net_score,
+            solved_at: row.solved_at,
+        }
+    }
+}
+
+/// Internal row structure for question topic queries.
+#[derive(sqlx::FromRow)]
+struct QuestionTopicRow {
+    practice_test_id: Uuid,
+    position: i32,
+    topic_id: Uuid,
+    topic_name: String,
+}
+
+impl From<QuestionTopicRow> for QuestionTopic {
+    fn from(row: QuestionTopicRow) -> Self {
+        QuestionTopic {
+            practice_test_id: row.practice_test_id,
+            position: row.position,
+            topic_id: row.topic_id,
+            topic_name: row.topic_name,
+        }
+    }
+}
+
+/// Internal row structure for progress timeline queries.
+#[derive(sqlx::FromRow)]
+struct ProgressPointRow {
+    group_id: Uuid,
+    group_name: String,
+    bucket_start: DateTime<Utc>,
+    average_net: f64,
+    test_count: i64,
+    best_net: f64,
+}
+
+impl From<ProgressPointRow> for ProgressPoint {
+    fn from(row: ProgressPointRow) -> Self {
+        ProgressPoint {
+            group_id: row.group_id,
+            group_name: row.group_name,
+            bucket_start: row.bucket_start,
+            average_net: row.average_net,
+            test_count: row.test_count,
+            best_net: row.best_net,
+        }
+    }
+}
+
+#[async_trait]
+impl AnalyticsRepository for PgAnalyticsRepository {
+    async fn find_records_by_user(
+        &self,
+        user_id: Uuid,
+        limit: u32,
+    ) -> Result<Vec<AnalyticsRecord>, DomainError> {
+        let rows = sqlx::query_as::<_, AnalyticsRecordRow>(
+            r#"
+            SELECT * FROM (
+                SELECT tr.id AS result_id, pt.id AS practice_test_id, pt.name AS practice_test_name,
+                       s.id AS subject_id, s.name AS subject_name, l.id AS lesson_id, l.name AS lesson_name,
+                       akr.answer_key, tr.user_answers, tr.correct_count, tr.wrong_count, tr.empty_count,
+                       tr.net_score, tr.solved_at
+                FROM test_results tr
+                JOIN practice_tests pt ON pt.id = tr.practice_test_id
+                JOIN answer_key_revisions akr
+                    ON akr.practice_test_id = tr.practice_test_id AND akr.revision = tr.answer_key_revision
+                JOIN subjects s ON s.id = pt.subject_id
+                JOIN lessons l ON l.id = s.lesson_id
+                WHERE tr.user_id = $1 AND tr.mock_exam_attempt_id IS NULL
+                ORDER BY tr.solved_at DESC
+                LIMIT $2
+            ) recent
+            ORDER BY solved_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_question_topics(
+        &self,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<QuestionTopic>, DomainError> {
+        let rows = sqlx::query_as::<_, QuestionTopicRow>(
+            r#"
+            SELECT q.practice_test_id, q.position, t.id AS topic_id, t.name AS topic_name
+            FROM questions q
+            JOIN topics t ON t.id = q.topic_id
+            WHERE q.practice_test_id = ANY($1)
+            "#,
+        )
+        .bind(practice_test_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_progress_points(
+        &self,
+        user_id: Uuid,
+        grouping: TimelineGrouping,
+        granularity: TimelineGranularity,
+        from: Option<DateTime<Utc>>,
+        to: Option<DateTime<Utc>>,
+    ) -> Result<Vec<ProgressPoint>, DomainError> {
+        let (group_id, group_name) = match grouping {
+            TimelineGrouping::Lesson => ("l.id", "l.name"),
+            TimelineGrouping::ExamType => ("et.id", "et.name"),
+        };
+        let query = format!(
+            r#"
+            SELECT {id} AS group_id, {name} AS group_name,
+                   date_trunc($2, tr.solved_at, $5) AS bucket_start,
+                   AVG(tr.net_score) AS average_net, COUNT(*) AS test_count,
+                   MAX(tr.net_score) AS best_net
+            FROM test_results tr
+            JOIN practice_tests pt ON pt.id = tr.practice_test_id
+            JOIN subjects s ON s.id = pt.subject_id
+            JOIN lessons l ON l.id = s.lesson_id
+            JOIN exam_types et ON et.id = s.exam_type_id
+            WHERE tr.user_id = $1
+              AND ($3::timestamptz IS NULL OR tr.solved_at >= $3)
+              AND ($4::timestamptz IS NULL OR tr.solved_at < $4)
+            GROUP BY {id}, {name}, bucket_start
+            ORDER BY group_name ASC, group_id ASC, bucket_start ASC
+            "#,
+            id = group_id,
+            name = group_name
+        );
+
+        let rows = sqlx::query_as::<_, ProgressPointRow>(&query)
+            .bind(user_id)
+            .bind(granularity.as_str())
+            .bind(from)
+            .bind(to)
+            .bind(TIMELINE_TIME_ZONE)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
@@ -0,0 +1,275 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{Assignment, AssignmentSubmission, AssignmentSummary, StudentAssignment};
+use domain::errors::DomainError;
+use domain::repositories::AssignmentRepository;
+
+/// PostgreSQL implementation of the AssignmentRepository trait.
+pub struct PgAssignmentRepository {
+    pool: PgPool,
+}
+
+impl PgAssignmentRepository {
+    /// Creates a new PostgreSQL assignment repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for assignment queries.
+#[derive(sqlx::FromRow)]
+struct AssignmentRow {
+    id: Uuid,
+    classroom_id: Uuid,
+    practice_test_id: Uuid,
+    due_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<AssignmentRow> for Assignment {
+    fn from(row: AssignmentRow) -> Self {
+        Assignment {
+            id: row.id,
+            classroom_id: row.classroom_id,
+            practice_test_id: row.practice_test_id,
+            due_at: row.due_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// Internal row structure for assignment summary queries.
+#[derive(sqlx::FromRow)]
+struct AssignmentSummaryRow {
+    id: Uuid,
+    classroom_id: Uuid,
+    practice_test_id: Uuid,
+    due_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    practice_test_name: String,
+    student_count: i64,
+    submitted_count: i64,
+}
+
+impl From<AssignmentSummaryRow> for AssignmentSummary {
+    fn from(row: AssignmentSummaryRow) -> Self {
+        AssignmentSummary {
+            assignment: Assignment {
+                id: row.id,
+                classroom_id: row.classroom_id,
+                practice_test_id: row.practice_test_id,
+                due_at: row.due_at,
+                created_at: row.created_at,
+            },
+            practice_test_name: row.practice_test_name,
+            student_count: row.student_count,
+            submitted_count: row.submitted_count,
+        }
+    }
+}
+
+/// Internal row structure for submission queries.
+#[derive(sqlx::FromRow)]
+struct AssignmentSubmissionRow {
+    student_id: Uuid,
+    username: String,
+    test_result_id: Option<Uuid>,
+    net_score: Option<f64>,
+    solved_at: Option<DateTime<Utc>>,
+}
+
+impl From<AssignmentSubmissionRow> for AssignmentSubmission {
+    fn from(row: AssignmentSubmissionRow) -> Self {
+        AssignmentSubmission {
+            student_id: row.student_id,
+            username: row.username,
+            test_result_id: row.test_result_id,
+            net_score: row.net_score,
+            solved_at: row.solved_at,
+        }
+    }
+}
+
+/// Internal row structure for a student's assignment queries.
+#[derive(sqlx::FromRow)]
+struct StudentAssignmentRow {
+    id: Uuid,
+    classroom_id: Uuid,
+    practice_test_id: Uuid,
+    due_at: DateTime<Utc>,
+    created_at: DateTime<Utc>,
+    classroom_name: String,
+    practice_test_name: String,
+    test_result_id: Option<Uuid>,
+    net_score: Option<f64>,
+    solved_at: Option<DateTime<Utc>>,
+}
+
+impl From<StudentAssignmentRow> for StudentAssignment {
+    fn from(row: StudentAssignmentRow) -> Self {
+        StudentAssignment {
+            assignment: Assignment {
+                id: row.id,
+                classroom_id: row.classroom_id,
+                practice_test_id: row.practice_test_id,
+                due_at: row.due_at,
+                created_at: row.created_at,
+            },
+            classroom_name: row.classroom_name,
+            practice_test_name: row.practice_test_name,
+            test_result_id: row.test_result_id,
+            net_score: row.net_score,
+            solved_at: row.solved_at,
+        }
+    }
+}
+
+#[async_trait]
+impl AssignmentRepository for PgAssignmentRepository {
+    async fn create(&self, assignment: &Assignment) -> Result<Assignment, DomainError> {
+        let row = sqlx::query_as::<_, AssignmentRow>(
+            r#"
+            INSERT INTO assignments (id, classroom_id, practice_test_id, due_at, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, classroom_id, practice_test_id, due_at, created_at
+            "#,
+        )
+        .bind(assignment.id)
+        .bind(assignment.classroom_id)
+        .bind(assignment.practice_test_id)
+        .bind(assignment.due_at)
+        .bind(assignment.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Assignment>, DomainError> {
+        let row = sqlx::query_as::<_, AssignmentRow>(
+            r#"
+            SELECT id, classroom_id, practice_test_id, due_at, created_at
+            FROM assignments
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM assignments WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_summaries_by_classroom(
+        &self,
+        classroom_id: Uuid,
+    ) -> Result<Vec<AssignmentSummary>, DomainError> {
+        let rows = sqlx::query_as::<_, AssignmentSummaryRow>(
+            r#"
+            SELECT a.id, a.classroom_id, a.practice_test_id, a.due_at, a.created_at,
+                   pt.name AS practice_test_name,
+                   COUNT(e.student_id) AS student_count,
+                   COUNT(e.student_id) FILTER (WHERE EXISTS(
+                       SELECT 1 FROM test_results tr
+                       WHERE tr.user_id = e.student_id
+                         AND tr.practice_test_id = a.practice_test_id
+                         AND tr.solved_at >= a.created_at
+                   )) AS submitted_count
+            FROM assignments a
+            JOIN practice_tests pt ON pt.id = a.practice_test_id
+            LEFT JOIN enrollments e ON e.classroom_id = a.classroom_id
+            WHERE a.classroom_id = $1
+            GROUP BY a.id, pt.name
+            ORDER BY a.due_at ASC
+            "#,
+        )
+        .bind(classroom_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_submissions(
+        &self,
+        assignment: &Assignment,
+    ) -> Result<Vec<AssignmentSubmission>, DomainError> {
+        let rows = sqlx::query_as::<_, AssignmentSubmissionRow>(
+            r#"
+            SELECT e.student_id, u.username,
+                   tr.id AS test_result_id, tr.net_score, tr.solved_at
+            FROM enrollments e
+            JOIN users u ON u.id = e.student_id
+            LEFT JOIN LATERAL (
+                SELECT id, net_score, solved_at
+                FROM test_results
+                WHERE user_id = e.student_id
+                  AND practice_test_id = $2
+                  AND solved_at >= $3
+                ORDER BY solved_at ASC
+                LIMIT 1
+            ) tr ON true
+            WHERE e.classroom_id = $1
+            ORDER BY u.username ASC
+            "#,
+        )
+        .bind(assignment.classroom_id)
+        .bind(assignment.practice_test_id)
+        .bind(assignment.created_at)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<StudentAssignment>, DomainError> {
+        let rows = sqlx::query_as::<_, StudentAssignmentRow>(
+            r#"
+            SELECT a.id, a.classroom_id, a.practice_test_id, a.due_at, a.created_at,
+                   c.name AS classroom_name, pt.name AS practice_test_name,
+                   tr.id AS test_result_id, tr.net_score, tr.solved_at
+            FROM enrollments e
+            JOIN classrooms c ON c.id = e.classroom_id
+            JOIN assignments a ON a.classroom_id = e.classroom_id
+            JOIN practice_tests pt ON pt.id = a.practice_test_id
+            LEFT JOIN LATERAL (
+                SELECT id, net_score, solved_at
+                FROM test_results
+                WHERE user_id = e.student_id
+                  AND practice_test_id = a.practice_test_id
+                  AND solved_at >= a.created_at
+                ORDER BY solved_at ASC
+                LIMIT 1
+            ) tr ON true
+            WHERE e.student_id = $1
+            ORDER BY a.due_at ASC
+            "#,
+        )
+        .bind(student_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
@@ -0,0 +1,254 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{Classroom, ClassroomStudent, Enrollment};
+use domain::errors::DomainError;
+use domain::repositories::ClassroomRepository;
+
+/// PostgreSQL implementation of the ClassroomRepository trait.
+pub struct PgClassroomRepository {
+    pool: PgPool,
+}
+
+impl PgClassroomRepository {
+    /// Creates a new PostgreSQL classroom repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for classroom queries.
+#[derive(sqlx::FromRow)]
+struct ClassroomRow {
+    id: Uuid,
+    teacher_id: Uuid,
+    name: String,
+    invite_code: String,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<ClassroomRow> for Classroom {
+    fn from(row: ClassroomRow) -> Self {
+        Classroom {
+            id: row.id,
+            teacher_id: row.teacher_id,
+            name: row.name,
+            invite_code: row.invite_code,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Internal row structure for classroom roster queries.
+#[derive(sqlx::FromRow)]
+struct ClassroomStudentRow {
+    student_id: Uuid,
+    username: String,
+    enrolled_at: DateTime<Utc>,
+}
+
+impl From<ClassroomStudentRow> for ClassroomStudent {
+    fn from(row: ClassroomStudentRow) -> Self {
+        ClassroomStudent {
+            student_id: row.student_id,
+            username: row.username,
+            enrolled_at: row.enrolled_at,
+        }
+    }
+}
+
+#[async_trait]
+impl ClassroomRepository for PgClassroomRepository {
+    async fn create(&self, classroom: &Classroom) -> Result<Classroom, DomainError> {
+        let row = sqlx::query_as::<_, ClassroomRow>(
+            r#"
+            INSERT INTO classrooms (id, teacher_id, name, invite_code, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, teacher_id, name, invite_code, created_at, updated_at
+            "#,
+        )
+        .bind(classroom.id)
+        .bind(classroom.teacher_id)
+        .bind(&classroom.name)
+        .bind(&classroom.invite_code)
+        .bind(classroom.created_at)
+        .bind(classroom.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Classroom>, DomainError> {
+        let row = sqlx::query_as::<_, ClassroomRow>(
+            r#"
+            SELECT id, teacher_id, name, invite_code, created_at, updated_at
+            FROM classrooms
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_by_invite_code(
+        &self,
+        invite_code: &str,
+    ) -> Result<Option<Classroom>, DomainError> {
+        let row = sqlx::query_as::<_, ClassroomRow>(
+            r#"
+            SELECT id, teacher_id, name, invite_code, created_at, updated_at
+            FROM classrooms
+            WHERE invite_code = $1
+            "#,
+        )
+        .bind(invite_code)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_by_teacher(&self, teacher_id: Uuid) -> Result<Vec<Classroom>, DomainError> {
+        let rows = sqlx::query_as::<_, ClassroomRow>(
+            r#"
+            SELECT id, teacher_id, name, invite_code, created_at, updated_at
+            FROM classrooms
+            WHERE teacher_id = $1
+            ORDER BY name ASC
+            "#,
+        )
+        .bind(teacher_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_by_student(&self, student_id: Uuid) -> Result<Vec<Classroom>, DomainError> {
+        let rows = sqlx::query_as::<_, ClassroomRow>(
+            r#"
+            SELECT c.id, c.teacher_id, c.name, c.invite_code, c.created_at, c.updated_at
+            FROM classrooms c
+            JOIN enrollments e ON e.classroom_id = c.id
+            WHERE e.student_id = $1
+            ORDER BY c.name ASC
+            "#,
+        )
+        .bind(student_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn update(&self, classroom: &Classroom) -> Result<Classroom, DomainError> {
+        let row = sqlx::query_as::<_, ClassroomRow>(
+            r#"
+            UPDATE classrooms
+            SET name = $2, invite_code = $3, updated_at = $4
+            WHERE id = $1
+            RETURNING id, teacher_id, name, invite_code, created_at, updated_at
+            "#,
+        )
+        .bind(classroom.id)
+        .bind(&classroom.name)
+        .bind(&classroom.invite_code)
+        .bind(classroom.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM classrooms WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn enroll(&self, enrollment: &Enrollment) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO enrollments (classroom_id, student_id, enrolled_at)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (classroom_id, student_id) DO NOTHING
+            "#,
+        )
+        .bind(enrollment.classroom_id)
+        .bind(enrollment.student_id)
+        .bind(enrollment.enrolled_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn unenroll(&self, classroom_id: Uuid, student_id: Uuid) -> Result<bool, DomainError> {
+        let result =
+            sqlx::query("DELETE FROM enrollments WHERE classroom_id = $1 AND student_id = $2")
+                .bind(classroom_id)
+                .bind(student_id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn is_enrolled(&self, classroom_id: Uuid, student_id: Uuid) -> Result<bool, DomainError> {
+        let enrolled = sqlx::query_scalar::<_, bool>(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM enrollments WHERE classroom_id = $1 AND student_id = $2
+            )
+            "#,
+        )
+        .bind(classroom_id)
+        .bind(student_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(enrolled)
+    }
+
+    async fn find_students(
+        &self,
+        classroom_id: Uuid,
+    ) -> Result<Vec<ClassroomStudent>, DomainError> {
+        let rows = sqlx::query_as::<_, ClassroomStudentRow>(
+            r#"
+            SELECT e.student_id, u.username, e.enrolled_at
+            FROM enrollments e
+            JOIN users u ON u.id = e.student_id
+            WHERE e.classroom_id = $1
+            ORDER BY u.username ASC
+            "#,
+        )
+        .bind(classroom_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
@@ -0,0 +1,205 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{ExamSession, ExamSessionStatus, TestResult};
+use domain::errors::DomainError;
+use domain::repositories::ExamSessionRepository;
+
+use super::test_result_repository_impl::insert_test_result;
+
+/// PostgreSQL implementation of the ExamSessionRepository trait.
+pub struct PgExamSessionRepository {
+    pool: PgPool,
+}
+
+impl PgExamSessionRepository {
+    /// Creates a new PostgreSQL exam session repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct ExamSessionRow {
+    id: Uuid,
+    user_id: Uuid,
+    practice_test_id: Uuid,
+    answers: String,
+    status: String,
+    started_at: DateTime<Utc>,
+    expires_at: Option<DateTime<Utc>>,
+    last_saved_at: DateTime<Utc>,
+    submitted_at: Option<DateTime<Utc>>,
+    test_result_id: Option<Uuid>,
+}
+
+impl TryFrom<ExamSessionRow> for ExamSession {
+    type Error = DomainError;
+
+    fn try_from(row: ExamSessionRow) -> Result<Self, Self::Error> {
+        let status = ExamSessionStatus::parse(&row.status).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown exam session status: {}", row.status))
+        })?;
+
+        Ok(ExamSession {
+            id: row.id,
+            user_id: row.user_id,
+            practice_test_id: row.practice_test_id,
+            answers: row.answers,
+            status,
+            started_at: row.started_at,
+            expires_at: row.expires_at,
+            last_saved_at: row.last_saved_at,
+            submitted_at: row.submitted_at,
+            test_result_id: row.test_result_id,
+        })
+    }
+}
+
+#[async_trait]
+impl ExamSessionRepository for PgExamSessionRepository {
+    async fn create(&self, session: &ExamSession) -> Result<ExamSession, DomainError> {
+        let row = sqlx::query_as::<_, ExamSessionRow>(
+            r#"
+            INSERT INTO exam_sessions (id, user_id, practice_test_id, answers, status, started_at, expires_at, last_saved_at, submitted_at, test_result_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING id, user_id, practice_test_id, answers, status, started_at, expires_at, last_saved_at, submitted_at, test_result_id
+            "#,
+        )
+        .bind(session.id)
+        .bind(session.user_id)
+        .bind(session.practice_test_id)
+        .bind(&session.answers)
+        .bind(session.status.as_str())
+        .bind(session.started_at)
+        .bind(session.expires_at)
+        .bind(session.last_saved_at)
+        .bind(session.submitted_at)
+        .bind(session.test_result_id)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamSession>, DomainError> {
+        let row = sqlx::query_as::<_, ExamSessionRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, answers, status, started_at, expires_at, last_saved_at, submitted_at, test_result_id
+            FROM exam_sessions
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_in_progress_by_user_and_practice_test(
+        &self,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<Option<ExamSession>, DomainError> {
+        let row = sqlx::query_as::<_, ExamSessionRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, answers, status, started_at, expires_at, last_saved_at, submitted_at, test_result_id
+            FROM exam_sessions
+            WHERE user_id = $1 AND practice_test_id = $2 AND status = 'in_progress'
+            "#,
+        )
+        .bind(user_id)
+        .bind(practice_test_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<ExamSession>, DomainError> {
+        let rows = sqlx::query_as::<_, ExamSessionRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, answers, status, started_at, expires_at, last_saved_at, submitted_at, test_result_id
+            FROM exam_sessions
+            WHERE status = 'in_progress' AND expires_at <= $1
+            ORDER BY expires_at ASC
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn save_answers(&self, session: &ExamSession) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE exam_sessions
+            SET answers = $2, last_saved_at = $3
+            WHERE id = $1 AND status = 'in_progress'
+            "#,
+        )
+        .bind(session.id)
+        .bind(&session.answers)
+        .bind(session.last_saved_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn submit(
+        &self,
+        session: &ExamSession,
+        test_result: &TestResult,
+    ) -> Result<bool, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        // The result must exist before the session can reference it
+        insert_test_result(&mut *tx, test_result).await?;
+
+        // Only the first submission wins; a concurrent auto-submit rolls back here
+        let updated = sqlx::query(
+            r#"
+            UPDATE exam_sessions
+            SET answers = $2, status = $3, submitted_at = $4, test_result_id = $5
+            WHERE id = $1 AND status = 'in_progress'
+            "#,
+        )
+        .bind(session.id)
+        .bind(&session.answers)
+        .bind(session.status.as_str())
+        .bind(session.submitted_at)
+        .bind(test_result.id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if updated.rows_affected() == 0 {
+            tx.rollback()
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            return Ok(false);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+}
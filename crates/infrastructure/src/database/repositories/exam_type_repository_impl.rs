@@ -25,6 +25,7 @@ struct ExamTypeRow {
     id: Uuid,
     name: String,
     description: Option<String>,
+    reveal_answer_keys: bool,
     created_at: DateTime<Utc>,
 }
 
@@ -34,6 +35,7 @@ impl From<ExamTypeRow> for ExamType {
             id: row.id,
             name: row.name,
             description: row.description,
+            reveal_answer_keys: row.reveal_answer_keys,
             created_at: row.created_at,
         }
     }
@@ -44,14 +46,15 @@ impl ExamTypeRepository for PgExamTypeRepository {
     async fn create(&self, exam_type: &ExamType) -> Result<ExamType, DomainError> {
         let row = sqlx::query_as::<_, ExamTypeRow>(
             r#"
-            INSERT INTO exam_types (id, name, description, created_at)
-            VALUES ($1, $2, $3, $4)
-            RETURNING id, name, description, created_at
+            INSERT INTO exam_types (id, name, description, reveal_answer_keys, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING id, name, description, reveal_answer_keys, created_at
             "#,
         )
         .bind(exam_type.id)
         .bind(&exam_type.name)
         .bind(&exam_type.description)
+        .bind(exam_type.reveal_answer_keys)
         .bind(exam_type.created_at)
         .fetch_one(&self.pool)
         .await
@@ -63,7 +66,7 @@ impl ExamTypeRepository for PgExamTypeRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<ExamType>, DomainError> {
         let row = sqlx::query_as::<_, ExamTypeRow>(
             r#"
-            SELECT id, name, description, created_at
+            SELECT id, name, description, reveal_answer_keys, created_at
             FROM exam_types
             WHERE id = $1
             "#,
@@ -76,10 +79,26 @@ impl ExamTypeRepository for PgExamTypeRepository {
         Ok(row.map(|r| r.into()))
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<ExamType>, DomainError> {
+        let rows = sqlx::query_as::<_, ExamTypeRow>(
+            r#"
+            SELECT id, name, description, reveal_answer_keys, created_at
+            FROM exam_types
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
     async fn find_by_name(&self, name: &str) -> Result<Option<ExamType>, DomainError> {
         let row = sqlx::query_as::<_, ExamTypeRow>(
             r#"
-            SELECT id, name, description, created_at
+            SELECT id, name, description, reveal_answer_keys, created_at
             FROM exam_types
             WHERE LOWER(name) = LOWER($1)
             "#,
@@ -96,14 +115,15 @@ impl ExamTypeRepository for PgExamTypeRepository {
         let row = sqlx::query_as::<_, ExamTypeRow>(
             r#"
             UPDATE exam_types
-            SET name = $2, description = $3
+            SET name = $2, description = $3, reveal_answer_keys = $4
             WHERE id = $1
-            RETURNING id, name, description, created_at
+            RETURNING id, name, description, reveal_answer_keys, created_at
             "#,
         )
         .bind(exam_type.id)
         .bind(&exam_type.name)
         .bind(&exam_type.description)
+        .bind(exam_type.reveal_answer_keys)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
@@ -124,7 +144,7 @@ impl ExamTypeRepository for PgExamTypeRepository {
     async fn list_all(&self) -> Result<Vec<ExamType>, DomainError> {
         let rows = sqlx::query_as::<_, ExamTypeRow>(
             r#"
-            SELECT id, name, description, created_at
+            SELECT id, name, description, reveal_answer_keys, created_at
             FROM exam_types
             ORDER BY created_at DESC
             "#,
@@ -136,4 +156,3 @@ impl ExamTypeRepository for PgExamTypeRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 }
-
@@ -0,0 +1,220 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{GuardianLink, GuardianLinkDetail, GuardianLinkStatus};
+use domain::errors::DomainError;
+use domain::repositories::GuardianLinkRepository;
+
+/// PostgreSQL implementation of the GuardianLinkRepository trait.
+pub struct PgGuardianLinkRepository {
+    pool: PgPool,
+}
+
+impl PgGuardianLinkRepository {
+    /// Creates a new PostgreSQL guardian link repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for guardian link queries.
+#[derive(sqlx::FromRow)]
+struct GuardianLinkRow {
+    id: Uuid,
+    guardian_id: Uuid,
+    student_id: Uuid,
+    status: String,
+    invited_at: DateTime<Utc>,
+    accepted_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<GuardianLinkRow> for GuardianLink {
+    type Error = DomainError;
+
+    fn try_from(row: GuardianLinkRow) -> Result<Self, Self::Error> {
+        let status = GuardianLinkStatus::parse(&row.status).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown guardian link status: {}", row.status))
+        })?;
+
+        Ok(GuardianLink {
+            id: row.id,
+            guardian_id: row.guardian_id,
+            student_id: row.student_id,
+            status,
+            invited_at: row.invited_at,
+            accepted_at: row.accepted_at,
+        })
+    }
+}
+
+/// Internal row structure for guardian link queries with usernames.
+#[derive(sqlx::FromRow)]
+struct GuardianLinkDetailRow {
+    id: Uuid,
+    guardian_id: Uuid,
+    student_id: Uuid,
+    status: String,
+    invited_at: DateTime<Utc>,
+    accepted_at: Option<DateTime<Utc>>,
+    guardian_username: String,
+    student_username: String,
+}
+
+impl TryFrom<GuardianLinkDetailRow> for GuardianLinkDetail {
+    type Error = DomainError;
+
+    fn try_from(row: GuardianLinkDetailRow) -> Result<Self, Self::Error> {
+        let link = GuardianLinkRow {
+            id: row.id,
+            guardian_id: row.guardian_id,
+            student_id: row.student_id,
+            status: row.status,
+            invited_at: row.invited_at,
+            accepted_at: row.accepted_at,
+        };
+
+        Ok(GuardianLinkDetail {
+            link: link.try_into()?,
+            guardian_username: row.guardian_username,
+            student_username: row.student_username,
+        })
+    }
+}
+
+#[async_trait]
+impl GuardianLinkRepository for PgGuardianLinkRepository {
+    async fn create(&self, link: &GuardianLink) -> Result<GuardianLink, DomainError> {
+        let row = sqlx::query_as::<_, GuardianLinkRow>(
+            r#"
+            INSERT INTO guardian_links (id, guardian_id, student_id, status, invited_at, accepted_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, guardian_id, student_id, status, invited_at, accepted_at
+            "#,
+        )
+        .bind(link.id)
+        .bind(link.guardian_id)
+        .bind(link.student_id)
+        .bind(link.status.as_str())
+        .bind(link.invited_at)
+        .bind(link.accepted_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<GuardianLink>, DomainError> {
+        let row = sqlx::query_as::<_, GuardianLinkRow>(
+            r#"
+            SELECT id, guardian_id, student_id, status, invited_at, accepted_at
+            FROM guardian_links
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_by_pair(
+        &self,
+        guardian_id: Uuid,
+        student_id: Uuid,
+    ) -> Result<Option<GuardianLink>, DomainError> {
+        let row = sqlx::query_as::<_, GuardianLinkRow>(
+            r#"
+            SELECT id, guardian_id, student_id, status, invited_at, accepted_at
+            FROM guardian_links
+            WHERE guardian_id = $1 AND student_id = $2
+            "#,
+        )
+        .bind(guardian_id)
+        .bind(student_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_by_guardian(
+        &self,
+        guardian_id: Uuid,
+    ) -> Result<Vec<GuardianLinkDetail>, DomainError> {
+        let rows = sqlx::query_as::<_, GuardianLinkDetailRow>(
+            r#"
+            SELECT gl.id, gl.guardian_id, gl.student_id, gl.status, gl.invited_at, gl.accepted_at,
+                   g.username AS guardian_username, s.username AS student_username
+            FROM guardian_links gl
+            JOIN users g ON g.id = gl.guardian_id
+            JOIN users s ON s.id = gl.student_id
+            WHERE gl.guardian_id = $1
+            ORDER BY s.username ASC
+            "#,
+        )
+        .bind(guardian_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn find_by_student(
+        &self,
+        student_id: Uuid,
+    ) -> Result<Vec<GuardianLinkDetail>, DomainError> {
+        let rows = sqlx::query_as::<_, GuardianLinkDetailRow>(
+            r#"
+            SELECT gl.id, gl.guardian_id, gl.student_id, gl.status, gl.invited_at, gl.accepted_at,
+                   g.username AS guardian_username, s.username AS student_username
+            FROM guardian_links gl
+            JOIN users g ON g.id = gl.guardian_id
+            JOIN users s ON s.id = gl.student_id
+            WHERE gl.student_id = $1
+            ORDER BY g.username ASC
+            "#,
+        )
+        .bind(student_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn update(&self, link: &GuardianLink) -> Result<GuardianLink, DomainError> {
+        let row = sqlx::query_as::<_, GuardianLinkRow>(
+            r#"
+            UPDATE guardian_links
+            SET status = $2, accepted_at = $3
+            WHERE id = $1
+            RETURNING id, guardian_id, student_id, status, invited_at, accepted_at
+            "#,
+        )
+        .bind(link.id)
+        .bind(link.status.as_str())
+        .bind(link.accepted_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM guardian_links WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,354 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use domain::entities::{ExamSessionStatus, MockExamAttempt, MockExamAttemptSection, TestResult};
+use domain::errors::DomainError;
+use domain::repositories::MockExamAttemptRepository;
+
+use super::test_result_repository_impl::insert_test_result;
+
+/// PostgreSQL implementation of the MockExamAttemptRepository trait.
+pub struct PgMockExamAttemptRepository {
+    pool: PgPool,
+}
+
+impl PgMockExamAttemptRepository {
+    /// Creates a new PostgreSQL mock exam attempt repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Loads the sections of the given attempts, grouped by attempt ID.
+    async fn load_sections(
+        &self,
+        attempt_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<MockExamAttemptSection>>, DomainError> {
+        let rows = sqlx::query_as::<_, SectionRow>(
+            r#"
+            SELECT attempt_id, practice_test_id, position, answers, answer_key_revision, test_result_id
+            FROM mock_exam_attempt_sections
+            WHERE attempt_id = ANY($1)
+            ORDER BY position ASC
+            "#,
+        )
+        .bind(attempt_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut sections: HashMap<Uuid, Vec<MockExamAttemptSection>> = HashMap::new();
+        for row in rows {
+            sections
+                .entry(row.attempt_id)
+                .or_default()
+                .push(MockExamAttemptSection {
+                    practice_test_id: row.practice_test_id,
+                    position: row.position,
+                    answers: row.answers,
+                    answer_key_revision: row.answer_key_revision,
+                    test_result_id: row.test_result_id,
+                });
+        }
+
+        Ok(sections)
+    }
+
+    /// Attaches sections to the given attempt rows.
+    async fn attach_sections(
+        &self,
+        rows: Vec<AttemptRow>,
+    ) -> Result<Vec<MockExamAttempt>, DomainError> {
+        let ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+        let mut sections = self.load_sections(&ids).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let attempt_sections = sections.remove(&row.id).unwrap_or_default();
+                row.into_entity(attempt_sections)
+            })
+            .collect()
+    }
+
+    /// Stores the answers and test result references of every section of an attempt.
+    async fn update_sections(
+        tx: &mut Transaction<'_, Postgres>,
+        attempt: &MockExamAttempt,
+    ) -> Result<(), DomainError> {
+        for section in &attempt.sections {
+            sqlx::query(
+                r#"
+                UPDATE mock_exam_attempt_sections
+                SET answers = $3, test_result_id = $4
+                WHERE attempt_id = $1 AND practice_test_id = $2
+                "#,
+            )
+            .bind(attempt.id)
+            .bind(section.practice_test_id)
+            .bind(&section.answers)
+            .bind(section.test_result_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct AttemptRow {
+    id: Uuid,
+    user_id: Uuid,
+    mock_exam_id: Uuid,
+    status: String,
+    started_at: DateTime<Utc>,
+    expires_at: DateTime<Utc>,
+    last_saved_at: DateTime<Utc>,
+    submitted_at: Option<DateTime<Utc>>,
+}
+
+impl AttemptRow {
+    fn into_entity(
+        self,
+        sections: Vec<MockExamAttemptSection>,
+    ) -> Result<MockExamAttempt, DomainError> {
+        let status = ExamSessionStatus::parse(&self.status).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown mock exam attempt status: {}", self.status))
+        })?;
+
+        Ok(MockExamAttempt {
+            id: self.id,
+            user_id: self.user_id,
+            mock_exam_id: self.mock_exam_id,
+            status,
+            started_at: self.started_at,
+            expires_at: self.expires_at,
+            last_saved_at: self.last_saved_at,
+            submitted_at: self.submitted_at,
+            sections,
+        })
+    }
+}
+
+/// Internal row structure for section queries.
+#[derive(sqlx::FromRow)]
+struct SectionRow {
+    attempt_id: Uuid,
+    practice_test_id: Uuid,
+    position: i32,
+    answers: String,
+    answer_key_revision: i32,
+    test_result_id: Option<Uuid>,
+}
+
+#[async_trait]
+impl MockExamAttemptRepository for PgMockExamAttemptRepository {
+    async fn create(&self, attempt: &MockExamAttempt) -> Result<MockExamAttempt, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let row = sqlx::query_as::<_, AttemptRow>(
+            r#"
+            INSERT INTO mock_exam_attempts (id, user_id, mock_exam_id, status, started_at, expires_at, last_saved_at, submitted_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id, user_id, mock_exam_id, status, started_at, expires_at, last_saved_at, submitted_at
+            "#,
+        )
+        .bind(attempt.id)
+        .bind(attempt.user_id)
+        .bind(attempt.mock_exam_id)
+        .bind(attempt.status.as_str())
+        .bind(attempt.started_at)
+        .bind(attempt.expires_at)
+        .bind(attempt.last_saved_at)
+        .bind(attempt.submitted_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        for section in &attempt.sections {
+            sqlx::query(
+                r#"
+                INSERT INTO mock_exam_attempt_sections (attempt_id, practice_test_id, position, answers, answer_key_revision, test_result_id)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                "#,
+            )
+            .bind(attempt.id)
+            .bind(section.practice_test_id)
+            .bind(section.position)
+            .bind(&section.answers)
+            .bind(section.answer_key_revision)
+            .bind(section.test_result_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.into_entity(attempt.sections.clone())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<MockExamAttempt>, DomainError> {
+        let row = sqlx::query_as::<_, AttemptRow>(
+            r#"
+            SELECT id, user_id, mock_exam_id, status, started_at, expires_at, last_saved_at, submitted_at
+            FROM mock_exam_attempts
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(self.attach_sections(row.into_iter().collect()).await?.pop())
+    }
+
+    async fn find_in_progress_by_user_and_mock_exam(
+        &self,
+        user_id: Uuid,
+        mock_exam_id: Uuid,
+    ) -> Result<Option<MockExamAttempt>, DomainError> {
+        let row = sqlx::query_as::<_, AttemptRow>(
+            r#"
+            SELECT id, user_id, mock_exam_id, status, started_at, expires_at, last_saved_at, submitted_at
+            FROM mock_exam_attempts
+            WHERE user_id = $1 AND mock_exam_id = $2 AND status = 'in_progress'
+            "#,
+        )
+        .bind(user_id)
+        .bind(mock_exam_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(self.attach_sections(row.into_iter().collect()).await?.pop())
+    }
+
+    async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<MockExamAttempt>, DomainError> {
+        let rows = sqlx::query_as::<_, AttemptRow>(
+            r#"
+            SELECT id, user_id, mock_exam_id, status, started_at, expires_at, last_saved_at, submitted_at
+            FROM mock_exam_attempts
+            WHERE user_id = $1
+            ORDER BY started_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.attach_sections(rows).await
+    }
+
+    async fn find_expired(&self, now: DateTime<Utc>) -> Result<Vec<MockExamAttempt>, DomainError> {
+        let rows = sqlx::query_as::<_, AttemptRow>(
+            r#"
+            SELECT id, user_id, mock_exam_id, status, started_at, expires_at, last_saved_at, submitted_at
+            FROM mock_exam_attempts
+            WHERE status = 'in_progress' AND expires_at <= $1
+            ORDER BY expires_at ASC
+            "#,
+        )
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.attach_sections(rows).await
+    }
+
+    async fn save_answers(&self, attempt: &MockExamAttempt) -> Result<bool, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let updated = sqlx::query(
+            r#"
+            UPDATE mock_exam_attempts
+            SET last_saved_at = $2
+            WHERE id = $1 AND status = 'in_progress'
+            "#,
+        )
+        .bind(attempt.id)
+        .bind(attempt.last_saved_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if updated.rows_affected() == 0 {
+            tx.rollback()
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            return Ok(false);
+        }
+
+        Self::update_sections(&mut tx, attempt).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+
+    async fn submit(
+        &self,
+        attempt: &MockExamAttempt,
+        test_results: &[TestResult],
+    ) -> Result<bool, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        // Only the first submission wins; a concurrent auto-submit rolls back here
+        let updated = sqlx::query(
+            r#"
+            UPDATE mock_exam_attempts
+            SET status = $2, submitted_at = $3
+            WHERE id = $1 AND status = 'in_progress'
+            "#,
+        )
+        .bind(attempt.id)
+        .bind(attempt.status.as_str())
+        .bind(attempt.submitted_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        if updated.rows_affected() == 0 {
+            tx.rollback()
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            return Ok(false);
+        }
+
+        // The results must exist before the sections can reference them
+        for test_result in test_results {
+            insert_test_result(&mut *tx, test_result).await?;
+        }
+        Self::update_sections(&mut tx, attempt).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(true)
+    }
+}
@@ -0,0 +1,242 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+
+use domain::entities::{MockExam, MockExamSection};
+use domain::errors::DomainError;
+use domain::repositories::MockExamRepository;
+
+/// PostgreSQL implementation of the MockExamRepository trait.
+pub struct PgMockExamRepository {
+    pool: PgPool,
+}
+
+impl PgMockExamRepository {
+    /// Creates a new PostgreSQL mock exam repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Loads the sections of the given mock exams, grouped by mock exam ID.
+    async fn load_sections(
+        &self,
+        mock_exam_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<MockExamSection>>, DomainError> {
+        let rows = sqlx::query_as::<_, SectionRow>(
+            r#"
+            SELECT mock_exam_id, practice_test_id, position
+            FROM mock_exam_sections
+            WHERE mock_exam_id = ANY($1)
+            ORDER BY position ASC
+            "#,
+        )
+        .bind(mock_exam_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut sections: HashMap<Uuid, Vec<MockExamSection>> = HashMap::new();
+        for row in rows {
+            sections
+                .entry(row.mock_exam_id)
+                .or_default()
+                .push(MockExamSection {
+                    practice_test_id: row.practice_test_id,
+                    position: row.position,
+                });
+        }
+
+        Ok(sections)
+    }
+
+    /// Attaches sections to the given mock exam rows.
+    async fn attach_sections(&self, rows: Vec<MockExamRow>) -> Result<Vec<MockExam>, DomainError> {
+        let ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+        let mut sections = self.load_sections(&ids).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let exam_sections = sections.remove(&row.id).unwrap_or_default();
+                row.into_entity(exam_sections)
+            })
+            .collect())
+    }
+
+    /// Replaces the stored sections of a mock exam.
+    async fn replace_sections(
+        tx: &mut Transaction<'_, Postgres>,
+        mock_exam: &MockExam,
+    ) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM mock_exam_sections WHERE mock_exam_id = $1")
+            .bind(mock_exam.id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        for section in &mock_exam.sections {
+            sqlx::query(
+                r#"
+                INSERT INTO mock_exam_sections (mock_exam_id, practice_test_id, position)
+                VALUES ($1, $2, $3)
+                "#,
+            )
+            .bind(mock_exam.id)
+            .bind(section.practice_test_id)
+            .bind(section.position)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct MockExamRow {
+    id: Uuid,
+    name: String,
+    exam_type_id: Uuid,
+    time_limit_minutes: i32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl MockExamRow {
+    fn into_entity(self, sections: Vec<MockExamSection>) -> MockExam {
+        MockExam {
+            id: self.id,
+            name: self.name,
+            exam_type_id: self.exam_type_id,
+            time_limit_minutes: self.time_limit_minutes,
+            sections,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Internal row structure for section queries.
+#[derive(sqlx::FromRow)]
+struct SectionRow {
+    mock_exam_id: Uuid,
+    practice_test_id: Uuid,
+    position: i32,
+}
+
+#[async_trait]
+impl MockExamRepository for PgMockExamRepository {
+    async fn create(&self, mock_exam: &MockExam) -> Result<MockExam, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let row = sqlx::query_as::<_, MockExamRow>(
+            r#"
+            INSERT INTO mock_exams (id, name, exam_type_id, time_limit_minutes, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            RETURNING id, name, exam_type_id, time_limit_minutes, created_at, updated_at
+            "#,
+        )
+        .bind(mock_exam.id)
+        .bind(&mock_exam.name)
+        .bind(mock_exam.exam_type_id)
+        .bind(mock_exam.time_limit_minutes)
+        .bind(mock_exam.created_at)
+        .bind(mock_exam.updated_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Self::replace_sections(&mut tx, mock_exam).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into_entity(mock_exam.sections.clone()))
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<MockExam>, DomainError> {
+        let row = sqlx::query_as::<_, MockExamRow>(
+            r#"
+            SELECT id, name, exam_type_id, time_limit_minutes, created_at, updated_at
+            FROM mock_exams
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(self.attach_sections(row.into_iter().collect()).await?.pop())
+    }
+
+    async fn list(&self, exam_type_id: Option<Uuid>) -> Result<Vec<MockExam>, DomainError> {
+        let rows = sqlx::query_as::<_, MockExamRow>(
+            r#"
+            SELECT id, name, exam_type_id, time_limit_minutes, created_at, updated_at
+            FROM mock_exams
+            WHERE $1::uuid IS NULL OR exam_type_id = $1
+            ORDER BY created_at DESC
+            "#,
+        )
+        .bind(exam_type_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.attach_sections(rows).await
+    }
+
+    async fn update(&self, mock_exam: &MockExam) -> Result<MockExam, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let row = sqlx::query_as::<_, MockExamRow>(
+            r#"
+            UPDATE mock_exams
+            SET name = $2, exam_type_id = $3, time_limit_minutes = $4, updated_at = $5
+            WHERE id = $1
+            RETURNING id, name, exam_type_id, time_limit_minutes, created_at, updated_at
+            "#,
+        )
+        .bind(mock_exam.id)
+        .bind(&mock_exam.name)
+        .bind(mock_exam.exam_type_id)
+        .bind(mock_exam.time_limit_minutes)
+        .bind(mock_exam.updated_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Self::replace_sections(&mut tx, mock_exam).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into_entity(mock_exam.sections.clone()))
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM mock_exams WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
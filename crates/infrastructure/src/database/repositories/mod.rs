@@ -1,22 +1,49 @@
+mod achievement_repository_impl;
+mod analytics_repository_impl;
+mod assignment_repository_impl;
+mod classroom_repository_impl;
+mod exam_session_repository_impl;
 mod exam_type_repository_impl;
+mod guardian_link_repository_impl;
 mod lesson_repository_impl;
+mod mock_exam_attempt_repository_impl;
+mod mock_exam_repository_impl;
+mod permission_repository_impl;
 mod practice_test_repository_impl;
 mod refresh_token_repository_impl;
+mod retake_policy_repository_impl;
 mod role_repository_impl;
+mod score_table_repository_impl;
+mod scoring_policy_repository_impl;
+mod study_plan_repository_impl;
 mod subject_repository_impl;
 mod test_book_repository_impl;
 mod test_book_subject_repository_impl;
 mod test_result_repository_impl;
+mod topic_repository_impl;
 mod user_repository_impl;
 
+pub use achievement_repository_impl::PgAchievementRepository;
+pub use analytics_repository_impl::PgAnalyticsRepository;
+pub use assignment_repository_impl::PgAssignmentRepository;
+pub use classroom_repository_impl::PgClassroomRepository;
+pub use exam_session_repository_impl::PgExamSessionRepository;
 pub use exam_type_repository_impl::PgExamTypeRepository;
+pub use guardian_link_repository_impl::PgGuardianLinkRepository;
 pub use lesson_repository_impl::PgLessonRepository;
+pub use mock_exam_attempt_repository_impl::PgMockExamAttemptRepository;
+pub use mock_exam_repository_impl::PgMockExamRepository;
+pub use permission_repository_impl::PgPermissionRepository;
 pub use practice_test_repository_impl::PgPracticeTestRepository;
 pub use refresh_token_repository_impl::PgRefreshTokenRepository;
+pub use retake_policy_repository_impl::PgRetakePolicyRepository;
 pub use role_repository_impl::PgRoleRepository;
+pub use score_table_repository_impl::PgScoreTableRepository;
+pub use scoring_policy_repository_impl::PgScoringPolicyRepository;
+pub use study_plan_repository_impl::PgStudyPlanRepository;
 pub use subject_repository_impl::PgSubjectRepository;
 pub use test_book_repository_impl::PgTestBookRepository;
 pub use test_book_subject_repository_impl::PgTestBookSubjectRepository;
 pub use test_result_repository_impl::PgTestResultRepository;
+pub use topic_repository_impl::PgTopicRepository;
 pub use user_repository_impl::PgUserRepository;
-
@@ -0,0 +1,144 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::Permission;
+use domain::errors::DomainError;
+use domain::repositories::PermissionRepository;
+
+/// PostgreSQL implementation of the PermissionRepository trait.
+pub struct PgPermissionRepository {
+    pool: PgPool,
+}
+
+impl PgPermissionRepository {
+    /// Creates a new PostgreSQL permission repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct PermissionRow {
+    id: Uuid,
+    name: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+impl From<PermissionRow> for Permission {
+    fn from(row: PermissionRow) -> Self {
+        Permission {
+            id: row.id,
+            name: row.name,
+            description: row.description,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl PermissionRepository for PgPermissionRepository {
+    async fn list(&self) -> Result<Vec<Permission>, DomainError> {
+        let rows = sqlx::query_as::<_, PermissionRow>(
+            r#"
+            SELECT id, name, description, created_at
+            FROM permissions
+            ORDER BY name ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Permission>, DomainError> {
+        let row = sqlx::query_as::<_, PermissionRow>(
+            r#"
+            SELECT id, name, description, created_at
+            FROM permissions
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_by_role_id(&self, role_id: Uuid) -> Result<Vec<Permission>, DomainError> {
+        let rows = sqlx::query_as::<_, PermissionRow>(
+            r#"
+            SELECT p.id, p.name, p.description, p.created_at
+            FROM permissions p
+            INNER JOIN role_permissions rp ON p.id = rp.permission_id
+            WHERE rp.role_id = $1
+            ORDER BY p.name ASC
+            "#,
+        )
+        .bind(role_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_names_by_user_id(&self, user_id: Uuid) -> Result<Vec<String>, DomainError> {
+        let names = sqlx::query_scalar::<_, String>(
+            r#"
+            SELECT DISTINCT p.name
+            FROM permissions p
+            INNER JOIN role_permissions rp ON p.id = rp.permission_id
+            INNER JOIN user_roles ur ON rp.role_id = ur.role_id
+            WHERE ur.user_id = $1
+            ORDER BY p.name ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(names)
+    }
+
+    async fn grant_to_role(&self, role_id: Uuid, permission_id: Uuid) -> Result<bool, DomainError> {
+        let result = sqlx::query(
+            r#"
+            INSERT INTO role_permissions (role_id, permission_id)
+            VALUES ($1, $2)
+            ON CONFLICT (role_id, permission_id) DO NOTHING
+            "#,
+        )
+        .bind(role_id)
+        .bind(permission_id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn revoke_from_role(
+        &self,
+        role_id: Uuid,
+        permission_id: Uuid,
+    ) -> Result<bool, DomainError> {
+        let result =
+            sqlx::query("DELETE FROM role_permissions WHERE role_id = $1 AND permission_id = $2")
+                .bind(role_id)
+                .bind(permission_id)
+                .execute(&self.pool)
+                .await
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
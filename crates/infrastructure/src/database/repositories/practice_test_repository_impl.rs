@@ -3,9 +3,11 @@ use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use domain::entities::PracticeTest;
+use domain::entities::{
+    AnswerKeyEntry, AnswerKeyRevision, PracticeTest, Question, QuestionDifficulty,
+};
 use domain::errors::DomainError;
-use domain::repositories::PracticeTestRepository;
+use domain::repositories::{PracticeTestRepository, RegradeLock};
 
 /// PostgreSQL implementation of the PracticeTestRepository trait.
 pub struct PgPracticeTestRepository {
@@ -17,6 +19,89 @@ impl PgPracticeTestRepository {
     pub fn new(pool: PgPool) -> Self {
         Self { pool }
     }
+
+    /// Stores the practice test's current answer key as a revision unless it already exists.
+    async fn insert_answer_key_revision(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        practice_test: &PracticeTest,
+        regraded_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            INSERT INTO answer_key_revisions (practice_test_id, revision, answer_key, regraded_at)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (practice_test_id, revision) DO NOTHING
+            "#,
+        )
+        .bind(practice_test.id)
+        .bind(practice_test.answer_key_revision)
+        .bind(&practice_test.answer_key)
+        .bind(regraded_at)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Updates the practice test and records its answer key revision if it is new.
+    async fn update_in_tx(
+        tx: &mut sqlx::Transaction<'_, sqlx::Postgres>,
+        practice_test: &PracticeTest,
+    ) -> Result<PracticeTest, DomainError> {
+        let row = sqlx::query_as::<_, PracticeTestRow>(
+            r#"
+            UPDATE practice_tests
+            SET name = $2, test_number = $3, question_count = $4, answer_key = $5, answer_key_revision = $6, test_book_id = $7, subject_id = $8, time_limit_minutes = $9, topic_id = $10
+            WHERE id = $1
+            RETURNING id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
+            "#,
+        )
+        .bind(practice_test.id)
+        .bind(&practice_test.name)
+        .bind(practice_test.test_number)
+        .bind(practice_test.question_count)
+        .bind(&practice_test.answer_key)
+        .bind(practice_test.answer_key_revision)
+        .bind(practice_test.test_book_id)
+        .bind(practice_test.subject_id)
+        .bind(practice_test.time_limit_minutes)
+        .bind(practice_test.topic_id)
+        .fetch_one(&mut **tx)
+        .await
+        .map_err(map_write_error)?;
+        let updated: PracticeTest = row.into();
+
+        Self::insert_answer_key_revision(tx, &updated, None).await?;
+
+        Ok(updated)
+    }
+}
+
+/// Maps write errors, keeping the unique constraint name visible to the service layer.
+fn map_write_error(e: sqlx::Error) -> DomainError {
+    if let sqlx::Error::Database(ref db_err) = e {
+        if db_err.is_unique_violation() {
+            let constraint = db_err.constraint().unwrap_or("");
+            if constraint == "practice_tests_book_subject_name_number_unique" {
+                return DomainError::DatabaseError(
+                    "duplicate key value violates unique constraint \"practice_tests_book_subject_name_number_unique\"".to_string(),
+                );
+            }
+        }
+        if db_err.is_foreign_key_violation() {
+            let constraint = db_err.constraint().unwrap_or("");
+            if constraint == "practice_tests_topic_id_fkey"
+                || constraint == "questions_topic_id_fkey"
+            {
+                return DomainError::DatabaseError(format!(
+                    "insert or update violates foreign key constraint \"{}\"",
+                    constraint
+                ));
+            }
+        }
+    }
+    DomainError::DatabaseError(e.to_string())
 }
 
 /// Internal row structure for database queries.
@@ -27,9 +112,77 @@ struct PracticeTestRow {
     test_number: i32,
     question_count: i32,
     answer_key: String,
+    answer_key_revision: i32,
     test_book_id: Uuid,
     subject_id: Uuid,
+    time_limit_minutes: Option<i32>,
+    topic_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+}
+
+/// Internal row structure for answer key revision queries.
+#[derive(sqlx::FromRow)]
+struct AnswerKeyRevisionRow {
+    id: Uuid,
+    practice_test_id: Uuid,
+    revision: i32,
+    answer_key: String,
     created_at: DateTime<Utc>,
+    regraded_at: Option<DateTime<Utc>>,
+}
+
+impl From<AnswerKeyRevisionRow> for AnswerKeyRevision {
+    fn from(row: AnswerKeyRevisionRow) -> Self {
+        AnswerKeyRevision {
+            id: row.id,
+            practice_test_id: row.practice_test_id,
+            revision: row.revision,
+            answer_key: row.answer_key,
+            created_at: row.created_at,
+            regraded_at: row.regraded_at,
+        }
+    }
+}
+
+/// Internal row structure for question queries.
+#[derive(sqlx::FromRow)]
+struct QuestionRow {
+    id: Uuid,
+    practice_test_id: Uuid,
+    position: i32,
+    answer: String,
+    topic_id: Option<Uuid>,
+    difficulty: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<QuestionRow> for Question {
+    type Error = DomainError;
+
+    fn try_from(row: QuestionRow) -> Result<Self, Self::Error> {
+        let answer: AnswerKeyEntry = serde_json::from_str(&row.answer)
+            .map_err(|e| DomainError::DatabaseError(format!("Invalid question answer: {}", e)))?;
+        let difficulty = row
+            .difficulty
+            .map(|value| {
+                QuestionDifficulty::parse(&value).ok_or_else(|| {
+                    DomainError::DatabaseError(format!("Unknown question difficulty: {}", value))
+                })
+            })
+            .transpose()?;
+
+        Ok(Question {
+            id: row.id,
+            practice_test_id: row.practice_test_id,
+            position: row.position,
+            answer,
+            topic_id: row.topic_id,
+            difficulty,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
 }
 
 impl From<PracticeTestRow> for PracticeTest {
@@ -40,8 +193,11 @@ impl From<PracticeTestRow> for PracticeTest {
             test_number: row.test_number,
             question_count: row.question_count,
             answer_key: row.answer_key,
+            answer_key_revision: row.answer_key_revision,
             test_book_id: row.test_book_id,
             subject_id: row.subject_id,
+            time_limit_minutes: row.time_limit_minutes,
+            topic_id: row.topic_id,
             created_at: row.created_at,
         }
     }
@@ -50,11 +206,17 @@ impl From<PracticeTestRow> for PracticeTest {
 #[async_trait]
 impl PracticeTestRepository for PgPracticeTestRepository {
     async fn create(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
         let row = sqlx::query_as::<_, PracticeTestRow>(
             r#"
-            INSERT INTO practice_tests (id, name, test_number, question_count, answer_key, test_book_id, subject_id, created_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
-            RETURNING id, name, test_number, question_count, answer_key, test_book_id, subject_id, created_at
+            INSERT INTO practice_tests (id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            RETURNING id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
             "#,
         )
         .bind(practice_test.id)
@@ -62,32 +224,31 @@ impl PracticeTestRepository for PgPracticeTestRepository {
         .bind(practice_test.test_number)
         .bind(practice_test.question_count)
         .bind(&practice_test.answer_key)
+        .bind(practice_test.answer_key_revision)
         .bind(practice_test.test_book_id)
         .bind(practice_test.subject_id)
+        .bind(practice_test.time_limit_minutes)
+        .bind(practice_test.topic_id)
         .bind(practice_test.created_at)
-        .fetch_one(&self.pool)
+        .fetch_one(&mut *tx)
         .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(ref db_err) = e {
-                if db_err.is_unique_violation() {
-                    let constraint = db_err.constraint().unwrap_or("");
-                    if constraint == "practice_tests_book_subject_name_number_unique" {
-                        return DomainError::DatabaseError(
-                            "duplicate key value violates unique constraint \"practice_tests_book_subject_name_number_unique\"".to_string(),
-                        );
-                    }
-                }
-            }
-            DomainError::DatabaseError(e.to_string())
-        })?;
+        .map_err(map_write_error)?;
+        let created: PracticeTest = row.into();
+
+        // The first revision has no earlier results to re-grade
+        Self::insert_answer_key_revision(&mut tx, &created, Some(created.created_at)).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(row.into())
+        Ok(created)
     }
 
     async fn find_by_id(&self, id: Uuid) -> Result<Option<PracticeTest>, DomainError> {
         let row = sqlx::query_as::<_, PracticeTestRow>(
             r#"
-            SELECT id, name, test_number, question_count, answer_key, test_book_id, subject_id, created_at
+            SELECT id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
             FROM practice_tests
             WHERE id = $1
             "#,
@@ -100,10 +261,29 @@ impl PracticeTestRepository for PgPracticeTestRepository {
         Ok(row.map(|r| r.into()))
     }
 
-    async fn find_by_test_book_id(&self, test_book_id: Uuid) -> Result<Vec<PracticeTest>, DomainError> {
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<PracticeTest>, DomainError> {
         let rows = sqlx::query_as::<_, PracticeTestRow>(
             r#"
-            SELECT id, name, test_number, question_count, answer_key, test_book_id, subject_id, created_at
+            SELECT id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
+            FROM practice_tests
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_by_test_book_id(
+        &self,
+        test_book_id: Uuid,
+    ) -> Result<Vec<PracticeTest>, DomainError> {
+        let rows = sqlx::query_as::<_, PracticeTestRow>(
+            r#"
+            SELECT id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
             FROM practice_tests
             WHERE test_book_id = $1
             ORDER BY test_number ASC
@@ -117,39 +297,45 @@ impl PracticeTestRepository for PgPracticeTestRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 
-    async fn update(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError> {
-        let row = sqlx::query_as::<_, PracticeTestRow>(
+    async fn find_in_test_books_started_by(
+        &self,
+        user_id: Uuid,
+    ) -> Result<Vec<PracticeTest>, DomainError> {
+        let rows = sqlx::query_as::<_, PracticeTestRow>(
             r#"
-            UPDATE practice_tests
-            SET name = $2, test_number = $3, question_count = $4, answer_key = $5, test_book_id = $6, subject_id = $7
-            WHERE id = $1
-            RETURNING id, name, test_number, question_count, answer_key, test_book_id, subject_id, created_at
+            SELECT id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
+            FROM practice_tests
+            WHERE test_book_id IN (
+                SELECT pt.test_book_id
+                FROM test_results tr
+                JOIN practice_tests pt ON pt.id = tr.practice_test_id
+                WHERE tr.user_id = $1 AND tr.mock_exam_attempt_id IS NULL
+            )
+            ORDER BY test_book_id, test_number ASC
             "#,
         )
-        .bind(practice_test.id)
-        .bind(&practice_test.name)
-        .bind(practice_test.test_number)
-        .bind(practice_test.question_count)
-        .bind(&practice_test.answer_key)
-        .bind(practice_test.test_book_id)
-        .bind(practice_test.subject_id)
-        .fetch_one(&self.pool)
+        .bind(user_id)
+        .fetch_all(&self.pool)
         .await
-        .map_err(|e| {
-            if let sqlx::Error::Database(ref db_err) = e {
-                if db_err.is_unique_violation() {
-                    let constraint = db_err.constraint().unwrap_or("");
-                    if constraint == "practice_tests_book_subject_name_number_unique" {
-                        return DomainError::DatabaseError(
-                            "duplicate key value violates unique constraint \"practice_tests_book_subject_name_number_unique\"".to_string(),
-                        );
-                    }
-                }
-            }
-            DomainError::DatabaseError(e.to_string())
-        })?;
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(row.into())
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn update(&self, practice_test: &PracticeTest) -> Result<PracticeTest, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let updated = Self::update_in_tx(&mut tx, practice_test).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(updated)
     }
 
     async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
@@ -165,7 +351,7 @@ impl PracticeTestRepository for PgPracticeTestRepository {
     async fn list_all(&self) -> Result<Vec<PracticeTest>, DomainError> {
         let rows = sqlx::query_as::<_, PracticeTestRow>(
             r#"
-            SELECT id, name, test_number, question_count, answer_key, test_book_id, subject_id, created_at
+            SELECT id, name, test_number, question_count, answer_key, answer_key_revision, test_book_id, subject_id, time_limit_minutes, topic_id, created_at
             FROM practice_tests
             ORDER BY created_at DESC
             "#,
@@ -176,5 +362,149 @@ impl PracticeTestRepository for PgPracticeTestRepository {
 
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
-}
 
+    async fn find_questions(&self, practice_test_id: Uuid) -> Result<Vec<Question>, DomainError> {
+        let rows = sqlx::query_as::<_, QuestionRow>(
+            r#"
+            SELECT id, practice_test_id, position, answer, topic_id, difficulty, created_at, updated_at
+            FROM questions
+            WHERE practice_test_id = $1
+            ORDER BY position ASC
+            "#,
+        )
+        .bind(practice_test_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(Question::try_from).collect()
+    }
+
+    async fn replace_questions(
+        &self,
+        practice_test: &PracticeTest,
+        questions: &[Question],
+    ) -> Result<(PracticeTest, Vec<Question>), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let updated = Self::update_in_tx(&mut tx, practice_test).await?;
+
+        sqlx::query("DELETE FROM questions WHERE practice_test_id = $1")
+            .bind(practice_test.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut saved = Vec::with_capacity(questions.len());
+        for question in questions {
+            let answer = serde_json::to_string(&question.answer)
+                .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+            let row = sqlx::query_as::<_, QuestionRow>(
+                r#"
+                INSERT INTO questions (id, practice_test_id, position, answer, topic_id, difficulty, created_at, updated_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING id, practice_test_id, position, answer, topic_id, difficulty, created_at, updated_at
+                "#,
+            )
+            .bind(question.id)
+            .bind(practice_test.id)
+            .bind(question.position)
+            .bind(answer)
+            .bind(question.topic_id)
+            .bind(question.difficulty.map(|d| d.as_str()))
+            .bind(question.created_at)
+            .bind(question.updated_at)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(map_write_error)?;
+            saved.push(Question::try_from(row)?);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok((updated, saved))
+    }
+
+    async fn find_answer_key_revisions(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<AnswerKeyRevision>, DomainError> {
+        let rows = sqlx::query_as::<_, AnswerKeyRevisionRow>(
+            r#"
+            SELECT id, practice_test_id, revision, answer_key, created_at, regraded_at
+            FROM answer_key_revisions
+            WHERE practice_test_id = $1
+            ORDER BY revision DESC
+            "#,
+        )
+        .bind(practice_test_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_pending_answer_key_revisions(
+        &self,
+    ) -> Result<Vec<AnswerKeyRevision>, DomainError> {
+        let rows = sqlx::query_as::<_, AnswerKeyRevisionRow>(
+            r#"
+            SELECT id, practice_test_id, revision, answer_key, created_at, regraded_at
+            FROM answer_key_revisions
+            WHERE regraded_at IS NULL
+            ORDER BY created_at ASC
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn lock_regrade(&self, practice_test_id: Uuid) -> Result<RegradeLock, DomainError> {
+        // The transaction-level lock is released when the transaction ends, which
+        // dropping the lock does by rolling it back
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended('regrade:' || $1::text, 0))")
+            .bind(practice_test_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(RegradeLock::new(tx))
+    }
+
+    async fn mark_answer_key_revision_regraded(
+        &self,
+        practice_test_id: Uuid,
+        revision: i32,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE answer_key_revisions
+            SET regraded_at = NOW()
+            WHERE practice_test_id = $1 AND revision <= $2 AND regraded_at IS NULL
+            "#,
+        )
+        .bind(practice_test_id)
+        .bind(revision)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{AttemptScoring, RetakePolicy, RetakePolicyScope};
+use domain::errors::DomainError;
+use domain::repositories::RetakePolicyRepository;
+
+/// PostgreSQL implementation of the RetakePolicyRepository trait.
+pub struct PgRetakePolicyRepository {
+    pool: PgPool,
+}
+
+impl PgRetakePolicyRepository {
+    /// Creates a new PostgreSQL retake policy repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct RetakePolicyRow {
+    id: Uuid,
+    exam_type_id: Option<Uuid>,
+    test_book_id: Option<Uuid>,
+    cooldown_minutes: i32,
+    max_attempts: Option<i32>,
+    attempt_scoring: String,
+    unlimited_practice: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl TryFrom<RetakePolicyRow> for RetakePolicy {
+    type Error = DomainError;
+
+    fn try_from(row: RetakePolicyRow) -> Result<Self, Self::Error> {
+        let scope = match (row.test_book_id, row.exam_type_id) {
+            (Some(test_book_id), _) => RetakePolicyScope::TestBook(test_book_id),
+            (None, Some(exam_type_id)) => RetakePolicyScope::ExamType(exam_type_id),
+            (None, None) => {
+                return Err(DomainError::DatabaseError(format!(
+                    "Retake policy {} has no scope",
+                    row.id
+                )))
+            }
+        };
+        let attempt_scoring = AttemptScoring::parse(&row.attempt_scoring).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown attempt scoring: {}", row.attempt_scoring))
+        })?;
+
+        Ok(RetakePolicy {
+            id: row.id,
+            scope,
+            cooldown_minutes: row.cooldown_minutes,
+            max_attempts: row.max_attempts,
+            attempt_scoring,
+            unlimited_practice: row.unlimited_practice,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// Splits a scope into its exam type and test book columns.
+fn scope_columns(scope: RetakePolicyScope) -> (Option<Uuid>, Option<Uuid>) {
+    match scope {
+        RetakePolicyScope::ExamType(id) => (Some(id), None),
+        RetakePolicyScope::TestBook(id) => (None, Some(id)),
+    }
+}
+
+#[async_trait]
+impl RetakePolicyRepository for PgRetakePolicyRepository {
+    async fn save(&self, policy: &RetakePolicy) -> Result<RetakePolicy, DomainError> {
+        // Replace the existing policy of the same scope, keeping its ID
+        let conflict_target = match policy.scope {
+            RetakePolicyScope::ExamType(_) => "exam_type_id",
+            RetakePolicyScope::TestBook(_) => "test_book_id",
+        };
+        let query = format!(
+            r#"
+            INSERT INTO retake_policies (id, exam_type_id, test_book_id, cooldown_minutes, max_attempts, attempt_scoring, unlimited_practice, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            ON CONFLICT ({}) DO UPDATE
+            SET cooldown_minutes = EXCLUDED.cooldown_minutes,
+                max_attempts = EXCLUDED.max_attempts,
+                attempt_scoring = EXCLUDED.attempt_scoring,
+                unlimited_practice = EXCLUDED.unlimited_practice,
+                updated_at = EXCLUDED.updated_at
+            RETURNING id, exam_type_id, test_book_id, cooldown_minutes, max_attempts, attempt_scoring, unlimited_practice, created_at, updated_at
+            "#,
+            conflict_target
+        );
+        let (exam_type_id, test_book_id) = scope_columns(policy.scope);
+
+        let row = sqlx::query_as::<_, RetakePolicyRow>(&query)
+            .bind(policy.id)
+            .bind(exam_type_id)
+            .bind(test_book_id)
+            .bind(policy.cooldown_minutes)
+            .bind(policy.max_attempts)
+            .bind(policy.attempt_scoring.as_str())
+            .bind(policy.unlimited_practice)
+            .bind(policy.created_at)
+            .bind(policy.updated_at)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn find_by_scope(
+        &self,
+        scope: RetakePolicyScope,
+    ) -> Result<Option<RetakePolicy>, DomainError> {
+        let (exam_type_id, test_book_id) = scope_columns(scope);
+
+        let row = sqlx::query_as::<_, RetakePolicyRow>(
+            r#"
+            SELECT id, exam_type_id, test_book_id, cooldown_minutes, max_attempts, attempt_scoring, unlimited_practice, created_at, updated_at
+            FROM retake_policies
+            WHERE exam_type_id = $1 OR test_book_id = $2
+            "#,
+        )
+        .bind(exam_type_id)
+        .bind(test_book_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_effective(
+        &self,
+        test_book_id: Uuid,
+        exam_type_id: Uuid,
+    ) -> Result<Option<RetakePolicy>, DomainError> {
+        let row = sqlx::query_as::<_, RetakePolicyRow>(
+            r#"
+            SELECT id, exam_type_id, test_book_id, cooldown_minutes, max_attempts, attempt_scoring, unlimited_practice, created_at, updated_at
+            FROM retake_policies
+            WHERE test_book_id = $1 OR exam_type_id = $2
+            ORDER BY test_book_id IS NULL
+            LIMIT 1
+            "#,
+        )
+        .bind(test_book_id)
+        .bind(exam_type_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM retake_policies WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,241 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{LessonCoefficient, ScoreTable};
+use domain::errors::DomainError;
+use domain::repositories::ScoreTableRepository;
+
+/// PostgreSQL implementation of the ScoreTableRepository trait.
+pub struct PgScoreTableRepository {
+    pool: PgPool,
+}
+
+impl PgScoreTableRepository {
+    /// Creates a new PostgreSQL score table repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Loads the coefficients of the given tables, grouped by table ID.
+    async fn load_coefficients(
+        &self,
+        score_table_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<LessonCoefficient>>, DomainError> {
+        let rows = sqlx::query_as::<_, CoefficientRow>(
+            r#"
+            SELECT score_table_id, lesson_id, coefficient
+            FROM score_table_coefficients
+            WHERE score_table_id = ANY($1)
+            "#,
+        )
+        .bind(score_table_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut coefficients: HashMap<Uuid, Vec<LessonCoefficient>> = HashMap::new();
+        for row in rows {
+            coefficients
+                .entry(row.score_table_id)
+                .or_default()
+                .push(LessonCoefficient {
+                    lesson_id: row.lesson_id,
+                    coefficient: row.coefficient,
+                });
+        }
+
+        Ok(coefficients)
+    }
+
+    /// Attaches coefficients to the given table rows.
+    async fn attach_coefficients(
+        &self,
+        rows: Vec<ScoreTableRow>,
+    ) -> Result<Vec<ScoreTable>, DomainError> {
+        let ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+        let mut coefficients = self.load_coefficients(&ids).await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let table_coefficients = coefficients.remove(&row.id).unwrap_or_default();
+                row.into_entity(table_coefficients)
+            })
+            .collect())
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct ScoreTableRow {
+    id: Uuid,
+    exam_type_id: Uuid,
+    year: i32,
+    base_score: f64,
+    diploma_coefficient: f64,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl ScoreTableRow {
+    fn into_entity(self, coefficients: Vec<LessonCoefficient>) -> ScoreTable {
+        ScoreTable {
+            id: self.id,
+            exam_type_id: self.exam_type_id,
+            year: self.year,
+            base_score: self.base_score,
+            diploma_coefficient: self.diploma_coefficient,
+            coefficients,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+}
+
+/// Internal row structure for coefficient queries.
+#[derive(sqlx::FromRow)]
+struct CoefficientRow {
+    score_table_id: Uuid,
+    lesson_id: Uuid,
+    coefficient: f64,
+}
+
+#[async_trait]
+impl ScoreTableRepository for PgScoreTableRepository {
+    async fn save(&self, score_table: &ScoreTable) -> Result<ScoreTable, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        // Replace the existing table of the same exam type and year, keeping its ID
+        let row = sqlx::query_as::<_, ScoreTableRow>(
+            r#"
+            INSERT INTO score_tables (id, exam_type_id, year, base_score, diploma_coefficient, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            ON CONFLICT (exam_type_id, year) DO UPDATE
+            SET base_score = EXCLUDED.base_score,
+                diploma_coefficient = EXCLUDED.diploma_coefficient,
+                updated_at = EXCLUDED.updated_at
+            RETURNING id, exam_type_id, year, base_score, diploma_coefficient, created_at, updated_at
+            "#,
+        )
+        .bind(score_table.id)
+        .bind(score_table.exam_type_id)
+        .bind(score_table.year)
+        .bind(score_table.base_score)
+        .bind(score_table.diploma_coefficient)
+        .bind(score_table.created_at)
+        .bind(score_table.updated_at)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        sqlx::query("DELETE FROM score_table_coefficients WHERE score_table_id = $1")
+            .bind(row.id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        for coefficient in &score_table.coefficients {
+            sqlx::query(
+                r#"
+                INSERT INTO score_table_coefficients (score_table_id, lesson_id, coefficient)
+                VALUES ($1, $2, $3)
+                "#,
+            )
+            .bind(row.id)
+            .bind(coefficient.lesson_id)
+            .bind(coefficient.coefficient)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into_entity(score_table.coefficients.clone()))
+    }
+
+    async fn find_by_exam_type_and_year(
+        &self,
+        exam_type_id: Uuid,
+        year: i32,
+    ) -> Result<Option<ScoreTable>, DomainError> {
+        let row = sqlx::query_as::<_, ScoreTableRow>(
+            r#"
+            SELECT id, exam_type_id, year, base_score, diploma_coefficient, created_at, updated_at
+            FROM score_tables
+            WHERE exam_type_id = $1 AND year = $2
+            "#,
+        )
+        .bind(exam_type_id)
+        .bind(year)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(self
+            .attach_coefficients(row.into_iter().collect())
+            .await?
+            .pop())
+    }
+
+    async fn find_latest_by_exam_type(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Option<ScoreTable>, DomainError> {
+        let row = sqlx::query_as::<_, ScoreTableRow>(
+            r#"
+            SELECT id, exam_type_id, year, base_score, diploma_coefficient, created_at, updated_at
+            FROM score_tables
+            WHERE exam_type_id = $1
+            ORDER BY year DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(exam_type_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(self
+            .attach_coefficients(row.into_iter().collect())
+            .await?
+            .pop())
+    }
+
+    async fn list_by_exam_type(&self, exam_type_id: Uuid) -> Result<Vec<ScoreTable>, DomainError> {
+        let rows = sqlx::query_as::<_, ScoreTableRow>(
+            r#"
+            SELECT id, exam_type_id, year, base_score, diploma_coefficient, created_at, updated_at
+            FROM score_tables
+            WHERE exam_type_id = $1
+            ORDER BY year DESC
+            "#,
+        )
+        .bind(exam_type_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        self.attach_coefficients(rows).await
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM score_tables WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
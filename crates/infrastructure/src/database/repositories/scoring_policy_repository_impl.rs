@@ -0,0 +1,137 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{RoundingMode, ScoringPolicy};
+use domain::errors::DomainError;
+use domain::repositories::ScoringPolicyRepository;
+
+/// PostgreSQL implementation of the ScoringPolicyRepository trait.
+pub struct PgScoringPolicyRepository {
+    pool: PgPool,
+}
+
+impl PgScoringPolicyRepository {
+    /// Creates a new PostgreSQL scoring policy repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct ScoringPolicyRow {
+    id: Uuid,
+    exam_type_id: Uuid,
+    version: i32,
+    penalty_ratio: f64,
+    question_weight: f64,
+    rounding_mode: String,
+    decimal_places: i32,
+    created_at: DateTime<Utc>,
+}
+
+impl TryFrom<ScoringPolicyRow> for ScoringPolicy {
+    type Error = DomainError;
+
+    fn try_from(row: ScoringPolicyRow) -> Result<Self, Self::Error> {
+        let rounding_mode = RoundingMode::parse(&row.rounding_mode).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown rounding mode: {}", row.rounding_mode))
+        })?;
+
+        Ok(ScoringPolicy {
+            id: row.id,
+            exam_type_id: row.exam_type_id,
+            version: row.version,
+            penalty_ratio: row.penalty_ratio,
+            question_weight: row.question_weight,
+            rounding_mode,
+            decimal_places: row.decimal_places,
+            created_at: row.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ScoringPolicyRepository for PgScoringPolicyRepository {
+    async fn create(&self, policy: &ScoringPolicy) -> Result<ScoringPolicy, DomainError> {
+        let row = sqlx::query_as::<_, ScoringPolicyRow>(
+            r#"
+            INSERT INTO scoring_policies (id, exam_type_id, version, penalty_ratio, question_weight, rounding_mode, decimal_places, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING id, exam_type_id, version, penalty_ratio, question_weight, rounding_mode, decimal_places, created_at
+            "#,
+        )
+        .bind(policy.id)
+        .bind(policy.exam_type_id)
+        .bind(policy.version)
+        .bind(policy.penalty_ratio)
+        .bind(policy.question_weight)
+        .bind(policy.rounding_mode.as_str())
+        .bind(policy.decimal_places)
+        .bind(policy.created_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ScoringPolicy>, DomainError> {
+        let row = sqlx::query_as::<_, ScoringPolicyRow>(
+            r#"
+            SELECT id, exam_type_id, version, penalty_ratio, question_weight, rounding_mode, decimal_places, created_at
+            FROM scoring_policies
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(TryInto::try_into).transpose()
+    }
+
+    async fn find_current_by_exam_type(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Option<ScoringPolicy>, DomainError> {
+        let row = sqlx::query_as::<_, ScoringPolicyRow>(
+            r#"
+            SELECT id, exam_type_id, version, penalty_ratio, question_weight, rounding_mode, decimal_places, created_at
+            FROM scoring_policies
+            WHERE exam_type_id = $1
+            ORDER BY version DESC
+            LIMIT 1
+            "#,
+        )
+        .bind(exam_type_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(TryInto::try_into).transpose()
+    }
+
+    async fn list_by_exam_type(
+        &self,
+        exam_type_id: Uuid,
+    ) -> Result<Vec<ScoringPolicy>, DomainError> {
+        let rows = sqlx::query_as::<_, ScoringPolicyRow>(
+            r#"
+            SELECT id, exam_type_id, version, penalty_ratio, question_weight, rounding_mode, decimal_places, created_at
+            FROM scoring_policies
+            WHERE exam_type_id = $1
+            ORDER BY version DESC
+            "#,
+        )
+        .bind(exam_type_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(TryInto::try_into).collect()
+    }
+}
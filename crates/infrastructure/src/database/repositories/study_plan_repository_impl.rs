@@ -0,0 +1,245 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::{DailyActivity, GoalMetric, StudyGoal, StudyPlan};
+use domain::errors::DomainError;
+use domain::repositories::StudyPlanRepository;
+
+/// PostgreSQL implementation of the StudyPlanRepository trait.
+pub struct PgStudyPlanRepository {
+    pool: PgPool,
+}
+
+impl PgStudyPlanRepository {
+    /// Creates a new PostgreSQL study plan repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for study plan queries.
+#[derive(sqlx::FromRow)]
+struct StudyPlanRow {
+    id: Uuid,
+    user_id: Uuid,
+    exam_type_id: Uuid,
+    target_date: NaiveDate,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<StudyPlanRow> for StudyPlan {
+    fn from(row: StudyPlanRow) -> Self {
+        StudyPlan {
+            id: row.id,
+            user_id: row.user_id,
+            exam_type_id: row.exam_type_id,
+            target_date: row.target_date,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+/// Internal row structure for study goal queries.
+#[derive(sqlx::FromRow)]
+struct StudyGoalRow {
+    id: Uuid,
+    user_id: Uuid,
+    metric: String,
+    daily_target: i32,
+    lesson_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    archived_at: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<StudyGoalRow> for StudyGoal {
+    type Error = DomainError;
+
+    fn try_from(row: StudyGoalRow) -> Result<Self, Self::Error> {
+        let metric = GoalMetric::parse(&row.metric).ok_or_else(|| {
+            DomainError::DatabaseError(format!("Unknown goal metric: {}", row.metric))
+        })?;
+
+        Ok(StudyGoal {
+            id: row.id,
+            user_id: row.user_id,
+            metric,
+            daily_target: row.daily_target,
+            lesson_id: row.lesson_id,
+            created_at: row.created_at,
+            archived_at: row.archived_at,
+        })
+    }
+}
+
+/// Internal row structure for daily activity queries.
+#[derive(sqlx::FromRow)]
+struct DailyActivityRow {
+    day: NaiveDate,
+    lesson_id: Uuid,
+    test_count: i64,
+    question_count: i64,
+}
+
+impl From<DailyActivityRow> for DailyActivity {
+    fn from(row: DailyActivityRow) -> Self {
+        DailyActivity {
+            day: row.day,
+            lesson_id: row.lesson_id,
+            test_count: row.test_count,
+            question_count: row.question_count,
+        }
+    }
+}
+
+#[async_trait]
+impl StudyPlanRepository for PgStudyPlanRepository {
+    async fn find_plan_by_user(&self, user_id: Uuid) -> Result<Option<StudyPlan>, DomainError> {
+        let row = sqlx::query_as::<_, StudyPlanRow>(
+            r#"
+            SELECT id, user_id, exam_type_id, target_date, created_at, updated_at
+            FROM study_plans
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn save_plan(&self, plan: &StudyPlan) -> Result<StudyPlan, DomainError> {
+        let row = sqlx::query_as::<_, StudyPlanRow>(
+            r#"
+            INSERT INTO study_plans (id, user_id, exam_type_id, target_date, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (user_id) DO UPDATE
+            SET exam_type_id = EXCLUDED.exam_type_id,
+                target_date = EXCLUDED.target_date,
+                updated_at = EXCLUDED.updated_at
+            RETURNING id, user_id, exam_type_id, target_date, created_at, updated_at
+            "#,
+        )
+        .bind(plan.id)
+        .bind(plan.user_id)
+        .bind(plan.exam_type_id)
+        .bind(plan.target_date)
+        .bind(plan.created_at)
+        .bind(plan.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn create_goal(&self, goal: &StudyGoal) -> Result<StudyGoal, DomainError> {
+        let row = sqlx::query_as::<_, StudyGoalRow>(
+            r#"
+            INSERT INTO study_goals (id, user_id, metric, daily_target, lesson_id, created_at, archived_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, user_id, metric, daily_target, lesson_id, created_at, archived_at
+            "#,
+        )
+        .bind(goal.id)
+        .bind(goal.user_id)
+        .bind(goal.metric.as_str())
+        .bind(goal.daily_target)
+        .bind(goal.lesson_id)
+        .bind(goal.created_at)
+        .bind(goal.archived_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.try_into()
+    }
+
+    async fn find_goal_by_id(&self, id: Uuid) -> Result<Option<StudyGoal>, DomainError> {
+        let row = sqlx::query_as::<_, StudyGoalRow>(
+            r#"
+            SELECT id, user_id, metric, daily_target, lesson_id, created_at, archived_at
+            FROM study_goals
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        row.map(|r| r.try_into()).transpose()
+    }
+
+    async fn find_goals_by_user(&self, user_id: Uuid) -> Result<Vec<StudyGoal>, DomainError> {
+        let rows = sqlx::query_as::<_, StudyGoalRow>(
+            r#"
+            SELECT id, user_id, metric, daily_target, lesson_id, created_at, archived_at
+            FROM study_goals
+            WHERE user_id = $1
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        rows.into_iter().map(|r| r.try_into()).collect()
+    }
+
+    async fn archive_goal(&self, id: Uuid, archived_at: DateTime<Utc>) -> Result<(), DomainError> {
+        sqlx::query(
+            r#"
+            UPDATE study_goals
+            SET archived_at = $2
+            WHERE id = $1 AND archived_at IS NULL
+            "#,
+        )
+        .bind(id)
+        .bind(archived_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn find_daily_activity(
+        &self,
+        user_id: Uuid,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<DailyActivity>, DomainError> {
+        let start = from.and_time(NaiveTime::MIN).and_utc();
+        let end = (to + Duration::days(1)).and_time(NaiveTime::MIN).and_utc();
+
+        let rows = sqlx::query_as::<_, DailyActivityRow>(
+            r#"
+            SELECT (tr.solved_at AT TIME ZONE 'UTC')::date AS day,
+                   s.lesson_id,
+                   COUNT(*) AS test_count,
+                   SUM(tr.correct_count + tr.wrong_count + tr.empty_count)::BIGINT AS question_count
+            FROM test_results tr
+            JOIN practice_tests pt ON pt.id = tr.practice_test_id
+            JOIN subjects s ON s.id = pt.subject_id
+            WHERE tr.user_id = $1 AND tr.solved_at >= $2 AND tr.solved_at < $3
+            GROUP BY day, s.lesson_id
+            ORDER BY day ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
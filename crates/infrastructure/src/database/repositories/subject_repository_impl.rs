@@ -79,6 +79,22 @@ impl SubjectRepository for PgSubjectRepository {
         Ok(row.map(|r| r.into()))
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<Subject>, DomainError> {
+        let rows = sqlx::query_as::<_, SubjectRow>(
+            r#"
+            SELECT id, name, lesson_id, exam_type_id, created_at
+            FROM subjects
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
     async fn find_by_exam_type_id(&self, exam_type_id: Uuid) -> Result<Vec<Subject>, DomainError> {
         let rows = sqlx::query_as::<_, SubjectRow>(
             r#"
@@ -163,4 +179,3 @@ impl SubjectRepository for PgSubjectRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 }
-
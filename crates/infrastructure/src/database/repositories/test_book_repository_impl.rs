@@ -82,6 +82,22 @@ impl TestBookRepository for PgTestBookRepository {
         Ok(row.map(|r| r.into()))
     }
 
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestBook>, DomainError> {
+        let rows = sqlx::query_as::<_, TestBookRow>(
+            r#"
+            SELECT id, name, lesson_id, exam_type_id, published_year, created_at
+            FROM test_books
+            WHERE id = ANY($1)
+            "#,
+        )
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
     async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<TestBook>, DomainError> {
         let rows = sqlx::query_as::<_, TestBookRow>(
             r#"
@@ -202,4 +218,3 @@ impl TestBookRepository for PgTestBookRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 }
-
@@ -1,5 +1,6 @@
 use async_trait::async_trait;
 use sqlx::PgPool;
+use std::collections::HashMap;
 use uuid::Uuid;
 
 use domain::errors::DomainError;
@@ -17,13 +18,16 @@ impl PgTestBookSubjectRepository {
     }
 }
 
+/// Internal row structure for test book-subject links.
+#[derive(sqlx::FromRow)]
+struct TestBookSubjectRow {
+    test_book_id: Uuid,
+    subject_id: Uuid,
+}
+
 #[async_trait]
 impl TestBookSubjectRepository for PgTestBookSubjectRepository {
-    async fn add_subject(
-        &self,
-        test_book_id: Uuid,
-        subject_id: Uuid,
-    ) -> Result<(), DomainError> {
+    async fn add_subject(&self, test_book_id: Uuid, subject_id: Uuid) -> Result<(), DomainError> {
         sqlx::query(
             r#"
             INSERT INTO test_book_subjects (test_book_id, subject_id)
@@ -122,6 +126,34 @@ impl TestBookSubjectRepository for PgTestBookSubjectRepository {
         Ok(rows)
     }
 
+    async fn find_subject_ids_by_test_book_ids(
+        &self,
+        test_book_ids: &[Uuid],
+    ) -> Result<HashMap<Uuid, Vec<Uuid>>, DomainError> {
+        let rows = sqlx::query_as::<_, TestBookSubjectRow>(
+            r#"
+            SELECT test_book_id, subject_id
+            FROM test_book_subjects
+            WHERE test_book_id = ANY($1)
+            ORDER BY created_at ASC
+            "#,
+        )
+        .bind(test_book_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let mut subject_ids: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+        for row in rows {
+            subject_ids
+                .entry(row.test_book_id)
+                .or_default()
+                .push(row.subject_id);
+        }
+
+        Ok(subject_ids)
+    }
+
     async fn find_test_book_ids_by_subject_id(
         &self,
         subject_id: Uuid,
@@ -152,4 +184,3 @@ impl TestBookSubjectRepository for PgTestBookSubjectRepository {
         Ok(())
     }
 }
-
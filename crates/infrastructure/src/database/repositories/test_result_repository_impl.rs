@@ -3,7 +3,10 @@ use chrono::{DateTime, Utc};
 use sqlx::PgPool;
 use uuid::Uuid;
 
-use domain::entities::TestResult;
+use domain::entities::{
+    LeaderboardEntry, LeaderboardPage, LeaderboardScope, NetDistributionBucket, TestBookProgress,
+    TestResult, TestResultHistory,
+};
 use domain::errors::DomainError;
 use domain::repositories::TestResultRepository;
 
@@ -30,7 +33,14 @@ struct TestResultRow {
     wrong_count: i32,
     empty_count: i32,
     net_score: f64,
+    scoring_policy_id: Uuid,
+    scoring_policy_version: i32,
+    answer_key_revision: i32,
+    score_changed_at: Option<DateTime<Utc>>,
     solved_at: DateTime<Utc>,
+    started_at: Option<DateTime<Utc>>,
+    elapsed_seconds: Option<i32>,
+    mock_exam_attempt_id: Option<Uuid>,
 }
 
 impl From<TestResultRow> for TestResult {
@@ -44,7 +54,175 @@ impl From<TestResultRow> for TestResult {
             wrong_count: row.wrong_count,
             empty_count: row.empty_count,
             net_score: row.net_score,
+            scoring_policy_id: row.scoring_policy_id,
+            scoring_policy_version: row.scoring_policy_version,
+            answer_key_revision: row.answer_key_revision,
+            score_changed_at: row.score_changed_at,
             solved_at: row.solved_at,
+            started_at: row.started_at,
+            elapsed_seconds: row.elapsed_seconds,
+            mock_exam_attempt_id: row.mock_exam_attempt_id,
+        }
+    }
+}
+
+/// Internal row structure for history queries.
+#[derive(sqlx::FromRow)]
+struct TestResultHistoryRow {
+    id: Uuid,
+    test_result_id: Uuid,
+    answer_key_revision: i32,
+    correct_count: i32,
+    wrong_count: i32,
+    empty_count: i32,
+    net_score: f64,
+    replaced_at: DateTime<Utc>,
+}
+
+impl From<TestResultHistoryRow> for TestResultHistory {
+    fn from(row: TestResultHistoryRow) -> Self {
+        TestResultHistory {
+            id: row.id,
+            test_result_id: row.test_result_id,
+            answer_key_revision: row.answer_key_revision,
+            correct_count: row.correct_count,
+            wrong_count: row.wrong_count,
+            empty_count: row.empty_count,
+            net_score: row.net_score,
+            replaced_at: row.replaced_at,
+        }
+    }
+}
+
+/// Inserts a test result; shared with the exam session repository, which stores
+/// the result together with the session submission.
+pub(super) async fn insert_test_result<'e, E>(
+    executor: E,
+    test_result: &TestResult,
+) -> Result<TestResult, DomainError>
+where
+    E: sqlx::PgExecutor<'e>,
+{
+    let row = sqlx::query_as::<_, TestResultRow>(
+        r#"
+        INSERT INTO test_results (id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16)
+        RETURNING id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+        "#,
+    )
+    .bind(test_result.id)
+    .bind(test_result.user_id)
+    .bind(test_result.practice_test_id)
+    .bind(&test_result.user_answers)
+    .bind(test_result.correct_count)
+    .bind(test_result.wrong_count)
+    .bind(test_result.empty_count)
+    .bind(test_result.net_score)
+    .bind(test_result.scoring_policy_id)
+    .bind(test_result.scoring_policy_version)
+    .bind(test_result.answer_key_revision)
+    .bind(test_result.score_changed_at)
+    .bind(test_result.solved_at)
+    .bind(test_result.started_at)
+    .bind(test_result.elapsed_seconds)
+    .bind(test_result.mock_exam_attempt_id)
+    .fetch_one(executor)
+    .await
+    .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+    Ok(row.into())
+}
+
+/// Internal row structure for net distribution queries.
+#[derive(sqlx::FromRow)]
+struct NetDistributionRow {
+    net_score: f64,
+    first_attempt_count: i64,
+}
+
+impl From<NetDistributionRow> for NetDistributionBucket {
+    fn from(row: NetDistributionRow) -> Self {
+        NetDistributionBucket {
+            net_score: row.net_score,
+            first_attempt_count: row.first_attempt_count,
+        }
+    }
+}
+
+/// Internal row structure for test book progress queries.
+#[derive(sqlx::FromRow)]
+struct TestBookProgressRow {
+    test_book_id: Uuid,
+    total_test_count: i64,
+    solved_test_count: i64,
+}
+
+impl From<TestBookProgressRow> for TestBookProgress {
+    fn from(row: TestBookProgressRow) -> Self {
+        TestBookProgress {
+            test_book_id: row.test_book_id,
+            total_test_count: row.total_test_count,
+            solved_test_count: row.solved_test_count,
+        }
+    }
+}
+
+/// Internal row structure for leaderboard queries.
+///
+/// Entry columns are null on the single row returned for an empty page.
+#[derive(sqlx::FromRow)]
+struct LeaderboardRow {
+    user_id: Option<Uuid>,
+    username: Option<String>,
+    score: Option<f64>,
+    test_count: Option<i64>,
+    rank: Option<i64>,
+    row_number: Option<i64>,
+    total_entries: i64,
+}
+
+/// Returns the query computing `(user_id, score, test_count)` per user of a scope.
+///
+/// Scores use each user's first attempt of a practice test; mock exam results are
+/// not attempts and are left out.
+/// `$1` is the scope's ID; the weekly scope also uses `$5` and `$6` as week bounds.
+fn leaderboard_scores_query(scope: &LeaderboardScope) -> &'static str {
+    match scope {
+        LeaderboardScope::PracticeTest(_) => {
+            r#"
+            SELECT DISTINCT ON (tr.user_id) tr.user_id, tr.net_score AS score, 1::BIGINT AS test_count
+            FROM test_results tr
+            WHERE tr.practice_test_id = $1 AND tr.mock_exam_attempt_id IS NULL
+            ORDER BY tr.user_id, tr.solved_at ASC
+            "#
+        }
+        LeaderboardScope::TestBook(_) => {
+            r#"
+            SELECT first.user_id, AVG(first.net_score) AS score, COUNT(*) AS test_count
+            FROM (
+                SELECT DISTINCT ON (tr.user_id, tr.practice_test_id) tr.user_id, tr.net_score
+                FROM test_results tr
+                JOIN practice_tests pt ON pt.id = tr.practice_test_id
+                WHERE pt.test_book_id = $1 AND tr.mock_exam_attempt_id IS NULL
+                ORDER BY tr.user_id, tr.practice_test_id, tr.solved_at ASC
+            ) first
+            GROUP BY first.user_id
+            "#
+        }
+        LeaderboardScope::ExamTypeWeek { .. } => {
+            r#"
+            SELECT first.user_id, SUM(first.net_score) AS score, COUNT(*) AS test_count
+            FROM (
+                SELECT DISTINCT ON (tr.user_id, tr.practice_test_id) tr.user_id, tr.net_score, tr.solved_at
+                FROM test_results tr
+                JOIN practice_tests pt ON pt.id = tr.practice_test_id
+                JOIN test_books tb ON tb.id = pt.test_book_id
+                WHERE tb.exam_type_id = $1 AND tr.mock_exam_attempt_id IS NULL
+                ORDER BY tr.user_id, tr.practice_test_id, tr.solved_at ASC
+            ) first
+            WHERE first.solved_at >= $5 AND first.solved_at < $6
+            GROUP BY first.user_id
+            "#
         }
     }
 }
@@ -52,49 +230,45 @@ impl From<TestResultRow> for TestResult {
 #[async_trait]
 impl TestResultRepository for PgTestResultRepository {
     async fn create(&self, test_result: &TestResult) -> Result<TestResult, DomainError> {
+        insert_test_result(&self.pool, test_result).await
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<TestResult>, DomainError> {
         let row = sqlx::query_as::<_, TestResultRow>(
             r#"
-            INSERT INTO test_results (id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
-            RETURNING id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            FROM test_results
+            WHERE id = $1
             "#,
         )
-        .bind(test_result.id)
-        .bind(test_result.user_id)
-        .bind(test_result.practice_test_id)
-        .bind(&test_result.user_answers)
-        .bind(test_result.correct_count)
-        .bind(test_result.wrong_count)
-        .bind(test_result.empty_count)
-        .bind(test_result.net_score)
-        .bind(test_result.solved_at)
-        .fetch_one(&self.pool)
+        .bind(id)
+        .fetch_optional(&self.pool)
         .await
         .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(row.into())
+        Ok(row.map(|r| r.into()))
     }
 
-    async fn find_by_id(&self, id: Uuid) -> Result<Option<TestResult>, DomainError> {
-        let row = sqlx::query_as::<_, TestResultRow>(
+    async fn find_by_ids(&self, ids: &[Uuid]) -> Result<Vec<TestResult>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultRow>(
             r#"
-            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
             FROM test_results
-            WHERE id = $1
+            WHERE id = ANY($1)
             "#,
         )
-        .bind(id)
-        .fetch_optional(&self.pool)
+        .bind(ids)
+        .fetch_all(&self.pool)
         .await
         .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
 
-        Ok(row.map(|r| r.into()))
+        Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 
     async fn find_by_user_id(&self, user_id: Uuid) -> Result<Vec<TestResult>, DomainError> {
         let rows = sqlx::query_as::<_, TestResultRow>(
             r#"
-            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
             FROM test_results
             WHERE user_id = $1
             ORDER BY solved_at DESC
@@ -108,10 +282,13 @@ impl TestResultRepository for PgTestResultRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 
-    async fn find_by_practice_test_id(&self, practice_test_id: Uuid) -> Result<Vec<TestResult>, DomainError> {
+    async fn find_by_practice_test_id(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<TestResult>, DomainError> {
         let rows = sqlx::query_as::<_, TestResultRow>(
             r#"
-            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
             FROM test_results
             WHERE practice_test_id = $1
             ORDER BY solved_at DESC
@@ -125,6 +302,25 @@ impl TestResultRepository for PgTestResultRepository {
         Ok(rows.into_iter().map(|r| r.into()).collect())
     }
 
+    async fn find_user_ids_with_result(
+        &self,
+        practice_test_id: Uuid,
+        user_ids: &[Uuid],
+    ) -> Result<Vec<Uuid>, DomainError> {
+        sqlx::query_scalar::<_, Uuid>(
+            r#"
+            SELECT DISTINCT user_id
+            FROM test_results
+            WHERE practice_test_id = $1 AND user_id = ANY($2)
+            "#,
+        )
+        .bind(practice_test_id)
+        .bind(user_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))
+    }
+
     async fn find_by_user_and_practice_test(
         &self,
         user_id: Uuid,
@@ -132,7 +328,7 @@ impl TestResultRepository for PgTestResultRepository {
     ) -> Result<Option<TestResult>, DomainError> {
         let row = sqlx::query_as::<_, TestResultRow>(
             r#"
-            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
             FROM test_results
             WHERE user_id = $1 AND practice_test_id = $2
             ORDER BY solved_at DESC
@@ -148,6 +344,28 @@ impl TestResultRepository for PgTestResultRepository {
         Ok(row.map(|r| r.into()))
     }
 
+    async fn find_attempts_by_user_and_practice_test(
+        &self,
+        user_id: Uuid,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<TestResult>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            FROM test_results
+            WHERE user_id = $1 AND practice_test_id = $2 AND mock_exam_attempt_id IS NULL
+            ORDER BY solved_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(practice_test_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
     async fn find_latest_by_user_and_practice_test(
         &self,
         user_id: Uuid,
@@ -155,7 +373,7 @@ impl TestResultRepository for PgTestResultRepository {
     ) -> Result<Option<TestResult>, DomainError> {
         let row = sqlx::query_as::<_, TestResultRow>(
             r#"
-            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
             FROM test_results
             WHERE user_id = $1 AND practice_test_id = $2
             ORDER BY solved_at DESC
@@ -171,6 +389,104 @@ impl TestResultRepository for PgTestResultRepository {
         Ok(row.map(|r| r.into()))
     }
 
+    async fn find_attempts_by_user_and_practice_tests(
+        &self,
+        user_id: Uuid,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<TestResult>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            FROM test_results
+            WHERE user_id = $1 AND practice_test_id = ANY($2) AND mock_exam_attempt_id IS NULL
+            ORDER BY practice_test_id, solved_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(practice_test_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_results_by_user_and_practice_tests(
+        &self,
+        user_id: Uuid,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<TestResult>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            FROM test_results
+            WHERE user_id = $1 AND practice_test_id = ANY($2)
+            ORDER BY practice_test_id, solved_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(practice_test_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_latest_by_user_and_practice_tests(
+        &self,
+        user_id: Uuid,
+        practice_test_ids: &[Uuid],
+    ) -> Result<Vec<TestResult>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultRow>(
+            r#"
+            SELECT DISTINCT ON (practice_test_id)
+                id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            FROM test_results
+            WHERE user_id = $1 AND practice_test_id = ANY($2) AND mock_exam_attempt_id IS NULL
+            ORDER BY practice_test_id, solved_at DESC
+            "#,
+        )
+        .bind(user_id)
+        .bind(practice_test_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_test_book_progress(
+        &self,
+        user_id: Uuid,
+        test_book_ids: &[Uuid],
+    ) -> Result<Vec<TestBookProgress>, DomainError> {
+        let rows = sqlx::query_as::<_, TestBookProgressRow>(
+            r#"
+            SELECT
+                b.test_book_id,
+                COUNT(pt.id) AS total_test_count,
+                COUNT(pt.id) FILTER (
+                    WHERE EXISTS (
+                        SELECT 1 FROM test_results tr
+                        WHERE tr.user_id = $1 AND tr.practice_test_id = pt.id
+                          AND tr.mock_exam_attempt_id IS NULL
+                    )
+                ) AS solved_test_count
+            FROM UNNEST($2::uuid[]) AS b(test_book_id)
+            LEFT JOIN practice_tests pt ON pt.test_book_id = b.test_book_id
+            GROUP BY b.test_book_id
+            "#,
+        )
+        .bind(user_id)
+        .bind(test_book_ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
     async fn list(
         &self,
         user_id: Option<Uuid>,
@@ -183,7 +499,7 @@ impl TestResultRepository for PgTestResultRepository {
         // Build dynamic WHERE clause
         let mut query = String::from(
             r#"
-            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, solved_at
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
             FROM test_results
             WHERE 1=1
             "#,
@@ -239,5 +555,219 @@ impl TestResultRepository for PgTestResultRepository {
 
         Ok((rows.into_iter().map(|r| r.into()).collect(), total as u64))
     }
-}
 
+    async fn find_outdated_by_practice_test(
+        &self,
+        practice_test_id: Uuid,
+        answer_key_revision: i32,
+    ) -> Result<Vec<TestResult>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultRow>(
+            r#"
+            SELECT id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            FROM test_results
+            WHERE practice_test_id = $1 AND answer_key_revision < $2
+            "#,
+        )
+        .bind(practice_test_id)
+        .bind(answer_key_revision)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn save_regrade(
+        &self,
+        test_result: &TestResult,
+        previous: Option<&TestResultHistory>,
+    ) -> Result<Option<TestResult>, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        // A result already graded with this or a newer revision is left alone
+        let row = sqlx::query_as::<_, TestResultRow>(
+            r#"
+            UPDATE test_results
+            SET correct_count = $2, wrong_count = $3, empty_count = $4, net_score = $5, answer_key_revision = $6, score_changed_at = $7
+            WHERE id = $1 AND answer_key_revision < $6
+            RETURNING id, user_id, practice_test_id, user_answers, correct_count, wrong_count, empty_count, net_score, scoring_policy_id, scoring_policy_version, answer_key_revision, score_changed_at, solved_at, started_at, elapsed_seconds, mock_exam_attempt_id
+            "#,
+        )
+        .bind(test_result.id)
+        .bind(test_result.correct_count)
+        .bind(test_result.wrong_count)
+        .bind(test_result.empty_count)
+        .bind(test_result.net_score)
+        .bind(test_result.answer_key_revision)
+        .bind(test_result.score_changed_at)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        if let Some(history) = previous {
+            sqlx::query(
+                r#"
+                INSERT INTO test_result_history (id, test_result_id, answer_key_revision, correct_count, wrong_count, empty_count, net_score, replaced_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                "#,
+            )
+            .bind(history.id)
+            .bind(history.test_result_id)
+            .bind(history.answer_key_revision)
+            .bind(history.correct_count)
+            .bind(history.wrong_count)
+            .bind(history.empty_count)
+            .bind(history.net_score)
+            .bind(history.replaced_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(Some(row.into()))
+    }
+
+    async fn find_history_by_test_result_id(
+        &self,
+        test_result_id: Uuid,
+    ) -> Result<Vec<TestResultHistory>, DomainError> {
+        let rows = sqlx::query_as::<_, TestResultHistoryRow>(
+            r#"
+            SELECT id, test_result_id, answer_key_revision, correct_count, wrong_count, empty_count, net_score, replaced_at
+            FROM test_result_history
+            WHERE test_result_id = $1
+            ORDER BY replaced_at DESC
+            "#,
+        )
+        .bind(test_result_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn find_leaderboard(
+        &self,
+        scope: LeaderboardScope,
+        viewer_id: Uuid,
+        page: u32,
+        per_page: u32,
+    ) -> Result<LeaderboardPage, DomainError> {
+        let offset = (page - 1) * per_page;
+        let scope_id = match scope {
+            LeaderboardScope::PracticeTest(id) | LeaderboardScope::TestBook(id) => id,
+            LeaderboardScope::ExamTypeWeek { exam_type_id, .. } => exam_type_id,
+        };
+
+        // Left-joining the page onto the count keeps the total when the page is empty
+        let query = format!(
+            r#"
+            WITH scores AS ({}),
+            ranked AS (
+                SELECT s.user_id, u.username, s.score, s.test_count,
+                       RANK() OVER (ORDER BY s.score DESC) AS rank,
+                       ROW_NUMBER() OVER (ORDER BY s.score DESC, u.username ASC) AS row_number
+                FROM scores s
+                JOIN users u ON u.id = s.user_id
+                WHERE u.deleted_at IS NULL AND (NOT u.leaderboard_opt_out OR u.id = $2)
+            )
+            SELECT r.user_id, r.username, r.score, r.test_count, r.rank, r.row_number, t.total_entries
+            FROM (SELECT COUNT(*) AS total_entries FROM ranked) t
+            LEFT JOIN ranked r
+                ON (r.row_number > $4 AND r.row_number <= $4 + $3) OR r.user_id = $2
+            ORDER BY r.row_number ASC
+            "#,
+            leaderboard_scores_query(&scope)
+        );
+
+        let mut query_builder = sqlx::query_as::<_, LeaderboardRow>(&query)
+            .bind(scope_id)
+            .bind(viewer_id)
+            .bind(per_page as i64)
+            .bind(offset as i64);
+        if let Some((week_start, week_end)) = scope.week() {
+            query_builder = query_builder.bind(week_start).bind(week_end);
+        }
+
+        let rows = query_builder
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        let total_entries = rows.first().map_or(0, |row| row.total_entries) as u64;
+        let mut entries = Vec::new();
+        let mut viewer_entry = None;
+        for row in rows {
+            let (
+                Some(user_id),
+                Some(username),
+                Some(score),
+                Some(test_count),
+                Some(rank),
+                Some(row_number),
+            ) = (
+                row.user_id,
+                row.username,
+                row.score,
+                row.test_count,
+                row.rank,
+                row.row_number,
+            )
+            else {
+                continue;
+            };
+            let entry = LeaderboardEntry {
+                rank,
+                user_id,
+                username,
+                score,
+                test_count,
+            };
+            if user_id == viewer_id {
+                viewer_entry = Some(entry.clone());
+            }
+            if row_number > offset as i64 && row_number <= (offset + per_page) as i64 {
+                entries.push(entry);
+            }
+        }
+
+        Ok(LeaderboardPage {
+            entries,
+            total_entries,
+            viewer_entry,
+        })
+    }
+
+    async fn find_net_distribution(
+        &self,
+        practice_test_id: Uuid,
+    ) -> Result<Vec<NetDistributionBucket>, DomainError> {
+        let rows = sqlx::query_as::<_, NetDistributionRow>(
+            r#"
+            SELECT net_score, first_attempt_count::BIGINT AS first_attempt_count
+            FROM practice_test_net_distribution
+            WHERE practice_test_id = $1
+            ORDER BY net_score ASC
+            "#,
+        )
+        .bind(practice_test_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+}
@@ -0,0 +1,164 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+use domain::entities::Topic;
+use domain::errors::DomainError;
+use domain::repositories::TopicRepository;
+
+/// PostgreSQL implementation of the TopicRepository trait.
+pub struct PgTopicRepository {
+    pool: PgPool,
+}
+
+impl PgTopicRepository {
+    /// Creates a new PostgreSQL topic repository.
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Internal row structure for database queries.
+#[derive(sqlx::FromRow)]
+struct TopicRow {
+    id: Uuid,
+    subject_id: Uuid,
+    parent_id: Option<Uuid>,
+    name: String,
+    position: i32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+impl From<TopicRow> for Topic {
+    fn from(row: TopicRow) -> Self {
+        Topic {
+            id: row.id,
+            subject_id: row.subject_id,
+            parent_id: row.parent_id,
+            name: row.name,
+            position: row.position,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl TopicRepository for PgTopicRepository {
+    async fn create(&self, topic: &Topic) -> Result<Topic, DomainError> {
+        let row = sqlx::query_as::<_, TopicRow>(
+            r#"
+            INSERT INTO topics (id, subject_id, parent_id, name, position, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING id, subject_id, parent_id, name, position, created_at, updated_at
+            "#,
+        )
+        .bind(topic.id)
+        .bind(topic.subject_id)
+        .bind(topic.parent_id)
+        .bind(&topic.name)
+        .bind(topic.position)
+        .bind(topic.created_at)
+        .bind(topic.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<Topic>, DomainError> {
+        let row = sqlx::query_as::<_, TopicRow>(
+            r#"
+            SELECT id, subject_id, parent_id, name, position, created_at, updated_at
+            FROM topics
+            WHERE id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.map(|r| r.into()))
+    }
+
+    async fn find_by_subject_id(&self, subject_id: Uuid) -> Result<Vec<Topic>, DomainError> {
+        let rows = sqlx::query_as::<_, TopicRow>(
+            r#"
+            SELECT id, subject_id, parent_id, name, position, created_at, updated_at
+            FROM topics
+            WHERE subject_id = $1
+            ORDER BY position ASC, created_at ASC
+            "#,
+        )
+        .bind(subject_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|r| r.into()).collect())
+    }
+
+    async fn update(&self, topic: &Topic) -> Result<Topic, DomainError> {
+        let row = sqlx::query_as::<_, TopicRow>(
+            r#"
+            UPDATE topics
+            SET name = $2, updated_at = $3
+            WHERE id = $1
+            RETURNING id, subject_id, parent_id, name, position, created_at, updated_at
+            "#,
+        )
+        .bind(topic.id)
+        .bind(&topic.name)
+        .bind(topic.updated_at)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(row.into())
+    }
+
+    async fn update_placements(&self, topics: &[Topic]) -> Result<(), DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        for topic in topics {
+            sqlx::query(
+                r#"
+                UPDATE topics
+                SET parent_id = $2, position = $3, updated_at = $4
+                WHERE id = $1
+                "#,
+            )
+            .bind(topic.id)
+            .bind(topic.parent_id)
+            .bind(topic.position)
+            .bind(topic.updated_at)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        sqlx::query("DELETE FROM topics WHERE id = $1")
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
@@ -31,6 +31,8 @@ struct UserRow {
     updated_at: DateTime<Utc>,
     deleted_at: Option<DateTime<Utc>>,
     deleted_by: Option<Uuid>,
+    student_number: Option<String>,
+    leaderboard_opt_out: bool,
 }
 
 impl From<UserRow> for User {
@@ -45,6 +47,8 @@ impl From<UserRow> for User {
             updated_at: row.updated_at,
             deleted_at: row.deleted_at,
             deleted_by: row.deleted_by,
+            student_number: row.student_number,
+            leaderboard_opt_out: row.leaderboard_opt_out,
         }
     }
 }
@@ -54,9 +58,9 @@ impl UserRepository for PgUserRepository {
     async fn create(&self, user: &User) -> Result<User, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            INSERT INTO users (id, username, email, password_hash, is_active, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
-            RETURNING id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+            INSERT INTO users (id, username, email, password_hash, is_active, created_at, updated_at, student_number, leaderboard_opt_out)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
             "#,
         )
         .bind(user.id)
@@ -66,6 +70,8 @@ impl UserRepository for PgUserRepository {
         .bind(user.is_active)
         .bind(user.created_at)
         .bind(user.updated_at)
+        .bind(&user.student_number)
+        .bind(user.leaderboard_opt_out)
         .fetch_one(&self.pool)
         .await
         .map_err(|e| match e {
@@ -75,6 +81,11 @@ impl UserRepository for PgUserRepository {
                     DomainError::DuplicateEmail(user.email.clone())
                 } else if constraint.contains("username") {
                     DomainError::DuplicateUsername(user.username.clone())
+                } else if constraint.contains("student_number") {
+                    DomainError::ValidationError(format!(
+                        "Student number already exists: {}",
+                        user.student_number.clone().unwrap_or_default()
+                    ))
                 } else {
                     DomainError::DatabaseError(e.to_string())
                 }
@@ -88,7 +99,7 @@ impl UserRepository for PgUserRepository {
     async fn find_by_id(&self, id: Uuid) -> Result<Option<User>, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
             FROM users
             WHERE id = $1
             "#,
@@ -104,7 +115,7 @@ impl UserRepository for PgUserRepository {
     async fn find_by_email(&self, email: &str) -> Result<Option<User>, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
             FROM users
             WHERE LOWER(email) = LOWER($1) AND deleted_at IS NULL
             "#,
@@ -120,7 +131,7 @@ impl UserRepository for PgUserRepository {
     async fn find_by_username(&self, username: &str) -> Result<Option<User>, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
-            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
             FROM users
             WHERE LOWER(username) = LOWER($1) AND deleted_at IS NULL
             "#,
@@ -133,13 +144,32 @@ impl UserRepository for PgUserRepository {
         Ok(row.map(Into::into))
     }
 
+    async fn find_by_student_numbers(
+        &self,
+        student_numbers: &[String],
+    ) -> Result<Vec<User>, DomainError> {
+        let rows = sqlx::query_as::<_, UserRow>(
+            r#"
+            SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
+            FROM users
+            WHERE student_number = ANY($1) AND deleted_at IS NULL
+            "#,
+        )
+        .bind(student_numbers)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
     async fn update(&self, user: &User) -> Result<User, DomainError> {
         let row = sqlx::query_as::<_, UserRow>(
             r#"
             UPDATE users
-            SET username = $2, email = $3, password_hash = $4, is_active = $5, updated_at = $6
+            SET username = $2, email = $3, password_hash = $4, is_active = $5, updated_at = $6, student_number = $7, leaderboard_opt_out = $8
             WHERE id = $1
-            RETURNING id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+            RETURNING id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
             "#,
         )
         .bind(user.id)
@@ -148,6 +178,8 @@ impl UserRepository for PgUserRepository {
         .bind(&user.password_hash)
         .bind(user.is_active)
         .bind(Utc::now())
+        .bind(&user.student_number)
+        .bind(user.leaderboard_opt_out)
         .fetch_optional(&self.pool)
         .await
         .map_err(|e| match e {
@@ -157,6 +189,11 @@ impl UserRepository for PgUserRepository {
                     DomainError::DuplicateEmail(user.email.clone())
                 } else if constraint.contains("username") {
                     DomainError::DuplicateUsername(user.username.clone())
+                } else if constraint.contains("student_number") {
+                    DomainError::ValidationError(format!(
+                        "Student number already exists: {}",
+                        user.student_number.clone().unwrap_or_default()
+                    ))
                 } else {
                     DomainError::DatabaseError(e.to_string())
                 }
@@ -266,7 +303,7 @@ impl UserRepository for PgUserRepository {
         let (users, total): (Vec<UserRow>, i64) = if include_deleted {
             let users = sqlx::query_as::<_, UserRow>(
                 r#"
-                SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+                SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
                 FROM users
                 ORDER BY created_at DESC
                 LIMIT $1 OFFSET $2
@@ -287,7 +324,7 @@ impl UserRepository for PgUserRepository {
         } else {
             let users = sqlx::query_as::<_, UserRow>(
                 r#"
-                SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by
+                SELECT id, username, email, password_hash, is_active, created_at, updated_at, deleted_at, deleted_by, student_number, leaderboard_opt_out
                 FROM users
                 WHERE deleted_at IS NULL
                 ORDER BY created_at DESC
@@ -369,4 +406,3 @@ impl UserRepository for PgUserRepository {
         Ok(roles)
     }
 }
-
@@ -1,4 +1,5 @@
+pub mod cache;
 pub mod config;
 pub mod database;
+pub mod rate_limit;
 pub mod security;
-
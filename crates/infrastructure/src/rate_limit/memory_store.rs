@@ -0,0 +1,146 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+
+use super::{RateLimitDecision, RateLimitError, RateLimitStore};
+
+/// Number of hits between sweeps of keys whose windows have emptied.
+const SWEEP_INTERVAL: u64 = 1024;
+
+/// Requests counted for a single key.
+struct Window {
+    /// Length of the window the hits were counted against
+    length: Duration,
+    /// Arrival times of the counted requests, oldest first
+    hits: VecDeque<Instant>,
+}
+
+impl Window {
+    /// Drops hits that have left the window.
+    fn prune(&mut self, now: Instant) {
+        while let Some(oldest) = self.hits.front() {
+            if now.duration_since(*oldest) >= self.length {
+                self.hits.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct State {
+    windows: HashMap<String, Window>,
+    hits_since_sweep: u64,
+}
+
+/// In-process rate limit store for tests and single-node deployments.
+///
+/// Keeps a sliding log of request times per key in memory.
+#[derive(Default)]
+pub struct InMemoryRateLimitStore {
+    state: Mutex<State>,
+}
+
+impl InMemoryRateLimitStore {
+    /// Creates an empty store.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for InMemoryRateLimitStore {
+    async fn hit(
+        &self,
+        key: &str,
+        limit: u32,
+        window: Duration,
+    ) -> Result<RateLimitDecision, RateLimitError> {
+        let now = Instant::now();
+        let mut state = self
+            .state
+            .lock()
+            .map_err(|e| RateLimitError::StoreError(e.to_string()))?;
+
+        // Forget keys that have gone quiet so the map does not grow without bound
+        state.hits_since_sweep += 1;
+        if state.hits_since_sweep >= SWEEP_INTERVAL {
+            state.hits_since_sweep = 0;
+            state.windows.retain(|_, w| {
+                w.prune(now);
+                !w.hits.is_empty()
+            });
+        }
+
+        let entry = state
+            .windows
+            .entry(key.to_string())
+            .or_insert_with(|| Window {
+                length: window,
+                hits: VecDeque::new(),
+            });
+        entry.length = window;
+        entry.prune(now);
+
+        let allowed = (entry.hits.len() as u32) < limit;
+        if allowed {
+            entry.hits.push_back(now);
+        }
+
+        let reset_after = entry
+            .hits
+            .front()
+            .map(|oldest| window.saturating_sub(now.duration_since(*oldest)))
+            .unwrap_or(window);
+
+        Ok(RateLimitDecision {
+            allowed,
+            limit,
+            remaining: limit.saturating_sub(entry.hits.len() as u32),
+            reset_after,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rejects_requests_over_limit() {
+        let store = InMemoryRateLimitStore::new();
+        let window = Duration::from_secs(60);
+
+        for expected_remaining in [2, 1, 0] {
+            let decision = store.hit("ip:1", 3, window).await.unwrap();
+            assert!(decision.allowed);
+            assert_eq!(decision.remaining, expected_remaining);
+        }
+
+        let decision = store.hit("ip:1", 3, window).await.unwrap();
+        assert!(!decision.allowed);
+        assert_eq!(decision.remaining, 0);
+        assert!(decision.reset_after <= window);
+
+        // Other keys have their own budget
+        assert!(store.hit("ip:2", 3, window).await.unwrap().allowed);
+    }
+
+    #[tokio::test]
+    async fn test_window_slides() {
+        let store = InMemoryRateLimitStore::new();
+        let window = Duration::from_millis(50);
+
+        assert!(store.hit("user:1", 1, window).await.unwrap().allowed);
+        assert!(!store.hit("user:1", 1, window).await.unwrap().allowed);
+
+        tokio::time::sleep(Duration::from_millis(60)).await;
+
+        let decision = store.hit("user:1", 1, window).await.unwrap();
+        assert!(decision.allowed);
+        assert_eq!(decision.remaining, 0);
+    }
+}
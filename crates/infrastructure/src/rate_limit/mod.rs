@@ -0,0 +1,71 @@
+mod memory_store;
+mod redis_store;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use thiserror::Error;
+use tracing::info;
+
+use crate::cache::RedisConnection;
+use crate::config::Settings;
+
+pub use memory_store::InMemoryRateLimitStore;
+pub use redis_store::RedisRateLimitStore;
+
+/// Rate limiting errors.
+#[derive(Debug, Error)]
+pub enum RateLimitError {
+    #[error("Rate limit store error: {0}")]
+    StoreError(String),
+}
+
+/// Outcome of counting a request against a rate limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitDecision {
+    /// Whether the request is within the limit
+    pub allowed: bool,
+    /// Maximum number of requests per window
+    pub limit: u32,
+    /// Requests left in the current window
+    pub remaining: u32,
+    /// Time until the oldest counted request leaves the window
+    pub reset_after: Duration,
+}
+
+/// Sliding-window request counter shared by the rate limiting middleware.
+#[async_trait]
+pub trait RateLimitStore: Send + Sync {
+    /// Counts a request for `key` and reports whether it fits in `limit` requests per `window`.
+    ///
+    /// Rejected requests are not counted, so a client that keeps retrying is let
+    /// through again as soon as its earliest request leaves the window.
+    async fn hit(
+        &self,
+        key: &str,
+        limit: u32,
+        window: Duration,
+    ) -> Result<RateLimitDecision, RateLimitError>;
+}
+
+/// Creates the rate limit store selected by `RATE_LIMIT_STORE`.
+///
+/// Uses Redis so that limits are shared between instances. Falls back to the
+/// in-process store when `RATE_LIMIT_STORE=memory` or when Redis is not
+/// connected, in which case every instance enforces its own limits.
+pub fn create_rate_limit_store(
+    settings: &Settings,
+    redis: Option<RedisConnection>,
+) -> Arc<dyn RateLimitStore> {
+    match redis {
+        Some(connection) if settings.rate_limit.store == "redis" => {
+            info!("Using Redis rate limit store");
+            Arc::new(RedisRateLimitStore::new(connection))
+        }
+        _ => {
+            info!("Using in-process rate limit store");
+            Arc::new(InMemoryRateLimitStore::new())
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use redis::Script;
+use uuid::Uuid;
+
+use super::{RateLimitDecision, RateLimitError, RateLimitStore};
+use crate::cache::RedisConnection;
+
+/// Prefix for rate limit keys in Redis.
+const KEY_PREFIX: &str = "rate_limit:";
+
+/// Sliding-window log kept in a sorted set scored by arrival time in milliseconds.
+///
+/// Uses the Redis clock so that every instance sees the same window.
+/// Returns `{allowed, count, reset_ms}`.
+const HIT_SCRIPT: &str = r#"
+local key = KEYS[1]
+local limit = tonumber(ARGV[1])
+local window = tonumber(ARGV[2])
+local member = ARGV[3]
+
+local time = redis.call('TIME')
+local now = tonumber(time[1]) * 1000 + math.floor(tonumber(time[2]) / 1000)
+
+redis.call('ZREMRANGEBYSCORE', key, '-inf', now - window)
+local count = redis.call('ZCARD', key)
+local allowed = 0
+if count < limit then
+    redis.call('ZADD', key, now, member)
+    count = count + 1
+    allowed = 1
+end
+redis.call('PEXPIRE', key, window)
+
+local reset = window
+local oldest = redis.call('ZRANGE', key, 0, 0, 'WITHSCORES')
+if oldest[2] then
+    reset = tonumber(oldest[2]) + window - now
+end
+
+return {allowed, count, reset}
+"#;
+
+/// Redis-backed rate limit store shared by every API instance.
+#[derive(Clone)]
+pub struct RedisRateLimitStore {
+    connection: RedisConnection,
+    script: Script,
+}
+
+impl RedisRateLimitStore {
+    /// Creates a store using the shared Redis connection.
+    pub fn new(connection: RedisConnection) -> Self {
+        Self {
+            connection,
+            script: Script::new(HIT_SCRIPT),
+        }
+    }
+}
+
+#[async_trait]
+impl RateLimitStore for RedisRateLimitStore {
+    async fn hit(
+        &self,
+        key: &str,
+        limit: u32,
+        window: Duration,
+    ) -> Result<RateLimitDecision, RateLimitError> {
+        let mut connection = self.connection.clone();
+        let (allowed, count, reset_ms): (i64, i64, i64) = self
+            .script
+            .key(format!("{}{}", KEY_PREFIX, key))
+            .arg(limit)
+            .arg(window.as_millis() as u64)
+            .arg(Uuid::new_v4().to_string())
+            .invoke_async(&mut connection)
+            .await
+            .map_err(|e| RateLimitError::StoreError(e.to_string()))?;
+
+        Ok(RateLimitDecision {
+            allowed: allowed == 1,
+            limit,
+            remaining: limit.saturating_sub(count.max(0) as u32),
+            reset_after: Duration::from_millis(reset_ms.max(0) as u64),
+        })
+    }
+}
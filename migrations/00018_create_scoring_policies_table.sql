@@ -0,0 +1,41 @@
+-- Create scoring_policies table
+-- Each exam type has a versioned history of scoring rules; the highest version is the active one
+CREATE TABLE scoring_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    exam_type_id UUID NOT NULL REFERENCES exam_types(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL,
+    penalty_ratio DOUBLE PRECISION NOT NULL,
+    question_weight DOUBLE PRECISION NOT NULL DEFAULT 1.0,
+    rounding_mode VARCHAR(20) NOT NULL DEFAULT 'none',
+    decimal_places INTEGER NOT NULL DEFAULT 2,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT scoring_policies_exam_type_version_unique UNIQUE(exam_type_id, version),
+    CONSTRAINT scoring_policies_penalty_ratio_check CHECK (penalty_ratio >= 0 AND penalty_ratio <= 1),
+    CONSTRAINT scoring_policies_question_weight_check CHECK (question_weight > 0),
+    CONSTRAINT scoring_policies_rounding_mode_check CHECK (rounding_mode IN ('none', 'nearest', 'floor'))
+);
+
+-- Create index for looking up the current policy of an exam type
+CREATE INDEX idx_scoring_policies_exam_type_version ON scoring_policies(exam_type_id, version DESC);
+
+-- Seed version 1 with the legacy correct - wrong / 4 rule for every existing exam type
+INSERT INTO scoring_policies (exam_type_id, version, penalty_ratio, question_weight, rounding_mode, decimal_places)
+SELECT id, 1, 0.25, 1.0, 'none', 2
+FROM exam_types;
+
+-- Record which policy version produced each test result
+ALTER TABLE test_results ADD COLUMN scoring_policy_id UUID REFERENCES scoring_policies(id);
+ALTER TABLE test_results ADD COLUMN scoring_policy_version INTEGER;
+
+-- Existing results were all scored with the legacy rule, i.e. version 1 of their exam type
+UPDATE test_results tr
+SET scoring_policy_id = sp.id, scoring_policy_version = sp.version
+FROM practice_tests pt
+INNER JOIN test_books tb ON tb.id = pt.test_book_id
+INNER JOIN scoring_policies sp ON sp.exam_type_id = tb.exam_type_id AND sp.version = 1
+WHERE pt.id = tr.practice_test_id;
+
+ALTER TABLE test_results ALTER COLUMN scoring_policy_id SET NOT NULL;
+ALTER TABLE test_results ALTER COLUMN scoring_policy_version SET NOT NULL;
+
+CREATE INDEX idx_test_results_scoring_policy ON test_results(scoring_policy_id);
@@ -0,0 +1,25 @@
+-- Create score_tables table
+-- Yearly ÖSYM coefficients used to estimate standard scores (puan) from lesson nets
+CREATE TABLE score_tables (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    exam_type_id UUID NOT NULL REFERENCES exam_types(id) ON DELETE CASCADE,
+    year INTEGER NOT NULL,
+    base_score DOUBLE PRECISION NOT NULL,
+    diploma_coefficient DOUBLE PRECISION NOT NULL DEFAULT 0.12,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT score_tables_exam_type_year_unique UNIQUE(exam_type_id, year)
+);
+
+CREATE INDEX idx_score_tables_exam_type_year ON score_tables(exam_type_id, year DESC);
+
+-- Create score_table_coefficients table
+CREATE TABLE score_table_coefficients (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    score_table_id UUID NOT NULL REFERENCES score_tables(id) ON DELETE CASCADE,
+    lesson_id UUID NOT NULL REFERENCES lessons(id) ON DELETE CASCADE,
+    coefficient DOUBLE PRECISION NOT NULL,
+    UNIQUE(score_table_id, lesson_id)
+);
+
+CREATE INDEX idx_score_table_coefficients_table ON score_table_coefficients(score_table_id);
@@ -0,0 +1,43 @@
+-- Track the current answer key revision of each practice test
+ALTER TABLE practice_tests ADD COLUMN answer_key_revision INTEGER NOT NULL DEFAULT 1;
+
+-- Create answer_key_revisions table
+-- Keeps every version of an answer key instead of overwriting it
+CREATE TABLE answer_key_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    practice_test_id UUID NOT NULL REFERENCES practice_tests(id) ON DELETE CASCADE,
+    revision INTEGER NOT NULL,
+    answer_key TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    regraded_at TIMESTAMPTZ,
+    CONSTRAINT answer_key_revisions_test_revision_unique UNIQUE(practice_test_id, revision)
+);
+
+-- Index for finding revisions whose re-grade is still pending
+CREATE INDEX idx_answer_key_revisions_pending ON answer_key_revisions(created_at) WHERE regraded_at IS NULL;
+
+-- Existing answer keys become revision 1; there is nothing to re-grade for them
+INSERT INTO answer_key_revisions (practice_test_id, revision, answer_key, created_at, regraded_at)
+SELECT id, 1, answer_key, created_at, NOW()
+FROM practice_tests;
+
+-- Record which revision each result was graded with, and when a re-grade changed it
+ALTER TABLE test_results ADD COLUMN answer_key_revision INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE test_results ADD COLUMN score_changed_at TIMESTAMPTZ;
+
+CREATE INDEX idx_test_results_practice_test_revision ON test_results(practice_test_id, answer_key_revision);
+
+-- Create test_result_history table
+-- Previous scores of re-graded results, kept for audit
+CREATE TABLE test_result_history (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    test_result_id UUID NOT NULL REFERENCES test_results(id) ON DELETE CASCADE,
+    answer_key_revision INTEGER NOT NULL,
+    correct_count INTEGER NOT NULL,
+    wrong_count INTEGER NOT NULL,
+    empty_count INTEGER NOT NULL,
+    net_score DOUBLE PRECISION NOT NULL,
+    replaced_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_test_result_history_result ON test_result_history(test_result_id, replaced_at DESC);
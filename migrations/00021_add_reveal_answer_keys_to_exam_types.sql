@@ -0,0 +1,6 @@
+-- Add answer key visibility policy to exam types
+-- By default students only see a practice test's answer key after submitting it;
+-- exam types with this flag set show the key to everyone up front
+
+ALTER TABLE exam_types
+ADD COLUMN reveal_answer_keys BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,20 @@
+-- Create retake_policies table
+-- A policy applies to either an exam type or a test book; test book policies take precedence.
+-- Tests without any policy keep the original 24-hour cooldown.
+CREATE TABLE retake_policies (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    exam_type_id UUID REFERENCES exam_types(id) ON DELETE CASCADE,
+    test_book_id UUID REFERENCES test_books(id) ON DELETE CASCADE,
+    cooldown_minutes INTEGER NOT NULL DEFAULT 1440,
+    max_attempts INTEGER,
+    attempt_scoring VARCHAR(20) NOT NULL DEFAULT 'first',
+    unlimited_practice BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT retake_policies_exam_type_unique UNIQUE(exam_type_id),
+    CONSTRAINT retake_policies_test_book_unique UNIQUE(test_book_id),
+    CONSTRAINT retake_policies_scope_check CHECK ((exam_type_id IS NULL) <> (test_book_id IS NULL)),
+    CONSTRAINT retake_policies_cooldown_check CHECK (cooldown_minutes >= 0),
+    CONSTRAINT retake_policies_max_attempts_check CHECK (max_attempts IS NULL OR max_attempts > 0),
+    CONSTRAINT retake_policies_attempt_scoring_check CHECK (attempt_scoring IN ('first', 'best'))
+);
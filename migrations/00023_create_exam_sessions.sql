@@ -0,0 +1,32 @@
+-- Timed exam sessions
+-- Practice tests may carry a time limit; results record when the session started and how long it took.
+ALTER TABLE practice_tests
+    ADD COLUMN time_limit_minutes INTEGER CHECK (time_limit_minutes IS NULL OR time_limit_minutes > 0);
+
+ALTER TABLE test_results
+    ADD COLUMN started_at TIMESTAMPTZ,
+    ADD COLUMN elapsed_seconds INTEGER;
+
+CREATE TABLE exam_sessions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    practice_test_id UUID NOT NULL REFERENCES practice_tests(id) ON DELETE CASCADE,
+    answers TEXT NOT NULL,
+    status VARCHAR(20) NOT NULL DEFAULT 'in_progress',
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ,
+    last_saved_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    submitted_at TIMESTAMPTZ,
+    test_result_id UUID REFERENCES test_results(id) ON DELETE SET NULL,
+    CONSTRAINT exam_sessions_status_check CHECK (status IN ('in_progress', 'submitted', 'expired'))
+);
+
+-- At most one open session per user and practice test
+CREATE UNIQUE INDEX idx_exam_sessions_in_progress
+    ON exam_sessions(user_id, practice_test_id)
+    WHERE status = 'in_progress';
+
+-- Lookup of sessions due for auto-submission
+CREATE INDEX idx_exam_sessions_expires_at
+    ON exam_sessions(expires_at)
+    WHERE status = 'in_progress';
@@ -0,0 +1,65 @@
+-- Mock exams (deneme sınavları)
+-- A mock exam groups practice tests of one exam type into sections solved under a single clock.
+CREATE TABLE mock_exams (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(200) NOT NULL,
+    exam_type_id UUID NOT NULL REFERENCES exam_types(id) ON DELETE CASCADE,
+    time_limit_minutes INTEGER NOT NULL CHECK (time_limit_minutes > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_mock_exams_exam_type ON mock_exams(exam_type_id);
+
+CREATE TABLE mock_exam_sections (
+    mock_exam_id UUID NOT NULL REFERENCES mock_exams(id) ON DELETE CASCADE,
+    practice_test_id UUID NOT NULL REFERENCES practice_tests(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (mock_exam_id, practice_test_id),
+    UNIQUE (mock_exam_id, position)
+);
+
+CREATE TABLE mock_exam_attempts (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    mock_exam_id UUID NOT NULL REFERENCES mock_exams(id) ON DELETE CASCADE,
+    status VARCHAR(20) NOT NULL DEFAULT 'in_progress',
+    started_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL,
+    last_saved_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    submitted_at TIMESTAMPTZ,
+    CONSTRAINT mock_exam_attempts_status_check CHECK (status IN ('in_progress', 'submitted', 'expired'))
+);
+
+CREATE INDEX idx_mock_exam_attempts_user ON mock_exam_attempts(user_id, started_at DESC);
+
+-- At most one open attempt per user and mock exam
+CREATE UNIQUE INDEX idx_mock_exam_attempts_in_progress
+    ON mock_exam_attempts(user_id, mock_exam_id)
+    WHERE status = 'in_progress';
+
+-- Lookup of attempts due for auto-submission
+CREATE INDEX idx_mock_exam_attempts_expires_at
+    ON mock_exam_attempts(expires_at)
+    WHERE status = 'in_progress';
+
+-- Answers of each section; every section is graded into its own test result
+CREATE TABLE mock_exam_attempt_sections (
+    attempt_id UUID NOT NULL REFERENCES mock_exam_attempts(id) ON DELETE CASCADE,
+    practice_test_id UUID NOT NULL REFERENCES practice_tests(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    answers TEXT NOT NULL,
+    -- Answer key revision of the practice test when the attempt started; the section is graded against it
+    answer_key_revision INTEGER NOT NULL,
+    test_result_id UUID REFERENCES test_results(id) ON DELETE SET NULL,
+    PRIMARY KEY (attempt_id, practice_test_id)
+);
+
+-- Results graded in a mock exam attempt reference it. They are not attempts at their
+-- practice tests and are left out of retake policies and cohort statistics.
+ALTER TABLE test_results
+    ADD COLUMN mock_exam_attempt_id UUID REFERENCES mock_exam_attempts(id) ON DELETE CASCADE;
+
+CREATE INDEX idx_test_results_mock_exam_attempt
+    ON test_results(mock_exam_attempt_id)
+    WHERE mock_exam_attempt_id IS NOT NULL;
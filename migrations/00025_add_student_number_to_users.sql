@@ -0,0 +1,7 @@
+-- Student numbers printed on optical answer forms (OMR)
+-- Used to match scanned rows to users when importing results solved on paper.
+ALTER TABLE users ADD COLUMN student_number VARCHAR(50);
+
+CREATE UNIQUE INDEX idx_users_student_number_unique
+    ON users(student_number)
+    WHERE student_number IS NOT NULL AND deleted_at IS NULL;
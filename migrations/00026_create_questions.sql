@@ -0,0 +1,19 @@
+-- Questions of practice tests
+-- A practice test with questions derives its answer key from them; tests without
+-- questions keep grading from their plain answer key.
+CREATE TABLE questions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    practice_test_id UUID NOT NULL REFERENCES practice_tests(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL CHECK (position > 0),
+    -- Answer key entry of the question, stored as JSON
+    answer TEXT NOT NULL,
+    -- Curriculum topic (kazanım) the question measures
+    topic_id UUID,
+    difficulty VARCHAR(20),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT questions_practice_test_position_unique UNIQUE (practice_test_id, position),
+    CONSTRAINT questions_difficulty_check CHECK (difficulty IS NULL OR difficulty IN ('easy', 'medium', 'hard'))
+);
+
+CREATE INDEX idx_questions_topic ON questions(topic_id) WHERE topic_id IS NOT NULL;
@@ -0,0 +1,27 @@
+-- Curriculum topic tree
+-- Topics hang under a subject as an adjacency list: units, topics and learning
+-- outcomes (kazanımlar). Siblings are ordered by position.
+CREATE TABLE topics (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subject_id UUID NOT NULL REFERENCES subjects(id) ON DELETE CASCADE,
+    parent_id UUID REFERENCES topics(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    position INTEGER NOT NULL CHECK (position > 0),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_topics_subject ON topics(subject_id);
+CREATE INDEX idx_topics_parent ON topics(parent_id);
+
+-- Questions and practice tests reference the topic they measure
+ALTER TABLE questions
+    ADD CONSTRAINT questions_topic_id_fkey
+    FOREIGN KEY (topic_id) REFERENCES topics(id) ON DELETE SET NULL;
+
+ALTER TABLE practice_tests
+    ADD COLUMN topic_id UUID,
+    ADD CONSTRAINT practice_tests_topic_id_fkey
+    FOREIGN KEY (topic_id) REFERENCES topics(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_practice_tests_topic ON practice_tests(topic_id) WHERE topic_id IS NOT NULL;
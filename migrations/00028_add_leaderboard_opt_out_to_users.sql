@@ -0,0 +1,2 @@
+-- Users who opt out are left out of public leaderboards; they still see their own rank.
+ALTER TABLE users ADD COLUMN leaderboard_opt_out BOOLEAN NOT NULL DEFAULT FALSE;
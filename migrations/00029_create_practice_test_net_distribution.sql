@@ -0,0 +1,105 @@
+-- Cohort statistics of practice tests (percentile, average and median net of first attempts).
+-- The first attempt of each user and the number of first attempts per net score are kept
+-- up to date by a trigger on test_results, so reading the statistics never scans all results.
+-- Neither table references practice_tests: rows empty themselves as results are deleted.
+-- Mock exam results are not attempts at their practice tests and are left out.
+CREATE TABLE practice_test_first_attempts (
+    practice_test_id UUID NOT NULL,
+    user_id UUID NOT NULL,
+    test_result_id UUID NOT NULL,
+    net_score DOUBLE PRECISION NOT NULL,
+    PRIMARY KEY (practice_test_id, user_id)
+);
+
+CREATE TABLE practice_test_net_distribution (
+    practice_test_id UUID NOT NULL,
+    net_score DOUBLE PRECISION NOT NULL,
+    first_attempt_count INTEGER NOT NULL CHECK (first_attempt_count > 0),
+    PRIMARY KEY (practice_test_id, net_score)
+);
+
+-- Re-derives the first attempt of one user on one practice test and moves its
+-- net score between distribution buckets when it changed.
+CREATE FUNCTION refresh_practice_test_first_attempt(p_practice_test_id UUID, p_user_id UUID)
+RETURNS VOID AS $$
+DECLARE
+    previous practice_test_first_attempts%ROWTYPE;
+    current_id UUID;
+    current_net DOUBLE PRECISION;
+BEGIN
+    -- Serialize concurrent writes for the same user and practice test
+    PERFORM pg_advisory_xact_lock(hashtextextended(p_practice_test_id::text || p_user_id::text, 0));
+
+    SELECT * INTO previous
+    FROM practice_test_first_attempts
+    WHERE practice_test_id = p_practice_test_id AND user_id = p_user_id;
+
+    SELECT id, net_score INTO current_id, current_net
+    FROM test_results
+    WHERE practice_test_id = p_practice_test_id AND user_id = p_user_id
+      AND mock_exam_attempt_id IS NULL
+    ORDER BY solved_at ASC, id ASC
+    LIMIT 1;
+
+    IF previous.test_result_id IS NOT DISTINCT FROM current_id
+        AND previous.net_score IS NOT DISTINCT FROM current_net THEN
+        RETURN;
+    END IF;
+
+    IF previous.test_result_id IS NOT NULL THEN
+        DELETE FROM practice_test_net_distribution
+        WHERE practice_test_id = p_practice_test_id
+          AND net_score = previous.net_score
+          AND first_attempt_count = 1;
+        IF NOT FOUND THEN
+            UPDATE practice_test_net_distribution
+            SET first_attempt_count = first_attempt_count - 1
+            WHERE practice_test_id = p_practice_test_id AND net_score = previous.net_score;
+        END IF;
+    END IF;
+
+    IF current_id IS NULL THEN
+        DELETE FROM practice_test_first_attempts
+        WHERE practice_test_id = p_practice_test_id AND user_id = p_user_id;
+    ELSE
+        INSERT INTO practice_test_first_attempts (practice_test_id, user_id, test_result_id, net_score)
+        VALUES (p_practice_test_id, p_user_id, current_id, current_net)
+        ON CONFLICT (practice_test_id, user_id) DO UPDATE
+        SET test_result_id = EXCLUDED.test_result_id, net_score = EXCLUDED.net_score;
+
+        INSERT INTO practice_test_net_distribution (practice_test_id, net_score, first_attempt_count)
+        VALUES (p_practice_test_id, current_net, 1)
+        ON CONFLICT (practice_test_id, net_score) DO UPDATE
+        SET first_attempt_count = practice_test_net_distribution.first_attempt_count + 1;
+    END IF;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE FUNCTION test_results_refresh_first_attempt()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF TG_OP IN ('UPDATE', 'DELETE') THEN
+        PERFORM refresh_practice_test_first_attempt(OLD.practice_test_id, OLD.user_id);
+    END IF;
+    IF TG_OP IN ('INSERT', 'UPDATE') THEN
+        PERFORM refresh_practice_test_first_attempt(NEW.practice_test_id, NEW.user_id);
+    END IF;
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER test_results_first_attempt
+AFTER INSERT OR DELETE OR UPDATE OF net_score, solved_at, user_id, practice_test_id ON test_results
+FOR EACH ROW EXECUTE FUNCTION test_results_refresh_first_attempt();
+
+-- Backfill from existing results
+INSERT INTO practice_test_first_attempts (practice_test_id, user_id, test_result_id, net_score)
+SELECT DISTINCT ON (practice_test_id, user_id) practice_test_id, user_id, id, net_score
+FROM test_results
+WHERE mock_exam_attempt_id IS NULL
+ORDER BY practice_test_id, user_id, solved_at ASC, id ASC;
+
+INSERT INTO practice_test_net_distribution (practice_test_id, net_score, first_attempt_count)
+SELECT practice_test_id, net_score, COUNT(*)
+FROM practice_test_first_attempts
+GROUP BY practice_test_id, net_score;
@@ -0,0 +1,30 @@
+-- Study plans and daily goals
+-- A student has at most one study plan: the exam type they prepare for and the
+-- date of the exam.
+CREATE TABLE study_plans (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL UNIQUE REFERENCES users(id) ON DELETE CASCADE,
+    exam_type_id UUID NOT NULL REFERENCES exam_types(id) ON DELETE CASCADE,
+    target_date DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Daily goals, e.g. "3 tests a day" or "40 Matematik questions a day".
+-- A goal counts from the day it was created until the day it was archived, so
+-- archiving a goal keeps past days of the calendar intact. lesson_id limits the
+-- goal to one lesson.
+CREATE TABLE study_goals (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    metric VARCHAR(20) NOT NULL CHECK (metric IN ('tests', 'questions')),
+    daily_target INTEGER NOT NULL CHECK (daily_target > 0),
+    lesson_id UUID REFERENCES lessons(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    archived_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_study_goals_user ON study_goals(user_id);
+
+-- Daily activity is aggregated from a user's results by solve time
+CREATE INDEX idx_test_results_user_solved_at ON test_results(user_id, solved_at);
@@ -0,0 +1,38 @@
+-- Achievements (badges) and the users who earned them
+-- rule decides what threshold is compared with; exam_type_id and lesson_id
+-- limit net score rules to tests of one exam type and lesson.
+CREATE TABLE achievements (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    code VARCHAR(100) NOT NULL UNIQUE,
+    name VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL,
+    rule VARCHAR(30) NOT NULL
+        CHECK (rule IN ('solving_streak', 'tests_solved', 'test_books_completed', 'net_score')),
+    threshold DOUBLE PRECISION NOT NULL CHECK (threshold > 0),
+    exam_type_id UUID REFERENCES exam_types(id) ON DELETE CASCADE,
+    lesson_id UUID REFERENCES lessons(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (rule = 'net_score' OR (exam_type_id IS NULL AND lesson_id IS NULL))
+);
+
+CREATE TABLE user_achievements (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    achievement_id UUID NOT NULL REFERENCES achievements(id) ON DELETE CASCADE,
+    awarded_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, achievement_id)
+);
+
+INSERT INTO achievements (code, name, description, rule, threshold) VALUES
+    ('first_test', 'First Step', 'Solve your first practice test', 'tests_solved', 1),
+    ('tests_100', 'Century', 'Solve 100 different practice tests', 'tests_solved', 100),
+    ('streak_7', 'Week Streak', 'Solve at least one test on 7 consecutive days', 'solving_streak', 7),
+    ('streak_30', 'Month Streak', 'Solve at least one test on 30 consecutive days', 'solving_streak', 30),
+    ('first_test_book', 'Book Finisher', 'Solve every practice test of a test book', 'test_books_completed', 1);
+
+-- Only seeded where the TYT exam type and the Türkçe lesson exist
+INSERT INTO achievements (code, name, description, rule, threshold, exam_type_id, lesson_id)
+SELECT 'tyt_turkce_net_35', 'TYT Türkçe 35+', 'Score a net of 35 or more on a TYT Türkçe test',
+       'net_score', 35, e.id, l.id
+FROM exam_types e
+CROSS JOIN lessons l
+WHERE e.name = 'TYT' AND l.name = 'Türkçe';
@@ -0,0 +1,36 @@
+-- Teacher role, classrooms, enrollments and homework assignments
+INSERT INTO roles (name, description, is_system) VALUES
+    ('teacher', 'Teacher managing classrooms and homework', true)
+ON CONFLICT (name) DO NOTHING;
+
+CREATE TABLE classrooms (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    teacher_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    name VARCHAR(255) NOT NULL,
+    invite_code VARCHAR(20) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_classrooms_teacher ON classrooms(teacher_id);
+
+CREATE TABLE enrollments (
+    classroom_id UUID NOT NULL REFERENCES classrooms(id) ON DELETE CASCADE,
+    student_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    enrolled_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (classroom_id, student_id)
+);
+
+CREATE INDEX idx_enrollments_student ON enrollments(student_id);
+
+-- A student completes an assignment with their first result of the practice
+-- test solved after the assignment was created.
+CREATE TABLE assignments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    classroom_id UUID NOT NULL REFERENCES classrooms(id) ON DELETE CASCADE,
+    practice_test_id UUID NOT NULL REFERENCES practice_tests(id) ON DELETE CASCADE,
+    due_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_assignments_classroom ON assignments(classroom_id, due_at);
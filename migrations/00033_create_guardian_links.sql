@@ -0,0 +1,19 @@
+-- Guardian role and guardian-student links
+-- A guardian invites a student; the link grants read-only access once the
+-- student accepts it.
+INSERT INTO roles (name, description, is_system) VALUES
+    ('guardian', 'Parent or guardian with read-only access to linked students', true)
+ON CONFLICT (name) DO NOTHING;
+
+CREATE TABLE guardian_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    guardian_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    student_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'accepted')),
+    invited_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    accepted_at TIMESTAMPTZ,
+    UNIQUE (guardian_id, student_id),
+    CHECK (guardian_id <> student_id)
+);
+
+CREATE INDEX idx_guardian_links_student ON guardian_links(student_id);
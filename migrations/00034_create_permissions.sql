@@ -0,0 +1,36 @@
+-- Create permissions and role_permissions tables
+-- Permission names follow "resource:action"; the API checks them by name, and
+-- a user's permissions are flattened from their roles into the access token.
+CREATE TABLE permissions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(100) NOT NULL UNIQUE,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE role_permissions (
+    role_id UUID NOT NULL REFERENCES roles(id) ON DELETE CASCADE,
+    permission_id UUID NOT NULL REFERENCES permissions(id) ON DELETE CASCADE,
+    granted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (role_id, permission_id)
+);
+
+CREATE INDEX idx_role_permissions_permission ON role_permissions(permission_id);
+
+INSERT INTO permissions (name, description) VALUES
+    ('tests:read', 'View lessons, exam types, subjects, topics, test books, practice tests and their policies'),
+    ('tests:write', 'Create, update and delete test content, policies, score tables and mock exams'),
+    ('results:import', 'Import optical form results'),
+    ('achievements:read', 'View achievement definitions'),
+    ('achievements:write', 'Create and delete achievements'),
+    ('users:read', 'View user accounts'),
+    ('users:write', 'Update, delete and restore user accounts'),
+    ('roles:read', 'View roles and permissions'),
+    ('roles:write', 'Manage roles, role assignments and role permissions');
+
+-- Admins keep every permission they had through the admin role
+INSERT INTO role_permissions (role_id, permission_id)
+SELECT r.id, p.id
+FROM roles r
+CROSS JOIN permissions p
+WHERE r.name = 'admin';
@@ -0,0 +1,16 @@
+-- Normalize answer keys saved before answer keys were validated
+-- Plain keys that only differ from the current format by case or surrounding
+-- whitespace are rewritten as uppercase letters. Keys with any other character
+-- are left as they are and reported as invalid until an administrator fixes them.
+
+UPDATE practice_tests
+SET answer_key = UPPER(BTRIM(answer_key, E' \t\r\n'))
+WHERE answer_key NOT LIKE '[%'
+  AND UPPER(BTRIM(answer_key, E' \t\r\n')) ~ '^[A-Z]+$'
+  AND answer_key <> UPPER(BTRIM(answer_key, E' \t\r\n'));
+
+UPDATE answer_key_revisions
+SET answer_key = UPPER(BTRIM(answer_key, E' \t\r\n'))
+WHERE answer_key NOT LIKE '[%'
+  AND UPPER(BTRIM(answer_key, E' \t\r\n')) ~ '^[A-Z]+$'
+  AND answer_key <> UPPER(BTRIM(answer_key, E' \t\r\n'));